@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use ibus::{
+    engine::{Engine, EngineContext, EngineHost, EngineRegistry},
+    keysyms, Bus, ComponentBuilder, EngineDescBuilder, Modifiers, PropList, PropState, PropType,
+    Property,
+};
+
+// A toy engine that types every word backwards.
+//
+// `cargo run --example engine` installs the component for the current user,
+// registers it with the running daemon, and serves the engine. It can then be
+// selected from the panel or with `ibus engine rust-reverse`. After the daemon
+// is restarted, the engine shows up in `ibus-setup`, and the daemon starts
+// this program with the `--ibus` argument when the engine is selected.
+
+const COMPONENT_NAME: &str = "org.freedesktop.IBus.RustReverse";
+const ENGINE_NAME: &str = "rust-reverse";
+const ENABLED_PROP: &str = "Reverse.Enabled";
+
+struct Reverse {
+    word: Vec<char>,
+    enabled: bool,
+}
+impl Reverse {
+    fn new() -> Self {
+        Reverse {
+            word: Vec::new(),
+            enabled: true,
+        }
+    }
+
+    fn reversed(&self) -> String {
+        self.word.iter().rev().collect()
+    }
+
+    fn show(&self, ctx: &mut EngineContext) {
+        let text = self.reversed();
+        let len = text.chars().count() as u32;
+        ctx.update_preedit_text(text, len, !self.word.is_empty());
+    }
+
+    fn commit(&mut self, ctx: &mut EngineContext) {
+        if !self.word.is_empty() {
+            ctx.commit_text(self.reversed());
+            self.word.clear();
+            self.show(ctx);
+        }
+    }
+
+    fn enabled_property(&self) -> Property {
+        let mut prop = Property::new(ENABLED_PROP, PropType::Toggle);
+        prop.label = "Reverse words".into();
+        prop.symbol = if self.enabled { "⇄" } else { "→" }.into();
+        prop.state = if self.enabled {
+            PropState::Checked
+        } else {
+            PropState::Unchecked
+        };
+        prop
+    }
+}
+
+impl Engine for Reverse {
+    fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        _code: u32,
+        modifiers: Modifiers,
+    ) -> bool {
+        if !self.enabled
+            || modifiers.contains(Modifiers::RELEASE)
+            || modifiers.intersects(Modifiers::CONTROL | Modifiers::MOD1)
+        {
+            return false;
+        }
+        match sym {
+            keysyms::KEY_BackSpace if !self.word.is_empty() => {
+                self.word.pop();
+                self.show(ctx);
+                true
+            }
+            keysyms::KEY_Escape if !self.word.is_empty() => {
+                self.word.clear();
+                self.show(ctx);
+                true
+            }
+            _ => match ibus::keysym_to_char(sym) {
+                Some(c) if c.is_alphanumeric() => {
+                    self.word.push(c);
+                    self.show(ctx);
+                    true
+                }
+                _ => {
+                    // Anything else ends the word, and goes to the application
+                    self.commit(ctx);
+                    false
+                }
+            },
+        }
+    }
+
+    fn focus_in(&mut self, ctx: &mut EngineContext) {
+        let mut props = PropList::new();
+        props.push(self.enabled_property());
+        ctx.register_properties(props);
+    }
+
+    fn focus_out(&mut self, ctx: &mut EngineContext) {
+        self.commit(ctx);
+    }
+
+    fn reset(&mut self, ctx: &mut EngineContext) {
+        self.word.clear();
+        self.show(ctx);
+    }
+
+    fn property_activate(&mut self, ctx: &mut EngineContext, name: &str, state: PropState) {
+        if name == ENABLED_PROP {
+            self.commit(ctx);
+            self.enabled = state == PropState::Checked;
+            ctx.update_property(self.enabled_property());
+        }
+    }
+}
+
+fn main() {
+    simple_logger::SimpleLogger::new().init().unwrap();
+
+    let exe = std::env::current_exe().unwrap();
+    let component = ComponentBuilder::new(COMPONENT_NAME)
+        .description("Types every word backwards")
+        .exec(format!("{} --ibus", exe.display()))
+        .engine(
+            EngineDescBuilder::new(ENGINE_NAME)
+                .longname("Reverse (Rust example)")
+                .description("Types every word backwards")
+                .language("en")
+                .symbol("⇄")
+                .build(),
+        )
+        .build();
+
+    let bus = Bus::new().unwrap();
+    let mut host = EngineHost::new(&bus);
+    host.set_factory(EngineRegistry::new().register(ENGINE_NAME, Reverse::new));
+    if std::env::args().any(|a| a == "--ibus") {
+        // Started by the daemon, which already knows about the component
+        host.request_name(COMPONENT_NAME).unwrap();
+    } else {
+        let path = host.install_and_register(&component).unwrap();
+        println!("Installed {}", path.display());
+        println!("Select the engine with `ibus engine {}`", ENGINE_NAME);
+    }
+
+    loop {
+        if let Err(e) = bus.process(Duration::from_secs(1)) {
+            log::error!("Lost the connection to the daemon: {}", e);
+            break;
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    collections::VecDeque,
+    io::{BufRead, Write},
+    sync::mpsc,
+    time::Duration,
+};
+
+use ibus::{
+    dbus::strings::Path,
+    panel::{CursorLocation, Panel, PanelContext, PanelHost},
+    Bus, CandidatePopupModel, EngineDesc, LookupTable, Modifiers, PropList, PropState, PropType,
+    Property, Text,
+};
+
+// A panel that draws everything in the terminal.
+//
+// `cargo run --example panel` replaces the panel of the desktop for as long
+// as it runs, so it's useful for checking what an engine sends. The candidate
+// window, the properties of the engine, and the last calls of the daemon are
+// redrawn whenever they change. Type a command and press Enter to act on the
+// focused engine:
+//
+// - `n` / `p`: next / previous page
+// - `j` / `k`: move the cursor down / up
+// - a number: click the candidate with that index on the page
+// - the key of a toggle property: activate it
+// - `q`: quit
+//
+// Restart the daemon (`ibus restart`) afterwards to get the usual panel back.
+
+const LOG_LEN: usize = 8;
+
+#[derive(Default)]
+struct TerminalPanel {
+    popup: CandidatePopupModel,
+    properties: PropList,
+    cursor: Option<CursorLocation>,
+    engine: Option<String>,
+    log: VecDeque<String>,
+    dirty: bool,
+}
+impl TerminalPanel {
+    fn log(&mut self, call: String) {
+        if self.log.len() == LOG_LEN {
+            self.log.pop_front();
+        }
+        self.log.push_back(call);
+        self.dirty = true;
+    }
+
+    fn draw(&mut self, ctx: &PanelContext) {
+        self.popup.take_changed();
+        self.dirty = false;
+
+        let mut out = String::new();
+        // Clear the screen, and move to the top left corner
+        out.push_str("\x1b[2J\x1b[H");
+        let focused = ctx.focused().map_or("none".to_owned(), |p| p.to_string());
+        out.push_str(&format!("Focused: {}\n", focused));
+        out.push_str(&format!(
+            "Engine:  {}\n",
+            self.engine.as_deref().unwrap_or("none")
+        ));
+        if let Some(c) = &self.cursor {
+            out.push_str(&format!(
+                "Cursor:  {}x{} at ({}, {}){}\n",
+                c.width,
+                c.height,
+                c.x,
+                c.y,
+                if c.relative { " in the window" } else { "" }
+            ));
+        }
+
+        out.push_str("\n┌─ Candidates\n");
+        let content = self.popup.content();
+        if let Some((preedit, cursor_pos)) = &content.preedit {
+            out.push_str(&format!(
+                "│ preedit: {} (cursor at {})\n",
+                preedit.as_str(),
+                cursor_pos
+            ));
+        }
+        if let Some(aux) = &content.auxiliary {
+            out.push_str(&format!("│ {}\n", aux.as_str()));
+        }
+        for candidate in &content.candidates {
+            let marker = if candidate.selected { '▶' } else { ' ' };
+            out.push_str(&format!(
+                "│ {}{}. {}\n",
+                marker,
+                candidate.label,
+                candidate.text.as_str()
+            ));
+        }
+        if !content.is_visible() {
+            out.push_str("│ (hidden)\n");
+        }
+
+        out.push_str("\n┌─ Properties\n");
+        draw_properties(&mut out, &self.properties, 0);
+
+        out.push_str("\n┌─ Last calls\n");
+        for call in &self.log {
+            out.push_str(&format!("│ {}\n", call));
+        }
+        out.push_str("\ncommand> ");
+
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(out.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+fn draw_properties(out: &mut String, props: &PropList, depth: usize) {
+    for prop in &props.properties {
+        if !prop.visible || prop.prop_type == PropType::Separator {
+            continue;
+        }
+        let state = match (prop.prop_type, prop.state) {
+            (PropType::Toggle | PropType::Radio, PropState::Checked) => "[x] ",
+            (PropType::Toggle | PropType::Radio, _) => "[ ] ",
+            _ => "",
+        };
+        out.push_str(&format!(
+            "│ {:indent$}{}{} {} ({})\n",
+            "",
+            state,
+            prop.symbol.as_str(),
+            prop.label.as_str(),
+            prop.key,
+            indent = depth * 2
+        ));
+        draw_properties(out, &prop.sub_props, depth + 1);
+    }
+}
+
+impl Panel for TerminalPanel {
+    fn update_preedit_text(
+        &mut self,
+        _ctx: &mut PanelContext,
+        text: Text<'static>,
+        cursor_pos: u32,
+        visible: bool,
+    ) {
+        self.popup.update_preedit_text(text, cursor_pos, visible);
+    }
+
+    fn show_preedit_text(&mut self, _ctx: &mut PanelContext) {
+        self.popup.show_preedit_text();
+    }
+
+    fn hide_preedit_text(&mut self, _ctx: &mut PanelContext) {
+        self.popup.hide_preedit_text();
+    }
+
+    fn update_auxiliary_text(
+        &mut self,
+        _ctx: &mut PanelContext,
+        text: Text<'static>,
+        visible: bool,
+    ) {
+        self.popup.update_auxiliary_text(text, visible);
+    }
+
+    fn show_auxiliary_text(&mut self, _ctx: &mut PanelContext) {
+        self.popup.show_auxiliary_text();
+    }
+
+    fn hide_auxiliary_text(&mut self, _ctx: &mut PanelContext) {
+        self.popup.hide_auxiliary_text();
+    }
+
+    fn update_lookup_table(&mut self, _ctx: &mut PanelContext, table: LookupTable, visible: bool) {
+        self.log(format!(
+            "UpdateLookupTable: {} candidates, cursor at {}",
+            table.candidates.len(),
+            table.cursor_pos
+        ));
+        self.popup.update_lookup_table(table, visible);
+    }
+
+    fn show_lookup_table(&mut self, _ctx: &mut PanelContext) {
+        self.popup.show_lookup_table();
+    }
+
+    fn hide_lookup_table(&mut self, _ctx: &mut PanelContext) {
+        self.popup.hide_lookup_table();
+    }
+
+    fn register_properties(&mut self, _ctx: &mut PanelContext, props: PropList) {
+        self.log(format!("RegisterProperties: {}", props.properties.len()));
+        self.properties = props;
+    }
+
+    fn update_property(&mut self, _ctx: &mut PanelContext, prop: Property) {
+        self.log(format!("UpdateProperty: {}", prop.key));
+        self.properties.update_property(&prop);
+    }
+
+    fn set_cursor_location(&mut self, _ctx: &mut PanelContext, location: &CursorLocation) {
+        self.cursor = Some(location.clone());
+        self.dirty = true;
+    }
+
+    fn set_cursor_location_relative(&mut self, ctx: &mut PanelContext, location: &CursorLocation) {
+        self.set_cursor_location(ctx, location);
+    }
+
+    fn focus_in(&mut self, _ctx: &mut PanelContext, input_context: &Path<'static>) {
+        self.log(format!("FocusIn: {}", input_context));
+    }
+
+    fn focus_out(&mut self, _ctx: &mut PanelContext, input_context: &Path<'static>) {
+        self.log(format!("FocusOut: {}", input_context));
+        self.popup.clear();
+    }
+
+    fn engine_changed(&mut self, _ctx: &mut PanelContext, engine: Option<&EngineDesc>) {
+        self.engine = engine.map(|desc| format!("{} ({})", desc.longname, desc.name));
+        self.dirty = true;
+    }
+
+    fn destroy(&mut self, _ctx: &mut PanelContext) {
+        println!("\nThe daemon asked the panel to quit");
+        std::process::exit(0);
+    }
+}
+
+/// Acts on a line typed by the user. Returns false to quit.
+fn run_command(panel: &mut TerminalPanel, ctx: &mut PanelContext, command: &str) -> bool {
+    match command {
+        "q" => return false,
+        "n" => ctx.page_down(),
+        "p" => ctx.page_up(),
+        "j" => ctx.cursor_down(),
+        "k" => ctx.cursor_up(),
+        "" => {}
+        _ => {
+            if let Ok(index) = command.parse::<u32>() {
+                // The labels start at 1, and the indices at 0
+                ctx.candidate_clicked(index.saturating_sub(1), 1, Modifiers::empty());
+            } else if let Some(prop) = panel.properties.get(command) {
+                let state = match prop.state {
+                    PropState::Checked => PropState::Unchecked,
+                    _ => PropState::Checked,
+                };
+                ctx.property_activate(command, state);
+            } else {
+                panel.log(format!("Unknown command `{}`", command));
+            }
+        }
+    }
+    panel.dirty = true;
+    true
+}
+
+fn main() {
+    let bus = Bus::new().unwrap();
+    let host = PanelHost::new(&bus, TerminalPanel::default()).unwrap();
+
+    let (sender, commands) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+            if sender.send(line.trim().to_owned()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut first = true;
+    loop {
+        if let Err(e) = bus.process(Duration::from_millis(50)) {
+            eprintln!("Lost the connection to the daemon: {}", e);
+            break;
+        }
+        let mut quit = false;
+        while let Ok(command) = commands.try_recv() {
+            quit |= !host.with_panel(|panel, ctx| run_command(panel, ctx, &command));
+        }
+        if quit {
+            break;
+        }
+        host.with_panel(|panel, ctx| {
+            if first || panel.dirty || panel.popup.take_changed() {
+                panel.draw(ctx);
+            }
+        });
+        first = false;
+    }
+}
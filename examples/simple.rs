@@ -38,10 +38,5 @@ fn main() {
 
     ctx.focus_out().unwrap();
 
-    loop {
-        match bus.process(std::time::Duration::from_secs(0)) {
-            Ok(true) => {}
-            _ => break,
-        }
-    }
+    while let Ok(true) = bus.process(std::time::Duration::from_secs(0)) {}
 }
@@ -0,0 +1,24 @@
+//! Reads arbitrary values as the serializable types of the crate
+//!
+//! `cargo fuzz run get`
+
+#![no_main]
+
+use ibus::{
+    panel::extension::ExtensionEvent, Attribute, Component, EngineDesc, LookupTable, PropList,
+    Property, Text,
+};
+use ibus_fuzz::{signal, Value};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|values: Vec<Value>| {
+    let msg = signal("Fuzz", &values);
+    let _ = msg.read1::<Text>();
+    let _ = msg.read1::<Attribute>();
+    let _ = msg.read1::<LookupTable>();
+    let _ = msg.read1::<Property>();
+    let _ = msg.read1::<PropList>();
+    let _ = msg.read1::<EngineDesc>();
+    let _ = msg.read1::<Component>();
+    let _ = msg.read1::<ExtensionEvent>();
+});
@@ -0,0 +1,31 @@
+//! Decodes arbitrary arguments as every signal of an input context
+//!
+//! `cargo fuzz run signals`
+
+#![no_main]
+
+use ibus::ImeEvent;
+use ibus_fuzz::{signal, Value};
+use libfuzzer_sys::fuzz_target;
+
+const SIGNALS: &[&str] = &[
+    "CommitText",
+    "UpdatePreeditText",
+    "ShowPreeditText",
+    "HidePreeditText",
+    "ForwardKeyEvent",
+    "DeleteSurroundingText",
+    "RequireSurroundingText",
+    "UpdateAuxiliaryText",
+    "ShowAuxiliaryText",
+    "HideAuxiliaryText",
+    "UpdateLookupTable",
+    "ShowLookupTable",
+    "HideLookupTable",
+];
+
+fuzz_target!(|values: Vec<Value>| {
+    for member in SIGNALS {
+        let _ = ImeEvent::from_message(&signal(member, &values));
+    }
+});
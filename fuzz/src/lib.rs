@@ -0,0 +1,142 @@
+//! Arbitrary D-Bus values for the fuzz targets
+//!
+//! Random bytes are almost never a valid D-Bus message, and libdbus rejects
+//! the invalid ones before the crate sees them. So the targets generate
+//! valid messages of arbitrary structure instead, which is what a buggy or
+//! malicious engine can send.
+
+use arbitrary::Arbitrary;
+use dbus::{arg::IterAppend, Message, Signature};
+
+/// Deeper values are cut off, libdbus aborts on too deeply nested containers
+const MAX_DEPTH: usize = 16;
+
+/// The longest signature that D-Bus allows
+const MAX_SIGNATURE: usize = 255;
+
+#[derive(Arbitrary, Debug)]
+pub enum Value {
+    Bool(bool),
+    Byte(u8),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    Double(f64),
+    Str(String),
+    Variant(Box<Value>),
+    Struct(Vec<Value>),
+    /// `av`
+    Variants(Vec<Value>),
+    Strings(Vec<String>),
+    U32s(Vec<u32>),
+    /// `a{sv}`
+    Dict(Vec<(String, Value)>),
+}
+impl Value {
+    fn signature(&self, depth: usize) -> String {
+        if depth > MAX_DEPTH {
+            return "y".into();
+        }
+        match self {
+            Value::Bool(_) => "b".into(),
+            Value::Byte(_) => "y".into(),
+            Value::I32(_) => "i".into(),
+            Value::U32(_) => "u".into(),
+            Value::I64(_) => "x".into(),
+            Value::U64(_) => "t".into(),
+            Value::Double(_) => "d".into(),
+            Value::Str(_) => "s".into(),
+            Value::Variant(_) => "v".into(),
+            Value::Struct(fields) if fields.is_empty() => "(y)".into(),
+            Value::Struct(fields) => {
+                let fields: String = fields.iter().map(|f| f.signature(depth + 1)).collect();
+                format!("({})", fields)
+            }
+            Value::Variants(_) => "av".into(),
+            Value::Strings(_) => "as".into(),
+            Value::U32s(_) => "au".into(),
+            Value::Dict(_) => "a{sv}".into(),
+        }
+    }
+
+    fn append(&self, i: &mut IterAppend, depth: usize) {
+        if depth > MAX_DEPTH {
+            i.append(0u8);
+            return;
+        }
+        match self {
+            Value::Bool(b) => i.append(*b),
+            Value::Byte(b) => i.append(*b),
+            Value::I32(n) => i.append(*n),
+            Value::U32(n) => i.append(*n),
+            Value::I64(n) => i.append(*n),
+            Value::U64(n) => i.append(*n),
+            Value::Double(n) => i.append(*n),
+            Value::Str(s) => i.append(valid_string(s)),
+            Value::Variant(v) => append_variant(v, i, depth + 1),
+            Value::Struct(fields) => i.append_struct(|i| {
+                if fields.is_empty() {
+                    i.append(0u8);
+                }
+                for field in fields {
+                    field.append(i, depth + 1);
+                }
+            }),
+            Value::Variants(values) => i.append_array(&Signature::from("v"), |i| {
+                for v in values {
+                    append_variant(v, i, depth + 1);
+                }
+            }),
+            Value::Strings(strings) => {
+                i.append(strings.iter().map(|s| valid_string(s)).collect::<Vec<_>>())
+            }
+            Value::U32s(numbers) => i.append(numbers.clone()),
+            Value::Dict(entries) => {
+                i.append_dict(&Signature::from("s"), &Signature::from("v"), |i| {
+                    for (key, v) in entries {
+                        i.append_dict_entry(|i| {
+                            i.append(valid_string(key));
+                            append_variant(v, i, depth + 1);
+                        });
+                    }
+                })
+            }
+        }
+    }
+}
+
+fn append_variant(v: &Value, i: &mut IterAppend, depth: usize) {
+    let signature = v.signature(depth);
+    if signature.len() > MAX_SIGNATURE {
+        i.append_variant(&Signature::from("y"), |i| i.append(0u8));
+        return;
+    }
+    i.append_variant(&Signature::from(signature), |i| v.append(i, depth));
+}
+
+/// D-Bus strings can't contain NUL
+fn valid_string(s: &str) -> String {
+    s.replace('\0', "")
+}
+
+/// Returns a signal of the input context interface with `values` as its
+/// arguments, as many of them as fit in a signature
+pub fn signal(member: &str, values: &[Value]) -> Message {
+    let mut msg = Message::new_signal(
+        "/org/freedesktop/IBus/InputContext_1",
+        "org.freedesktop.IBus.InputContext",
+        member,
+    )
+    .expect("the signal should be valid");
+    let mut i = IterAppend::new(&mut msg);
+    let mut signature_len = 0;
+    for v in values {
+        signature_len += v.signature(0).len();
+        if signature_len > MAX_SIGNATURE {
+            break;
+        }
+        v.append(&mut i, 0);
+    }
+    msg
+}
@@ -0,0 +1,77 @@
+/*
+ * The C API of the ibus crate, built with the `ffi` feature. See the
+ * documentation of the `ffi` module.
+ */
+
+#ifndef IBUS_RS_H
+#define IBUS_RS_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct IbusRsBus IbusRsBus;
+typedef struct IbusRsContext IbusRsContext;
+
+#define IBUS_RS_CAP_PREEDIT_TEXT (1u << 0)
+#define IBUS_RS_CAP_AUXILIARY_TEXT (1u << 1)
+#define IBUS_RS_CAP_LOOKUP_TABLE (1u << 2)
+#define IBUS_RS_CAP_FOCUS (1u << 3)
+#define IBUS_RS_CAP_PROPERTY (1u << 4)
+#define IBUS_RS_CAP_SURROUNDING_TEXT (1u << 5)
+
+#define IBUS_RS_EVENT_COMMIT_TEXT 1u
+#define IBUS_RS_EVENT_UPDATE_PREEDIT_TEXT 2u
+#define IBUS_RS_EVENT_SHOW_PREEDIT_TEXT 3u
+#define IBUS_RS_EVENT_HIDE_PREEDIT_TEXT 4u
+#define IBUS_RS_EVENT_FORWARD_KEY_EVENT 5u
+#define IBUS_RS_EVENT_DELETE_SURROUNDING_TEXT 6u
+#define IBUS_RS_EVENT_REQUIRE_SURROUNDING_TEXT 7u
+
+/* The fields that don't belong to `kind` are zero. The strings are valid
+ * until the next ibus_rs_bus_poll. */
+typedef struct IbusRsEvent {
+    uint32_t kind;
+    const char *context;
+    /* COMMIT_TEXT and UPDATE_PREEDIT_TEXT */
+    const char *text;
+    /* UPDATE_PREEDIT_TEXT */
+    uint32_t cursor_pos;
+    int visible;
+    /* FORWARD_KEY_EVENT */
+    uint32_t keysym;
+    uint32_t keycode;
+    uint32_t modifiers;
+    /* DELETE_SURROUNDING_TEXT, in characters from the cursor */
+    int32_t offset;
+    uint32_t nchars;
+} IbusRsEvent;
+
+const char *ibus_rs_last_error(void);
+
+IbusRsBus *ibus_rs_bus_new(void);
+void ibus_rs_bus_free(IbusRsBus *bus);
+int ibus_rs_bus_fd(const IbusRsBus *bus);
+int ibus_rs_bus_poll(IbusRsBus *bus, uint32_t timeout_ms, IbusRsEvent *event);
+
+IbusRsContext *ibus_rs_context_new(const IbusRsBus *bus, const char *name);
+void ibus_rs_context_free(IbusRsContext *ctx);
+const char *ibus_rs_context_path(const IbusRsContext *ctx);
+int ibus_rs_context_set_capabilities(const IbusRsContext *ctx, uint32_t capabilities);
+int ibus_rs_context_process_key_event(const IbusRsContext *ctx, uint32_t keysym,
+                                      uint32_t keycode, uint32_t modifiers);
+int ibus_rs_context_set_cursor_location(const IbusRsContext *ctx, int32_t x, int32_t y,
+                                        int32_t w, int32_t h);
+int ibus_rs_context_set_surrounding_text(const IbusRsContext *ctx, const char *text,
+                                         uint32_t cursor_pos, uint32_t anchor_pos);
+int ibus_rs_context_focus_in(const IbusRsContext *ctx);
+int ibus_rs_context_focus_out(const IbusRsContext *ctx);
+int ibus_rs_context_reset(const IbusRsContext *ctx);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
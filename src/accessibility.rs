@@ -0,0 +1,215 @@
+//! Telling screen readers about the composition
+//!
+//! The preedit text and the candidate window aren't part of the text of the
+//! application, so screen readers don't see them, and their users can't
+//! hear what they're typing until it's committed. `ImeAnnouncer` makes
+//! short announcements from the signals of the input context, and gives
+//! them to an `Announcer`, which the accessibility layer of the application
+//! implements. With AT-SPI that's the `Announcement` signal of the focused
+//! object, and with AccessKit a live region:
+//!
+//! ```
+//! use ibus::accessibility::{ImeAnnouncer, Politeness};
+//! use ibus::{ImeEventKind, Text};
+//!
+//! let mut spoken = Vec::new();
+//! let mut announcer = ImeAnnouncer::new(|message: &str, politeness: Politeness| {
+//!     spoken.push((message.to_owned(), politeness));
+//! });
+//!
+//! // For the events popped from an `EventQueue`, or in the handler of `EventRouter`
+//! announcer.handle_event(&ImeEventKind::UpdatePreeditText {
+//!     text: Text::new("ni", vec![]),
+//!     cursor_pos: 2,
+//!     visible: true,
+//! });
+//! announcer.handle_event(&ImeEventKind::CommitText(Text::new("你", vec![])));
+//! drop(announcer);
+//! assert_eq!(
+//!     spoken,
+//!     [
+//!         ("ni".to_owned(), Politeness::Polite),
+//!         ("你".to_owned(), Politeness::Assertive),
+//!     ]
+//! );
+//! ```
+//!
+
+use crate::{ImeEventKind, LookupTable};
+
+/// How a screen reader should speak an announcement, like `aria-live`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    /// After what's being spoken
+    Polite,
+    /// Interrupting what's being spoken
+    Assertive,
+}
+
+/// Speaks the announcements of `ImeAnnouncer`, implemented by the
+/// accessibility layer of the application
+///
+/// It's implemented for closures.
+pub trait Announcer {
+    fn announce(&mut self, message: &str, politeness: Politeness);
+}
+impl<F: FnMut(&str, Politeness)> Announcer for F {
+    fn announce(&mut self, message: &str, politeness: Politeness) {
+        self(message, politeness)
+    }
+}
+
+/// Announces the changes of the preedit text and of the selected candidate
+/// politely, and the committed text assertively, see the module
+/// documentation
+///
+/// Nothing is announced when the same text is sent again, which engines do
+/// often.
+#[derive(Debug, Clone)]
+pub struct ImeAnnouncer<A> {
+    announcer: A,
+    preedit: String,
+    preedit_visible: bool,
+    table: Option<LookupTable>,
+    table_visible: bool,
+    candidate: Option<String>,
+}
+impl<A: Announcer> ImeAnnouncer<A> {
+    pub fn new(announcer: A) -> Self {
+        ImeAnnouncer {
+            announcer,
+            preedit: String::new(),
+            preedit_visible: false,
+            table: None,
+            table_visible: false,
+            candidate: None,
+        }
+    }
+
+    pub fn announcer(&self) -> &A {
+        &self.announcer
+    }
+
+    pub fn announcer_mut(&mut self) -> &mut A {
+        &mut self.announcer
+    }
+
+    /// Forgets the composition without announcing anything, e.g. when the
+    /// input context loses the focus
+    pub fn reset(&mut self) {
+        self.preedit.clear();
+        self.preedit_visible = false;
+        self.table = None;
+        self.table_visible = false;
+        self.candidate = None;
+    }
+
+    /// Announces what `event` changed, if anything
+    pub fn handle_event(&mut self, event: &ImeEventKind) {
+        match event {
+            ImeEventKind::CommitText(text) => {
+                let text = text.as_str();
+                if !text.is_empty() {
+                    self.announcer.announce(text, Politeness::Assertive);
+                }
+                // The preedit is cleared after the commit, and should be
+                // announced again if the engine starts with the same text
+                self.preedit.clear();
+                self.candidate = None;
+            }
+            ImeEventKind::UpdatePreeditText { text, visible, .. } => {
+                let was_shown = self.preedit_shown();
+                let old = std::mem::replace(&mut self.preedit, text.as_str().to_owned());
+                self.preedit_visible = *visible;
+                if self.preedit_shown() && (!was_shown || old != self.preedit) {
+                    self.announcer.announce(&self.preedit, Politeness::Polite);
+                }
+            }
+            ImeEventKind::ShowPreeditText => {
+                let was_shown = self.preedit_shown();
+                self.preedit_visible = true;
+                if self.preedit_shown() && !was_shown {
+                    self.announcer.announce(&self.preedit, Politeness::Polite);
+                }
+            }
+            ImeEventKind::HidePreeditText => self.preedit_visible = false,
+            ImeEventKind::UpdateLookupTable { table, visible } => {
+                self.table = Some(table.clone());
+                self.table_visible = *visible;
+                self.announce_candidate();
+            }
+            ImeEventKind::ShowLookupTable => {
+                self.table_visible = true;
+                self.announce_candidate();
+            }
+            ImeEventKind::HideLookupTable => {
+                self.table_visible = false;
+                self.candidate = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn preedit_shown(&self) -> bool {
+        self.preedit_visible && !self.preedit.is_empty()
+    }
+
+    /// Announces the candidate under the cursor of the lookup table if it's
+    /// another one than the last time
+    fn announce_candidate(&mut self) {
+        let candidate = self
+            .table
+            .as_ref()
+            .filter(|table| self.table_visible && table.cursor_visible)
+            .and_then(|table| table.candidates.get(table.cursor_pos as usize))
+            .map(|text| text.as_str().to_owned());
+        if candidate != self.candidate {
+            if let Some(candidate) = &candidate {
+                self.announcer.announce(candidate, Politeness::Polite);
+            }
+            self.candidate = candidate;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Text;
+
+    fn preedit(text: &str, visible: bool) -> ImeEventKind {
+        ImeEventKind::UpdatePreeditText {
+            text: Text::new(text.to_owned(), vec![]),
+            cursor_pos: 0,
+            visible,
+        }
+    }
+
+    #[test]
+    fn announcements() {
+        let mut spoken = Vec::new();
+        let mut announcer =
+            ImeAnnouncer::new(|message: &str, _: Politeness| spoken.push(message.to_owned()));
+        announcer.handle_event(&preedit("n", true));
+        announcer.handle_event(&preedit("n", true));
+        announcer.handle_event(&preedit("ni", false));
+        announcer.handle_event(&ImeEventKind::ShowPreeditText);
+
+        let mut table = LookupTable::default();
+        table.append_candidate("你");
+        table.append_candidate("尼");
+        announcer.handle_event(&ImeEventKind::UpdateLookupTable {
+            table: table.clone(),
+            visible: true,
+        });
+        table.cursor_down();
+        announcer.handle_event(&ImeEventKind::UpdateLookupTable {
+            table,
+            visible: true,
+        });
+        announcer.handle_event(&ImeEventKind::CommitText(Text::new("尼", vec![])));
+        announcer.handle_event(&preedit("", true));
+        drop(announcer);
+        assert_eq!(spoken, ["n", "ni", "你", "尼", "尼"]);
+    }
+}
@@ -0,0 +1,293 @@
+//! Implementing `zwp_text_input_v3` in a compositor with IBus
+//!
+//! A compositor, e.g. one made with Smithay, that gives the text input of
+//! its clients to IBus keeps a `TextInputBridge` for each `zwp_text_input_v3`
+//! object, and an `InputContext` for it. The requests of the client are
+//! passed to the bridge, which keeps them pending like the protocol says,
+//! and sends them to the input context on `commit`. The signals of the input
+//! context go to `handle_event`, which returns the events to send to the
+//! client:
+//!
+//! ```no_run
+//! use ibus::{bridge::TextInputBridge, Bus, Capabilites};
+//!
+//! let bus = Bus::new().unwrap();
+//! let ctx = bus.create_input_context("compositor").unwrap();
+//! ctx.set_capabilities(
+//!     Capabilites::PREEDIT_TEXT | Capabilites::FOCUS | Capabilites::SURROUNDING_TEXT,
+//! );
+//! let mut bridge = TextInputBridge::new();
+//!
+//! // The requests of the client
+//! bridge.enable();
+//! bridge.set_surrounding_text("Hello", 5, 5);
+//! bridge.set_cursor_rectangle(40, 0, 2, 18);
+//! bridge.commit(&ctx).unwrap();
+//!
+//! // For the events popped from an `EventQueue`, or in the handler of `EventRouter`
+//! # let event = ibus::ImeEventKind::ShowPreeditText;
+//! if let Some(done) = bridge.handle_event(&event) {
+//!     // Send `done.preedit_string`, `done.commit_string`,
+//!     // `done.delete_surrounding_text`, then `done(done.serial)`
+//! }
+//! ```
+//!
+//! The keys of the focused client are sent with
+//! `InputContext::process_key_event` while the bridge is enabled, and the
+//! keys that weren't handled or that were forwarded with `ForwardKeyEvent`
+//! go to the client through `wl_keyboard`. The lookup table isn't part of
+//! text-input, the compositor can draw it with `CandidatePopupModel`.
+//!
+//! The positions of text-input count bytes, the bridge converts them to the
+//! characters of IBus.
+//!
+
+use log::warn;
+
+use crate::{Error, ImeEventKind, InputContext, Selection};
+
+/// The double-buffered state of a `zwp_text_input_v3`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct State {
+    enabled: bool,
+    /// The text, and the cursor and the anchor in bytes
+    surrounding_text: Option<(String, u32, u32)>,
+    cursor_rectangle: Option<(i32, i32, i32, i32)>,
+}
+
+/// The events to send to the client, followed by `done`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInputDone {
+    /// The arguments of `preedit_string`: the text, and the start and end of
+    /// the cursor in bytes, or -1 to hide it. `None` if there's no preedit.
+    pub preedit_string: Option<(String, i32, i32)>,
+    pub commit_string: Option<String>,
+    /// The arguments of `delete_surrounding_text`, the bytes to delete before
+    /// and after the cursor
+    pub delete_surrounding_text: Option<(u32, u32)>,
+    /// The argument of `done`, the number of `commit` requests so far
+    pub serial: u32,
+}
+
+/// Maps the requests and events of a `zwp_text_input_v3` to an
+/// `InputContext`
+#[derive(Debug, Clone, Default)]
+pub struct TextInputBridge {
+    pending: State,
+    current: State,
+    commits: u32,
+    surface_origin: (i32, i32),
+    /// The preedit of the engine, with the cursor in characters
+    preedit: Option<(String, u32)>,
+    preedit_visible: bool,
+}
+impl TextInputBridge {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The `enable` request, which resets the rest of the pending state
+    pub fn enable(&mut self) {
+        self.pending = State {
+            enabled: true,
+            ..State::default()
+        };
+    }
+
+    /// The `disable` request
+    pub fn disable(&mut self) {
+        self.pending.enabled = false;
+    }
+
+    /// The `set_surrounding_text` request, `cursor` and `anchor` count bytes
+    pub fn set_surrounding_text(&mut self, text: impl Into<String>, cursor: u32, anchor: u32) {
+        self.pending.surrounding_text = Some((text.into(), cursor, anchor));
+    }
+
+    /// The `set_cursor_rectangle` request, relative to the surface
+    pub fn set_cursor_rectangle(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        self.pending.cursor_rectangle = Some((x, y, width, height));
+    }
+
+    /// Sets where the top left corner of the surface is, in the physical
+    /// pixels of the whole screen, to place the cursor rectangle
+    ///
+    /// Applied by the next `commit`.
+    pub fn set_surface_origin(&mut self, x: i32, y: i32) {
+        self.surface_origin = (x, y);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.current.enabled
+    }
+
+    /// The `commit` request, sends the pending state to the input context
+    ///
+    /// Enabling calls `focus_in`, disabling calls `reset` and `focus_out`.
+    /// A surrounding text with positions inside a character isn't sent.
+    pub fn commit(&mut self, ctx: &InputContext) -> Result<(), Error> {
+        self.commits = self.commits.wrapping_add(1);
+        let was_enabled = self.current.enabled;
+        self.current = if self.pending.enabled {
+            self.pending.clone()
+        } else {
+            State::default()
+        };
+        self.pending.surrounding_text = None;
+        self.pending.cursor_rectangle = None;
+
+        if !self.current.enabled {
+            self.preedit = None;
+            if was_enabled {
+                ctx.reset()?;
+                ctx.focus_out()?;
+            }
+            return Ok(());
+        }
+        if !was_enabled {
+            ctx.focus_in()?;
+        }
+        if let Some((text, selection)) = self.surrounding_selection() {
+            ctx.set_surrounding_text_clamped(text.to_owned(), selection)?;
+        }
+        if let Some((x, y, width, height)) = self.current.cursor_rectangle {
+            let (origin_x, origin_y) = self.surface_origin;
+            ctx.set_cursor_location(origin_x + x, origin_y + y, width.max(0), height.max(0))?;
+        }
+        Ok(())
+    }
+
+    /// The surrounding text and the selection in characters
+    fn surrounding_selection(&self) -> Option<(&str, Selection)> {
+        let (text, cursor, anchor) = self.current.surrounding_text.as_ref()?;
+        match Selection::from_utf8(text, *cursor as usize, *anchor as usize) {
+            Some(selection) => Some((text, selection)),
+            None => {
+                warn!(
+                    "The surrounding text positions {} and {} aren't character boundaries",
+                    cursor, anchor
+                );
+                None
+            }
+        }
+    }
+
+    /// Returns the events to send to the client for a signal of the input
+    /// context, `None` if there are none or the bridge isn't enabled
+    ///
+    /// The preedit is sent again with every `done`, since the client clears
+    /// it otherwise.
+    pub fn handle_event(&mut self, event: &ImeEventKind) -> Option<TextInputDone> {
+        if !self.current.enabled {
+            return None;
+        }
+        let mut done = TextInputDone::default();
+        match event {
+            ImeEventKind::CommitText(text) => {
+                done.commit_string = Some(text.as_str().to_owned());
+            }
+            ImeEventKind::UpdatePreeditText {
+                text,
+                cursor_pos,
+                visible,
+            } => {
+                self.preedit = Some((text.as_str().to_owned(), *cursor_pos));
+                self.preedit_visible = *visible;
+            }
+            ImeEventKind::ShowPreeditText => self.preedit_visible = true,
+            ImeEventKind::HidePreeditText => self.preedit_visible = false,
+            ImeEventKind::DeleteSurroundingText { offset, nchars } => {
+                done.delete_surrounding_text = Some(self.delete_lengths(*offset, *nchars)?);
+            }
+            _ => return None,
+        }
+        done.preedit_string = self.preedit_string();
+        done.serial = self.commits;
+        Some(done)
+    }
+
+    fn preedit_string(&self) -> Option<(String, i32, i32)> {
+        let (text, cursor) = self.preedit.as_ref().filter(|_| self.preedit_visible)?;
+        if text.is_empty() {
+            return None;
+        }
+        let (cursor, _) = Selection::caret(*cursor).clamp_to(text).to_utf8(text)?;
+        Some((text.clone(), cursor as i32, cursor as i32))
+    }
+
+    /// Converts the characters that IBus deletes, from `offset` relative to
+    /// the cursor, to the bytes before and after the cursor
+    ///
+    /// Returns `None` if the range doesn't touch the cursor, text-input can't
+    /// delete it.
+    fn delete_lengths(&self, offset: i32, nchars: u32) -> Option<(u32, u32)> {
+        let (text, selection) = self.surrounding_selection()?;
+        let cursor = selection.cursor as i64;
+        let start = (cursor + offset as i64).max(0);
+        let end = start + nchars as i64;
+        if start > cursor || end < cursor {
+            warn!(
+                "Can't delete {} characters at {} from the cursor with text-input",
+                nchars, offset
+            );
+            return None;
+        }
+        let (start, end) = Selection::new(start as u32, end as u32)
+            .clamp_to(text)
+            .to_utf8(text)?;
+        let (cursor, _) = selection.to_utf8(text)?;
+        Some(((cursor - start) as u32, (end - cursor) as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Text;
+
+    fn enabled(text: &str, cursor: u32) -> TextInputBridge {
+        let mut bridge = TextInputBridge::new();
+        bridge.enable();
+        bridge.set_surrounding_text(text, cursor, cursor);
+        bridge.current = bridge.pending.clone();
+        bridge.commits = 1;
+        bridge
+    }
+
+    #[test]
+    fn events() {
+        let mut bridge = TextInputBridge::new();
+        assert_eq!(bridge.handle_event(&ImeEventKind::ShowPreeditText), None);
+
+        let mut bridge = enabled("añb", 3);
+        let preedit = ImeEventKind::UpdatePreeditText {
+            text: Text::new("ñañ", vec![]),
+            cursor_pos: 2,
+            visible: true,
+        };
+        let done = bridge.handle_event(&preedit).unwrap();
+        assert_eq!(done.preedit_string, Some(("ñañ".to_owned(), 3, 3)));
+        assert_eq!(done.serial, 1);
+
+        let commit = ImeEventKind::CommitText(Text::new("x", vec![]));
+        let done = bridge.handle_event(&commit).unwrap();
+        assert_eq!(done.commit_string.as_deref(), Some("x"));
+        // Sent again, the preedit is still there
+        assert!(done.preedit_string.is_some());
+
+        let hide = ImeEventKind::HidePreeditText;
+        assert_eq!(bridge.handle_event(&hide).unwrap().preedit_string, None);
+    }
+
+    #[test]
+    fn delete_lengths() {
+        // The cursor is after ñ
+        let bridge = enabled("añb", 3);
+        assert_eq!(bridge.delete_lengths(-1, 1), Some((2, 0)));
+        assert_eq!(bridge.delete_lengths(-2, 3), Some((3, 1)));
+        assert_eq!(bridge.delete_lengths(0, 1), Some((0, 1)));
+        assert_eq!(bridge.delete_lengths(1, 1), None);
+        // Inside ñ
+        assert_eq!(enabled("añb", 2).delete_lengths(-1, 1), None);
+    }
+}
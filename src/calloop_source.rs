@@ -0,0 +1,135 @@
+use std::{
+    os::unix::io::BorrowedFd,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use calloop::{EventSource, Interest, Mode, Poll, PostAction, Readiness, Token, TokenFactory};
+use dbus::channel::MatchingReceiver;
+
+use crate::{dispatch::DispatchScope, dump, Bus, Error, ImeEvent};
+
+/// Lets a calloop event loop drive the connection, e.g. the loop of a
+/// Smithay compositor or of a wayland-rs client. Needs the `calloop`
+/// feature.
+///
+/// The signals of the input contexts are decoded and passed to the callback
+/// of the loop. Signals that have a callback registered with the `on_*`
+/// methods of `InputContext` go to that callback instead.
+///
+/// ```no_run
+/// use ibus::{Bus, Capabilites, ImeEventKind};
+///
+/// let mut event_loop = calloop::EventLoop::<()>::try_new().unwrap();
+/// let bus = Bus::new().unwrap();
+/// let ctx = bus.create_input_context("my-app").unwrap();
+/// ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+/// ctx.focus_in().unwrap();
+///
+/// event_loop
+///     .handle()
+///     .insert_source(bus, |event, _, _| {
+///         if let ImeEventKind::CommitText(text) = event.kind {
+///             println!("Committed {}", text.as_str());
+///         }
+///     })
+///     .unwrap();
+/// event_loop.run(None, &mut (), |_| {}).unwrap();
+/// ```
+impl EventSource for Bus {
+    type Event = ImeEvent;
+    type Metadata = ();
+    type Ret = ();
+    type Error = Error;
+
+    fn process_events<F>(
+        &mut self,
+        _readiness: Readiness,
+        _token: Token,
+        mut callback: F,
+    ) -> Result<PostAction, Error>
+    where
+        F: FnMut(ImeEvent, &mut ()),
+    {
+        let scope = match DispatchScope::enter(Rc::as_ptr(&self.conn)) {
+            Some(scope) => scope,
+            None => return Ok(PostAction::Continue),
+        };
+        // The filter has to be `Send`, and it's registered last, so that the
+        // `on_*` callbacks, which were registered before, get their signals
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let token = self.conn.start_receive(ImeEvent::match_rule(), {
+            let events = events.clone();
+            Box::new(move |msg, _| {
+                dump::received(&msg);
+                if let Some(event) = ImeEvent::from_message(&msg) {
+                    events.lock().unwrap().push(event);
+                }
+                true
+            })
+        });
+        let mut result = Ok(());
+        loop {
+            match self.conn.process(Duration::ZERO) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => {
+                    result = Err(Error::from(e));
+                    break;
+                }
+            }
+        }
+        self.conn.stop_receive(token);
+        drop(scope);
+
+        // Called after processing, so that the callback can make calls with
+        // the connection
+        let events = std::mem::take(&mut *events.lock().unwrap());
+        for event in events {
+            callback(event, &mut ());
+        }
+        result.map(|()| PostAction::Continue)
+    }
+
+    fn register(
+        &mut self,
+        poll: &mut Poll,
+        token_factory: &mut TokenFactory,
+    ) -> calloop::Result<()> {
+        // Safety: the fd is owned by the connection, which outlives the
+        // registration because the bus is unregistered before it's dropped
+        unsafe {
+            poll.register(
+                self.fd(),
+                Interest::READ,
+                Mode::Level,
+                token_factory.token(),
+            )
+        }
+    }
+
+    fn reregister(
+        &mut self,
+        poll: &mut Poll,
+        token_factory: &mut TokenFactory,
+    ) -> calloop::Result<()> {
+        poll.reregister(
+            self.fd(),
+            Interest::READ,
+            Mode::Level,
+            token_factory.token(),
+        )
+    }
+
+    fn unregister(&mut self, poll: &mut Poll) -> calloop::Result<()> {
+        poll.unregister(self.fd())
+    }
+}
+
+impl Bus {
+    fn fd(&self) -> BorrowedFd<'_> {
+        // Safety: the fd stays open as long as the connection
+        unsafe { BorrowedFd::borrow_raw(self.conn.channel().watch().fd) }
+    }
+}
@@ -0,0 +1,572 @@
+//! The content of a candidate window
+//!
+//! Engines update the preedit text, the auxiliary text and the lookup table
+//! with separate signals, and show or hide each of them separately.
+//! `CandidatePopupModel` combines these into what should be on screen, so a
+//! candidate window only needs to redraw `CandidatePopupModel::content` when
+//! it changes. The same model is used by panels and by applications that
+//! draw the candidates themselves.
+//!
+//! Applications that draw the candidates inside their own window (e.g.
+//! fullscreen games, where the panel's window can't be seen) can use
+//! `EmbeddedCandidates`, which sets up the input context for it.
+//!
+//! `place_popup` computes where to open the candidate window so it stays on
+//! screen, and `WindowOrigin` finds where the caret of a window is on the
+//! screen, for `InputContext::set_cursor_location`.
+//!
+
+use std::sync::{Arc, Mutex};
+
+use log::warn;
+
+use dbus::channel::Token;
+
+use crate::{
+    AfterCallback, Capabilites, Error, InputContext, LookupTable, Modifiers, Orientation, Text,
+};
+
+/// A candidate on the current page of the lookup table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PopupCandidate {
+    pub label: String,
+    pub text: Text<'static>,
+    /// Whether the candidate is under the cursor of the lookup table
+    pub selected: bool,
+}
+
+/// What the candidate window should show
+///
+/// Parts that are hidden, or empty, are `None` (or no candidates).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PopupContent {
+    /// The preedit text and the cursor position in it. Only needed if the
+    /// application doesn't draw the preedit text.
+    pub preedit: Option<(Text<'static>, u32)>,
+    pub auxiliary: Option<Text<'static>>,
+    /// The candidates on the current page
+    pub candidates: Vec<PopupCandidate>,
+    pub orientation: Orientation,
+}
+impl PopupContent {
+    fn empty() -> Self {
+        PopupContent {
+            preedit: None,
+            auxiliary: None,
+            candidates: Vec::new(),
+            orientation: Orientation::System,
+        }
+    }
+
+    /// Whether there's anything to show, i.e. the candidate window should be
+    /// open
+    pub fn is_visible(&self) -> bool {
+        self.preedit.is_some() || self.auxiliary.is_some() || !self.candidates.is_empty()
+    }
+}
+
+/// Aggregates the signals of the focused engine, see the module
+/// documentation
+///
+/// Every update method returns true if the content changed.
+///
+/// ```
+/// use ibus::{CandidatePopupModel, LookupTable};
+///
+/// let mut model = CandidatePopupModel::new();
+/// let mut table = LookupTable::default();
+/// table.append_candidate("你");
+/// table.append_candidate("尼");
+/// assert!(model.update_lookup_table(table.clone(), true));
+/// assert!(!model.update_lookup_table(table, true));
+///
+/// let content = model.content();
+/// assert!(content.is_visible());
+/// assert_eq!(content.candidates[1].label, "2");
+/// assert!(content.candidates[0].selected);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandidatePopupModel {
+    preedit: Text<'static>,
+    preedit_cursor: u32,
+    preedit_visible: bool,
+    auxiliary: Text<'static>,
+    auxiliary_visible: bool,
+    table: LookupTable,
+    table_visible: bool,
+    content: PopupContent,
+    changed: bool,
+}
+impl Default for CandidatePopupModel {
+    fn default() -> Self {
+        CandidatePopupModel {
+            preedit: Text::from(""),
+            preedit_cursor: 0,
+            preedit_visible: false,
+            auxiliary: Text::from(""),
+            auxiliary_visible: false,
+            table: LookupTable::default(),
+            table_visible: false,
+            content: PopupContent::empty(),
+            changed: false,
+        }
+    }
+}
+impl CandidatePopupModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// What should be on screen right now
+    pub fn content(&self) -> &PopupContent {
+        &self.content
+    }
+
+    /// The last lookup table, even if it's hidden
+    pub fn lookup_table(&self) -> &LookupTable {
+        &self.table
+    }
+
+    /// Returns true if the content changed since the last call
+    ///
+    /// This is useful when several signals arrive together, and the window
+    /// should only be redrawn once.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    pub fn update_preedit_text(
+        &mut self,
+        text: Text<'static>,
+        cursor_pos: u32,
+        visible: bool,
+    ) -> bool {
+        self.preedit = text;
+        self.preedit_cursor = cursor_pos;
+        self.preedit_visible = visible;
+        self.refresh()
+    }
+
+    pub fn show_preedit_text(&mut self) -> bool {
+        self.preedit_visible = true;
+        self.refresh()
+    }
+
+    pub fn hide_preedit_text(&mut self) -> bool {
+        self.preedit_visible = false;
+        self.refresh()
+    }
+
+    pub fn update_auxiliary_text(&mut self, text: Text<'static>, visible: bool) -> bool {
+        self.auxiliary = text;
+        self.auxiliary_visible = visible;
+        self.refresh()
+    }
+
+    pub fn show_auxiliary_text(&mut self) -> bool {
+        self.auxiliary_visible = true;
+        self.refresh()
+    }
+
+    pub fn hide_auxiliary_text(&mut self) -> bool {
+        self.auxiliary_visible = false;
+        self.refresh()
+    }
+
+    pub fn update_lookup_table(&mut self, table: LookupTable, visible: bool) -> bool {
+        self.table = table;
+        self.table_visible = visible;
+        self.refresh()
+    }
+
+    pub fn show_lookup_table(&mut self) -> bool {
+        self.table_visible = true;
+        self.refresh()
+    }
+
+    pub fn hide_lookup_table(&mut self) -> bool {
+        self.table_visible = false;
+        self.refresh()
+    }
+
+    /// Hides everything, e.g. when the input context loses focus
+    pub fn clear(&mut self) -> bool {
+        let changed = self.content.is_visible();
+        *self = CandidatePopupModel {
+            changed: self.changed || changed,
+            ..Self::default()
+        };
+        changed
+    }
+
+    /// Computes the content again, and returns true if it changed
+    fn refresh(&mut self) -> bool {
+        let preedit = (self.preedit_visible && !self.preedit.as_str().is_empty())
+            .then(|| (self.preedit.clone(), self.preedit_cursor));
+        let auxiliary = (self.auxiliary_visible && !self.auxiliary.as_str().is_empty())
+            .then(|| self.auxiliary.clone());
+        let candidates = if self.table_visible {
+            let start = self.table.current_page_start();
+            let cursor = self.table.cursor_pos_in_page();
+            let cursor_visible = self.table.cursor_visible;
+            self.table
+                .candidates_in_current_page()
+                .iter()
+                .zip(0..)
+                .map(|(text, i)| PopupCandidate {
+                    label: self
+                        .table
+                        .label_for(start + i)
+                        .map(|l| l.into_owned())
+                        .unwrap_or_default(),
+                    text: text.clone(),
+                    selected: cursor_visible && i == cursor,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        // The orientation of a hidden table doesn't change what's on screen
+        let orientation = if candidates.is_empty() {
+            Orientation::System
+        } else {
+            self.table.orientation
+        };
+        let content = PopupContent {
+            preedit,
+            auxiliary,
+            candidates,
+            orientation,
+        };
+        if content == self.content {
+            return false;
+        }
+        self.content = content;
+        self.changed = true;
+        true
+    }
+}
+
+/// Receives the candidates of an input context, so the application can
+/// draw them itself
+///
+/// Creating it adds `Capabilites::LOOKUP_TABLE` and
+/// `Capabilites::AUXILIARY_TEXT` to the capabilities of the input context, so
+/// the daemon sends the candidates to the application instead of the panel.
+/// The signals are received while calling `Bus::process`, check
+/// `take_changed` afterwards to know when to redraw.
+///
+/// ```no_run
+/// use ibus::{Bus, Capabilites, EmbeddedCandidates};
+/// use std::time::Duration;
+///
+/// let bus = Bus::new().unwrap();
+/// let ctx = bus.create_input_context("my-game").unwrap();
+/// let candidates = EmbeddedCandidates::new(&ctx, Capabilites::PREEDIT_TEXT | Capabilites::FOCUS).unwrap();
+/// loop {
+///     bus.process(Duration::from_millis(16)).unwrap();
+///     if candidates.take_changed() {
+///         let content = candidates.content();
+///         // Draw `content.candidates` and `content.auxiliary`
+///     }
+/// }
+/// ```
+pub struct EmbeddedCandidates {
+    ctx: InputContext,
+    model: Arc<Mutex<CandidatePopupModel>>,
+    tokens: Vec<Token>,
+}
+impl EmbeddedCandidates {
+    /// `caps` are the other capabilities of the application
+    pub fn new(ctx: &InputContext, caps: Capabilites) -> Result<Self, Error> {
+        let ctx = InputContext {
+            conn: ctx.conn.clone(),
+            obj_path: ctx.obj_path.clone(),
+            retry: ctx.retry,
+            watchdog: ctx.watchdog.clone(),
+        };
+        let model = Arc::new(Mutex::new(CandidatePopupModel::new()));
+        let mut candidates = EmbeddedCandidates {
+            ctx,
+            model,
+            tokens: Vec::new(),
+        };
+        candidates.subscribe()?;
+        candidates
+            .ctx
+            .set_capabilities(caps | Capabilites::LOOKUP_TABLE | Capabilites::AUXILIARY_TEXT);
+        Ok(candidates)
+    }
+
+    fn subscribe(&mut self) -> Result<(), Error> {
+        let model = &self.model;
+        let update = |f: fn(&mut CandidatePopupModel)| {
+            let model = model.clone();
+            move |_: &_, _: &_| {
+                f(&mut model.lock().unwrap());
+                AfterCallback::Keep
+            }
+        };
+        self.tokens = vec![
+            self.ctx.on_update_auxiliary_text({
+                let model = model.clone();
+                move |s, _, _| {
+                    model
+                        .lock()
+                        .unwrap()
+                        .update_auxiliary_text(s.text, s.visible);
+                    AfterCallback::Keep
+                }
+            })?,
+            self.ctx.on_show_auxiliary_text(update(|m| {
+                m.show_auxiliary_text();
+            }))?,
+            self.ctx.on_hide_auxiliary_text(update(|m| {
+                m.hide_auxiliary_text();
+            }))?,
+            self.ctx.on_update_lookup_table({
+                let model = model.clone();
+                move |s, _, _| {
+                    model
+                        .lock()
+                        .unwrap()
+                        .update_lookup_table(s.table, s.visible);
+                    AfterCallback::Keep
+                }
+            })?,
+            self.ctx.on_show_lookup_table(update(|m| {
+                m.show_lookup_table();
+            }))?,
+            self.ctx.on_hide_lookup_table(update(|m| {
+                m.hide_lookup_table();
+            }))?,
+        ];
+        Ok(())
+    }
+
+    /// What should be drawn right now
+    pub fn content(&self) -> PopupContent {
+        self.model.lock().unwrap().content().clone()
+    }
+
+    /// The last lookup table sent by the engine
+    pub fn lookup_table(&self) -> LookupTable {
+        self.model.lock().unwrap().lookup_table().clone()
+    }
+
+    /// Returns true if the content changed since the last call
+    pub fn take_changed(&self) -> bool {
+        self.model.lock().unwrap().take_changed()
+    }
+
+    /// Hides everything until the engine sends new candidates, e.g. after
+    /// the application called `InputContext::focus_out`
+    pub fn clear(&self) {
+        self.model.lock().unwrap().clear();
+    }
+
+    /// Tells the engine that the user clicked the candidate at `index` on the
+    /// current page, with the primary mouse button
+    pub fn candidate_clicked(&self, index: u32) -> Result<(), Error> {
+        self.ctx.candidate_clicked(index, 1, Modifiers::empty())
+    }
+
+    pub fn page_up(&self) -> Result<(), Error> {
+        self.ctx.page_up()
+    }
+
+    pub fn page_down(&self) -> Result<(), Error> {
+        self.ctx.page_down()
+    }
+
+    pub fn cursor_up(&self) -> Result<(), Error> {
+        self.ctx.cursor_up()
+    }
+
+    pub fn cursor_down(&self) -> Result<(), Error> {
+        self.ctx.cursor_down()
+    }
+}
+impl Drop for EmbeddedCandidates {
+    fn drop(&mut self) {
+        for token in self.tokens.drain(..) {
+            if self.ctx.conn.remove_match(token).is_err() {
+                warn!("Failed to stop receiving the candidates");
+            }
+        }
+    }
+}
+
+/// A rectangle in physical pixels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+impl Rect {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> i32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+}
+
+/// Where the content of a window is on the screen, for converting the caret
+/// of the window to the physical pixels of the screen
+///
+/// The positions of the window are physical already, like the ones of winit,
+/// and the screen can have monitors at negative positions, or with other
+/// scale factors, so only the caret is scaled.
+///
+/// ```
+/// use ibus::{Rect, WindowOrigin};
+///
+/// // A window on a monitor left of the main one, with a 30 pixel title bar
+/// let origin = WindowOrigin::new((-1800, 200), 1.5).with_content_offset((0, 30));
+/// assert_eq!(
+///     origin.caret_rect(100.0, 40.0, 1.0, 18.0),
+///     Rect::new(-1650, 290, 2, 27)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowOrigin {
+    /// The outer position of the window, with its frame, in physical pixels
+    pub outer_position: (i32, i32),
+    /// Where the content starts in the frame, in physical pixels, the inner
+    /// position minus the outer position
+    pub content_offset: (i32, i32),
+    pub scale_factor: f64,
+}
+impl WindowOrigin {
+    pub fn new(outer_position: (i32, i32), scale_factor: f64) -> Self {
+        WindowOrigin {
+            outer_position,
+            content_offset: (0, 0),
+            scale_factor,
+        }
+    }
+
+    pub fn with_content_offset(mut self, content_offset: (i32, i32)) -> Self {
+        self.content_offset = content_offset;
+        self
+    }
+
+    /// The rect for `InputContext::set_cursor_location` of a caret at `x` and
+    /// `y` of the content, in logical pixels
+    ///
+    /// The edges are rounded outwards, so a thin caret stays at least a pixel
+    /// wide.
+    pub fn caret_rect(&self, x: f64, y: f64, width: f64, height: f64) -> Rect {
+        let scale = |v: f64| v * self.scale_factor;
+        let left = scale(x).floor();
+        let top = scale(y).floor();
+        let right = scale(x + width.max(0.0)).ceil();
+        let bottom = scale(y + height.max(0.0)).ceil();
+        Rect::new(
+            self.outer_position.0 + self.content_offset.0 + left as i32,
+            self.outer_position.1 + self.content_offset.1 + top as i32,
+            (right - left) as i32,
+            (bottom - top) as i32,
+        )
+    }
+}
+
+/// The result of `place_popup`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// The top left corner of the popup
+    pub x: i32,
+    pub y: i32,
+    /// Whether the popup is above the caret instead of below it
+    pub above: bool,
+}
+
+/// Computes where to show a popup of the given size next to the caret
+///
+/// The popup is placed under the caret, aligned to its left edge. It's moved
+/// above the caret if there isn't enough room below it on the monitor, and
+/// moved horizontally to stay on the monitor. If it doesn't fit on either
+/// side, it goes to the side with more room, and is pushed onto the monitor
+/// (covering the caret).
+///
+/// ```
+/// use ibus::{place_popup, Rect};
+///
+/// let monitor = Rect::new(0, 0, 1920, 1080);
+/// let placement = place_popup(Rect::new(1900, 1060, 2, 16), monitor, (200, 120));
+/// assert_eq!((placement.x, placement.y, placement.above), (1720, 940, true));
+/// ```
+pub fn place_popup(caret: Rect, monitor: Rect, popup_size: (i32, i32)) -> Placement {
+    let (width, height) = popup_size;
+    let x = caret.x.min(monitor.right() - width).max(monitor.x);
+
+    let room_below = monitor.bottom() - caret.bottom();
+    let room_above = caret.y - monitor.y;
+    let above = room_below < height && room_above > room_below;
+    let y = if above {
+        (caret.y - height).max(monitor.y)
+    } else {
+        caret.bottom().min(monitor.bottom() - height).max(monitor.y)
+    };
+    Placement { x, y, above }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visibility_and_paging() {
+        let mut model = CandidatePopupModel::new();
+        assert!(!model.update_auxiliary_text("".into(), true));
+        assert!(!model.content().is_visible());
+
+        let mut table = LookupTable::new(2, 0, true, false);
+        for c in ["a", "b", "c"] {
+            table.append_candidate(c);
+        }
+        model.update_lookup_table(table.clone(), false);
+        assert!(!model.take_changed());
+        assert!(model.show_lookup_table());
+        assert!(model.take_changed());
+
+        table.page_down();
+        model.update_lookup_table(table, true);
+        let candidates = &model.content().candidates;
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].text.as_str(), "c");
+        assert_eq!(candidates[0].label, "1");
+        assert!(candidates[0].selected);
+
+        model.update_preedit_text("abc".into(), 3, true);
+        assert!(model.clear());
+        assert!(!model.content().is_visible());
+        assert!(model.take_changed());
+    }
+
+    #[test]
+    fn popup_placement() {
+        let monitor = Rect::new(1920, 0, 1280, 1024);
+        let caret = Rect::new(2000, 100, 2, 20);
+        let below = place_popup(caret, monitor, (300, 200));
+        assert_eq!((below.x, below.y, below.above), (2000, 120, false));
+        // Too wide for the space right of the caret
+        assert_eq!(place_popup(caret, monitor, (1300, 200)).x, 1920);
+        // Doesn't fit on either side, but there's more room below
+        let tall = place_popup(caret, monitor, (300, 1000));
+        assert_eq!((tall.y, tall.above), (24, false));
+    }
+}
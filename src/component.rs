@@ -0,0 +1,601 @@
+//! IBusComponent
+//!
+//! A component is a program that provides one or more engines. The daemon
+//! learns about the installed components from the XML files in the
+//! `ibus/component` data directories.
+//!
+
+use std::{
+    any::Any,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use log::{debug, warn};
+
+use dbus::arg::{Append, Arg, ArgType, Get, IterAppend, PropMap, RefArg, Variant};
+
+use crate::{EngineDesc, Error};
+
+const COMPONENT_NAME: &str = "IBusComponent";
+const COMPONENT_SIGNATURE: &str = "(sa{sv}ssssssssavav)";
+
+/// Describes a program that provides engines
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Component {
+    /// The D-Bus name that the program requests when it's started, e.g.
+    /// "org.freedesktop.IBus.MyEngine"
+    pub name: String,
+    pub description: String,
+    pub version: String,
+    pub license: String,
+    pub author: String,
+    pub homepage: String,
+
+    /// The command line that the daemon uses to start the program
+    pub exec: String,
+    pub textdomain: String,
+    pub engines: Vec<EngineDesc>,
+}
+
+impl Component {
+    /// Returns the contents of the component XML file that describes this
+    /// component
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        xml.push_str("<component>\n");
+        write_element(&mut xml, 1, "name", &self.name);
+        write_element(&mut xml, 1, "description", &self.description);
+        write_element(&mut xml, 1, "exec", &self.exec);
+        write_element(&mut xml, 1, "version", &self.version);
+        write_element(&mut xml, 1, "author", &self.author);
+        write_element(&mut xml, 1, "license", &self.license);
+        write_element(&mut xml, 1, "homepage", &self.homepage);
+        write_element(&mut xml, 1, "textdomain", &self.textdomain);
+        xml.push_str("  <engines>\n");
+        for engine in &self.engines {
+            write_engine(&mut xml, engine);
+        }
+        xml.push_str("  </engines>\n");
+        xml.push_str("</component>\n");
+        xml
+    }
+
+    /// Returns the path of the directory that holds the component files of
+    /// the current user, that is `$XDG_DATA_HOME/ibus/component`
+    pub fn user_component_dir() -> Result<PathBuf, Error> {
+        let data_home: PathBuf = if let Ok(data_home) = std::env::var("XDG_DATA_HOME") {
+            data_home.into()
+        } else if let Ok(home) = std::env::var("HOME") {
+            Path::new(&home).join(".local/share")
+        } else {
+            return Err(Error::Unknown {
+                description: "Could not find the home data folder".into(),
+            });
+        };
+        Ok(data_home.join("ibus/component"))
+    }
+
+    /// Writes the component XML into the component directory of the current
+    /// user (see `user_component_dir`), creating the directory if needed.
+    ///
+    /// Returns the path of the written file.
+    ///
+    /// Note that the daemon caches the component registry, so it may need to
+    /// be restarted (`ibus restart`) to pick up a newly installed component.
+    pub fn install_user(&self) -> Result<PathBuf, Error> {
+        let dir = Self::user_component_dir()?;
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.xml", self.name));
+        std::fs::write(&path, self.to_xml())?;
+        Ok(path)
+    }
+
+    /// Removes the file written by `install_user`. Returns false if it
+    /// didn't exist.
+    pub fn uninstall_user(&self) -> Result<bool, Error> {
+        let path = Self::user_component_dir()?.join(format!("{}.xml", self.name));
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Parses the contents of a component XML file
+    ///
+    /// Engine lists that are produced by running a command (the `exec`
+    /// attribute of the `<engines>` element) aren't supported; such lists are
+    /// skipped.
+    pub fn from_xml(xml: &str) -> Result<Component, Error> {
+        let doc = roxmltree::Document::parse(xml).map_err(|e| Error::Unknown {
+            description: format!("Failed to parse the component XML: {}", e),
+        })?;
+        let root = doc.root_element();
+        if !root.has_tag_name("component") {
+            return Err(Error::Unknown {
+                description: format!(
+                    "Expected a `component` root element, found `{}`",
+                    root.tag_name().name()
+                ),
+            });
+        }
+        let mut component = Component::default();
+        for child in root.children().filter(|n| n.is_element()) {
+            let text = || element_text(&child);
+            match child.tag_name().name() {
+                "name" => component.name = text(),
+                "description" => component.description = text(),
+                "version" => component.version = text(),
+                "license" => component.license = text(),
+                "author" => component.author = text(),
+                "homepage" => component.homepage = text(),
+                "exec" => component.exec = text(),
+                "textdomain" => component.textdomain = text(),
+                "engines" => {
+                    if child.has_attribute("exec") {
+                        debug!("Skipping an engine list that needs to be generated");
+                        continue;
+                    }
+                    for engine in child.children().filter(|n| n.has_tag_name("engine")) {
+                        component.engines.push(parse_engine(&engine));
+                    }
+                }
+                other => debug!("Ignoring unknown component element `{}`", other),
+            }
+        }
+        Ok(component)
+    }
+
+    /// Returns the path of the directory that holds the component files
+    /// installed system wide
+    pub fn system_component_dir() -> PathBuf {
+        PathBuf::from("/usr/share/ibus/component")
+    }
+}
+
+/// Returns every component that's installed on the system, without talking to
+/// the daemon.
+///
+/// Like the daemon, this reads the directories listed in the
+/// `IBUS_COMPONENT_PATH` environment variable when it's set, and otherwise the
+/// system and the user component directories. Files that can't be parsed are
+/// skipped.
+pub fn installed_components() -> Vec<Component> {
+    let dirs: Vec<PathBuf> = match std::env::var_os("IBUS_COMPONENT_PATH") {
+        Some(paths) => std::env::split_paths(&paths).collect(),
+        None => {
+            let mut dirs = vec![Component::system_component_dir()];
+            if let Ok(dir) = Component::user_component_dir() {
+                dirs.push(dir);
+            }
+            dirs
+        }
+    };
+    components_in(&dirs)
+}
+
+/// The components in `dirs`, in order, see `installed_components`
+fn components_in(dirs: &[PathBuf]) -> Vec<Component> {
+    let mut components = Vec::new();
+    for dir in dirs {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                debug!("Couldn't read the component directory {:?}: {}", dir, e);
+                continue;
+            }
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "xml"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(Error::from)
+                .and_then(|xml| Component::from_xml(&xml));
+            match parsed {
+                Ok(component) => components.push(component),
+                Err(e) => warn!("Skipping the component file {:?}: {}", path, e),
+            }
+        }
+    }
+    components
+}
+
+fn element_text(node: &roxmltree::Node) -> String {
+    node.text().unwrap_or_default().trim().to_owned()
+}
+
+fn parse_engine(node: &roxmltree::Node) -> EngineDesc {
+    let mut desc = EngineDesc::default();
+    for child in node.children().filter(|n| n.is_element()) {
+        let text = element_text(&child);
+        match child.tag_name().name() {
+            "name" => desc.name = text,
+            "longname" => desc.longname = text,
+            "description" => desc.description = text,
+            "language" => desc.language = text,
+            "license" => desc.license = text,
+            "author" => desc.author = text,
+            "icon" => desc.icon = text,
+            "layout" => desc.layout = text,
+            "layout_variant" => desc.layout_variant = text,
+            "layout_option" => desc.layout_option = text,
+            "rank" => {
+                desc.rank = text.parse().unwrap_or_else(|_| {
+                    debug!("Invalid engine rank `{}`", text);
+                    0
+                })
+            }
+            "hotkeys" => desc.hotkeys = text,
+            "symbol" => desc.symbol = text,
+            "setup" => desc.setup = text,
+            "version" => desc.version = text,
+            "textdomain" => desc.textdomain = text,
+            "icon_prop_key" => desc.icon_prop_key = text,
+            other => debug!("Ignoring unknown engine element `{}`", other),
+        }
+    }
+    desc
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn write_element(xml: &mut String, depth: usize, name: &str, value: &str) {
+    if value.is_empty() {
+        return;
+    }
+    let _ = writeln!(
+        xml,
+        "{:indent$}<{name}>{}</{name}>",
+        "",
+        escape(value),
+        indent = depth * 2,
+        name = name
+    );
+}
+
+fn write_engine(xml: &mut String, engine: &EngineDesc) {
+    xml.push_str("    <engine>\n");
+    write_element(xml, 3, "name", &engine.name);
+    write_element(xml, 3, "longname", &engine.longname);
+    write_element(xml, 3, "description", &engine.description);
+    write_element(xml, 3, "language", &engine.language);
+    write_element(xml, 3, "license", &engine.license);
+    write_element(xml, 3, "author", &engine.author);
+    write_element(xml, 3, "icon", &engine.icon);
+    write_element(xml, 3, "layout", &engine.layout);
+    write_element(xml, 3, "layout_variant", &engine.layout_variant);
+    write_element(xml, 3, "layout_option", &engine.layout_option);
+    write_element(xml, 3, "rank", &engine.rank.to_string());
+    write_element(xml, 3, "hotkeys", &engine.hotkeys);
+    write_element(xml, 3, "symbol", &engine.symbol);
+    write_element(xml, 3, "setup", &engine.setup);
+    write_element(xml, 3, "version", &engine.version);
+    write_element(xml, 3, "textdomain", &engine.textdomain);
+    write_element(xml, 3, "icon_prop_key", &engine.icon_prop_key);
+    xml.push_str("    </engine>\n");
+}
+
+/// Helps declaring a component
+///
+/// ```no_run
+/// let engine = ibus::EngineDescBuilder::new("my-engine").build();
+/// let component = ibus::ComponentBuilder::new("org.freedesktop.IBus.MyEngine")
+///     .description("My input method")
+///     .engine(engine)
+///     .build();
+/// component.install_user().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComponentBuilder {
+    component: Component,
+}
+impl ComponentBuilder {
+    /// Creates a builder for a component with the given bus name.
+    ///
+    /// The `exec` command defaults to the path of the current executable.
+    pub fn new(name: impl Into<String>) -> Self {
+        let exec = match std::env::current_exe() {
+            Ok(path) => path.to_string_lossy().into_owned(),
+            Err(e) => {
+                debug!("Couldn't get the path of the current executable: {}", e);
+                String::new()
+            }
+        };
+        ComponentBuilder {
+            component: Component {
+                name: name.into(),
+                exec,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.component.description = description.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.component.version = version.into();
+        self
+    }
+
+    pub fn license(mut self, license: impl Into<String>) -> Self {
+        self.component.license = license.into();
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.component.author = author.into();
+        self
+    }
+
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.component.homepage = homepage.into();
+        self
+    }
+
+    pub fn exec(mut self, exec: impl Into<String>) -> Self {
+        self.component.exec = exec.into();
+        self
+    }
+
+    pub fn textdomain(mut self, textdomain: impl Into<String>) -> Self {
+        self.component.textdomain = textdomain.into();
+        self
+    }
+
+    pub fn engine(mut self, engine: EngineDesc) -> Self {
+        self.component.engines.push(engine);
+        self
+    }
+
+    pub fn build(self) -> Component {
+        self.component
+    }
+}
+
+impl RefArg for Component {
+    fn arg_type(&self) -> ArgType {
+        ArgType::Variant
+    }
+
+    fn signature(&self) -> dbus::Signature<'static> {
+        <Self as Arg>::signature()
+    }
+
+    fn append(&self, i: &mut IterAppend) {
+        i.append_variant(&dbus::Signature::from(COMPONENT_SIGNATURE), |i| {
+            i.append_struct(|i| {
+                i.append(COMPONENT_NAME);
+                i.append(PropMap::new());
+                i.append(self.name.as_str());
+                i.append(self.description.as_str());
+                i.append(self.version.as_str());
+                i.append(self.license.as_str());
+                i.append(self.author.as_str());
+                i.append(self.homepage.as_str());
+                i.append(self.exec.as_str());
+                i.append(self.textdomain.as_str());
+                // Observed paths aren't supported
+                i.append(Vec::<Variant<u32>>::new());
+                i.append(self.engines.clone());
+            })
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn box_clone(&self) -> Box<dyn RefArg + 'static> {
+        Box::new(self.clone())
+    }
+}
+impl Arg for Component {
+    const ARG_TYPE: ArgType = ArgType::Variant;
+
+    fn signature() -> dbus::Signature<'static> {
+        dbus::Signature::from("v\u{0}")
+    }
+}
+impl Append for Component {
+    fn append_by_ref(&self, i: &mut IterAppend) {
+        <Self as RefArg>::append(self, i);
+    }
+}
+impl<'a> Get<'a> for Component {
+    fn get(i: &mut dbus::arg::Iter<'a>) -> Option<Self> {
+        let mut variant = i.recurse(ArgType::Variant)?;
+        let mut s = variant.recurse(ArgType::Struct)?;
+        let struct_name: &str = s.read().ok()?;
+        if struct_name != COMPONENT_NAME {
+            debug!("Component didn't have the expected name.");
+            return None;
+        }
+        let _: PropMap = s.read().ok()?;
+        let mut component = Component {
+            name: s.read().ok()?,
+            description: s.read().ok()?,
+            version: s.read().ok()?,
+            license: s.read().ok()?,
+            author: s.read().ok()?,
+            homepage: s.read().ok()?,
+            exec: s.read().ok()?,
+            textdomain: s.read().ok()?,
+            engines: Vec::new(),
+        };
+        // Skip the observed paths
+        s.next();
+        component.engines = s.read().ok()?;
+        Some(component)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EngineDescBuilder;
+
+    #[test]
+    fn xml_and_serialization() {
+        let component = ComponentBuilder::new("org.freedesktop.IBus.Test")
+            .description("Tests & <stuff>")
+            .exec("/usr/bin/test --ibus")
+            .engine(EngineDescBuilder::new("test").rank(3).build())
+            .build();
+        let xml = component.to_xml();
+        assert!(xml.contains("<description>Tests &amp; &lt;stuff&gt;</description>"));
+        assert!(xml.contains("      <rank>3</rank>\n"));
+
+        let msg = dbus::Message::new_method_call("a.b", "/a/b", "a.b", "C")
+            .unwrap()
+            .append1(component.clone());
+        let read: Component = msg.read1().unwrap();
+        assert_eq!(read, component);
+
+        assert_eq!(Component::from_xml(&xml).unwrap(), component);
+    }
+
+    /// The component file of the XKB engines that IBus ships, shortened
+    const SIMPLE_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- filename: simple.xml -->
+<component>
+	<name>org.freedesktop.IBus.Simple</name>
+	<description>A table based simple engine</description>
+	<exec>/usr/libexec/ibus-engine-simple</exec>
+	<version>1.5.29</version>
+	<author>Peng Huang &lt;shawn.p.huang@gmail.com&gt;</author>
+	<license>GPL</license>
+	<homepage>https://github.com/ibus/ibus/wiki</homepage>
+	<textdomain>ibus10</textdomain>
+	<engines>
+		<engine>
+			<name>xkb:us::eng</name>
+			<language>en</language>
+			<license>GPL</license>
+			<author>Peng Huang &lt;shawn.p.huang@gmail.com&gt;</author>
+			<layout>us</layout>
+			<longname>English (US)</longname>
+			<description>English (US)</description>
+			<icon>ibus-keyboard</icon>
+			<rank>99</rank>
+		</engine>
+		<engine>
+			<name>xkb:us:intl:eng</name>
+			<language>en</language>
+			<license>GPL</license>
+			<author>Peng Huang &lt;shawn.p.huang@gmail.com&gt;</author>
+			<layout>us</layout>
+			<layout_variant>intl</layout_variant>
+			<longname>English (US, intl., with dead keys)</longname>
+			<description>English (US, intl., with dead keys)</description>
+			<icon>ibus-keyboard</icon>
+			<rank>1</rank>
+		</engine>
+		<engine>
+			<name>xkb:de::ger</name>
+			<language>de</language>
+			<license>GPL</license>
+			<author>Peng Huang &lt;shawn.p.huang@gmail.com&gt;</author>
+			<layout>de</layout>
+			<longname>German</longname>
+			<description>German</description>
+			<icon>ibus-keyboard</icon>
+			<rank>1</rank>
+		</engine>
+	</engines>
+</component>
+"#;
+
+    /// A component like the one of ibus-m17n, which lists its engines by
+    /// running a command
+    const M17N_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<component>
+	<name>org.freedesktop.IBus.M17n</name>
+	<description>M17N Component</description>
+	<exec>/usr/libexec/ibus-engine-m17n --ibus</exec>
+	<version>1.4.19</version>
+	<author>Daiki Ueno &lt;ueno@unixuser.org&gt;</author>
+	<license>GPL</license>
+	<homepage>https://github.com/ibus/ibus-m17n</homepage>
+	<textdomain>ibus-m17n</textdomain>
+	<engines exec="/usr/libexec/ibus-engine-m17n --xml"/>
+</component>
+"#;
+
+    #[test]
+    fn ibus_component_files() {
+        let simple = Component::from_xml(SIMPLE_XML).unwrap();
+        assert_eq!(simple.name, "org.freedesktop.IBus.Simple");
+        assert_eq!(simple.exec, "/usr/libexec/ibus-engine-simple");
+        assert_eq!(simple.author, "Peng Huang <shawn.p.huang@gmail.com>");
+        assert_eq!(simple.textdomain, "ibus10");
+        let names: Vec<&str> = simple.engines.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["xkb:us::eng", "xkb:us:intl:eng", "xkb:de::ger"]);
+        let intl = &simple.engines[1];
+        assert_eq!(intl.layout, "us");
+        assert_eq!(intl.layout_variant, "intl");
+        assert_eq!(intl.longname, "English (US, intl., with dead keys)");
+        assert_eq!(simple.engines[0].rank, 99);
+
+        let m17n = Component::from_xml(M17N_XML).unwrap();
+        assert_eq!(m17n.name, "org.freedesktop.IBus.M17n");
+        assert_eq!(m17n.exec, "/usr/libexec/ibus-engine-m17n --ibus");
+        assert!(m17n.engines.is_empty());
+
+        assert!(Component::from_xml("<engines/>").is_err());
+        assert!(Component::from_xml("<component><name>x</component>").is_err());
+    }
+
+    #[test]
+    fn components_in_directories() {
+        let dir = std::env::temp_dir().join(format!("ibus-rs-components-{}", std::process::id()));
+        let (first, second) = (dir.join("first"), dir.join("second"));
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+        std::fs::write(first.join("simple.xml"), SIMPLE_XML).unwrap();
+        std::fs::write(first.join("broken.xml"), "<component><name>").unwrap();
+        std::fs::write(first.join("notes.txt"), "not a component").unwrap();
+        std::fs::write(second.join("m17n.xml"), M17N_XML).unwrap();
+        std::fs::write(second.join("panel.xml"), "<panel/>").unwrap();
+
+        let components = components_in(&[first, dir.join("missing"), second]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<&str> = components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["org.freedesktop.IBus.Simple", "org.freedesktop.IBus.M17n"]
+        );
+        assert_eq!(components[0].engines.len(), 3);
+    }
+}
@@ -0,0 +1,193 @@
+//! The IBus configuration service
+//!
+//! The daemon keeps its settings, and those of the engines, in the service
+//! at `org.freedesktop.IBus.Config`. Values are identified by a section and
+//! a name (e.g. `panel` and `lookup-table-orientation`), and engines usually
+//! use the `engine/<name>` section.
+//!
+
+use std::{collections::HashMap, rc::Rc};
+
+use dbus::{
+    arg::{Append, Arg, Get, PropMap, RefArg, Variant},
+    blocking::{Connection, Proxy},
+    channel::Token,
+    message::{MatchRule, SignalArgs},
+    Message,
+};
+
+use crate::{AfterCallback, Bus, Error, REQ_TIMEOUT};
+
+mod schema;
+mod service;
+#[cfg(feature = "serde")]
+mod typed;
+pub use schema::*;
+pub use service::*;
+#[cfg(feature = "serde")]
+pub use typed::*;
+
+pub(crate) const CONFIG_NAME: &str = "org.freedesktop.IBus.Config";
+pub(crate) const CONFIG_INTERFACE: &str = "org.freedesktop.IBus.Config";
+pub(crate) const CONFIG_PATH: &str = "/org/freedesktop/IBus/Config";
+
+/// Emitted by the configuration service when a value changes
+#[derive(Debug)]
+pub struct ValueChangedSignal {
+    pub section: String,
+    pub name: String,
+    /// `None` if the value was unset
+    pub value: Option<Box<dyn RefArg>>,
+}
+impl dbus::arg::ReadAll for ValueChangedSignal {
+    fn read(i: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        let section = i.read()?;
+        let name = i.read()?;
+        let value: Variant<Box<dyn RefArg>> = i.read()?;
+        let value = Some(value.0).filter(|value| !is_unset_value(&**value));
+        Ok(ValueChangedSignal {
+            section,
+            name,
+            value,
+        })
+    }
+}
+impl SignalArgs for ValueChangedSignal {
+    const NAME: &'static str = "ValueChanged";
+    const INTERFACE: &'static str = CONFIG_INTERFACE;
+}
+
+/// A client of the configuration service
+///
+/// ```no_run
+/// use ibus::{Bus, Config};
+///
+/// let bus = Bus::new().unwrap();
+/// let config = Config::new(&bus);
+/// let orientation: i32 = config.get_value("panel", "lookup-table-orientation").unwrap();
+/// config.set_value("panel", "lookup-table-orientation", 1 - orientation).unwrap();
+/// ```
+pub struct Config {
+    conn: Rc<Connection>,
+}
+impl Config {
+    pub fn new(bus: &Bus) -> Self {
+        Config {
+            conn: bus.conn.clone(),
+        }
+    }
+
+    /// Returns the value, converted to `T`
+    ///
+    /// Fails if the value isn't set, or if it has a different type. Use
+    /// `Box<dyn RefArg>` as `T` to get the value with whatever type it has.
+    pub fn get_value<T>(&self, section: &str, name: &str) -> Result<T, Error>
+    where
+        T: for<'a> Get<'a>,
+    {
+        let (value,): (Variant<T>,) = self
+            .proxy()
+            .method_call(CONFIG_INTERFACE, "GetValue", (section, name))
+            .map_err(|e| config_error(e, "GetValue"))?;
+        Ok(value.0)
+    }
+
+    /// Returns all of the values that are set in the section, e.g. to fill
+    /// in the setup dialog of an engine
+    pub fn get_values(&self, section: &str) -> Result<HashMap<String, Box<dyn RefArg>>, Error> {
+        get_values(&self.conn, section)
+    }
+
+    pub fn set_value<T>(&self, section: &str, name: &str, value: T) -> Result<(), Error>
+    where
+        T: Arg + Append,
+    {
+        let () = self
+            .proxy()
+            .method_call(
+                CONFIG_INTERFACE,
+                "SetValue",
+                (section, name, Variant(value)),
+            )
+            .map_err(|e| config_error(e, "SetValue"))?;
+        Ok(())
+    }
+
+    /// Removes the value, so the default is used again
+    pub fn unset_value(&self, section: &str, name: &str) -> Result<(), Error> {
+        let () = self
+            .proxy()
+            .method_call(CONFIG_INTERFACE, "UnsetValue", (section, name))
+            .map_err(|e| config_error(e, "UnsetValue"))?;
+        Ok(())
+    }
+
+    /// Calls `callback` when a value changes, in any section, e.g. because
+    /// the user edited it in `ibus-setup` or with `dconf`
+    ///
+    /// The callback is called while calling `Bus::process`.
+    pub fn on_value_changed<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(ValueChangedSignal, &Connection, &Message) -> AfterCallback + Send + 'static,
+    {
+        // The rule doesn't include the sender, because the service may be
+        // restarted with a new unique name
+        let rule = MatchRule::new_signal(CONFIG_INTERFACE, ValueChangedSignal::NAME);
+        let token = self
+            .conn
+            .add_match(rule, move |signal: ValueChangedSignal, conn, msg| {
+                callback(signal, conn, msg).to_bool()
+            })?;
+        Ok(token)
+    }
+
+    fn proxy(&self) -> Proxy<'_, &Connection> {
+        self.conn.with_proxy(CONFIG_NAME, CONFIG_PATH, REQ_TIMEOUT)
+    }
+}
+
+fn config_error(e: dbus::Error, member: &str) -> Error {
+    Error::from(e).in_call(CONFIG_PATH, CONFIG_INTERFACE, member)
+}
+
+pub(crate) fn get_values(
+    conn: &Connection,
+    section: &str,
+) -> Result<HashMap<String, Box<dyn RefArg>>, Error> {
+    let config = conn.with_proxy(CONFIG_NAME, CONFIG_PATH, REQ_TIMEOUT);
+    let (values,): (PropMap,) = config
+        .method_call(CONFIG_INTERFACE, "GetValues", (section,))
+        .map_err(|e| config_error(e, "GetValues"))?;
+    Ok(values
+        .into_iter()
+        .map(|(name, value)| (name, value.0))
+        .collect())
+}
+
+/// Returns true if `value` is what the config service sends for a value that
+/// was unset
+pub(crate) fn is_unset_value(value: &dyn RefArg) -> bool {
+    match &*value.signature() {
+        "" | "()" => true,
+        // Sent by `ConfigService`, which can't send the empty tuple
+        "av" => value
+            .as_iter()
+            .is_some_and(|mut items| items.next().is_none()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_changed_signal() {
+        let msg = Message::new_signal(CONFIG_PATH, CONFIG_INTERFACE, "ValueChanged")
+            .unwrap()
+            .append3("engine/test", "speed", Variant(3i32));
+        let changed: ValueChangedSignal = msg.read_all().unwrap();
+        assert_eq!(changed.section, "engine/test");
+        assert_eq!(changed.value.unwrap().as_i64(), Some(3));
+    }
+}
@@ -0,0 +1,458 @@
+use std::collections::{HashMap, HashSet};
+
+use log::{debug, warn};
+
+use dbus::arg::RefArg;
+
+use super::Config;
+use crate::Error;
+
+/// The name of the value where `Schema` stores the version of the section
+pub const SCHEMA_VERSION_KEY: &str = "schema-version";
+
+/// The types of the values declared in a `Schema`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingType {
+    Bool,
+    Int,
+    Double,
+    String,
+    Strings,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingValue {
+    Bool(bool),
+    Int(i32),
+    Double(f64),
+    String(String),
+    Strings(Vec<String>),
+}
+impl SettingValue {
+    pub fn setting_type(&self) -> SettingType {
+        match self {
+            SettingValue::Bool(_) => SettingType::Bool,
+            SettingValue::Int(_) => SettingType::Int,
+            SettingValue::Double(_) => SettingType::Double,
+            SettingValue::String(_) => SettingType::String,
+            SettingValue::Strings(_) => SettingType::Strings,
+        }
+    }
+
+    /// Converts a value of the config service. Returns `None` for types that
+    /// settings can't have.
+    pub fn from_ref_arg(value: &dyn RefArg) -> Option<Self> {
+        match &*value.signature() {
+            "b" => value.as_u64().map(|b| SettingValue::Bool(b != 0)),
+            "y" | "n" | "q" | "i" | "u" | "x" | "t" => value
+                .as_i64()
+                .and_then(|i| i32::try_from(i).ok())
+                .map(SettingValue::Int),
+            "d" => value.as_f64().map(SettingValue::Double),
+            "s" => value.as_str().map(|s| SettingValue::String(s.to_owned())),
+            "as" => value
+                .as_iter()?
+                .map(|s| s.as_str().map(str::to_owned))
+                .collect::<Option<_>>()
+                .map(SettingValue::Strings),
+            _ => None,
+        }
+    }
+
+    /// Converts the value to `ty` if that doesn't lose information, e.g. an
+    /// integer to a double
+    fn convert(self, ty: SettingType) -> Option<Self> {
+        match (self, ty) {
+            (value, ty) if value.setting_type() == ty => Some(value),
+            (SettingValue::Int(i), SettingType::Double) => Some(SettingValue::Double(i.into())),
+            (SettingValue::String(s), SettingType::Strings) => Some(SettingValue::Strings(vec![s])),
+            _ => None,
+        }
+    }
+
+    fn save(&self, config: &Config, section: &str, name: &str) -> Result<(), Error> {
+        match self {
+            SettingValue::Bool(b) => config.set_value(section, name, *b),
+            SettingValue::Int(i) => config.set_value(section, name, *i),
+            SettingValue::Double(d) => config.set_value(section, name, *d),
+            SettingValue::String(s) => config.set_value(section, name, s.as_str()),
+            SettingValue::Strings(s) => config.set_value(section, name, s.clone()),
+        }
+    }
+}
+impl From<bool> for SettingValue {
+    fn from(b: bool) -> Self {
+        SettingValue::Bool(b)
+    }
+}
+impl From<i32> for SettingValue {
+    fn from(i: i32) -> Self {
+        SettingValue::Int(i)
+    }
+}
+impl From<f64> for SettingValue {
+    fn from(d: f64) -> Self {
+        SettingValue::Double(d)
+    }
+}
+impl From<&str> for SettingValue {
+    fn from(s: &str) -> Self {
+        SettingValue::String(s.to_owned())
+    }
+}
+impl From<String> for SettingValue {
+    fn from(s: String) -> Self {
+        SettingValue::String(s)
+    }
+}
+impl From<Vec<String>> for SettingValue {
+    fn from(s: Vec<String>) -> Self {
+        SettingValue::Strings(s)
+    }
+}
+
+type Check = Box<dyn Fn(&SettingValue) -> bool + Send + Sync>;
+type Migration = Box<dyn Fn(&mut SchemaValues) + Send + Sync>;
+
+struct Key {
+    name: String,
+    default: SettingValue,
+    check: Option<Check>,
+}
+
+/// The values of a section, as read by `Schema::load`
+///
+/// Every key of the schema has a value: the stored one if it was valid, and
+/// the default otherwise.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaValues {
+    version: u32,
+    values: HashMap<String, SettingValue>,
+    /// Values that a migration removed, which `Schema::save` unsets
+    removed: HashSet<String>,
+}
+impl SchemaValues {
+    /// The version that the values were stored with. During a migration this
+    /// is the version the values are being migrated from.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SettingValue> {
+        self.values.get(name)
+    }
+
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.get(name)? {
+            SettingValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn get_int(&self, name: &str) -> Option<i32> {
+        match self.get(name)? {
+            SettingValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn get_double(&self, name: &str) -> Option<f64> {
+        match self.get(name)? {
+            SettingValue::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        match self.get(name)? {
+            SettingValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn get_strings(&self, name: &str) -> Option<&[String]> {
+        match self.get(name)? {
+            SettingValue::Strings(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Sets the value. It's validated by `Schema::save`.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<SettingValue>) {
+        let name = name.into();
+        self.removed.remove(&name);
+        self.values.insert(name, value.into());
+    }
+
+    /// Removes the value, and returns it. Meant for migrations: the value is
+    /// unset when the settings are saved.
+    pub fn remove(&mut self, name: &str) -> Option<SettingValue> {
+        let value = self.values.remove(name)?;
+        self.removed.insert(name.to_owned());
+        Some(value)
+    }
+
+    /// Moves a value to a new name, for migrations
+    pub fn rename(&mut self, from: &str, to: impl Into<String>) {
+        if let Some(value) = self.remove(from) {
+            self.set(to, value);
+        }
+    }
+}
+
+/// The settings of an engine: the keys of a config section, with their
+/// types and defaults
+///
+/// `load` reads the section and replaces the values that are missing, have
+/// the wrong type, or fail their check with the defaults. The schema has a
+/// version, which is stored in the section as `schema-version`. When the
+/// stored version is older, the migrations up to the current version run on
+/// the stored values, and the result is written back.
+///
+/// ```no_run
+/// use ibus::{Bus, Config, Schema, SettingValue};
+///
+/// let schema = Schema::new("engine/my-engine", 2)
+///     .key_checked("page-size", 5, |v| matches!(v, SettingValue::Int(1..=10)))
+///     .key("auto-commit", false)
+///     // Version 1 called it `candidates-per-page`
+///     .migration(2, |settings| settings.rename("candidates-per-page", "page-size"));
+///
+/// let bus = Bus::new().unwrap();
+/// let config = Config::new(&bus);
+/// let settings = schema.load(&config).unwrap();
+/// let page_size = settings.get_int("page-size").unwrap();
+/// ```
+pub struct Schema {
+    section: String,
+    version: u32,
+    keys: Vec<Key>,
+    /// Sorted by the version
+    migrations: Vec<(u32, Migration)>,
+}
+impl Schema {
+    pub fn new(section: impl Into<String>, version: u32) -> Self {
+        Schema {
+            section: section.into(),
+            version,
+            keys: Vec::new(),
+            migrations: Vec::new(),
+        }
+    }
+
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Declares a key. Its type is the type of the default.
+    pub fn key(self, name: impl Into<String>, default: impl Into<SettingValue>) -> Self {
+        self.add_key(name.into(), default.into(), None)
+    }
+
+    /// Declares a key with a check for its values, e.g. for a range
+    pub fn key_checked<F>(
+        self,
+        name: impl Into<String>,
+        default: impl Into<SettingValue>,
+        check: F,
+    ) -> Self
+    where
+        F: Fn(&SettingValue) -> bool + Send + Sync + 'static,
+    {
+        self.add_key(name.into(), default.into(), Some(Box::new(check)))
+    }
+
+    fn add_key(mut self, name: String, default: SettingValue, check: Option<Check>) -> Self {
+        if check.as_ref().is_some_and(|check| !check(&default)) {
+            warn!("The default of {} fails its own check", name);
+        }
+        self.keys.retain(|key| key.name != name);
+        self.keys.push(Key {
+            name,
+            default,
+            check,
+        });
+        self
+    }
+
+    /// Adds a migration, which upgrades the values stored by `version - 1`
+    /// (or older, if there's no migration for that version) to `version`
+    ///
+    /// The migration gets every value that's set in the section, including
+    /// ones that aren't keys of the schema anymore.
+    pub fn migration<F>(mut self, version: u32, migrate: F) -> Self
+    where
+        F: Fn(&mut SchemaValues) + Send + Sync + 'static,
+    {
+        let index = self.migrations.partition_point(|(v, _)| *v <= version);
+        self.migrations.insert(index, (version, Box::new(migrate)));
+        self
+    }
+
+    /// The settings with the default of every key
+    pub fn defaults(&self) -> SchemaValues {
+        SchemaValues {
+            version: self.version,
+            values: self
+                .keys
+                .iter()
+                .map(|key| (key.name.clone(), key.default.clone()))
+                .collect(),
+            removed: HashSet::new(),
+        }
+    }
+
+    /// Whether `value` is valid for the key `name`
+    pub fn is_valid(&self, name: &str, value: &SettingValue) -> bool {
+        match self.keys.iter().find(|key| key.name == name) {
+            Some(key) => {
+                value.setting_type() == key.default.setting_type()
+                    && key.check.as_ref().is_none_or(|check| check(value))
+            }
+            None => false,
+        }
+    }
+
+    /// Reads the section, see the documentation of `Schema`
+    pub fn load(&self, config: &Config) -> Result<SchemaValues, Error> {
+        let (settings, migrated) = self.apply(&config.get_values(&self.section)?);
+        if migrated {
+            self.save(config, &settings)?;
+        }
+        Ok(settings)
+    }
+
+    /// Validates and writes every key, and the version of the schema
+    pub fn save(&self, config: &Config, settings: &SchemaValues) -> Result<(), Error> {
+        for key in &self.keys {
+            if let Some(value) = settings.get(&key.name) {
+                if !self.is_valid(&key.name, value) {
+                    return Err(Error::Unknown {
+                        description: format!("Invalid value for {}: {:?}", key.name, value),
+                    });
+                }
+            }
+        }
+        for name in &settings.removed {
+            config.unset_value(&self.section, name)?;
+        }
+        for key in &self.keys {
+            if let Some(value) = settings.get(&key.name) {
+                value.save(config, &self.section, &key.name)?;
+            }
+        }
+        config.set_value(&self.section, SCHEMA_VERSION_KEY, self.version as i32)
+    }
+
+    /// Turns the stored values into settings. Also returns whether they were
+    /// migrated.
+    fn apply(&self, stored: &HashMap<String, Box<dyn RefArg>>) -> (SchemaValues, bool) {
+        let mut settings = SchemaValues::default();
+        for (name, value) in stored {
+            if name == SCHEMA_VERSION_KEY {
+                continue;
+            }
+            match SettingValue::from_ref_arg(&**value) {
+                Some(value) => settings.set(name.clone(), value),
+                None => debug!(
+                    "Ignoring {} with the unsupported type {}",
+                    name,
+                    value.signature()
+                ),
+            }
+        }
+        // Sections without a version were written before the schema was used
+        settings.version = stored
+            .get(SCHEMA_VERSION_KEY)
+            .and_then(|v| v.as_i64())
+            .and_then(|v| u32::try_from(v).ok())
+            .unwrap_or(0);
+
+        let mut migrated = false;
+        if !stored.is_empty() && settings.version < self.version {
+            for (version, migrate) in &self.migrations {
+                if *version > settings.version && *version <= self.version {
+                    debug!("Migrating {} to version {}", self.section, version);
+                    migrate(&mut settings);
+                    settings.version = *version;
+                }
+            }
+            migrated = true;
+        }
+        settings.version = self.version;
+
+        let mut values = HashMap::new();
+        for key in &self.keys {
+            let value = settings
+                .values
+                .remove(&key.name)
+                .and_then(|value| value.convert(key.default.setting_type()))
+                .filter(|value| self.is_valid(&key.name, value));
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    if stored.contains_key(&key.name) {
+                        warn!("Invalid value for {}, using the default", key.name);
+                    }
+                    key.default.clone()
+                }
+            };
+            values.insert(key.name.clone(), value);
+        }
+        for name in settings.values.keys() {
+            debug!("Ignoring {}, which isn't in the schema", name);
+        }
+        settings.values = values;
+        (settings, migrated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_validation_and_migration() {
+        let schema = Schema::new("engine/test", 2)
+            .key_checked("page-size", 5, |v| matches!(v, SettingValue::Int(1..=10)))
+            .key("scale", 1.0)
+            .key("layouts", vec!["us".to_owned()])
+            .migration(2, |settings| {
+                settings.rename("candidates-per-page", "page-size")
+            })
+            .migration(1, |settings| {
+                settings.remove("obsolete");
+            });
+
+        let (settings, migrated) = schema.apply(&HashMap::new());
+        assert!(!migrated);
+        assert_eq!(settings, schema.defaults());
+
+        let mut stored: HashMap<String, Box<dyn RefArg>> = HashMap::new();
+        stored.insert("candidates-per-page".into(), Box::new(7i32));
+        stored.insert("obsolete".into(), Box::new(true));
+        stored.insert("scale".into(), Box::new(2i32));
+        stored.insert("layouts".into(), Box::new("de".to_owned()));
+        let (settings, migrated) = schema.apply(&stored);
+        assert!(migrated);
+        assert_eq!(settings.version(), 2);
+        assert_eq!(settings.get_int("page-size"), Some(7));
+        assert_eq!(settings.get_double("scale"), Some(2.0));
+        assert_eq!(
+            settings.get_strings("layouts"),
+            Some(&["de".to_owned()][..])
+        );
+        assert!(settings.removed.contains("obsolete"));
+
+        stored.clear();
+        stored.insert(SCHEMA_VERSION_KEY.into(), Box::new(2i32));
+        stored.insert("page-size".into(), Box::new(42i32));
+        let (settings, migrated) = schema.apply(&stored);
+        assert!(!migrated);
+        assert_eq!(settings.get_int("page-size"), Some(5));
+    }
+}
@@ -0,0 +1,268 @@
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use log::warn;
+
+use dbus::{
+    arg::{PropMap, RefArg, Variant},
+    blocking::Connection,
+    channel::{MatchingReceiver, Sender, Token},
+    message::MatchRule,
+    Message,
+};
+
+use super::{CONFIG_INTERFACE, CONFIG_NAME, CONFIG_PATH};
+use crate::{
+    engine::{error_reply, invalid_args, SERVICE_INTERFACE},
+    Bus, Error,
+};
+
+/// Where a `ConfigService` keeps the values
+pub trait ConfigStore: Send {
+    fn get(&self, section: &str, name: &str) -> Option<Box<dyn RefArg>>;
+
+    /// Returns the values that are set in the section
+    fn get_section(&self, section: &str) -> HashMap<String, Box<dyn RefArg>>;
+
+    fn set(&mut self, section: &str, name: &str, value: Box<dyn RefArg>) -> Result<(), Error>;
+
+    /// Removes the value. Returns the value that's in effect afterwards, if
+    /// the store has defaults.
+    fn unset(&mut self, section: &str, name: &str) -> Result<Option<Box<dyn RefArg>>, Error>;
+}
+
+/// Keeps the values in memory, without defaults
+///
+/// This is also a starting point for stores that save the values to a file:
+/// load the file into a `MemoryConfigStore`, and write `values` back after
+/// every change.
+#[derive(Debug, Default)]
+pub struct MemoryConfigStore {
+    /// The values by section and name
+    pub values: HashMap<String, HashMap<String, Box<dyn RefArg>>>,
+}
+impl MemoryConfigStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl ConfigStore for MemoryConfigStore {
+    fn get(&self, section: &str, name: &str) -> Option<Box<dyn RefArg>> {
+        Some(self.values.get(section)?.get(name)?.box_clone())
+    }
+
+    fn get_section(&self, section: &str) -> HashMap<String, Box<dyn RefArg>> {
+        self.values
+            .get(section)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.box_clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn set(&mut self, section: &str, name: &str, value: Box<dyn RefArg>) -> Result<(), Error> {
+        self.values
+            .entry(section.to_owned())
+            .or_default()
+            .insert(name.to_owned(), value);
+        Ok(())
+    }
+
+    fn unset(&mut self, section: &str, name: &str) -> Result<Option<Box<dyn RefArg>>, Error> {
+        if let Some(values) = self.values.get_mut(section) {
+            values.remove(name);
+        }
+        Ok(None)
+    }
+}
+
+/// Handles a method call of the config interface. Returns the reply, and the
+/// `ValueChanged` signal to emit if a value changed.
+pub(crate) fn dispatch(store: &mut dyn ConfigStore, msg: &Message) -> (Message, Option<Message>) {
+    let value_changed = |section: &str, name: &str, value: Option<Box<dyn RefArg>>| {
+        // D-Bus can't carry the empty tuple that IBus uses for unset values,
+        // so an empty array is sent instead
+        let value = value.unwrap_or_else(|| Box::new(Vec::<Variant<Box<dyn RefArg>>>::new()));
+        Message::new_signal(CONFIG_PATH, CONFIG_INTERFACE, "ValueChanged")
+            .expect("the config signal name should be valid")
+            .append3(section, name, Variant(value))
+    };
+    let reply = match (msg.interface().as_deref(), msg.member().as_deref()) {
+        (Some(CONFIG_INTERFACE), Some("GetValue")) => {
+            let (section, name): (&str, &str) = match msg.read2() {
+                Ok(args) => args,
+                Err(e) => return (invalid_args(msg, e), None),
+            };
+            match store.get(section, name) {
+                Some(value) => msg.method_return().append1(Variant(value)),
+                None => error_reply(
+                    msg,
+                    "org.freedesktop.DBus.Error.Failed",
+                    format!("Config value [{}:{}] does not exist.", section, name),
+                ),
+            }
+        }
+        (Some(CONFIG_INTERFACE), Some("GetValues")) => {
+            let section: &str = match msg.read1() {
+                Ok(args) => args,
+                Err(e) => return (invalid_args(msg, e), None),
+            };
+            let values: PropMap = store
+                .get_section(section)
+                .into_iter()
+                .map(|(name, value)| (name, Variant(value)))
+                .collect();
+            msg.method_return().append1(values)
+        }
+        (Some(CONFIG_INTERFACE), Some("SetValue")) => {
+            let (section, name, value): (&str, &str, Variant<Box<dyn RefArg>>) = match msg.read3() {
+                Ok(args) => args,
+                Err(e) => return (invalid_args(msg, e), None),
+            };
+            let signal = value_changed(section, name, Some(value.0.box_clone()));
+            return match store.set(section, name, value.0) {
+                Ok(()) => (msg.method_return(), Some(signal)),
+                Err(e) => (
+                    error_reply(msg, "org.freedesktop.DBus.Error.Failed", e.to_string()),
+                    None,
+                ),
+            };
+        }
+        (Some(CONFIG_INTERFACE), Some("UnsetValue")) => {
+            let (section, name): (&str, &str) = match msg.read2() {
+                Ok(args) => args,
+                Err(e) => return (invalid_args(msg, e), None),
+            };
+            return match store.unset(section, name) {
+                Ok(value) => (
+                    msg.method_return(),
+                    Some(value_changed(section, name, value)),
+                ),
+                Err(e) => (
+                    error_reply(msg, "org.freedesktop.DBus.Error.Failed", e.to_string()),
+                    None,
+                ),
+            };
+        }
+        (Some(SERVICE_INTERFACE), Some("Destroy")) => msg.method_return(),
+        (interface, member) => error_reply(
+            msg,
+            "org.freedesktop.DBus.Error.UnknownMethod",
+            format!("Unknown method {:?}.{:?}", interface, member),
+        ),
+    };
+    (reply, None)
+}
+
+/// Serves the configuration of the session from a `ConfigStore`
+///
+/// Only one program can provide the configuration, so this fails if the
+/// usual provider (which stores the values with GSettings) is running. The
+/// method calls are handled while calling `Bus::process`.
+///
+/// ```no_run
+/// use ibus::{Bus, ConfigService, MemoryConfigStore};
+/// use std::time::Duration;
+///
+/// let bus = Bus::new().unwrap();
+/// let _service = ConfigService::new(&bus, MemoryConfigStore::new()).unwrap();
+/// loop {
+///     bus.process(Duration::from_secs(1)).unwrap();
+/// }
+/// ```
+pub struct ConfigService<S> {
+    conn: Rc<Connection>,
+    store: Arc<Mutex<S>>,
+    token: Token,
+}
+impl<S: ConfigStore + 'static> ConfigService<S> {
+    pub fn new(bus: &Bus, store: S) -> Result<Self, Error> {
+        let store = Arc::new(Mutex::new(store));
+        let rule = MatchRule::new_method_call().with_path(CONFIG_PATH);
+        let token = bus.conn.start_receive(rule, {
+            let store = store.clone();
+            Box::new(move |msg, conn| {
+                let (reply, signal) = dispatch(&mut *store.lock().unwrap(), &msg);
+                if !msg.get_no_reply() && conn.send(reply).is_err() {
+                    warn!("Failed to send the reply to {:?}", msg.member());
+                }
+                if let Some(signal) = signal {
+                    if conn.send(signal).is_err() {
+                        warn!("Failed to send a ValueChanged signal");
+                    }
+                }
+                true
+            })
+        });
+        let service = ConfigService {
+            conn: bus.conn.clone(),
+            store,
+            token,
+        };
+        // Unlike `Bus::request_name`, this doesn't take the name over from
+        // the running provider
+        use dbus::blocking::stdintf::org_freedesktop_dbus::RequestNameReply;
+        match bus.conn.request_name(CONFIG_NAME, false, false, true)? {
+            RequestNameReply::PrimaryOwner | RequestNameReply::AlreadyOwner => Ok(service),
+            reply => Err(Error::Unknown {
+                description: format!("Another config provider is running: {:?}", reply),
+            }),
+        }
+    }
+
+    /// Calls `f` with the store, e.g. to save it. Changes made here aren't
+    /// signalled to the clients.
+    pub fn with_store<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut S) -> R,
+    {
+        f(&mut self.store.lock().unwrap())
+    }
+}
+impl<S> Drop for ConfigService<S> {
+    fn drop(&mut self) {
+        self.conn.stop_receive(self.token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValueChangedSignal;
+
+    fn call(method: &str) -> Message {
+        let mut msg =
+            Message::new_method_call(CONFIG_NAME, CONFIG_PATH, CONFIG_INTERFACE, method).unwrap();
+        msg.set_serial(1);
+        msg
+    }
+
+    #[test]
+    fn set_get_and_unset() {
+        let mut store = MemoryConfigStore::new();
+        let (reply, signal) = dispatch(
+            &mut store,
+            &call("SetValue").append3("panel", "show", Variant(1i32)),
+        );
+        assert!(reply.read_all::<()>().is_ok());
+        let signal: ValueChangedSignal = signal.unwrap().read_all().unwrap();
+        assert_eq!(signal.value.unwrap().as_i64(), Some(1));
+
+        let (reply, _) = dispatch(&mut store, &call("GetValue").append2("panel", "show"));
+        assert_eq!(reply.read1::<Variant<i32>>().unwrap().0, 1);
+        let (reply, _) = dispatch(&mut store, &call("GetValues").append1("panel"));
+        assert_eq!(reply.read1::<PropMap>().unwrap().len(), 1);
+
+        let (_, signal) = dispatch(&mut store, &call("UnsetValue").append2("panel", "show"));
+        let signal: ValueChangedSignal = signal.unwrap().read_all().unwrap();
+        assert!(signal.value.is_none());
+        let (reply, _) = dispatch(&mut store, &call("GetValue").append2("panel", "show"));
+        assert!(reply.read1::<Variant<i32>>().is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use std::{collections::HashMap, marker::PhantomData};
+
+use log::debug;
+
+use dbus::{
+    arg::{ArgType, PropMap, RefArg, Variant},
+    channel::Token,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value};
+
+use super::{get_values, Config};
+use crate::{AfterCallback, Bus, Error};
+
+/// A section of the configuration, as a struct
+///
+/// Every field of the struct is a value in the section. Fields that aren't
+/// set in the configuration get their defaults, so the struct should use
+/// `#[serde(default)]`. The values in IBus sections are usually named in
+/// kebab case, which `#[serde(rename_all = "kebab-case")]` takes care of.
+///
+/// ```no_run
+/// use ibus::{Bus, TypedConfig};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Default, Serialize, Deserialize)]
+/// #[serde(default, rename_all = "kebab-case")]
+/// struct Settings {
+///     page_size: u32,
+///     auto_commit: bool,
+///     dictionaries: Vec<String>,
+/// }
+///
+/// let bus = Bus::new().unwrap();
+/// let config = TypedConfig::<Settings>::new(&bus, "engine/my-engine");
+/// let mut settings = config.load().unwrap();
+/// settings.page_size = 9;
+/// config.save(&settings).unwrap();
+/// ```
+///
+/// Integers are stored as 32 bit values if they fit (the type used by the
+/// IBus schemas), strings as strings, lists of strings as string arrays, and
+/// nested structs as dictionaries. A field that is `None` unsets the value.
+pub struct TypedConfig<T> {
+    config: Config,
+    section: String,
+    _marker: PhantomData<fn() -> T>,
+}
+impl<T: Serialize + DeserializeOwned> TypedConfig<T> {
+    pub fn new(bus: &Bus, section: impl Into<String>) -> Self {
+        TypedConfig {
+            config: Config::new(bus),
+            section: section.into(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
+    /// Reads the values of the section
+    pub fn load(&self) -> Result<T, Error> {
+        from_values(&self.config.get_values(&self.section)?)
+    }
+
+    /// Writes every field of `value` to the section
+    pub fn save(&self, value: &T) -> Result<(), Error> {
+        let fields = match serde_json::to_value(value) {
+            Ok(Value::Object(fields)) => fields,
+            Ok(_) => {
+                return Err(Error::Unknown {
+                    description: "Only structs can be saved as a config section".into(),
+                })
+            }
+            Err(e) => {
+                return Err(Error::Unknown {
+                    description: format!("Couldn't serialize the settings: {}", e),
+                })
+            }
+        };
+        for (name, value) in fields {
+            match to_ref_arg(value) {
+                Some(value) => self
+                    .config
+                    .set_value(&self.section, &name, Variant(value))?,
+                None => self.config.unset_value(&self.section, &name)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls `callback` with the settings whenever a value of the section
+    /// changes
+    pub fn watch<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        let section = self.section.clone();
+        self.config.on_value_changed(move |signal, conn, _| {
+            if signal.section == section {
+                match get_values(conn, &section).and_then(|values| from_values(&values)) {
+                    Ok(settings) => callback(settings),
+                    Err(e) => debug!("Couldn't reload the settings of {}: {}", section, e),
+                }
+            }
+            AfterCallback::Keep
+        })
+    }
+}
+
+fn from_values<T: DeserializeOwned>(values: &HashMap<String, Box<dyn RefArg>>) -> Result<T, Error> {
+    let fields: Map<String, Value> = values
+        .iter()
+        .map(|(name, value)| (name.clone(), to_json(&**value)))
+        .collect();
+    serde_json::from_value(Value::Object(fields)).map_err(|e| Error::Unknown {
+        description: format!("Invalid settings: {}", e),
+    })
+}
+
+fn to_json(value: &dyn RefArg) -> Value {
+    match value.arg_type() {
+        ArgType::Boolean => Value::Bool(value.as_u64() == Some(1)),
+        ArgType::Byte
+        | ArgType::Int16
+        | ArgType::UInt16
+        | ArgType::Int32
+        | ArgType::UInt32
+        | ArgType::Int64
+        | ArgType::UnixFd => value.as_i64().map_or(Value::Null, Value::from),
+        ArgType::UInt64 => value.as_u64().map_or(Value::Null, Value::from),
+        ArgType::Double => value
+            .as_f64()
+            .and_then(Number::from_f64)
+            .map_or(Value::Null, Value::Number),
+        ArgType::String | ArgType::ObjectPath | ArgType::Signature => {
+            value.as_str().map_or(Value::Null, Value::from)
+        }
+        ArgType::Variant => value
+            .as_iter()
+            .and_then(|mut inner| inner.next().map(to_json))
+            .unwrap_or(Value::Null),
+        ArgType::Array if value.signature().starts_with("a{") => {
+            let mut map = Map::new();
+            if let Some(mut items) = value.as_iter() {
+                while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                    let key = match to_json(key) {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    map.insert(key, to_json(value));
+                }
+            }
+            Value::Object(map)
+        }
+        ArgType::Array | ArgType::Struct => value.as_iter().map_or(Value::Null, |items| {
+            Value::Array(items.map(to_json).collect())
+        }),
+        ArgType::DictEntry | ArgType::Invalid => Value::Null,
+    }
+}
+
+/// Returns `None` for values that should be unset
+fn to_ref_arg(value: Value) -> Option<Box<dyn RefArg>> {
+    let value: Box<dyn RefArg> = match value {
+        Value::Null => return None,
+        Value::Bool(b) => Box::new(b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                match i32::try_from(i) {
+                    Ok(i) => Box::new(i),
+                    Err(_) => Box::new(i),
+                }
+            } else if let Some(u) = n.as_u64() {
+                Box::new(u)
+            } else {
+                Box::new(n.as_f64().unwrap_or_default())
+            }
+        }
+        Value::String(s) => Box::new(s),
+        Value::Array(items) => {
+            if items.iter().all(Value::is_string) {
+                let strings: Vec<String> = items
+                    .into_iter()
+                    .filter_map(|item| item.as_str().map(str::to_owned))
+                    .collect();
+                Box::new(strings)
+            } else {
+                let items: Vec<Variant<Box<dyn RefArg>>> = items
+                    .into_iter()
+                    .filter_map(to_ref_arg)
+                    .map(Variant)
+                    .collect();
+                Box::new(items)
+            }
+        }
+        Value::Object(fields) => {
+            let map: PropMap = fields
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, Variant(to_ref_arg(value)?))))
+                .collect();
+            Box::new(map)
+        }
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default, rename_all = "kebab-case")]
+    struct Settings {
+        page_size: u32,
+        auto_commit: bool,
+        dictionaries: Vec<String>,
+        name: Option<String>,
+    }
+
+    #[test]
+    fn round_trip_through_variants() {
+        let settings = Settings {
+            page_size: 7,
+            auto_commit: true,
+            dictionaries: vec!["main".into(), "user".into()],
+            name: None,
+        };
+        let values: HashMap<String, Box<dyn RefArg>> = match serde_json::to_value(&settings) {
+            Ok(Value::Object(fields)) => fields
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, to_ref_arg(value)?)))
+                .collect(),
+            _ => unreachable!(),
+        };
+        assert!(!values.contains_key("name"));
+        assert_eq!(&*values["page-size"].signature(), "i");
+        assert_eq!(&*values["dictionaries"].signature(), "as");
+        assert_eq!(from_values::<Settings>(&values).unwrap(), settings);
+
+        // Missing values get their defaults
+        let values = HashMap::from([("auto-commit".to_owned(), Box::new(true) as Box<dyn RefArg>)]);
+        let loaded: Settings = from_values(&values).unwrap();
+        assert!(loaded.auto_commit);
+        assert_eq!(loaded.page_size, 0);
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use dbus::channel::MatchingReceiver;
+use log::debug;
+
+use crate::{dump, Bus, Error, ImeEvent, InputContext};
+
+/// How long the thread waits for a message before it looks for calls from
+/// `with_context`
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+type Command = Box<dyn FnOnce(&InputContext) + Send>;
+
+/// An input context on a thread that passes its events to a callback, for
+/// the event loops that can't poll a `Bus` themselves
+///
+/// The thread stops when this is dropped, or when the callback returns
+/// false because the receiving side is gone.
+pub(crate) struct ContextThread {
+    commands: Option<mpsc::Sender<Command>>,
+    thread: Option<JoinHandle<()>>,
+}
+impl ContextThread {
+    /// Connects to the daemon and creates an input context called `name` on
+    /// a new thread. Returns once the input context is created.
+    pub fn spawn<F>(thread_name: &str, name: &str, send: F) -> Result<Self, Error>
+    where
+        F: FnMut(ImeEvent) -> bool + Send + 'static,
+    {
+        let (commands, receiver) = mpsc::channel::<Command>();
+        let (ready, ready_receiver) = mpsc::channel();
+        let name = name.to_owned();
+        let thread = std::thread::Builder::new()
+            .name(thread_name.into())
+            .spawn(move || {
+                let (bus, ctx) = match Bus::new().and_then(|bus| {
+                    let ctx = bus.create_input_context(&name)?;
+                    Ok((bus, ctx))
+                }) {
+                    Ok(connected) => {
+                        let _ = ready.send(Ok(()));
+                        connected
+                    }
+                    Err(e) => {
+                        let _ = ready.send(Err(e));
+                        return;
+                    }
+                };
+                run(bus, ctx, send, receiver);
+            })?;
+        match ready_receiver.recv() {
+            Ok(Ok(())) => Ok(ContextThread {
+                commands: Some(commands),
+                thread: Some(thread),
+            }),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(disconnected()),
+        }
+    }
+
+    /// Calls `f` with the input context on the thread of the connection, and
+    /// returns its result
+    pub fn with_context<R, F>(&self, f: F) -> Result<R, Error>
+    where
+        R: Send + 'static,
+        F: FnOnce(&InputContext) -> R + Send + 'static,
+    {
+        let (reply, reply_receiver) = mpsc::channel();
+        let command: Command = Box::new(move |ctx| {
+            let _ = reply.send(f(ctx));
+        });
+        self.commands
+            .as_ref()
+            .and_then(|commands| commands.send(command).ok())
+            .ok_or_else(disconnected)?;
+        reply_receiver.recv().map_err(|_| disconnected())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
+}
+impl Drop for ContextThread {
+    fn drop(&mut self) {
+        self.commands = None;
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn run<F>(bus: Bus, ctx: InputContext, mut send: F, commands: mpsc::Receiver<Command>)
+where
+    F: FnMut(ImeEvent) -> bool + Send + 'static,
+{
+    let closed = Arc::new(AtomicBool::new(false));
+    bus.conn.start_receive(ImeEvent::match_rule(), {
+        let closed = closed.clone();
+        Box::new(move |msg, _| {
+            dump::received(&msg);
+            if let Some(event) = ImeEvent::from_message(&msg) {
+                if !send(event) {
+                    closed.store(true, Ordering::Relaxed);
+                }
+            }
+            true
+        })
+    });
+
+    loop {
+        loop {
+            match commands.try_recv() {
+                Ok(command) => command(&ctx),
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => return,
+            }
+        }
+        if let Err(e) = bus.process(POLL_INTERVAL) {
+            debug!("Stopping the dispatcher thread: {}", e);
+            return;
+        }
+        if closed.load(Ordering::Relaxed) {
+            debug!("Stopping the dispatcher thread, the events aren't received anymore");
+            return;
+        }
+    }
+}
+
+fn disconnected() -> Error {
+    Error::Unknown {
+        description: "The thread of the dispatcher has stopped".into(),
+    }
+}
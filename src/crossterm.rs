@@ -0,0 +1,294 @@
+//! Using IBus in a terminal application with crossterm
+//!
+//! A terminal only reports characters and some named keys, not the physical
+//! keys, so `key_event_args` finds the keysyms from `KeyCode` and sends 0
+//! as the keycode. The engines that are driven by the keysyms, which is most
+//! of them, work with it. Key releases are only reported by the terminals
+//! that support the
+//! [kitty protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/),
+//! when `KeyboardEnhancementFlags::REPORT_EVENT_TYPES` is pushed.
+//!
+//! There's no candidate window over a terminal, so the preedit and the
+//! candidates are drawn by the application. `preedit_segments` and
+//! `candidate_segments` make styled text for them, to print inline:
+//!
+//! ```no_run
+//! use crossterm::{event::{read, Event}, queue, style::PrintStyledContent};
+//! use ibus::{Bus, CandidatePopupModel, Capabilites};
+//! use std::io::Write;
+//!
+//! let bus = Bus::new().unwrap();
+//! let ctx = bus.create_input_context("editor").unwrap();
+//! ctx.set_capabilities(
+//!     Capabilites::PREEDIT_TEXT | Capabilites::LOOKUP_TABLE | Capabilites::FOCUS,
+//! );
+//! let model = CandidatePopupModel::new();
+//!
+//! if let Event::Key(event) = read().unwrap() {
+//!     if let Some((sym, code, state)) = ibus::crossterm::key_event_args(&event) {
+//!         let handled = ctx.process_key_event(sym, code, state).unwrap_or(false);
+//!     }
+//! }
+//! let mut out = std::io::stdout();
+//! for segment in ibus::crossterm::candidate_segments(model.content()) {
+//!     queue!(out, PrintStyledContent(segment)).unwrap();
+//! }
+//! out.flush().unwrap();
+//! ```
+//!
+//! This module needs the `crossterm` feature.
+//!
+
+use crossterm::{
+    event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, ModifierKeyCode},
+    style::{Attribute as StyleAttribute, Color, ContentStyle, StyledContent},
+};
+
+use crate::{keysyms, AttributeKind, Modifiers, PopupContent, Text, UnderlineKind};
+
+/// The keysym, the keycode and the modifiers of a crossterm key event, the
+/// arguments of `InputContext::process_key_event`
+///
+/// The keycode is always 0. Returns `None` for keys without a keysym, like
+/// the media keys.
+pub fn key_event_args(event: &KeyEvent) -> Option<(u32, u32, Modifiers)> {
+    let sym = keysym(event.code)?;
+    let mut state = modifiers(event.modifiers, event.state);
+    if event.kind == KeyEventKind::Release {
+        state |= Modifiers::RELEASE;
+    }
+    Some((sym, 0, state))
+}
+
+/// Converts the modifiers of crossterm to the ones of IBus, Super is also
+/// `MOD4` as in X11
+///
+/// Caps Lock and Num Lock are only known with the kitty protocol, and
+/// `KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES`.
+pub fn modifiers(modifiers: KeyModifiers, state: KeyEventState) -> Modifiers {
+    let mut result = Modifiers::empty();
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        result |= Modifiers::SHIFT;
+    }
+    if state.contains(KeyEventState::CAPS_LOCK) {
+        result |= Modifiers::LOCK;
+    }
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        result |= Modifiers::CONTROL;
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        result |= Modifiers::MOD1;
+    }
+    if state.contains(KeyEventState::NUM_LOCK) {
+        result |= Modifiers::MOD2;
+    }
+    if modifiers.contains(KeyModifiers::SUPER) {
+        result |= Modifiers::SUPER | Modifiers::MOD4;
+    }
+    if modifiers.contains(KeyModifiers::HYPER) {
+        result |= Modifiers::HYPER;
+    }
+    if modifiers.contains(KeyModifiers::META) {
+        result |= Modifiers::META;
+    }
+    result
+}
+
+/// The keysym of a crossterm key code
+///
+/// The characters are already shifted by the terminal. `BackTab` is `Tab`
+/// with Shift, which X11 calls `ISO_Left_Tab`.
+pub fn keysym(code: KeyCode) -> Option<u32> {
+    use keysyms::*;
+    Some(match code {
+        KeyCode::Char(c) => keysym_from_char(c)?,
+        KeyCode::Backspace => KEY_BackSpace,
+        KeyCode::Enter => KEY_Return,
+        KeyCode::Left => KEY_Left,
+        KeyCode::Right => KEY_Right,
+        KeyCode::Up => KEY_Up,
+        KeyCode::Down => KEY_Down,
+        KeyCode::Home => KEY_Home,
+        KeyCode::End => KEY_End,
+        KeyCode::PageUp => KEY_Page_Up,
+        KeyCode::PageDown => KEY_Page_Down,
+        KeyCode::Tab => KEY_Tab,
+        KeyCode::BackTab => KEY_ISO_Left_Tab,
+        KeyCode::Delete => KEY_Delete,
+        KeyCode::Insert => KEY_Insert,
+        KeyCode::F(n @ 1..=35) => KEY_F1 + u32::from(n) - 1,
+        KeyCode::Esc => KEY_Escape,
+        KeyCode::CapsLock => KEY_Caps_Lock,
+        KeyCode::ScrollLock => KEY_Scroll_Lock,
+        KeyCode::NumLock => KEY_Num_Lock,
+        KeyCode::PrintScreen => KEY_Print,
+        KeyCode::Pause => KEY_Pause,
+        KeyCode::Menu => KEY_Menu,
+        KeyCode::KeypadBegin => KEY_KP_Begin,
+        KeyCode::Modifier(modifier) => modifier_keysym(modifier)?,
+        _ => return None,
+    })
+}
+
+fn modifier_keysym(modifier: ModifierKeyCode) -> Option<u32> {
+    use keysyms::*;
+    Some(match modifier {
+        ModifierKeyCode::LeftShift => KEY_Shift_L,
+        ModifierKeyCode::LeftControl => KEY_Control_L,
+        ModifierKeyCode::LeftAlt => KEY_Alt_L,
+        ModifierKeyCode::LeftSuper => KEY_Super_L,
+        ModifierKeyCode::LeftHyper => KEY_Hyper_L,
+        ModifierKeyCode::LeftMeta => KEY_Meta_L,
+        ModifierKeyCode::RightShift => KEY_Shift_R,
+        ModifierKeyCode::RightControl => KEY_Control_R,
+        ModifierKeyCode::RightAlt => KEY_Alt_R,
+        ModifierKeyCode::RightSuper => KEY_Super_R,
+        ModifierKeyCode::RightHyper => KEY_Hyper_R,
+        ModifierKeyCode::RightMeta => KEY_Meta_R,
+        ModifierKeyCode::IsoLevel3Shift => KEY_ISO_Level3_Shift,
+        ModifierKeyCode::IsoLevel5Shift => KEY_ISO_Level5_Shift,
+    })
+}
+
+/// The preedit text in styled segments, with its attributes, and the
+/// character at `cursor_pos` in reverse video as a block cursor
+///
+/// A space is added for the cursor when it's at the end. Text without
+/// attributes is underlined, like the toolkits draw the preedit.
+pub fn preedit_segments(text: &Text, cursor_pos: u32) -> Vec<StyledContent<String>> {
+    let chars: Vec<char> = text.as_str().chars().collect();
+    let len = chars.len() as u32;
+    let cursor = cursor_pos.min(len);
+    let mut boundaries = vec![0, len, cursor, (cursor + 1).min(len)];
+    for attribute in text.attributes() {
+        boundaries.push(attribute.start_index.min(len));
+        boundaries.push(attribute.end_index.min(len));
+    }
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut segments: Vec<_> = boundaries
+        .windows(2)
+        .map(|range| {
+            let (start, end) = (range[0], range[1]);
+            let mut style = style_at(text, start);
+            if start == cursor_pos {
+                style.attributes.set(StyleAttribute::Reverse);
+            }
+            let string = chars[start as usize..end as usize].iter().collect();
+            StyledContent::new(style, string)
+        })
+        .collect();
+    if cursor_pos >= len {
+        let mut style = ContentStyle::new();
+        style.attributes.set(StyleAttribute::Reverse);
+        segments.push(StyledContent::new(style, " ".to_owned()));
+    }
+    segments
+}
+
+/// The style of the character at `index`, from the attributes that cover it
+fn style_at(text: &Text, index: u32) -> ContentStyle {
+    let mut style = ContentStyle::new();
+    let mut underline = None;
+    for attribute in text.attributes() {
+        if !(attribute.start_index..attribute.end_index).contains(&index) {
+            continue;
+        }
+        match attribute.kind {
+            AttributeKind::Underline(kind) => underline = Some(kind),
+            AttributeKind::Foreground(color) => style.foreground_color = Some(rgb(color)),
+            AttributeKind::Background(color) => style.background_color = Some(rgb(color)),
+            AttributeKind::Other { .. } => {}
+        }
+    }
+    match underline.unwrap_or(UnderlineKind::Single) {
+        UnderlineKind::None => {}
+        UnderlineKind::Single | UnderlineKind::Low => {
+            style.attributes.set(StyleAttribute::Underlined)
+        }
+        UnderlineKind::Double => style.attributes.set(StyleAttribute::DoubleUnderlined),
+        UnderlineKind::Error => style.attributes.set(StyleAttribute::Undercurled),
+    }
+    style
+}
+
+/// IBus colors are `0xRRGGBB`
+fn rgb(color: u32) -> Color {
+    Color::Rgb {
+        r: (color >> 16) as u8,
+        g: (color >> 8) as u8,
+        b: color as u8,
+    }
+}
+
+/// The auxiliary text and the candidates on one line, e.g. `pin 1.你 2.尼`,
+/// with the selected candidate in reverse video
+pub fn candidate_segments(content: &PopupContent) -> Vec<StyledContent<String>> {
+    let mut segments = Vec::new();
+    if let Some(auxiliary) = &content.auxiliary {
+        segments.push(StyledContent::new(
+            ContentStyle::new(),
+            auxiliary.as_str().to_owned(),
+        ));
+    }
+    for candidate in &content.candidates {
+        if !segments.is_empty() {
+            segments.push(StyledContent::new(ContentStyle::new(), " ".to_owned()));
+        }
+        let mut style = ContentStyle::new();
+        if candidate.selected {
+            style.attributes.set(StyleAttribute::Reverse);
+        }
+        segments.push(StyledContent::new(
+            style,
+            format!("{}.{}", candidate.label, candidate.text.as_str()),
+        ));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Attribute;
+
+    #[test]
+    fn keysyms() {
+        assert_eq!(keysym(KeyCode::Char('a')), Some(keysyms::KEY_a));
+        assert_eq!(keysym(KeyCode::Char('A')), Some(keysyms::KEY_A));
+        assert_eq!(keysym(KeyCode::F(12)), Some(keysyms::KEY_F12));
+        assert_eq!(keysym(KeyCode::BackTab), Some(keysyms::KEY_ISO_Left_Tab));
+        assert_eq!(keysym(KeyCode::Null), None);
+
+        let mut event = KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT);
+        event.kind = KeyEventKind::Release;
+        assert_eq!(
+            key_event_args(&event),
+            Some((keysyms::KEY_Return, 0, Modifiers::MOD1 | Modifiers::RELEASE))
+        );
+    }
+
+    #[test]
+    fn preedit() {
+        let text = Text::new(
+            "nihao",
+            vec![Attribute {
+                kind: AttributeKind::Underline(UnderlineKind::Double),
+                start_index: 2,
+                end_index: 5,
+            }],
+        );
+        let segments = preedit_segments(&text, 2);
+        let strings: Vec<&str> = segments.iter().map(|s| s.content().as_str()).collect();
+        assert_eq!(strings, ["ni", "h", "ao"]);
+        let attributes = segments[1].style().attributes;
+        assert!(attributes.has(StyleAttribute::Reverse));
+        assert!(attributes.has(StyleAttribute::DoubleUnderlined));
+        let attributes = segments[0].style().attributes;
+        assert!(attributes.has(StyleAttribute::Underlined));
+
+        let segments = preedit_segments(&Text::new("ni", vec![]), 2);
+        assert_eq!(segments.last().unwrap().content(), " ");
+    }
+}
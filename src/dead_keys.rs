@@ -0,0 +1,244 @@
+//! Dead keys
+//!
+//! A dead key (e.g. `dead_acute`) doesn't produce a character by itself, but
+//! modifies the next character. The helpers here combine dead keys with a
+//! base character the way the toolkits do when no compose sequence matches:
+//! every dead key adds a combining mark, and the result is normalized to NFC.
+//!
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+    keysyms::{self, is_modifier_key, keysym_to_char},
+    Modifiers,
+};
+
+/// Whether the keysym is a dead key
+pub fn is_dead_key(keysym: u32) -> bool {
+    matches!(keysym, 0xfe50..=0xfe6f | 0xfe80..=0xfe8c | 0xfe90..=0xfe93)
+}
+
+/// The combining mark that the dead key adds, e.g. U+0301 for `dead_acute`
+///
+/// Returns `None` for keysyms that aren't dead keys, and for the few dead
+/// keys that don't correspond to a combining mark (e.g. `dead_greek`).
+pub fn dead_key_combining_char(keysym: u32) -> Option<char> {
+    let c = match keysym {
+        keysyms::KEY_dead_grave => '\u{300}',
+        keysyms::KEY_dead_acute => '\u{301}',
+        keysyms::KEY_dead_circumflex => '\u{302}',
+        keysyms::KEY_dead_tilde => '\u{303}',
+        keysyms::KEY_dead_macron => '\u{304}',
+        keysyms::KEY_dead_breve => '\u{306}',
+        keysyms::KEY_dead_abovedot => '\u{307}',
+        keysyms::KEY_dead_diaeresis => '\u{308}',
+        keysyms::KEY_dead_hook => '\u{309}',
+        keysyms::KEY_dead_abovering => '\u{30a}',
+        keysyms::KEY_dead_doubleacute => '\u{30b}',
+        keysyms::KEY_dead_caron => '\u{30c}',
+        keysyms::KEY_dead_aboveverticalline => '\u{30d}',
+        keysyms::KEY_dead_doublegrave => '\u{30f}',
+        keysyms::KEY_dead_invertedbreve => '\u{311}',
+        keysyms::KEY_dead_abovecomma => '\u{313}',
+        keysyms::KEY_dead_abovereversedcomma => '\u{314}',
+        keysyms::KEY_dead_horn => '\u{31b}',
+        keysyms::KEY_dead_belowdot => '\u{323}',
+        keysyms::KEY_dead_belowdiaeresis => '\u{324}',
+        keysyms::KEY_dead_belowring => '\u{325}',
+        keysyms::KEY_dead_belowcomma => '\u{326}',
+        keysyms::KEY_dead_cedilla => '\u{327}',
+        keysyms::KEY_dead_ogonek => '\u{328}',
+        keysyms::KEY_dead_belowverticalline => '\u{329}',
+        keysyms::KEY_dead_belowcircumflex => '\u{32d}',
+        keysyms::KEY_dead_belowbreve => '\u{32e}',
+        keysyms::KEY_dead_belowtilde => '\u{330}',
+        keysyms::KEY_dead_belowmacron => '\u{331}',
+        keysyms::KEY_dead_lowline => '\u{332}',
+        keysyms::KEY_dead_stroke | keysyms::KEY_dead_longsolidusoverlay => '\u{338}',
+        keysyms::KEY_dead_iota => '\u{345}',
+        keysyms::KEY_dead_voiced_sound => '\u{3099}',
+        keysyms::KEY_dead_semivoiced_sound => '\u{309a}',
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// The character that the dead key produces on its own, i.e. when it's
+/// followed by Space
+///
+/// This is the spacing version of the accent if there's one (e.g. '´' for
+/// `dead_acute`), and the combining mark otherwise.
+pub fn dead_key_spacing_char(keysym: u32) -> Option<char> {
+    let c = match keysym {
+        keysyms::KEY_dead_grave => '`',
+        keysyms::KEY_dead_acute => '´',
+        keysyms::KEY_dead_circumflex => '^',
+        keysyms::KEY_dead_tilde => '~',
+        keysyms::KEY_dead_macron => '¯',
+        keysyms::KEY_dead_breve => '˘',
+        keysyms::KEY_dead_abovedot => '˙',
+        keysyms::KEY_dead_diaeresis => '¨',
+        keysyms::KEY_dead_abovering => '˚',
+        keysyms::KEY_dead_doubleacute => '˝',
+        keysyms::KEY_dead_caron => 'ˇ',
+        keysyms::KEY_dead_cedilla => '¸',
+        keysyms::KEY_dead_ogonek => '˛',
+        keysyms::KEY_dead_iota => 'ͺ',
+        keysyms::KEY_dead_voiced_sound => '゛',
+        keysyms::KEY_dead_semivoiced_sound => '゜',
+        keysyms::KEY_dead_lowline => '_',
+        keysyms::KEY_dead_currency => '¤',
+        _ => return dead_key_combining_char(keysym),
+    };
+    Some(c)
+}
+
+/// Combines the dead keys, in the order they were typed, with `base`
+///
+/// The result is in NFC, so it's a single precomposed character when
+/// Unicode has one, and the base followed by combining marks otherwise.
+/// Returns `None` if one of the keysyms isn't a dead key with a combining
+/// mark.
+///
+/// ```
+/// use ibus::{combine_dead_keys, keysyms};
+///
+/// assert_eq!(combine_dead_keys(&[keysyms::KEY_dead_acute], 'e').as_deref(), Some("é"));
+/// assert_eq!(
+///     combine_dead_keys(&[keysyms::KEY_dead_circumflex, keysyms::KEY_dead_acute], 'e').as_deref(),
+///     Some("ế"),
+/// );
+/// ```
+pub fn combine_dead_keys(dead_keys: &[u32], base: char) -> Option<String> {
+    let mut decomposed = String::new();
+    decomposed.push(base);
+    for &keysym in dead_keys {
+        decomposed.push(dead_key_combining_char(keysym)?);
+    }
+    Some(decomposed.nfc().collect())
+}
+
+/// The result of `DeadKeyComposer::process_key_event`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeadKeyResult {
+    /// The key isn't part of a dead key sequence, and should be handled as
+    /// usual
+    Ignored,
+    /// The key was added to the sequence, or removed from it with Backspace
+    Pending,
+    /// The sequence is finished, and the text should be inserted
+    Commit(String),
+    /// The sequence was abandoned. The key should still be handled as usual,
+    /// unless it was Escape.
+    Cancelled,
+}
+
+/// Tracks typed dead keys, for clients that want to handle them locally when
+/// no engine does
+///
+/// ```
+/// use ibus::{DeadKeyComposer, DeadKeyResult, Modifiers, keysyms};
+///
+/// let mut composer = DeadKeyComposer::new();
+/// let none = Modifiers::empty();
+/// assert_eq!(composer.process_key_event(keysyms::KEY_dead_diaeresis, none), DeadKeyResult::Pending);
+/// assert_eq!(composer.process_key_event(keysyms::KEY_u, none), DeadKeyResult::Commit("ü".into()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DeadKeyComposer {
+    pending: Vec<u32>,
+}
+impl DeadKeyComposer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The dead keys typed so far
+    pub fn pending(&self) -> &[u32] {
+        &self.pending
+    }
+
+    /// The pending dead keys as text, e.g. for showing them as preedit
+    pub fn pending_text(&self) -> String {
+        self.pending
+            .iter()
+            .filter_map(|&keysym| dead_key_spacing_char(keysym))
+            .collect()
+    }
+
+    /// Key releases and modifier keys are ignored. Space produces the
+    /// spacing accents of the pending dead keys.
+    pub fn process_key_event(&mut self, keysym: u32, modifiers: Modifiers) -> DeadKeyResult {
+        if modifiers.contains(Modifiers::RELEASE) || is_modifier_key(keysym) {
+            return DeadKeyResult::Ignored;
+        }
+        if is_dead_key(keysym) {
+            self.pending.push(keysym);
+            return DeadKeyResult::Pending;
+        }
+        if self.pending.is_empty() {
+            return DeadKeyResult::Ignored;
+        }
+        match keysym {
+            keysyms::KEY_BackSpace => {
+                self.pending.pop();
+                DeadKeyResult::Pending
+            }
+            keysyms::KEY_space => {
+                let text = self.pending_text();
+                self.reset();
+                DeadKeyResult::Commit(text)
+            }
+            _ => {
+                let result = keysym_to_char(keysym)
+                    .filter(|_| !modifiers.intersects(Modifiers::CONTROL | Modifiers::MOD1))
+                    .and_then(|c| combine_dead_keys(&self.pending, c));
+                self.reset();
+                match result {
+                    Some(text) => DeadKeyResult::Commit(text),
+                    None => DeadKeyResult::Cancelled,
+                }
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combining() {
+        assert!(is_dead_key(keysyms::KEY_dead_greek));
+        assert!(!is_dead_key(keysyms::KEY_a));
+        assert_eq!(
+            combine_dead_keys(&[keysyms::KEY_dead_cedilla], 'C').as_deref(),
+            Some("Ç")
+        );
+        // No precomposed character
+        assert_eq!(
+            combine_dead_keys(&[keysyms::KEY_dead_acute], 'q').as_deref(),
+            Some("q\u{301}")
+        );
+        assert_eq!(combine_dead_keys(&[keysyms::KEY_a], 'e'), None);
+
+        let mut composer = DeadKeyComposer::new();
+        let none = Modifiers::empty();
+        composer.process_key_event(keysyms::KEY_dead_grave, none);
+        assert_eq!(composer.pending_text(), "`");
+        assert_eq!(
+            composer.process_key_event(keysyms::KEY_space, none),
+            DeadKeyResult::Commit("`".into())
+        );
+        composer.process_key_event(keysyms::KEY_dead_grave, none);
+        assert_eq!(
+            composer.process_key_event(keysyms::KEY_Escape, none),
+            DeadKeyResult::Cancelled
+        );
+        assert!(composer.pending().is_empty());
+    }
+}
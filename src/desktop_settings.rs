@@ -0,0 +1,398 @@
+//! The settings of IBus itself
+//!
+//! These are stored with GSettings, in the `org.freedesktop.ibus` schemas,
+//! and are usually edited with `ibus-setup`. Applications can read them to
+//! adapt to how the user configured IBus, either through the config service
+//! (`DesktopSettings::from_config`), or directly from GSettings
+//! (`DesktopSettings::from_gsettings`, with the `gsettings` feature) when the
+//! config service doesn't run.
+//!
+//! `SwitchHotkeys` turns the configured accelerators into hotkeys, so that
+//! clients that grab keys themselves can avoid the ones that switch the
+//! input method.
+//!
+
+use std::collections::HashSet;
+
+use log::debug;
+
+use dbus::{arg::RefArg, channel::Token};
+
+use crate::{AfterCallback, Config, Error, Hotkey, HotkeyProfile, Modifiers, Orientation};
+
+/// The settings of the daemon and the panel that matter to applications
+///
+/// The defaults are the defaults of the IBus schemas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesktopSettings {
+    /// The engines that the user selected, in the order of the menu of the
+    /// panel (`general/preload-engines`)
+    pub preload_engines: Vec<String>,
+    /// The engines in the order they were last used (`general/engines-order`)
+    pub engines_order: Vec<String>,
+    /// Whether the engines use the keyboard layout of the desktop instead of
+    /// their own (`general/use-system-keyboard-layout`)
+    pub use_system_keyboard_layout: bool,
+    /// Whether the preedit text is shown in the application, instead of the
+    /// candidate window (`general/embed-preedit-text`)
+    pub embed_preedit_text: bool,
+    /// Whether all applications share the same engine
+    /// (`general/use-global-engine`)
+    pub use_global_engine: bool,
+    /// The accelerators that turn the input method on and off, or switch to
+    /// the next engine (`general/hotkey/triggers`)
+    pub triggers: Vec<String>,
+    /// `general/hotkey/next-engine-in-menu`
+    pub next_engine: Vec<String>,
+    /// `general/hotkey/prev-engine`
+    pub previous_engine: Vec<String>,
+    /// `panel/lookup-table-orientation`
+    pub lookup_table_orientation: Orientation,
+}
+impl Default for DesktopSettings {
+    fn default() -> Self {
+        DesktopSettings {
+            preload_engines: Vec::new(),
+            engines_order: Vec::new(),
+            use_system_keyboard_layout: false,
+            embed_preedit_text: true,
+            use_global_engine: true,
+            triggers: vec!["<Super>space".to_owned()],
+            next_engine: Vec::new(),
+            previous_engine: Vec::new(),
+            lookup_table_orientation: Orientation::Vertical,
+        }
+    }
+}
+
+/// Where the settings are, as (config section, key)
+const GENERAL: &str = "general";
+const HOTKEY: &str = "general/hotkey";
+const PANEL: &str = "panel";
+
+impl DesktopSettings {
+    /// Reads the settings through the config service. Settings that can't be
+    /// read keep their defaults.
+    pub fn from_config(config: &Config) -> Self {
+        let mut settings = Self::default();
+        for section in [GENERAL, HOTKEY, PANEL] {
+            match config.get_values(section) {
+                Ok(values) => {
+                    for (key, value) in values {
+                        settings.set(section, &key, Value::from_ref_arg(&*value));
+                    }
+                }
+                Err(e) => debug!("Couldn't read the {} settings: {}", section, e),
+            }
+        }
+        settings
+    }
+
+    /// Reads the settings from GSettings with the `gsettings` tool, without
+    /// the config service
+    #[cfg(feature = "gsettings")]
+    pub fn from_gsettings() -> Result<Self, crate::Error> {
+        let mut settings = Self::default();
+        for schema in ["org.freedesktop.ibus.general", "org.freedesktop.ibus.panel"] {
+            let output = std::process::Command::new("gsettings")
+                .args(["list-recursively", schema])
+                .output()?;
+            if !output.status.success() {
+                return Err(crate::Error::Unknown {
+                    description: format!(
+                        "gsettings couldn't read {}: {}",
+                        schema,
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ),
+                });
+            }
+            settings.apply_gsettings_output(&String::from_utf8_lossy(&output.stdout));
+        }
+        Ok(settings)
+    }
+
+    /// Applies the output of `gsettings list-recursively`, which has a
+    /// `schema key value` line for every key
+    #[cfg_attr(not(feature = "gsettings"), allow(dead_code))]
+    fn apply_gsettings_output(&mut self, output: &str) {
+        for line in output.lines() {
+            let mut parts = line.splitn(3, ' ');
+            let (schema, key, value) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(schema), Some(key), Some(value)) => (schema, key, value),
+                _ => continue,
+            };
+            let section = match schema {
+                "org.freedesktop.ibus.general" => GENERAL,
+                "org.freedesktop.ibus.general.hotkey" => HOTKEY,
+                "org.freedesktop.ibus.panel" => PANEL,
+                _ => continue,
+            };
+            match Value::parse(value) {
+                Some(value) => self.set(section, key, value),
+                None => debug!("Couldn't parse the value of {}: {}", key, value),
+            }
+        }
+    }
+
+    /// The hotkeys of the `general/hotkey` settings
+    pub fn switch_hotkeys(&self) -> SwitchHotkeys {
+        SwitchHotkeys {
+            triggers: parse_hotkeys(&self.triggers),
+            next_engine: parse_hotkeys(&self.next_engine),
+            previous_engine: parse_hotkeys(&self.previous_engine),
+        }
+    }
+
+    /// Sets a value that was unset back to its default
+    fn reset(&mut self, section: &str, key: &str) {
+        let defaults = Self::default();
+        match (section, key) {
+            (HOTKEY, "triggers") => self.triggers = defaults.triggers,
+            (HOTKEY, "next-engine-in-menu") => self.next_engine = defaults.next_engine,
+            (HOTKEY, "prev-engine") => self.previous_engine = defaults.previous_engine,
+            _ => {}
+        }
+    }
+
+    fn set(&mut self, section: &str, key: &str, value: Value) {
+        let strings = |value: Value| match value {
+            Value::Strings(strings) => Some(strings),
+            _ => None,
+        };
+        let boolean = |value: Value| match value {
+            Value::Bool(b) => Some(b),
+            _ => None,
+        };
+        match (section, key) {
+            (GENERAL, "preload-engines") => set(&mut self.preload_engines, strings(value)),
+            (GENERAL, "engines-order") => set(&mut self.engines_order, strings(value)),
+            (GENERAL, "use-system-keyboard-layout") => {
+                set(&mut self.use_system_keyboard_layout, boolean(value))
+            }
+            (GENERAL, "embed-preedit-text") => set(&mut self.embed_preedit_text, boolean(value)),
+            (GENERAL, "use-global-engine") => set(&mut self.use_global_engine, boolean(value)),
+            (HOTKEY, "triggers") => set(&mut self.triggers, strings(value)),
+            (HOTKEY, "next-engine-in-menu") => set(&mut self.next_engine, strings(value)),
+            (HOTKEY, "prev-engine") => set(&mut self.previous_engine, strings(value)),
+            (PANEL, "lookup-table-orientation") => {
+                let orientation = match value {
+                    Value::Int(i) => Orientation::from_value(i as i32),
+                    _ => None,
+                };
+                set(&mut self.lookup_table_orientation, orientation)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn set<T>(field: &mut T, value: Option<T>) {
+    match value {
+        Some(value) => *field = value,
+        None => debug!("Ignoring a setting with an unexpected type"),
+    }
+}
+
+fn parse_hotkeys(accelerators: &[String]) -> HashSet<Hotkey> {
+    accelerators
+        .iter()
+        .filter_map(|accelerator| {
+            let hotkey = Hotkey::parse(accelerator);
+            if hotkey.is_none() {
+                debug!("Ignoring the invalid hotkey {:?}", accelerator);
+            }
+            hotkey
+        })
+        .collect()
+}
+
+/// The hotkeys that the daemon uses to switch the input method
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SwitchHotkeys {
+    /// Turns the input method on and off, or switches to the next engine
+    pub triggers: HashSet<Hotkey>,
+    pub next_engine: HashSet<Hotkey>,
+    pub previous_engine: HashSet<Hotkey>,
+}
+impl SwitchHotkeys {
+    /// Reads the hotkeys through the config service. See
+    /// `DesktopSettings::from_config`.
+    pub fn from_config(config: &Config) -> Self {
+        DesktopSettings::from_config(config).switch_hotkeys()
+    }
+
+    /// Whether the key event would switch the input method
+    pub fn contains(&self, keysym: u32, modifiers: Modifiers) -> bool {
+        let hotkey = Hotkey::new(keysym, modifiers);
+        self.iter().any(|h| *h == hotkey)
+    }
+
+    /// All the hotkeys
+    pub fn iter(&self) -> impl Iterator<Item = &Hotkey> {
+        self.triggers
+            .iter()
+            .chain(&self.next_engine)
+            .chain(&self.previous_engine)
+    }
+
+    /// A profile with the `trigger`, `next-engine` and `previous-engine`
+    /// events
+    pub fn to_profile(&self) -> HotkeyProfile {
+        let mut profile = HotkeyProfile::new();
+        for (hotkeys, event) in [
+            (&self.triggers, "trigger"),
+            (&self.next_engine, "next-engine"),
+            (&self.previous_engine, "previous-engine"),
+        ] {
+            for hotkey in hotkeys {
+                profile.add_hotkey(*hotkey, event);
+            }
+        }
+        profile
+    }
+
+    /// Reads the hotkeys, and calls `callback` with them initially, and
+    /// whenever the user changes them
+    ///
+    /// The callback runs while the connection of the bus processes messages.
+    pub fn watch<F>(config: &Config, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(&SwitchHotkeys) + Send + 'static,
+    {
+        let mut settings = DesktopSettings::from_config(config);
+        let mut hotkeys = settings.switch_hotkeys();
+        callback(&hotkeys);
+        config.on_value_changed(move |signal, _, _| {
+            if signal.section != HOTKEY {
+                return AfterCallback::Keep;
+            }
+            match &signal.value {
+                Some(value) => settings.set(HOTKEY, &signal.name, Value::from_ref_arg(&**value)),
+                None => settings.reset(HOTKEY, &signal.name),
+            }
+            let changed = settings.switch_hotkeys();
+            if changed != hotkeys {
+                hotkeys = changed;
+                callback(&hotkeys);
+            }
+            AfterCallback::Keep
+        })
+    }
+}
+
+/// The types of values used by the settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Bool(bool),
+    Int(i64),
+    Strings(Vec<String>),
+    Other,
+}
+impl Value {
+    fn from_ref_arg(value: &dyn RefArg) -> Self {
+        match &*value.signature() {
+            "b" => Value::Bool(value.as_u64() == Some(1)),
+            "i" | "u" | "x" | "t" | "n" | "q" | "y" => {
+                value.as_i64().map_or(Value::Other, Value::Int)
+            }
+            "as" => Value::Strings(
+                value
+                    .as_iter()
+                    .map(|items| {
+                        items
+                            .filter_map(|s| s.as_str().map(str::to_owned))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            ),
+            _ => Value::Other,
+        }
+    }
+
+    /// Parses the text format of GVariant, as printed by `gsettings`, for the
+    /// types above
+    #[cfg_attr(not(feature = "gsettings"), allow(dead_code))]
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        // Empty arrays are printed with their type, e.g. `@as []`
+        let text = match text.strip_prefix('@') {
+            Some(typed) => typed.split_once(' ')?.1,
+            None => text,
+        };
+        match text {
+            "true" => return Some(Value::Bool(true)),
+            "false" => return Some(Value::Bool(false)),
+            _ => {}
+        }
+        // Integers other than int32 are printed with their type
+        let number = text.rsplit(' ').next().unwrap_or(text);
+        if let Ok(i) = number.parse() {
+            return Some(Value::Int(i));
+        }
+        let items = text.strip_prefix('[')?.strip_suffix(']')?;
+        parse_strings(items).map(Value::Strings)
+    }
+}
+
+/// Parses a comma separated list of quoted strings
+#[cfg_attr(not(feature = "gsettings"), allow(dead_code))]
+fn parse_strings(items: &str) -> Option<Vec<String>> {
+    let mut strings = Vec::new();
+    let mut chars = items.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+        let quote = match chars.next() {
+            Some(quote @ ('\'' | '"')) => quote,
+            Some(_) => return None,
+            None => return Some(strings),
+        };
+        let mut s = String::new();
+        loop {
+            match chars.next()? {
+                '\\' => s.push(chars.next()?),
+                c if c == quote => break,
+                c => s.push(c),
+            }
+        }
+        strings.push(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keysyms;
+
+    #[test]
+    fn gsettings_output() {
+        let mut settings = DesktopSettings::default();
+        settings.apply_gsettings_output(
+            "org.freedesktop.ibus.general preload-engines ['xkb:us::eng', 'anthy']\n\
+             org.freedesktop.ibus.general engines-order @as []\n\
+             org.freedesktop.ibus.general embed-preedit-text false\n\
+             org.freedesktop.ibus.general.hotkey triggers ['<Super>space', \"<Control>it\\'s\"]\n\
+             org.freedesktop.ibus.panel lookup-table-orientation 0\n\
+             org.freedesktop.ibus.panel custom-font 'Sans 10'\n",
+        );
+        assert_eq!(settings.preload_engines, ["xkb:us::eng", "anthy"]);
+        assert!(settings.engines_order.is_empty());
+        assert!(!settings.embed_preedit_text);
+        assert_eq!(settings.triggers, ["<Super>space", "<Control>it's"]);
+        assert_eq!(settings.lookup_table_orientation, Orientation::Horizontal);
+
+        let hotkeys = settings.switch_hotkeys();
+        assert!(hotkeys.contains(keysyms::KEY_space, Modifiers::MOD4));
+        assert!(!hotkeys.contains(keysyms::KEY_space, Modifiers::CONTROL));
+        // `<Control>it's` isn't a valid accelerator
+        assert_eq!(hotkeys.iter().count(), 1);
+        assert_eq!(
+            hotkeys.to_profile().filter_key_event(
+                keysyms::KEY_space,
+                Modifiers::SUPER,
+                0,
+                Modifiers::empty()
+            ),
+            Some("trigger")
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use dbus::{Message, MessageType};
+use log::warn;
+
+use crate::{input_context::INTERFACE_NAME, Capabilites};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The capabilities set on each input context, by path
+static NEGOTIATED: Mutex<Option<HashMap<String, Capabilites>>> = Mutex::new(None);
+
+/// See `Bus::set_diagnostics`
+pub(crate) fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        *negotiated() = None;
+    }
+}
+
+fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn negotiated() -> std::sync::MutexGuard<'static, Option<HashMap<String, Capabilites>>> {
+    NEGOTIATED.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn capabilities(path: &str) -> Option<Capabilites> {
+    negotiated().as_ref()?.get(path).copied()
+}
+
+pub(crate) fn set_capabilities(path: &str, caps: Capabilites) {
+    if is_enabled() {
+        negotiated()
+            .get_or_insert_with(HashMap::new)
+            .insert(path.to_owned(), caps);
+    }
+}
+
+/// The capabilities that the engine needs to handle keys in the usual way
+const KEY_CAPABILITIES: Capabilites =
+    Capabilites::from_bits_truncate(Capabilites::PREEDIT_TEXT.bits() | Capabilites::FOCUS.bits());
+
+pub(crate) fn key_not_handled(path: &str) {
+    if !is_enabled() {
+        return;
+    }
+    match capabilities(path) {
+        None => warn!(
+            target: "ibus::diagnostics",
+            "ProcessKeyEvent wasn't handled on {}, and set_capabilities was never called \
+             on it; most engines need {:?}",
+            path,
+            KEY_CAPABILITIES
+        ),
+        Some(caps) if !caps.contains(KEY_CAPABILITIES) => warn!(
+            target: "ibus::diagnostics",
+            "ProcessKeyEvent wasn't handled on {}, {:?} not negotiated",
+            path,
+            KEY_CAPABILITIES - caps
+        ),
+        Some(_) => {}
+    }
+}
+
+/// The capability that the signal relies on
+fn required(member: &str) -> Option<Capabilites> {
+    Some(match member {
+        "RequireSurroundingText" | "DeleteSurroundingText" => Capabilites::SURROUNDING_TEXT,
+        "UpdatePreeditText" | "ShowPreeditText" => Capabilites::PREEDIT_TEXT,
+        "UpdateAuxiliaryText" | "ShowAuxiliaryText" => Capabilites::AUXILIARY_TEXT,
+        "UpdateLookupTable" | "ShowLookupTable" => Capabilites::LOOKUP_TABLE,
+        "RegisterProperties" | "UpdateProperty" => Capabilites::PROPERTY,
+        _ => return None,
+    })
+}
+
+pub(crate) fn received(msg: &Message) {
+    if !is_enabled()
+        || msg.msg_type() != MessageType::Signal
+        || msg.interface().as_deref() != Some(INTERFACE_NAME)
+    {
+        return;
+    }
+    let (path, member) = match (msg.path(), msg.member()) {
+        (Some(path), Some(member)) => (path, member),
+        _ => return,
+    };
+    let required = match required(&member) {
+        Some(required) => required,
+        None => return,
+    };
+    if let Some(caps) = capabilities(&path) {
+        if let Some(message) = mismatch(&member, required, caps) {
+            warn!(target: "ibus::diagnostics", "{} on {}", message, &*path);
+        }
+    }
+}
+
+fn mismatch(member: &str, required: Capabilites, caps: Capabilites) -> Option<String> {
+    (!caps.contains(required)).then(|| {
+        format!(
+            "{:?} not negotiated; the engine sent {} which relies on it",
+            required, member
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mismatches() {
+        let caps = Capabilites::PREEDIT_TEXT | Capabilites::FOCUS;
+        assert_eq!(
+            mismatch(
+                "RequireSurroundingText",
+                required("RequireSurroundingText").unwrap(),
+                caps
+            )
+            .unwrap(),
+            "SURROUNDING_TEXT not negotiated; the engine sent RequireSurroundingText which relies on it"
+        );
+        let preedit = required("UpdatePreeditText").unwrap();
+        assert_eq!(mismatch("UpdatePreeditText", preedit, caps), None);
+        assert_eq!(required("CommitText"), None);
+    }
+}
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+
+thread_local! {
+    /// The connections that are dispatching on this thread
+    static DISPATCHING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks a connection as dispatching on the current thread, until dropped
+///
+/// A callback may call `process` of its own connection, e.g. through a
+/// helper that waits for something. Dispatching from there would run the
+/// callbacks nested in each other, out of order, and would deadlock on the
+/// state that the outer callback has locked. So the nested `process` doesn't
+/// dispatch, the messages are left for the outer one.
+pub(crate) struct DispatchScope {
+    conn: usize,
+}
+impl DispatchScope {
+    /// Returns `None` if `conn` is already dispatching on this thread
+    pub(crate) fn enter<T>(conn: *const T) -> Option<Self> {
+        let conn = conn as usize;
+        DISPATCHING.with(|dispatching| {
+            let mut dispatching = dispatching.borrow_mut();
+            if dispatching.contains(&conn) {
+                return None;
+            }
+            dispatching.push(conn);
+            Some(DispatchScope { conn })
+        })
+    }
+}
+impl Drop for DispatchScope {
+    fn drop(&mut self) {
+        DISPATCHING.with(|dispatching| dispatching.borrow_mut().retain(|c| *c != self.conn));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_scopes() {
+        let (a, b) = (1u8, 2u8);
+        let outer = DispatchScope::enter(&a).unwrap();
+        assert!(DispatchScope::enter(&a).is_none());
+        let other = DispatchScope::enter(&b).unwrap();
+        drop(other);
+        drop(outer);
+        assert!(DispatchScope::enter(&a).is_some());
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+use dbus::{arg::ReadAll, channel::Channel, Message, MessageType};
+use log::debug;
+
+use crate::{body_signature, instrument::CallSpan, metrics, REQ_TIMEOUT};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// See `Bus::set_message_dump`
+pub(crate) fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub(crate) fn sent(msg: &Message) {
+    if is_enabled() {
+        debug!(target: "ibus::dump", "-> {}", Pretty(msg));
+    }
+}
+
+pub(crate) fn received(msg: &Message) {
+    crate::instrument::received(msg);
+    crate::diagnostics::received(msg);
+    if msg.msg_type() == MessageType::Signal {
+        if let Some(member) = msg.member() {
+            metrics::signal_received(&member);
+        }
+    }
+    if is_enabled() {
+        debug!(target: "ibus::dump", "<- {}", Pretty(msg));
+    }
+}
+
+/// Sends a method call and waits for its reply, dumping both and measuring
+/// the latency
+pub(crate) fn call<R: ReadAll>(channel: &Channel, msg: Message) -> Result<R, dbus::Error> {
+    sent(&msg);
+    let member = msg.member().map(|m| m.to_string()).unwrap_or_default();
+    let span = CallSpan::new(&msg);
+    let start = Instant::now();
+    let reply = channel.send_with_reply_and_block(msg, REQ_TIMEOUT);
+    metrics::round_trip(&member, start.elapsed());
+    span.finish(reply.as_ref().err());
+    let reply = reply.inspect_err(|e| {
+        if is_enabled() {
+            debug!(
+                target: "ibus::dump",
+                "<- error {}: {}",
+                e.name().unwrap_or("unknown"),
+                e.message().unwrap_or("")
+            );
+        }
+    })?;
+    received(&reply);
+    Ok(R::read(&mut reply.iter_init())?)
+}
+
+/// A message in the format of `dbus-monitor`, without the time and with the
+/// arguments as the crate decodes them
+struct Pretty<'a>(&'a Message);
+impl fmt::Display for Pretty<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = self.0;
+        let kind = match msg.msg_type() {
+            MessageType::MethodCall => "method call",
+            MessageType::MethodReturn => "method return",
+            MessageType::Error => "error",
+            MessageType::Signal => "signal",
+        };
+        write!(f, "{}", kind)?;
+        if let Some(sender) = msg.sender() {
+            write!(f, " sender={}", sender)?;
+        }
+        if let Some(destination) = msg.destination() {
+            write!(f, " -> destination={}", destination)?;
+        }
+        if let Some(serial) = msg.get_serial() {
+            write!(f, " serial={}", serial)?;
+        }
+        if let Some(reply_serial) = msg.get_reply_serial() {
+            write!(f, " reply_serial={}", reply_serial)?;
+        }
+        if let Some(path) = msg.path() {
+            write!(f, " path={};", path)?;
+        }
+        if let Some(interface) = msg.interface() {
+            write!(f, " interface={};", interface)?;
+        }
+        if let Some(member) = msg.member() {
+            write!(f, " member={}", member)?;
+        }
+        write!(f, " signature={}", body_signature(msg))?;
+        let mut args = msg.iter_init();
+        while let Some(arg) = args.get_refarg() {
+            write!(f, "\n   {:?}", arg)?;
+            args.next();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_message() {
+        let msg = Message::new_signal(
+            "/ic/1",
+            "org.freedesktop.IBus.InputContext",
+            "ForwardKeyEvent",
+        )
+        .unwrap()
+        .append3(0x61u32, 30u32, 0u32);
+        let pretty = Pretty(&msg).to_string();
+        assert!(pretty.starts_with("signal "));
+        assert!(pretty.contains(
+            " path=/ic/1; interface=org.freedesktop.IBus.InputContext; member=ForwardKeyEvent signature=uuu"
+        ));
+        assert_eq!(pretty.lines().count(), 4);
+    }
+}
@@ -0,0 +1,1953 @@
+//! Writing input method engines
+//!
+//! An engine is the part of an input method that turns key presses into
+//! text. The daemon forwards the key events of the focused input context to
+//! the active engine, and the engine answers with signals (e.g. "CommitText").
+//!
+//! Implement the `Engine` trait and export it on the bus using an
+//! `EngineHost`. The method calls are handled while calling `Bus::process`.
+//!
+//! Some ready-made engines are available in the submodules.
+//!
+
+pub mod asynchronous;
+pub mod candidates;
+pub mod compose;
+pub mod reconvert;
+pub mod table;
+pub mod testing;
+pub mod transliterate;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    ffi::CString,
+    path::PathBuf,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use log::{debug, warn};
+
+use dbus::{
+    arg::{PropMap, RefArg, TypeMismatchError, Variant},
+    blocking::Connection,
+    channel::{MatchingReceiver, Sender, Token},
+    message::MatchRule,
+    strings::{ErrorName, Path},
+    Message,
+};
+
+use crate::{
+    config::{get_values, is_unset_value, CONFIG_INTERFACE, CONFIG_NAME, CONFIG_PATH},
+    Bus, Capabilites, Component, Error, InputHints, InputPurpose, LookupTable, Modifiers, PropList,
+    PropState, Property, Text, INPUT_MODE_PROP,
+};
+
+pub(crate) const ENGINE_INTERFACE: &str = "org.freedesktop.IBus.Engine";
+pub(crate) const FACTORY_INTERFACE: &str = "org.freedesktop.IBus.Factory";
+pub(crate) const SERVICE_INTERFACE: &str = "org.freedesktop.IBus.Service";
+pub(crate) const FACTORY_PATH: &str = "/org/freedesktop/IBus/Factory";
+const ENGINE_PATH_PREFIX: &str = "/org/freedesktop/IBus/Engine";
+
+/// An input method engine
+///
+/// The engine receives a `EngineContext` in every call, which can be used to
+/// send text and other updates to the input context that the engine is
+/// serving.
+pub trait Engine: Send {
+    /// Returns true if the key event was handled by the engine. Unhandled key
+    /// events are passed on to the application.
+    fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        code: u32,
+        modifiers: Modifiers,
+    ) -> bool;
+
+    /// Called when the user activates one of the properties that the engine
+    /// registered, e.g. by clicking it in the panel
+    fn property_activate(&mut self, ctx: &mut EngineContext, name: &str, state: PropState) {
+        let _ = (ctx, name, state);
+    }
+
+    /// Called when the panel starts showing one of the registered
+    /// properties, e.g. because the menu containing it was opened
+    fn property_show(&mut self, ctx: &mut EngineContext, name: &str) {
+        let _ = (ctx, name);
+    }
+
+    /// Called when the panel stops showing the property
+    fn property_hide(&mut self, ctx: &mut EngineContext, name: &str) {
+        let _ = (ctx, name);
+    }
+
+    /// Called when the input context that the engine is serving gets focus
+    fn focus_in(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the input context that the engine is serving loses focus
+    fn focus_out(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the composition should be abandoned, e.g. because the
+    /// user clicked somewhere else in the text
+    fn reset(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the engine is switched on
+    fn enable(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the engine is switched off
+    fn disable(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called right before the engine is removed from the bus
+    fn destroy(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the user asks for the previous page of candidates, e.g.
+    /// from the candidate window
+    fn page_up(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the user asks for the next page of candidates
+    fn page_down(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the user asks for the previous candidate
+    fn cursor_up(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the user asks for the next candidate
+    fn cursor_down(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the user clicks a candidate in the candidate window
+    ///
+    /// `index` is relative to the start of the current page.
+    fn candidate_clicked(
+        &mut self,
+        ctx: &mut EngineContext,
+        index: u32,
+        button: u32,
+        state: Modifiers,
+    ) {
+        let _ = (ctx, index, button, state);
+    }
+
+    /// Called when the user finishes drawing a stroke in a handwriting
+    /// input window
+    ///
+    /// The stroke is a list of points, with coordinates between 0 and 1
+    /// relative to the drawing area. `HandwritingStrokes` can be used to
+    /// collect the strokes.
+    fn process_hand_writing_event(&mut self, ctx: &mut EngineContext, stroke: &[(f64, f64)]) {
+        let _ = (ctx, stroke);
+    }
+
+    /// Called when the user removes the last `n_strokes` strokes. 0 means
+    /// removing all of them.
+    fn cancel_hand_writing(&mut self, ctx: &mut EngineContext, n_strokes: u32) {
+        let _ = (ctx, n_strokes);
+    }
+
+    /// Called when the position of the cursor changes
+    ///
+    /// The coordinates are in physical pixels, relative to the top left
+    /// corner of the screen.
+    fn set_cursor_location(&mut self, ctx: &mut EngineContext, x: i32, y: i32, w: i32, h: i32) {
+        let _ = (ctx, x, y, w, h);
+    }
+
+    /// Same as `set_cursor_location`, but the coordinates are relative to the
+    /// window of the application. This is used by applications on Wayland,
+    /// where the position of the window is unknown.
+    fn set_cursor_location_relative(
+        &mut self,
+        ctx: &mut EngineContext,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+    ) {
+        let _ = (ctx, x, y, w, h);
+    }
+
+    /// Called when the application sets its capabilities. The current value
+    /// is also available through `EngineContext::capabilities`.
+    fn set_capabilities(&mut self, ctx: &mut EngineContext, caps: Capabilites) {
+        let _ = (ctx, caps);
+    }
+
+    /// Called when the application reports the text around the cursor, which
+    /// is then available through `EngineContext::surrounding_text`
+    fn surrounding_text_changed(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the application describes the purpose of the focused text
+    /// field. Engines may for example want to disable themselves in password
+    /// fields. The current value is also available through
+    /// `EngineContext::content_type`.
+    fn set_content_type(
+        &mut self,
+        ctx: &mut EngineContext,
+        purpose: InputPurpose,
+        hints: InputHints,
+    ) {
+        let _ = (ctx, purpose, hints);
+    }
+
+    /// Called when a setting in the section of the engine changes, e.g.
+    /// because the user edited it in the setup dialog of the engine
+    ///
+    /// The new value is available through `EngineContext::config_value`. It's
+    /// `None` if the setting was removed.
+    fn config_changed(&mut self, ctx: &mut EngineContext, name: &str) {
+        let _ = (ctx, name);
+    }
+
+    /// Called by `EngineHost::poll`, and before every method call that the
+    /// engine receives
+    ///
+    /// Engines that do work outside of the method calls (e.g. on another
+    /// thread) can use this for delivering the results.
+    fn poll(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+}
+
+/// What happens to the preedit text when the input context loses focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreeditFocusMode {
+    /// The preedit text is discarded
+    Clear,
+    /// The preedit text is committed
+    Commit,
+}
+impl PreeditFocusMode {
+    fn to_value(self) -> u32 {
+        match self {
+            Self::Clear => 0,
+            Self::Commit => 1,
+        }
+    }
+}
+
+/// A signal emitted by an engine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineSignal {
+    CommitText(Text<'static>),
+    UpdatePreeditText {
+        text: Text<'static>,
+        cursor_pos: u32,
+        visible: bool,
+        mode: PreeditFocusMode,
+    },
+    ShowPreeditText,
+    HidePreeditText,
+    UpdateAuxiliaryText {
+        text: Text<'static>,
+        visible: bool,
+    },
+    ShowAuxiliaryText,
+    HideAuxiliaryText,
+    UpdateLookupTable {
+        table: LookupTable,
+        visible: bool,
+    },
+    ShowLookupTable,
+    HideLookupTable,
+    ForwardKeyEvent {
+        sym: u32,
+        code: u32,
+        modifiers: Modifiers,
+    },
+    RequireSurroundingText,
+    RegisterProperties(PropList),
+    UpdateProperty(Property),
+    DeleteSurroundingText {
+        offset: i32,
+        nchars: u32,
+    },
+}
+impl EngineSignal {
+    pub(crate) fn to_message(&self, path: &Path<'static>) -> Message {
+        self.message(path, ENGINE_INTERFACE)
+    }
+
+    /// The signal as the daemon relays it to the application, where
+    /// `UpdatePreeditText` doesn't have the mode
+    #[cfg(feature = "testing")]
+    pub(crate) fn to_input_context_message(&self, path: &Path<'static>) -> Message {
+        let interface = crate::input_context::INTERFACE_NAME;
+        match self {
+            EngineSignal::UpdatePreeditText {
+                text,
+                cursor_pos,
+                visible,
+                ..
+            } => Message::new_signal(path.to_string(), interface, "UpdatePreeditText")
+                .expect("the signal name should be valid")
+                .append3(text, *cursor_pos, *visible),
+            signal => signal.message(path, interface),
+        }
+    }
+
+    fn message(&self, path: &Path<'static>, interface: &str) -> Message {
+        let signal = |name: &str| {
+            Message::new_signal(path.to_string(), interface, name)
+                .expect("the engine signal name should be valid")
+        };
+        match self {
+            EngineSignal::CommitText(text) => signal("CommitText").append1(text),
+            EngineSignal::UpdatePreeditText {
+                text,
+                cursor_pos,
+                visible,
+                mode,
+            } => signal("UpdatePreeditText")
+                .append3(text, *cursor_pos, *visible)
+                .append1(mode.to_value()),
+            EngineSignal::ShowPreeditText => signal("ShowPreeditText"),
+            EngineSignal::HidePreeditText => signal("HidePreeditText"),
+            EngineSignal::UpdateAuxiliaryText { text, visible } => {
+                signal("UpdateAuxiliaryText").append2(text, *visible)
+            }
+            EngineSignal::ShowAuxiliaryText => signal("ShowAuxiliaryText"),
+            EngineSignal::HideAuxiliaryText => signal("HideAuxiliaryText"),
+            EngineSignal::UpdateLookupTable { table, visible } => {
+                signal("UpdateLookupTable").append2(table, *visible)
+            }
+            EngineSignal::ShowLookupTable => signal("ShowLookupTable"),
+            EngineSignal::HideLookupTable => signal("HideLookupTable"),
+            EngineSignal::ForwardKeyEvent {
+                sym,
+                code,
+                modifiers,
+            } => signal("ForwardKeyEvent").append3(*sym, *code, modifiers.bits()),
+            EngineSignal::RequireSurroundingText => signal("RequireSurroundingText"),
+            EngineSignal::RegisterProperties(props) => signal("RegisterProperties").append1(props),
+            EngineSignal::UpdateProperty(prop) => signal("UpdateProperty").append1(prop),
+            EngineSignal::DeleteSurroundingText { offset, nchars } => {
+                signal("DeleteSurroundingText").append2(*offset, *nchars)
+            }
+        }
+    }
+}
+
+/// The text around the cursor in the application, as reported by the
+/// application
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SurroundingText {
+    pub text: Text<'static>,
+
+    /// Counting in UTF32 characters
+    pub cursor_pos: u32,
+
+    /// The other end of the selection, or the same as `cursor_pos` if nothing
+    /// is selected. Counting in UTF32 characters.
+    pub anchor_pos: u32,
+}
+
+/// The strokes drawn for handwriting recognition
+///
+/// Engines add the strokes they receive in
+/// `Engine::process_hand_writing_event`, and remove them in
+/// `Engine::cancel_hand_writing`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HandwritingStrokes {
+    pub strokes: Vec<Vec<(f64, f64)>>,
+}
+impl HandwritingStrokes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, stroke: &[(f64, f64)]) {
+        self.strokes.push(stroke.to_vec());
+    }
+
+    /// Removes the last `n_strokes` strokes, or all of them if `n_strokes`
+    /// is 0, the same way as the argument of `Engine::cancel_hand_writing`
+    pub fn cancel(&mut self, n_strokes: u32) {
+        let keep = match n_strokes {
+            0 => 0,
+            n => self.strokes.len().saturating_sub(n as usize),
+        };
+        self.strokes.truncate(keep);
+    }
+
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty()
+    }
+}
+
+/// Gives an engine access to the input context it's serving
+pub struct EngineContext {
+    path: Path<'static>,
+    pending: Vec<EngineSignal>,
+    surrounding_text: Option<SurroundingText>,
+    capabilities: Capabilites,
+    content_type: (InputPurpose, InputHints),
+    defer_key_reply: bool,
+    pending_key_reply: Option<(Message, Instant)>,
+    replies: Vec<Message>,
+    /// The answered key events and their latency, for `EngineMetric::KeyEvent`
+    key_events: Vec<(bool, Duration)>,
+    states: HashMap<TypeId, Box<dyn Any + Send>>,
+    config_section: Option<String>,
+    config: HashMap<String, Box<dyn RefArg>>,
+    config_writes: Vec<Message>,
+    properties: PropList,
+}
+impl EngineContext {
+    pub(crate) fn new(path: Path<'static>) -> Self {
+        EngineContext {
+            path,
+            pending: Vec::new(),
+            surrounding_text: None,
+            capabilities: Capabilites::empty(),
+            content_type: (InputPurpose::FreeForm, InputHints::empty()),
+            defer_key_reply: false,
+            pending_key_reply: None,
+            replies: Vec::new(),
+            key_events: Vec::new(),
+            states: HashMap::new(),
+            config_section: None,
+            config: HashMap::new(),
+            config_writes: Vec::new(),
+            properties: PropList::new(),
+        }
+    }
+
+    /// The section of `org.freedesktop.IBus.Config` that stores the settings
+    /// of this engine, like "engine/mylang"
+    ///
+    /// This is `None` for engines that weren't created by a factory, because
+    /// their name isn't known.
+    pub fn config_section(&self) -> Option<&str> {
+        self.config_section.as_deref()
+    }
+
+    /// Returns a setting from the section of the engine
+    ///
+    /// The settings are loaded when the engine is created, and kept up to
+    /// date as they change, so this doesn't block.
+    pub fn config_value(&self, name: &str) -> Option<&(dyn RefArg + 'static)> {
+        self.config.get(name).map(|value| value.as_ref())
+    }
+
+    /// Returns a setting of a basic type, like `bool`, `i32`, or `String`.
+    /// Returns `None` if the setting doesn't exist or has a different type.
+    ///
+    /// Use `config_value` for arrays and other container types.
+    pub fn config<T: Clone + 'static>(&self, name: &str) -> Option<T> {
+        dbus::arg::cast::<T>(self.config_value(name)?).cloned()
+    }
+
+    /// Changes a setting in the section of the engine
+    ///
+    /// The change is sent to the config service together with the signals
+    /// of the engine. Does nothing if the engine has no config section.
+    pub fn set_config(&mut self, name: &str, value: impl RefArg + 'static) {
+        let section = match &self.config_section {
+            Some(section) => section.clone(),
+            None => {
+                debug!("The engine has no config section, can't set `{}`", name);
+                return;
+            }
+        };
+        let value: Box<dyn RefArg> = Box::new(value);
+        let mut msg =
+            config_call("SetValue").append3(section.as_str(), name, Variant(value.box_clone()));
+        msg.set_no_reply(true);
+        self.config_writes.push(msg);
+        self.config.insert(name.to_owned(), value);
+    }
+
+    /// Removes a setting from the section of the engine
+    pub fn unset_config(&mut self, name: &str) {
+        let section = match &self.config_section {
+            Some(section) => section.clone(),
+            None => return,
+        };
+        let mut msg = config_call("UnsetValue").append2(section.as_str(), name);
+        msg.set_no_reply(true);
+        self.config_writes.push(msg);
+        self.config.remove(name);
+    }
+
+    /// Returns the state of type `S` that belongs to this input context,
+    /// creating it with `S::default()` if it doesn't exist yet
+    ///
+    /// Every engine instance has its own context, so the state isn't shared
+    /// between text fields even if the engines share everything else. The
+    /// state is dropped together with the engine. Different types are stored
+    /// separately, so several components of an engine can keep their own
+    /// state.
+    pub fn state<S: Default + Send + 'static>(&mut self) -> &mut S {
+        self.states
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| Box::new(S::default()))
+            .downcast_mut()
+            .unwrap()
+    }
+
+    /// Returns the state of type `S` if it exists
+    pub fn get_state<S: Send + 'static>(&mut self) -> Option<&mut S> {
+        self.states
+            .get_mut(&TypeId::of::<S>())
+            .and_then(|state| state.downcast_mut())
+    }
+
+    /// Replaces the state of type `S`
+    pub fn set_state<S: Send + 'static>(&mut self, state: S) {
+        self.states.insert(TypeId::of::<S>(), Box::new(state));
+    }
+
+    /// Removes the state of type `S` and returns it
+    pub fn take_state<S: Send + 'static>(&mut self) -> Option<S> {
+        self.states
+            .remove(&TypeId::of::<S>())
+            .and_then(|state| state.downcast().ok())
+            .map(|state| *state)
+    }
+
+    /// The capabilities of the application
+    #[inline]
+    pub fn capabilities(&self) -> Capabilites {
+        self.capabilities
+    }
+
+    /// The purpose and hints of the focused text field
+    #[inline]
+    pub fn content_type(&self) -> (InputPurpose, InputHints) {
+        self.content_type
+    }
+
+    /// The object path of the engine on the bus
+    #[inline]
+    pub fn object_path(&self) -> &Path<'static> {
+        &self.path
+    }
+
+    /// Queues a signal to be sent
+    ///
+    /// The queued signals are sent right before the reply to the method call
+    /// that's currently being handled.
+    pub fn emit(&mut self, signal: EngineSignal) {
+        self.pending.push(signal);
+    }
+
+    /// Sends text to the application
+    pub fn commit_text(&mut self, text: impl Into<Text<'static>>) {
+        self.emit(EngineSignal::CommitText(text.into()));
+    }
+
+    /// Sets the text that's being composed
+    ///
+    /// `cursor_pos` is counting in UTF32 characters. The preedit text is
+    /// discarded when the input context loses focus; see
+    /// `update_preedit_text_with_mode` for committing it instead.
+    pub fn update_preedit_text(
+        &mut self,
+        text: impl Into<Text<'static>>,
+        cursor_pos: u32,
+        visible: bool,
+    ) {
+        self.update_preedit_text_with_mode(text, cursor_pos, visible, PreeditFocusMode::Clear);
+    }
+
+    /// Same as `update_preedit_text` but `mode` specifies what happens with the
+    /// preedit text when the input context loses focus
+    pub fn update_preedit_text_with_mode(
+        &mut self,
+        text: impl Into<Text<'static>>,
+        cursor_pos: u32,
+        visible: bool,
+        mode: PreeditFocusMode,
+    ) {
+        self.emit(EngineSignal::UpdatePreeditText {
+            text: text.into(),
+            cursor_pos,
+            visible,
+            mode,
+        });
+    }
+
+    pub fn show_preedit_text(&mut self) {
+        self.emit(EngineSignal::ShowPreeditText);
+    }
+
+    pub fn hide_preedit_text(&mut self) {
+        self.emit(EngineSignal::HidePreeditText);
+    }
+
+    /// Sets the auxiliary text, which is usually displayed above the
+    /// candidates (e.g. the reading of the selected candidate)
+    pub fn update_auxiliary_text(&mut self, text: impl Into<Text<'static>>, visible: bool) {
+        self.emit(EngineSignal::UpdateAuxiliaryText {
+            text: text.into(),
+            visible,
+        });
+    }
+
+    pub fn show_auxiliary_text(&mut self) {
+        self.emit(EngineSignal::ShowAuxiliaryText);
+    }
+
+    pub fn hide_auxiliary_text(&mut self) {
+        self.emit(EngineSignal::HideAuxiliaryText);
+    }
+
+    /// Sets the candidates shown in the candidate window
+    pub fn update_lookup_table(&mut self, table: LookupTable, visible: bool) {
+        self.emit(EngineSignal::UpdateLookupTable { table, visible });
+    }
+
+    pub fn show_lookup_table(&mut self) {
+        self.emit(EngineSignal::ShowLookupTable);
+    }
+
+    pub fn hide_lookup_table(&mut self) {
+        self.emit(EngineSignal::HideLookupTable);
+    }
+
+    /// Sends a key event to the application as if the engine wasn't there
+    ///
+    /// This is useful for passing keys through after processing them, e.g.
+    /// committing the preedit text on Return and then forwarding the Return
+    /// itself.
+    pub fn forward_key_event(&mut self, sym: u32, code: u32, modifiers: Modifiers) {
+        self.emit(EngineSignal::ForwardKeyEvent {
+            sym,
+            code,
+            modifiers,
+        });
+    }
+
+    /// Asks the application to delete `nchars` characters of the text around
+    /// the cursor, starting `offset` characters from the cursor (negative
+    /// values are before the cursor)
+    ///
+    /// The text returned by `surrounding_text` is updated accordingly, until
+    /// the application reports the new surrounding text.
+    pub fn delete_surrounding_text(&mut self, offset: i32, nchars: u32) {
+        if let Some(surrounding) = &mut self.surrounding_text {
+            let chars: Vec<char> = surrounding.text.as_str().chars().collect();
+            let start = (surrounding.cursor_pos as i64 + offset as i64).clamp(0, chars.len() as i64)
+                as usize;
+            let end = (start + nchars as usize).min(chars.len());
+            let text: String = chars[..start].iter().chain(&chars[end..]).collect();
+            *surrounding = SurroundingText {
+                text: text.into(),
+                cursor_pos: start as u32,
+                anchor_pos: start as u32,
+            };
+        }
+        self.emit(EngineSignal::DeleteSurroundingText { offset, nchars });
+    }
+
+    /// Returns the text around the cursor, if the application has reported it
+    ///
+    /// Applications only report the surrounding text if they support the
+    /// `SURROUNDING_TEXT` capability. Call `require_surrounding_text` to ask
+    /// the application for it.
+    #[inline]
+    pub fn surrounding_text(&self) -> Option<&SurroundingText> {
+        self.surrounding_text.as_ref()
+    }
+
+    /// Tells the application that the engine needs the surrounding text
+    pub fn require_surrounding_text(&mut self) {
+        self.emit(EngineSignal::RequireSurroundingText);
+    }
+
+    /// Sets the properties that the panel shows for this engine
+    ///
+    /// This is usually done whenever the engine gets focus, because the panel
+    /// shows the properties of the focused engine.
+    pub fn register_properties(&mut self, props: PropList) {
+        self.properties = props.clone();
+        self.emit(EngineSignal::RegisterProperties(props));
+    }
+
+    /// Updates one of the registered properties, identified by its key
+    pub fn update_property(&mut self, prop: Property) {
+        self.properties.update_property(&prop);
+        self.emit(EngineSignal::UpdateProperty(prop));
+    }
+
+    /// The properties registered with `register_properties`, with the updates
+    /// applied
+    pub fn properties(&self) -> &PropList {
+        &self.properties
+    }
+
+    /// Shows the current input mode in the panel, e.g. "あ" for Hiragana
+    ///
+    /// This updates the symbol and label of the `INPUT_MODE_PROP` property,
+    /// and registers the property first if needed. Set the `icon_prop_key` of
+    /// the engine to `INPUT_MODE_PROP` so that the IBus panel shows it too.
+    ///
+    /// ```
+    /// # use ibus::engine::{Engine, EngineContext};
+    /// # use ibus::Modifiers;
+    /// struct Kana {
+    ///     katakana: bool,
+    /// }
+    /// impl Engine for Kana {
+    ///     # fn process_key_event(&mut self, _: &mut EngineContext, _: u32, _: u32, _: Modifiers) -> bool {
+    ///     #     false
+    ///     # }
+    ///     fn focus_in(&mut self, ctx: &mut EngineContext) {
+    ///         if self.katakana {
+    ///             ctx.set_input_mode("ア", "Katakana");
+    ///         } else {
+    ///             ctx.set_input_mode("あ", "Hiragana");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn set_input_mode(
+        &mut self,
+        symbol: impl Into<Text<'static>>,
+        label: impl Into<Text<'static>>,
+    ) {
+        match self.properties.get(INPUT_MODE_PROP) {
+            Some(prop) => {
+                let mut prop = prop.clone();
+                prop.symbol = symbol.into();
+                prop.label = label.into();
+                self.update_property(prop);
+            }
+            None => {
+                let mut props = self.properties.clone();
+                props
+                    .properties
+                    .insert(0, Property::input_mode(symbol, label));
+                self.register_properties(props);
+            }
+        }
+    }
+
+    /// Makes the daemon wait for the result of the current key event
+    ///
+    /// Only has an effect when called from `Engine::process_key_event`. The
+    /// value returned from there is ignored, and the key event stays pending
+    /// until `finish_key_event` is called. Other method calls are still
+    /// delivered in the meantime.
+    pub fn defer_key_event_reply(&mut self) {
+        self.defer_key_reply = true;
+    }
+
+    /// Answers the key event that was deferred with `defer_key_event_reply`
+    ///
+    /// Returns false if there was no deferred key event.
+    pub fn finish_key_event(&mut self, handled: bool) -> bool {
+        match self.pending_key_reply.take() {
+            Some((reply, received)) => {
+                self.replies.push(reply.append1(handled));
+                self.key_events.push((handled, received.elapsed()));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether there's a deferred key event that hasn't been answered yet
+    pub fn has_pending_key_event(&self) -> bool {
+        self.pending_key_reply.is_some()
+    }
+
+    pub(crate) fn take_signals(&mut self) -> Vec<EngineSignal> {
+        std::mem::take(&mut self.pending)
+    }
+
+    pub(crate) fn take_replies(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.replies)
+    }
+
+    /// Sets the config section from the name of the engine, and loads the
+    /// settings in it
+    fn load_config(&mut self, conn: &Connection, engine_name: &str) {
+        let section = format!("engine/{}", engine_name);
+        match get_values(conn, &section) {
+            Ok(values) => self.config = values,
+            Err(e) => debug!("Couldn't load the settings of {}: {}", section, e),
+        }
+        self.config_section = Some(section);
+    }
+}
+
+fn config_call(method: &str) -> Message {
+    Message::new_method_call(CONFIG_NAME, CONFIG_PATH, CONFIG_INTERFACE, method).unwrap()
+}
+
+pub(crate) struct EngineObject {
+    pub(crate) engine: Box<dyn Engine>,
+    pub(crate) ctx: EngineContext,
+    /// The number of signals sent by `flush`, for `EngineMetric::MethodCall`
+    signals_sent: usize,
+}
+impl EngineObject {
+    pub(crate) fn new(path: Path<'static>, engine: Box<dyn Engine>) -> Self {
+        EngineObject {
+            engine,
+            ctx: EngineContext::new(path),
+            signals_sent: 0,
+        }
+    }
+
+    fn set_content_type(&mut self, purpose: u32, hints: u32) {
+        let purpose = InputPurpose::from_value(purpose).unwrap_or_else(|| {
+            debug!("Unexpected input purpose `{}`", purpose);
+            InputPurpose::FreeForm
+        });
+        let hints = InputHints::from_bits_truncate(hints);
+        self.ctx.content_type = (purpose, hints);
+        self.engine.set_content_type(&mut self.ctx, purpose, hints);
+    }
+
+    /// Handles the `org.freedesktop.DBus.Properties` interface
+    fn dispatch_properties(&mut self, msg: &Message) -> Message {
+        // The daemon only checks whether the engine supports these features,
+        // and this crate handles both ways of delivering them.
+        let read_only = |name: &str| match name {
+            "FocusId" | "ActiveSurroundingText" => Some((false,)),
+            _ => None,
+        };
+        match msg.member().as_deref() {
+            Some("Set") => {
+                let (interface, name, value): (&str, &str, Variant<(u32, u32)>) = match msg.read3()
+                {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                if interface != ENGINE_INTERFACE || name != "ContentType" {
+                    return error_reply(
+                        msg,
+                        "org.freedesktop.DBus.Error.PropertyReadOnly",
+                        format!("Can't set {}.{}", interface, name),
+                    );
+                }
+                let (purpose, hints) = value.0;
+                self.set_content_type(purpose, hints);
+                msg.method_return()
+            }
+            Some("Get") => {
+                let (interface, name): (&str, &str) = match msg.read2() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                match read_only(name).filter(|_| interface == ENGINE_INTERFACE) {
+                    Some(value) => msg.method_return().append1(Variant(value)),
+                    None => error_reply(
+                        msg,
+                        "org.freedesktop.DBus.Error.UnknownProperty",
+                        format!("Unknown property {}.{}", interface, name),
+                    ),
+                }
+            }
+            Some("GetAll") => {
+                let mut props = PropMap::new();
+                for name in ["FocusId", "ActiveSurroundingText"] {
+                    let value = read_only(name).unwrap();
+                    props.insert(name.into(), Variant(Box::new(value) as Box<dyn RefArg>));
+                }
+                msg.method_return().append1(props)
+            }
+            member => error_reply(
+                msg,
+                "org.freedesktop.DBus.Error.UnknownMethod",
+                format!("Unknown method {:?}", member),
+            ),
+        }
+    }
+
+    /// Calls the engine method that corresponds to the method call message,
+    /// and returns the reply
+    ///
+    /// Returns `None` if the engine deferred the reply to a key event.
+    pub(crate) fn dispatch(&mut self, msg: &Message) -> Option<Message> {
+        if msg.interface().as_deref() == Some(ENGINE_INTERFACE)
+            && msg.member().as_deref() == Some("ProcessKeyEvent")
+        {
+            return self.dispatch_key_event(msg);
+        }
+        Some(self.dispatch_method(msg))
+    }
+
+    fn dispatch_key_event(&mut self, msg: &Message) -> Option<Message> {
+        let received = Instant::now();
+        let (sym, code, state): (u32, u32, u32) = match msg.read3() {
+            Ok(args) => args,
+            Err(e) => return Some(invalid_args(msg, e)),
+        };
+        let modifiers = Modifiers::from_bits_truncate(state);
+        self.ctx.defer_key_reply = false;
+        let handled = self
+            .engine
+            .process_key_event(&mut self.ctx, sym, code, modifiers);
+        if !std::mem::take(&mut self.ctx.defer_key_reply) {
+            self.ctx.key_events.push((handled, received.elapsed()));
+            return Some(msg.method_return().append1(handled));
+        }
+        let pending = (msg.method_return(), received);
+        if let Some((previous, since)) = self.ctx.pending_key_reply.replace(pending) {
+            warn!("A deferred key event was never finished, answering it as unhandled");
+            self.ctx.replies.push(previous.append1(false));
+            self.ctx.key_events.push((false, since.elapsed()));
+        }
+        None
+    }
+
+    fn dispatch_method(&mut self, msg: &Message) -> Message {
+        let interface = msg.interface();
+        let member = msg.member();
+        match (interface.as_deref(), member.as_deref()) {
+            (Some(ENGINE_INTERFACE), Some("SetSurroundingText")) => {
+                let (text, cursor_pos, anchor_pos): (Text, u32, u32) = match msg.read3() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                self.ctx.surrounding_text = Some(SurroundingText {
+                    text,
+                    cursor_pos,
+                    anchor_pos,
+                });
+                self.engine.surrounding_text_changed(&mut self.ctx);
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some("PropertyActivate")) => {
+                let (name, state): (&str, u32) = match msg.read2() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let state = PropState::from_value(state).unwrap_or(PropState::Unchecked);
+                self.engine.property_activate(&mut self.ctx, name, state);
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some(method @ "PropertyShow"))
+            | (Some(ENGINE_INTERFACE), Some(method @ "PropertyHide")) => {
+                let name: &str = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                if method == "PropertyShow" {
+                    self.engine.property_show(&mut self.ctx, name);
+                } else {
+                    self.engine.property_hide(&mut self.ctx, name);
+                }
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some(method @ "SetCursorLocation"))
+            | (Some(ENGINE_INTERFACE), Some(method @ "SetCursorLocationRelative")) => {
+                let (x, y, w, h): (i32, i32, i32, i32) = match msg.read4() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                if method == "SetCursorLocation" {
+                    self.engine.set_cursor_location(&mut self.ctx, x, y, w, h);
+                } else {
+                    self.engine
+                        .set_cursor_location_relative(&mut self.ctx, x, y, w, h);
+                }
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some("SetCapabilities")) => {
+                let caps: u32 = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let caps = Capabilites::from_bits_truncate(caps);
+                self.ctx.capabilities = caps;
+                self.engine.set_capabilities(&mut self.ctx, caps);
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some("SetContentType")) => {
+                let (purpose, hints): (u32, u32) = match msg.read2() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                self.set_content_type(purpose, hints);
+                msg.method_return()
+            }
+            (Some("org.freedesktop.DBus.Properties"), _) => self.dispatch_properties(msg),
+            (Some(ENGINE_INTERFACE), Some("ProcessHandWritingEvent")) => {
+                let coordinates: Vec<f64> = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let points = coordinates.chunks_exact(2);
+                if !points.remainder().is_empty() {
+                    warn!("Ignoring the last coordinate of an odd length handwriting stroke");
+                }
+                let stroke: Vec<(f64, f64)> = points.map(|point| (point[0], point[1])).collect();
+                self.engine
+                    .process_hand_writing_event(&mut self.ctx, &stroke);
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some("CancelHandWriting")) => {
+                let n_strokes: u32 = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                self.engine.cancel_hand_writing(&mut self.ctx, n_strokes);
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some("CandidateClicked")) => {
+                let (index, button, state): (u32, u32, u32) = match msg.read3() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let state = Modifiers::from_bits_truncate(state);
+                self.engine
+                    .candidate_clicked(&mut self.ctx, index, button, state);
+                msg.method_return()
+            }
+            (Some(ENGINE_INTERFACE), Some(method)) => {
+                let engine = &mut self.engine;
+                let ctx = &mut self.ctx;
+                match method {
+                    // The `Id` variants are used when the engine has the
+                    // `FocusId` property
+                    "FocusIn" | "FocusInId" => engine.focus_in(ctx),
+                    "FocusOut" | "FocusOutId" => engine.focus_out(ctx),
+                    "Reset" => engine.reset(ctx),
+                    "Enable" => engine.enable(ctx),
+                    "Disable" => engine.disable(ctx),
+                    "PageUp" => engine.page_up(ctx),
+                    "PageDown" => engine.page_down(ctx),
+                    "CursorUp" => engine.cursor_up(ctx),
+                    "CursorDown" => engine.cursor_down(ctx),
+                    // The rest of the methods are acknowledged without doing
+                    // anything
+                    _ => debug!("Ignoring the engine method {}", method),
+                }
+                msg.method_return()
+            }
+            (Some(SERVICE_INTERFACE), Some("Destroy")) => {
+                self.engine.destroy(&mut self.ctx);
+                msg.method_return()
+            }
+            _ => error_reply(
+                msg,
+                "org.freedesktop.DBus.Error.UnknownMethod",
+                format!("Unknown method {:?}.{:?}", interface, member),
+            ),
+        }
+    }
+
+    /// Dispatches the method call and sends the queued signals followed by
+    /// the reply
+    ///
+    /// Returns false if the engine was destroyed by this call.
+    pub(crate) fn handle(&mut self, msg: &Message, conn: &Connection) -> bool {
+        self.engine.poll(&mut self.ctx);
+        let reply = self.dispatch(msg);
+        self.flush(conn);
+        if let Some(reply) = reply {
+            if !msg.get_no_reply() && conn.send(reply).is_err() {
+                warn!("Failed to send the reply to {:?}", msg.member());
+            }
+        }
+        !is_destroy(msg)
+    }
+
+    /// Sends the queued signals, followed by the replies to the deferred key
+    /// events that were finished
+    pub(crate) fn flush(&mut self, conn: &Connection) {
+        for signal in self.ctx.take_signals() {
+            if conn.send(signal.to_message(&self.ctx.path)).is_err() {
+                warn!("Failed to send the engine signal {:?}", signal);
+            }
+            self.signals_sent += 1;
+        }
+        for reply in self.ctx.take_replies() {
+            if conn.send(reply).is_err() {
+                warn!("Failed to send the reply to a deferred key event");
+            }
+        }
+        for write in std::mem::take(&mut self.ctx.config_writes) {
+            if conn.send(write).is_err() {
+                warn!("Failed to send a config change");
+            }
+        }
+    }
+}
+
+fn is_destroy(msg: &Message) -> bool {
+    msg.interface().as_deref() == Some(SERVICE_INTERFACE)
+        && msg.member().as_deref() == Some("Destroy")
+}
+
+pub(crate) fn error_reply(msg: &Message, name: &'static str, description: String) -> Message {
+    debug!("Replying with an error: {}", description);
+    let description = CString::new(description).unwrap_or_default();
+    msg.error(&ErrorName::from(name), &description)
+}
+
+pub(crate) fn invalid_args(msg: &Message, e: TypeMismatchError) -> Message {
+    error_reply(
+        msg,
+        "org.freedesktop.DBus.Error.InvalidArgs",
+        format!("Invalid arguments for {:?}: {}", msg.member(), e),
+    )
+}
+
+/// Creates engines when the daemon asks for them
+///
+/// This is implemented for closures that take the name of the requested
+/// engine.
+pub trait EngineFactory: Send {
+    /// Returns `None` if this factory doesn't provide an engine with the given
+    /// name
+    fn create_engine(&mut self, name: &str) -> Option<Box<dyn Engine>>;
+}
+impl<F> EngineFactory for F
+where
+    F: FnMut(&str) -> Option<Box<dyn Engine>> + Send,
+{
+    fn create_engine(&mut self, name: &str) -> Option<Box<dyn Engine>> {
+        (self)(name)
+    }
+}
+
+type EngineConstructor = Box<dyn FnMut() -> Box<dyn Engine> + Send>;
+
+/// A factory that serves several engines, routing `CreateEngine` by the
+/// requested engine name
+///
+/// ```
+/// use ibus::engine::{compose::{ComposeEngine, ComposeTable}, EngineRegistry};
+/// use std::sync::Arc;
+///
+/// let table = Arc::new(ComposeTable::new());
+/// let registry = EngineRegistry::new()
+///     .register("compose", move || ComposeEngine::new(table.clone()))
+///     .register("compose-empty", || ComposeEngine::new(Arc::new(ComposeTable::new())));
+/// assert!(registry.contains("compose-empty"));
+/// ```
+#[derive(Default)]
+pub struct EngineRegistry {
+    constructors: Vec<(String, EngineConstructor)>,
+}
+impl EngineRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an engine called `name`, replacing the previous one with the same
+    /// name. `constructor` is called for every input context that uses the
+    /// engine.
+    pub fn register<E, F>(mut self, name: impl Into<String>, mut constructor: F) -> Self
+    where
+        E: Engine + 'static,
+        F: FnMut() -> E + Send + 'static,
+    {
+        let name = name.into();
+        let constructor: EngineConstructor = Box::new(move || Box::new(constructor()));
+        match self.constructors.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, c)) => *c = constructor,
+            None => self.constructors.push((name, constructor)),
+        }
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.iter().any(|(n, _)| n == name)
+    }
+
+    /// The names of the registered engines, in the order of registration
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.constructors.iter().map(|(n, _)| n.as_str())
+    }
+}
+impl EngineFactory for EngineRegistry {
+    fn create_engine(&mut self, name: &str) -> Option<Box<dyn Engine>> {
+        let (_, constructor) = self.constructors.iter_mut().find(|(n, _)| n == name)?;
+        Some(constructor())
+    }
+}
+
+struct ExportedEngine {
+    token: Token,
+    object: Arc<Mutex<EngineObject>>,
+}
+
+type EngineMap = Arc<Mutex<HashMap<Path<'static>, ExportedEngine>>>;
+
+type ContextHook = Box<dyn FnMut(&mut EngineContext) + Send>;
+
+type MetricHook = Box<dyn FnMut(&EngineMetric) + Send>;
+
+/// A measurement of the work done by an engine, see `EngineHost::on_metric`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineMetric {
+    /// An engine handled a method call from the daemon
+    MethodCall {
+        path: Path<'static>,
+        method: String,
+        /// The time spent handling the call, including `Engine::poll`
+        duration: Duration,
+        /// The number of signals that the engine emitted during the call
+        signals: usize,
+        /// Whether the engine still has a deferred key event after the call.
+        /// Key events that arrive in the meantime queue up in the daemon.
+        key_event_pending: bool,
+    },
+    /// A key event was answered
+    KeyEvent {
+        path: Path<'static>,
+        handled: bool,
+        /// The time from receiving the key event to answering it. For
+        /// deferred replies this lasts until `EngineContext::finish_key_event`.
+        latency: Duration,
+    },
+}
+
+/// See `EngineHost::on_engine_created`, `EngineHost::on_engine_destroyed`,
+/// and `EngineHost::on_metric`
+#[derive(Default)]
+struct Hooks {
+    created: Option<ContextHook>,
+    destroyed: Option<ContextHook>,
+    metric: Option<MetricHook>,
+}
+impl Hooks {
+    /// Reports the key events that were answered since the last call, and
+    /// the method call if there was one
+    fn report(&mut self, object: &mut EngineObject, call: Option<(&Message, Duration, usize)>) {
+        let key_events = std::mem::take(&mut object.ctx.key_events);
+        let hook = match &mut self.metric {
+            Some(hook) => hook,
+            None => return,
+        };
+        if let Some((msg, duration, signals_before)) = call {
+            hook(&EngineMetric::MethodCall {
+                path: object.ctx.path.clone(),
+                method: msg.member().map_or_else(String::new, |m| m.to_string()),
+                duration,
+                signals: object.signals_sent - signals_before,
+                key_event_pending: object.ctx.has_pending_key_event(),
+            });
+        }
+        for (handled, latency) in key_events {
+            hook(&EngineMetric::KeyEvent {
+                path: object.ctx.path.clone(),
+                handled,
+                latency,
+            });
+        }
+    }
+
+    fn created(&mut self, ctx: &mut EngineContext) {
+        if let Some(hook) = &mut self.created {
+            hook(ctx);
+        }
+    }
+
+    fn destroyed(&mut self, ctx: &mut EngineContext) {
+        if let Some(hook) = &mut self.destroyed {
+            hook(ctx);
+        }
+    }
+}
+
+type SharedHooks = Arc<Mutex<Hooks>>;
+
+/// Starts handling the method calls to the engine object at `path`
+fn export_engine(
+    conn: &Connection,
+    engines: &EngineMap,
+    hooks: &SharedHooks,
+    path: Path<'static>,
+    engine_name: Option<&str>,
+    engine: Box<dyn Engine>,
+) {
+    let mut object = EngineObject::new(path.clone(), engine);
+    if let Some(name) = engine_name {
+        object.ctx.load_config(conn, name);
+    }
+    hooks.lock().unwrap().created(&mut object.ctx);
+    object.flush(conn);
+    let object = Arc::new(Mutex::new(object));
+    let rule = MatchRule::new_method_call().with_path(path.clone());
+    let token = conn.start_receive(rule, {
+        let object = object.clone();
+        let engines = engines.clone();
+        let hooks = hooks.clone();
+        let path = path.clone();
+        Box::new(move |msg, conn| {
+            let mut object = object.lock().unwrap();
+            let start = Instant::now();
+            let signals_before = object.signals_sent;
+            let keep = object.handle(&msg, conn);
+            let duration = start.elapsed();
+            hooks
+                .lock()
+                .unwrap()
+                .report(&mut object, Some((&msg, duration, signals_before)));
+            if !keep {
+                debug!("Engine {} was destroyed", path);
+                hooks.lock().unwrap().destroyed(&mut object.ctx);
+                object.flush(conn);
+                engines.lock().unwrap().remove(&path);
+            }
+            keep
+        })
+    });
+    let previous = engines
+        .lock()
+        .unwrap()
+        .insert(path, ExportedEngine { token, object });
+    if let Some(previous) = previous {
+        conn.stop_receive(previous.token);
+    }
+}
+
+/// Exports engines on the bus
+///
+/// Engines can either be exported at a fixed path with `add_engine`, or be
+/// created on demand by a factory (see `set_factory`), which is what the
+/// daemon expects from the program of a component.
+///
+/// The engines are removed from the bus when the host is dropped.
+pub struct EngineHost {
+    conn: Rc<Connection>,
+    engines: EngineMap,
+    hooks: SharedHooks,
+    factory_token: Option<Token>,
+    config_token: Option<Token>,
+    /// The names requested through the host, released by `shutdown`
+    names: Vec<String>,
+    /// The components installed by `install_and_register`
+    installed: Vec<Component>,
+}
+impl EngineHost {
+    pub fn new(bus: &Bus) -> Self {
+        EngineHost {
+            conn: bus.conn.clone(),
+            engines: Default::default(),
+            hooks: Default::default(),
+            factory_token: None,
+            config_token: None,
+            names: Vec::new(),
+            installed: Vec::new(),
+        }
+    }
+
+    /// Exports an engine at the given object path, replacing the engine that
+    /// was previously exported there.
+    pub fn add_engine<E>(&self, path: impl Into<Path<'static>>, engine: E)
+    where
+        E: Engine + 'static,
+    {
+        export_engine(
+            &self.conn,
+            &self.engines,
+            &self.hooks,
+            path.into(),
+            None,
+            Box::new(engine),
+        );
+    }
+
+    /// Removes the engine from the bus. Returns false if there was no engine
+    /// at the given path.
+    pub fn remove_engine(&self, path: &Path) -> bool {
+        let removed = self
+            .engines
+            .lock()
+            .unwrap()
+            .remove(&path.clone().into_static());
+        match removed {
+            Some(removed) => {
+                self.conn.stop_receive(removed.token);
+                let mut object = removed.object.lock().unwrap();
+                let object = &mut *object;
+                object.engine.destroy(&mut object.ctx);
+                self.hooks.lock().unwrap().destroyed(&mut object.ctx);
+                object.flush(&self.conn);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Exports an `org.freedesktop.IBus.Factory` object, so that the daemon can
+    /// create engines by calling `CreateEngine`. This replaces the previously
+    /// set factory.
+    ///
+    /// Every created engine gets its own object path, and it's removed from
+    /// the bus when the daemon destroys it.
+    pub fn set_factory<F>(&mut self, factory: F)
+    where
+        F: EngineFactory + 'static,
+    {
+        if let Some(token) = self.factory_token.take() {
+            self.conn.stop_receive(token);
+        }
+        let mut factory = factory;
+        let mut next_id: u32 = 1;
+        let engines = self.engines.clone();
+        let hooks = self.hooks.clone();
+        let rule = MatchRule::new_method_call().with_path(FACTORY_PATH);
+        let token = self.conn.start_receive(
+            rule,
+            Box::new(move |msg, conn| {
+                let reply = match (msg.interface().as_deref(), msg.member().as_deref()) {
+                    (Some(FACTORY_INTERFACE), Some("CreateEngine")) => match msg.read1::<&str>() {
+                        Ok(name) => match factory.create_engine(name) {
+                            Some(engine) => {
+                                let path =
+                                    Path::from(format!("{}/{}", ENGINE_PATH_PREFIX, next_id));
+                                next_id += 1;
+                                debug!("Creating engine `{}` at {}", name, path);
+                                export_engine(
+                                    conn,
+                                    &engines,
+                                    &hooks,
+                                    path.clone(),
+                                    Some(name),
+                                    engine,
+                                );
+                                msg.method_return().append1(path)
+                            }
+                            None => error_reply(
+                                &msg,
+                                "org.freedesktop.DBus.Error.Failed",
+                                format!("Unknown engine `{}`", name),
+                            ),
+                        },
+                        Err(e) => invalid_args(&msg, e),
+                    },
+                    (Some(SERVICE_INTERFACE), Some("Destroy")) => msg.method_return(),
+                    (interface, member) => error_reply(
+                        &msg,
+                        "org.freedesktop.DBus.Error.UnknownMethod",
+                        format!("Unknown method {:?}.{:?}", interface, member),
+                    ),
+                };
+                if !msg.get_no_reply() && conn.send(reply).is_err() {
+                    warn!("Failed to send the reply to {:?}", msg.member());
+                }
+                true
+            }),
+        );
+        self.factory_token = Some(token);
+        if self.config_token.is_none() {
+            self.watch_config();
+        }
+    }
+
+    /// Keeps the settings of the created engines up to date
+    fn watch_config(&mut self) {
+        let rule = MatchRule::new_signal(CONFIG_INTERFACE, "ValueChanged");
+        if let Err(e) = self.conn.add_match_no_cb(&rule.match_str()) {
+            debug!("Couldn't watch the config for changes: {}", e);
+            return;
+        }
+        let engines = self.engines.clone();
+        let token = self.conn.start_receive(
+            rule,
+            Box::new(move |msg, conn| {
+                let (section, name, value): (&str, &str, Variant<Box<dyn RefArg>>) =
+                    match msg.read3() {
+                        Ok(args) => args,
+                        Err(e) => {
+                            debug!("Invalid ValueChanged signal: {}", e);
+                            return true;
+                        }
+                    };
+                let objects: Vec<_> = engines
+                    .lock()
+                    .unwrap()
+                    .values()
+                    .map(|exported| exported.object.clone())
+                    .collect();
+                for object in objects {
+                    let mut object = object.lock().unwrap();
+                    let object = &mut *object;
+                    if object.ctx.config_section() != Some(section) {
+                        continue;
+                    }
+                    // Unsetting a value is signalled with an empty value
+                    if is_unset_value(&*value.0) {
+                        object.ctx.config.remove(name);
+                    } else {
+                        object
+                            .ctx
+                            .config
+                            .insert(name.to_owned(), value.0.box_clone());
+                    }
+                    object.engine.config_changed(&mut object.ctx, name);
+                    object.flush(conn);
+                }
+                true
+            }),
+        );
+        self.config_token = Some(token);
+    }
+
+    /// Makes the component available right away, for running an engine
+    /// straight from `cargo run`
+    ///
+    /// This installs the component for the current user (so that it's still
+    /// there after restarting the daemon), registers it with the running
+    /// daemon, and requests the bus name of the component, which the daemon
+    /// waits for before calling the factory. Set up the factory with
+    /// `set_factory` before calling this.
+    ///
+    /// Returns the path of the installed component file.
+    pub fn install_and_register(&mut self, component: &Component) -> Result<PathBuf, Error> {
+        let path = component.install_user()?;
+        self.installed.push(component.clone());
+        let bus = Bus {
+            conn: self.conn.clone(),
+        };
+        bus.register_component(component)?;
+        self.request_name(&component.name)?;
+        Ok(path)
+    }
+
+    /// Requests a name on the bus, like `Bus::request_name`, and releases it
+    /// on `shutdown`
+    ///
+    /// Programs started by the daemon use this to claim the name of their
+    /// component.
+    pub fn request_name(&mut self, name: &str) -> Result<(), Error> {
+        let bus = Bus {
+            conn: self.conn.clone(),
+        };
+        bus.request_name(name)?;
+        if !self.names.iter().any(|n| n == name) {
+            self.names.push(name.to_owned());
+        }
+        Ok(())
+    }
+
+    /// Stops serving engines, leaving the daemon in a clean state
+    ///
+    /// Every live engine receives `Engine::destroy` and its signals are sent,
+    /// the factory is removed, and the names requested through the host are
+    /// released. With `uninstall`, the components installed by
+    /// `install_and_register` are uninstalled too, so the daemon won't try to
+    /// start this program again after its next restart.
+    ///
+    /// All the steps are attempted even if one of them fails. The first
+    /// error is returned.
+    pub fn shutdown(mut self, uninstall: bool) -> Result<(), Error> {
+        if let Some(token) = self.factory_token.take() {
+            self.conn.stop_receive(token);
+        }
+        if let Some(token) = self.config_token.take() {
+            self.conn.stop_receive(token);
+        }
+        let paths: Vec<_> = self.engines.lock().unwrap().keys().cloned().collect();
+        for path in paths {
+            self.remove_engine(&path);
+        }
+
+        let mut result = Ok(());
+        let bus = Bus {
+            conn: self.conn.clone(),
+        };
+        for name in std::mem::take(&mut self.names) {
+            if let Err(e) = bus.release_name(&name) {
+                warn!("Failed to release the name {}: {}", name, e);
+                result = result.and(Err(e));
+            }
+        }
+        if uninstall {
+            for component in std::mem::take(&mut self.installed) {
+                if let Err(e) = component.uninstall_user() {
+                    warn!(
+                        "Failed to uninstall the component {}: {}",
+                        component.name, e
+                    );
+                    result = result.and(Err(e));
+                }
+            }
+        }
+        self.conn.channel().flush();
+        result
+    }
+
+    /// Sets a function that's called for every new engine instance, before it
+    /// receives any method calls
+    ///
+    /// This is the place for setting up the per input context state (see
+    /// `EngineContext::set_state`) of engines created by the factory.
+    pub fn on_engine_created<F>(&self, hook: F)
+    where
+        F: FnMut(&mut EngineContext) + Send + 'static,
+    {
+        self.hooks.lock().unwrap().created = Some(Box::new(hook));
+    }
+
+    /// Sets a function that's called after `Engine::destroy`, right before
+    /// the engine is removed from the bus
+    ///
+    /// The state of the input context can be taken out with
+    /// `EngineContext::take_state`, e.g. for saving it.
+    pub fn on_engine_destroyed<F>(&self, hook: F)
+    where
+        F: FnMut(&mut EngineContext) + Send + 'static,
+    {
+        self.hooks.lock().unwrap().destroyed = Some(Box::new(hook));
+    }
+
+    /// Sets a function that receives measurements of the engines' work, like
+    /// the time spent on every method call and the latency of key events
+    ///
+    /// ```no_run
+    /// use ibus::engine::{EngineHost, EngineMetric};
+    /// use std::time::Duration;
+    ///
+    /// let bus = ibus::Bus::new().unwrap();
+    /// let host = EngineHost::new(&bus);
+    /// host.on_metric(|metric| {
+    ///     if let EngineMetric::KeyEvent { latency, .. } = metric {
+    ///         if *latency > Duration::from_millis(50) {
+    ///             log::warn!("Slow key event: {:?}", latency);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn on_metric<F>(&self, hook: F)
+    where
+        F: FnMut(&EngineMetric) + Send + 'static,
+    {
+        self.hooks.lock().unwrap().metric = Some(Box::new(hook));
+    }
+
+    /// Calls `Engine::poll` on every exported engine, and sends the signals
+    /// that they emitted
+    ///
+    /// Call this regularly from the event loop when using engines that do
+    /// work in the background, like `asynchronous::AsyncEngineAdapter`.
+    pub fn poll(&self) {
+        let objects: Vec<_> = self
+            .engines
+            .lock()
+            .unwrap()
+            .values()
+            .map(|exported| exported.object.clone())
+            .collect();
+        for object in objects {
+            let mut object = object.lock().unwrap();
+            let object = &mut *object;
+            object.engine.poll(&mut object.ctx);
+            object.flush(&self.conn);
+            self.hooks.lock().unwrap().report(object, None);
+        }
+    }
+
+    /// Calls `f` with an exported engine outside of a method call, e.g. from
+    /// a timer, then sends the signals that it emitted.
+    ///
+    /// Returns `None` if there's no engine at the given path.
+    pub fn with_engine<R, F>(&self, path: &Path, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut dyn Engine, &mut EngineContext) -> R,
+    {
+        let object = self
+            .engines
+            .lock()
+            .unwrap()
+            .get(&path.clone().into_static())?
+            .object
+            .clone();
+        let mut object = object.lock().unwrap();
+        let object = &mut *object;
+        let result = f(object.engine.as_mut(), &mut object.ctx);
+        object.flush(&self.conn);
+        self.hooks.lock().unwrap().report(object, None);
+        Some(result)
+    }
+}
+impl Drop for EngineHost {
+    fn drop(&mut self) {
+        if let Some(token) = self.factory_token.take() {
+            self.conn.stop_receive(token);
+        }
+        if let Some(token) = self.config_token.take() {
+            self.conn.stop_receive(token);
+        }
+        for (_, exported) in self.engines.lock().unwrap().drain() {
+            self.conn.stop_receive(exported.token);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+    impl Engine for Echo {
+        fn process_key_event(
+            &mut self,
+            ctx: &mut EngineContext,
+            sym: u32,
+            _code: u32,
+            _modifiers: Modifiers,
+        ) -> bool {
+            match char::from_u32(sym) {
+                Some(c) if c.is_ascii_alphabetic() => {
+                    ctx.commit_text(c.to_string());
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
+
+    #[test]
+    fn dispatch_process_key_event() {
+        let path = Path::from("/org/freedesktop/IBus/Engine/1");
+        let mut object = EngineObject::new(path.clone(), Box::new(Echo));
+        let call = |sym: u32| {
+            let mut msg =
+                Message::new_method_call("a.b", path.clone(), ENGINE_INTERFACE, "ProcessKeyEvent")
+                    .unwrap()
+                    .append3(sym, 0u32, 0u32);
+            msg.set_serial(1);
+            msg
+        };
+        let reply = object.dispatch(&call('x' as u32)).unwrap();
+        assert!(reply.read1::<bool>().unwrap());
+        assert_eq!(
+            object.ctx.take_signals(),
+            vec![EngineSignal::CommitText("x".into())]
+        );
+        let reply = object.dispatch(&call(' ' as u32)).unwrap();
+        assert!(!reply.read1::<bool>().unwrap());
+        assert!(object.ctx.take_signals().is_empty());
+    }
+
+    /// Records the calls in the state of the context
+    struct Clicks;
+    impl Engine for Clicks {
+        fn process_key_event(
+            &mut self,
+            _: &mut EngineContext,
+            _: u32,
+            _: u32,
+            _: Modifiers,
+        ) -> bool {
+            false
+        }
+
+        fn candidate_clicked(
+            &mut self,
+            ctx: &mut EngineContext,
+            index: u32,
+            button: u32,
+            state: Modifiers,
+        ) {
+            ctx.state::<Vec<String>>()
+                .push(format!("clicked {} {} {:?}", index, button, state));
+        }
+
+        fn property_show(&mut self, ctx: &mut EngineContext, name: &str) {
+            ctx.state::<Vec<String>>().push(format!("show {}", name));
+        }
+    }
+
+    #[test]
+    fn panel_calls_reach_the_engine() {
+        let path = Path::from("/org/freedesktop/IBus/Engine/1");
+        let mut object = EngineObject::new(path.clone(), Box::new(Clicks));
+        let mut clicked =
+            Message::new_method_call("a.b", path.clone(), ENGINE_INTERFACE, "CandidateClicked")
+                .unwrap()
+                .append3(2u32, 3u32, Modifiers::SHIFT.bits());
+        clicked.set_serial(1);
+        assert!(object.dispatch(&clicked).is_some());
+        let mut show = Message::new_method_call("a.b", path, ENGINE_INTERFACE, "PropertyShow")
+            .unwrap()
+            .append1("InputMode");
+        show.set_serial(2);
+        assert!(object.dispatch(&show).is_some());
+        assert_eq!(
+            object.ctx.state::<Vec<String>>(),
+            &["clicked 2 3 SHIFT", "show InputMode"]
+        );
+    }
+
+    #[test]
+    fn input_mode_is_registered_once() {
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        ctx.set_input_mode("あ", "Hiragana");
+        ctx.set_input_mode("ア", "Katakana");
+        let signals = ctx.take_signals();
+        assert!(matches!(signals[0], EngineSignal::RegisterProperties(_)));
+        assert!(
+            matches!(&signals[1], EngineSignal::UpdateProperty(p) if p.symbol.as_str() == "ア")
+        );
+        assert_eq!(ctx.properties().properties.len(), 1);
+    }
+
+    #[test]
+    fn handwriting_strokes() {
+        struct Strokes;
+        impl Engine for Strokes {
+            fn process_key_event(
+                &mut self,
+                _: &mut EngineContext,
+                _: u32,
+                _: u32,
+                _: Modifiers,
+            ) -> bool {
+                false
+            }
+
+            fn process_hand_writing_event(
+                &mut self,
+                ctx: &mut EngineContext,
+                stroke: &[(f64, f64)],
+            ) {
+                ctx.state::<HandwritingStrokes>().push(stroke);
+            }
+
+            fn cancel_hand_writing(&mut self, ctx: &mut EngineContext, n_strokes: u32) {
+                ctx.state::<HandwritingStrokes>().cancel(n_strokes);
+            }
+        }
+
+        let path = Path::from("/org/freedesktop/IBus/Engine/1");
+        let mut object = EngineObject::new(path.clone(), Box::new(Strokes));
+        let call = |method: &str| {
+            let mut msg =
+                Message::new_method_call("a.b", path.clone(), ENGINE_INTERFACE, method).unwrap();
+            msg.set_serial(1);
+            msg
+        };
+        object.dispatch(&call("ProcessHandWritingEvent").append1(vec![0.1, 0.2, 0.3, 0.4]));
+        object.dispatch(&call("ProcessHandWritingEvent").append1(vec![0.5, 0.6, 0.7]));
+        assert_eq!(
+            object.ctx.state::<HandwritingStrokes>().strokes,
+            [vec![(0.1, 0.2), (0.3, 0.4)], vec![(0.5, 0.6)]]
+        );
+        object.dispatch(&call("CancelHandWriting").append1(1u32));
+        assert_eq!(object.ctx.state::<HandwritingStrokes>().strokes.len(), 1);
+        object.dispatch(&call("CancelHandWriting").append1(0u32));
+        assert!(object.ctx.state::<HandwritingStrokes>().is_empty());
+    }
+
+    #[test]
+    fn key_event_metrics() {
+        let path = Path::from("/org/freedesktop/IBus/Engine/1");
+        let mut object = EngineObject::new(path.clone(), Box::new(Echo));
+        let mut msg = Message::new_method_call("a.b", path, ENGINE_INTERFACE, "ProcessKeyEvent")
+            .unwrap()
+            .append3('x' as u32, 0u32, 0u32);
+        msg.set_serial(1);
+        object.dispatch(&msg);
+
+        let metrics = Arc::new(Mutex::new(Vec::new()));
+        let mut hooks = Hooks {
+            metric: Some(Box::new({
+                let metrics = metrics.clone();
+                move |m: &EngineMetric| metrics.lock().unwrap().push(m.clone())
+            })),
+            ..Default::default()
+        };
+        hooks.report(&mut object, Some((&msg, Duration::from_millis(1), 0)));
+        let metrics = metrics.lock().unwrap();
+        assert!(matches!(
+            &metrics[0],
+            EngineMetric::MethodCall { method, key_event_pending: false, .. } if method == "ProcessKeyEvent"
+        ));
+        assert!(matches!(
+            metrics[1],
+            EngineMetric::KeyEvent { handled: true, .. }
+        ));
+        assert!(object.ctx.key_events.is_empty());
+    }
+
+    #[test]
+    fn delete_surrounding_text_updates_the_cache() {
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        ctx.surrounding_text = Some(SurroundingText {
+            text: "héllo".into(),
+            cursor_pos: 3,
+            anchor_pos: 3,
+        });
+        ctx.delete_surrounding_text(-2, 2);
+        let surrounding = ctx.surrounding_text().unwrap();
+        assert_eq!(surrounding.text.as_str(), "hlo");
+        assert_eq!(surrounding.cursor_pos, 1);
+    }
+
+    #[test]
+    fn content_type_property_is_stored() {
+        let path = Path::from("/org/freedesktop/IBus/Engine/1");
+        let mut object = EngineObject::new(path.clone(), Box::new(Echo));
+        let mut msg =
+            Message::new_method_call("a.b", path, "org.freedesktop.DBus.Properties", "Set")
+                .unwrap()
+                .append3(ENGINE_INTERFACE, "ContentType", Variant((8u32, 1u32 << 7)));
+        msg.set_serial(1);
+        let reply = object.dispatch(&msg).unwrap();
+        assert_eq!(reply.msg_type(), dbus::MessageType::MethodReturn);
+        assert_eq!(
+            object.ctx.content_type(),
+            (InputPurpose::Password, InputHints::INHIBIT_OSK)
+        );
+    }
+
+    #[test]
+    fn context_state_is_per_type() {
+        #[derive(Default)]
+        struct Count(u32);
+
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        ctx.state::<Count>().0 += 1;
+        ctx.state::<Count>().0 += 1;
+        ctx.set_state(String::from("mode"));
+        assert_eq!(ctx.get_state::<Count>().map(|c| c.0), Some(2));
+        assert_eq!(ctx.take_state::<String>().as_deref(), Some("mode"));
+        assert!(ctx.get_state::<String>().is_none());
+    }
+
+    #[test]
+    fn set_config_updates_the_cache() {
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        ctx.set_config("ignored", true);
+        assert!(ctx.config_writes.is_empty());
+
+        ctx.config_section = Some("engine/test".into());
+        ctx.set_config("page-size", 7i32);
+        assert_eq!(ctx.config::<i32>("page-size"), Some(7));
+        assert_eq!(ctx.config::<bool>("page-size"), None);
+        let write = ctx.config_writes.pop().unwrap();
+        assert_eq!(write.member().as_deref(), Some("SetValue"));
+        assert_eq!(
+            write.read2::<&str, &str>().unwrap(),
+            ("engine/test", "page-size")
+        );
+    }
+}
@@ -0,0 +1,757 @@
+//! Engines with slow lookups
+//!
+//! Some engines can't decide quickly what to do with a key event, e.g.
+//! because they look up candidates in a large dictionary or over the
+//! network. `AsyncEngine` lets `process_key_event` return a future instead,
+//! and `AsyncEngineAdapter` turns such an engine into an `Engine`.
+//!
+//! The futures run on a background thread, so the engine keeps answering
+//! the other method calls in the meantime. When another key is pressed, or
+//! the composition is abandoned, the lookup in progress is cancelled.
+//!
+//! The results are delivered from `EngineHost::poll` (and before every
+//! method call), so the event loop has to call it while lookups are running.
+//! See `AsyncEngineAdapter::with_notify` for waking up the event loop.
+//!
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+use log::debug;
+
+use crate::{Capabilites, InputHints, InputPurpose, Modifiers, PropState};
+
+use super::{Engine, EngineContext};
+
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// Tells a lookup that its result isn't needed anymore
+///
+/// The future of a cancelled lookup is dropped the next time it's woken up,
+/// so futures that wait for something else should also wait for
+/// `cancelled` (e.g. by selecting on both) to stop quickly.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancelState>,
+}
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for waker in self.inner.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+
+    /// Completes when the token is cancelled
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.inner.wakers.lock().unwrap();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+}
+
+/// The future returned by `CancellationToken::cancelled`
+#[derive(Debug)]
+pub struct Cancelled {
+    token: CancellationToken,
+}
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        self.token.register(cx.waker());
+        // Cancelling may have happened before registering the waker
+        if self.token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// What to do with a key event
+pub enum KeyResponse<T> {
+    /// Answer right away
+    Done(bool),
+
+    /// Answer right away, and run the lookup in the background. The result
+    /// is passed to `AsyncEngine::lookup_finished`.
+    ///
+    /// This is the right choice when the engine knows that it handles the
+    /// key, but computing the candidates takes a while.
+    Background { handled: bool, lookup: BoxFuture<T> },
+
+    /// Run the lookup, and answer the key event with the value returned from
+    /// `AsyncEngine::lookup_finished`. The application doesn't know whether
+    /// to handle the key itself until then.
+    Deferred(BoxFuture<T>),
+}
+
+/// The asynchronous variant of `Engine`
+///
+/// The methods other than the ones about key events are the same as the
+/// methods of `Engine`. The `reset`, `focus_out`, `disable`, and `destroy`
+/// methods are called after cancelling the lookup in progress.
+pub trait AsyncEngine: Send {
+    /// The result of a lookup
+    type Output: Send + 'static;
+
+    /// Starts processing a key event. For a key press, any lookup that's
+    /// still in progress has already been cancelled when this is called.
+    ///
+    /// A key release doesn't cancel the lookup, which is usually the one of
+    /// its press, unless it starts a lookup itself.
+    ///
+    /// `cancel` is the token of the returned lookup, if there's one.
+    fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        code: u32,
+        modifiers: Modifiers,
+        cancel: &CancellationToken,
+    ) -> KeyResponse<Self::Output>;
+
+    /// Called with the result of a lookup that wasn't cancelled
+    ///
+    /// For `KeyResponse::Deferred` lookups the return value answers the key
+    /// event. It's ignored for `KeyResponse::Background` lookups.
+    fn lookup_finished(&mut self, ctx: &mut EngineContext, output: Self::Output) -> bool;
+
+    /// Called when a lookup is cancelled, because it was superseded by a new
+    /// key event or the composition was abandoned
+    ///
+    /// For `KeyResponse::Deferred` lookups the return value answers the key
+    /// event. The default returns true, so that the key doesn't reach the
+    /// application.
+    fn lookup_cancelled(&mut self, ctx: &mut EngineContext) -> bool {
+        let _ = ctx;
+        true
+    }
+
+    /// See `Engine::property_activate`
+    fn property_activate(&mut self, ctx: &mut EngineContext, name: &str, state: PropState) {
+        let _ = (ctx, name, state);
+    }
+
+    /// See `Engine::property_show`
+    fn property_show(&mut self, ctx: &mut EngineContext, name: &str) {
+        let _ = (ctx, name);
+    }
+
+    /// See `Engine::property_hide`
+    fn property_hide(&mut self, ctx: &mut EngineContext, name: &str) {
+        let _ = (ctx, name);
+    }
+
+    /// See `Engine::focus_in`
+    fn focus_in(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::focus_out`
+    fn focus_out(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::reset`
+    fn reset(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::enable`
+    fn enable(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::disable`
+    fn disable(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::destroy`
+    fn destroy(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::page_up`
+    fn page_up(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::page_down`
+    fn page_down(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::cursor_up`
+    fn cursor_up(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::cursor_down`
+    fn cursor_down(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::candidate_clicked`
+    fn candidate_clicked(
+        &mut self,
+        ctx: &mut EngineContext,
+        index: u32,
+        button: u32,
+        state: Modifiers,
+    ) {
+        let _ = (ctx, index, button, state);
+    }
+
+    /// See `Engine::process_hand_writing_event`
+    fn process_hand_writing_event(&mut self, ctx: &mut EngineContext, stroke: &[(f64, f64)]) {
+        let _ = (ctx, stroke);
+    }
+
+    /// See `Engine::cancel_hand_writing`
+    fn cancel_hand_writing(&mut self, ctx: &mut EngineContext, n_strokes: u32) {
+        let _ = (ctx, n_strokes);
+    }
+
+    /// See `Engine::set_cursor_location`
+    fn set_cursor_location(&mut self, ctx: &mut EngineContext, x: i32, y: i32, w: i32, h: i32) {
+        let _ = (ctx, x, y, w, h);
+    }
+
+    /// See `Engine::set_cursor_location_relative`
+    fn set_cursor_location_relative(
+        &mut self,
+        ctx: &mut EngineContext,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+    ) {
+        let _ = (ctx, x, y, w, h);
+    }
+
+    /// See `Engine::set_capabilities`
+    fn set_capabilities(&mut self, ctx: &mut EngineContext, caps: Capabilites) {
+        let _ = (ctx, caps);
+    }
+
+    /// See `Engine::surrounding_text_changed`
+    fn surrounding_text_changed(&mut self, ctx: &mut EngineContext) {
+        let _ = ctx;
+    }
+
+    /// See `Engine::set_content_type`
+    fn set_content_type(
+        &mut self,
+        ctx: &mut EngineContext,
+        purpose: InputPurpose,
+        hints: InputHints,
+    ) {
+        let _ = (ctx, purpose, hints);
+    }
+}
+
+struct PendingLookup {
+    id: u64,
+    token: CancellationToken,
+    deferred: bool,
+}
+
+type Notify = Arc<dyn Fn() + Send + Sync>;
+
+/// A lookup for the worker thread
+struct Task<T> {
+    id: u64,
+    lookup: BoxFuture<T>,
+    token: CancellationToken,
+}
+
+/// The thread that runs the lookups of an `AsyncEngineAdapter`
+///
+/// It stops when this is dropped, and drops the lookups that are still
+/// running.
+struct Worker<T> {
+    tasks: Option<Sender<Task<T>>>,
+    thread: Thread,
+}
+impl<T: Send + 'static> Worker<T> {
+    fn spawn(results: Sender<(u64, T)>, notify: Option<Notify>) -> Self {
+        let (tasks, receiver) = mpsc::channel();
+        let thread = thread::spawn(move || run_lookups(receiver, results, notify));
+        Worker {
+            tasks: Some(tasks),
+            thread: thread.thread().clone(),
+        }
+    }
+
+    fn run(&self, task: Task<T>) {
+        if let Some(tasks) = &self.tasks {
+            let _ = tasks.send(task);
+        }
+        self.thread.unpark();
+    }
+}
+impl<T> Drop for Worker<T> {
+    fn drop(&mut self) {
+        self.tasks = None;
+        self.thread.unpark();
+    }
+}
+
+/// Runs an `AsyncEngine` as an `Engine`
+///
+/// The lookups run together on one thread, which is started with the first
+/// one. A lookup that blocks (e.g. on a mutex or on file IO) holds up the
+/// others, so it should do the blocking part on a thread of its own.
+pub struct AsyncEngineAdapter<E: AsyncEngine> {
+    engine: E,
+    next_id: u64,
+    pending: Option<PendingLookup>,
+    sender: Sender<(u64, E::Output)>,
+    receiver: Receiver<(u64, E::Output)>,
+    notify: Option<Notify>,
+    worker: Option<Worker<E::Output>>,
+}
+impl<E: AsyncEngine> AsyncEngineAdapter<E> {
+    pub fn new(engine: E) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        AsyncEngineAdapter {
+            engine,
+            next_id: 0,
+            pending: None,
+            sender,
+            receiver,
+            notify: None,
+            worker: None,
+        }
+    }
+
+    /// `notify` is called from the background thread when a lookup finishes.
+    /// It can be used to wake up the event loop, so that it calls
+    /// `EngineHost::poll` without delay.
+    pub fn with_notify(engine: E, notify: impl Fn() + Send + Sync + 'static) -> Self {
+        let mut adapter = Self::new(engine);
+        adapter.notify = Some(Arc::new(notify));
+        adapter
+    }
+
+    pub fn engine(&self) -> &E {
+        &self.engine
+    }
+
+    pub fn engine_mut(&mut self) -> &mut E {
+        &mut self.engine
+    }
+
+    /// Whether a lookup is in progress
+    pub fn is_busy(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Starts a lookup, after cancelling the one in progress
+    fn start(
+        &mut self,
+        ctx: &mut EngineContext,
+        lookup: BoxFuture<E::Output>,
+        token: CancellationToken,
+        deferred: bool,
+    ) {
+        self.cancel(ctx);
+        let id = self.next_id;
+        self.next_id += 1;
+        self.pending = Some(PendingLookup {
+            id,
+            token: token.clone(),
+            deferred,
+        });
+        self.worker
+            .get_or_insert_with(|| Worker::spawn(self.sender.clone(), self.notify.clone()))
+            .run(Task { id, lookup, token });
+    }
+
+    fn cancel(&mut self, ctx: &mut EngineContext) {
+        if let Some(pending) = self.pending.take() {
+            debug!("Cancelling lookup {}", pending.id);
+            pending.token.cancel();
+            let handled = self.engine.lookup_cancelled(ctx);
+            if pending.deferred {
+                ctx.finish_key_event(handled);
+            }
+        }
+    }
+}
+
+/// Polls the lookups on the current thread when they're woken up, until the
+/// `Worker` is dropped. The cancelled ones are dropped.
+fn run_lookups<T>(tasks: Receiver<Task<T>>, results: Sender<(u64, T)>, notify: Option<Notify>) {
+    struct TaskWaker {
+        id: u64,
+        woken: Arc<Mutex<Vec<u64>>>,
+        thread: Thread,
+    }
+    impl Wake for TaskWaker {
+        fn wake(self: Arc<Self>) {
+            self.woken.lock().unwrap().push(self.id);
+            self.thread.unpark();
+        }
+    }
+
+    let woken = Arc::new(Mutex::new(Vec::new()));
+    let mut running = HashMap::new();
+    loop {
+        loop {
+            match tasks.try_recv() {
+                Ok(task) => {
+                    let waker = Waker::from(Arc::new(TaskWaker {
+                        id: task.id,
+                        woken: woken.clone(),
+                        thread: thread::current(),
+                    }));
+                    task.token.register(&waker);
+                    woken.lock().unwrap().push(task.id);
+                    running.insert(task.id, (task, waker));
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        let ids = std::mem::take(&mut *woken.lock().unwrap());
+        if ids.is_empty() {
+            // Waking up or sending a task in the meantime unparks the thread,
+            // so this returns right away then
+            thread::park();
+            continue;
+        }
+        for id in ids {
+            let (task, waker) = match running.get_mut(&id) {
+                Some(running) => running,
+                None => continue,
+            };
+            let done = task.token.is_cancelled()
+                || match task.lookup.as_mut().poll(&mut Context::from_waker(waker)) {
+                    Poll::Ready(output) => {
+                        if results.send((id, output)).is_ok() {
+                            if let Some(notify) = &notify {
+                                notify();
+                            }
+                        }
+                        true
+                    }
+                    Poll::Pending => false,
+                };
+            if done {
+                running.remove(&id);
+            }
+        }
+    }
+}
+
+impl<E: AsyncEngine> Engine for AsyncEngineAdapter<E> {
+    fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        code: u32,
+        modifiers: Modifiers,
+    ) -> bool {
+        // The daemon sends the release of a key right after its press, it
+        // mustn't cancel the lookup of the press
+        if !modifiers.contains(Modifiers::RELEASE) {
+            self.cancel(ctx);
+        }
+        let token = CancellationToken::new();
+        match self
+            .engine
+            .process_key_event(ctx, sym, code, modifiers, &token)
+        {
+            KeyResponse::Done(handled) => handled,
+            KeyResponse::Background { handled, lookup } => {
+                self.start(ctx, lookup, token, false);
+                handled
+            }
+            KeyResponse::Deferred(lookup) => {
+                self.start(ctx, lookup, token, true);
+                ctx.defer_key_event_reply();
+                false
+            }
+        }
+    }
+
+    fn poll(&mut self, ctx: &mut EngineContext) {
+        while let Ok((id, output)) = self.receiver.try_recv() {
+            match &self.pending {
+                Some(pending) if pending.id == id => {
+                    let deferred = pending.deferred;
+                    self.pending = None;
+                    let handled = self.engine.lookup_finished(ctx, output);
+                    if deferred {
+                        ctx.finish_key_event(handled);
+                    }
+                }
+                // Finished just before it was cancelled
+                _ => debug!("Dropping the result of the stale lookup {}", id),
+            }
+        }
+    }
+
+    fn property_activate(&mut self, ctx: &mut EngineContext, name: &str, state: PropState) {
+        self.engine.property_activate(ctx, name, state);
+    }
+
+    fn property_show(&mut self, ctx: &mut EngineContext, name: &str) {
+        self.engine.property_show(ctx, name);
+    }
+
+    fn property_hide(&mut self, ctx: &mut EngineContext, name: &str) {
+        self.engine.property_hide(ctx, name);
+    }
+
+    fn focus_in(&mut self, ctx: &mut EngineContext) {
+        self.engine.focus_in(ctx);
+    }
+
+    fn focus_out(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+        self.engine.focus_out(ctx);
+    }
+
+    fn reset(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+        self.engine.reset(ctx);
+    }
+
+    fn enable(&mut self, ctx: &mut EngineContext) {
+        self.engine.enable(ctx);
+    }
+
+    fn disable(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+        self.engine.disable(ctx);
+    }
+
+    fn destroy(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+        self.engine.destroy(ctx);
+    }
+
+    fn page_up(&mut self, ctx: &mut EngineContext) {
+        self.engine.page_up(ctx);
+    }
+
+    fn page_down(&mut self, ctx: &mut EngineContext) {
+        self.engine.page_down(ctx);
+    }
+
+    fn cursor_up(&mut self, ctx: &mut EngineContext) {
+        self.engine.cursor_up(ctx);
+    }
+
+    fn cursor_down(&mut self, ctx: &mut EngineContext) {
+        self.engine.cursor_down(ctx);
+    }
+
+    fn candidate_clicked(
+        &mut self,
+        ctx: &mut EngineContext,
+        index: u32,
+        button: u32,
+        state: Modifiers,
+    ) {
+        self.engine.candidate_clicked(ctx, index, button, state);
+    }
+
+    fn process_hand_writing_event(&mut self, ctx: &mut EngineContext, stroke: &[(f64, f64)]) {
+        self.engine.process_hand_writing_event(ctx, stroke);
+    }
+
+    fn cancel_hand_writing(&mut self, ctx: &mut EngineContext, n_strokes: u32) {
+        self.engine.cancel_hand_writing(ctx, n_strokes);
+    }
+
+    fn set_cursor_location(&mut self, ctx: &mut EngineContext, x: i32, y: i32, w: i32, h: i32) {
+        self.engine.set_cursor_location(ctx, x, y, w, h);
+    }
+
+    fn set_cursor_location_relative(
+        &mut self,
+        ctx: &mut EngineContext,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+    ) {
+        self.engine.set_cursor_location_relative(ctx, x, y, w, h);
+    }
+
+    fn set_capabilities(&mut self, ctx: &mut EngineContext, caps: Capabilites) {
+        self.engine.set_capabilities(ctx, caps);
+    }
+
+    fn surrounding_text_changed(&mut self, ctx: &mut EngineContext) {
+        self.engine.surrounding_text_changed(ctx);
+    }
+
+    fn set_content_type(
+        &mut self,
+        ctx: &mut EngineContext,
+        purpose: InputPurpose,
+        hints: InputHints,
+    ) {
+        self.engine.set_content_type(ctx, purpose, hints);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::testing::EngineTester;
+
+    /// Uppercases the key after a lookup that waits until it's released
+    struct SlowUpper {
+        gate: Arc<Mutex<Option<Waker>>>,
+        open: Arc<AtomicBool>,
+    }
+
+    struct Gate {
+        waker: Arc<Mutex<Option<Waker>>>,
+        open: Arc<AtomicBool>,
+        c: char,
+    }
+    impl Future for Gate {
+        type Output = char;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<char> {
+            *self.waker.lock().unwrap() = Some(cx.waker().clone());
+            if self.open.load(Ordering::SeqCst) {
+                Poll::Ready(self.c.to_ascii_uppercase())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    impl AsyncEngine for SlowUpper {
+        type Output = char;
+
+        fn process_key_event(
+            &mut self,
+            _ctx: &mut EngineContext,
+            sym: u32,
+            _code: u32,
+            modifiers: Modifiers,
+            _cancel: &CancellationToken,
+        ) -> KeyResponse<char> {
+            match char::from_u32(sym) {
+                Some(c) if c.is_ascii_lowercase() && !modifiers.contains(Modifiers::RELEASE) => {
+                    KeyResponse::Deferred(Box::pin(Gate {
+                        waker: self.gate.clone(),
+                        open: self.open.clone(),
+                        c,
+                    }))
+                }
+                _ => KeyResponse::Done(false),
+            }
+        }
+
+        fn lookup_finished(&mut self, ctx: &mut EngineContext, output: char) -> bool {
+            ctx.commit_text(output.to_string());
+            true
+        }
+
+        fn lookup_cancelled(&mut self, ctx: &mut EngineContext) -> bool {
+            ctx.commit_text("?");
+            true
+        }
+    }
+
+    #[test]
+    fn deferred_and_superseded_lookups() {
+        let gate: Arc<Mutex<Option<Waker>>> = Default::default();
+        let open = Arc::new(AtomicBool::new(false));
+        let mut tester = EngineTester::new(AsyncEngineAdapter::new(SlowUpper {
+            gate: gate.clone(),
+            open: open.clone(),
+        }));
+        // The first lookup is superseded by the second key event
+        assert_eq!(
+            tester.send_key_event('a' as u32, 0, Modifiers::empty()),
+            None
+        );
+        assert_eq!(
+            tester.send_key_event('b' as u32, 0, Modifiers::empty()),
+            None
+        );
+        assert!(tester.wait_for_key_event());
+        assert_eq!(tester.committed_text(), "?");
+
+        open.store(true, Ordering::SeqCst);
+        if let Some(waker) = gate.lock().unwrap().take() {
+            waker.wake();
+        }
+        assert!(tester.wait_for_key_event());
+        assert_eq!(tester.committed_text(), "?B");
+    }
+
+    #[test]
+    fn release_keeps_the_lookup_of_the_press() {
+        let gate: Arc<Mutex<Option<Waker>>> = Default::default();
+        let open = Arc::new(AtomicBool::new(false));
+        let mut tester = EngineTester::new(AsyncEngineAdapter::new(SlowUpper {
+            gate: gate.clone(),
+            open: open.clone(),
+        }));
+        assert_eq!(
+            tester.send_key_event('a' as u32, 0, Modifiers::empty()),
+            None
+        );
+        assert_eq!(
+            tester.send_key_event('a' as u32, 0, Modifiers::RELEASE),
+            Some(false)
+        );
+        assert_eq!(tester.committed_text(), "");
+
+        open.store(true, Ordering::SeqCst);
+        if let Some(waker) = gate.lock().unwrap().take() {
+            waker.wake();
+        }
+        assert!(tester.wait_for_key_event());
+        assert_eq!(tester.committed_text(), "A");
+    }
+}
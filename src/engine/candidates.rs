@@ -0,0 +1,417 @@
+//! Candidate selection for engines that use a lookup table
+//!
+
+use std::fmt;
+
+use crate::{
+    keysyms::{self, keysym_to_char},
+    LookupTable, Modifiers, Orientation, Text,
+};
+
+use super::EngineContext;
+
+/// What a key did to the candidates, see `CandidateSelector::process_key_event`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Selection {
+    /// The cursor or the page changed. The lookup table has already been
+    /// updated.
+    Moved,
+
+    /// The candidate with the contained index (into
+    /// `LookupTable::candidates`) was chosen with its label
+    Chosen(u32),
+}
+
+/// Produces candidates on demand, see `CandidateSelector::set_candidate_source`
+///
+/// This is implemented for closures that take the number of requested
+/// candidates. An iterator can be turned into a source with
+/// `move |n| iter.by_ref().take(n).collect()`.
+pub trait CandidateSource: Send {
+    /// Returns the next `count` candidates. Returning fewer means that there
+    /// are no more candidates, and the source isn't called again.
+    fn fetch(&mut self, count: usize) -> Vec<Text<'static>>;
+}
+impl<F> CandidateSource for F
+where
+    F: FnMut(usize) -> Vec<Text<'static>> + Send,
+{
+    fn fetch(&mut self, count: usize) -> Vec<Text<'static>> {
+        (self)(count)
+    }
+}
+
+/// Implements the behaviour that candidate based engines have in common
+///
+/// The selector owns the `LookupTable`, and handles paging, cursor movement,
+/// clicks in the candidate window, and selection with the labels (the
+/// number keys by default). The engine forwards the corresponding calls to
+/// it:
+///
+/// ```
+/// use ibus::engine::{candidates::{CandidateSelector, Selection}, Engine, EngineContext};
+/// use ibus::Modifiers;
+///
+/// struct MyEngine {
+///     selector: CandidateSelector,
+/// }
+/// impl Engine for MyEngine {
+///     fn process_key_event(
+///         &mut self,
+///         ctx: &mut EngineContext,
+///         sym: u32,
+///         _code: u32,
+///         modifiers: Modifiers,
+///     ) -> bool {
+///         match self.selector.process_key_event(ctx, sym, modifiers) {
+///             Some(Selection::Chosen(index)) => {
+///                 let text = self.selector.table().candidates[index as usize].clone();
+///                 self.selector.clear(ctx);
+///                 ctx.commit_text(text);
+///                 true
+///             }
+///             Some(Selection::Moved) => true,
+///             None => false,
+///         }
+///     }
+///
+///     fn page_up(&mut self, ctx: &mut EngineContext) {
+///         self.selector.page_up(ctx);
+///     }
+///
+///     // And the same for `page_down`, `cursor_up`, `cursor_down`, and
+///     // `candidate_clicked`
+/// }
+/// ```
+#[derive(Default)]
+pub struct CandidateSelector {
+    table: LookupTable,
+    visible: bool,
+    /// The source of the candidates that aren't in `table` yet. `None` once
+    /// all candidates are loaded.
+    source: Option<Box<dyn CandidateSource>>,
+}
+impl fmt::Debug for CandidateSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CandidateSelector")
+            .field("table", &self.table)
+            .field("visible", &self.visible)
+            .field("complete", &self.is_complete())
+            .finish()
+    }
+}
+impl CandidateSelector {
+    /// Uses `table` for the page size, labels, and other settings. The
+    /// candidates of the table are kept.
+    pub fn new(table: LookupTable) -> Self {
+        CandidateSelector {
+            table,
+            visible: false,
+            source: None,
+        }
+    }
+
+    pub fn table(&self) -> &LookupTable {
+        &self.table
+    }
+
+    /// Gives access to the lookup table, e.g. for changing the candidates.
+    /// Call `show` or `update` afterwards to send the changes.
+    pub fn table_mut(&mut self) -> &mut LookupTable {
+        &mut self.table
+    }
+
+    /// Whether the lookup table is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Replaces the candidates, moves the cursor to the first one, and shows
+    /// the lookup table (or hides it if there are no candidates)
+    pub fn set_candidates<I, T>(&mut self, ctx: &mut EngineContext, candidates: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Text<'static>>,
+    {
+        self.table.candidates.clear();
+        self.table.cursor_pos = 0;
+        self.source = None;
+        for candidate in candidates {
+            self.table.append_candidate(candidate);
+        }
+        let visible = !self.table.candidates.is_empty();
+        self.update(ctx, visible);
+    }
+
+    /// Like `set_candidates`, but the candidates are fetched from `source` a
+    /// page at a time, when the user moves past the loaded ones
+    ///
+    /// This keeps the latency low when the candidates come from a large
+    /// dictionary. One candidate more than needed is loaded, so that the
+    /// candidate window knows whether there's a next page. Until all the
+    /// candidates are loaded, moving up from the first candidate or page
+    /// doesn't wrap around to the end, even if `LookupTable::round` is set.
+    pub fn set_candidate_source(
+        &mut self,
+        ctx: &mut EngineContext,
+        source: impl CandidateSource + 'static,
+    ) {
+        self.table.candidates.clear();
+        self.table.cursor_pos = 0;
+        self.source = Some(Box::new(source));
+        self.load(self.page_size());
+        let visible = !self.table.candidates.is_empty();
+        self.update(ctx, visible);
+    }
+
+    /// Returns false while there may be candidates that weren't fetched from
+    /// the `CandidateSource` yet
+    pub fn is_complete(&self) -> bool {
+        self.source.is_none()
+    }
+
+    /// Removes the candidates and hides the lookup table
+    pub fn clear(&mut self, ctx: &mut EngineContext) {
+        self.table.candidates.clear();
+        self.table.cursor_pos = 0;
+        self.source = None;
+        if self.visible {
+            self.visible = false;
+            ctx.hide_lookup_table();
+        }
+    }
+
+    /// Sends the lookup table to the application
+    pub fn update(&mut self, ctx: &mut EngineContext, visible: bool) {
+        self.visible = visible;
+        ctx.update_lookup_table(self.table.clone(), visible);
+    }
+
+    pub fn show(&mut self, ctx: &mut EngineContext) {
+        self.update(ctx, true);
+    }
+
+    pub fn hide(&mut self, ctx: &mut EngineContext) {
+        if self.visible {
+            self.visible = false;
+            ctx.hide_lookup_table();
+        }
+    }
+
+    /// The index of the candidate under the cursor, if there are candidates
+    pub fn selected(&self) -> Option<u32> {
+        if self.table.candidates.is_empty() {
+            None
+        } else {
+            Some(self.table.cursor_pos)
+        }
+    }
+
+    /// The candidate under the cursor
+    pub fn selected_candidate(&self) -> Option<&Text<'static>> {
+        self.table.candidates.get(self.selected()? as usize)
+    }
+
+    /// Returns false if there's no previous page
+    pub fn page_up(&mut self, ctx: &mut EngineContext) -> bool {
+        if self.table.cursor_pos < self.page_size() && !self.is_complete() {
+            return false;
+        }
+        self.moved(ctx, LookupTable::page_up)
+    }
+
+    /// Returns false if there's no next page
+    pub fn page_down(&mut self, ctx: &mut EngineContext) -> bool {
+        let next_page = self.table.page_of(self.table.cursor_pos) + 1;
+        self.load((next_page + 1) * self.page_size());
+        self.moved(ctx, LookupTable::page_down)
+    }
+
+    /// Returns false if there's no previous candidate
+    pub fn cursor_up(&mut self, ctx: &mut EngineContext) -> bool {
+        if self.table.cursor_pos == 0 && !self.is_complete() {
+            return false;
+        }
+        self.moved(ctx, LookupTable::cursor_up)
+    }
+
+    /// Returns false if there's no next candidate
+    pub fn cursor_down(&mut self, ctx: &mut EngineContext) -> bool {
+        let next_page = self.table.page_of(self.table.cursor_pos + 1);
+        self.load((next_page + 1) * self.page_size());
+        self.moved(ctx, LookupTable::cursor_down)
+    }
+
+    /// Handles `Engine::candidate_clicked`: moves the cursor to the clicked
+    /// candidate, and returns its index into `LookupTable::candidates`
+    ///
+    /// `index_in_page` is the index that the engine received. Returns `None`
+    /// if there's no such candidate on the current page.
+    pub fn candidate_clicked(
+        &mut self,
+        ctx: &mut EngineContext,
+        index_in_page: u32,
+    ) -> Option<u32> {
+        if index_in_page as usize >= self.table.candidates_in_current_page().len() {
+            return None;
+        }
+        let index = self.table.current_page_start() + index_in_page;
+        if index != self.table.cursor_pos {
+            self.table.cursor_pos = index;
+            self.show(ctx);
+        }
+        Some(index)
+    }
+
+    /// Handles the keys that move within the candidates or choose one
+    ///
+    /// - Up and Down move the cursor (Left and Right for horizontal tables)
+    /// - Page Up and Page Down change the page
+    /// - The labels choose a candidate on the current page
+    ///
+    /// Returns `None` when the lookup table isn't visible, and for any other
+    /// key, including the release of the keys above.
+    pub fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        modifiers: Modifiers,
+    ) -> Option<Selection> {
+        if !self.visible
+            || self.table.candidates.is_empty()
+            || modifiers.contains(Modifiers::RELEASE)
+            || modifiers.intersects(Modifiers::CONTROL | Modifiers::MOD1 | Modifiers::SUPER)
+        {
+            return None;
+        }
+        let horizontal = self.table.orientation == Orientation::Horizontal;
+        let (previous, next) = if horizontal {
+            (
+                [keysyms::KEY_Left, keysyms::KEY_KP_Left],
+                [keysyms::KEY_Right, keysyms::KEY_KP_Right],
+            )
+        } else {
+            (
+                [keysyms::KEY_Up, keysyms::KEY_KP_Up],
+                [keysyms::KEY_Down, keysyms::KEY_KP_Down],
+            )
+        };
+        if previous.contains(&sym) {
+            self.cursor_up(ctx);
+            return Some(Selection::Moved);
+        }
+        if next.contains(&sym) {
+            self.cursor_down(ctx);
+            return Some(Selection::Moved);
+        }
+        match sym {
+            keysyms::KEY_Page_Up | keysyms::KEY_KP_Page_Up => {
+                self.page_up(ctx);
+                Some(Selection::Moved)
+            }
+            keysyms::KEY_Page_Down | keysyms::KEY_KP_Page_Down => {
+                self.page_down(ctx);
+                Some(Selection::Moved)
+            }
+            _ => {
+                let label = keysym_to_char(sym)?;
+                self.table.select_by_label(label).map(Selection::Chosen)
+            }
+        }
+    }
+
+    fn page_size(&self) -> u32 {
+        self.table.page_size.max(1)
+    }
+
+    /// Fetches candidates until there are more than `count`, or the source
+    /// runs out
+    fn load(&mut self, count: u32) {
+        let source = match &mut self.source {
+            Some(source) => source,
+            None => return,
+        };
+        let loaded = self.table.candidates.len();
+        let wanted = count as usize + 1;
+        if loaded >= wanted {
+            return;
+        }
+        // Fetch at least a page, so the source isn't called for every
+        // cursor movement
+        let requested = (wanted - loaded).max(self.table.page_size.max(1) as usize);
+        let candidates = source.fetch(requested);
+        if candidates.len() < requested {
+            self.source = None;
+        }
+        self.table.candidates.extend(candidates);
+    }
+
+    fn moved(&mut self, ctx: &mut EngineContext, f: fn(&mut LookupTable) -> bool) -> bool {
+        let moved = f(&mut self.table);
+        if moved {
+            self.show(ctx);
+        }
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbus::strings::Path;
+
+    #[test]
+    fn keys_and_clicks() {
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        let mut selector = CandidateSelector::new(LookupTable::new(3, 0, true, false));
+        selector.set_candidates(&mut ctx, ["a", "b", "c", "d", "e"]);
+        let none = Modifiers::empty();
+        assert_eq!(
+            selector.process_key_event(&mut ctx, keysyms::KEY_Page_Down, none),
+            Some(Selection::Moved)
+        );
+        assert_eq!(selector.selected(), Some(3));
+        // Labels are relative to the current page
+        assert_eq!(
+            selector.process_key_event(&mut ctx, keysyms::KEY_2, none),
+            Some(Selection::Chosen(4))
+        );
+        assert_eq!(
+            selector.process_key_event(&mut ctx, keysyms::KEY_3, none),
+            None
+        );
+        assert_eq!(selector.candidate_clicked(&mut ctx, 1), Some(4));
+        assert_eq!(selector.selected_candidate().map(|c| c.as_str()), Some("e"));
+        assert_eq!(selector.candidate_clicked(&mut ctx, 2), None);
+
+        selector.clear(&mut ctx);
+        assert!(!selector.is_visible());
+        assert_eq!(
+            selector.process_key_event(&mut ctx, keysyms::KEY_Down, none),
+            None
+        );
+    }
+
+    #[test]
+    fn candidates_are_loaded_lazily() {
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        let mut selector = CandidateSelector::new(LookupTable::new(3, 0, true, true));
+        let mut numbers = (0..8).map(|n| Text::from(n.to_string()));
+        selector.set_candidate_source(&mut ctx, move |n| numbers.by_ref().take(n).collect());
+        assert_eq!(selector.table().candidates.len(), 4);
+        // No wrapping around while the end isn't known
+        assert!(!selector.cursor_up(&mut ctx));
+        assert!(selector.page_down(&mut ctx));
+        assert_eq!(selector.table().candidates.len(), 7);
+        assert!(!selector.is_complete());
+        assert!(selector.page_down(&mut ctx));
+        assert_eq!(selector.table().candidates.len(), 8);
+        assert!(selector.is_complete());
+        assert_eq!(selector.selected(), Some(6));
+        // Back to the first page
+        assert!(selector.page_down(&mut ctx));
+        assert_eq!(selector.selected(), Some(0));
+        assert!(selector.cursor_up(&mut ctx));
+        assert_eq!(selector.selected(), Some(7));
+    }
+}
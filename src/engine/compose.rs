@@ -0,0 +1,534 @@
+//! An engine for compose sequences
+//!
+//! `ComposeEngine` implements the compose key and dead keys the same way
+//! X11 does, using the XCompose files of the system and the user. It's
+//! useful on its own, and it's also a small but complete example of an
+//! engine.
+//!
+
+use std::{
+    collections::BTreeMap,
+    ops::Bound,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use log::debug;
+
+use crate::{
+    combine_dead_keys, dead_key_spacing_char, is_dead_key,
+    keysyms::{self, is_modifier_key, keysym_from_name, keysym_to_char},
+    Attribute, AttributeKind, Error, Modifiers, Text, UnderlineKind,
+};
+
+use super::{Engine, EngineContext};
+
+const SYSTEM_LOCALE_DIR: &str = "/usr/share/X11/locale";
+
+/// Includes can't nest deeper than this, to avoid include loops
+const MAX_INCLUDE_DEPTH: u32 = 8;
+
+/// The result of looking up a key sequence in a `ComposeTable`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeMatch<'a> {
+    /// The sequence isn't the start of any compose sequence
+    None,
+
+    /// The sequence is the start of at least one compose sequence
+    Prefix,
+
+    /// The sequence is complete and produces the contained string
+    Complete(&'a str),
+}
+
+/// Compose sequences, mapping a list of keysyms to the string they produce
+#[derive(Debug, Clone, Default)]
+pub struct ComposeTable {
+    sequences: BTreeMap<Vec<u32>, String>,
+}
+impl ComposeTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the same table that X11 applications would use
+    ///
+    /// This is `$XCOMPOSEFILE` if it's set, otherwise `~/.XCompose` if it
+    /// exists, otherwise the Compose file of the current locale. Errors are
+    /// logged and result in a table with fewer (or no) sequences.
+    pub fn load_default() -> Self {
+        let mut table = Self::new();
+        let user_file = std::env::var_os("XCOMPOSEFILE")
+            .map(PathBuf::from)
+            .or_else(|| home_dir().map(|home| home.join(".XCompose")))
+            .filter(|path| path.is_file());
+        let path = match user_file.or_else(system_compose_file) {
+            Some(path) => path,
+            None => {
+                debug!("Couldn't find any Compose file");
+                return table;
+            }
+        };
+        if let Err(e) = table.load_file(&path) {
+            debug!("Couldn't load {:?}: {}", path, e);
+        }
+        table
+    }
+
+    /// Parses a Compose file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut table = Self::new();
+        table.load_file(path.as_ref())?;
+        Ok(table)
+    }
+
+    /// Parses the contents of a Compose file
+    ///
+    /// Lines that can't be parsed are skipped. Include directives are
+    /// followed, and relative include paths are resolved against the current
+    /// directory.
+    pub fn parse(content: &str) -> Self {
+        let mut table = Self::new();
+        table.parse_with_depth(content, 0);
+        table
+    }
+
+    /// Adds the sequences of a Compose file to the table. Sequences that are
+    /// already in the table are overridden.
+    pub fn load_file(&mut self, path: &Path) -> Result<(), Error> {
+        self.load_file_with_depth(path, 0)
+    }
+
+    /// Adds a sequence to the table, overriding the previous result of the
+    /// same sequence
+    pub fn insert(&mut self, sequence: Vec<u32>, result: impl Into<String>) {
+        if sequence.is_empty() {
+            return;
+        }
+        self.sequences.insert(sequence, result.into());
+    }
+
+    pub fn lookup(&self, sequence: &[u32]) -> ComposeMatch<'_> {
+        if sequence.is_empty() {
+            return ComposeMatch::Prefix;
+        }
+        let mut range = self
+            .sequences
+            .range::<[u32], _>((Bound::Included(sequence), Bound::Unbounded));
+        match range.next() {
+            Some((key, result)) if key.as_slice() == sequence => ComposeMatch::Complete(result),
+            Some((key, _)) if key.starts_with(sequence) => ComposeMatch::Prefix,
+            _ => ComposeMatch::None,
+        }
+    }
+
+    /// The number of sequences
+    pub fn len(&self) -> usize {
+        self.sequences.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+
+    fn load_file_with_depth(&mut self, path: &Path, depth: u32) -> Result<(), Error> {
+        let content = std::fs::read_to_string(path)?;
+        self.parse_with_depth(&content, depth);
+        Ok(())
+    }
+
+    fn parse_with_depth(&mut self, content: &str, depth: u32) {
+        for (line_index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix("include") {
+                self.include(rest, depth);
+                continue;
+            }
+            match parse_sequence_line(line) {
+                Some((sequence, result)) => self.insert(sequence, result),
+                None => debug!(
+                    "Skipping line {} of the Compose file: {:?}",
+                    line_index + 1,
+                    line
+                ),
+            }
+        }
+    }
+
+    fn include(&mut self, directive: &str, depth: u32) {
+        if depth >= MAX_INCLUDE_DEPTH {
+            debug!(
+                "Compose includes are nested too deep, ignoring: {}",
+                directive
+            );
+            return;
+        }
+        let path = match parse_string(directive.trim()) {
+            Some((path, _)) => path,
+            None => {
+                debug!("Invalid include directive: {:?}", directive);
+                return;
+            }
+        };
+        let path = match expand_include_path(&path) {
+            Some(path) => path,
+            None => {
+                debug!("Couldn't resolve the include path {:?}", path);
+                return;
+            }
+        };
+        if let Err(e) = self.load_file_with_depth(&path, depth + 1) {
+            debug!("Couldn't include {:?}: {}", path, e);
+        }
+    }
+}
+
+/// Parses `<key> <key> ... : "result" keysym`, where both the string and the
+/// keysym are optional, but at least one of them has to be there
+fn parse_sequence_line(line: &str) -> Option<(Vec<u32>, String)> {
+    let (keys, result) = line.split_once(':')?;
+    let mut sequence = Vec::new();
+    for key in keys.split_whitespace() {
+        // Lines with modifiers (e.g. `~Ctrl <a>`) aren't supported
+        let name = key.strip_prefix('<')?.strip_suffix('>')?;
+        sequence.push(keysym_from_name(name)?);
+    }
+    if sequence.is_empty() {
+        return None;
+    }
+    let result = result.trim_start();
+    if result.starts_with('"') {
+        let (string, _) = parse_string(result)?;
+        return Some((sequence, string));
+    }
+    let keysym = result.split_whitespace().next()?;
+    let c = keysym_to_char(keysym_from_name(keysym)?)?;
+    Some((sequence, c.to_string()))
+}
+
+/// Parses a quoted string with the escapes that XCompose supports, and
+/// returns it along with the rest of the input
+fn parse_string(input: &str) -> Option<(String, &str)> {
+    let mut chars = input.strip_prefix('"')?.char_indices().peekable();
+    let mut string = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((string, &input[i + 2..])),
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                match escaped {
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'x' | 'X' => {
+                        let mut value: u32 = 0;
+                        while let Some(digit) = chars.peek().and_then(|&(_, c)| c.to_digit(16)) {
+                            // Too large for a char, the line is skipped
+                            value = value.checked_mul(16)?.checked_add(digit)?;
+                            chars.next();
+                        }
+                        string.push(char::from_u32(value)?);
+                    }
+                    '0'..='7' => {
+                        let mut value = escaped.to_digit(8)?;
+                        for _ in 0..2 {
+                            match chars.peek().and_then(|&(_, c)| c.to_digit(8)) {
+                                Some(digit) => {
+                                    value = value * 8 + digit;
+                                    chars.next();
+                                }
+                                None => break,
+                            }
+                        }
+                        string.push(char::from_u32(value)?);
+                    }
+                    other => string.push(other),
+                }
+            }
+            c => string.push(c),
+        }
+    }
+    None
+}
+
+/// Expands `%H` (home), `%L` (the Compose file of the locale), and `%S` (the
+/// system locale directory)
+fn expand_include_path(path: &str) -> Option<PathBuf> {
+    if path == "%L" {
+        return system_compose_file();
+    }
+    let mut expanded = String::new();
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            expanded.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'H' => expanded.push_str(home_dir()?.to_str()?),
+            'L' => expanded.push_str(system_compose_file()?.to_str()?),
+            'S' => expanded.push_str(system_locale_dir().to_str()?),
+            '%' => expanded.push('%'),
+            _ => return None,
+        }
+    }
+    Some(expanded.into())
+}
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+fn system_locale_dir() -> PathBuf {
+    std::env::var_os("XLOCALEDIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| SYSTEM_LOCALE_DIR.into())
+}
+
+/// Finds the Compose file of the current locale using `compose.dir`
+fn system_compose_file() -> Option<PathBuf> {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| "C".into());
+    // Modifiers like `@euro` don't affect the compose file
+    let locale = locale.split('@').next().unwrap_or_default();
+    let normalized = match locale.split_once('.') {
+        Some((lang, codeset)) if codeset.eq_ignore_ascii_case("utf8") => format!("{}.UTF-8", lang),
+        _ => locale.to_owned(),
+    };
+
+    let dir = system_locale_dir();
+    let compose_dir = std::fs::read_to_string(dir.join("compose.dir")).ok()?;
+    compose_dir.lines().find_map(|line| {
+        if line.starts_with('#') {
+            return None;
+        }
+        let mut columns = line.split_whitespace();
+        let file = columns.next()?.trim_end_matches(':');
+        let name = columns.next()?;
+        if name == locale || name == normalized {
+            Some(dir.join(file))
+        } else {
+            None
+        }
+    })
+}
+
+/// Implements compose sequences like `<Multi_key> <apostrophe> <e>` → "é"
+///
+/// Dead keys that have no sequence in the table are combined with the next
+/// character using `combine_dead_keys`.
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use ibus::engine::compose::{ComposeEngine, ComposeTable};
+///
+/// let table = Arc::new(ComposeTable::load_default());
+/// let engine = ComposeEngine::new(table);
+/// ```
+pub struct ComposeEngine {
+    table: Arc<ComposeTable>,
+    sequence: Vec<u32>,
+}
+impl ComposeEngine {
+    /// The table is behind an `Arc` so that the engines of several input
+    /// contexts can share it
+    pub fn new(table: Arc<ComposeTable>) -> Self {
+        ComposeEngine {
+            table,
+            sequence: Vec::new(),
+        }
+    }
+
+    /// The keys of the sequence that's being composed
+    pub fn sequence(&self) -> &[u32] {
+        &self.sequence
+    }
+
+    fn show_sequence(&self, ctx: &mut EngineContext) {
+        let string: String = self
+            .sequence
+            .iter()
+            .map(|&keysym| {
+                keysym_to_char(keysym)
+                    .or_else(|| dead_key_spacing_char(keysym))
+                    .unwrap_or('·')
+            })
+            .collect();
+        let len = string.chars().count() as u32;
+        let attributes = vec![Attribute {
+            kind: AttributeKind::Underline(UnderlineKind::Single),
+            start_index: 0,
+            end_index: len,
+        }];
+        ctx.update_preedit_text(Text::new(string, attributes), len, true);
+    }
+
+    fn cancel(&mut self, ctx: &mut EngineContext) {
+        if !self.sequence.is_empty() {
+            self.sequence.clear();
+            ctx.hide_preedit_text();
+        }
+    }
+}
+
+impl Engine for ComposeEngine {
+    fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        _code: u32,
+        modifiers: Modifiers,
+    ) -> bool {
+        if modifiers.contains(Modifiers::RELEASE) || is_modifier_key(sym) {
+            return false;
+        }
+        if self.sequence.is_empty()
+            && modifiers.intersects(Modifiers::CONTROL | Modifiers::MOD1 | Modifiers::SUPER)
+        {
+            return false;
+        }
+        if !self.sequence.is_empty() {
+            match sym {
+                keysyms::KEY_BackSpace => {
+                    self.sequence.pop();
+                    if self.sequence.is_empty() {
+                        ctx.hide_preedit_text();
+                    } else {
+                        self.show_sequence(ctx);
+                    }
+                    return true;
+                }
+                keysyms::KEY_Escape => {
+                    self.cancel(ctx);
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        self.sequence.push(sym);
+        match self.table.lookup(&self.sequence) {
+            ComposeMatch::Complete(result) => {
+                let result = result.to_owned();
+                self.cancel(ctx);
+                ctx.commit_text(result);
+                true
+            }
+            ComposeMatch::Prefix => {
+                self.show_sequence(ctx);
+                true
+            }
+            ComposeMatch::None => {
+                // Dead keys that the table doesn't know about are combined
+                // with the following character
+                let (&last, dead_keys) = self.sequence.split_last().unwrap();
+                if dead_keys.iter().all(|&k| is_dead_key(k)) {
+                    if is_dead_key(last) {
+                        self.show_sequence(ctx);
+                        return true;
+                    }
+                    let combined = keysym_to_char(last)
+                        .filter(|_| !dead_keys.is_empty())
+                        .and_then(|c| combine_dead_keys(dead_keys, c));
+                    if let Some(result) = combined {
+                        self.cancel(ctx);
+                        ctx.commit_text(result);
+                        return true;
+                    }
+                }
+                if self.sequence.len() == 1 {
+                    // Not a compose sequence, let the application handle it
+                    self.sequence.clear();
+                    false
+                } else {
+                    // An invalid sequence swallows the last key, like in X11
+                    self.cancel(ctx);
+                    true
+                }
+            }
+        }
+    }
+
+    fn focus_out(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+    }
+
+    fn reset(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+    }
+
+    fn disable(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::EngineSignal;
+    use dbus::strings::Path as ObjectPath;
+
+    const COMPOSE: &str = r#"
+# A comment
+<Multi_key> <apostrophe> <e>    : "é"   eacute # LATIN SMALL LETTER E WITH ACUTE
+<dead_acute> <a>                : aacute
+<Multi_key> <quotedbl> <quotedbl> : "\"\x263a\101"
+<Multi_key> ~Ctrl <a>           : "ignored"
+<Multi_key> <x> <x>             : "\x123456789"
+"#;
+
+    #[test]
+    fn parse_and_lookup() {
+        let table = ComposeTable::parse(COMPOSE);
+        assert_eq!(table.len(), 3);
+        let multi = keysyms::KEY_Multi_key;
+        assert_eq!(table.lookup(&[multi]), ComposeMatch::Prefix);
+        assert_eq!(
+            table.lookup(&[multi, keysyms::KEY_apostrophe, keysyms::KEY_e]),
+            ComposeMatch::Complete("é")
+        );
+        assert_eq!(
+            table.lookup(&[keysyms::KEY_dead_acute, keysyms::KEY_a]),
+            ComposeMatch::Complete("á")
+        );
+        assert_eq!(
+            table.lookup(&[multi, keysyms::KEY_quotedbl, keysyms::KEY_quotedbl]),
+            ComposeMatch::Complete("\"☺A")
+        );
+        assert_eq!(table.lookup(&[keysyms::KEY_a]), ComposeMatch::None);
+        assert_eq!(
+            table.lookup(&[multi, keysyms::KEY_x, keysyms::KEY_x]),
+            ComposeMatch::None
+        );
+    }
+
+    #[test]
+    fn engine_composes() {
+        let table = Arc::new(ComposeTable::parse(COMPOSE));
+        let mut engine = ComposeEngine::new(table);
+        let mut ctx = EngineContext::new(ObjectPath::from("/a"));
+        let mut press = |sym| engine.process_key_event(&mut ctx, sym, 0, Modifiers::empty());
+        assert!(!press(keysyms::KEY_x));
+        assert!(press(keysyms::KEY_Multi_key));
+        assert!(press(keysyms::KEY_apostrophe));
+        assert!(press(keysyms::KEY_e));
+        let signals = ctx.take_signals();
+        assert_eq!(signals.last(), Some(&EngineSignal::CommitText("é".into())));
+        assert!(signals.contains(&EngineSignal::HidePreeditText));
+
+        // Not in the table
+        for sym in [keysyms::KEY_dead_grave, keysyms::KEY_o] {
+            assert!(engine.process_key_event(&mut ctx, sym, 0, Modifiers::empty()));
+        }
+        assert_eq!(
+            ctx.take_signals().last(),
+            Some(&EngineSignal::CommitText("ò".into()))
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Reconverting text that was already committed
+//!
+//! With reconversion the user selects some text in the application (or
+//! places the cursor after a word) and asks the engine to convert it again.
+//! The engine removes the text from the application, and continues with it
+//! as preedit text, as if it had just been typed.
+//!
+//! `Reconversion` implements the steps that need the application:
+//! fetching the surrounding text, deleting the range, and putting the
+//! original text back if the user cancels.
+//!
+//! ```
+//! use ibus::engine::{reconvert::{ReconvertStart, Reconversion}, Engine, EngineContext};
+//! use ibus::{keysyms, Modifiers};
+//!
+//! struct MyEngine {
+//!     reconversion: Reconversion,
+//!     composition: String,
+//! }
+//! impl MyEngine {
+//!     fn begin(&mut self, ctx: &mut EngineContext, text: String) {
+//!         // Convert `text` as if the user had typed it
+//!         self.composition = text;
+//!     }
+//! }
+//! impl Engine for MyEngine {
+//!     fn process_key_event(
+//!         &mut self,
+//!         ctx: &mut EngineContext,
+//!         sym: u32,
+//!         _code: u32,
+//!         modifiers: Modifiers,
+//!     ) -> bool {
+//!         if sym == keysyms::KEY_r && modifiers == Modifiers::CONTROL {
+//!             if let ReconvertStart::Started(text) = self.reconversion.start(ctx) {
+//!                 self.begin(ctx, text);
+//!             }
+//!             return true;
+//!         }
+//!         if sym == keysyms::KEY_Escape && self.reconversion.is_active() {
+//!             self.reconversion.cancel(ctx);
+//!             return true;
+//!         }
+//!         // When the composition is committed, call `self.reconversion.finish()`
+//!         false
+//!     }
+//!
+//!     fn surrounding_text_changed(&mut self, ctx: &mut EngineContext) {
+//!         if let Some(text) = self.reconversion.surrounding_text_changed(ctx) {
+//!             self.begin(ctx, text);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+
+use crate::{Attribute, AttributeKind, Capabilites, Text, UnderlineKind};
+
+use super::EngineContext;
+
+/// The result of `Reconversion::start`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReconvertStart {
+    /// The text was removed from the application and is shown as preedit
+    /// text. The engine should continue composing with it.
+    Started(String),
+    /// The surrounding text was requested from the application.
+    /// `Reconversion::surrounding_text_changed` finishes starting when it
+    /// arrives.
+    Waiting,
+    /// There's nothing to reconvert, or the application doesn't report the
+    /// surrounding text
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Idle,
+    Waiting,
+    Active {
+        original: String,
+    },
+}
+
+/// Tracks a reconversion, see the module documentation
+#[derive(Debug, Clone, Default)]
+pub struct Reconversion {
+    state: State,
+}
+impl Reconversion {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the text is being reconverted, i.e. `start` succeeded, and
+    /// neither `finish` nor `cancel` was called since
+    pub fn is_active(&self) -> bool {
+        matches!(self.state, State::Active { .. })
+    }
+
+    /// The text that was removed from the application
+    pub fn original(&self) -> Option<&str> {
+        match &self.state {
+            State::Active { original } => Some(original),
+            _ => None,
+        }
+    }
+
+    /// Starts reconverting the selected text, or the word before the cursor
+    /// if nothing is selected
+    pub fn start(&mut self, ctx: &mut EngineContext) -> ReconvertStart {
+        if ctx.surrounding_text().is_none() {
+            if !ctx.capabilities().contains(Capabilites::SURROUNDING_TEXT) {
+                return ReconvertStart::Unavailable;
+            }
+            self.state = State::Waiting;
+            ctx.require_surrounding_text();
+            return ReconvertStart::Waiting;
+        }
+        match self.take_range(ctx) {
+            Some(text) => ReconvertStart::Started(text),
+            None => {
+                self.state = State::Idle;
+                ReconvertStart::Unavailable
+            }
+        }
+    }
+
+    /// Call this from `Engine::surrounding_text_changed`. Returns the text
+    /// to reconvert if the reconversion was waiting for the surrounding text.
+    pub fn surrounding_text_changed(&mut self, ctx: &mut EngineContext) -> Option<String> {
+        if self.state != State::Waiting {
+            return None;
+        }
+        self.state = State::Idle;
+        self.take_range(ctx)
+    }
+
+    /// Ends the reconversion after the engine committed the result
+    pub fn finish(&mut self) {
+        self.state = State::Idle;
+    }
+
+    /// Puts the original text back into the application, and hides the
+    /// preedit text
+    ///
+    /// Call this when the user cancels the conversion, and when the input
+    /// context loses focus or is reset.
+    pub fn cancel(&mut self, ctx: &mut EngineContext) {
+        if let State::Active { original } = std::mem::take(&mut self.state) {
+            ctx.hide_preedit_text();
+            ctx.commit_text(original);
+        }
+    }
+
+    /// Removes the text to reconvert from the application, and shows it as
+    /// preedit text instead
+    fn take_range(&mut self, ctx: &mut EngineContext) -> Option<String> {
+        let surrounding = ctx.surrounding_text()?;
+        let chars: Vec<char> = surrounding.text.as_str().chars().collect();
+        let cursor = (surrounding.cursor_pos as usize).min(chars.len());
+        let anchor = (surrounding.anchor_pos as usize).min(chars.len());
+        let (start, end) = if cursor != anchor {
+            (cursor.min(anchor), cursor.max(anchor))
+        } else {
+            let start = chars[..cursor]
+                .iter()
+                .rposition(|c| !c.is_alphanumeric())
+                .map_or(0, |i| i + 1);
+            (start, cursor)
+        };
+        if start == end {
+            return None;
+        }
+        let original: String = chars[start..end].iter().collect();
+        ctx.delete_surrounding_text(start as i32 - cursor as i32, (end - start) as u32);
+
+        let len = (end - start) as u32;
+        let attributes = vec![Attribute {
+            kind: AttributeKind::Underline(UnderlineKind::Single),
+            start_index: 0,
+            end_index: len,
+        }];
+        ctx.update_preedit_text(Text::new(original.clone(), attributes), len, true);
+        self.state = State::Active {
+            original: original.clone(),
+        };
+        Some(original)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{EngineSignal, SurroundingText};
+    use dbus::strings::Path;
+
+    #[test]
+    fn reconvert_word_before_cursor() {
+        let mut ctx = EngineContext::new(Path::from("/a"));
+        let mut reconversion = Reconversion::new();
+        assert_eq!(reconversion.start(&mut ctx), ReconvertStart::Unavailable);
+
+        ctx.capabilities = Capabilites::SURROUNDING_TEXT;
+        assert_eq!(reconversion.start(&mut ctx), ReconvertStart::Waiting);
+        ctx.surrounding_text = Some(SurroundingText {
+            text: "один два три".into(),
+            cursor_pos: 8,
+            anchor_pos: 8,
+        });
+        assert_eq!(
+            reconversion.surrounding_text_changed(&mut ctx).as_deref(),
+            Some("два")
+        );
+        assert_eq!(reconversion.original(), Some("два"));
+        assert_eq!(ctx.surrounding_text().unwrap().text.as_str(), "один  три");
+        let signals = ctx.take_signals();
+        assert!(signals.contains(&EngineSignal::DeleteSurroundingText {
+            offset: -3,
+            nchars: 3
+        }));
+
+        reconversion.cancel(&mut ctx);
+        assert!(!reconversion.is_active());
+        assert_eq!(
+            ctx.take_signals().last(),
+            Some(&EngineSignal::CommitText("два".into()))
+        );
+    }
+}
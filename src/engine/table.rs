@@ -0,0 +1,352 @@
+//! An engine driven by a table of key sequences and candidates
+//!
+//! `TableEngine` collects the typed characters, shows the candidates of all
+//! the sequences that start with them in a lookup table, and commits the
+//! selected candidate. This is enough for many transliteration and
+//! romanization schemes, where only a data file has to be written.
+//!
+//! The data file is a tab separated list, where each line contains a key
+//! sequence followed by one or more candidates. Lines starting with '#' are
+//! comments.
+//!
+//! ```
+//! use ibus::engine::table::CandidateTable;
+//!
+//! let table = CandidateTable::parse("# Hiragana and katakana\nka\tか\tカ\nki\tき\tキ\n");
+//! assert_eq!(table.get("ka"), ["か", "カ"]);
+//! ```
+//!
+
+use std::{collections::BTreeMap, ops::Bound, path::Path, sync::Arc};
+
+use log::debug;
+
+use crate::{
+    keysyms::{self, keysym_to_char},
+    Attribute, AttributeKind, Error, LookupTable, Modifiers, Text, UnderlineKind,
+};
+
+use super::{
+    candidates::{CandidateSelector, Selection},
+    Engine, EngineContext,
+};
+
+/// Maps key sequences to their candidates
+#[derive(Debug, Clone, Default)]
+pub struct CandidateTable {
+    entries: BTreeMap<String, Vec<String>>,
+}
+impl CandidateTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a tab separated table. See the module documentation for the
+    /// format.
+    ///
+    /// Lines without candidates are skipped. When a sequence appears on
+    /// several lines, the candidates are appended in order.
+    pub fn parse(content: &str) -> Self {
+        let mut table = Self::new();
+        for (line_index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut columns = line.split('\t');
+            let sequence = columns.next().unwrap_or_default();
+            let mut candidates = columns.filter(|c| !c.is_empty()).peekable();
+            if sequence.is_empty() || candidates.peek().is_none() {
+                debug!(
+                    "Skipping line {} of the candidate table: {:?}",
+                    line_index + 1,
+                    line
+                );
+                continue;
+            }
+            for candidate in candidates {
+                table.insert(sequence, candidate);
+            }
+        }
+        table
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Appends a candidate to the candidates of `sequence`
+    pub fn insert(&mut self, sequence: impl Into<String>, candidate: impl Into<String>) {
+        self.entries
+            .entry(sequence.into())
+            .or_default()
+            .push(candidate.into());
+    }
+
+    /// The candidates of exactly this sequence
+    pub fn get(&self, sequence: &str) -> &[String] {
+        self.entries.get(sequence).map_or(&[], |c| c.as_slice())
+    }
+
+    /// Returns true if at least one sequence starts with `prefix`
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.with_prefix(prefix).next().is_some()
+    }
+
+    /// The candidates of all sequences that start with `prefix`. The
+    /// candidates of the exact match come first, and the others are ordered
+    /// by their sequence.
+    pub fn candidates_with_prefix<'a>(&'a self, prefix: &str) -> Vec<&'a str> {
+        self.with_prefix(prefix)
+            .flat_map(|(_, candidates)| candidates.iter().map(String::as_str))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn with_prefix<'a: 'p, 'p>(
+        &'a self,
+        prefix: &'p str,
+    ) -> impl Iterator<Item = (&'a String, &'a Vec<String>)> + 'p {
+        self.entries
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(move |(sequence, _)| sequence.starts_with(prefix))
+    }
+}
+
+/// An engine that converts the typed sequences using a `CandidateTable`
+///
+/// While composing:
+/// - Space commits the selected candidate, Enter commits the typed sequence
+/// - The labels of the lookup table (1-9 and 0 by default) select a candidate
+///   on the current page, unless the digit continues a sequence
+/// - Up and Down move the cursor, Page Up and Page Down change the page
+/// - Backspace removes the last character, Escape cancels
+/// - Any other character commits the selected candidate first
+pub struct TableEngine {
+    table: Arc<CandidateTable>,
+    input: String,
+    selector: CandidateSelector,
+}
+impl TableEngine {
+    /// The table is behind an `Arc` so that the engines of several input
+    /// contexts can share it
+    pub fn new(table: Arc<CandidateTable>) -> Self {
+        Self::with_lookup_table(table, LookupTable::default())
+    }
+
+    /// Uses `lookup_table` as a template, e.g. for the page size and labels.
+    /// Its candidates are replaced while composing.
+    pub fn with_lookup_table(table: Arc<CandidateTable>, mut lookup_table: LookupTable) -> Self {
+        lookup_table.candidates.clear();
+        lookup_table.cursor_pos = 0;
+        TableEngine {
+            table,
+            input: String::new(),
+            selector: CandidateSelector::new(lookup_table),
+        }
+    }
+
+    /// The characters typed so far
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    fn update(&mut self, ctx: &mut EngineContext) {
+        if self.input.is_empty() {
+            ctx.hide_preedit_text();
+            self.selector.clear(ctx);
+            return;
+        }
+        let len = self.input.chars().count() as u32;
+        let attributes = vec![Attribute {
+            kind: AttributeKind::Underline(UnderlineKind::Single),
+            start_index: 0,
+            end_index: len,
+        }];
+        ctx.update_preedit_text(Text::new(self.input.clone(), attributes), len, true);
+
+        let candidates = self.table.candidates_with_prefix(&self.input);
+        self.selector
+            .set_candidates(ctx, candidates.into_iter().map(str::to_owned));
+    }
+
+    /// Commits the candidate at `index`, or the typed sequence if there's no
+    /// such candidate
+    fn commit(&mut self, ctx: &mut EngineContext, index: Option<u32>) {
+        let text = index
+            .and_then(|i| self.selector.table().candidates.get(i as usize))
+            .map_or_else(|| self.input.clone(), |c| c.as_str().to_owned());
+        self.input.clear();
+        self.update(ctx);
+        ctx.commit_text(text);
+    }
+
+    fn cancel(&mut self, ctx: &mut EngineContext) {
+        if !self.input.is_empty() {
+            self.input.clear();
+            self.update(ctx);
+        }
+    }
+
+    /// Appends `c` to the input if a sequence starts with the result.
+    /// Returns false otherwise.
+    fn push_input(&mut self, ctx: &mut EngineContext, c: char) -> bool {
+        self.input.push(c);
+        if !self.table.has_prefix(&self.input) {
+            self.input.pop();
+            return false;
+        }
+        self.update(ctx);
+        true
+    }
+}
+
+impl Engine for TableEngine {
+    fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        _code: u32,
+        modifiers: Modifiers,
+    ) -> bool {
+        if modifiers.contains(Modifiers::RELEASE)
+            || modifiers.intersects(Modifiers::CONTROL | Modifiers::MOD1 | Modifiers::SUPER)
+        {
+            return false;
+        }
+        let c = keysym_to_char(sym);
+        if c.is_some_and(|c| self.push_input(ctx, c)) {
+            return true;
+        }
+        if self.input.is_empty() {
+            return false;
+        }
+
+        match self.selector.process_key_event(ctx, sym, modifiers) {
+            Some(Selection::Chosen(index)) => {
+                self.commit(ctx, Some(index));
+                return true;
+            }
+            Some(Selection::Moved) => return true,
+            None => {}
+        }
+
+        let cursor = self.selector.selected();
+        match sym {
+            keysyms::KEY_space => self.commit(ctx, cursor),
+            keysyms::KEY_Return | keysyms::KEY_KP_Enter => self.commit(ctx, None),
+            keysyms::KEY_BackSpace => {
+                self.input.pop();
+                self.update(ctx);
+            }
+            keysyms::KEY_Escape => self.cancel(ctx),
+            _ => {
+                if c.is_none() {
+                    // A function key, e.g. an arrow key. Keep composing.
+                    return true;
+                }
+                self.commit(ctx, cursor);
+                // The key may start a new sequence
+                return c.is_some_and(|c| self.push_input(ctx, c));
+            }
+        }
+        true
+    }
+
+    fn page_up(&mut self, ctx: &mut EngineContext) {
+        self.selector.page_up(ctx);
+    }
+
+    fn page_down(&mut self, ctx: &mut EngineContext) {
+        self.selector.page_down(ctx);
+    }
+
+    fn cursor_up(&mut self, ctx: &mut EngineContext) {
+        self.selector.cursor_up(ctx);
+    }
+
+    fn cursor_down(&mut self, ctx: &mut EngineContext) {
+        self.selector.cursor_down(ctx);
+    }
+
+    fn candidate_clicked(
+        &mut self,
+        ctx: &mut EngineContext,
+        index: u32,
+        _button: u32,
+        _state: Modifiers,
+    ) {
+        if self.input.is_empty() {
+            return;
+        }
+        if let Some(index) = self.selector.candidate_clicked(ctx, index) {
+            self.commit(ctx, Some(index));
+        }
+    }
+
+    fn focus_out(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+    }
+
+    fn reset(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+    }
+
+    fn disable(&mut self, ctx: &mut EngineContext) {
+        self.cancel(ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::EngineSignal;
+    use dbus::strings::Path as ObjectPath;
+
+    const TABLE: &str = "# comment\nka\tか\tカ\nki\tき\nkya\tきゃ\nn\tん\n";
+
+    #[test]
+    fn parse_and_prefix_lookup() {
+        let table = CandidateTable::parse(TABLE);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table.get("ka"), ["か", "カ"]);
+        assert_eq!(
+            table.candidates_with_prefix("k"),
+            ["か", "カ", "き", "きゃ"]
+        );
+        assert!(table.has_prefix("ky"));
+        assert!(!table.has_prefix("x"));
+    }
+
+    #[test]
+    fn engine_selects_candidates() {
+        let mut engine = TableEngine::new(Arc::new(CandidateTable::parse(TABLE)));
+        let mut ctx = EngineContext::new(ObjectPath::from("/a"));
+        let mut press = |sym| engine.process_key_event(&mut ctx, sym, 0, Modifiers::empty());
+        assert!(!press(keysyms::KEY_x));
+        assert!(press(keysyms::KEY_k));
+        assert!(press(keysyms::KEY_a));
+        // The second candidate
+        assert!(press(keysyms::KEY_2));
+        assert!(press(keysyms::KEY_n));
+        // "n" is committed before "k" starts a new sequence
+        assert!(press(keysyms::KEY_k));
+        assert!(press(keysyms::KEY_i));
+        assert!(press(keysyms::KEY_space));
+        let commits: Vec<_> = ctx
+            .take_signals()
+            .into_iter()
+            .filter_map(|s| match s {
+                EngineSignal::CommitText(text) => Some(text.into_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(commits, ["カ", "ん", "き"]);
+    }
+}
@@ -0,0 +1,380 @@
+//! Testing engines without a daemon
+//!
+//! `EngineTester` wraps an engine, feeds it method calls the same way the
+//! daemon would, and records the signals that the engine emits. It also
+//! keeps track of what the application would display, so tests can assert
+//! on the result instead of the individual signals.
+//!
+//! ```
+//! use ibus::engine::{testing::EngineTester, Engine, EngineContext};
+//! use ibus::Modifiers;
+//!
+//! struct Upper;
+//! impl Engine for Upper {
+//!     fn process_key_event(
+//!         &mut self,
+//!         ctx: &mut EngineContext,
+//!         sym: u32,
+//!         _code: u32,
+//!         modifiers: Modifiers,
+//!     ) -> bool {
+//!         match char::from_u32(sym) {
+//!             Some(c) if c.is_ascii_lowercase() && !modifiers.contains(Modifiers::RELEASE) => {
+//!                 ctx.commit_text(c.to_ascii_uppercase().to_string());
+//!                 true
+//!             }
+//!             _ => false,
+//!         }
+//!     }
+//! }
+//!
+//! let mut tester = EngineTester::new(Upper);
+//! tester.type_str("abc");
+//! assert_eq!(tester.committed_text(), "ABC");
+//! ```
+//!
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use dbus::{
+    arg::{AppendAll, IterAppend},
+    strings::Path,
+    Message,
+};
+
+use crate::{
+    Capabilites, InputHints, InputPurpose, LookupTable, Modifiers, PropList, PropState, Text,
+};
+
+use super::{Engine, EngineObject, EngineSignal, ENGINE_INTERFACE};
+
+/// The state of the preedit text, the auxiliary text, or the lookup table,
+/// as the application would display it
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Shown<T> {
+    value: T,
+    visible: bool,
+}
+impl<T> Shown<T> {
+    fn get(&self) -> Option<&T> {
+        if self.visible {
+            Some(&self.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Drives an engine without a D-Bus connection
+///
+/// Every method call goes through the same dispatching code that handles
+/// the calls from the daemon, so the engine sees exactly what it would see
+/// when running for real.
+pub struct EngineTester {
+    object: EngineObject,
+    signals: Vec<EngineSignal>,
+    committed: String,
+    preedit: Shown<(Text<'static>, u32)>,
+    auxiliary: Shown<Text<'static>>,
+    lookup_table: Shown<LookupTable>,
+    properties: PropList,
+    key_replies: VecDeque<Message>,
+}
+impl EngineTester {
+    pub fn new(engine: impl Engine + 'static) -> Self {
+        Self::from_box(Box::new(engine))
+    }
+
+    pub fn from_box(engine: Box<dyn Engine>) -> Self {
+        let path = Path::from("/org/freedesktop/IBus/Engine/1");
+        EngineTester {
+            object: EngineObject::new(path, engine),
+            signals: Vec::new(),
+            committed: String::new(),
+            preedit: Shown {
+                value: (Text::from(String::new()), 0),
+                visible: false,
+            },
+            auxiliary: Shown {
+                value: Text::from(String::new()),
+                visible: false,
+            },
+            lookup_table: Shown {
+                value: LookupTable::default(),
+                visible: false,
+            },
+            properties: PropList::new(),
+            key_replies: VecDeque::new(),
+        }
+    }
+
+    /// Sends a key event, and returns whether the engine handled it
+    ///
+    /// If the engine defers the reply, this waits for it the same way the
+    /// daemon would. See `wait_for_key_event`.
+    pub fn key_event(&mut self, sym: u32, code: u32, modifiers: Modifiers) -> bool {
+        match self.send_key_event(sym, code, modifiers) {
+            Some(handled) => handled,
+            None => self.wait_for_key_event(),
+        }
+    }
+
+    /// Sends a key event without waiting for a deferred reply. Returns `None`
+    /// if the engine deferred the reply.
+    pub fn send_key_event(&mut self, sym: u32, code: u32, modifiers: Modifiers) -> Option<bool> {
+        self.call("ProcessKeyEvent", (sym, code, modifiers.bits()))
+            .map(|reply| reply.read1().unwrap_or(false))
+    }
+
+    /// Polls the engine until it finishes the oldest deferred key event, and
+    /// returns whether it was handled
+    ///
+    /// Panics if the key event isn't finished within 10 seconds.
+    pub fn wait_for_key_event(&mut self) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            if let Some(reply) = self.key_replies.pop_front() {
+                return reply.read1().unwrap_or(false);
+            }
+            assert!(
+                Instant::now() < deadline,
+                "The engine didn't finish the deferred key event"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+            self.poll();
+        }
+    }
+
+    /// Calls `Engine::poll`, the same way `EngineHost::poll` does
+    pub fn poll(&mut self) {
+        self.object.engine.poll(&mut self.object.ctx);
+        self.collect();
+    }
+
+    /// Sends a key press without any modifiers
+    pub fn press(&mut self, sym: u32) -> bool {
+        self.key_event(sym, 0, Modifiers::empty())
+    }
+
+    /// Sends a key press followed by the release of the key. Returns whether
+    /// the engine handled the press.
+    pub fn tap(&mut self, sym: u32) -> bool {
+        let handled = self.press(sym);
+        self.key_event(sym, 0, Modifiers::RELEASE);
+        handled
+    }
+
+    /// Taps the keys that produce the characters of `s`. Returns false if
+    /// the engine didn't handle at least one of the keys.
+    pub fn type_str(&mut self, s: &str) -> bool {
+        let mut all_handled = true;
+        for c in s.chars() {
+            all_handled &= self.tap(keysym_of_char(c));
+        }
+        all_handled
+    }
+
+    pub fn focus_in(&mut self) {
+        self.call("FocusIn", ());
+    }
+
+    pub fn focus_out(&mut self) {
+        self.call("FocusOut", ());
+    }
+
+    pub fn reset(&mut self) {
+        self.call("Reset", ());
+    }
+
+    pub fn enable(&mut self) {
+        self.call("Enable", ());
+    }
+
+    pub fn disable(&mut self) {
+        self.call("Disable", ());
+    }
+
+    pub fn page_up(&mut self) {
+        self.call("PageUp", ());
+    }
+
+    pub fn page_down(&mut self) {
+        self.call("PageDown", ());
+    }
+
+    pub fn cursor_up(&mut self) {
+        self.call("CursorUp", ());
+    }
+
+    pub fn cursor_down(&mut self) {
+        self.call("CursorDown", ());
+    }
+
+    /// `index` is relative to the current page of the lookup table
+    pub fn candidate_clicked(&mut self, index: u32, button: u32, state: Modifiers) {
+        self.call("CandidateClicked", (index, button, state.bits()));
+    }
+
+    pub fn property_activate(&mut self, name: &str, state: PropState) {
+        self.call("PropertyActivate", (name, state.to_value()));
+    }
+
+    pub fn property_show(&mut self, name: &str) {
+        self.call("PropertyShow", (name,));
+    }
+
+    pub fn property_hide(&mut self, name: &str) {
+        self.call("PropertyHide", (name,));
+    }
+
+    pub fn set_surrounding_text(
+        &mut self,
+        text: impl Into<Text<'static>>,
+        cursor_pos: u32,
+        anchor_pos: u32,
+    ) {
+        self.call("SetSurroundingText", (text.into(), cursor_pos, anchor_pos));
+    }
+
+    pub fn set_capabilities(&mut self, caps: Capabilites) {
+        self.call("SetCapabilities", (caps.bits(),));
+    }
+
+    pub fn set_content_type(&mut self, purpose: InputPurpose, hints: InputHints) {
+        self.call("SetContentType", (purpose.to_value(), hints.bits()));
+    }
+
+    /// Sends a handwriting stroke, with coordinates between 0 and 1
+    pub fn hand_writing_stroke(&mut self, stroke: &[(f64, f64)]) {
+        let coordinates: Vec<f64> = stroke.iter().flat_map(|&(x, y)| [x, y]).collect();
+        self.call("ProcessHandWritingEvent", (coordinates,));
+    }
+
+    pub fn cancel_hand_writing(&mut self, n_strokes: u32) {
+        self.call("CancelHandWriting", (n_strokes,));
+    }
+
+    pub fn set_cursor_location(&mut self, x: i32, y: i32, w: i32, h: i32) {
+        self.call("SetCursorLocation", (x, y, w, h));
+    }
+
+    /// All signals emitted by the engine so far
+    pub fn signals(&self) -> &[EngineSignal] {
+        &self.signals
+    }
+
+    /// Returns the signals emitted since the last call to this function
+    pub fn take_signals(&mut self) -> Vec<EngineSignal> {
+        std::mem::take(&mut self.signals)
+    }
+
+    /// All the text committed so far, concatenated
+    pub fn committed_text(&self) -> &str {
+        &self.committed
+    }
+
+    /// The preedit text and the cursor position within it, if it's visible
+    pub fn preedit_text(&self) -> Option<(&Text<'static>, u32)> {
+        self.preedit.get().map(|(text, cursor)| (text, *cursor))
+    }
+
+    /// The auxiliary text, if it's visible
+    pub fn auxiliary_text(&self) -> Option<&Text<'static>> {
+        self.auxiliary.get()
+    }
+
+    /// The lookup table, if it's visible
+    pub fn lookup_table(&self) -> Option<&LookupTable> {
+        self.lookup_table.get()
+    }
+
+    /// The registered properties, with the updates applied
+    pub fn properties(&self) -> &PropList {
+        &self.properties
+    }
+
+    /// Gives access to the engine, e.g. for checking its internal state
+    pub fn engine_mut(&mut self) -> &mut dyn Engine {
+        self.object.engine.as_mut()
+    }
+
+    fn call<A: AppendAll>(&mut self, method: &str, args: A) -> Option<Message> {
+        let mut msg = Message::new_method_call(
+            "org.freedesktop.IBus",
+            self.object.ctx.object_path().clone(),
+            ENGINE_INTERFACE,
+            method,
+        )
+        .unwrap();
+        {
+            let mut i = IterAppend::new(&mut msg);
+            args.append(&mut i);
+        }
+        msg.set_serial(1);
+        self.object.engine.poll(&mut self.object.ctx);
+        let reply = self.object.dispatch(&msg);
+        self.collect();
+        reply
+    }
+
+    fn collect(&mut self) {
+        for signal in self.object.ctx.take_signals() {
+            self.apply(&signal);
+            self.signals.push(signal);
+        }
+        self.key_replies.extend(self.object.ctx.take_replies());
+        self.object.ctx.key_events.clear();
+    }
+
+    fn apply(&mut self, signal: &EngineSignal) {
+        match signal {
+            EngineSignal::CommitText(text) => self.committed.push_str(text.as_str()),
+            EngineSignal::UpdatePreeditText {
+                text,
+                cursor_pos,
+                visible,
+                ..
+            } => {
+                self.preedit = Shown {
+                    value: (text.clone(), *cursor_pos),
+                    visible: *visible,
+                }
+            }
+            EngineSignal::ShowPreeditText => self.preedit.visible = true,
+            EngineSignal::HidePreeditText => self.preedit.visible = false,
+            EngineSignal::UpdateAuxiliaryText { text, visible } => {
+                self.auxiliary = Shown {
+                    value: text.clone(),
+                    visible: *visible,
+                }
+            }
+            EngineSignal::ShowAuxiliaryText => self.auxiliary.visible = true,
+            EngineSignal::HideAuxiliaryText => self.auxiliary.visible = false,
+            EngineSignal::UpdateLookupTable { table, visible } => {
+                self.lookup_table = Shown {
+                    value: table.clone(),
+                    visible: *visible,
+                }
+            }
+            EngineSignal::ShowLookupTable => self.lookup_table.visible = true,
+            EngineSignal::HideLookupTable => self.lookup_table.visible = false,
+            EngineSignal::RegisterProperties(props) => self.properties = props.clone(),
+            EngineSignal::UpdateProperty(prop) => {
+                self.properties.update_property(prop);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The keysym that produces `c`. Latin-1 characters have their own keysyms,
+/// the others use the Unicode keysym range.
+fn keysym_of_char(c: char) -> u32 {
+    match c as u32 {
+        cp @ 0x20..=0x7e | cp @ 0xa0..=0xff => cp,
+        cp => 0x0100_0000 | cp,
+    }
+}
@@ -0,0 +1,332 @@
+//! Keymap driven transliteration
+//!
+//! `Transliterator` converts the typed characters with a
+//! `TransliterationTable`, using the longest matching rule. The converted
+//! text is kept in the preedit until the word ends, so Backspace can still
+//! undo the last typed key. This is the core of most phonetic input
+//! methods, and an engine only needs to forward its key events:
+//!
+//! ```
+//! use ibus::engine::transliterate::{TransliterationTable, Transliterator};
+//! use std::sync::Arc;
+//!
+//! let table = TransliterationTable::parse("k\tк\nkh\tх\na\tа\n");
+//! let mut transliterator = Transliterator::new(Arc::new(table));
+//! for c in "kha".chars() {
+//!     assert!(transliterator.push(c));
+//! }
+//! assert_eq!(transliterator.preedit(), "ха");
+//! assert!(transliterator.backspace());
+//! assert_eq!(transliterator.preedit(), "х");
+//! ```
+//!
+//! The table file uses the same format as the one of
+//! `table::CandidateTable`, with a single output on every line.
+//!
+
+use std::{collections::BTreeMap, ops::Bound, path::Path, sync::Arc};
+
+use log::debug;
+
+use crate::{
+    keysyms::{self, keysym_to_char},
+    Attribute, AttributeKind, Error, Modifiers, Text, UnderlineKind,
+};
+
+use super::EngineContext;
+
+/// Maps input sequences to their output
+#[derive(Debug, Clone, Default)]
+pub struct TransliterationTable {
+    rules: BTreeMap<String, String>,
+}
+impl TransliterationTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a tab separated table of input sequences and outputs. Lines
+    /// starting with '#' are comments.
+    pub fn parse(content: &str) -> Self {
+        let mut table = Self::new();
+        for (line_index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('\t') {
+                Some((input, output)) if !input.is_empty() => table.insert(input, output),
+                _ => debug!(
+                    "Skipping line {} of the transliteration table: {:?}",
+                    line_index + 1,
+                    line
+                ),
+            }
+        }
+        table
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Replaces the previous output of `input`
+    pub fn insert(&mut self, input: impl Into<String>, output: impl Into<String>) {
+        self.rules.insert(input.into(), output.into());
+    }
+
+    pub fn get(&self, input: &str) -> Option<&str> {
+        self.rules.get(input).map(String::as_str)
+    }
+
+    /// Returns true if a rule's input starts with `prefix`, and is longer
+    /// than it
+    pub fn can_continue(&self, prefix: &str) -> bool {
+        self.rules
+            .range::<str, _>((Bound::Excluded(prefix), Bound::Unbounded))
+            .next()
+            .is_some_and(|(input, _)| input.starts_with(prefix))
+    }
+
+    /// Returns true if `c` appears in the input of a rule
+    pub fn uses_char(&self, c: char) -> bool {
+        self.rules.keys().any(|input| input.contains(c))
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// A part of the input that has been converted
+#[derive(Debug, Clone)]
+struct Segment {
+    input: String,
+    output: String,
+}
+
+/// Converts typed characters using a `TransliterationTable`
+///
+/// See the module documentation for an example.
+#[derive(Debug, Clone)]
+pub struct Transliterator {
+    table: Arc<TransliterationTable>,
+    segments: Vec<Segment>,
+    /// The input that may still become a longer match
+    pending: String,
+}
+impl Transliterator {
+    /// The table is behind an `Arc` so that the engines of several input
+    /// contexts can share it
+    pub fn new(table: Arc<TransliterationTable>) -> Self {
+        Transliterator {
+            table,
+            segments: Vec::new(),
+            pending: String::new(),
+        }
+    }
+
+    /// Whether there's typed input that hasn't been taken with `finish` yet
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty() && self.pending.is_empty()
+    }
+
+    /// The characters typed since the last `finish`
+    pub fn input(&self) -> String {
+        let mut input: String = self.segments.iter().map(|s| s.input.as_str()).collect();
+        input.push_str(&self.pending);
+        input
+    }
+
+    /// The text to show as preedit. The pending input is shown using the
+    /// rule that would be applied if no more characters were typed.
+    pub fn preedit(&self) -> String {
+        let mut preedit: String = self.segments.iter().map(|s| s.output.as_str()).collect();
+        for segment in self.convert(&self.pending) {
+            preedit.push_str(&segment.output);
+        }
+        preedit
+    }
+
+    /// Adds a typed character. Returns false, without changing anything, if
+    /// no rule uses the character.
+    pub fn push(&mut self, c: char) -> bool {
+        if !self.table.uses_char(c) {
+            return false;
+        }
+        self.pending.push(c);
+        self.settle();
+        true
+    }
+
+    /// Removes the last typed character. Returns false if there's no input.
+    ///
+    /// The rest of the input is converted again, so it can become part of a
+    /// longer match when typing continues.
+    pub fn backspace(&mut self) -> bool {
+        let mut input = self.input();
+        if input.pop().is_none() {
+            return false;
+        }
+        self.clear();
+        for c in input.chars() {
+            self.pending.push(c);
+            self.settle();
+        }
+        true
+    }
+
+    /// Returns the converted text, and clears the input
+    pub fn finish(&mut self) -> String {
+        let text = self.preedit();
+        self.clear();
+        text
+    }
+
+    /// Discards the input
+    pub fn clear(&mut self) {
+        self.segments.clear();
+        self.pending.clear();
+    }
+
+    /// Handles a key event, showing the result in the preedit text
+    ///
+    /// - Characters used by the rules are added to the input
+    /// - Backspace removes the last typed character, Escape discards the
+    ///   input
+    /// - Any other key commits the converted text, and is then given to the
+    ///   application (false is returned)
+    pub fn process_key_event(
+        &mut self,
+        ctx: &mut EngineContext,
+        sym: u32,
+        modifiers: Modifiers,
+    ) -> bool {
+        if modifiers.contains(Modifiers::RELEASE) {
+            return false;
+        }
+        if !modifiers.intersects(Modifiers::CONTROL | Modifiers::MOD1 | Modifiers::SUPER) {
+            match sym {
+                keysyms::KEY_BackSpace if self.backspace() => {
+                    self.update_preedit(ctx);
+                    return true;
+                }
+                keysyms::KEY_Escape if !self.is_empty() => {
+                    self.reset(ctx);
+                    return true;
+                }
+                _ => {
+                    if keysym_to_char(sym).is_some_and(|c| self.push(c)) {
+                        self.update_preedit(ctx);
+                        return true;
+                    }
+                }
+            }
+        }
+        self.commit(ctx);
+        false
+    }
+
+    /// Commits the converted text, e.g. on focus out
+    pub fn commit(&mut self, ctx: &mut EngineContext) {
+        if !self.is_empty() {
+            let text = self.finish();
+            ctx.hide_preedit_text();
+            ctx.commit_text(text);
+        }
+    }
+
+    /// Discards the input, and hides the preedit text
+    pub fn reset(&mut self, ctx: &mut EngineContext) {
+        if !self.is_empty() {
+            self.clear();
+            ctx.hide_preedit_text();
+        }
+    }
+
+    /// Shows the converted text as an underlined preedit text
+    pub fn update_preedit(&self, ctx: &mut EngineContext) {
+        if self.is_empty() {
+            ctx.hide_preedit_text();
+            return;
+        }
+        let preedit = self.preedit();
+        let len = preedit.chars().count() as u32;
+        let attributes = vec![Attribute {
+            kind: AttributeKind::Underline(UnderlineKind::Single),
+            start_index: 0,
+            end_index: len,
+        }];
+        ctx.update_preedit_text(Text::new(preedit, attributes), len, true);
+    }
+
+    /// Converts the pending input until it's empty, or a longer rule could
+    /// still match it
+    fn settle(&mut self) {
+        while !self.pending.is_empty() && !self.table.can_continue(&self.pending) {
+            let segment = self.longest_match(&self.pending);
+            self.pending.drain(..segment.input.len());
+            self.segments.push(segment);
+        }
+    }
+
+    /// Converts all of `input`, as if no more characters were typed
+    fn convert(&self, mut input: &str) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        while !input.is_empty() {
+            let segment = self.longest_match(input);
+            input = &input[segment.input.len()..];
+            segments.push(segment);
+        }
+        segments
+    }
+
+    /// The longest rule that matches the start of `input`. The first
+    /// character is kept unchanged if there's no such rule.
+    fn longest_match(&self, input: &str) -> Segment {
+        let mut ends: Vec<usize> = input.char_indices().skip(1).map(|(i, _)| i).collect();
+        ends.push(input.len());
+        for &end in ends.iter().rev() {
+            if let Some(output) = self.table.get(&input[..end]) {
+                return Segment {
+                    input: input[..end].to_owned(),
+                    output: output.to_owned(),
+                };
+            }
+        }
+        let first = &input[..ends[0]];
+        Segment {
+            input: first.to_owned(),
+            output: first.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_prefix_and_backspace() {
+        let table =
+            TransliterationTable::parse("# Some Cyrillic\ns\tс\nsh\tш\nshch\tщ\nc\tц\na\tа\n");
+        let mut t = Transliterator::new(Arc::new(table));
+        for c in "shc".chars() {
+            assert!(t.push(c));
+        }
+        // "shc" may still become "shch"
+        assert_eq!(t.preedit(), "шц");
+        assert!(t.push('a'));
+        assert_eq!(t.preedit(), "шца");
+        assert!(t.backspace());
+        assert!(t.push('h'));
+        assert_eq!(t.preedit(), "щ");
+        assert!(!t.push('1'));
+        assert_eq!(t.input(), "shch");
+        assert_eq!(t.finish(), "щ");
+        assert!(!t.backspace());
+    }
+}
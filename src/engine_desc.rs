@@ -0,0 +1,352 @@
+//! IBusEngineDesc
+//!
+//! Describes an input method engine: its name, language, keyboard layout, and
+//! everything else that the daemon and the setup tools need to know about it.
+//!
+
+use std::{
+    any::Any,
+    path::{Path, PathBuf},
+};
+
+use log::debug;
+
+use dbus::arg::{Append, Arg, ArgType, Get, IterAppend, PropMap, RefArg};
+
+const ENGINE_DESC_NAME: &str = "IBusEngineDesc";
+const ENGINE_DESC_SIGNATURE: &str = "(sa{sv}ssssssssussssssss)";
+
+/// The description of an engine
+///
+/// The fields correspond to the `<engine>` element of a component XML file.
+/// See `EngineDescBuilder` for an easy way to create one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct EngineDesc {
+    /// The unique name of the engine, e.g. "xkb:us::eng" or "mozc-jp"
+    pub name: String,
+
+    /// The name that's displayed to the user
+    pub longname: String,
+    pub description: String,
+
+    /// Language code, e.g. "ja" or "en"
+    pub language: String,
+    pub license: String,
+    pub author: String,
+
+    /// Either an icon name from the icon theme, or an absolute path to an
+    /// icon file
+    pub icon: String,
+
+    /// The keyboard layout to use while this engine is active. The special
+    /// value "default" means keeping the user's current layout.
+    pub layout: String,
+
+    /// Engines with higher ranks are preferred when picking a default engine
+    /// for a language
+    pub rank: u32,
+    pub hotkeys: String,
+
+    /// A short symbol that represents the engine, shown in the panel
+    pub symbol: String,
+
+    /// The command line of the setup program of this engine
+    pub setup: String,
+    pub layout_variant: String,
+    pub layout_option: String,
+    pub version: String,
+    pub textdomain: String,
+
+    /// The key of the property whose icon should be shown in the panel
+    /// instead of the engine's icon
+    pub icon_prop_key: String,
+}
+
+impl EngineDesc {
+    /// The string fields before and after the rank, in wire order
+    fn strings(&self) -> ([&str; 8], [&str; 8]) {
+        (
+            [
+                &self.name,
+                &self.longname,
+                &self.description,
+                &self.language,
+                &self.license,
+                &self.author,
+                &self.icon,
+                &self.layout,
+            ],
+            [
+                &self.hotkeys,
+                &self.symbol,
+                &self.setup,
+                &self.layout_variant,
+                &self.layout_option,
+                &self.version,
+                &self.textdomain,
+                &self.icon_prop_key,
+            ],
+        )
+    }
+}
+
+/// Helps declaring an engine in a few lines
+///
+/// ```
+/// let desc = ibus::EngineDescBuilder::new("my-engine")
+///     .longname("My Engine")
+///     .language("en")
+///     .icon_path("icons/my-engine.svg")
+///     .build();
+/// assert_eq!(desc.layout, "default");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EngineDescBuilder {
+    desc: EngineDesc,
+}
+impl EngineDescBuilder {
+    /// Creates a builder with the following defaults:
+    ///
+    /// - `longname` is the same as `name`
+    /// - `layout` is "default", meaning that the user's layout is kept
+    /// - `rank` is 0
+    /// - `icon` is "ibus-engine", the generic engine icon
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        EngineDescBuilder {
+            desc: EngineDesc {
+                longname: name.clone(),
+                name,
+                layout: "default".into(),
+                icon: "ibus-engine".into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn longname(mut self, longname: impl Into<String>) -> Self {
+        self.desc.longname = longname.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.desc.description = description.into();
+        self
+    }
+
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.desc.language = language.into();
+        self
+    }
+
+    pub fn license(mut self, license: impl Into<String>) -> Self {
+        self.desc.license = license.into();
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.desc.author = author.into();
+        self
+    }
+
+    /// Sets the icon to a name from the icon theme
+    pub fn icon(mut self, icon_name: impl Into<String>) -> Self {
+        self.desc.icon = icon_name.into();
+        self
+    }
+
+    /// Sets the icon to an image file
+    ///
+    /// Relative paths are resolved against the current directory, because the
+    /// panel that displays the icon doesn't run in the same directory as the
+    /// engine.
+    pub fn icon_path(mut self, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let path: PathBuf = if path.is_relative() {
+            match std::env::current_dir() {
+                Ok(dir) => dir.join(path),
+                Err(e) => {
+                    debug!("Couldn't get the current directory: {}", e);
+                    path.to_owned()
+                }
+            }
+        } else {
+            path.to_owned()
+        };
+        self.desc.icon = path.to_string_lossy().into_owned();
+        self
+    }
+
+    pub fn layout(mut self, layout: impl Into<String>) -> Self {
+        self.desc.layout = layout.into();
+        self
+    }
+
+    pub fn layout_variant(mut self, variant: impl Into<String>) -> Self {
+        self.desc.layout_variant = variant.into();
+        self
+    }
+
+    pub fn layout_option(mut self, option: impl Into<String>) -> Self {
+        self.desc.layout_option = option.into();
+        self
+    }
+
+    pub fn rank(mut self, rank: u32) -> Self {
+        self.desc.rank = rank;
+        self
+    }
+
+    pub fn hotkeys(mut self, hotkeys: impl Into<String>) -> Self {
+        self.desc.hotkeys = hotkeys.into();
+        self
+    }
+
+    /// A short symbol shown in the panel while the engine is active, e.g.
+    /// "あ". Engines with several modes can show the current one with
+    /// `EngineContext::set_input_mode` instead.
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.desc.symbol = symbol.into();
+        self
+    }
+
+    pub fn setup(mut self, setup: impl Into<String>) -> Self {
+        self.desc.setup = setup.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.desc.version = version.into();
+        self
+    }
+
+    pub fn textdomain(mut self, textdomain: impl Into<String>) -> Self {
+        self.desc.textdomain = textdomain.into();
+        self
+    }
+
+    /// Usually `INPUT_MODE_PROP`, so the panel shows the icon of the current
+    /// input mode instead of the engine's icon
+    pub fn icon_prop_key(mut self, key: impl Into<String>) -> Self {
+        self.desc.icon_prop_key = key.into();
+        self
+    }
+
+    pub fn build(self) -> EngineDesc {
+        self.desc
+    }
+}
+
+impl RefArg for EngineDesc {
+    fn arg_type(&self) -> ArgType {
+        ArgType::Variant
+    }
+
+    fn signature(&self) -> dbus::Signature<'static> {
+        <Self as Arg>::signature()
+    }
+
+    fn append(&self, i: &mut IterAppend) {
+        let (before_rank, after_rank) = self.strings();
+        i.append_variant(&dbus::Signature::from(ENGINE_DESC_SIGNATURE), |i| {
+            i.append_struct(|i| {
+                i.append(ENGINE_DESC_NAME);
+                i.append(PropMap::new());
+                for s in before_rank {
+                    i.append(s);
+                }
+                i.append(self.rank);
+                for s in after_rank {
+                    i.append(s);
+                }
+            })
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn box_clone(&self) -> Box<dyn RefArg + 'static> {
+        Box::new(self.clone())
+    }
+}
+impl Arg for EngineDesc {
+    const ARG_TYPE: ArgType = ArgType::Variant;
+
+    fn signature() -> dbus::Signature<'static> {
+        dbus::Signature::from("v\u{0}")
+    }
+}
+impl Append for EngineDesc {
+    fn append_by_ref(&self, i: &mut IterAppend) {
+        <Self as RefArg>::append(self, i);
+    }
+}
+impl<'a> Get<'a> for EngineDesc {
+    fn get(i: &mut dbus::arg::Iter<'a>) -> Option<Self> {
+        let mut variant = i.recurse(ArgType::Variant)?;
+        let mut s = variant.recurse(ArgType::Struct)?;
+        let struct_name: &str = s.read().ok()?;
+        if struct_name != ENGINE_DESC_NAME {
+            debug!("Engine desc didn't have the expected name.");
+            return None;
+        }
+        let _: PropMap = s.read().ok()?;
+
+        // Older versions of IBus don't send all of the trailing fields
+        let mut next = || s.read::<String>().unwrap_or_default();
+        let mut desc = EngineDesc {
+            name: next(),
+            longname: next(),
+            description: next(),
+            language: next(),
+            license: next(),
+            author: next(),
+            icon: next(),
+            layout: next(),
+            ..Default::default()
+        };
+        desc.rank = s.read().ok()?;
+        let mut next = || s.read::<String>().unwrap_or_default();
+        desc.hotkeys = next();
+        desc.symbol = next();
+        desc.setup = next();
+        desc.layout_variant = next();
+        desc.layout_option = next();
+        desc.version = next();
+        desc.textdomain = next();
+        desc.icon_prop_key = next();
+        Some(desc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_round_trip() {
+        let desc = EngineDescBuilder::new("test")
+            .language("en")
+            .rank(50)
+            .symbol("T")
+            .icon_prop_key("InputMode")
+            .build();
+        let msg = dbus::Message::new_method_call("a.b", "/a/b", "a.b", "C")
+            .unwrap()
+            .append1(desc.clone());
+        let read: EngineDesc = msg.read1().unwrap();
+        assert_eq!(read, desc);
+    }
+}
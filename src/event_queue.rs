@@ -0,0 +1,287 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{dump, ImeEvent, ImeEventKind, MessageFilter};
+
+/// What `EventQueue::push` does when the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Waits until the consumer makes room. The thread that processes the
+    /// connection stops reading, so the messages wait in the socket and
+    /// eventually slow down the daemon.
+    ///
+    /// Never push from the thread that pops, it would wait forever.
+    Block,
+    /// Drops the oldest event to make room
+    DropOldest,
+    /// An update of the preedit text, the auxiliary text or the lookup table
+    /// replaces the queued update of the same input context, since only the
+    /// latest one matters. This is done even when the queue isn't full. If
+    /// it's still full, the oldest event is dropped.
+    CoalescePreedit,
+}
+
+struct Inner {
+    events: VecDeque<ImeEvent>,
+    dropped: u64,
+}
+
+struct Shared {
+    inner: Mutex<Inner>,
+    /// Notified when an event is pushed
+    not_empty: Condvar,
+    /// Notified when an event is popped
+    not_full: Condvar,
+    capacity: usize,
+    policy: Backpressure,
+}
+
+/// A bounded queue of `ImeEvent`s, between the thread that processes the
+/// connection and the thread that handles the events, e.g. a UI thread
+///
+/// Clones share the queue. `Bus::queue_events` and `SyncBus::queue_events`
+/// fill it from `process`.
+///
+/// ```no_run
+/// use std::{sync::Arc, time::Duration};
+/// use ibus::{Backpressure, EventQueue, SyncBus};
+///
+/// let bus = Arc::new(SyncBus::new().unwrap());
+/// let queue = EventQueue::new(64, Backpressure::CoalescePreedit);
+/// bus.queue_events(queue.clone());
+/// std::thread::spawn({
+///     let bus = bus.clone();
+///     move || loop {
+///         bus.process(Duration::from_secs(1)).unwrap();
+///     }
+/// });
+///
+/// // On the UI thread
+/// while let Some(event) = queue.pop() {
+///     println!("{:?}", event.kind);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct EventQueue {
+    shared: Arc<Shared>,
+}
+impl EventQueue {
+    /// `capacity` is at least 1
+    pub fn new(capacity: usize, policy: Backpressure) -> Self {
+        EventQueue {
+            shared: Arc::new(Shared {
+                inner: Mutex::new(Inner {
+                    events: VecDeque::new(),
+                    dropped: 0,
+                }),
+                not_empty: Condvar::new(),
+                not_full: Condvar::new(),
+                capacity: capacity.max(1),
+                policy,
+            }),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    pub fn policy(&self) -> Backpressure {
+        self.shared.policy
+    }
+
+    /// Adds an event, applying the `Backpressure` policy if the queue is
+    /// full
+    pub fn push(&self, event: ImeEvent) {
+        let shared = &*self.shared;
+        let mut inner = shared.inner.lock().unwrap();
+        match shared.policy {
+            Backpressure::Block => {
+                while inner.events.len() >= shared.capacity {
+                    inner = shared.not_full.wait(inner).unwrap();
+                }
+            }
+            Backpressure::DropOldest => {
+                if inner.events.len() >= shared.capacity {
+                    inner.events.pop_front();
+                    inner.dropped += 1;
+                }
+            }
+            Backpressure::CoalescePreedit => {
+                let replaced = inner
+                    .events
+                    .iter()
+                    .position(|queued| replaces(&event, queued));
+                if let Some(i) = replaced {
+                    inner.events.remove(i);
+                    inner.dropped += 1;
+                } else if inner.events.len() >= shared.capacity {
+                    inner.events.pop_front();
+                    inner.dropped += 1;
+                }
+            }
+        }
+        inner.events.push_back(event);
+        drop(inner);
+        shared.not_empty.notify_one();
+    }
+
+    /// Removes the oldest event, without waiting
+    pub fn pop(&self) -> Option<ImeEvent> {
+        let event = self.shared.inner.lock().unwrap().events.pop_front();
+        if event.is_some() {
+            self.shared.not_full.notify_one();
+        }
+        event
+    }
+
+    /// Removes the oldest event, waiting at most `timeout` for one
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<ImeEvent> {
+        let shared = &*self.shared;
+        let deadline = Instant::now() + timeout;
+        let mut inner = shared.inner.lock().unwrap();
+        loop {
+            if let Some(event) = inner.events.pop_front() {
+                drop(inner);
+                shared.not_full.notify_one();
+                return Some(event);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            inner = shared
+                .not_empty
+                .wait_timeout(inner, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Removes all the queued events
+    pub fn drain(&self) -> Vec<ImeEvent> {
+        let events = std::mem::take(&mut self.shared.inner.lock().unwrap().events);
+        self.shared.not_full.notify_all();
+        events.into()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared.inner.lock().unwrap().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of events that were dropped or replaced because of the
+    /// policy
+    pub fn dropped(&self) -> u64 {
+        self.shared.inner.lock().unwrap().dropped
+    }
+}
+
+/// The filter of `Bus::queue_events` and `SyncBus::queue_events`
+pub(crate) fn queue_filter<C>(queue: EventQueue) -> MessageFilter<C> {
+    Box::new(move |msg, _| {
+        dump::received(&msg);
+        if let Some(event) = ImeEvent::from_message(&msg) {
+            queue.push(event);
+        }
+        true
+    })
+}
+
+/// Whether `new` makes `queued` obsolete with `Backpressure::CoalescePreedit`
+fn replaces(new: &ImeEvent, queued: &ImeEvent) -> bool {
+    use ImeEventKind::*;
+    if new.input_context != queued.input_context {
+        return false;
+    }
+    matches!(
+        (&new.kind, &queued.kind),
+        (UpdatePreeditText { .. }, UpdatePreeditText { .. })
+            | (UpdateAuxiliaryText { .. }, UpdateAuxiliaryText { .. })
+            | (UpdateLookupTable { .. }, UpdateLookupTable { .. })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(ctx: &str, kind: ImeEventKind) -> ImeEvent {
+        ImeEvent {
+            input_context: ctx.to_owned().into(),
+            kind,
+        }
+    }
+
+    fn preedit(text: &str) -> ImeEventKind {
+        ImeEventKind::UpdatePreeditText {
+            text: text.to_owned().into(),
+            cursor_pos: 0,
+            visible: true,
+        }
+    }
+
+    #[test]
+    fn drop_oldest() {
+        let queue = EventQueue::new(2, Backpressure::DropOldest);
+        queue.push(event("/ic/1", ImeEventKind::ShowPreeditText));
+        queue.push(event("/ic/1", preedit("a")));
+        queue.push(event("/ic/1", ImeEventKind::CommitText("b".into())));
+        assert_eq!(queue.dropped(), 1);
+        assert_eq!(queue.pop().unwrap().kind, preedit("a"));
+        assert_eq!(
+            queue.pop().unwrap().kind,
+            ImeEventKind::CommitText("b".into())
+        );
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn coalesce_preedit() {
+        let queue = EventQueue::new(8, Backpressure::CoalescePreedit);
+        queue.push(event("/ic/1", preedit("k")));
+        queue.push(event("/ic/2", preedit("x")));
+        queue.push(event("/ic/1", ImeEventKind::CommitText("か".into())));
+        queue.push(event("/ic/1", preedit("ka")));
+        let kinds: Vec<_> = queue
+            .drain()
+            .into_iter()
+            .map(|e| (e.input_context.to_string(), e.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("/ic/2".to_owned(), preedit("x")),
+                ("/ic/1".to_owned(), ImeEventKind::CommitText("か".into())),
+                ("/ic/1".to_owned(), preedit("ka")),
+            ]
+        );
+        assert_eq!(queue.dropped(), 1);
+    }
+
+    #[test]
+    fn block_until_popped() {
+        let queue = EventQueue::new(1, Backpressure::Block);
+        queue.push(event("/ic/1", ImeEventKind::ShowPreeditText));
+        let producer = std::thread::spawn({
+            let queue = queue.clone();
+            move || queue.push(event("/ic/1", ImeEventKind::HidePreeditText))
+        });
+        assert_eq!(
+            queue.pop_timeout(Duration::from_secs(5)).unwrap().kind,
+            ImeEventKind::ShowPreeditText
+        );
+        assert_eq!(
+            queue.pop_timeout(Duration::from_secs(5)).unwrap().kind,
+            ImeEventKind::HidePreeditText
+        );
+        producer.join().unwrap();
+        assert_eq!(queue.dropped(), 0);
+    }
+}
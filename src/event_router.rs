@@ -0,0 +1,131 @@
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use dbus::{
+    blocking::Connection,
+    channel::{MatchingReceiver, Token},
+    strings::Path,
+};
+use log::debug;
+
+use crate::{dump, Bus, Error, EventQueue, ImeEvent, ImeEventKind, InputContext};
+
+type Handler = Box<dyn FnMut(ImeEventKind) + Send>;
+
+enum Route {
+    Handler(Handler),
+    Queue(EventQueue),
+}
+
+/// Owns the input contexts of an application that has many of them, e.g. one
+/// per document of a tabbed editor, and routes their events by object path
+///
+/// The events of all the contexts are matched once, instead of once for
+/// every context and signal. The events of a context go either to a handler,
+/// called from `Bus::process`, or to an `EventQueue`.
+///
+/// The router stops routing when it's dropped.
+///
+/// ```no_run
+/// use ibus::{Bus, EventRouter, ImeEventKind};
+///
+/// let bus = Bus::new().unwrap();
+/// let mut router = EventRouter::new(&bus);
+/// for document in ["notes.txt", "todo.txt"] {
+///     router
+///         .create_context("my-editor", move |event| {
+///             if let ImeEventKind::CommitText(text) = event {
+///                 println!("{}: {}", document, text.as_str());
+///             }
+///         })
+///         .unwrap();
+/// }
+/// ```
+pub struct EventRouter {
+    conn: Rc<Connection>,
+    routes: Arc<Mutex<HashMap<Path<'static>, Route>>>,
+    contexts: HashMap<Path<'static>, InputContext>,
+    token: Token,
+}
+impl EventRouter {
+    pub fn new(bus: &Bus) -> Self {
+        let routes = Arc::new(Mutex::new(HashMap::<Path<'static>, Route>::new()));
+        let token = bus.conn.start_receive(ImeEvent::match_rule(), {
+            let routes = routes.clone();
+            Box::new(move |msg, _| {
+                dump::received(&msg);
+                let event = match ImeEvent::from_message(&msg) {
+                    Some(event) => event,
+                    None => return true,
+                };
+                match routes.lock().unwrap().get_mut(&event.input_context) {
+                    Some(Route::Handler(handler)) => handler(event.kind),
+                    Some(Route::Queue(queue)) => queue.push(event),
+                    None => debug!("No route for {}", event.input_context),
+                }
+                true
+            })
+        });
+        EventRouter {
+            conn: bus.conn.clone(),
+            routes,
+            contexts: HashMap::new(),
+            token,
+        }
+    }
+
+    /// Creates an input context whose events are passed to `handler`
+    ///
+    /// The handler is called from `Bus::process`, without access to the
+    /// router. Use `create_queued_context` to handle the events where the
+    /// input context can be called.
+    pub fn create_context<F>(&mut self, name: &str, handler: F) -> Result<&InputContext, Error>
+    where
+        F: FnMut(ImeEventKind) + Send + 'static,
+    {
+        self.add(name, Route::Handler(Box::new(handler)))
+    }
+
+    /// Creates an input context whose events are pushed to `queue`. Several
+    /// contexts can share a queue and tell the events apart by
+    /// `ImeEvent::input_context`.
+    pub fn create_queued_context(
+        &mut self,
+        name: &str,
+        queue: EventQueue,
+    ) -> Result<&InputContext, Error> {
+        self.add(name, Route::Queue(queue))
+    }
+
+    pub fn context(&self, path: &Path<'static>) -> Option<&InputContext> {
+        self.contexts.get(path)
+    }
+
+    pub fn contexts(&self) -> impl Iterator<Item = &InputContext> {
+        self.contexts.values()
+    }
+
+    /// Stops routing the events of a context, and gives it back
+    pub fn remove_context(&mut self, path: &Path<'static>) -> Option<InputContext> {
+        self.routes.lock().unwrap().remove(path);
+        self.contexts.remove(path)
+    }
+
+    fn add(&mut self, name: &str, route: Route) -> Result<&InputContext, Error> {
+        let ctx = Bus {
+            conn: self.conn.clone(),
+        }
+        .create_input_context(name)?;
+        let path = ctx.path().clone();
+        self.routes.lock().unwrap().insert(path.clone(), route);
+        Ok(self.contexts.entry(path).or_insert(ctx))
+    }
+}
+impl Drop for EventRouter {
+    fn drop(&mut self) {
+        self.conn.stop_receive(self.token);
+    }
+}
@@ -0,0 +1,470 @@
+//! A C API, for using this crate from C, C++ and game engines
+//!
+//! Build it as a shared library with
+//! `cargo rustc --release --features ffi --crate-type cdylib`, and include
+//! `include/ibus_rs.h` of the repository. A single thread creates the bus,
+//! and uses it and its input contexts.
+//!
+//! ```c
+//! IbusRsBus *bus = ibus_rs_bus_new();
+//! IbusRsContext *ctx = ibus_rs_context_new(bus, "my-app");
+//! ibus_rs_context_set_capabilities(ctx, IBUS_RS_CAP_PREEDIT_TEXT | IBUS_RS_CAP_FOCUS);
+//! ibus_rs_context_focus_in(ctx);
+//!
+//! // For each key event
+//! if (ibus_rs_context_process_key_event(ctx, keysym, keycode, state) != 1) {
+//!     // Handle the key as usual
+//! }
+//!
+//! // In the main loop, or when `ibus_rs_bus_fd` is readable
+//! IbusRsEvent event;
+//! while (ibus_rs_bus_poll(bus, 0, &event) == 1) {
+//!     if (event.kind == IBUS_RS_EVENT_COMMIT_TEXT) {
+//!         insert_text(event.text);
+//!     }
+//! }
+//!
+//! ibus_rs_context_free(ctx);
+//! ibus_rs_bus_free(bus);
+//! ```
+//!
+//! The functions that can fail return -1 or NULL, and
+//! `ibus_rs_last_error` describes the error. The strings of the events stay
+//! valid until the next `ibus_rs_bus_poll` on the same bus.
+//!
+//! This module needs the `ffi` feature.
+//!
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_int, CStr, CString},
+    ptr,
+    time::Duration,
+};
+
+use crate::{
+    Backpressure, Bus, Capabilites, Error, EventQueue, ImeEvent, ImeEventKind, InputContext,
+    Modifiers,
+};
+
+pub const IBUS_RS_EVENT_COMMIT_TEXT: u32 = 1;
+pub const IBUS_RS_EVENT_UPDATE_PREEDIT_TEXT: u32 = 2;
+pub const IBUS_RS_EVENT_SHOW_PREEDIT_TEXT: u32 = 3;
+pub const IBUS_RS_EVENT_HIDE_PREEDIT_TEXT: u32 = 4;
+pub const IBUS_RS_EVENT_FORWARD_KEY_EVENT: u32 = 5;
+pub const IBUS_RS_EVENT_DELETE_SURROUNDING_TEXT: u32 = 6;
+pub const IBUS_RS_EVENT_REQUIRE_SURROUNDING_TEXT: u32 = 7;
+
+/// How many events wait for `ibus_rs_bus_poll`, the older preedit updates
+/// are dropped when there are more
+const QUEUE_CAPACITY: usize = 256;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// A connection to the daemon
+pub struct IbusRsBus {
+    bus: Bus,
+    queue: EventQueue,
+    /// The strings of the last event
+    strings: Option<(CString, Option<CString>)>,
+}
+
+pub struct IbusRsContext {
+    ctx: InputContext,
+    path: CString,
+}
+
+/// An event of an input context, the fields that don't belong to `kind`
+/// are zero
+///
+/// `text` is the text of `COMMIT_TEXT` and `UPDATE_PREEDIT_TEXT`, and NULL
+/// otherwise. `cursor_pos` and `visible` belong to `UPDATE_PREEDIT_TEXT`,
+/// `keysym`, `keycode` and `modifiers` to `FORWARD_KEY_EVENT`, `offset` and
+/// `nchars` to `DELETE_SURROUNDING_TEXT`. The positions count characters.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IbusRsEvent {
+    pub kind: u32,
+    /// The path of the input context, see `ibus_rs_context_path`
+    pub context: *const c_char,
+    pub text: *const c_char,
+    pub cursor_pos: u32,
+    pub visible: c_int,
+    pub keysym: u32,
+    pub keycode: u32,
+    pub modifiers: u32,
+    pub offset: i32,
+    pub nchars: u32,
+}
+impl Default for IbusRsEvent {
+    fn default() -> Self {
+        IbusRsEvent {
+            kind: 0,
+            context: ptr::null(),
+            text: ptr::null(),
+            cursor_pos: 0,
+            visible: 0,
+            keysym: 0,
+            keycode: 0,
+            modifiers: 0,
+            offset: 0,
+            nchars: 0,
+        }
+    }
+}
+
+fn set_last_error(e: &Error) {
+    let message = CString::new(e.to_string().replace('\0', " ")).ok();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+}
+
+/// The value of `result`, or `error` after saving the error for
+/// `ibus_rs_last_error`
+fn check<T>(result: Result<T, Error>, error: T) -> T {
+    result.unwrap_or_else(|e| {
+        set_last_error(&e);
+        error
+    })
+}
+
+fn status(result: Result<(), Error>) -> c_int {
+    check(result.map(|()| 0), -1)
+}
+
+/// The error of the last function that failed on this thread, or NULL
+///
+/// The string stays valid until the next error.
+#[no_mangle]
+pub extern "C" fn ibus_rs_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+/// Connects to the daemon, NULL on failure
+#[no_mangle]
+pub extern "C" fn ibus_rs_bus_new() -> *mut IbusRsBus {
+    let bus = match Bus::new() {
+        Ok(bus) => bus,
+        Err(e) => {
+            set_last_error(&e);
+            return ptr::null_mut();
+        }
+    };
+    let queue = EventQueue::new(QUEUE_CAPACITY, Backpressure::CoalescePreedit);
+    bus.queue_events(queue.clone());
+    Box::into_raw(Box::new(IbusRsBus {
+        bus,
+        queue,
+        strings: None,
+    }))
+}
+
+/// # Safety
+///
+/// `bus` is NULL or was returned by `ibus_rs_bus_new`, and isn't used after
+/// this.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_bus_free(bus: *mut IbusRsBus) {
+    if !bus.is_null() {
+        drop(Box::from_raw(bus));
+    }
+}
+
+/// The file descriptor of the connection, to wait until it's readable and
+/// then call `ibus_rs_bus_poll`
+///
+/// # Safety
+///
+/// `bus` was returned by `ibus_rs_bus_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_bus_fd(bus: *const IbusRsBus) -> c_int {
+    (*bus).bus.watch().fd
+}
+
+/// Writes the next event to `event` and returns 1, waiting for up to
+/// `timeout_ms` milliseconds for it. Returns 0 if there's no event, -1 on
+/// failure.
+///
+/// # Safety
+///
+/// `bus` was returned by `ibus_rs_bus_new`, and `event` points to an
+/// `IbusRsEvent`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_bus_poll(
+    bus: *mut IbusRsBus,
+    timeout_ms: u32,
+    event: *mut IbusRsEvent,
+) -> c_int {
+    let bus = &mut *bus;
+    if bus.queue.is_empty() {
+        let timeout = Duration::from_millis(u64::from(timeout_ms));
+        if let Err(e) = bus.bus.process(timeout) {
+            set_last_error(&e);
+            return -1;
+        }
+    }
+    while let Some(next) = bus.queue.pop() {
+        let (converted, text) = match convert(&next) {
+            Some(converted) => converted,
+            None => continue,
+        };
+        let context = CString::new(next.input_context.to_string()).unwrap_or_default();
+        let text = text.map(|text| CString::new(text.replace('\0', "")).unwrap_or_default());
+        *event = IbusRsEvent {
+            context: context.as_ptr(),
+            text: text.as_ref().map_or(ptr::null(), |text| text.as_ptr()),
+            ..converted
+        };
+        bus.strings = Some((context, text));
+        return 1;
+    }
+    0
+}
+
+/// The C event of an `ImeEvent` without the strings, and its text. `None`
+/// for the events that the C API doesn't have.
+fn convert(event: &ImeEvent) -> Option<(IbusRsEvent, Option<String>)> {
+    let mut converted = IbusRsEvent::default();
+    let mut text = None;
+    match &event.kind {
+        ImeEventKind::CommitText(commit) => {
+            converted.kind = IBUS_RS_EVENT_COMMIT_TEXT;
+            text = Some(commit.as_str().to_owned());
+        }
+        ImeEventKind::UpdatePreeditText {
+            text: preedit,
+            cursor_pos,
+            visible,
+        } => {
+            converted.kind = IBUS_RS_EVENT_UPDATE_PREEDIT_TEXT;
+            converted.cursor_pos = *cursor_pos;
+            converted.visible = c_int::from(*visible);
+            text = Some(preedit.as_str().to_owned());
+        }
+        ImeEventKind::ShowPreeditText => converted.kind = IBUS_RS_EVENT_SHOW_PREEDIT_TEXT,
+        ImeEventKind::HidePreeditText => converted.kind = IBUS_RS_EVENT_HIDE_PREEDIT_TEXT,
+        ImeEventKind::ForwardKeyEvent {
+            keysym,
+            keycode,
+            modifiers,
+        } => {
+            converted.kind = IBUS_RS_EVENT_FORWARD_KEY_EVENT;
+            converted.keysym = *keysym;
+            converted.keycode = *keycode;
+            converted.modifiers = modifiers.bits();
+        }
+        ImeEventKind::DeleteSurroundingText { offset, nchars } => {
+            converted.kind = IBUS_RS_EVENT_DELETE_SURROUNDING_TEXT;
+            converted.offset = *offset;
+            converted.nchars = *nchars;
+        }
+        ImeEventKind::RequireSurroundingText => {
+            converted.kind = IBUS_RS_EVENT_REQUIRE_SURROUNDING_TEXT
+        }
+        _ => return None,
+    }
+    Some((converted, text))
+}
+
+/// Creates an input context called `name`, NULL on failure
+///
+/// # Safety
+///
+/// `bus` was returned by `ibus_rs_bus_new`, and `name` is a NUL-terminated
+/// UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_new(
+    bus: *const IbusRsBus,
+    name: *const c_char,
+) -> *mut IbusRsContext {
+    let name = match CStr::from_ptr(name).to_str() {
+        Ok(name) => name,
+        Err(_) => {
+            set_last_error(&invalid_utf8());
+            return ptr::null_mut();
+        }
+    };
+    let ctx = match (*bus).bus.create_input_context(name) {
+        Ok(ctx) => ctx,
+        Err(e) => {
+            set_last_error(&e);
+            return ptr::null_mut();
+        }
+    };
+    let path = CString::new(ctx.path().to_string()).unwrap_or_default();
+    Box::into_raw(Box::new(IbusRsContext { ctx, path }))
+}
+
+fn invalid_utf8() -> Error {
+    Error::Unknown {
+        description: "The string isn't valid UTF-8".into(),
+    }
+}
+
+/// # Safety
+///
+/// `ctx` is NULL or was returned by `ibus_rs_context_new`, and isn't used
+/// after this.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_free(ctx: *mut IbusRsContext) {
+    if !ctx.is_null() {
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// The path of the input context, the same as the `context` of its events
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`. The string is valid until
+/// the context is freed.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_path(ctx: *const IbusRsContext) -> *const c_char {
+    (*ctx).path.as_ptr()
+}
+
+/// Returns 0, or -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_set_capabilities(
+    ctx: *const IbusRsContext,
+    capabilities: u32,
+) -> c_int {
+    status(
+        (*ctx)
+            .ctx
+            .try_set_capabilities(Capabilites::from_bits_truncate(capabilities)),
+    )
+}
+
+/// Returns 1 if the engine handled the key, 0 if it didn't, -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_process_key_event(
+    ctx: *const IbusRsContext,
+    keysym: u32,
+    keycode: u32,
+    modifiers: u32,
+) -> c_int {
+    let handled =
+        (*ctx)
+            .ctx
+            .process_key_event(keysym, keycode, Modifiers::from_bits_truncate(modifiers));
+    check(handled.map(c_int::from), -1)
+}
+
+/// Returns 0, or -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_set_cursor_location(
+    ctx: *const IbusRsContext,
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+) -> c_int {
+    status((*ctx).ctx.set_cursor_location(x, y, w, h))
+}
+
+/// Returns 0, or -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`, and `text` is a
+/// NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_set_surrounding_text(
+    ctx: *const IbusRsContext,
+    text: *const c_char,
+    cursor_pos: u32,
+    anchor_pos: u32,
+) -> c_int {
+    let text = match CStr::from_ptr(text).to_str() {
+        Ok(text) => text,
+        Err(_) => return status(Err(invalid_utf8())),
+    };
+    status(
+        (*ctx)
+            .ctx
+            .set_surrounding_text(text, cursor_pos, anchor_pos),
+    )
+}
+
+/// Returns 0, or -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_focus_in(ctx: *const IbusRsContext) -> c_int {
+    status((*ctx).ctx.focus_in())
+}
+
+/// Returns 0, or -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_focus_out(ctx: *const IbusRsContext) -> c_int {
+    status((*ctx).ctx.focus_out())
+}
+
+/// Returns 0, or -1 on failure
+///
+/// # Safety
+///
+/// `ctx` was returned by `ibus_rs_context_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ibus_rs_context_reset(ctx: *const IbusRsContext) -> c_int {
+    status((*ctx).ctx.reset())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Text;
+    use dbus::strings::Path;
+
+    fn event(kind: ImeEventKind) -> ImeEvent {
+        ImeEvent {
+            input_context: Path::new("/org/freedesktop/IBus/InputContext_1").unwrap(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn events() {
+        let (commit, text) =
+            convert(&event(ImeEventKind::CommitText(Text::new("你好", vec![])))).unwrap();
+        assert_eq!(commit.kind, IBUS_RS_EVENT_COMMIT_TEXT);
+        assert_eq!(text.as_deref(), Some("你好"));
+
+        let delete = ImeEventKind::DeleteSurroundingText {
+            offset: -2,
+            nchars: 2,
+        };
+        let (delete, text) = convert(&event(delete)).unwrap();
+        assert_eq!((delete.offset, delete.nchars), (-2, 2));
+        assert_eq!(text, None);
+        assert!(convert(&event(ImeEventKind::ShowLookupTable)).is_none());
+    }
+
+    #[test]
+    fn last_error() {
+        assert!(ibus_rs_last_error().is_null());
+        status(Err(invalid_utf8()));
+        let error = unsafe { CStr::from_ptr(ibus_rs_last_error()) };
+        assert!(error.to_str().unwrap().contains("UTF-8"));
+    }
+}
@@ -0,0 +1,305 @@
+//! Using IBus from the GLib main loop
+//!
+//! `GioBus` connects to the daemon with a `gio::DBusConnection`, so the
+//! replies and signals are delivered on the thread-default main context of
+//! the thread that created the bus, e.g. the main loop of GTK. There's no
+//! need to call `process` or to run a thread for the connection.
+//!
+//! The calls that the daemon answers take a callback for the reply, the
+//! others are sent without waiting, and errors are only logged.
+//!
+//! ```no_run
+//! use ibus::{gdbus::GioBus, Capabilites, CommitTextSignal, Modifiers};
+//!
+//! let bus = GioBus::new().unwrap();
+//! let ctx = bus.create_input_context("my-app").unwrap();
+//! ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+//! ctx.focus_in();
+//! // Dropping the subscription stops the callback
+//! let _commits = ctx.connect_signal(|signal: CommitTextSignal| {
+//!     println!("Committed {}", signal.text.as_str());
+//! });
+//! ctx.process_key_event(ibus::keysyms::KEY_a, 30, Modifiers::empty(), |handled| {
+//!     // Forward the key to the widget if it wasn't handled
+//! });
+//!
+//! gio::glib::MainLoop::new(None, false).run();
+//! ```
+//!
+//! This module needs the `gio` feature.
+//!
+
+use log::debug;
+
+use dbus::{
+    arg::{AppendAll, ReadAll},
+    message::SignalArgs,
+    strings::Path,
+};
+use gio::{
+    glib, DBusCapabilityFlags, DBusConnection, DBusConnectionFlags, DBusMessage,
+    DBusSendMessageFlags, DBusSignalFlags, SignalSubscription,
+};
+
+use crate::{
+    get_address, input_context::INTERFACE_NAME, Capabilites, EngineDesc, Error, Modifiers,
+    PropState, Text, REQ_TIMEOUT,
+};
+
+const IBUS_NAME: &str = "org.freedesktop.IBus";
+const IBUS_PATH: &str = "/org/freedesktop/IBus";
+
+/// A connection to the IBus daemon, see the module documentation
+pub struct GioBus {
+    conn: DBusConnection,
+}
+impl GioBus {
+    /// Connects to the daemon. This blocks until the connection is set up.
+    pub fn new() -> Result<Self, Error> {
+        let addr = get_address().map_err(|e| Error::Unknown { description: e })?;
+        let conn = DBusConnection::for_address_sync(
+            &addr,
+            DBusConnectionFlags::AUTHENTICATION_CLIENT
+                | DBusConnectionFlags::MESSAGE_BUS_CONNECTION,
+            None,
+            gio::Cancellable::NONE,
+        )
+        .map_err(to_error)?;
+        Ok(GioBus { conn })
+    }
+
+    /// The underlying connection, for calls that this crate doesn't wrap
+    pub fn connection(&self) -> &DBusConnection {
+        &self.conn
+    }
+
+    /// Creates an input context. This blocks until the daemon answers, like
+    /// `Bus::create_input_context`.
+    pub fn create_input_context(&self, name: &str) -> Result<GioInputContext, Error> {
+        let msg = to_gio(
+            dbus::Message::new_method_call(IBUS_NAME, IBUS_PATH, IBUS_NAME, "CreateInputContext")
+                .map_err(|e| Error::Unknown { description: e })?
+                .append1(name),
+        )?;
+        let (obj_path,): (Path<'static>,) = self
+            .conn
+            .send_message_with_reply_sync(
+                &msg,
+                DBusSendMessageFlags::NONE,
+                timeout_msec(),
+                gio::Cancellable::NONE,
+            )
+            .map_err(to_error)
+            .and_then(|(reply, _)| read_reply(reply))
+            .map_err(|e| e.in_call(IBUS_PATH, IBUS_NAME, "CreateInputContext"))?;
+        Ok(GioInputContext {
+            conn: self.conn.clone(),
+            obj_path,
+        })
+    }
+}
+
+/// The version of `InputContext` for the GLib main loop
+pub struct GioInputContext {
+    conn: DBusConnection,
+    obj_path: Path<'static>,
+}
+impl GioInputContext {
+    pub fn path(&self) -> &Path<'static> {
+        &self.obj_path
+    }
+
+    pub fn set_capabilities(&self, caps: Capabilites) {
+        crate::diagnostics::set_capabilities(&self.obj_path, caps);
+        self.send("SetCapabilities", (caps.bits(),));
+    }
+
+    /// Calls `callback` with whether the engine handled the key event. See
+    /// `InputContext::process_key_event`.
+    pub fn process_key_event<F>(&self, sym: u32, code: u32, modifiers: Modifiers, callback: F)
+    where
+        F: FnOnce(Result<bool, Error>) + 'static,
+    {
+        crate::metrics::key_event_sent();
+        self.call(
+            "ProcessKeyEvent",
+            (sym, code, modifiers.bits()),
+            move |reply: Result<(bool,), Error>| callback(reply.map(|(handled,)| handled)),
+        );
+    }
+
+    /// See `InputContext::set_cursor_location`
+    pub fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) {
+        self.send("SetCursorLocation", (x, y, w, h));
+    }
+
+    pub fn focus_in(&self) {
+        self.send("FocusIn", ());
+    }
+
+    pub fn focus_out(&self) {
+        self.send("FocusOut", ());
+    }
+
+    pub fn reset(&self) {
+        self.send("Reset", ());
+    }
+
+    pub fn set_surrounding_text<'a>(
+        &self,
+        text: impl Into<Text<'a>>,
+        cursor_pos: u32,
+        anchor_pos: u32,
+    ) {
+        let text: Text<'a> = text.into();
+        self.send("SetSurroundingText", (text, cursor_pos, anchor_pos));
+    }
+
+    pub fn page_up(&self) {
+        self.send("PageUp", ());
+    }
+
+    pub fn page_down(&self) {
+        self.send("PageDown", ());
+    }
+
+    pub fn cursor_up(&self) {
+        self.send("CursorUp", ());
+    }
+
+    pub fn cursor_down(&self) {
+        self.send("CursorDown", ());
+    }
+
+    /// See `InputContext::candidate_clicked`
+    pub fn candidate_clicked(&self, index: u32, button: u32, state: Modifiers) {
+        self.send("CandidateClicked", (index, button, state.bits()));
+    }
+
+    pub fn property_activate(&self, name: &str, state: PropState) {
+        self.send("PropertyActivate", (name, state.to_value()));
+    }
+
+    /// Calls `callback` with the engine of the input context. See
+    /// `InputContext::engine`.
+    pub fn engine<F>(&self, callback: F)
+    where
+        F: FnOnce(Result<EngineDesc, Error>) + 'static,
+    {
+        self.call(
+            "GetEngine",
+            (),
+            move |reply: Result<(EngineDesc,), Error>| callback(reply.map(|(desc,)| desc)),
+        );
+    }
+
+    /// Calls `callback` for every signal of type `S` emitted by this input
+    /// context, e.g. `CommitTextSignal`, until the subscription is dropped
+    pub fn connect_signal<S, F>(&self, callback: F) -> SignalSubscription
+    where
+        S: SignalArgs + ReadAll,
+        F: Fn(S) + 'static,
+    {
+        self.conn.subscribe_to_signal(
+            Some(IBUS_NAME),
+            Some(S::INTERFACE),
+            Some(S::NAME),
+            Some(&self.obj_path),
+            None,
+            DBusSignalFlags::NONE,
+            move |signal| {
+                // The arguments are parsed by the same code as for the other
+                // connections, which works on libdbus messages
+                let msg = DBusMessage::new_signal(
+                    signal.object_path,
+                    signal.interface_name,
+                    signal.signal_name,
+                );
+                msg.set_body(signal.parameters);
+                match from_gio(&msg).and_then(|msg| Ok(msg.read_all::<S>()?)) {
+                    Ok(args) => callback(args),
+                    Err(e) => debug!("Couldn't read the {} signal: {}", S::NAME, e),
+                }
+            },
+        )
+    }
+
+    /// Calls a method without waiting for the reply
+    fn send<A: AppendAll>(&self, method: &str, args: A) {
+        let name = method.to_owned();
+        self.call(method, args, move |reply: Result<(), Error>| {
+            if let Err(e) = reply {
+                debug!("{} failed: {}", name, e);
+            }
+        });
+    }
+
+    fn call<A, R, F>(&self, method: &str, args: A, callback: F)
+    where
+        A: AppendAll,
+        R: ReadAll,
+        F: FnOnce(Result<R, Error>) + 'static,
+    {
+        let mut msg = dbus::Message::method_call(
+            &IBUS_NAME.into(),
+            &self.obj_path,
+            &INTERFACE_NAME.into(),
+            &method.into(),
+        );
+        msg.append_all(args);
+        let msg = match to_gio(msg) {
+            Ok(msg) => msg,
+            Err(e) => return callback(Err(e)),
+        };
+        let path = self.obj_path.to_string();
+        let member = method.to_owned();
+        self.conn.send_message_with_reply(
+            &msg,
+            DBusSendMessageFlags::NONE,
+            timeout_msec(),
+            gio::Cancellable::NONE,
+            move |reply| {
+                let reply = reply.map_err(to_error).and_then(read_reply);
+                callback(reply.map_err(|e| e.in_call(&path, INTERFACE_NAME, &member)))
+            },
+        );
+    }
+}
+
+fn timeout_msec() -> i32 {
+    REQ_TIMEOUT.as_millis() as i32
+}
+
+fn to_error(mut e: glib::Error) -> Error {
+    let name = gio::DBusError::remote_error(&e).map_or_else(
+        || "org.freedesktop.DBus.Error.Failed".to_owned(),
+        Into::into,
+    );
+    gio::DBusError::strip_remote_error(&mut e);
+    Error::DBus(dbus::Error::new_custom(&name, e.message()))
+}
+
+/// Converts a message built with `dbus` for sending it with gio
+fn to_gio(mut msg: dbus::Message) -> Result<DBusMessage, Error> {
+    // The connection sets its own serial when sending the message, but the
+    // serialized message must have one
+    msg.set_serial(1);
+    let mut blob = Vec::new();
+    let _ = msg.marshal(|bytes| {
+        blob.extend_from_slice(bytes);
+        Ok::<_, ()>(())
+    });
+    DBusMessage::from_blob(&blob, DBusCapabilityFlags::empty()).map_err(to_error)
+}
+
+fn from_gio(msg: &DBusMessage) -> Result<dbus::Message, Error> {
+    let blob = msg
+        .to_blob(DBusCapabilityFlags::empty())
+        .map_err(to_error)?;
+    Ok(dbus::Message::demarshal(&blob)?)
+}
+
+fn read_reply<R: ReadAll>(reply: DBusMessage) -> Result<R, Error> {
+    reply.to_gerror().map_err(to_error)?;
+    Ok(from_gio(&reply)?.read_all()?)
+}
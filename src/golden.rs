@@ -0,0 +1,150 @@
+//! Compares the outgoing calls and signals with the bytes that libibus sends
+//!
+//! The `.golden` files are captured with `tools/capture_golden.py`, they are
+//! the signature and the body of the message. Serializing the same values
+//! here must give the same bytes, so a refactoring of the serialization
+//! can't change the wire format unnoticed.
+
+use dbus::{strings::Path, Message};
+
+use crate::{
+    body_signature,
+    engine::{EngineSignal, PreeditFocusMode},
+    input_context::input_context_call,
+    keysyms, Attribute, AttributeKind, Capabilites, LookupTable, Modifiers, PropList, Property,
+    Text, UnderlineKind,
+};
+
+const PATH: &str = "/org/freedesktop/IBus/InputContext_1";
+
+fn path() -> Path<'static> {
+    Path::from(PATH)
+}
+
+fn call<A: dbus::arg::AppendAll>(method: &str, args: A) -> Message {
+    input_context_call(&path(), method, args)
+}
+
+fn signal(signal: EngineSignal) -> Message {
+    signal.to_message(&path())
+}
+
+/// The signature and the body of `msg`, the rest of the header depends on
+/// the connection
+fn body(msg: &Message) -> (String, Vec<u8>) {
+    let mut msg = msg.duplicate().unwrap();
+    msg.set_serial(1);
+    let mut bytes = Vec::new();
+    msg.marshal(|chunk| -> Result<(), ()> {
+        bytes.extend_from_slice(chunk);
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(bytes[0], b'l', "the golden messages are little endian");
+    let len = u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize;
+    (body_signature(&msg), bytes[bytes.len() - len..].to_vec())
+}
+
+fn parse(golden: &str) -> (String, Vec<u8>) {
+    let mut lines = golden.lines();
+    let signature = lines
+        .next()
+        .and_then(|l| l.strip_prefix("signature:"))
+        .expect("the golden file should start with the signature");
+    let bytes = lines
+        .flat_map(|l| l.split_whitespace())
+        .map(|b| u8::from_str_radix(b, 16).unwrap())
+        .collect();
+    (signature.trim().to_owned(), bytes)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .map(|line| {
+            let line: Vec<_> = line.iter().map(|b| format!("{:02x}", b)).collect();
+            line.join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+macro_rules! golden {
+    ($($name:ident => $msg:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let golden = include_str!(concat!("golden/", stringify!($name), ".golden"));
+                let (signature, bytes) = body(&$msg);
+                let (golden_signature, golden_bytes) = parse(golden);
+                assert_eq!(signature, golden_signature);
+                assert!(
+                    bytes == golden_bytes,
+                    "the body differs from libibus\nexpected:\n{}\nactual:\n{}",
+                    hex(&golden_bytes),
+                    hex(&bytes)
+                );
+            }
+        )*
+    };
+}
+
+fn lookup_table() -> LookupTable {
+    let mut table = LookupTable::new(5, 0, true, false);
+    table.append_candidate("你");
+    table.append_candidate("尼");
+    table
+}
+
+fn prop_list() -> PropList {
+    let mut props = PropList::new();
+    props.push(Property::input_mode("あ", "あ"));
+    props
+}
+
+golden! {
+    process_key_event => call("ProcessKeyEvent", (keysyms::KEY_a, 30u32, 0u32)),
+    process_key_event_release => call(
+        "ProcessKeyEvent",
+        (keysyms::KEY_a, 30u32, Modifiers::RELEASE.bits()),
+    ),
+    set_cursor_location => call("SetCursorLocation", (10i32, 20i32, 1i32, 16i32)),
+    set_capabilities => call(
+        "SetCapabilities",
+        ((Capabilites::PREEDIT_TEXT | Capabilites::FOCUS | Capabilites::SURROUNDING_TEXT).bits(),),
+    ),
+    set_surrounding_text => call("SetSurroundingText", (Text::from("héllo"), 3u32, 1u32)),
+    focus_in => call("FocusIn", ()),
+    commit_text => signal(EngineSignal::CommitText(Text::from("你好".to_owned()))),
+    update_preedit_text => signal(EngineSignal::UpdatePreeditText {
+        text: Text::new(
+            "ni".to_owned(),
+            vec![Attribute {
+                kind: AttributeKind::Underline(UnderlineKind::Single),
+                start_index: 0,
+                end_index: 2,
+            }],
+        ),
+        cursor_pos: 2,
+        visible: true,
+        mode: PreeditFocusMode::Commit,
+    }),
+    update_auxiliary_text => signal(EngineSignal::UpdateAuxiliaryText {
+        text: Text::from("n".to_owned()),
+        visible: true,
+    }),
+    update_lookup_table => signal(EngineSignal::UpdateLookupTable {
+        table: lookup_table(),
+        visible: true,
+    }),
+    forward_key_event => signal(EngineSignal::ForwardKeyEvent {
+        sym: keysyms::KEY_Return,
+        code: 28,
+        modifiers: Modifiers::empty(),
+    }),
+    delete_surrounding_text => signal(EngineSignal::DeleteSurroundingText {
+        offset: -1,
+        nchars: 1,
+    }),
+    register_properties => signal(EngineSignal::RegisterProperties(prop_list())),
+}
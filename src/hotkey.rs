@@ -0,0 +1,292 @@
+//! Hotkeys and hotkey profiles
+//!
+//! The equivalent of IBusHotkeyProfile. Engines use it for trigger and
+//! toggle keys, and clients can use it to implement their own shortcuts
+//! for turning the input method on and off.
+//!
+
+use std::fmt;
+
+use crate::{
+    keysyms::{self, keysym_from_name, keysym_name},
+    Modifiers,
+};
+
+/// The modifiers that are taken into account when matching hotkeys. Lock
+/// keys (Caps Lock, Num Lock) and mouse buttons are ignored.
+fn modifier_mask() -> Modifiers {
+    Modifiers::SHIFT
+        | Modifiers::CONTROL
+        | Modifiers::MOD1
+        | Modifiers::SUPER
+        | Modifiers::HYPER
+        | Modifiers::META
+        | Modifiers::RELEASE
+}
+
+/// Returns the modifier that's controlled by the key, if it's a modifier key
+fn modifier_of_key(keysym: u32) -> Modifiers {
+    match keysym {
+        keysyms::KEY_Shift_L | keysyms::KEY_Shift_R => Modifiers::SHIFT,
+        keysyms::KEY_Control_L | keysyms::KEY_Control_R => Modifiers::CONTROL,
+        keysyms::KEY_Alt_L | keysyms::KEY_Alt_R => Modifiers::MOD1,
+        keysyms::KEY_Meta_L | keysyms::KEY_Meta_R => Modifiers::META,
+        keysyms::KEY_Super_L | keysyms::KEY_Super_R => Modifiers::SUPER,
+        keysyms::KEY_Hyper_L | keysyms::KEY_Hyper_R => Modifiers::HYPER,
+        _ => Modifiers::empty(),
+    }
+}
+
+/// Brings a key event to the form that hotkeys are stored in
+fn normalize(keysym: u32, mut modifiers: Modifiers) -> (u32, Modifiers) {
+    // Super is usually reported as Mod4
+    if modifiers.contains(Modifiers::MOD4) {
+        modifiers.insert(Modifiers::SUPER);
+    }
+    // Pressing a modifier key doesn't include the modifier in the state, but
+    // releasing it does
+    modifiers.remove(modifier_of_key(keysym));
+    let keysym = match char::from_u32(keysym) {
+        Some(c) if c.is_ascii_uppercase() => c.to_ascii_lowercase() as u32,
+        _ => keysym,
+    };
+    (keysym, modifiers & modifier_mask())
+}
+
+/// A key combination, e.g. Ctrl+Space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    pub keysym: u32,
+    pub modifiers: Modifiers,
+}
+impl Hotkey {
+    pub fn new(keysym: u32, modifiers: Modifiers) -> Self {
+        let (keysym, modifiers) = normalize(keysym, modifiers);
+        Hotkey { keysym, modifiers }
+    }
+
+    /// Parses an accelerator string
+    ///
+    /// Both the GTK format (`<Control><Shift>space`) and the IBus format
+    /// (`Control+Shift+space`) are accepted. The `Release` modifier makes
+    /// the hotkey trigger when the key is released, which is how hotkeys
+    /// consisting of a single modifier key (e.g. `Release+Shift_L`) work.
+    ///
+    /// ```
+    /// use ibus::{Hotkey, Modifiers, keysyms};
+    ///
+    /// let hotkey = Hotkey::parse("<Ctrl>space").unwrap();
+    /// assert_eq!(hotkey, Hotkey::new(keysyms::KEY_space, Modifiers::CONTROL));
+    /// assert_eq!(Hotkey::parse("Control+space"), Some(hotkey));
+    /// ```
+    pub fn parse(accelerator: &str) -> Option<Self> {
+        let mut modifiers = Modifiers::empty();
+        let mut rest = accelerator.trim();
+        while let Some(after) = rest.strip_prefix('<') {
+            let (name, after) = after.split_once('>')?;
+            modifiers |= modifier_from_name(name)?;
+            rest = after;
+        }
+        let mut parts = rest.split('+').peekable();
+        let mut key = parts.next()?;
+        while parts.peek().is_some() {
+            modifiers |= modifier_from_name(key)?;
+            key = parts.next()?;
+        }
+        // "Control++" would be split into two empty parts
+        if key.is_empty() {
+            return None;
+        }
+        Some(Self::new(keysym_from_name(key)?, modifiers))
+    }
+
+    /// Whether this hotkey triggers when the key is released
+    pub fn on_release(&self) -> bool {
+        self.modifiers.contains(Modifiers::RELEASE)
+    }
+}
+
+/// Formats the hotkey in the IBus format, e.g. `Control+space`
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in MODIFIER_NAMES {
+            if self.modifiers.contains(*modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match keysym_name(self.keysym) {
+            Some(name) => f.write_str(name),
+            None => write!(f, "0x{:x}", self.keysym),
+        }
+    }
+}
+
+const MODIFIER_NAMES: &[(Modifiers, &str)] = &[
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::CONTROL, "Control"),
+    (Modifiers::MOD1, "Alt"),
+    (Modifiers::SUPER, "Super"),
+    (Modifiers::HYPER, "Hyper"),
+    (Modifiers::META, "Meta"),
+    (Modifiers::RELEASE, "Release"),
+];
+
+fn modifier_from_name(name: &str) -> Option<Modifiers> {
+    let modifier = match name.to_ascii_lowercase().as_str() {
+        "shift" => Modifiers::SHIFT,
+        "control" | "ctrl" | "ctl" | "primary" => Modifiers::CONTROL,
+        "alt" | "mod1" => Modifiers::MOD1,
+        "super" | "mod4" => Modifiers::SUPER,
+        "hyper" => Modifiers::HYPER,
+        "meta" => Modifiers::META,
+        "release" => Modifiers::RELEASE,
+        "lock" => Modifiers::LOCK,
+        "mod2" => Modifiers::MOD2,
+        "mod3" => Modifiers::MOD3,
+        "mod5" => Modifiers::MOD5,
+        _ => return None,
+    };
+    Some(modifier)
+}
+
+/// A set of hotkeys, each triggering a named event
+///
+/// ```
+/// use ibus::{HotkeyProfile, Modifiers, keysyms};
+///
+/// let mut profile = HotkeyProfile::new();
+/// profile.add_hotkey_from_str("Control+space", "toggle");
+/// let event = profile.filter_key_event(
+///     keysyms::KEY_space,
+///     Modifiers::CONTROL,
+///     keysyms::KEY_Control_L,
+///     Modifiers::empty(),
+/// );
+/// assert_eq!(event, Some("toggle"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HotkeyProfile {
+    hotkeys: Vec<(Hotkey, String)>,
+}
+impl HotkeyProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a hotkey that triggers `event`. A hotkey can only trigger a
+    /// single event, so this replaces the previous event of the hotkey.
+    pub fn add_hotkey(&mut self, hotkey: Hotkey, event: impl Into<String>) {
+        let event = event.into();
+        match self.hotkeys.iter_mut().find(|(h, _)| *h == hotkey) {
+            Some((_, e)) => *e = event,
+            None => self.hotkeys.push((hotkey, event)),
+        }
+    }
+
+    /// Parses the hotkey using `Hotkey::parse` and adds it.
+    ///
+    /// Returns false if the accelerator couldn't be parsed.
+    pub fn add_hotkey_from_str(&mut self, accelerator: &str, event: impl Into<String>) -> bool {
+        match Hotkey::parse(accelerator) {
+            Some(hotkey) => {
+                self.add_hotkey(hotkey, event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns false if the hotkey wasn't in the profile
+    pub fn remove_hotkey(&mut self, hotkey: &Hotkey) -> bool {
+        let len = self.hotkeys.len();
+        self.hotkeys.retain(|(h, _)| h != hotkey);
+        self.hotkeys.len() != len
+    }
+
+    /// Removes every hotkey of the event
+    pub fn remove_event(&mut self, event: &str) {
+        self.hotkeys.retain(|(_, e)| e != event);
+    }
+
+    /// The hotkeys that trigger `event`
+    pub fn hotkeys_for<'a>(&'a self, event: &'a str) -> impl Iterator<Item = &'a Hotkey> + 'a {
+        self.hotkeys
+            .iter()
+            .filter(move |(_, e)| e == event)
+            .map(|(h, _)| h)
+    }
+
+    /// Returns the event that the key event triggers
+    ///
+    /// `prev_keysym` and `prev_modifiers` describe the previous key event.
+    /// They are needed for hotkeys that trigger on release: these only
+    /// trigger if the previous event was pressing the same key, so that e.g.
+    /// Shift+A doesn't trigger a `Release+Shift_L` hotkey.
+    pub fn filter_key_event(
+        &self,
+        keysym: u32,
+        modifiers: Modifiers,
+        prev_keysym: u32,
+        prev_modifiers: Modifiers,
+    ) -> Option<&str> {
+        let (keysym, modifiers) = normalize(keysym, modifiers);
+        if modifiers.contains(Modifiers::RELEASE) {
+            let (prev_keysym, prev_modifiers) = normalize(prev_keysym, prev_modifiers);
+            if prev_keysym != keysym || prev_modifiers | Modifiers::RELEASE != modifiers {
+                return None;
+            }
+        }
+        let hotkey = Hotkey { keysym, modifiers };
+        self.hotkeys
+            .iter()
+            .find(|(h, _)| *h == hotkey)
+            .map(|(_, e)| e.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let hotkey = Hotkey::parse("<Ctrl><Shift>A").unwrap();
+        assert_eq!(hotkey.keysym, keysyms::KEY_a);
+        assert_eq!(hotkey.modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+        assert_eq!(hotkey.to_string(), "Shift+Control+a");
+        assert_eq!(Hotkey::parse(&hotkey.to_string()), Some(hotkey));
+        assert_eq!(Hotkey::parse("Nonexistent+a"), None);
+        assert_eq!(Hotkey::parse("Control+"), None);
+    }
+
+    #[test]
+    fn release_hotkeys_need_a_matching_press() {
+        let mut profile = HotkeyProfile::new();
+        assert!(profile.add_hotkey_from_str("Release+Shift_L", "switch"));
+        let shift = keysyms::KEY_Shift_L;
+        // Press Shift, release Shift
+        assert_eq!(
+            profile.filter_key_event(shift, Modifiers::empty(), 0, Modifiers::empty()),
+            None
+        );
+        assert_eq!(
+            profile.filter_key_event(
+                shift,
+                Modifiers::SHIFT | Modifiers::RELEASE,
+                shift,
+                Modifiers::empty()
+            ),
+            Some("switch")
+        );
+        // Shift+A, then releasing Shift
+        assert_eq!(
+            profile.filter_key_event(
+                shift,
+                Modifiers::SHIFT | Modifiers::RELEASE,
+                keysyms::KEY_A,
+                Modifiers::SHIFT | Modifiers::RELEASE
+            ),
+            None
+        );
+    }
+}
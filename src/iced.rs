@@ -0,0 +1,163 @@
+//! Receiving the events of IBus in an iced application
+//!
+//! `IcedDispatcher` runs the connection on a thread like `WinitDispatcher`,
+//! and `subscription` delivers the decoded `ImeEvent`s to `update` as
+//! messages:
+//!
+//! ```no_run
+//! use ibus::{iced::IcedDispatcher, Capabilites, ImeEvent};
+//! use iced_futures::Subscription;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Ime(ImeEvent),
+//! }
+//!
+//! struct App {
+//!     ime: IcedDispatcher,
+//! }
+//! impl App {
+//!     fn new() -> Self {
+//!         let ime = IcedDispatcher::spawn("my-app").unwrap();
+//!         ime.with_context(|ctx| {
+//!             ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+//!             ctx.focus_in()
+//!         })
+//!         .unwrap()
+//!         .unwrap();
+//!         App { ime }
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         self.ime.subscription().map(Message::Ime)
+//!     }
+//! }
+//! ```
+//!
+//! `set_caret_bounds` places the candidate window at the caret, from the
+//! bounds of a widget in the logical coordinates of iced.
+//!
+//! This module needs the `iced` feature.
+//!
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use futures_channel::mpsc::{self, UnboundedReceiver};
+use futures_util::{stream, StreamExt};
+use iced_core::{Point, Rectangle};
+use iced_futures::Subscription;
+
+use crate::{context_thread::ContextThread, Error, ImeEvent, InputContext};
+
+/// Tells the subscriptions of the dispatchers apart
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// An input context on a thread that sends its events to a `Subscription`,
+/// see the module documentation
+///
+/// The thread stops when the dispatcher is dropped.
+pub struct IcedDispatcher {
+    thread: ContextThread,
+    id: u64,
+    events: Arc<Mutex<Option<UnboundedReceiver<ImeEvent>>>>,
+}
+impl IcedDispatcher {
+    /// Connects to the daemon and creates an input context called `name` on
+    /// a new thread. Returns once the input context is created.
+    pub fn spawn(name: &str) -> Result<Self, Error> {
+        let (sender, receiver) = mpsc::unbounded();
+        let thread = ContextThread::spawn("ibus-iced", name, move |event| {
+            sender.unbounded_send(event).is_ok()
+        })?;
+        Ok(IcedDispatcher {
+            thread,
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            events: Arc::new(Mutex::new(Some(receiver))),
+        })
+    }
+
+    /// The events of the input context
+    ///
+    /// Return it from the `subscription` of the application every time, iced
+    /// keeps the first one running. The events can't be received again once
+    /// it was left out and stopped.
+    pub fn subscription(&self) -> Subscription<ImeEvent> {
+        let receiver = self.events.lock().unwrap_or_else(|e| e.into_inner()).take();
+        Subscription::run_with_id(("ibus", self.id), stream::iter(receiver).flatten())
+    }
+
+    /// Calls `f` with the input context on the thread of the connection, and
+    /// returns its result
+    pub fn with_context<R, F>(&self, f: F) -> Result<R, Error>
+    where
+        R: Send + 'static,
+        F: FnOnce(&InputContext) -> R + Send + 'static,
+    {
+        self.thread.with_context(f)
+    }
+
+    /// Whether the thread is still running
+    pub fn is_running(&self) -> bool {
+        self.thread.is_running()
+    }
+
+    /// Sets the cursor location to `caret`, see `cursor_location`
+    pub fn set_caret_bounds(
+        &self,
+        window_position: Point,
+        caret: Rectangle,
+        scale_factor: f64,
+    ) -> Result<(), Error> {
+        let (x, y, w, h) = cursor_location(window_position, caret, scale_factor);
+        self.with_context(move |ctx| ctx.set_cursor_location(x, y, w, h))?
+    }
+}
+impl std::fmt::Debug for IcedDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IcedDispatcher")
+            .field("id", &self.id)
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+/// The arguments of `InputContext::set_cursor_location` for a caret with
+/// the `caret` bounds in a window at `window_position`
+///
+/// Both are logical, like the coordinates of iced, and `window_position`
+/// is the one of `window::Event::Moved`. The result is in physical pixels.
+pub fn cursor_location(
+    window_position: Point,
+    caret: Rectangle,
+    scale_factor: f64,
+) -> (i32, i32, i32, i32) {
+    let scaled = |v: f32| (v as f64 * scale_factor).round() as i32;
+    (
+        scaled(window_position.x + caret.x),
+        scaled(window_position.y + caret.y),
+        scaled(caret.width).max(0),
+        scaled(caret.height).max(0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_core::Size;
+
+    #[test]
+    fn caret_bounds() {
+        let caret = Rectangle::new(Point::new(10.0, 20.5), Size::new(1.0, 16.0));
+        assert_eq!(
+            cursor_location(Point::new(100.0, 50.0), caret, 1.0),
+            (110, 71, 1, 16)
+        );
+        assert_eq!(
+            cursor_location(Point::new(100.0, 50.0), caret, 2.0),
+            (220, 141, 2, 32)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use dbus::{arg::ReadAll, message::MatchRule, strings::Path, Message, MessageType};
+
+use crate::{
+    input_context::INTERFACE_NAME, metrics, CommitTextSignal, LookupTable, Modifiers, Text,
+    UpdateAuxiliaryTextSignal, UpdateLookupTableSignal, UpdatePreeditTextSignal,
+};
+
+/// What the engine asks an application to do, decoded from a signal of an
+/// input context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImeEventKind {
+    CommitText(Text<'static>),
+    UpdatePreeditText {
+        text: Text<'static>,
+        cursor_pos: u32,
+        visible: bool,
+    },
+    ShowPreeditText,
+    HidePreeditText,
+    /// A key event that the engine didn't handle, and which the application
+    /// should process as if it had received it directly
+    ForwardKeyEvent {
+        keysym: u32,
+        keycode: u32,
+        modifiers: Modifiers,
+    },
+    /// `offset` is in characters, relative to the cursor
+    DeleteSurroundingText {
+        offset: i32,
+        nchars: u32,
+    },
+    /// The engine wants the application to call
+    /// `InputContext::set_surrounding_text`
+    RequireSurroundingText,
+    UpdateAuxiliaryText {
+        text: Text<'static>,
+        visible: bool,
+    },
+    ShowAuxiliaryText,
+    HideAuxiliaryText,
+    UpdateLookupTable {
+        table: LookupTable,
+        visible: bool,
+    },
+    ShowLookupTable,
+    HideLookupTable,
+}
+
+/// A signal of an input context, see `ImeEventKind`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImeEvent {
+    pub input_context: Path<'static>,
+    pub kind: ImeEventKind,
+}
+impl ImeEvent {
+    /// Decodes a signal of an input context. Returns `None` for other
+    /// messages, and for signals that applications don't need to handle.
+    pub fn from_message(msg: &Message) -> Option<Self> {
+        if msg.msg_type() != MessageType::Signal || &*msg.interface()? != INTERFACE_NAME {
+            return None;
+        }
+        let input_context = msg.path()?.into_static();
+        let member = msg.member()?;
+        let kind = match &*member {
+            "CommitText" => read::<CommitTextSignal>(msg).map(|s| ImeEventKind::CommitText(s.text)),
+            "UpdatePreeditText" => {
+                read::<UpdatePreeditTextSignal>(msg).map(|s| ImeEventKind::UpdatePreeditText {
+                    text: s.text,
+                    cursor_pos: s.cursor_pos,
+                    visible: s.visible,
+                })
+            }
+            "ShowPreeditText" => Some(ImeEventKind::ShowPreeditText),
+            "HidePreeditText" => Some(ImeEventKind::HidePreeditText),
+            "ForwardKeyEvent" => {
+                msg.read3()
+                    .ok()
+                    .map(|(keysym, keycode, state)| ImeEventKind::ForwardKeyEvent {
+                        keysym,
+                        keycode,
+                        modifiers: Modifiers::from_bits_truncate(state),
+                    })
+            }
+            "DeleteSurroundingText" => msg
+                .read2()
+                .ok()
+                .map(|(offset, nchars)| ImeEventKind::DeleteSurroundingText { offset, nchars }),
+            "RequireSurroundingText" => Some(ImeEventKind::RequireSurroundingText),
+            "UpdateAuxiliaryText" => {
+                read::<UpdateAuxiliaryTextSignal>(msg).map(|s| ImeEventKind::UpdateAuxiliaryText {
+                    text: s.text,
+                    visible: s.visible,
+                })
+            }
+            "ShowAuxiliaryText" => Some(ImeEventKind::ShowAuxiliaryText),
+            "HideAuxiliaryText" => Some(ImeEventKind::HideAuxiliaryText),
+            "UpdateLookupTable" => {
+                read::<UpdateLookupTableSignal>(msg).map(|s| ImeEventKind::UpdateLookupTable {
+                    table: s.table,
+                    visible: s.visible,
+                })
+            }
+            "ShowLookupTable" => Some(ImeEventKind::ShowLookupTable),
+            "HideLookupTable" => Some(ImeEventKind::HideLookupTable),
+            _ => return None,
+        };
+        let kind = match kind {
+            Some(kind) => kind,
+            None => {
+                metrics::decode_failed(&member);
+                return None;
+            }
+        };
+        Some(ImeEvent {
+            input_context,
+            kind,
+        })
+    }
+
+    /// Matches the signals of all the input contexts
+    pub(crate) fn match_rule() -> MatchRule<'static> {
+        MatchRule::new()
+            .with_type(MessageType::Signal)
+            .with_interface(INTERFACE_NAME)
+    }
+}
+
+fn read<S: ReadAll>(msg: &Message) -> Option<S> {
+    msg.read_all().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_signals() {
+        let msg = Message::new_signal("/ic/1", INTERFACE_NAME, "DeleteSurroundingText")
+            .unwrap()
+            .append2(-2i32, 2u32);
+        let event = ImeEvent::from_message(&msg).unwrap();
+        assert_eq!(&*event.input_context, "/ic/1");
+        assert_eq!(
+            event.kind,
+            ImeEventKind::DeleteSurroundingText {
+                offset: -2,
+                nchars: 2
+            }
+        );
+
+        let msg = Message::new_signal("/ic/1", INTERFACE_NAME, "CommitText")
+            .unwrap()
+            .append1(Text::from("あ"));
+        assert_eq!(
+            ImeEvent::from_message(&msg).unwrap().kind,
+            ImeEventKind::CommitText("あ".into())
+        );
+
+        let msg = Message::new_signal("/ic/1", INTERFACE_NAME, "Enabled").unwrap();
+        assert_eq!(ImeEvent::from_message(&msg), None);
+    }
+}
@@ -1,14 +1,28 @@
-use std::sync::Arc;
+use std::rc::Rc;
 
 use dbus::{
+    arg::{AppendAll, IterAppend, ReadAll},
     blocking::{Connection, Proxy},
     channel::Token,
+    strings::Path,
     Message,
 };
 
-use crate::{AfterCallback, Capabilites, Error, Modifiers, Text, REQ_TIMEOUT};
+use crate::{
+    diagnostics, dump, surrounding, validate, AfterCallback, Capabilites, EngineDesc, Error,
+    LookupTable, Modifiers, PropState, RetryPolicy, Selection, SurroundingTextProvider, Text,
+    Watchdog, REQ_TIMEOUT,
+};
+
+pub(crate) const INTERFACE_NAME: &str = "org.freedesktop.IBus.InputContext";
 
-const INTERFACE_NAME: &'static str = "org.freedesktop.IBus.InputContext";
+/// A method call of the input context at `path`
+pub(crate) fn input_context_call<A: AppendAll>(path: &Path<'_>, method: &str, args: A) -> Message {
+    let mut msg = Message::new_method_call("org.freedesktop.IBus", path, INTERFACE_NAME, method)
+        .expect("the method of an input context should be valid");
+    args.append(&mut IterAppend::new(&mut msg));
+    msg
+}
 
 #[derive(Debug)]
 pub struct CommitTextSignal {
@@ -21,8 +35,8 @@ impl dbus::arg::ReadAll for CommitTextSignal {
     }
 }
 impl dbus::message::SignalArgs for CommitTextSignal {
-    const NAME: &'static str = "CommitText";
-    const INTERFACE: &'static str = INTERFACE_NAME;
+    const NAME: &str = "CommitText";
+    const INTERFACE: &str = INTERFACE_NAME;
 }
 
 #[derive(Debug)]
@@ -33,8 +47,8 @@ impl dbus::arg::ReadAll for ShowPreeditTextSignal {
     }
 }
 impl dbus::message::SignalArgs for ShowPreeditTextSignal {
-    const NAME: &'static str = "ShowPreeditText";
-    const INTERFACE: &'static str = INTERFACE_NAME;
+    const NAME: &str = "ShowPreeditText";
+    const INTERFACE: &str = INTERFACE_NAME;
 }
 
 #[derive(Debug)]
@@ -45,8 +59,8 @@ impl dbus::arg::ReadAll for HidePreeditTextSignal {
     }
 }
 impl dbus::message::SignalArgs for HidePreeditTextSignal {
-    const NAME: &'static str = "HidePreeditText";
-    const INTERFACE: &'static str = INTERFACE_NAME;
+    const NAME: &str = "HidePreeditText";
+    const INTERFACE: &str = INTERFACE_NAME;
 }
 
 #[derive(Debug)]
@@ -68,22 +82,126 @@ impl dbus::arg::ReadAll for UpdatePreeditTextSignal {
     }
 }
 impl dbus::message::SignalArgs for UpdatePreeditTextSignal {
-    const NAME: &'static str = "UpdatePreeditText";
-    const INTERFACE: &'static str = INTERFACE_NAME;
+    const NAME: &str = "UpdatePreeditText";
+    const INTERFACE: &str = INTERFACE_NAME;
+}
+
+#[derive(Debug)]
+pub struct UpdateAuxiliaryTextSignal {
+    pub text: Text<'static>,
+    pub visible: bool,
+}
+impl dbus::arg::ReadAll for UpdateAuxiliaryTextSignal {
+    fn read(i: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        let text: Text = i.read()?;
+        let visible = i.read()?;
+        Ok(UpdateAuxiliaryTextSignal { text, visible })
+    }
+}
+impl dbus::message::SignalArgs for UpdateAuxiliaryTextSignal {
+    const NAME: &str = "UpdateAuxiliaryText";
+    const INTERFACE: &str = INTERFACE_NAME;
+}
+
+#[derive(Debug)]
+pub struct ShowAuxiliaryTextSignal {}
+impl dbus::arg::ReadAll for ShowAuxiliaryTextSignal {
+    fn read(_: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        Ok(ShowAuxiliaryTextSignal {})
+    }
+}
+impl dbus::message::SignalArgs for ShowAuxiliaryTextSignal {
+    const NAME: &str = "ShowAuxiliaryText";
+    const INTERFACE: &str = INTERFACE_NAME;
+}
+
+#[derive(Debug)]
+pub struct HideAuxiliaryTextSignal {}
+impl dbus::arg::ReadAll for HideAuxiliaryTextSignal {
+    fn read(_: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        Ok(HideAuxiliaryTextSignal {})
+    }
+}
+impl dbus::message::SignalArgs for HideAuxiliaryTextSignal {
+    const NAME: &str = "HideAuxiliaryText";
+    const INTERFACE: &str = INTERFACE_NAME;
+}
+
+#[derive(Debug)]
+pub struct UpdateLookupTableSignal {
+    pub table: LookupTable,
+    pub visible: bool,
+}
+impl dbus::arg::ReadAll for UpdateLookupTableSignal {
+    fn read(i: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        let table = i.read()?;
+        let visible = i.read()?;
+        Ok(UpdateLookupTableSignal { table, visible })
+    }
+}
+impl dbus::message::SignalArgs for UpdateLookupTableSignal {
+    const NAME: &str = "UpdateLookupTable";
+    const INTERFACE: &str = INTERFACE_NAME;
+}
+
+#[derive(Debug)]
+pub struct ShowLookupTableSignal {}
+impl dbus::arg::ReadAll for ShowLookupTableSignal {
+    fn read(_: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        Ok(ShowLookupTableSignal {})
+    }
+}
+impl dbus::message::SignalArgs for ShowLookupTableSignal {
+    const NAME: &str = "ShowLookupTable";
+    const INTERFACE: &str = INTERFACE_NAME;
+}
+
+#[derive(Debug)]
+pub struct HideLookupTableSignal {}
+impl dbus::arg::ReadAll for HideLookupTableSignal {
+    fn read(_: &mut dbus::arg::Iter) -> Result<Self, dbus::arg::TypeMismatchError> {
+        Ok(HideLookupTableSignal {})
+    }
+}
+impl dbus::message::SignalArgs for HideLookupTableSignal {
+    const NAME: &str = "HideLookupTable";
+    const INTERFACE: &str = INTERFACE_NAME;
 }
 
 pub struct InputContext {
-    pub(crate) conn: Arc<dbus::blocking::Connection>,
+    pub(crate) conn: Rc<dbus::blocking::Connection>,
     pub(crate) obj_path: dbus::strings::Path<'static>,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) watchdog: Option<Watchdog>,
 }
 impl InputContext {
+    pub fn path(&self) -> &dbus::strings::Path<'static> {
+        &self.obj_path
+    }
+
+    /// Sets how the calls that are safe to repeat are retried, see
+    /// `RetryPolicy`
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+    }
+
+    /// Sets the watchdog that notices when the engine stops answering, see
+    /// `Watchdog`
+    pub fn set_watchdog(&mut self, watchdog: Option<Watchdog>) {
+        self.watchdog = watchdog;
+    }
+
+    /// Panics if the call fails, see `try_set_capabilities`
     pub fn set_capabilities(&self, caps: Capabilites) {
-        self.with_proxy(|p| {
-            let caps = caps.bits();
-            let () = p
-                .method_call(INTERFACE_NAME, "SetCapabilities", (caps,))
-                .unwrap();
-        })
+        self.try_set_capabilities(caps).unwrap()
+    }
+
+    /// The version of `set_capabilities` that returns the error
+    pub fn try_set_capabilities(&self, caps: Capabilites) -> Result<(), Error> {
+        diagnostics::set_capabilities(&self.obj_path, caps);
+        let caps = caps.bits();
+        self.retry
+            .run(|| self.method_call("SetCapabilities", (caps,)))
     }
 
     pub fn on_show_preedit_text<F>(&self, mut callback: F) -> Result<Token, Error>
@@ -93,6 +211,7 @@ impl InputContext {
         let token = self.with_proxy(|p| {
             p.match_signal(
                 move |_a: ShowPreeditTextSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
                     (callback)(b, c).to_bool()
                 },
             )
@@ -107,6 +226,7 @@ impl InputContext {
         let token = self.with_proxy(|p| {
             p.match_signal(
                 move |_a: HidePreeditTextSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
                     (callback)(b, c).to_bool()
                 },
             )
@@ -120,6 +240,7 @@ impl InputContext {
     {
         let token = self.with_proxy(|p| {
             p.match_signal(move |a: CommitTextSignal, b: &Connection, c: &Message| {
+                dump::received(c);
                 (callback)(a, b, c).to_bool()
             })
         })?;
@@ -133,6 +254,26 @@ impl InputContext {
         let token = self.with_proxy(|p| {
             p.match_signal(
                 move |a: UpdatePreeditTextSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
+                    (callback)(a, b, c).to_bool()
+                },
+            )
+        })?;
+        Ok(token)
+    }
+
+    /// Only emitted if the capabilities include `Capabilites::AUXILIARY_TEXT`.
+    /// Otherwise the panel shows the auxiliary text.
+    pub fn on_update_auxiliary_text<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(UpdateAuxiliaryTextSignal, &Connection, &Message) -> AfterCallback
+            + Send
+            + 'static,
+    {
+        let token = self.with_proxy(|p| {
+            p.match_signal(
+                move |a: UpdateAuxiliaryTextSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
                     (callback)(a, b, c).to_bool()
                 },
             )
@@ -140,21 +281,112 @@ impl InputContext {
         Ok(token)
     }
 
+    pub fn on_show_auxiliary_text<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(&Connection, &Message) -> AfterCallback + Send + 'static,
+    {
+        let token = self.with_proxy(|p| {
+            p.match_signal(
+                move |_a: ShowAuxiliaryTextSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
+                    (callback)(b, c).to_bool()
+                },
+            )
+        })?;
+        Ok(token)
+    }
+
+    pub fn on_hide_auxiliary_text<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(&Connection, &Message) -> AfterCallback + Send + 'static,
+    {
+        let token = self.with_proxy(|p| {
+            p.match_signal(
+                move |_a: HideAuxiliaryTextSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
+                    (callback)(b, c).to_bool()
+                },
+            )
+        })?;
+        Ok(token)
+    }
+
+    /// Only emitted if the capabilities include `Capabilites::LOOKUP_TABLE`.
+    /// Otherwise the panel shows the candidates.
+    pub fn on_update_lookup_table<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(UpdateLookupTableSignal, &Connection, &Message) -> AfterCallback + Send + 'static,
+    {
+        let token = self.with_proxy(|p| {
+            p.match_signal(
+                move |a: UpdateLookupTableSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
+                    (callback)(a, b, c).to_bool()
+                },
+            )
+        })?;
+        Ok(token)
+    }
+
+    pub fn on_show_lookup_table<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(&Connection, &Message) -> AfterCallback + Send + 'static,
+    {
+        let token = self.with_proxy(|p| {
+            p.match_signal(
+                move |_a: ShowLookupTableSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
+                    (callback)(b, c).to_bool()
+                },
+            )
+        })?;
+        Ok(token)
+    }
+
+    pub fn on_hide_lookup_table<F>(&self, mut callback: F) -> Result<Token, Error>
+    where
+        F: FnMut(&Connection, &Message) -> AfterCallback + Send + 'static,
+    {
+        let token = self.with_proxy(|p| {
+            p.match_signal(
+                move |_a: HideLookupTableSignal, b: &Connection, c: &Message| {
+                    dump::received(c);
+                    (callback)(b, c).to_bool()
+                },
+            )
+        })?;
+        Ok(token)
+    }
+
     /// Returns:
     /// - `Ok(true)` if the call was handled succesfully
-    /// - `Ok(false)` if the call was executed but it wasn't handled (this can for example happen when the capabilities aren't set correctly)
+    /// - `Ok(false)` if the call was executed but it wasn't handled (this can for example happen when the capabilities aren't set correctly,
+    ///   `Bus::set_diagnostics` logs why)
     /// - `Err(e)` if an error occured
+    ///
+    /// This blocks until the daemon answers. The signals that arrive in the
+    /// meantime, e.g. the `CommitText` of this key, aren't dispatched during
+    /// the call, they're delivered by the next `Bus::process`, in the order
+    /// they were sent.
     pub fn process_key_event(
         &self,
         sym: u32,
         code: u32,
         modifiers: Modifiers,
     ) -> Result<bool, Error> {
-        self.with_proxy(|p| {
-            let key_args = (sym, code, modifiers.bits());
-            let (handled,): (bool,) = p.method_call(INTERFACE_NAME, "ProcessKeyEvent", key_args)?;
-            Ok(handled)
-        })
+        validate::key_event(&self.obj_path, sym, modifiers)?;
+        if let Some(watchdog) = &self.watchdog {
+            if !watchdog.should_send() {
+                return Ok(false);
+            }
+        }
+        let key_args = (sym, code, modifiers.bits());
+        crate::metrics::key_event_sent();
+        let (handled,): (bool,) = self.method_call("ProcessKeyEvent", key_args)?;
+        if !handled {
+            diagnostics::key_not_handled(&self.obj_path);
+        }
+        Ok(handled)
     }
 
     /// Sets the location of the IME "text selection box"
@@ -163,48 +395,123 @@ impl InputContext {
     ///   to the top left corner of the main display (I think)
     /// - `w` and `h` may be zero
     pub fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Result<(), Error> {
-        self.with_proxy(|p| {
-            let () = p.method_call(INTERFACE_NAME, "SetCursorLocation", (x, y, w, h))?;
-            Ok(())
-        })
+        validate::cursor_location(&self.obj_path, w, h)?;
+        self.retry
+            .run(|| self.method_call("SetCursorLocation", (x, y, w, h)))
     }
 
     pub fn focus_in(&self) -> Result<(), Error> {
-        self.with_proxy(|p| {
-            let () = p.method_call(INTERFACE_NAME, "FocusIn", ())?;
-            Ok(())
-        })
+        self.retry.run(|| self.call0("FocusIn"))
     }
 
     pub fn focus_out(&self) -> Result<(), Error> {
-        self.with_proxy(|p| {
-            let () = p.method_call(INTERFACE_NAME, "FocusOut", ())?;
-            Ok(())
-        })
+        self.retry.run(|| self.call0("FocusOut"))
     }
 
     pub fn reset(&self) -> Result<(), Error> {
-        self.with_proxy(|p| {
-            let () = p.method_call(INTERFACE_NAME, "Reset", ())?;
-            Ok(())
-        })
+        self.call0("Reset")
     }
 
+    /// Sends the text around the cursor, with the positions counting
+    /// characters. Fails with `Error::InvalidArgument` if a position is after
+    /// the end of the text, some engines crash on that.
     pub fn set_surrounding_text<'a>(
         &self,
         text: impl Into<Text<'a>>,
         cursor_pos: u32,
         anchor_pos: u32,
     ) -> Result<(), Error> {
-        self.with_proxy(|p| {
-            let text: Text<'a> = text.into();
-            let () = p.method_call(
-                INTERFACE_NAME,
-                "SetSurroundingText",
-                (text, cursor_pos, anchor_pos),
-            )?;
-            Ok(())
-        })
+        let text: Text<'a> = text.into();
+        validate::surrounding_text(&self.obj_path, &text, cursor_pos, anchor_pos)?;
+        self.method_call("SetSurroundingText", (text, cursor_pos, anchor_pos))
+    }
+
+    /// Like `set_surrounding_text`, but moves the positions after the end of
+    /// the text to the end instead of failing
+    ///
+    /// The positions count characters, see `Selection` for converting them
+    /// from byte or UTF-16 offsets.
+    pub fn set_surrounding_text_clamped<'a>(
+        &self,
+        text: impl Into<Text<'a>>,
+        selection: Selection,
+    ) -> Result<(), Error> {
+        let text: Text<'a> = text.into();
+        let Selection { cursor, anchor } = selection.clamp_to(text.as_str());
+        self.set_surrounding_text(text, cursor, anchor)
+    }
+
+    /// Sends the text around `selection` in `buffer`, e.g. a `String` or a
+    /// `ropey::Rope` with the `ropey` feature
+    ///
+    /// A few hundred characters before and after the selection are sent,
+    /// which is more than the engines look at.
+    pub fn set_surrounding_text_from(
+        &self,
+        buffer: &impl SurroundingTextProvider,
+        selection: Selection,
+    ) -> Result<(), Error> {
+        let (text, Selection { cursor, anchor }) =
+            buffer.surrounding_text(selection, surrounding::CONTEXT_CHARS);
+        self.set_surrounding_text(text, cursor, anchor)
+    }
+
+    /// Asks the engine to show the previous page of candidates, e.g. when the
+    /// user clicks a button of a candidate window drawn by the application
+    pub fn page_up(&self) -> Result<(), Error> {
+        self.call0("PageUp")
+    }
+
+    /// Asks the engine to show the next page of candidates
+    pub fn page_down(&self) -> Result<(), Error> {
+        self.call0("PageDown")
+    }
+
+    /// Asks the engine to move the cursor of the lookup table up
+    pub fn cursor_up(&self) -> Result<(), Error> {
+        self.call0("CursorUp")
+    }
+
+    /// Asks the engine to move the cursor of the lookup table down
+    pub fn cursor_down(&self) -> Result<(), Error> {
+        self.call0("CursorDown")
+    }
+
+    /// Tells the engine that the user clicked a candidate
+    ///
+    /// - `index` is relative to the current page of the lookup table
+    /// - `button` is the mouse button, 1 for the primary button
+    pub fn candidate_clicked(
+        &self,
+        index: u32,
+        button: u32,
+        state: Modifiers,
+    ) -> Result<(), Error> {
+        self.method_call("CandidateClicked", (index, button, state.bits()))
+    }
+
+    /// Tells the engine that the user activated one of its properties
+    pub fn property_activate(&self, name: &str, state: PropState) -> Result<(), Error> {
+        self.method_call("PropertyActivate", (name, state.to_value()))
+    }
+
+    /// Returns the engine that is used by this input context
+    pub fn engine(&self) -> Result<EngineDesc, Error> {
+        input_context_engine(&self.conn, &self.obj_path)
+    }
+
+    fn call0(&self, method: &str) -> Result<(), Error> {
+        self.method_call(method, ())
+    }
+
+    fn method_call<A: AppendAll, R: ReadAll>(&self, method: &str, args: A) -> Result<R, Error> {
+        let msg = input_context_call(&self.obj_path, method, args);
+        let result = dump::call(self.conn.channel(), msg)
+            .map_err(|e| Error::from(e).in_call(&self.obj_path, INTERFACE_NAME, method));
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.record(self.conn.channel(), &self.obj_path, &result);
+        }
+        result
     }
 
     fn with_proxy<R, F: FnOnce(Proxy<&Connection>) -> R>(&self, f: F) -> R {
@@ -214,3 +521,17 @@ impl InputContext {
         f(proxy)
     }
 }
+
+/// Returns the engine of the input context at `path`
+///
+/// Fails if the input context has no engine, e.g. because input methods are
+/// turned off.
+pub(crate) fn input_context_engine(
+    conn: &Connection,
+    path: &dbus::strings::Path,
+) -> Result<EngineDesc, Error> {
+    let msg = input_context_call(path, "GetEngine", ());
+    let (desc,): (EngineDesc,) = dump::call(conn.channel(), msg)
+        .map_err(|e| Error::from(e).in_call(path, INTERFACE_NAME, "GetEngine"))?;
+    Ok(desc)
+}
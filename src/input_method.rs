@@ -0,0 +1,251 @@
+use std::time::Duration;
+
+use dbus::{channel::Token, strings::Path};
+
+use crate::{
+    AnyBus, AnyInputContext, Bus, Capabilites, Error, EventQueue, InputContext, Modifiers, NullBus,
+    NullInputContext, PropState, SyncBus, SyncInputContext, Text,
+};
+
+/// A connection to an input method, implemented by `Bus`, `SyncBus`,
+/// `NullBus` and `AnyBus`
+///
+/// Code that is generic over this, e.g. a text editor widget, works with any
+/// of them, and can be tested with a mock instead of a daemon:
+///
+/// ```
+/// use std::cell::RefCell;
+/// use ibus::{Capabilites, Error, ImContext, Modifiers};
+///
+/// /// Handles everything but the keys of the Latin alphabet
+/// struct MockContext {
+///     keys: RefCell<Vec<u32>>,
+/// }
+/// impl ImContext for MockContext {
+///     fn path(&self) -> dbus::Path<'static> {
+///         "/mock".into()
+///     }
+///     fn set_capabilities(&self, _: Capabilites) -> Result<(), Error> {
+///         Ok(())
+///     }
+///     fn process_key_event(&self, sym: u32, _: u32, _: Modifiers) -> Result<bool, Error> {
+///         self.keys.borrow_mut().push(sym);
+///         Ok(!(0x61..=0x7a).contains(&sym))
+///     }
+///     // ... and the rest of the methods
+/// #   fn set_cursor_location(&self, _: i32, _: i32, _: i32, _: i32) -> Result<(), Error> { Ok(()) }
+/// #   fn focus_in(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn focus_out(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn reset(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn set_surrounding_text(&self, _: ibus::Text, _: u32, _: u32) -> Result<(), Error> { Ok(()) }
+/// #   fn page_up(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn page_down(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn cursor_up(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn cursor_down(&self) -> Result<(), Error> { Ok(()) }
+/// #   fn candidate_clicked(&self, _: u32, _: u32, _: Modifiers) -> Result<(), Error> { Ok(()) }
+/// #   fn property_activate(&self, _: &str, _: ibus::PropState) -> Result<(), Error> { Ok(()) }
+/// }
+///
+/// struct Editor<C> {
+///     ctx: C,
+///     text: String,
+/// }
+/// impl<C: ImContext> Editor<C> {
+///     fn key(&mut self, sym: u32, ch: char) {
+///         if !self.ctx.process_key_event(sym, 0, Modifiers::empty()).unwrap() {
+///             self.text.push(ch);
+///         }
+///     }
+/// }
+///
+/// let mut editor = Editor {
+///     ctx: MockContext { keys: RefCell::new(Vec::new()) },
+///     text: String::new(),
+/// };
+/// editor.key(0x61, 'a');
+/// editor.key(0x31, '1');
+/// assert_eq!(editor.text, "a");
+/// ```
+pub trait InputMethod {
+    type Context: ImContext;
+
+    fn create_input_context(&self, name: &str) -> Result<Self::Context, Error>;
+
+    /// Decodes the signals of the input contexts into `queue` from
+    /// `process`, see `Bus::queue_events`
+    fn queue_events(&self, queue: EventQueue) -> Token;
+
+    fn remove_queue(&self, token: Token);
+
+    /// See `Bus::process`
+    fn process(&self, timeout: Duration) -> Result<bool, Error>;
+}
+
+/// An input context of an `InputMethod`, see `InputContext` for the methods
+pub trait ImContext {
+    fn path(&self) -> Path<'static>;
+
+    fn set_capabilities(&self, caps: Capabilites) -> Result<(), Error>;
+
+    /// Returns whether the key was handled by the input method
+    fn process_key_event(&self, sym: u32, code: u32, modifiers: Modifiers) -> Result<bool, Error>;
+
+    fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Result<(), Error>;
+
+    fn focus_in(&self) -> Result<(), Error>;
+
+    fn focus_out(&self) -> Result<(), Error>;
+
+    fn reset(&self) -> Result<(), Error>;
+
+    fn set_surrounding_text(
+        &self,
+        text: Text<'_>,
+        cursor_pos: u32,
+        anchor_pos: u32,
+    ) -> Result<(), Error>;
+
+    fn page_up(&self) -> Result<(), Error>;
+
+    fn page_down(&self) -> Result<(), Error>;
+
+    fn cursor_up(&self) -> Result<(), Error>;
+
+    fn cursor_down(&self) -> Result<(), Error>;
+
+    fn candidate_clicked(&self, index: u32, button: u32, state: Modifiers) -> Result<(), Error>;
+
+    fn property_activate(&self, name: &str, state: PropState) -> Result<(), Error>;
+}
+
+macro_rules! impl_input_method {
+    ($bus:ty, $ctx:ty) => {
+        impl InputMethod for $bus {
+            type Context = $ctx;
+
+            fn create_input_context(&self, name: &str) -> Result<$ctx, Error> {
+                <$bus>::create_input_context(self, name)
+            }
+
+            fn queue_events(&self, queue: EventQueue) -> Token {
+                <$bus>::queue_events(self, queue)
+            }
+
+            fn remove_queue(&self, token: Token) {
+                <$bus>::remove_queue(self, token)
+            }
+
+            fn process(&self, timeout: Duration) -> Result<bool, Error> {
+                <$bus>::process(self, timeout)
+            }
+        }
+    };
+}
+
+impl_input_method!(Bus, InputContext);
+impl_input_method!(SyncBus, SyncInputContext);
+impl_input_method!(NullBus, NullInputContext);
+impl_input_method!(AnyBus, AnyInputContext);
+
+/// `set_capabilities` is the only method whose name differs between the
+/// contexts, it's implemented by the invocations
+macro_rules! impl_im_context {
+    ($ctx:ty, |$this:ident, $caps:ident| $set_capabilities:expr) => {
+        impl ImContext for $ctx {
+            fn path(&self) -> Path<'static> {
+                <$ctx>::path(self).clone()
+            }
+
+            fn set_capabilities(&self, $caps: Capabilites) -> Result<(), Error> {
+                let $this = self;
+                $set_capabilities
+            }
+
+            fn process_key_event(
+                &self,
+                sym: u32,
+                code: u32,
+                modifiers: Modifiers,
+            ) -> Result<bool, Error> {
+                <$ctx>::process_key_event(self, sym, code, modifiers)
+            }
+
+            fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Result<(), Error> {
+                <$ctx>::set_cursor_location(self, x, y, w, h)
+            }
+
+            fn focus_in(&self) -> Result<(), Error> {
+                <$ctx>::focus_in(self)
+            }
+
+            fn focus_out(&self) -> Result<(), Error> {
+                <$ctx>::focus_out(self)
+            }
+
+            fn reset(&self) -> Result<(), Error> {
+                <$ctx>::reset(self)
+            }
+
+            fn set_surrounding_text(
+                &self,
+                text: Text<'_>,
+                cursor_pos: u32,
+                anchor_pos: u32,
+            ) -> Result<(), Error> {
+                <$ctx>::set_surrounding_text(self, text, cursor_pos, anchor_pos)
+            }
+
+            fn page_up(&self) -> Result<(), Error> {
+                <$ctx>::page_up(self)
+            }
+
+            fn page_down(&self) -> Result<(), Error> {
+                <$ctx>::page_down(self)
+            }
+
+            fn cursor_up(&self) -> Result<(), Error> {
+                <$ctx>::cursor_up(self)
+            }
+
+            fn cursor_down(&self) -> Result<(), Error> {
+                <$ctx>::cursor_down(self)
+            }
+
+            fn candidate_clicked(
+                &self,
+                index: u32,
+                button: u32,
+                state: Modifiers,
+            ) -> Result<(), Error> {
+                <$ctx>::candidate_clicked(self, index, button, state)
+            }
+
+            fn property_activate(&self, name: &str, state: PropState) -> Result<(), Error> {
+                <$ctx>::property_activate(self, name, state)
+            }
+        }
+    };
+}
+
+impl_im_context!(InputContext, |ctx, caps| ctx.try_set_capabilities(caps));
+impl_im_context!(SyncInputContext, |ctx, caps| ctx.set_capabilities(caps));
+impl_im_context!(NullInputContext, |ctx, caps| ctx.try_set_capabilities(caps));
+impl_im_context!(AnyInputContext, |ctx, caps| ctx.try_set_capabilities(caps));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_key<M: InputMethod>(im: &M) -> Result<bool, Error> {
+        let ctx = im.create_input_context("test")?;
+        ctx.set_capabilities(Capabilites::FOCUS)?;
+        ctx.focus_in()?;
+        ctx.process_key_event(0x61, 30, Modifiers::empty())
+    }
+
+    #[test]
+    fn generic_over_the_backend() {
+        assert!(!type_key(&NullBus::new()).unwrap());
+        assert!(!type_key(&AnyBus::Null(NullBus::new())).unwrap());
+    }
+}
@@ -0,0 +1,101 @@
+//! The `tracing` instrumentation of the `tracing` feature
+//!
+//! Without the feature, these are no-ops that the compiler removes, so the
+//! rest of the crate doesn't need `cfg` attributes.
+
+#[cfg(feature = "tracing")]
+mod imp {
+    use std::time::Instant;
+
+    use dbus::Message;
+    use tracing::{field, Span};
+
+    /// The span of a method call, with its latency once it's finished
+    pub(crate) struct CallSpan {
+        span: Span,
+        start: Instant,
+    }
+    impl CallSpan {
+        pub(crate) fn new(msg: &Message) -> Self {
+            let span = tracing::debug_span!(
+                "ibus_call",
+                path = %msg.path().map(|p| p.to_string()).unwrap_or_default(),
+                interface = %msg.interface().map(|i| i.to_string()).unwrap_or_default(),
+                member = %msg.member().map(|m| m.to_string()).unwrap_or_default(),
+                latency_us = field::Empty,
+                error = field::Empty,
+            );
+            CallSpan {
+                span,
+                start: Instant::now(),
+            }
+        }
+
+        pub(crate) fn finish(self, error: Option<&dbus::Error>) {
+            let latency_us = self.start.elapsed().as_micros() as u64;
+            self.span.record("latency_us", latency_us);
+            match error {
+                Some(e) => {
+                    let name = e.name().unwrap_or("unknown");
+                    self.span.record("error", name);
+                    tracing::debug!(parent: &self.span, latency_us, error = name, "call failed");
+                }
+                None => tracing::trace!(parent: &self.span, latency_us, "call finished"),
+            }
+        }
+    }
+
+    pub(crate) fn dispatch_span() -> tracing::span::EnteredSpan {
+        tracing::trace_span!("ibus_process").entered()
+    }
+
+    pub(crate) fn received(msg: &Message) {
+        tracing::trace!(
+            path = %msg.path().map(|p| p.to_string()).unwrap_or_default(),
+            member = %msg.member().map(|m| m.to_string()).unwrap_or_default(),
+            "message received"
+        );
+    }
+
+    pub(crate) fn retry(attempt: u32, error: &crate::Error) {
+        tracing::debug!(attempt, error = %error, "retrying call");
+    }
+
+    pub(crate) fn disconnected() {
+        tracing::warn!("disconnected from the IBus daemon");
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+mod imp {
+    use dbus::Message;
+
+    pub(crate) struct CallSpan;
+    impl CallSpan {
+        #[inline]
+        pub(crate) fn new(_msg: &Message) -> Self {
+            CallSpan
+        }
+
+        #[inline]
+        pub(crate) fn finish(self, _error: Option<&dbus::Error>) {}
+    }
+
+    pub(crate) struct DispatchSpan;
+
+    #[inline]
+    pub(crate) fn dispatch_span() -> DispatchSpan {
+        DispatchSpan
+    }
+
+    #[inline]
+    pub(crate) fn received(_msg: &Message) {}
+
+    #[inline]
+    pub(crate) fn retry(_attempt: u32, _error: &crate::Error) {}
+
+    #[inline]
+    pub(crate) fn disconnected() {}
+}
+
+pub(crate) use imp::*;
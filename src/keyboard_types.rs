@@ -0,0 +1,408 @@
+//! Conversions between the types of the `keyboard-types` crate and IBus
+//!
+//! `keyboard-types` follows the W3C `KeyboardEvent`, which the crates of
+//! the druid and xilem lineage and the web-like stacks share. `Key` and
+//! `keysym` convert to and from the keysyms, `Code` and `evdev_code` to and
+//! from the keycodes, `Modifiers` and `modifiers` to and from the modifiers,
+//! and `key_event_args` converts a whole `KeyboardEvent`:
+//!
+//! ```
+//! use ibus::keyboard_types::key_event_args;
+//! use keyboard_types::{Code, Key, KeyboardEvent, Modifiers};
+//!
+//! let event = KeyboardEvent {
+//!     key: Key::Character("a".into()),
+//!     code: Code::KeyA,
+//!     modifiers: Modifiers::CONTROL,
+//!     ..KeyboardEvent::default()
+//! };
+//! assert_eq!(
+//!     key_event_args(&event),
+//!     Some((ibus::keysyms::KEY_a, 30, ibus::Modifiers::CONTROL))
+//! );
+//! ```
+//!
+//! This module needs the `keyboard-types` feature.
+//!
+
+use keyboard_types::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers as KeyModifiers};
+
+use crate::{keysyms, Modifiers};
+
+/// The keysym, the keycode and the modifiers of a `KeyboardEvent`, the
+/// arguments of `InputContext::process_key_event`
+///
+/// Returns `None` for keys without a keysym. The keycode is 0 for
+/// `Code::Unidentified`.
+pub fn key_event_args(event: &KeyboardEvent) -> Option<(u32, u32, Modifiers)> {
+    let sym = keysym(&event.key, event.location)?;
+    let mut state = modifiers(event.modifiers);
+    if event.state == KeyState::Up {
+        state |= Modifiers::RELEASE;
+    }
+    Some((sym, evdev_code(event.code), state))
+}
+
+/// Converts the modifiers of `keyboard-types` to the ones of IBus, Super is
+/// also `MOD4` and AltGraph is `MOD5` as in X11
+pub fn modifiers(modifiers: KeyModifiers) -> Modifiers {
+    let pairs = [
+        (KeyModifiers::SHIFT, Modifiers::SHIFT),
+        (KeyModifiers::CAPS_LOCK, Modifiers::LOCK),
+        (KeyModifiers::CONTROL, Modifiers::CONTROL),
+        (KeyModifiers::ALT, Modifiers::MOD1),
+        (KeyModifiers::NUM_LOCK, Modifiers::MOD2),
+        (KeyModifiers::SUPER, Modifiers::SUPER | Modifiers::MOD4),
+        (KeyModifiers::ALT_GRAPH, Modifiers::MOD5),
+        (KeyModifiers::HYPER, Modifiers::HYPER),
+        (KeyModifiers::META, Modifiers::META),
+    ];
+    pairs
+        .into_iter()
+        .filter(|(from, _)| modifiers.contains(*from))
+        .fold(Modifiers::empty(), |state, (_, to)| state | to)
+}
+
+/// Converts the modifiers of IBus to the ones of `keyboard-types`, the
+/// buttons and the flags of IBus are left out
+pub fn key_modifiers(state: Modifiers) -> KeyModifiers {
+    let pairs = [
+        (Modifiers::SHIFT, KeyModifiers::SHIFT),
+        (Modifiers::LOCK, KeyModifiers::CAPS_LOCK),
+        (Modifiers::CONTROL, KeyModifiers::CONTROL),
+        (Modifiers::MOD1, KeyModifiers::ALT),
+        (Modifiers::MOD2, KeyModifiers::NUM_LOCK),
+        (Modifiers::MOD4, KeyModifiers::SUPER),
+        (Modifiers::SUPER, KeyModifiers::SUPER),
+        (Modifiers::MOD5, KeyModifiers::ALT_GRAPH),
+        (Modifiers::HYPER, KeyModifiers::HYPER),
+        (Modifiers::META, KeyModifiers::META),
+    ];
+    pairs
+        .into_iter()
+        .filter(|(from, _)| state.contains(*from))
+        .fold(KeyModifiers::empty(), |modifiers, (_, to)| modifiers | to)
+}
+
+/// The named keys that are the same on every location
+const NAMED_KEYS: &[(Key, u32)] = &[
+    (Key::Enter, keysyms::KEY_Return),
+    (Key::Tab, keysyms::KEY_Tab),
+    (Key::Backspace, keysyms::KEY_BackSpace),
+    (Key::Escape, keysyms::KEY_Escape),
+    (Key::Delete, keysyms::KEY_Delete),
+    (Key::Insert, keysyms::KEY_Insert),
+    (Key::Home, keysyms::KEY_Home),
+    (Key::End, keysyms::KEY_End),
+    (Key::PageUp, keysyms::KEY_Page_Up),
+    (Key::PageDown, keysyms::KEY_Page_Down),
+    (Key::ArrowLeft, keysyms::KEY_Left),
+    (Key::ArrowRight, keysyms::KEY_Right),
+    (Key::ArrowUp, keysyms::KEY_Up),
+    (Key::ArrowDown, keysyms::KEY_Down),
+    (Key::AltGraph, keysyms::KEY_ISO_Level3_Shift),
+    (Key::CapsLock, keysyms::KEY_Caps_Lock),
+    (Key::NumLock, keysyms::KEY_Num_Lock),
+    (Key::ScrollLock, keysyms::KEY_Scroll_Lock),
+    (Key::PrintScreen, keysyms::KEY_Print),
+    (Key::Pause, keysyms::KEY_Pause),
+    (Key::ContextMenu, keysyms::KEY_Menu),
+    (Key::Compose, keysyms::KEY_Multi_key),
+    (Key::Help, keysyms::KEY_Help),
+    (Key::Clear, keysyms::KEY_Clear),
+    (Key::Select, keysyms::KEY_Select),
+    (Key::Execute, keysyms::KEY_Execute),
+    (Key::Undo, keysyms::KEY_Undo),
+    (Key::Redo, keysyms::KEY_Redo),
+    (Key::Find, keysyms::KEY_Find),
+    (Key::Cancel, keysyms::KEY_Cancel),
+    // The keys of the Japanese and Korean keyboards, which IMEs use
+    (Key::Convert, keysyms::KEY_Henkan),
+    (Key::NonConvert, keysyms::KEY_Muhenkan),
+    (Key::KanaMode, keysyms::KEY_Kana_Lock),
+    (Key::Hiragana, keysyms::KEY_Hiragana),
+    (Key::Katakana, keysyms::KEY_Katakana),
+    (Key::HiraganaKatakana, keysyms::KEY_Hiragana_Katakana),
+    (Key::ZenkakuHankaku, keysyms::KEY_Zenkaku_Hankaku),
+    (Key::KanjiMode, keysyms::KEY_Kanji),
+    (Key::Eisu, keysyms::KEY_Eisu_toggle),
+    (Key::Romaji, keysyms::KEY_Romaji),
+    (Key::HangulMode, keysyms::KEY_Hangul),
+    (Key::HanjaMode, keysyms::KEY_Hangul_Hanja),
+    (Key::F1, keysyms::KEY_F1),
+    (Key::F2, keysyms::KEY_F2),
+    (Key::F3, keysyms::KEY_F3),
+    (Key::F4, keysyms::KEY_F4),
+    (Key::F5, keysyms::KEY_F5),
+    (Key::F6, keysyms::KEY_F6),
+    (Key::F7, keysyms::KEY_F7),
+    (Key::F8, keysyms::KEY_F8),
+    (Key::F9, keysyms::KEY_F9),
+    (Key::F10, keysyms::KEY_F10),
+    (Key::F11, keysyms::KEY_F11),
+    (Key::F12, keysyms::KEY_F12),
+];
+
+/// The modifier keys, with the keysyms of the left and the right one
+const SIDED_KEYS: &[(Key, u32, u32)] = &[
+    (Key::Shift, keysyms::KEY_Shift_L, keysyms::KEY_Shift_R),
+    (Key::Control, keysyms::KEY_Control_L, keysyms::KEY_Control_R),
+    (Key::Alt, keysyms::KEY_Alt_L, keysyms::KEY_Alt_R),
+    (Key::Super, keysyms::KEY_Super_L, keysyms::KEY_Super_R),
+    (Key::Meta, keysyms::KEY_Meta_L, keysyms::KEY_Meta_R),
+    (Key::Hyper, keysyms::KEY_Hyper_L, keysyms::KEY_Hyper_R),
+];
+
+/// The keysym of a key, `location` tells the keypad and the right modifiers
+/// apart
+///
+/// `Key::Dead` doesn't say which dead key it is, so it has no keysym.
+pub fn keysym(key: &Key, location: Location) -> Option<u32> {
+    if let Key::Character(s) = key {
+        let mut chars = s.chars();
+        let c = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+        return match location {
+            Location::Numpad => keysyms::keypad_keysym(c).or_else(|| keysyms::keysym_from_char(c)),
+            _ => keysyms::keysym_from_char(c),
+        };
+    }
+    if *key == Key::Enter && location == Location::Numpad {
+        return Some(keysyms::KEY_KP_Enter);
+    }
+    if let Some(&(_, left, right)) = SIDED_KEYS.iter().find(|(named, ..)| named == key) {
+        return Some(if location == Location::Right {
+            right
+        } else {
+            left
+        });
+    }
+    NAMED_KEYS
+        .iter()
+        .find(|(named, _)| named == key)
+        .map(|&(_, sym)| sym)
+}
+
+/// The key of a keysym, `Key::Unidentified` if there's none
+///
+/// The keys of the keypad are characters, like the browsers report them.
+pub fn key(keysym: u32) -> Key {
+    if keysym == keysyms::KEY_KP_Enter {
+        return Key::Enter;
+    }
+    if let Some((named, ..)) = SIDED_KEYS
+        .iter()
+        .find(|&&(_, left, right)| keysym == left || keysym == right)
+    {
+        return named.clone();
+    }
+    if let Some((named, _)) = NAMED_KEYS.iter().find(|&&(_, sym)| sym == keysym) {
+        return named.clone();
+    }
+    if (keysyms::KEY_dead_grave..=keysyms::KEY_dead_greek).contains(&keysym) {
+        return Key::Dead;
+    }
+    let mut keypad = "0123456789.,+-*/=".chars();
+    if let Some(c) = keypad.find(|&c| keysyms::keypad_keysym(c) == Some(keysym)) {
+        return Key::Character(c.to_string());
+    }
+    match keysyms::keysym_to_char(keysym) {
+        Some(c) if !c.is_control() => Key::Character(c.to_string()),
+        _ => Key::Unidentified,
+    }
+}
+
+/// The location of the key of a keysym
+pub fn location(keysym: u32) -> Location {
+    if (keysyms::KEY_KP_Space..=keysyms::KEY_KP_Equal).contains(&keysym) {
+        Location::Numpad
+    } else if SIDED_KEYS.iter().any(|&(_, left, _)| left == keysym) {
+        Location::Left
+    } else if SIDED_KEYS.iter().any(|&(_, _, right)| right == keysym) {
+        Location::Right
+    } else {
+        Location::Standard
+    }
+}
+
+/// The physical keys and their evdev codes, the keycodes of IBus
+const CODES: &[(Code, u32)] = &[
+    (Code::Escape, 1),
+    (Code::Digit1, 2),
+    (Code::Digit2, 3),
+    (Code::Digit3, 4),
+    (Code::Digit4, 5),
+    (Code::Digit5, 6),
+    (Code::Digit6, 7),
+    (Code::Digit7, 8),
+    (Code::Digit8, 9),
+    (Code::Digit9, 10),
+    (Code::Digit0, 11),
+    (Code::Minus, 12),
+    (Code::Equal, 13),
+    (Code::Backspace, 14),
+    (Code::Tab, 15),
+    (Code::KeyQ, 16),
+    (Code::KeyW, 17),
+    (Code::KeyE, 18),
+    (Code::KeyR, 19),
+    (Code::KeyT, 20),
+    (Code::KeyY, 21),
+    (Code::KeyU, 22),
+    (Code::KeyI, 23),
+    (Code::KeyO, 24),
+    (Code::KeyP, 25),
+    (Code::BracketLeft, 26),
+    (Code::BracketRight, 27),
+    (Code::Enter, 28),
+    (Code::ControlLeft, 29),
+    (Code::KeyA, 30),
+    (Code::KeyS, 31),
+    (Code::KeyD, 32),
+    (Code::KeyF, 33),
+    (Code::KeyG, 34),
+    (Code::KeyH, 35),
+    (Code::KeyJ, 36),
+    (Code::KeyK, 37),
+    (Code::KeyL, 38),
+    (Code::Semicolon, 39),
+    (Code::Quote, 40),
+    (Code::Backquote, 41),
+    (Code::ShiftLeft, 42),
+    (Code::Backslash, 43),
+    (Code::KeyZ, 44),
+    (Code::KeyX, 45),
+    (Code::KeyC, 46),
+    (Code::KeyV, 47),
+    (Code::KeyB, 48),
+    (Code::KeyN, 49),
+    (Code::KeyM, 50),
+    (Code::Comma, 51),
+    (Code::Period, 52),
+    (Code::Slash, 53),
+    (Code::ShiftRight, 54),
+    (Code::NumpadMultiply, 55),
+    (Code::AltLeft, 56),
+    (Code::Space, 57),
+    (Code::CapsLock, 58),
+    (Code::F1, 59),
+    (Code::F2, 60),
+    (Code::F3, 61),
+    (Code::F4, 62),
+    (Code::F5, 63),
+    (Code::F6, 64),
+    (Code::F7, 65),
+    (Code::F8, 66),
+    (Code::F9, 67),
+    (Code::F10, 68),
+    (Code::NumLock, 69),
+    (Code::ScrollLock, 70),
+    (Code::Numpad7, 71),
+    (Code::Numpad8, 72),
+    (Code::Numpad9, 73),
+    (Code::NumpadSubtract, 74),
+    (Code::Numpad4, 75),
+    (Code::Numpad5, 76),
+    (Code::Numpad6, 77),
+    (Code::NumpadAdd, 78),
+    (Code::Numpad1, 79),
+    (Code::Numpad2, 80),
+    (Code::Numpad3, 81),
+    (Code::Numpad0, 82),
+    (Code::NumpadDecimal, 83),
+    (Code::Lang5, 85),
+    (Code::IntlBackslash, 86),
+    (Code::F11, 87),
+    (Code::F12, 88),
+    (Code::IntlRo, 89),
+    (Code::Lang3, 90),
+    (Code::Lang4, 91),
+    (Code::Convert, 92),
+    (Code::KanaMode, 93),
+    (Code::NonConvert, 94),
+    (Code::NumpadEnter, 96),
+    (Code::ControlRight, 97),
+    (Code::NumpadDivide, 98),
+    (Code::PrintScreen, 99),
+    (Code::AltRight, 100),
+    (Code::Home, 102),
+    (Code::ArrowUp, 103),
+    (Code::PageUp, 104),
+    (Code::ArrowLeft, 105),
+    (Code::ArrowRight, 106),
+    (Code::End, 107),
+    (Code::ArrowDown, 108),
+    (Code::PageDown, 109),
+    (Code::Insert, 110),
+    (Code::Delete, 111),
+    (Code::NumpadEqual, 117),
+    (Code::Pause, 119),
+    (Code::NumpadComma, 121),
+    (Code::Lang1, 122),
+    (Code::Lang2, 123),
+    (Code::IntlYen, 124),
+    (Code::MetaLeft, 125),
+    (Code::MetaRight, 126),
+    (Code::ContextMenu, 127),
+    (Code::Help, 138),
+];
+
+/// The evdev code of a physical key, the keycode that IBus expects, 0 for
+/// the keys that aren't on a PC keyboard
+pub fn evdev_code(code: Code) -> u32 {
+    CODES
+        .iter()
+        .find(|&&(known, _)| known == code)
+        .map_or(0, |&(_, evdev)| evdev)
+}
+
+/// The physical key of an evdev code, `Code::Unidentified` if it's unknown
+pub fn code(evdev_code: u32) -> Code {
+    CODES
+        .iter()
+        .find(|&&(_, evdev)| evdev == evdev_code)
+        .map_or(Code::Unidentified, |&(code, _)| code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        let a = Key::Character("a".into());
+        assert_eq!(keysym(&a, Location::Standard), Some(keysyms::KEY_a));
+        let seven = Key::Character("7".into());
+        assert_eq!(keysym(&seven, Location::Numpad), Some(keysyms::KEY_KP_7));
+        assert_eq!(
+            keysym(&Key::Shift, Location::Right),
+            Some(keysyms::KEY_Shift_R)
+        );
+        assert_eq!(keysym(&Key::Dead, Location::Standard), None);
+
+        for sym in [keysyms::KEY_a, keysyms::KEY_Return, keysyms::KEY_Shift_R] {
+            assert_eq!(keysym(&key(sym), location(sym)), Some(sym));
+        }
+        assert_eq!(key(keysyms::KEY_dead_acute), Key::Dead);
+        assert_eq!(key(keysyms::KEY_KP_7), seven);
+        assert_eq!(location(keysyms::KEY_KP_7), Location::Numpad);
+    }
+
+    #[test]
+    fn codes_and_modifiers() {
+        for &(known, evdev) in CODES {
+            assert_eq!(code(evdev), known);
+            assert_eq!(evdev_code(known), evdev);
+        }
+        assert_eq!(evdev_code(Code::Unidentified), 0);
+
+        let state = modifiers(KeyModifiers::SHIFT | KeyModifiers::SUPER);
+        assert_eq!(state, Modifiers::SHIFT | Modifiers::SUPER | Modifiers::MOD4);
+        assert_eq!(
+            key_modifiers(state),
+            KeyModifiers::SHIFT | KeyModifiers::SUPER
+        );
+    }
+}
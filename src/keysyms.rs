@@ -0,0 +1,145 @@
+//! Keysym constants and name lookup
+//!
+//! The naming follows the IBus C API, so `IBUS_KEY_Return` is
+//! `keysyms::KEY_Return` here. The names are the same names that X11 uses,
+//! without the `XK_` prefix.
+//!
+
+#![allow(non_upper_case_globals)]
+
+#[rustfmt::skip]
+mod table;
+
+pub use table::*;
+
+/// Returns the name of a keysym, for example `"Return"` for `65293`
+///
+/// When several names exist for the same value, the canonical one is returned.
+pub fn keysym_name(keysym: u32) -> Option<&'static str> {
+    table::BY_VALUE
+        .binary_search_by_key(&keysym, |&(value, _)| value)
+        .ok()
+        .map(|i| table::BY_VALUE[i].1)
+}
+
+/// Returns the keysym with the given name, for example `65293` for `"Return"`
+///
+/// Besides the names in the table, this also accepts Unicode keysym names of
+/// the form `U20AC` (the same way `XStringToKeysym` does) and hexadecimal
+/// values like `0xff0d`.
+pub fn keysym_from_name(name: &str) -> Option<u32> {
+    if let Ok(i) = table::BY_NAME.binary_search_by_key(&name, |&(name, _)| name) {
+        return Some(table::BY_NAME[i].1);
+    }
+    if let Some(hex) = name.strip_prefix("0x") {
+        return u32::from_str_radix(hex, 16).ok();
+    }
+    if let Some(hex) = name.strip_prefix('U') {
+        let codepoint = u32::from_str_radix(hex, 16).ok()?;
+        return match codepoint {
+            0..=0x1f | 0x7f..=0x9f => None,
+            0x20..=0xff => Some(codepoint),
+            0x100..=0x10ffff => Some(codepoint | 0x0100_0000),
+            _ => None,
+        };
+    }
+    None
+}
+
+/// Returns the character that a keysym produces, if there's one
+///
+/// For example `KEY_eacute` gives `'é'`, but `KEY_Return` gives `None`
+/// because it's a function key, not a character.
+pub fn keysym_to_char(keysym: u32) -> Option<char> {
+    if keysym & 0xff00_0000 == 0x0100_0000 {
+        return char::from_u32(keysym & 0x00ff_ffff);
+    }
+    table::TO_UNICODE
+        .binary_search_by_key(&keysym, |&(value, _)| value)
+        .ok()
+        .map(|i| table::TO_UNICODE[i].1)
+}
+
+/// Returns the keysym that produces a character, the reverse of
+/// `keysym_to_char`
+///
+/// Latin-1 characters are their own keysyms, the others use the legacy
+/// keysym if there's one, like `KEY_Cyrillic_a` for `'а'`, and the Unicode
+/// keysym otherwise. Control characters have no keysym.
+pub fn keysym_from_char(c: char) -> Option<u32> {
+    let codepoint = c as u32;
+    match codepoint {
+        0..=0x1f | 0x7f..=0x9f => None,
+        0x20..=0xff => Some(codepoint),
+        _ => Some(
+            table::TO_UNICODE
+                .iter()
+                .find(|&&(_, to)| to == c)
+                .map_or(codepoint | 0x0100_0000, |&(keysym, _)| keysym),
+        ),
+    }
+}
+
+/// The keysym of a character on the keypad, like `KEY_KP_7` for `'7'`
+#[cfg_attr(
+    not(any(feature = "winit", feature = "keyboard-types")),
+    allow(dead_code)
+)]
+pub(crate) fn keypad_keysym(c: char) -> Option<u32> {
+    Some(match c {
+        '0'..='9' => KEY_KP_0 + (c as u32 - '0' as u32),
+        '.' => KEY_KP_Decimal,
+        ',' => KEY_KP_Separator,
+        '+' => KEY_KP_Add,
+        '-' => KEY_KP_Subtract,
+        '*' => KEY_KP_Multiply,
+        '/' => KEY_KP_Divide,
+        '=' => KEY_KP_Equal,
+        _ => return None,
+    })
+}
+
+/// Whether the keysym is a modifier key, like Shift or AltGr. These don't
+/// take part in compose or dead key sequences.
+pub(crate) fn is_modifier_key(keysym: u32) -> bool {
+    matches!(keysym, KEY_Shift_L..=KEY_Hyper_R)
+        || matches!(keysym, KEY_ISO_Lock..=KEY_ISO_Level5_Lock | KEY_Mode_switch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_lookup() {
+        assert_eq!(keysym_name(65293), Some("Return"));
+        assert_eq!(keysym_from_name("Return"), Some(65293));
+        assert_eq!(keysym_from_name("a"), Some(KEY_a));
+        assert_eq!(keysym_from_name("A"), Some(KEY_A));
+        assert_eq!(keysym_from_name("U00E9"), Some(KEY_eacute));
+        assert_eq!(keysym_from_name("U20AC"), Some(0x10020ac));
+        assert_eq!(keysym_from_name("Nonexistent"), None);
+        for &(name, value) in table::BY_NAME {
+            assert_eq!(keysym_from_name(name), Some(value));
+            assert!(keysym_name(value).is_some());
+        }
+    }
+
+    #[test]
+    fn to_char() {
+        assert_eq!(keysym_to_char(KEY_a), Some('a'));
+        assert_eq!(keysym_to_char(KEY_eacute), Some('é'));
+        assert_eq!(keysym_to_char(KEY_Cyrillic_ya), Some('я'));
+        assert_eq!(keysym_to_char(0x10020ac), Some('€'));
+        assert_eq!(keysym_to_char(KEY_Return), None);
+    }
+
+    #[test]
+    fn from_char() {
+        assert_eq!(keysym_from_char('a'), Some(KEY_a));
+        assert_eq!(keysym_from_char('é'), Some(KEY_eacute));
+        assert_eq!(keysym_from_char('я'), Some(KEY_Cyrillic_ya));
+        assert_eq!(keysym_from_char('😀'), Some(0x101f600));
+        assert_eq!(keysym_from_char('\n'), None);
+    }
+}
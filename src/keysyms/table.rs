@@ -0,0 +1,7145 @@
+// This file is generated by `tools/gen_keysyms.py`. Do not edit.
+
+pub const KEY_VoidSymbol: u32 = 0xffffff;
+pub const KEY_BackSpace: u32 = 0xff08;
+pub const KEY_Tab: u32 = 0xff09;
+pub const KEY_Linefeed: u32 = 0xff0a;
+pub const KEY_Clear: u32 = 0xff0b;
+pub const KEY_Return: u32 = 0xff0d;
+pub const KEY_Pause: u32 = 0xff13;
+pub const KEY_Scroll_Lock: u32 = 0xff14;
+pub const KEY_Sys_Req: u32 = 0xff15;
+pub const KEY_Escape: u32 = 0xff1b;
+pub const KEY_Delete: u32 = 0xffff;
+pub const KEY_Multi_key: u32 = 0xff20;
+pub const KEY_Codeinput: u32 = 0xff37;
+pub const KEY_SingleCandidate: u32 = 0xff3c;
+pub const KEY_MultipleCandidate: u32 = 0xff3d;
+pub const KEY_PreviousCandidate: u32 = 0xff3e;
+pub const KEY_Kanji: u32 = 0xff21;
+pub const KEY_Muhenkan: u32 = 0xff22;
+pub const KEY_Henkan_Mode: u32 = 0xff23;
+pub const KEY_Henkan: u32 = 0xff23;
+pub const KEY_Romaji: u32 = 0xff24;
+pub const KEY_Hiragana: u32 = 0xff25;
+pub const KEY_Katakana: u32 = 0xff26;
+pub const KEY_Hiragana_Katakana: u32 = 0xff27;
+pub const KEY_Zenkaku: u32 = 0xff28;
+pub const KEY_Hankaku: u32 = 0xff29;
+pub const KEY_Zenkaku_Hankaku: u32 = 0xff2a;
+pub const KEY_Touroku: u32 = 0xff2b;
+pub const KEY_Massyo: u32 = 0xff2c;
+pub const KEY_Kana_Lock: u32 = 0xff2d;
+pub const KEY_Kana_Shift: u32 = 0xff2e;
+pub const KEY_Eisu_Shift: u32 = 0xff2f;
+pub const KEY_Eisu_toggle: u32 = 0xff30;
+pub const KEY_Kanji_Bangou: u32 = 0xff37;
+pub const KEY_Zen_Koho: u32 = 0xff3d;
+pub const KEY_Mae_Koho: u32 = 0xff3e;
+pub const KEY_Home: u32 = 0xff50;
+pub const KEY_Left: u32 = 0xff51;
+pub const KEY_Up: u32 = 0xff52;
+pub const KEY_Right: u32 = 0xff53;
+pub const KEY_Down: u32 = 0xff54;
+pub const KEY_Prior: u32 = 0xff55;
+pub const KEY_Page_Up: u32 = 0xff55;
+pub const KEY_Next: u32 = 0xff56;
+pub const KEY_Page_Down: u32 = 0xff56;
+pub const KEY_End: u32 = 0xff57;
+pub const KEY_Begin: u32 = 0xff58;
+pub const KEY_Select: u32 = 0xff60;
+pub const KEY_Print: u32 = 0xff61;
+pub const KEY_Execute: u32 = 0xff62;
+pub const KEY_Insert: u32 = 0xff63;
+pub const KEY_Undo: u32 = 0xff65;
+pub const KEY_Redo: u32 = 0xff66;
+pub const KEY_Menu: u32 = 0xff67;
+pub const KEY_Find: u32 = 0xff68;
+pub const KEY_Cancel: u32 = 0xff69;
+pub const KEY_Help: u32 = 0xff6a;
+pub const KEY_Break: u32 = 0xff6b;
+pub const KEY_Mode_switch: u32 = 0xff7e;
+pub const KEY_script_switch: u32 = 0xff7e;
+pub const KEY_Num_Lock: u32 = 0xff7f;
+pub const KEY_KP_Space: u32 = 0xff80;
+pub const KEY_KP_Tab: u32 = 0xff89;
+pub const KEY_KP_Enter: u32 = 0xff8d;
+pub const KEY_KP_F1: u32 = 0xff91;
+pub const KEY_KP_F2: u32 = 0xff92;
+pub const KEY_KP_F3: u32 = 0xff93;
+pub const KEY_KP_F4: u32 = 0xff94;
+pub const KEY_KP_Home: u32 = 0xff95;
+pub const KEY_KP_Left: u32 = 0xff96;
+pub const KEY_KP_Up: u32 = 0xff97;
+pub const KEY_KP_Right: u32 = 0xff98;
+pub const KEY_KP_Down: u32 = 0xff99;
+pub const KEY_KP_Prior: u32 = 0xff9a;
+pub const KEY_KP_Page_Up: u32 = 0xff9a;
+pub const KEY_KP_Next: u32 = 0xff9b;
+pub const KEY_KP_Page_Down: u32 = 0xff9b;
+pub const KEY_KP_End: u32 = 0xff9c;
+pub const KEY_KP_Begin: u32 = 0xff9d;
+pub const KEY_KP_Insert: u32 = 0xff9e;
+pub const KEY_KP_Delete: u32 = 0xff9f;
+pub const KEY_KP_Equal: u32 = 0xffbd;
+pub const KEY_KP_Multiply: u32 = 0xffaa;
+pub const KEY_KP_Add: u32 = 0xffab;
+pub const KEY_KP_Separator: u32 = 0xffac;
+pub const KEY_KP_Subtract: u32 = 0xffad;
+pub const KEY_KP_Decimal: u32 = 0xffae;
+pub const KEY_KP_Divide: u32 = 0xffaf;
+pub const KEY_KP_0: u32 = 0xffb0;
+pub const KEY_KP_1: u32 = 0xffb1;
+pub const KEY_KP_2: u32 = 0xffb2;
+pub const KEY_KP_3: u32 = 0xffb3;
+pub const KEY_KP_4: u32 = 0xffb4;
+pub const KEY_KP_5: u32 = 0xffb5;
+pub const KEY_KP_6: u32 = 0xffb6;
+pub const KEY_KP_7: u32 = 0xffb7;
+pub const KEY_KP_8: u32 = 0xffb8;
+pub const KEY_KP_9: u32 = 0xffb9;
+pub const KEY_F1: u32 = 0xffbe;
+pub const KEY_F2: u32 = 0xffbf;
+pub const KEY_F3: u32 = 0xffc0;
+pub const KEY_F4: u32 = 0xffc1;
+pub const KEY_F5: u32 = 0xffc2;
+pub const KEY_F6: u32 = 0xffc3;
+pub const KEY_F7: u32 = 0xffc4;
+pub const KEY_F8: u32 = 0xffc5;
+pub const KEY_F9: u32 = 0xffc6;
+pub const KEY_F10: u32 = 0xffc7;
+pub const KEY_F11: u32 = 0xffc8;
+pub const KEY_L1: u32 = 0xffc8;
+pub const KEY_F12: u32 = 0xffc9;
+pub const KEY_L2: u32 = 0xffc9;
+pub const KEY_F13: u32 = 0xffca;
+pub const KEY_L3: u32 = 0xffca;
+pub const KEY_F14: u32 = 0xffcb;
+pub const KEY_L4: u32 = 0xffcb;
+pub const KEY_F15: u32 = 0xffcc;
+pub const KEY_L5: u32 = 0xffcc;
+pub const KEY_F16: u32 = 0xffcd;
+pub const KEY_L6: u32 = 0xffcd;
+pub const KEY_F17: u32 = 0xffce;
+pub const KEY_L7: u32 = 0xffce;
+pub const KEY_F18: u32 = 0xffcf;
+pub const KEY_L8: u32 = 0xffcf;
+pub const KEY_F19: u32 = 0xffd0;
+pub const KEY_L9: u32 = 0xffd0;
+pub const KEY_F20: u32 = 0xffd1;
+pub const KEY_L10: u32 = 0xffd1;
+pub const KEY_F21: u32 = 0xffd2;
+pub const KEY_R1: u32 = 0xffd2;
+pub const KEY_F22: u32 = 0xffd3;
+pub const KEY_R2: u32 = 0xffd3;
+pub const KEY_F23: u32 = 0xffd4;
+pub const KEY_R3: u32 = 0xffd4;
+pub const KEY_F24: u32 = 0xffd5;
+pub const KEY_R4: u32 = 0xffd5;
+pub const KEY_F25: u32 = 0xffd6;
+pub const KEY_R5: u32 = 0xffd6;
+pub const KEY_F26: u32 = 0xffd7;
+pub const KEY_R6: u32 = 0xffd7;
+pub const KEY_F27: u32 = 0xffd8;
+pub const KEY_R7: u32 = 0xffd8;
+pub const KEY_F28: u32 = 0xffd9;
+pub const KEY_R8: u32 = 0xffd9;
+pub const KEY_F29: u32 = 0xffda;
+pub const KEY_R9: u32 = 0xffda;
+pub const KEY_F30: u32 = 0xffdb;
+pub const KEY_R10: u32 = 0xffdb;
+pub const KEY_F31: u32 = 0xffdc;
+pub const KEY_R11: u32 = 0xffdc;
+pub const KEY_F32: u32 = 0xffdd;
+pub const KEY_R12: u32 = 0xffdd;
+pub const KEY_F33: u32 = 0xffde;
+pub const KEY_R13: u32 = 0xffde;
+pub const KEY_F34: u32 = 0xffdf;
+pub const KEY_R14: u32 = 0xffdf;
+pub const KEY_F35: u32 = 0xffe0;
+pub const KEY_R15: u32 = 0xffe0;
+pub const KEY_Shift_L: u32 = 0xffe1;
+pub const KEY_Shift_R: u32 = 0xffe2;
+pub const KEY_Control_L: u32 = 0xffe3;
+pub const KEY_Control_R: u32 = 0xffe4;
+pub const KEY_Caps_Lock: u32 = 0xffe5;
+pub const KEY_Shift_Lock: u32 = 0xffe6;
+pub const KEY_Meta_L: u32 = 0xffe7;
+pub const KEY_Meta_R: u32 = 0xffe8;
+pub const KEY_Alt_L: u32 = 0xffe9;
+pub const KEY_Alt_R: u32 = 0xffea;
+pub const KEY_Super_L: u32 = 0xffeb;
+pub const KEY_Super_R: u32 = 0xffec;
+pub const KEY_Hyper_L: u32 = 0xffed;
+pub const KEY_Hyper_R: u32 = 0xffee;
+pub const KEY_ISO_Lock: u32 = 0xfe01;
+pub const KEY_ISO_Level2_Latch: u32 = 0xfe02;
+pub const KEY_ISO_Level3_Shift: u32 = 0xfe03;
+pub const KEY_ISO_Level3_Latch: u32 = 0xfe04;
+pub const KEY_ISO_Level3_Lock: u32 = 0xfe05;
+pub const KEY_ISO_Level5_Shift: u32 = 0xfe11;
+pub const KEY_ISO_Level5_Latch: u32 = 0xfe12;
+pub const KEY_ISO_Level5_Lock: u32 = 0xfe13;
+pub const KEY_ISO_Group_Shift: u32 = 0xff7e;
+pub const KEY_ISO_Group_Latch: u32 = 0xfe06;
+pub const KEY_ISO_Group_Lock: u32 = 0xfe07;
+pub const KEY_ISO_Next_Group: u32 = 0xfe08;
+pub const KEY_ISO_Next_Group_Lock: u32 = 0xfe09;
+pub const KEY_ISO_Prev_Group: u32 = 0xfe0a;
+pub const KEY_ISO_Prev_Group_Lock: u32 = 0xfe0b;
+pub const KEY_ISO_First_Group: u32 = 0xfe0c;
+pub const KEY_ISO_First_Group_Lock: u32 = 0xfe0d;
+pub const KEY_ISO_Last_Group: u32 = 0xfe0e;
+pub const KEY_ISO_Last_Group_Lock: u32 = 0xfe0f;
+pub const KEY_ISO_Left_Tab: u32 = 0xfe20;
+pub const KEY_ISO_Move_Line_Up: u32 = 0xfe21;
+pub const KEY_ISO_Move_Line_Down: u32 = 0xfe22;
+pub const KEY_ISO_Partial_Line_Up: u32 = 0xfe23;
+pub const KEY_ISO_Partial_Line_Down: u32 = 0xfe24;
+pub const KEY_ISO_Partial_Space_Left: u32 = 0xfe25;
+pub const KEY_ISO_Partial_Space_Right: u32 = 0xfe26;
+pub const KEY_ISO_Set_Margin_Left: u32 = 0xfe27;
+pub const KEY_ISO_Set_Margin_Right: u32 = 0xfe28;
+pub const KEY_ISO_Release_Margin_Left: u32 = 0xfe29;
+pub const KEY_ISO_Release_Margin_Right: u32 = 0xfe2a;
+pub const KEY_ISO_Release_Both_Margins: u32 = 0xfe2b;
+pub const KEY_ISO_Fast_Cursor_Left: u32 = 0xfe2c;
+pub const KEY_ISO_Fast_Cursor_Right: u32 = 0xfe2d;
+pub const KEY_ISO_Fast_Cursor_Up: u32 = 0xfe2e;
+pub const KEY_ISO_Fast_Cursor_Down: u32 = 0xfe2f;
+pub const KEY_ISO_Continuous_Underline: u32 = 0xfe30;
+pub const KEY_ISO_Discontinuous_Underline: u32 = 0xfe31;
+pub const KEY_ISO_Emphasize: u32 = 0xfe32;
+pub const KEY_ISO_Center_Object: u32 = 0xfe33;
+pub const KEY_ISO_Enter: u32 = 0xfe34;
+pub const KEY_dead_grave: u32 = 0xfe50;
+pub const KEY_dead_acute: u32 = 0xfe51;
+pub const KEY_dead_circumflex: u32 = 0xfe52;
+pub const KEY_dead_tilde: u32 = 0xfe53;
+pub const KEY_dead_perispomeni: u32 = 0xfe53;
+pub const KEY_dead_macron: u32 = 0xfe54;
+pub const KEY_dead_breve: u32 = 0xfe55;
+pub const KEY_dead_abovedot: u32 = 0xfe56;
+pub const KEY_dead_diaeresis: u32 = 0xfe57;
+pub const KEY_dead_abovering: u32 = 0xfe58;
+pub const KEY_dead_doubleacute: u32 = 0xfe59;
+pub const KEY_dead_caron: u32 = 0xfe5a;
+pub const KEY_dead_cedilla: u32 = 0xfe5b;
+pub const KEY_dead_ogonek: u32 = 0xfe5c;
+pub const KEY_dead_iota: u32 = 0xfe5d;
+pub const KEY_dead_voiced_sound: u32 = 0xfe5e;
+pub const KEY_dead_semivoiced_sound: u32 = 0xfe5f;
+pub const KEY_dead_belowdot: u32 = 0xfe60;
+pub const KEY_dead_hook: u32 = 0xfe61;
+pub const KEY_dead_horn: u32 = 0xfe62;
+pub const KEY_dead_stroke: u32 = 0xfe63;
+pub const KEY_dead_abovecomma: u32 = 0xfe64;
+pub const KEY_dead_psili: u32 = 0xfe64;
+pub const KEY_dead_abovereversedcomma: u32 = 0xfe65;
+pub const KEY_dead_dasia: u32 = 0xfe65;
+pub const KEY_dead_doublegrave: u32 = 0xfe66;
+pub const KEY_dead_belowring: u32 = 0xfe67;
+pub const KEY_dead_belowmacron: u32 = 0xfe68;
+pub const KEY_dead_belowcircumflex: u32 = 0xfe69;
+pub const KEY_dead_belowtilde: u32 = 0xfe6a;
+pub const KEY_dead_belowbreve: u32 = 0xfe6b;
+pub const KEY_dead_belowdiaeresis: u32 = 0xfe6c;
+pub const KEY_dead_invertedbreve: u32 = 0xfe6d;
+pub const KEY_dead_belowcomma: u32 = 0xfe6e;
+pub const KEY_dead_currency: u32 = 0xfe6f;
+pub const KEY_dead_lowline: u32 = 0xfe90;
+pub const KEY_dead_aboveverticalline: u32 = 0xfe91;
+pub const KEY_dead_belowverticalline: u32 = 0xfe92;
+pub const KEY_dead_longsolidusoverlay: u32 = 0xfe93;
+pub const KEY_dead_a: u32 = 0xfe80;
+pub const KEY_dead_A: u32 = 0xfe81;
+pub const KEY_dead_e: u32 = 0xfe82;
+pub const KEY_dead_E: u32 = 0xfe83;
+pub const KEY_dead_i: u32 = 0xfe84;
+pub const KEY_dead_I: u32 = 0xfe85;
+pub const KEY_dead_o: u32 = 0xfe86;
+pub const KEY_dead_O: u32 = 0xfe87;
+pub const KEY_dead_u: u32 = 0xfe88;
+pub const KEY_dead_U: u32 = 0xfe89;
+pub const KEY_dead_small_schwa: u32 = 0xfe8a;
+pub const KEY_dead_capital_schwa: u32 = 0xfe8b;
+pub const KEY_dead_greek: u32 = 0xfe8c;
+pub const KEY_First_Virtual_Screen: u32 = 0xfed0;
+pub const KEY_Prev_Virtual_Screen: u32 = 0xfed1;
+pub const KEY_Next_Virtual_Screen: u32 = 0xfed2;
+pub const KEY_Last_Virtual_Screen: u32 = 0xfed4;
+pub const KEY_Terminate_Server: u32 = 0xfed5;
+pub const KEY_AccessX_Enable: u32 = 0xfe70;
+pub const KEY_AccessX_Feedback_Enable: u32 = 0xfe71;
+pub const KEY_RepeatKeys_Enable: u32 = 0xfe72;
+pub const KEY_SlowKeys_Enable: u32 = 0xfe73;
+pub const KEY_BounceKeys_Enable: u32 = 0xfe74;
+pub const KEY_StickyKeys_Enable: u32 = 0xfe75;
+pub const KEY_MouseKeys_Enable: u32 = 0xfe76;
+pub const KEY_MouseKeys_Accel_Enable: u32 = 0xfe77;
+pub const KEY_Overlay1_Enable: u32 = 0xfe78;
+pub const KEY_Overlay2_Enable: u32 = 0xfe79;
+pub const KEY_AudibleBell_Enable: u32 = 0xfe7a;
+pub const KEY_Pointer_Left: u32 = 0xfee0;
+pub const KEY_Pointer_Right: u32 = 0xfee1;
+pub const KEY_Pointer_Up: u32 = 0xfee2;
+pub const KEY_Pointer_Down: u32 = 0xfee3;
+pub const KEY_Pointer_UpLeft: u32 = 0xfee4;
+pub const KEY_Pointer_UpRight: u32 = 0xfee5;
+pub const KEY_Pointer_DownLeft: u32 = 0xfee6;
+pub const KEY_Pointer_DownRight: u32 = 0xfee7;
+pub const KEY_Pointer_Button_Dflt: u32 = 0xfee8;
+pub const KEY_Pointer_Button1: u32 = 0xfee9;
+pub const KEY_Pointer_Button2: u32 = 0xfeea;
+pub const KEY_Pointer_Button3: u32 = 0xfeeb;
+pub const KEY_Pointer_Button4: u32 = 0xfeec;
+pub const KEY_Pointer_Button5: u32 = 0xfeed;
+pub const KEY_Pointer_DblClick_Dflt: u32 = 0xfeee;
+pub const KEY_Pointer_DblClick1: u32 = 0xfeef;
+pub const KEY_Pointer_DblClick2: u32 = 0xfef0;
+pub const KEY_Pointer_DblClick3: u32 = 0xfef1;
+pub const KEY_Pointer_DblClick4: u32 = 0xfef2;
+pub const KEY_Pointer_DblClick5: u32 = 0xfef3;
+pub const KEY_Pointer_Drag_Dflt: u32 = 0xfef4;
+pub const KEY_Pointer_Drag1: u32 = 0xfef5;
+pub const KEY_Pointer_Drag2: u32 = 0xfef6;
+pub const KEY_Pointer_Drag3: u32 = 0xfef7;
+pub const KEY_Pointer_Drag4: u32 = 0xfef8;
+pub const KEY_Pointer_Drag5: u32 = 0xfefd;
+pub const KEY_Pointer_EnableKeys: u32 = 0xfef9;
+pub const KEY_Pointer_Accelerate: u32 = 0xfefa;
+pub const KEY_Pointer_DfltBtnNext: u32 = 0xfefb;
+pub const KEY_Pointer_DfltBtnPrev: u32 = 0xfefc;
+pub const KEY_ch: u32 = 0xfea0;
+pub const KEY_Ch: u32 = 0xfea1;
+pub const KEY_CH: u32 = 0xfea2;
+pub const KEY_c_h: u32 = 0xfea3;
+pub const KEY_C_h: u32 = 0xfea4;
+pub const KEY_C_H: u32 = 0xfea5;
+pub const KEY_3270_Duplicate: u32 = 0xfd01;
+pub const KEY_3270_FieldMark: u32 = 0xfd02;
+pub const KEY_3270_Right2: u32 = 0xfd03;
+pub const KEY_3270_Left2: u32 = 0xfd04;
+pub const KEY_3270_BackTab: u32 = 0xfd05;
+pub const KEY_3270_EraseEOF: u32 = 0xfd06;
+pub const KEY_3270_EraseInput: u32 = 0xfd07;
+pub const KEY_3270_Reset: u32 = 0xfd08;
+pub const KEY_3270_Quit: u32 = 0xfd09;
+pub const KEY_3270_PA1: u32 = 0xfd0a;
+pub const KEY_3270_PA2: u32 = 0xfd0b;
+pub const KEY_3270_PA3: u32 = 0xfd0c;
+pub const KEY_3270_Test: u32 = 0xfd0d;
+pub const KEY_3270_Attn: u32 = 0xfd0e;
+pub const KEY_3270_CursorBlink: u32 = 0xfd0f;
+pub const KEY_3270_AltCursor: u32 = 0xfd10;
+pub const KEY_3270_KeyClick: u32 = 0xfd11;
+pub const KEY_3270_Jump: u32 = 0xfd12;
+pub const KEY_3270_Ident: u32 = 0xfd13;
+pub const KEY_3270_Rule: u32 = 0xfd14;
+pub const KEY_3270_Copy: u32 = 0xfd15;
+pub const KEY_3270_Play: u32 = 0xfd16;
+pub const KEY_3270_Setup: u32 = 0xfd17;
+pub const KEY_3270_Record: u32 = 0xfd18;
+pub const KEY_3270_ChangeScreen: u32 = 0xfd19;
+pub const KEY_3270_DeleteWord: u32 = 0xfd1a;
+pub const KEY_3270_ExSelect: u32 = 0xfd1b;
+pub const KEY_3270_CursorSelect: u32 = 0xfd1c;
+pub const KEY_3270_PrintScreen: u32 = 0xfd1d;
+pub const KEY_3270_Enter: u32 = 0xfd1e;
+pub const KEY_space: u32 = 0x20;
+pub const KEY_exclam: u32 = 0x21;
+pub const KEY_quotedbl: u32 = 0x22;
+pub const KEY_numbersign: u32 = 0x23;
+pub const KEY_dollar: u32 = 0x24;
+pub const KEY_percent: u32 = 0x25;
+pub const KEY_ampersand: u32 = 0x26;
+pub const KEY_apostrophe: u32 = 0x27;
+pub const KEY_quoteright: u32 = 0x27;
+pub const KEY_parenleft: u32 = 0x28;
+pub const KEY_parenright: u32 = 0x29;
+pub const KEY_asterisk: u32 = 0x2a;
+pub const KEY_plus: u32 = 0x2b;
+pub const KEY_comma: u32 = 0x2c;
+pub const KEY_minus: u32 = 0x2d;
+pub const KEY_period: u32 = 0x2e;
+pub const KEY_slash: u32 = 0x2f;
+pub const KEY_0: u32 = 0x30;
+pub const KEY_1: u32 = 0x31;
+pub const KEY_2: u32 = 0x32;
+pub const KEY_3: u32 = 0x33;
+pub const KEY_4: u32 = 0x34;
+pub const KEY_5: u32 = 0x35;
+pub const KEY_6: u32 = 0x36;
+pub const KEY_7: u32 = 0x37;
+pub const KEY_8: u32 = 0x38;
+pub const KEY_9: u32 = 0x39;
+pub const KEY_colon: u32 = 0x3a;
+pub const KEY_semicolon: u32 = 0x3b;
+pub const KEY_less: u32 = 0x3c;
+pub const KEY_equal: u32 = 0x3d;
+pub const KEY_greater: u32 = 0x3e;
+pub const KEY_question: u32 = 0x3f;
+pub const KEY_at: u32 = 0x40;
+pub const KEY_A: u32 = 0x41;
+pub const KEY_B: u32 = 0x42;
+pub const KEY_C: u32 = 0x43;
+pub const KEY_D: u32 = 0x44;
+pub const KEY_E: u32 = 0x45;
+pub const KEY_F: u32 = 0x46;
+pub const KEY_G: u32 = 0x47;
+pub const KEY_H: u32 = 0x48;
+pub const KEY_I: u32 = 0x49;
+pub const KEY_J: u32 = 0x4a;
+pub const KEY_K: u32 = 0x4b;
+pub const KEY_L: u32 = 0x4c;
+pub const KEY_M: u32 = 0x4d;
+pub const KEY_N: u32 = 0x4e;
+pub const KEY_O: u32 = 0x4f;
+pub const KEY_P: u32 = 0x50;
+pub const KEY_Q: u32 = 0x51;
+pub const KEY_R: u32 = 0x52;
+pub const KEY_S: u32 = 0x53;
+pub const KEY_T: u32 = 0x54;
+pub const KEY_U: u32 = 0x55;
+pub const KEY_V: u32 = 0x56;
+pub const KEY_W: u32 = 0x57;
+pub const KEY_X: u32 = 0x58;
+pub const KEY_Y: u32 = 0x59;
+pub const KEY_Z: u32 = 0x5a;
+pub const KEY_bracketleft: u32 = 0x5b;
+pub const KEY_backslash: u32 = 0x5c;
+pub const KEY_bracketright: u32 = 0x5d;
+pub const KEY_asciicircum: u32 = 0x5e;
+pub const KEY_underscore: u32 = 0x5f;
+pub const KEY_grave: u32 = 0x60;
+pub const KEY_quoteleft: u32 = 0x60;
+pub const KEY_a: u32 = 0x61;
+pub const KEY_b: u32 = 0x62;
+pub const KEY_c: u32 = 0x63;
+pub const KEY_d: u32 = 0x64;
+pub const KEY_e: u32 = 0x65;
+pub const KEY_f: u32 = 0x66;
+pub const KEY_g: u32 = 0x67;
+pub const KEY_h: u32 = 0x68;
+pub const KEY_i: u32 = 0x69;
+pub const KEY_j: u32 = 0x6a;
+pub const KEY_k: u32 = 0x6b;
+pub const KEY_l: u32 = 0x6c;
+pub const KEY_m: u32 = 0x6d;
+pub const KEY_n: u32 = 0x6e;
+pub const KEY_o: u32 = 0x6f;
+pub const KEY_p: u32 = 0x70;
+pub const KEY_q: u32 = 0x71;
+pub const KEY_r: u32 = 0x72;
+pub const KEY_s: u32 = 0x73;
+pub const KEY_t: u32 = 0x74;
+pub const KEY_u: u32 = 0x75;
+pub const KEY_v: u32 = 0x76;
+pub const KEY_w: u32 = 0x77;
+pub const KEY_x: u32 = 0x78;
+pub const KEY_y: u32 = 0x79;
+pub const KEY_z: u32 = 0x7a;
+pub const KEY_braceleft: u32 = 0x7b;
+pub const KEY_bar: u32 = 0x7c;
+pub const KEY_braceright: u32 = 0x7d;
+pub const KEY_asciitilde: u32 = 0x7e;
+pub const KEY_nobreakspace: u32 = 0xa0;
+pub const KEY_exclamdown: u32 = 0xa1;
+pub const KEY_cent: u32 = 0xa2;
+pub const KEY_sterling: u32 = 0xa3;
+pub const KEY_currency: u32 = 0xa4;
+pub const KEY_yen: u32 = 0xa5;
+pub const KEY_brokenbar: u32 = 0xa6;
+pub const KEY_section: u32 = 0xa7;
+pub const KEY_diaeresis: u32 = 0xa8;
+pub const KEY_copyright: u32 = 0xa9;
+pub const KEY_ordfeminine: u32 = 0xaa;
+pub const KEY_guillemotleft: u32 = 0xab;
+pub const KEY_notsign: u32 = 0xac;
+pub const KEY_hyphen: u32 = 0xad;
+pub const KEY_registered: u32 = 0xae;
+pub const KEY_macron: u32 = 0xaf;
+pub const KEY_degree: u32 = 0xb0;
+pub const KEY_plusminus: u32 = 0xb1;
+pub const KEY_twosuperior: u32 = 0xb2;
+pub const KEY_threesuperior: u32 = 0xb3;
+pub const KEY_acute: u32 = 0xb4;
+pub const KEY_mu: u32 = 0xb5;
+pub const KEY_paragraph: u32 = 0xb6;
+pub const KEY_periodcentered: u32 = 0xb7;
+pub const KEY_cedilla: u32 = 0xb8;
+pub const KEY_onesuperior: u32 = 0xb9;
+pub const KEY_masculine: u32 = 0xba;
+pub const KEY_guillemotright: u32 = 0xbb;
+pub const KEY_onequarter: u32 = 0xbc;
+pub const KEY_onehalf: u32 = 0xbd;
+pub const KEY_threequarters: u32 = 0xbe;
+pub const KEY_questiondown: u32 = 0xbf;
+pub const KEY_Agrave: u32 = 0xc0;
+pub const KEY_Aacute: u32 = 0xc1;
+pub const KEY_Acircumflex: u32 = 0xc2;
+pub const KEY_Atilde: u32 = 0xc3;
+pub const KEY_Adiaeresis: u32 = 0xc4;
+pub const KEY_Aring: u32 = 0xc5;
+pub const KEY_AE: u32 = 0xc6;
+pub const KEY_Ccedilla: u32 = 0xc7;
+pub const KEY_Egrave: u32 = 0xc8;
+pub const KEY_Eacute: u32 = 0xc9;
+pub const KEY_Ecircumflex: u32 = 0xca;
+pub const KEY_Ediaeresis: u32 = 0xcb;
+pub const KEY_Igrave: u32 = 0xcc;
+pub const KEY_Iacute: u32 = 0xcd;
+pub const KEY_Icircumflex: u32 = 0xce;
+pub const KEY_Idiaeresis: u32 = 0xcf;
+pub const KEY_ETH: u32 = 0xd0;
+pub const KEY_Eth: u32 = 0xd0;
+pub const KEY_Ntilde: u32 = 0xd1;
+pub const KEY_Ograve: u32 = 0xd2;
+pub const KEY_Oacute: u32 = 0xd3;
+pub const KEY_Ocircumflex: u32 = 0xd4;
+pub const KEY_Otilde: u32 = 0xd5;
+pub const KEY_Odiaeresis: u32 = 0xd6;
+pub const KEY_multiply: u32 = 0xd7;
+pub const KEY_Oslash: u32 = 0xd8;
+pub const KEY_Ooblique: u32 = 0xd8;
+pub const KEY_Ugrave: u32 = 0xd9;
+pub const KEY_Uacute: u32 = 0xda;
+pub const KEY_Ucircumflex: u32 = 0xdb;
+pub const KEY_Udiaeresis: u32 = 0xdc;
+pub const KEY_Yacute: u32 = 0xdd;
+pub const KEY_THORN: u32 = 0xde;
+pub const KEY_Thorn: u32 = 0xde;
+pub const KEY_ssharp: u32 = 0xdf;
+pub const KEY_agrave: u32 = 0xe0;
+pub const KEY_aacute: u32 = 0xe1;
+pub const KEY_acircumflex: u32 = 0xe2;
+pub const KEY_atilde: u32 = 0xe3;
+pub const KEY_adiaeresis: u32 = 0xe4;
+pub const KEY_aring: u32 = 0xe5;
+pub const KEY_ae: u32 = 0xe6;
+pub const KEY_ccedilla: u32 = 0xe7;
+pub const KEY_egrave: u32 = 0xe8;
+pub const KEY_eacute: u32 = 0xe9;
+pub const KEY_ecircumflex: u32 = 0xea;
+pub const KEY_ediaeresis: u32 = 0xeb;
+pub const KEY_igrave: u32 = 0xec;
+pub const KEY_iacute: u32 = 0xed;
+pub const KEY_icircumflex: u32 = 0xee;
+pub const KEY_idiaeresis: u32 = 0xef;
+pub const KEY_eth: u32 = 0xf0;
+pub const KEY_ntilde: u32 = 0xf1;
+pub const KEY_ograve: u32 = 0xf2;
+pub const KEY_oacute: u32 = 0xf3;
+pub const KEY_ocircumflex: u32 = 0xf4;
+pub const KEY_otilde: u32 = 0xf5;
+pub const KEY_odiaeresis: u32 = 0xf6;
+pub const KEY_division: u32 = 0xf7;
+pub const KEY_oslash: u32 = 0xf8;
+pub const KEY_ooblique: u32 = 0xf8;
+pub const KEY_ugrave: u32 = 0xf9;
+pub const KEY_uacute: u32 = 0xfa;
+pub const KEY_ucircumflex: u32 = 0xfb;
+pub const KEY_udiaeresis: u32 = 0xfc;
+pub const KEY_yacute: u32 = 0xfd;
+pub const KEY_thorn: u32 = 0xfe;
+pub const KEY_ydiaeresis: u32 = 0xff;
+pub const KEY_Aogonek: u32 = 0x1a1;
+pub const KEY_breve: u32 = 0x1a2;
+pub const KEY_Lstroke: u32 = 0x1a3;
+pub const KEY_Lcaron: u32 = 0x1a5;
+pub const KEY_Sacute: u32 = 0x1a6;
+pub const KEY_Scaron: u32 = 0x1a9;
+pub const KEY_Scedilla: u32 = 0x1aa;
+pub const KEY_Tcaron: u32 = 0x1ab;
+pub const KEY_Zacute: u32 = 0x1ac;
+pub const KEY_Zcaron: u32 = 0x1ae;
+pub const KEY_Zabovedot: u32 = 0x1af;
+pub const KEY_aogonek: u32 = 0x1b1;
+pub const KEY_ogonek: u32 = 0x1b2;
+pub const KEY_lstroke: u32 = 0x1b3;
+pub const KEY_lcaron: u32 = 0x1b5;
+pub const KEY_sacute: u32 = 0x1b6;
+pub const KEY_caron: u32 = 0x1b7;
+pub const KEY_scaron: u32 = 0x1b9;
+pub const KEY_scedilla: u32 = 0x1ba;
+pub const KEY_tcaron: u32 = 0x1bb;
+pub const KEY_zacute: u32 = 0x1bc;
+pub const KEY_doubleacute: u32 = 0x1bd;
+pub const KEY_zcaron: u32 = 0x1be;
+pub const KEY_zabovedot: u32 = 0x1bf;
+pub const KEY_Racute: u32 = 0x1c0;
+pub const KEY_Abreve: u32 = 0x1c3;
+pub const KEY_Lacute: u32 = 0x1c5;
+pub const KEY_Cacute: u32 = 0x1c6;
+pub const KEY_Ccaron: u32 = 0x1c8;
+pub const KEY_Eogonek: u32 = 0x1ca;
+pub const KEY_Ecaron: u32 = 0x1cc;
+pub const KEY_Dcaron: u32 = 0x1cf;
+pub const KEY_Dstroke: u32 = 0x1d0;
+pub const KEY_Nacute: u32 = 0x1d1;
+pub const KEY_Ncaron: u32 = 0x1d2;
+pub const KEY_Odoubleacute: u32 = 0x1d5;
+pub const KEY_Rcaron: u32 = 0x1d8;
+pub const KEY_Uring: u32 = 0x1d9;
+pub const KEY_Udoubleacute: u32 = 0x1db;
+pub const KEY_Tcedilla: u32 = 0x1de;
+pub const KEY_racute: u32 = 0x1e0;
+pub const KEY_abreve: u32 = 0x1e3;
+pub const KEY_lacute: u32 = 0x1e5;
+pub const KEY_cacute: u32 = 0x1e6;
+pub const KEY_ccaron: u32 = 0x1e8;
+pub const KEY_eogonek: u32 = 0x1ea;
+pub const KEY_ecaron: u32 = 0x1ec;
+pub const KEY_dcaron: u32 = 0x1ef;
+pub const KEY_dstroke: u32 = 0x1f0;
+pub const KEY_nacute: u32 = 0x1f1;
+pub const KEY_ncaron: u32 = 0x1f2;
+pub const KEY_odoubleacute: u32 = 0x1f5;
+pub const KEY_rcaron: u32 = 0x1f8;
+pub const KEY_uring: u32 = 0x1f9;
+pub const KEY_udoubleacute: u32 = 0x1fb;
+pub const KEY_tcedilla: u32 = 0x1fe;
+pub const KEY_abovedot: u32 = 0x1ff;
+pub const KEY_Hstroke: u32 = 0x2a1;
+pub const KEY_Hcircumflex: u32 = 0x2a6;
+pub const KEY_Iabovedot: u32 = 0x2a9;
+pub const KEY_Gbreve: u32 = 0x2ab;
+pub const KEY_Jcircumflex: u32 = 0x2ac;
+pub const KEY_hstroke: u32 = 0x2b1;
+pub const KEY_hcircumflex: u32 = 0x2b6;
+pub const KEY_idotless: u32 = 0x2b9;
+pub const KEY_gbreve: u32 = 0x2bb;
+pub const KEY_jcircumflex: u32 = 0x2bc;
+pub const KEY_Cabovedot: u32 = 0x2c5;
+pub const KEY_Ccircumflex: u32 = 0x2c6;
+pub const KEY_Gabovedot: u32 = 0x2d5;
+pub const KEY_Gcircumflex: u32 = 0x2d8;
+pub const KEY_Ubreve: u32 = 0x2dd;
+pub const KEY_Scircumflex: u32 = 0x2de;
+pub const KEY_cabovedot: u32 = 0x2e5;
+pub const KEY_ccircumflex: u32 = 0x2e6;
+pub const KEY_gabovedot: u32 = 0x2f5;
+pub const KEY_gcircumflex: u32 = 0x2f8;
+pub const KEY_ubreve: u32 = 0x2fd;
+pub const KEY_scircumflex: u32 = 0x2fe;
+pub const KEY_kra: u32 = 0x3a2;
+pub const KEY_kappa: u32 = 0x3a2;
+pub const KEY_Rcedilla: u32 = 0x3a3;
+pub const KEY_Itilde: u32 = 0x3a5;
+pub const KEY_Lcedilla: u32 = 0x3a6;
+pub const KEY_Emacron: u32 = 0x3aa;
+pub const KEY_Gcedilla: u32 = 0x3ab;
+pub const KEY_Tslash: u32 = 0x3ac;
+pub const KEY_rcedilla: u32 = 0x3b3;
+pub const KEY_itilde: u32 = 0x3b5;
+pub const KEY_lcedilla: u32 = 0x3b6;
+pub const KEY_emacron: u32 = 0x3ba;
+pub const KEY_gcedilla: u32 = 0x3bb;
+pub const KEY_tslash: u32 = 0x3bc;
+pub const KEY_ENG: u32 = 0x3bd;
+pub const KEY_eng: u32 = 0x3bf;
+pub const KEY_Amacron: u32 = 0x3c0;
+pub const KEY_Iogonek: u32 = 0x3c7;
+pub const KEY_Eabovedot: u32 = 0x3cc;
+pub const KEY_Imacron: u32 = 0x3cf;
+pub const KEY_Ncedilla: u32 = 0x3d1;
+pub const KEY_Omacron: u32 = 0x3d2;
+pub const KEY_Kcedilla: u32 = 0x3d3;
+pub const KEY_Uogonek: u32 = 0x3d9;
+pub const KEY_Utilde: u32 = 0x3dd;
+pub const KEY_Umacron: u32 = 0x3de;
+pub const KEY_amacron: u32 = 0x3e0;
+pub const KEY_iogonek: u32 = 0x3e7;
+pub const KEY_eabovedot: u32 = 0x3ec;
+pub const KEY_imacron: u32 = 0x3ef;
+pub const KEY_ncedilla: u32 = 0x3f1;
+pub const KEY_omacron: u32 = 0x3f2;
+pub const KEY_kcedilla: u32 = 0x3f3;
+pub const KEY_uogonek: u32 = 0x3f9;
+pub const KEY_utilde: u32 = 0x3fd;
+pub const KEY_umacron: u32 = 0x3fe;
+pub const KEY_Wcircumflex: u32 = 0x1000174;
+pub const KEY_wcircumflex: u32 = 0x1000175;
+pub const KEY_Ycircumflex: u32 = 0x1000176;
+pub const KEY_ycircumflex: u32 = 0x1000177;
+pub const KEY_Babovedot: u32 = 0x1001e02;
+pub const KEY_babovedot: u32 = 0x1001e03;
+pub const KEY_Dabovedot: u32 = 0x1001e0a;
+pub const KEY_dabovedot: u32 = 0x1001e0b;
+pub const KEY_Fabovedot: u32 = 0x1001e1e;
+pub const KEY_fabovedot: u32 = 0x1001e1f;
+pub const KEY_Mabovedot: u32 = 0x1001e40;
+pub const KEY_mabovedot: u32 = 0x1001e41;
+pub const KEY_Pabovedot: u32 = 0x1001e56;
+pub const KEY_pabovedot: u32 = 0x1001e57;
+pub const KEY_Sabovedot: u32 = 0x1001e60;
+pub const KEY_sabovedot: u32 = 0x1001e61;
+pub const KEY_Tabovedot: u32 = 0x1001e6a;
+pub const KEY_tabovedot: u32 = 0x1001e6b;
+pub const KEY_Wgrave: u32 = 0x1001e80;
+pub const KEY_wgrave: u32 = 0x1001e81;
+pub const KEY_Wacute: u32 = 0x1001e82;
+pub const KEY_wacute: u32 = 0x1001e83;
+pub const KEY_Wdiaeresis: u32 = 0x1001e84;
+pub const KEY_wdiaeresis: u32 = 0x1001e85;
+pub const KEY_Ygrave: u32 = 0x1001ef2;
+pub const KEY_ygrave: u32 = 0x1001ef3;
+pub const KEY_OE: u32 = 0x13bc;
+pub const KEY_oe: u32 = 0x13bd;
+pub const KEY_Ydiaeresis: u32 = 0x13be;
+pub const KEY_overline: u32 = 0x47e;
+pub const KEY_kana_fullstop: u32 = 0x4a1;
+pub const KEY_kana_openingbracket: u32 = 0x4a2;
+pub const KEY_kana_closingbracket: u32 = 0x4a3;
+pub const KEY_kana_comma: u32 = 0x4a4;
+pub const KEY_kana_conjunctive: u32 = 0x4a5;
+pub const KEY_kana_middledot: u32 = 0x4a5;
+pub const KEY_kana_WO: u32 = 0x4a6;
+pub const KEY_kana_a: u32 = 0x4a7;
+pub const KEY_kana_i: u32 = 0x4a8;
+pub const KEY_kana_u: u32 = 0x4a9;
+pub const KEY_kana_e: u32 = 0x4aa;
+pub const KEY_kana_o: u32 = 0x4ab;
+pub const KEY_kana_ya: u32 = 0x4ac;
+pub const KEY_kana_yu: u32 = 0x4ad;
+pub const KEY_kana_yo: u32 = 0x4ae;
+pub const KEY_kana_tsu: u32 = 0x4af;
+pub const KEY_kana_tu: u32 = 0x4af;
+pub const KEY_prolongedsound: u32 = 0x4b0;
+pub const KEY_kana_A: u32 = 0x4b1;
+pub const KEY_kana_I: u32 = 0x4b2;
+pub const KEY_kana_U: u32 = 0x4b3;
+pub const KEY_kana_E: u32 = 0x4b4;
+pub const KEY_kana_O: u32 = 0x4b5;
+pub const KEY_kana_KA: u32 = 0x4b6;
+pub const KEY_kana_KI: u32 = 0x4b7;
+pub const KEY_kana_KU: u32 = 0x4b8;
+pub const KEY_kana_KE: u32 = 0x4b9;
+pub const KEY_kana_KO: u32 = 0x4ba;
+pub const KEY_kana_SA: u32 = 0x4bb;
+pub const KEY_kana_SHI: u32 = 0x4bc;
+pub const KEY_kana_SU: u32 = 0x4bd;
+pub const KEY_kana_SE: u32 = 0x4be;
+pub const KEY_kana_SO: u32 = 0x4bf;
+pub const KEY_kana_TA: u32 = 0x4c0;
+pub const KEY_kana_CHI: u32 = 0x4c1;
+pub const KEY_kana_TI: u32 = 0x4c1;
+pub const KEY_kana_TSU: u32 = 0x4c2;
+pub const KEY_kana_TU: u32 = 0x4c2;
+pub const KEY_kana_TE: u32 = 0x4c3;
+pub const KEY_kana_TO: u32 = 0x4c4;
+pub const KEY_kana_NA: u32 = 0x4c5;
+pub const KEY_kana_NI: u32 = 0x4c6;
+pub const KEY_kana_NU: u32 = 0x4c7;
+pub const KEY_kana_NE: u32 = 0x4c8;
+pub const KEY_kana_NO: u32 = 0x4c9;
+pub const KEY_kana_HA: u32 = 0x4ca;
+pub const KEY_kana_HI: u32 = 0x4cb;
+pub const KEY_kana_FU: u32 = 0x4cc;
+pub const KEY_kana_HU: u32 = 0x4cc;
+pub const KEY_kana_HE: u32 = 0x4cd;
+pub const KEY_kana_HO: u32 = 0x4ce;
+pub const KEY_kana_MA: u32 = 0x4cf;
+pub const KEY_kana_MI: u32 = 0x4d0;
+pub const KEY_kana_MU: u32 = 0x4d1;
+pub const KEY_kana_ME: u32 = 0x4d2;
+pub const KEY_kana_MO: u32 = 0x4d3;
+pub const KEY_kana_YA: u32 = 0x4d4;
+pub const KEY_kana_YU: u32 = 0x4d5;
+pub const KEY_kana_YO: u32 = 0x4d6;
+pub const KEY_kana_RA: u32 = 0x4d7;
+pub const KEY_kana_RI: u32 = 0x4d8;
+pub const KEY_kana_RU: u32 = 0x4d9;
+pub const KEY_kana_RE: u32 = 0x4da;
+pub const KEY_kana_RO: u32 = 0x4db;
+pub const KEY_kana_WA: u32 = 0x4dc;
+pub const KEY_kana_N: u32 = 0x4dd;
+pub const KEY_voicedsound: u32 = 0x4de;
+pub const KEY_semivoicedsound: u32 = 0x4df;
+pub const KEY_kana_switch: u32 = 0xff7e;
+pub const KEY_Farsi_0: u32 = 0x10006f0;
+pub const KEY_Farsi_1: u32 = 0x10006f1;
+pub const KEY_Farsi_2: u32 = 0x10006f2;
+pub const KEY_Farsi_3: u32 = 0x10006f3;
+pub const KEY_Farsi_4: u32 = 0x10006f4;
+pub const KEY_Farsi_5: u32 = 0x10006f5;
+pub const KEY_Farsi_6: u32 = 0x10006f6;
+pub const KEY_Farsi_7: u32 = 0x10006f7;
+pub const KEY_Farsi_8: u32 = 0x10006f8;
+pub const KEY_Farsi_9: u32 = 0x10006f9;
+pub const KEY_Arabic_percent: u32 = 0x100066a;
+pub const KEY_Arabic_superscript_alef: u32 = 0x1000670;
+pub const KEY_Arabic_tteh: u32 = 0x1000679;
+pub const KEY_Arabic_peh: u32 = 0x100067e;
+pub const KEY_Arabic_tcheh: u32 = 0x1000686;
+pub const KEY_Arabic_ddal: u32 = 0x1000688;
+pub const KEY_Arabic_rreh: u32 = 0x1000691;
+pub const KEY_Arabic_comma: u32 = 0x5ac;
+pub const KEY_Arabic_fullstop: u32 = 0x10006d4;
+pub const KEY_Arabic_0: u32 = 0x1000660;
+pub const KEY_Arabic_1: u32 = 0x1000661;
+pub const KEY_Arabic_2: u32 = 0x1000662;
+pub const KEY_Arabic_3: u32 = 0x1000663;
+pub const KEY_Arabic_4: u32 = 0x1000664;
+pub const KEY_Arabic_5: u32 = 0x1000665;
+pub const KEY_Arabic_6: u32 = 0x1000666;
+pub const KEY_Arabic_7: u32 = 0x1000667;
+pub const KEY_Arabic_8: u32 = 0x1000668;
+pub const KEY_Arabic_9: u32 = 0x1000669;
+pub const KEY_Arabic_semicolon: u32 = 0x5bb;
+pub const KEY_Arabic_question_mark: u32 = 0x5bf;
+pub const KEY_Arabic_hamza: u32 = 0x5c1;
+pub const KEY_Arabic_maddaonalef: u32 = 0x5c2;
+pub const KEY_Arabic_hamzaonalef: u32 = 0x5c3;
+pub const KEY_Arabic_hamzaonwaw: u32 = 0x5c4;
+pub const KEY_Arabic_hamzaunderalef: u32 = 0x5c5;
+pub const KEY_Arabic_hamzaonyeh: u32 = 0x5c6;
+pub const KEY_Arabic_alef: u32 = 0x5c7;
+pub const KEY_Arabic_beh: u32 = 0x5c8;
+pub const KEY_Arabic_tehmarbuta: u32 = 0x5c9;
+pub const KEY_Arabic_teh: u32 = 0x5ca;
+pub const KEY_Arabic_theh: u32 = 0x5cb;
+pub const KEY_Arabic_jeem: u32 = 0x5cc;
+pub const KEY_Arabic_hah: u32 = 0x5cd;
+pub const KEY_Arabic_khah: u32 = 0x5ce;
+pub const KEY_Arabic_dal: u32 = 0x5cf;
+pub const KEY_Arabic_thal: u32 = 0x5d0;
+pub const KEY_Arabic_ra: u32 = 0x5d1;
+pub const KEY_Arabic_zain: u32 = 0x5d2;
+pub const KEY_Arabic_seen: u32 = 0x5d3;
+pub const KEY_Arabic_sheen: u32 = 0x5d4;
+pub const KEY_Arabic_sad: u32 = 0x5d5;
+pub const KEY_Arabic_dad: u32 = 0x5d6;
+pub const KEY_Arabic_tah: u32 = 0x5d7;
+pub const KEY_Arabic_zah: u32 = 0x5d8;
+pub const KEY_Arabic_ain: u32 = 0x5d9;
+pub const KEY_Arabic_ghain: u32 = 0x5da;
+pub const KEY_Arabic_tatweel: u32 = 0x5e0;
+pub const KEY_Arabic_feh: u32 = 0x5e1;
+pub const KEY_Arabic_qaf: u32 = 0x5e2;
+pub const KEY_Arabic_kaf: u32 = 0x5e3;
+pub const KEY_Arabic_lam: u32 = 0x5e4;
+pub const KEY_Arabic_meem: u32 = 0x5e5;
+pub const KEY_Arabic_noon: u32 = 0x5e6;
+pub const KEY_Arabic_ha: u32 = 0x5e7;
+pub const KEY_Arabic_heh: u32 = 0x5e7;
+pub const KEY_Arabic_waw: u32 = 0x5e8;
+pub const KEY_Arabic_alefmaksura: u32 = 0x5e9;
+pub const KEY_Arabic_yeh: u32 = 0x5ea;
+pub const KEY_Arabic_fathatan: u32 = 0x5eb;
+pub const KEY_Arabic_dammatan: u32 = 0x5ec;
+pub const KEY_Arabic_kasratan: u32 = 0x5ed;
+pub const KEY_Arabic_fatha: u32 = 0x5ee;
+pub const KEY_Arabic_damma: u32 = 0x5ef;
+pub const KEY_Arabic_kasra: u32 = 0x5f0;
+pub const KEY_Arabic_shadda: u32 = 0x5f1;
+pub const KEY_Arabic_sukun: u32 = 0x5f2;
+pub const KEY_Arabic_madda_above: u32 = 0x1000653;
+pub const KEY_Arabic_hamza_above: u32 = 0x1000654;
+pub const KEY_Arabic_hamza_below: u32 = 0x1000655;
+pub const KEY_Arabic_jeh: u32 = 0x1000698;
+pub const KEY_Arabic_veh: u32 = 0x10006a4;
+pub const KEY_Arabic_keheh: u32 = 0x10006a9;
+pub const KEY_Arabic_gaf: u32 = 0x10006af;
+pub const KEY_Arabic_noon_ghunna: u32 = 0x10006ba;
+pub const KEY_Arabic_heh_doachashmee: u32 = 0x10006be;
+pub const KEY_Farsi_yeh: u32 = 0x10006cc;
+pub const KEY_Arabic_farsi_yeh: u32 = 0x10006cc;
+pub const KEY_Arabic_yeh_baree: u32 = 0x10006d2;
+pub const KEY_Arabic_heh_goal: u32 = 0x10006c1;
+pub const KEY_Arabic_switch: u32 = 0xff7e;
+pub const KEY_Cyrillic_GHE_bar: u32 = 0x1000492;
+pub const KEY_Cyrillic_ghe_bar: u32 = 0x1000493;
+pub const KEY_Cyrillic_ZHE_descender: u32 = 0x1000496;
+pub const KEY_Cyrillic_zhe_descender: u32 = 0x1000497;
+pub const KEY_Cyrillic_KA_descender: u32 = 0x100049a;
+pub const KEY_Cyrillic_ka_descender: u32 = 0x100049b;
+pub const KEY_Cyrillic_KA_vertstroke: u32 = 0x100049c;
+pub const KEY_Cyrillic_ka_vertstroke: u32 = 0x100049d;
+pub const KEY_Cyrillic_EN_descender: u32 = 0x10004a2;
+pub const KEY_Cyrillic_en_descender: u32 = 0x10004a3;
+pub const KEY_Cyrillic_U_straight: u32 = 0x10004ae;
+pub const KEY_Cyrillic_u_straight: u32 = 0x10004af;
+pub const KEY_Cyrillic_U_straight_bar: u32 = 0x10004b0;
+pub const KEY_Cyrillic_u_straight_bar: u32 = 0x10004b1;
+pub const KEY_Cyrillic_HA_descender: u32 = 0x10004b2;
+pub const KEY_Cyrillic_ha_descender: u32 = 0x10004b3;
+pub const KEY_Cyrillic_CHE_descender: u32 = 0x10004b6;
+pub const KEY_Cyrillic_che_descender: u32 = 0x10004b7;
+pub const KEY_Cyrillic_CHE_vertstroke: u32 = 0x10004b8;
+pub const KEY_Cyrillic_che_vertstroke: u32 = 0x10004b9;
+pub const KEY_Cyrillic_SHHA: u32 = 0x10004ba;
+pub const KEY_Cyrillic_shha: u32 = 0x10004bb;
+pub const KEY_Cyrillic_SCHWA: u32 = 0x10004d8;
+pub const KEY_Cyrillic_schwa: u32 = 0x10004d9;
+pub const KEY_Cyrillic_I_macron: u32 = 0x10004e2;
+pub const KEY_Cyrillic_i_macron: u32 = 0x10004e3;
+pub const KEY_Cyrillic_O_bar: u32 = 0x10004e8;
+pub const KEY_Cyrillic_o_bar: u32 = 0x10004e9;
+pub const KEY_Cyrillic_U_macron: u32 = 0x10004ee;
+pub const KEY_Cyrillic_u_macron: u32 = 0x10004ef;
+pub const KEY_Serbian_dje: u32 = 0x6a1;
+pub const KEY_Macedonia_gje: u32 = 0x6a2;
+pub const KEY_Cyrillic_io: u32 = 0x6a3;
+pub const KEY_Ukrainian_ie: u32 = 0x6a4;
+pub const KEY_Ukranian_je: u32 = 0x6a4;
+pub const KEY_Macedonia_dse: u32 = 0x6a5;
+pub const KEY_Ukrainian_i: u32 = 0x6a6;
+pub const KEY_Ukranian_i: u32 = 0x6a6;
+pub const KEY_Ukrainian_yi: u32 = 0x6a7;
+pub const KEY_Ukranian_yi: u32 = 0x6a7;
+pub const KEY_Cyrillic_je: u32 = 0x6a8;
+pub const KEY_Serbian_je: u32 = 0x6a8;
+pub const KEY_Cyrillic_lje: u32 = 0x6a9;
+pub const KEY_Serbian_lje: u32 = 0x6a9;
+pub const KEY_Cyrillic_nje: u32 = 0x6aa;
+pub const KEY_Serbian_nje: u32 = 0x6aa;
+pub const KEY_Serbian_tshe: u32 = 0x6ab;
+pub const KEY_Macedonia_kje: u32 = 0x6ac;
+pub const KEY_Ukrainian_ghe_with_upturn: u32 = 0x6ad;
+pub const KEY_Byelorussian_shortu: u32 = 0x6ae;
+pub const KEY_Cyrillic_dzhe: u32 = 0x6af;
+pub const KEY_Serbian_dze: u32 = 0x6af;
+pub const KEY_numerosign: u32 = 0x6b0;
+pub const KEY_Serbian_DJE: u32 = 0x6b1;
+pub const KEY_Macedonia_GJE: u32 = 0x6b2;
+pub const KEY_Cyrillic_IO: u32 = 0x6b3;
+pub const KEY_Ukrainian_IE: u32 = 0x6b4;
+pub const KEY_Ukranian_JE: u32 = 0x6b4;
+pub const KEY_Macedonia_DSE: u32 = 0x6b5;
+pub const KEY_Ukrainian_I: u32 = 0x6b6;
+pub const KEY_Ukranian_I: u32 = 0x6b6;
+pub const KEY_Ukrainian_YI: u32 = 0x6b7;
+pub const KEY_Ukranian_YI: u32 = 0x6b7;
+pub const KEY_Cyrillic_JE: u32 = 0x6b8;
+pub const KEY_Serbian_JE: u32 = 0x6b8;
+pub const KEY_Cyrillic_LJE: u32 = 0x6b9;
+pub const KEY_Serbian_LJE: u32 = 0x6b9;
+pub const KEY_Cyrillic_NJE: u32 = 0x6ba;
+pub const KEY_Serbian_NJE: u32 = 0x6ba;
+pub const KEY_Serbian_TSHE: u32 = 0x6bb;
+pub const KEY_Macedonia_KJE: u32 = 0x6bc;
+pub const KEY_Ukrainian_GHE_WITH_UPTURN: u32 = 0x6bd;
+pub const KEY_Byelorussian_SHORTU: u32 = 0x6be;
+pub const KEY_Cyrillic_DZHE: u32 = 0x6bf;
+pub const KEY_Serbian_DZE: u32 = 0x6bf;
+pub const KEY_Cyrillic_yu: u32 = 0x6c0;
+pub const KEY_Cyrillic_a: u32 = 0x6c1;
+pub const KEY_Cyrillic_be: u32 = 0x6c2;
+pub const KEY_Cyrillic_tse: u32 = 0x6c3;
+pub const KEY_Cyrillic_de: u32 = 0x6c4;
+pub const KEY_Cyrillic_ie: u32 = 0x6c5;
+pub const KEY_Cyrillic_ef: u32 = 0x6c6;
+pub const KEY_Cyrillic_ghe: u32 = 0x6c7;
+pub const KEY_Cyrillic_ha: u32 = 0x6c8;
+pub const KEY_Cyrillic_i: u32 = 0x6c9;
+pub const KEY_Cyrillic_shorti: u32 = 0x6ca;
+pub const KEY_Cyrillic_ka: u32 = 0x6cb;
+pub const KEY_Cyrillic_el: u32 = 0x6cc;
+pub const KEY_Cyrillic_em: u32 = 0x6cd;
+pub const KEY_Cyrillic_en: u32 = 0x6ce;
+pub const KEY_Cyrillic_o: u32 = 0x6cf;
+pub const KEY_Cyrillic_pe: u32 = 0x6d0;
+pub const KEY_Cyrillic_ya: u32 = 0x6d1;
+pub const KEY_Cyrillic_er: u32 = 0x6d2;
+pub const KEY_Cyrillic_es: u32 = 0x6d3;
+pub const KEY_Cyrillic_te: u32 = 0x6d4;
+pub const KEY_Cyrillic_u: u32 = 0x6d5;
+pub const KEY_Cyrillic_zhe: u32 = 0x6d6;
+pub const KEY_Cyrillic_ve: u32 = 0x6d7;
+pub const KEY_Cyrillic_softsign: u32 = 0x6d8;
+pub const KEY_Cyrillic_yeru: u32 = 0x6d9;
+pub const KEY_Cyrillic_ze: u32 = 0x6da;
+pub const KEY_Cyrillic_sha: u32 = 0x6db;
+pub const KEY_Cyrillic_e: u32 = 0x6dc;
+pub const KEY_Cyrillic_shcha: u32 = 0x6dd;
+pub const KEY_Cyrillic_che: u32 = 0x6de;
+pub const KEY_Cyrillic_hardsign: u32 = 0x6df;
+pub const KEY_Cyrillic_YU: u32 = 0x6e0;
+pub const KEY_Cyrillic_A: u32 = 0x6e1;
+pub const KEY_Cyrillic_BE: u32 = 0x6e2;
+pub const KEY_Cyrillic_TSE: u32 = 0x6e3;
+pub const KEY_Cyrillic_DE: u32 = 0x6e4;
+pub const KEY_Cyrillic_IE: u32 = 0x6e5;
+pub const KEY_Cyrillic_EF: u32 = 0x6e6;
+pub const KEY_Cyrillic_GHE: u32 = 0x6e7;
+pub const KEY_Cyrillic_HA: u32 = 0x6e8;
+pub const KEY_Cyrillic_I: u32 = 0x6e9;
+pub const KEY_Cyrillic_SHORTI: u32 = 0x6ea;
+pub const KEY_Cyrillic_KA: u32 = 0x6eb;
+pub const KEY_Cyrillic_EL: u32 = 0x6ec;
+pub const KEY_Cyrillic_EM: u32 = 0x6ed;
+pub const KEY_Cyrillic_EN: u32 = 0x6ee;
+pub const KEY_Cyrillic_O: u32 = 0x6ef;
+pub const KEY_Cyrillic_PE: u32 = 0x6f0;
+pub const KEY_Cyrillic_YA: u32 = 0x6f1;
+pub const KEY_Cyrillic_ER: u32 = 0x6f2;
+pub const KEY_Cyrillic_ES: u32 = 0x6f3;
+pub const KEY_Cyrillic_TE: u32 = 0x6f4;
+pub const KEY_Cyrillic_U: u32 = 0x6f5;
+pub const KEY_Cyrillic_ZHE: u32 = 0x6f6;
+pub const KEY_Cyrillic_VE: u32 = 0x6f7;
+pub const KEY_Cyrillic_SOFTSIGN: u32 = 0x6f8;
+pub const KEY_Cyrillic_YERU: u32 = 0x6f9;
+pub const KEY_Cyrillic_ZE: u32 = 0x6fa;
+pub const KEY_Cyrillic_SHA: u32 = 0x6fb;
+pub const KEY_Cyrillic_E: u32 = 0x6fc;
+pub const KEY_Cyrillic_SHCHA: u32 = 0x6fd;
+pub const KEY_Cyrillic_CHE: u32 = 0x6fe;
+pub const KEY_Cyrillic_HARDSIGN: u32 = 0x6ff;
+pub const KEY_Greek_ALPHAaccent: u32 = 0x7a1;
+pub const KEY_Greek_EPSILONaccent: u32 = 0x7a2;
+pub const KEY_Greek_ETAaccent: u32 = 0x7a3;
+pub const KEY_Greek_IOTAaccent: u32 = 0x7a4;
+pub const KEY_Greek_IOTAdieresis: u32 = 0x7a5;
+pub const KEY_Greek_IOTAdiaeresis: u32 = 0x7a5;
+pub const KEY_Greek_OMICRONaccent: u32 = 0x7a7;
+pub const KEY_Greek_UPSILONaccent: u32 = 0x7a8;
+pub const KEY_Greek_UPSILONdieresis: u32 = 0x7a9;
+pub const KEY_Greek_OMEGAaccent: u32 = 0x7ab;
+pub const KEY_Greek_accentdieresis: u32 = 0x7ae;
+pub const KEY_Greek_horizbar: u32 = 0x7af;
+pub const KEY_Greek_alphaaccent: u32 = 0x7b1;
+pub const KEY_Greek_epsilonaccent: u32 = 0x7b2;
+pub const KEY_Greek_etaaccent: u32 = 0x7b3;
+pub const KEY_Greek_iotaaccent: u32 = 0x7b4;
+pub const KEY_Greek_iotadieresis: u32 = 0x7b5;
+pub const KEY_Greek_iotaaccentdieresis: u32 = 0x7b6;
+pub const KEY_Greek_omicronaccent: u32 = 0x7b7;
+pub const KEY_Greek_upsilonaccent: u32 = 0x7b8;
+pub const KEY_Greek_upsilondieresis: u32 = 0x7b9;
+pub const KEY_Greek_upsilonaccentdieresis: u32 = 0x7ba;
+pub const KEY_Greek_omegaaccent: u32 = 0x7bb;
+pub const KEY_Greek_ALPHA: u32 = 0x7c1;
+pub const KEY_Greek_BETA: u32 = 0x7c2;
+pub const KEY_Greek_GAMMA: u32 = 0x7c3;
+pub const KEY_Greek_DELTA: u32 = 0x7c4;
+pub const KEY_Greek_EPSILON: u32 = 0x7c5;
+pub const KEY_Greek_ZETA: u32 = 0x7c6;
+pub const KEY_Greek_ETA: u32 = 0x7c7;
+pub const KEY_Greek_THETA: u32 = 0x7c8;
+pub const KEY_Greek_IOTA: u32 = 0x7c9;
+pub const KEY_Greek_KAPPA: u32 = 0x7ca;
+pub const KEY_Greek_LAMDA: u32 = 0x7cb;
+pub const KEY_Greek_LAMBDA: u32 = 0x7cb;
+pub const KEY_Greek_MU: u32 = 0x7cc;
+pub const KEY_Greek_NU: u32 = 0x7cd;
+pub const KEY_Greek_XI: u32 = 0x7ce;
+pub const KEY_Greek_OMICRON: u32 = 0x7cf;
+pub const KEY_Greek_PI: u32 = 0x7d0;
+pub const KEY_Greek_RHO: u32 = 0x7d1;
+pub const KEY_Greek_SIGMA: u32 = 0x7d2;
+pub const KEY_Greek_TAU: u32 = 0x7d4;
+pub const KEY_Greek_UPSILON: u32 = 0x7d5;
+pub const KEY_Greek_PHI: u32 = 0x7d6;
+pub const KEY_Greek_CHI: u32 = 0x7d7;
+pub const KEY_Greek_PSI: u32 = 0x7d8;
+pub const KEY_Greek_OMEGA: u32 = 0x7d9;
+pub const KEY_Greek_alpha: u32 = 0x7e1;
+pub const KEY_Greek_beta: u32 = 0x7e2;
+pub const KEY_Greek_gamma: u32 = 0x7e3;
+pub const KEY_Greek_delta: u32 = 0x7e4;
+pub const KEY_Greek_epsilon: u32 = 0x7e5;
+pub const KEY_Greek_zeta: u32 = 0x7e6;
+pub const KEY_Greek_eta: u32 = 0x7e7;
+pub const KEY_Greek_theta: u32 = 0x7e8;
+pub const KEY_Greek_iota: u32 = 0x7e9;
+pub const KEY_Greek_kappa: u32 = 0x7ea;
+pub const KEY_Greek_lamda: u32 = 0x7eb;
+pub const KEY_Greek_lambda: u32 = 0x7eb;
+pub const KEY_Greek_mu: u32 = 0x7ec;
+pub const KEY_Greek_nu: u32 = 0x7ed;
+pub const KEY_Greek_xi: u32 = 0x7ee;
+pub const KEY_Greek_omicron: u32 = 0x7ef;
+pub const KEY_Greek_pi: u32 = 0x7f0;
+pub const KEY_Greek_rho: u32 = 0x7f1;
+pub const KEY_Greek_sigma: u32 = 0x7f2;
+pub const KEY_Greek_finalsmallsigma: u32 = 0x7f3;
+pub const KEY_Greek_tau: u32 = 0x7f4;
+pub const KEY_Greek_upsilon: u32 = 0x7f5;
+pub const KEY_Greek_phi: u32 = 0x7f6;
+pub const KEY_Greek_chi: u32 = 0x7f7;
+pub const KEY_Greek_psi: u32 = 0x7f8;
+pub const KEY_Greek_omega: u32 = 0x7f9;
+pub const KEY_Greek_switch: u32 = 0xff7e;
+pub const KEY_leftradical: u32 = 0x8a1;
+pub const KEY_topleftradical: u32 = 0x8a2;
+pub const KEY_horizconnector: u32 = 0x8a3;
+pub const KEY_topintegral: u32 = 0x8a4;
+pub const KEY_botintegral: u32 = 0x8a5;
+pub const KEY_vertconnector: u32 = 0x8a6;
+pub const KEY_topleftsqbracket: u32 = 0x8a7;
+pub const KEY_botleftsqbracket: u32 = 0x8a8;
+pub const KEY_toprightsqbracket: u32 = 0x8a9;
+pub const KEY_botrightsqbracket: u32 = 0x8aa;
+pub const KEY_topleftparens: u32 = 0x8ab;
+pub const KEY_botleftparens: u32 = 0x8ac;
+pub const KEY_toprightparens: u32 = 0x8ad;
+pub const KEY_botrightparens: u32 = 0x8ae;
+pub const KEY_leftmiddlecurlybrace: u32 = 0x8af;
+pub const KEY_rightmiddlecurlybrace: u32 = 0x8b0;
+pub const KEY_topleftsummation: u32 = 0x8b1;
+pub const KEY_botleftsummation: u32 = 0x8b2;
+pub const KEY_topvertsummationconnector: u32 = 0x8b3;
+pub const KEY_botvertsummationconnector: u32 = 0x8b4;
+pub const KEY_toprightsummation: u32 = 0x8b5;
+pub const KEY_botrightsummation: u32 = 0x8b6;
+pub const KEY_rightmiddlesummation: u32 = 0x8b7;
+pub const KEY_lessthanequal: u32 = 0x8bc;
+pub const KEY_notequal: u32 = 0x8bd;
+pub const KEY_greaterthanequal: u32 = 0x8be;
+pub const KEY_integral: u32 = 0x8bf;
+pub const KEY_therefore: u32 = 0x8c0;
+pub const KEY_variation: u32 = 0x8c1;
+pub const KEY_infinity: u32 = 0x8c2;
+pub const KEY_nabla: u32 = 0x8c5;
+pub const KEY_approximate: u32 = 0x8c8;
+pub const KEY_similarequal: u32 = 0x8c9;
+pub const KEY_ifonlyif: u32 = 0x8cd;
+pub const KEY_implies: u32 = 0x8ce;
+pub const KEY_identical: u32 = 0x8cf;
+pub const KEY_radical: u32 = 0x8d6;
+pub const KEY_includedin: u32 = 0x8da;
+pub const KEY_includes: u32 = 0x8db;
+pub const KEY_intersection: u32 = 0x8dc;
+pub const KEY_union: u32 = 0x8dd;
+pub const KEY_logicaland: u32 = 0x8de;
+pub const KEY_logicalor: u32 = 0x8df;
+pub const KEY_partialderivative: u32 = 0x8ef;
+pub const KEY_function: u32 = 0x8f6;
+pub const KEY_leftarrow: u32 = 0x8fb;
+pub const KEY_uparrow: u32 = 0x8fc;
+pub const KEY_rightarrow: u32 = 0x8fd;
+pub const KEY_downarrow: u32 = 0x8fe;
+pub const KEY_blank: u32 = 0x9df;
+pub const KEY_soliddiamond: u32 = 0x9e0;
+pub const KEY_checkerboard: u32 = 0x9e1;
+pub const KEY_ht: u32 = 0x9e2;
+pub const KEY_ff: u32 = 0x9e3;
+pub const KEY_cr: u32 = 0x9e4;
+pub const KEY_lf: u32 = 0x9e5;
+pub const KEY_nl: u32 = 0x9e8;
+pub const KEY_vt: u32 = 0x9e9;
+pub const KEY_lowrightcorner: u32 = 0x9ea;
+pub const KEY_uprightcorner: u32 = 0x9eb;
+pub const KEY_upleftcorner: u32 = 0x9ec;
+pub const KEY_lowleftcorner: u32 = 0x9ed;
+pub const KEY_crossinglines: u32 = 0x9ee;
+pub const KEY_horizlinescan1: u32 = 0x9ef;
+pub const KEY_horizlinescan3: u32 = 0x9f0;
+pub const KEY_horizlinescan5: u32 = 0x9f1;
+pub const KEY_horizlinescan7: u32 = 0x9f2;
+pub const KEY_horizlinescan9: u32 = 0x9f3;
+pub const KEY_leftt: u32 = 0x9f4;
+pub const KEY_rightt: u32 = 0x9f5;
+pub const KEY_bott: u32 = 0x9f6;
+pub const KEY_topt: u32 = 0x9f7;
+pub const KEY_vertbar: u32 = 0x9f8;
+pub const KEY_emspace: u32 = 0xaa1;
+pub const KEY_enspace: u32 = 0xaa2;
+pub const KEY_em3space: u32 = 0xaa3;
+pub const KEY_em4space: u32 = 0xaa4;
+pub const KEY_digitspace: u32 = 0xaa5;
+pub const KEY_punctspace: u32 = 0xaa6;
+pub const KEY_thinspace: u32 = 0xaa7;
+pub const KEY_hairspace: u32 = 0xaa8;
+pub const KEY_emdash: u32 = 0xaa9;
+pub const KEY_endash: u32 = 0xaaa;
+pub const KEY_signifblank: u32 = 0xaac;
+pub const KEY_ellipsis: u32 = 0xaae;
+pub const KEY_doubbaselinedot: u32 = 0xaaf;
+pub const KEY_onethird: u32 = 0xab0;
+pub const KEY_twothirds: u32 = 0xab1;
+pub const KEY_onefifth: u32 = 0xab2;
+pub const KEY_twofifths: u32 = 0xab3;
+pub const KEY_threefifths: u32 = 0xab4;
+pub const KEY_fourfifths: u32 = 0xab5;
+pub const KEY_onesixth: u32 = 0xab6;
+pub const KEY_fivesixths: u32 = 0xab7;
+pub const KEY_careof: u32 = 0xab8;
+pub const KEY_figdash: u32 = 0xabb;
+pub const KEY_leftanglebracket: u32 = 0xabc;
+pub const KEY_decimalpoint: u32 = 0xabd;
+pub const KEY_rightanglebracket: u32 = 0xabe;
+pub const KEY_marker: u32 = 0xabf;
+pub const KEY_oneeighth: u32 = 0xac3;
+pub const KEY_threeeighths: u32 = 0xac4;
+pub const KEY_fiveeighths: u32 = 0xac5;
+pub const KEY_seveneighths: u32 = 0xac6;
+pub const KEY_trademark: u32 = 0xac9;
+pub const KEY_signaturemark: u32 = 0xaca;
+pub const KEY_trademarkincircle: u32 = 0xacb;
+pub const KEY_leftopentriangle: u32 = 0xacc;
+pub const KEY_rightopentriangle: u32 = 0xacd;
+pub const KEY_emopencircle: u32 = 0xace;
+pub const KEY_emopenrectangle: u32 = 0xacf;
+pub const KEY_leftsinglequotemark: u32 = 0xad0;
+pub const KEY_rightsinglequotemark: u32 = 0xad1;
+pub const KEY_leftdoublequotemark: u32 = 0xad2;
+pub const KEY_rightdoublequotemark: u32 = 0xad3;
+pub const KEY_prescription: u32 = 0xad4;
+pub const KEY_permille: u32 = 0xad5;
+pub const KEY_minutes: u32 = 0xad6;
+pub const KEY_seconds: u32 = 0xad7;
+pub const KEY_latincross: u32 = 0xad9;
+pub const KEY_hexagram: u32 = 0xada;
+pub const KEY_filledrectbullet: u32 = 0xadb;
+pub const KEY_filledlefttribullet: u32 = 0xadc;
+pub const KEY_filledrighttribullet: u32 = 0xadd;
+pub const KEY_emfilledcircle: u32 = 0xade;
+pub const KEY_emfilledrect: u32 = 0xadf;
+pub const KEY_enopencircbullet: u32 = 0xae0;
+pub const KEY_enopensquarebullet: u32 = 0xae1;
+pub const KEY_openrectbullet: u32 = 0xae2;
+pub const KEY_opentribulletup: u32 = 0xae3;
+pub const KEY_opentribulletdown: u32 = 0xae4;
+pub const KEY_openstar: u32 = 0xae5;
+pub const KEY_enfilledcircbullet: u32 = 0xae6;
+pub const KEY_enfilledsqbullet: u32 = 0xae7;
+pub const KEY_filledtribulletup: u32 = 0xae8;
+pub const KEY_filledtribulletdown: u32 = 0xae9;
+pub const KEY_leftpointer: u32 = 0xaea;
+pub const KEY_rightpointer: u32 = 0xaeb;
+pub const KEY_club: u32 = 0xaec;
+pub const KEY_diamond: u32 = 0xaed;
+pub const KEY_heart: u32 = 0xaee;
+pub const KEY_maltesecross: u32 = 0xaf0;
+pub const KEY_dagger: u32 = 0xaf1;
+pub const KEY_doubledagger: u32 = 0xaf2;
+pub const KEY_checkmark: u32 = 0xaf3;
+pub const KEY_ballotcross: u32 = 0xaf4;
+pub const KEY_musicalsharp: u32 = 0xaf5;
+pub const KEY_musicalflat: u32 = 0xaf6;
+pub const KEY_malesymbol: u32 = 0xaf7;
+pub const KEY_femalesymbol: u32 = 0xaf8;
+pub const KEY_telephone: u32 = 0xaf9;
+pub const KEY_telephonerecorder: u32 = 0xafa;
+pub const KEY_phonographcopyright: u32 = 0xafb;
+pub const KEY_caret: u32 = 0xafc;
+pub const KEY_singlelowquotemark: u32 = 0xafd;
+pub const KEY_doublelowquotemark: u32 = 0xafe;
+pub const KEY_cursor: u32 = 0xaff;
+pub const KEY_leftcaret: u32 = 0xba3;
+pub const KEY_rightcaret: u32 = 0xba6;
+pub const KEY_downcaret: u32 = 0xba8;
+pub const KEY_upcaret: u32 = 0xba9;
+pub const KEY_overbar: u32 = 0xbc0;
+pub const KEY_downtack: u32 = 0xbc2;
+pub const KEY_upshoe: u32 = 0xbc3;
+pub const KEY_downstile: u32 = 0xbc4;
+pub const KEY_underbar: u32 = 0xbc6;
+pub const KEY_jot: u32 = 0xbca;
+pub const KEY_quad: u32 = 0xbcc;
+pub const KEY_uptack: u32 = 0xbce;
+pub const KEY_circle: u32 = 0xbcf;
+pub const KEY_upstile: u32 = 0xbd3;
+pub const KEY_downshoe: u32 = 0xbd6;
+pub const KEY_rightshoe: u32 = 0xbd8;
+pub const KEY_leftshoe: u32 = 0xbda;
+pub const KEY_lefttack: u32 = 0xbdc;
+pub const KEY_righttack: u32 = 0xbfc;
+pub const KEY_hebrew_doublelowline: u32 = 0xcdf;
+pub const KEY_hebrew_aleph: u32 = 0xce0;
+pub const KEY_hebrew_bet: u32 = 0xce1;
+pub const KEY_hebrew_beth: u32 = 0xce1;
+pub const KEY_hebrew_gimel: u32 = 0xce2;
+pub const KEY_hebrew_gimmel: u32 = 0xce2;
+pub const KEY_hebrew_dalet: u32 = 0xce3;
+pub const KEY_hebrew_daleth: u32 = 0xce3;
+pub const KEY_hebrew_he: u32 = 0xce4;
+pub const KEY_hebrew_waw: u32 = 0xce5;
+pub const KEY_hebrew_zain: u32 = 0xce6;
+pub const KEY_hebrew_zayin: u32 = 0xce6;
+pub const KEY_hebrew_chet: u32 = 0xce7;
+pub const KEY_hebrew_het: u32 = 0xce7;
+pub const KEY_hebrew_tet: u32 = 0xce8;
+pub const KEY_hebrew_teth: u32 = 0xce8;
+pub const KEY_hebrew_yod: u32 = 0xce9;
+pub const KEY_hebrew_finalkaph: u32 = 0xcea;
+pub const KEY_hebrew_kaph: u32 = 0xceb;
+pub const KEY_hebrew_lamed: u32 = 0xcec;
+pub const KEY_hebrew_finalmem: u32 = 0xced;
+pub const KEY_hebrew_mem: u32 = 0xcee;
+pub const KEY_hebrew_finalnun: u32 = 0xcef;
+pub const KEY_hebrew_nun: u32 = 0xcf0;
+pub const KEY_hebrew_samech: u32 = 0xcf1;
+pub const KEY_hebrew_samekh: u32 = 0xcf1;
+pub const KEY_hebrew_ayin: u32 = 0xcf2;
+pub const KEY_hebrew_finalpe: u32 = 0xcf3;
+pub const KEY_hebrew_pe: u32 = 0xcf4;
+pub const KEY_hebrew_finalzade: u32 = 0xcf5;
+pub const KEY_hebrew_finalzadi: u32 = 0xcf5;
+pub const KEY_hebrew_zade: u32 = 0xcf6;
+pub const KEY_hebrew_zadi: u32 = 0xcf6;
+pub const KEY_hebrew_qoph: u32 = 0xcf7;
+pub const KEY_hebrew_kuf: u32 = 0xcf7;
+pub const KEY_hebrew_resh: u32 = 0xcf8;
+pub const KEY_hebrew_shin: u32 = 0xcf9;
+pub const KEY_hebrew_taw: u32 = 0xcfa;
+pub const KEY_hebrew_taf: u32 = 0xcfa;
+pub const KEY_Hebrew_switch: u32 = 0xff7e;
+pub const KEY_Thai_kokai: u32 = 0xda1;
+pub const KEY_Thai_khokhai: u32 = 0xda2;
+pub const KEY_Thai_khokhuat: u32 = 0xda3;
+pub const KEY_Thai_khokhwai: u32 = 0xda4;
+pub const KEY_Thai_khokhon: u32 = 0xda5;
+pub const KEY_Thai_khorakhang: u32 = 0xda6;
+pub const KEY_Thai_ngongu: u32 = 0xda7;
+pub const KEY_Thai_chochan: u32 = 0xda8;
+pub const KEY_Thai_choching: u32 = 0xda9;
+pub const KEY_Thai_chochang: u32 = 0xdaa;
+pub const KEY_Thai_soso: u32 = 0xdab;
+pub const KEY_Thai_chochoe: u32 = 0xdac;
+pub const KEY_Thai_yoying: u32 = 0xdad;
+pub const KEY_Thai_dochada: u32 = 0xdae;
+pub const KEY_Thai_topatak: u32 = 0xdaf;
+pub const KEY_Thai_thothan: u32 = 0xdb0;
+pub const KEY_Thai_thonangmontho: u32 = 0xdb1;
+pub const KEY_Thai_thophuthao: u32 = 0xdb2;
+pub const KEY_Thai_nonen: u32 = 0xdb3;
+pub const KEY_Thai_dodek: u32 = 0xdb4;
+pub const KEY_Thai_totao: u32 = 0xdb5;
+pub const KEY_Thai_thothung: u32 = 0xdb6;
+pub const KEY_Thai_thothahan: u32 = 0xdb7;
+pub const KEY_Thai_thothong: u32 = 0xdb8;
+pub const KEY_Thai_nonu: u32 = 0xdb9;
+pub const KEY_Thai_bobaimai: u32 = 0xdba;
+pub const KEY_Thai_popla: u32 = 0xdbb;
+pub const KEY_Thai_phophung: u32 = 0xdbc;
+pub const KEY_Thai_fofa: u32 = 0xdbd;
+pub const KEY_Thai_phophan: u32 = 0xdbe;
+pub const KEY_Thai_fofan: u32 = 0xdbf;
+pub const KEY_Thai_phosamphao: u32 = 0xdc0;
+pub const KEY_Thai_moma: u32 = 0xdc1;
+pub const KEY_Thai_yoyak: u32 = 0xdc2;
+pub const KEY_Thai_rorua: u32 = 0xdc3;
+pub const KEY_Thai_ru: u32 = 0xdc4;
+pub const KEY_Thai_loling: u32 = 0xdc5;
+pub const KEY_Thai_lu: u32 = 0xdc6;
+pub const KEY_Thai_wowaen: u32 = 0xdc7;
+pub const KEY_Thai_sosala: u32 = 0xdc8;
+pub const KEY_Thai_sorusi: u32 = 0xdc9;
+pub const KEY_Thai_sosua: u32 = 0xdca;
+pub const KEY_Thai_hohip: u32 = 0xdcb;
+pub const KEY_Thai_lochula: u32 = 0xdcc;
+pub const KEY_Thai_oang: u32 = 0xdcd;
+pub const KEY_Thai_honokhuk: u32 = 0xdce;
+pub const KEY_Thai_paiyannoi: u32 = 0xdcf;
+pub const KEY_Thai_saraa: u32 = 0xdd0;
+pub const KEY_Thai_maihanakat: u32 = 0xdd1;
+pub const KEY_Thai_saraaa: u32 = 0xdd2;
+pub const KEY_Thai_saraam: u32 = 0xdd3;
+pub const KEY_Thai_sarai: u32 = 0xdd4;
+pub const KEY_Thai_saraii: u32 = 0xdd5;
+pub const KEY_Thai_saraue: u32 = 0xdd6;
+pub const KEY_Thai_sarauee: u32 = 0xdd7;
+pub const KEY_Thai_sarau: u32 = 0xdd8;
+pub const KEY_Thai_sarauu: u32 = 0xdd9;
+pub const KEY_Thai_phinthu: u32 = 0xdda;
+pub const KEY_Thai_maihanakat_maitho: u32 = 0xdde;
+pub const KEY_Thai_baht: u32 = 0xddf;
+pub const KEY_Thai_sarae: u32 = 0xde0;
+pub const KEY_Thai_saraae: u32 = 0xde1;
+pub const KEY_Thai_sarao: u32 = 0xde2;
+pub const KEY_Thai_saraaimaimuan: u32 = 0xde3;
+pub const KEY_Thai_saraaimaimalai: u32 = 0xde4;
+pub const KEY_Thai_lakkhangyao: u32 = 0xde5;
+pub const KEY_Thai_maiyamok: u32 = 0xde6;
+pub const KEY_Thai_maitaikhu: u32 = 0xde7;
+pub const KEY_Thai_maiek: u32 = 0xde8;
+pub const KEY_Thai_maitho: u32 = 0xde9;
+pub const KEY_Thai_maitri: u32 = 0xdea;
+pub const KEY_Thai_maichattawa: u32 = 0xdeb;
+pub const KEY_Thai_thanthakhat: u32 = 0xdec;
+pub const KEY_Thai_nikhahit: u32 = 0xded;
+pub const KEY_Thai_leksun: u32 = 0xdf0;
+pub const KEY_Thai_leknung: u32 = 0xdf1;
+pub const KEY_Thai_leksong: u32 = 0xdf2;
+pub const KEY_Thai_leksam: u32 = 0xdf3;
+pub const KEY_Thai_leksi: u32 = 0xdf4;
+pub const KEY_Thai_lekha: u32 = 0xdf5;
+pub const KEY_Thai_lekhok: u32 = 0xdf6;
+pub const KEY_Thai_lekchet: u32 = 0xdf7;
+pub const KEY_Thai_lekpaet: u32 = 0xdf8;
+pub const KEY_Thai_lekkao: u32 = 0xdf9;
+pub const KEY_Hangul: u32 = 0xff31;
+pub const KEY_Hangul_Start: u32 = 0xff32;
+pub const KEY_Hangul_End: u32 = 0xff33;
+pub const KEY_Hangul_Hanja: u32 = 0xff34;
+pub const KEY_Hangul_Jamo: u32 = 0xff35;
+pub const KEY_Hangul_Romaja: u32 = 0xff36;
+pub const KEY_Hangul_Codeinput: u32 = 0xff37;
+pub const KEY_Hangul_Jeonja: u32 = 0xff38;
+pub const KEY_Hangul_Banja: u32 = 0xff39;
+pub const KEY_Hangul_PreHanja: u32 = 0xff3a;
+pub const KEY_Hangul_PostHanja: u32 = 0xff3b;
+pub const KEY_Hangul_SingleCandidate: u32 = 0xff3c;
+pub const KEY_Hangul_MultipleCandidate: u32 = 0xff3d;
+pub const KEY_Hangul_PreviousCandidate: u32 = 0xff3e;
+pub const KEY_Hangul_Special: u32 = 0xff3f;
+pub const KEY_Hangul_switch: u32 = 0xff7e;
+pub const KEY_Hangul_Kiyeog: u32 = 0xea1;
+pub const KEY_Hangul_SsangKiyeog: u32 = 0xea2;
+pub const KEY_Hangul_KiyeogSios: u32 = 0xea3;
+pub const KEY_Hangul_Nieun: u32 = 0xea4;
+pub const KEY_Hangul_NieunJieuj: u32 = 0xea5;
+pub const KEY_Hangul_NieunHieuh: u32 = 0xea6;
+pub const KEY_Hangul_Dikeud: u32 = 0xea7;
+pub const KEY_Hangul_SsangDikeud: u32 = 0xea8;
+pub const KEY_Hangul_Rieul: u32 = 0xea9;
+pub const KEY_Hangul_RieulKiyeog: u32 = 0xeaa;
+pub const KEY_Hangul_RieulMieum: u32 = 0xeab;
+pub const KEY_Hangul_RieulPieub: u32 = 0xeac;
+pub const KEY_Hangul_RieulSios: u32 = 0xead;
+pub const KEY_Hangul_RieulTieut: u32 = 0xeae;
+pub const KEY_Hangul_RieulPhieuf: u32 = 0xeaf;
+pub const KEY_Hangul_RieulHieuh: u32 = 0xeb0;
+pub const KEY_Hangul_Mieum: u32 = 0xeb1;
+pub const KEY_Hangul_Pieub: u32 = 0xeb2;
+pub const KEY_Hangul_SsangPieub: u32 = 0xeb3;
+pub const KEY_Hangul_PieubSios: u32 = 0xeb4;
+pub const KEY_Hangul_Sios: u32 = 0xeb5;
+pub const KEY_Hangul_SsangSios: u32 = 0xeb6;
+pub const KEY_Hangul_Ieung: u32 = 0xeb7;
+pub const KEY_Hangul_Jieuj: u32 = 0xeb8;
+pub const KEY_Hangul_SsangJieuj: u32 = 0xeb9;
+pub const KEY_Hangul_Cieuc: u32 = 0xeba;
+pub const KEY_Hangul_Khieuq: u32 = 0xebb;
+pub const KEY_Hangul_Tieut: u32 = 0xebc;
+pub const KEY_Hangul_Phieuf: u32 = 0xebd;
+pub const KEY_Hangul_Hieuh: u32 = 0xebe;
+pub const KEY_Hangul_A: u32 = 0xebf;
+pub const KEY_Hangul_AE: u32 = 0xec0;
+pub const KEY_Hangul_YA: u32 = 0xec1;
+pub const KEY_Hangul_YAE: u32 = 0xec2;
+pub const KEY_Hangul_EO: u32 = 0xec3;
+pub const KEY_Hangul_E: u32 = 0xec4;
+pub const KEY_Hangul_YEO: u32 = 0xec5;
+pub const KEY_Hangul_YE: u32 = 0xec6;
+pub const KEY_Hangul_O: u32 = 0xec7;
+pub const KEY_Hangul_WA: u32 = 0xec8;
+pub const KEY_Hangul_WAE: u32 = 0xec9;
+pub const KEY_Hangul_OE: u32 = 0xeca;
+pub const KEY_Hangul_YO: u32 = 0xecb;
+pub const KEY_Hangul_U: u32 = 0xecc;
+pub const KEY_Hangul_WEO: u32 = 0xecd;
+pub const KEY_Hangul_WE: u32 = 0xece;
+pub const KEY_Hangul_WI: u32 = 0xecf;
+pub const KEY_Hangul_YU: u32 = 0xed0;
+pub const KEY_Hangul_EU: u32 = 0xed1;
+pub const KEY_Hangul_YI: u32 = 0xed2;
+pub const KEY_Hangul_I: u32 = 0xed3;
+pub const KEY_Hangul_J_Kiyeog: u32 = 0xed4;
+pub const KEY_Hangul_J_SsangKiyeog: u32 = 0xed5;
+pub const KEY_Hangul_J_KiyeogSios: u32 = 0xed6;
+pub const KEY_Hangul_J_Nieun: u32 = 0xed7;
+pub const KEY_Hangul_J_NieunJieuj: u32 = 0xed8;
+pub const KEY_Hangul_J_NieunHieuh: u32 = 0xed9;
+pub const KEY_Hangul_J_Dikeud: u32 = 0xeda;
+pub const KEY_Hangul_J_Rieul: u32 = 0xedb;
+pub const KEY_Hangul_J_RieulKiyeog: u32 = 0xedc;
+pub const KEY_Hangul_J_RieulMieum: u32 = 0xedd;
+pub const KEY_Hangul_J_RieulPieub: u32 = 0xede;
+pub const KEY_Hangul_J_RieulSios: u32 = 0xedf;
+pub const KEY_Hangul_J_RieulTieut: u32 = 0xee0;
+pub const KEY_Hangul_J_RieulPhieuf: u32 = 0xee1;
+pub const KEY_Hangul_J_RieulHieuh: u32 = 0xee2;
+pub const KEY_Hangul_J_Mieum: u32 = 0xee3;
+pub const KEY_Hangul_J_Pieub: u32 = 0xee4;
+pub const KEY_Hangul_J_PieubSios: u32 = 0xee5;
+pub const KEY_Hangul_J_Sios: u32 = 0xee6;
+pub const KEY_Hangul_J_SsangSios: u32 = 0xee7;
+pub const KEY_Hangul_J_Ieung: u32 = 0xee8;
+pub const KEY_Hangul_J_Jieuj: u32 = 0xee9;
+pub const KEY_Hangul_J_Cieuc: u32 = 0xeea;
+pub const KEY_Hangul_J_Khieuq: u32 = 0xeeb;
+pub const KEY_Hangul_J_Tieut: u32 = 0xeec;
+pub const KEY_Hangul_J_Phieuf: u32 = 0xeed;
+pub const KEY_Hangul_J_Hieuh: u32 = 0xeee;
+pub const KEY_Hangul_RieulYeorinHieuh: u32 = 0xeef;
+pub const KEY_Hangul_SunkyeongeumMieum: u32 = 0xef0;
+pub const KEY_Hangul_SunkyeongeumPieub: u32 = 0xef1;
+pub const KEY_Hangul_PanSios: u32 = 0xef2;
+pub const KEY_Hangul_KkogjiDalrinIeung: u32 = 0xef3;
+pub const KEY_Hangul_SunkyeongeumPhieuf: u32 = 0xef4;
+pub const KEY_Hangul_YeorinHieuh: u32 = 0xef5;
+pub const KEY_Hangul_AraeA: u32 = 0xef6;
+pub const KEY_Hangul_AraeAE: u32 = 0xef7;
+pub const KEY_Hangul_J_PanSios: u32 = 0xef8;
+pub const KEY_Hangul_J_KkogjiDalrinIeung: u32 = 0xef9;
+pub const KEY_Hangul_J_YeorinHieuh: u32 = 0xefa;
+pub const KEY_Korean_Won: u32 = 0xeff;
+pub const KEY_Armenian_ligature_ew: u32 = 0x1000587;
+pub const KEY_Armenian_full_stop: u32 = 0x1000589;
+pub const KEY_Armenian_verjaket: u32 = 0x1000589;
+pub const KEY_Armenian_separation_mark: u32 = 0x100055d;
+pub const KEY_Armenian_but: u32 = 0x100055d;
+pub const KEY_Armenian_hyphen: u32 = 0x100058a;
+pub const KEY_Armenian_yentamna: u32 = 0x100058a;
+pub const KEY_Armenian_exclam: u32 = 0x100055c;
+pub const KEY_Armenian_amanak: u32 = 0x100055c;
+pub const KEY_Armenian_accent: u32 = 0x100055b;
+pub const KEY_Armenian_shesht: u32 = 0x100055b;
+pub const KEY_Armenian_question: u32 = 0x100055e;
+pub const KEY_Armenian_paruyk: u32 = 0x100055e;
+pub const KEY_Armenian_AYB: u32 = 0x1000531;
+pub const KEY_Armenian_ayb: u32 = 0x1000561;
+pub const KEY_Armenian_BEN: u32 = 0x1000532;
+pub const KEY_Armenian_ben: u32 = 0x1000562;
+pub const KEY_Armenian_GIM: u32 = 0x1000533;
+pub const KEY_Armenian_gim: u32 = 0x1000563;
+pub const KEY_Armenian_DA: u32 = 0x1000534;
+pub const KEY_Armenian_da: u32 = 0x1000564;
+pub const KEY_Armenian_YECH: u32 = 0x1000535;
+pub const KEY_Armenian_yech: u32 = 0x1000565;
+pub const KEY_Armenian_ZA: u32 = 0x1000536;
+pub const KEY_Armenian_za: u32 = 0x1000566;
+pub const KEY_Armenian_E: u32 = 0x1000537;
+pub const KEY_Armenian_e: u32 = 0x1000567;
+pub const KEY_Armenian_AT: u32 = 0x1000538;
+pub const KEY_Armenian_at: u32 = 0x1000568;
+pub const KEY_Armenian_TO: u32 = 0x1000539;
+pub const KEY_Armenian_to: u32 = 0x1000569;
+pub const KEY_Armenian_ZHE: u32 = 0x100053a;
+pub const KEY_Armenian_zhe: u32 = 0x100056a;
+pub const KEY_Armenian_INI: u32 = 0x100053b;
+pub const KEY_Armenian_ini: u32 = 0x100056b;
+pub const KEY_Armenian_LYUN: u32 = 0x100053c;
+pub const KEY_Armenian_lyun: u32 = 0x100056c;
+pub const KEY_Armenian_KHE: u32 = 0x100053d;
+pub const KEY_Armenian_khe: u32 = 0x100056d;
+pub const KEY_Armenian_TSA: u32 = 0x100053e;
+pub const KEY_Armenian_tsa: u32 = 0x100056e;
+pub const KEY_Armenian_KEN: u32 = 0x100053f;
+pub const KEY_Armenian_ken: u32 = 0x100056f;
+pub const KEY_Armenian_HO: u32 = 0x1000540;
+pub const KEY_Armenian_ho: u32 = 0x1000570;
+pub const KEY_Armenian_DZA: u32 = 0x1000541;
+pub const KEY_Armenian_dza: u32 = 0x1000571;
+pub const KEY_Armenian_GHAT: u32 = 0x1000542;
+pub const KEY_Armenian_ghat: u32 = 0x1000572;
+pub const KEY_Armenian_TCHE: u32 = 0x1000543;
+pub const KEY_Armenian_tche: u32 = 0x1000573;
+pub const KEY_Armenian_MEN: u32 = 0x1000544;
+pub const KEY_Armenian_men: u32 = 0x1000574;
+pub const KEY_Armenian_HI: u32 = 0x1000545;
+pub const KEY_Armenian_hi: u32 = 0x1000575;
+pub const KEY_Armenian_NU: u32 = 0x1000546;
+pub const KEY_Armenian_nu: u32 = 0x1000576;
+pub const KEY_Armenian_SHA: u32 = 0x1000547;
+pub const KEY_Armenian_sha: u32 = 0x1000577;
+pub const KEY_Armenian_VO: u32 = 0x1000548;
+pub const KEY_Armenian_vo: u32 = 0x1000578;
+pub const KEY_Armenian_CHA: u32 = 0x1000549;
+pub const KEY_Armenian_cha: u32 = 0x1000579;
+pub const KEY_Armenian_PE: u32 = 0x100054a;
+pub const KEY_Armenian_pe: u32 = 0x100057a;
+pub const KEY_Armenian_JE: u32 = 0x100054b;
+pub const KEY_Armenian_je: u32 = 0x100057b;
+pub const KEY_Armenian_RA: u32 = 0x100054c;
+pub const KEY_Armenian_ra: u32 = 0x100057c;
+pub const KEY_Armenian_SE: u32 = 0x100054d;
+pub const KEY_Armenian_se: u32 = 0x100057d;
+pub const KEY_Armenian_VEV: u32 = 0x100054e;
+pub const KEY_Armenian_vev: u32 = 0x100057e;
+pub const KEY_Armenian_TYUN: u32 = 0x100054f;
+pub const KEY_Armenian_tyun: u32 = 0x100057f;
+pub const KEY_Armenian_RE: u32 = 0x1000550;
+pub const KEY_Armenian_re: u32 = 0x1000580;
+pub const KEY_Armenian_TSO: u32 = 0x1000551;
+pub const KEY_Armenian_tso: u32 = 0x1000581;
+pub const KEY_Armenian_VYUN: u32 = 0x1000552;
+pub const KEY_Armenian_vyun: u32 = 0x1000582;
+pub const KEY_Armenian_PYUR: u32 = 0x1000553;
+pub const KEY_Armenian_pyur: u32 = 0x1000583;
+pub const KEY_Armenian_KE: u32 = 0x1000554;
+pub const KEY_Armenian_ke: u32 = 0x1000584;
+pub const KEY_Armenian_O: u32 = 0x1000555;
+pub const KEY_Armenian_o: u32 = 0x1000585;
+pub const KEY_Armenian_FE: u32 = 0x1000556;
+pub const KEY_Armenian_fe: u32 = 0x1000586;
+pub const KEY_Armenian_apostrophe: u32 = 0x100055a;
+pub const KEY_Georgian_an: u32 = 0x10010d0;
+pub const KEY_Georgian_ban: u32 = 0x10010d1;
+pub const KEY_Georgian_gan: u32 = 0x10010d2;
+pub const KEY_Georgian_don: u32 = 0x10010d3;
+pub const KEY_Georgian_en: u32 = 0x10010d4;
+pub const KEY_Georgian_vin: u32 = 0x10010d5;
+pub const KEY_Georgian_zen: u32 = 0x10010d6;
+pub const KEY_Georgian_tan: u32 = 0x10010d7;
+pub const KEY_Georgian_in: u32 = 0x10010d8;
+pub const KEY_Georgian_kan: u32 = 0x10010d9;
+pub const KEY_Georgian_las: u32 = 0x10010da;
+pub const KEY_Georgian_man: u32 = 0x10010db;
+pub const KEY_Georgian_nar: u32 = 0x10010dc;
+pub const KEY_Georgian_on: u32 = 0x10010dd;
+pub const KEY_Georgian_par: u32 = 0x10010de;
+pub const KEY_Georgian_zhar: u32 = 0x10010df;
+pub const KEY_Georgian_rae: u32 = 0x10010e0;
+pub const KEY_Georgian_san: u32 = 0x10010e1;
+pub const KEY_Georgian_tar: u32 = 0x10010e2;
+pub const KEY_Georgian_un: u32 = 0x10010e3;
+pub const KEY_Georgian_phar: u32 = 0x10010e4;
+pub const KEY_Georgian_khar: u32 = 0x10010e5;
+pub const KEY_Georgian_ghan: u32 = 0x10010e6;
+pub const KEY_Georgian_qar: u32 = 0x10010e7;
+pub const KEY_Georgian_shin: u32 = 0x10010e8;
+pub const KEY_Georgian_chin: u32 = 0x10010e9;
+pub const KEY_Georgian_can: u32 = 0x10010ea;
+pub const KEY_Georgian_jil: u32 = 0x10010eb;
+pub const KEY_Georgian_cil: u32 = 0x10010ec;
+pub const KEY_Georgian_char: u32 = 0x10010ed;
+pub const KEY_Georgian_xan: u32 = 0x10010ee;
+pub const KEY_Georgian_jhan: u32 = 0x10010ef;
+pub const KEY_Georgian_hae: u32 = 0x10010f0;
+pub const KEY_Georgian_he: u32 = 0x10010f1;
+pub const KEY_Georgian_hie: u32 = 0x10010f2;
+pub const KEY_Georgian_we: u32 = 0x10010f3;
+pub const KEY_Georgian_har: u32 = 0x10010f4;
+pub const KEY_Georgian_hoe: u32 = 0x10010f5;
+pub const KEY_Georgian_fi: u32 = 0x10010f6;
+pub const KEY_Xabovedot: u32 = 0x1001e8a;
+pub const KEY_Ibreve: u32 = 0x100012c;
+pub const KEY_Zstroke: u32 = 0x10001b5;
+pub const KEY_Gcaron: u32 = 0x10001e6;
+pub const KEY_Ocaron: u32 = 0x10001d1;
+pub const KEY_Obarred: u32 = 0x100019f;
+pub const KEY_xabovedot: u32 = 0x1001e8b;
+pub const KEY_ibreve: u32 = 0x100012d;
+pub const KEY_zstroke: u32 = 0x10001b6;
+pub const KEY_gcaron: u32 = 0x10001e7;
+pub const KEY_ocaron: u32 = 0x10001d2;
+pub const KEY_obarred: u32 = 0x1000275;
+pub const KEY_SCHWA: u32 = 0x100018f;
+pub const KEY_schwa: u32 = 0x1000259;
+pub const KEY_EZH: u32 = 0x10001b7;
+pub const KEY_ezh: u32 = 0x1000292;
+pub const KEY_Lbelowdot: u32 = 0x1001e36;
+pub const KEY_lbelowdot: u32 = 0x1001e37;
+pub const KEY_Abelowdot: u32 = 0x1001ea0;
+pub const KEY_abelowdot: u32 = 0x1001ea1;
+pub const KEY_Ahook: u32 = 0x1001ea2;
+pub const KEY_ahook: u32 = 0x1001ea3;
+pub const KEY_Acircumflexacute: u32 = 0x1001ea4;
+pub const KEY_acircumflexacute: u32 = 0x1001ea5;
+pub const KEY_Acircumflexgrave: u32 = 0x1001ea6;
+pub const KEY_acircumflexgrave: u32 = 0x1001ea7;
+pub const KEY_Acircumflexhook: u32 = 0x1001ea8;
+pub const KEY_acircumflexhook: u32 = 0x1001ea9;
+pub const KEY_Acircumflextilde: u32 = 0x1001eaa;
+pub const KEY_acircumflextilde: u32 = 0x1001eab;
+pub const KEY_Acircumflexbelowdot: u32 = 0x1001eac;
+pub const KEY_acircumflexbelowdot: u32 = 0x1001ead;
+pub const KEY_Abreveacute: u32 = 0x1001eae;
+pub const KEY_abreveacute: u32 = 0x1001eaf;
+pub const KEY_Abrevegrave: u32 = 0x1001eb0;
+pub const KEY_abrevegrave: u32 = 0x1001eb1;
+pub const KEY_Abrevehook: u32 = 0x1001eb2;
+pub const KEY_abrevehook: u32 = 0x1001eb3;
+pub const KEY_Abrevetilde: u32 = 0x1001eb4;
+pub const KEY_abrevetilde: u32 = 0x1001eb5;
+pub const KEY_Abrevebelowdot: u32 = 0x1001eb6;
+pub const KEY_abrevebelowdot: u32 = 0x1001eb7;
+pub const KEY_Ebelowdot: u32 = 0x1001eb8;
+pub const KEY_ebelowdot: u32 = 0x1001eb9;
+pub const KEY_Ehook: u32 = 0x1001eba;
+pub const KEY_ehook: u32 = 0x1001ebb;
+pub const KEY_Etilde: u32 = 0x1001ebc;
+pub const KEY_etilde: u32 = 0x1001ebd;
+pub const KEY_Ecircumflexacute: u32 = 0x1001ebe;
+pub const KEY_ecircumflexacute: u32 = 0x1001ebf;
+pub const KEY_Ecircumflexgrave: u32 = 0x1001ec0;
+pub const KEY_ecircumflexgrave: u32 = 0x1001ec1;
+pub const KEY_Ecircumflexhook: u32 = 0x1001ec2;
+pub const KEY_ecircumflexhook: u32 = 0x1001ec3;
+pub const KEY_Ecircumflextilde: u32 = 0x1001ec4;
+pub const KEY_ecircumflextilde: u32 = 0x1001ec5;
+pub const KEY_Ecircumflexbelowdot: u32 = 0x1001ec6;
+pub const KEY_ecircumflexbelowdot: u32 = 0x1001ec7;
+pub const KEY_Ihook: u32 = 0x1001ec8;
+pub const KEY_ihook: u32 = 0x1001ec9;
+pub const KEY_Ibelowdot: u32 = 0x1001eca;
+pub const KEY_ibelowdot: u32 = 0x1001ecb;
+pub const KEY_Obelowdot: u32 = 0x1001ecc;
+pub const KEY_obelowdot: u32 = 0x1001ecd;
+pub const KEY_Ohook: u32 = 0x1001ece;
+pub const KEY_ohook: u32 = 0x1001ecf;
+pub const KEY_Ocircumflexacute: u32 = 0x1001ed0;
+pub const KEY_ocircumflexacute: u32 = 0x1001ed1;
+pub const KEY_Ocircumflexgrave: u32 = 0x1001ed2;
+pub const KEY_ocircumflexgrave: u32 = 0x1001ed3;
+pub const KEY_Ocircumflexhook: u32 = 0x1001ed4;
+pub const KEY_ocircumflexhook: u32 = 0x1001ed5;
+pub const KEY_Ocircumflextilde: u32 = 0x1001ed6;
+pub const KEY_ocircumflextilde: u32 = 0x1001ed7;
+pub const KEY_Ocircumflexbelowdot: u32 = 0x1001ed8;
+pub const KEY_ocircumflexbelowdot: u32 = 0x1001ed9;
+pub const KEY_Ohornacute: u32 = 0x1001eda;
+pub const KEY_ohornacute: u32 = 0x1001edb;
+pub const KEY_Ohorngrave: u32 = 0x1001edc;
+pub const KEY_ohorngrave: u32 = 0x1001edd;
+pub const KEY_Ohornhook: u32 = 0x1001ede;
+pub const KEY_ohornhook: u32 = 0x1001edf;
+pub const KEY_Ohorntilde: u32 = 0x1001ee0;
+pub const KEY_ohorntilde: u32 = 0x1001ee1;
+pub const KEY_Ohornbelowdot: u32 = 0x1001ee2;
+pub const KEY_ohornbelowdot: u32 = 0x1001ee3;
+pub const KEY_Ubelowdot: u32 = 0x1001ee4;
+pub const KEY_ubelowdot: u32 = 0x1001ee5;
+pub const KEY_Uhook: u32 = 0x1001ee6;
+pub const KEY_uhook: u32 = 0x1001ee7;
+pub const KEY_Uhornacute: u32 = 0x1001ee8;
+pub const KEY_uhornacute: u32 = 0x1001ee9;
+pub const KEY_Uhorngrave: u32 = 0x1001eea;
+pub const KEY_uhorngrave: u32 = 0x1001eeb;
+pub const KEY_Uhornhook: u32 = 0x1001eec;
+pub const KEY_uhornhook: u32 = 0x1001eed;
+pub const KEY_Uhorntilde: u32 = 0x1001eee;
+pub const KEY_uhorntilde: u32 = 0x1001eef;
+pub const KEY_Uhornbelowdot: u32 = 0x1001ef0;
+pub const KEY_uhornbelowdot: u32 = 0x1001ef1;
+pub const KEY_Ybelowdot: u32 = 0x1001ef4;
+pub const KEY_ybelowdot: u32 = 0x1001ef5;
+pub const KEY_Yhook: u32 = 0x1001ef6;
+pub const KEY_yhook: u32 = 0x1001ef7;
+pub const KEY_Ytilde: u32 = 0x1001ef8;
+pub const KEY_ytilde: u32 = 0x1001ef9;
+pub const KEY_Ohorn: u32 = 0x10001a0;
+pub const KEY_ohorn: u32 = 0x10001a1;
+pub const KEY_Uhorn: u32 = 0x10001af;
+pub const KEY_uhorn: u32 = 0x10001b0;
+pub const KEY_combining_tilde: u32 = 0x1000303;
+pub const KEY_combining_grave: u32 = 0x1000300;
+pub const KEY_combining_acute: u32 = 0x1000301;
+pub const KEY_combining_hook: u32 = 0x1000309;
+pub const KEY_combining_belowdot: u32 = 0x1000323;
+pub const KEY_EcuSign: u32 = 0x10020a0;
+pub const KEY_ColonSign: u32 = 0x10020a1;
+pub const KEY_CruzeiroSign: u32 = 0x10020a2;
+pub const KEY_FFrancSign: u32 = 0x10020a3;
+pub const KEY_LiraSign: u32 = 0x10020a4;
+pub const KEY_MillSign: u32 = 0x10020a5;
+pub const KEY_NairaSign: u32 = 0x10020a6;
+pub const KEY_PesetaSign: u32 = 0x10020a7;
+pub const KEY_RupeeSign: u32 = 0x10020a8;
+pub const KEY_WonSign: u32 = 0x10020a9;
+pub const KEY_NewSheqelSign: u32 = 0x10020aa;
+pub const KEY_DongSign: u32 = 0x10020ab;
+pub const KEY_EuroSign: u32 = 0x20ac;
+pub const KEY_zerosuperior: u32 = 0x1002070;
+pub const KEY_foursuperior: u32 = 0x1002074;
+pub const KEY_fivesuperior: u32 = 0x1002075;
+pub const KEY_sixsuperior: u32 = 0x1002076;
+pub const KEY_sevensuperior: u32 = 0x1002077;
+pub const KEY_eightsuperior: u32 = 0x1002078;
+pub const KEY_ninesuperior: u32 = 0x1002079;
+pub const KEY_zerosubscript: u32 = 0x1002080;
+pub const KEY_onesubscript: u32 = 0x1002081;
+pub const KEY_twosubscript: u32 = 0x1002082;
+pub const KEY_threesubscript: u32 = 0x1002083;
+pub const KEY_foursubscript: u32 = 0x1002084;
+pub const KEY_fivesubscript: u32 = 0x1002085;
+pub const KEY_sixsubscript: u32 = 0x1002086;
+pub const KEY_sevensubscript: u32 = 0x1002087;
+pub const KEY_eightsubscript: u32 = 0x1002088;
+pub const KEY_ninesubscript: u32 = 0x1002089;
+pub const KEY_partdifferential: u32 = 0x1002202;
+pub const KEY_emptyset: u32 = 0x1002205;
+pub const KEY_elementof: u32 = 0x1002208;
+pub const KEY_notelementof: u32 = 0x1002209;
+pub const KEY_containsas: u32 = 0x100220b;
+pub const KEY_squareroot: u32 = 0x100221a;
+pub const KEY_cuberoot: u32 = 0x100221b;
+pub const KEY_fourthroot: u32 = 0x100221c;
+pub const KEY_dintegral: u32 = 0x100222c;
+pub const KEY_tintegral: u32 = 0x100222d;
+pub const KEY_because: u32 = 0x1002235;
+pub const KEY_approxeq: u32 = 0x1002248;
+pub const KEY_notapproxeq: u32 = 0x1002247;
+pub const KEY_notidentical: u32 = 0x1002262;
+pub const KEY_stricteq: u32 = 0x1002263;
+pub const KEY_braille_dot_1: u32 = 0xfff1;
+pub const KEY_braille_dot_2: u32 = 0xfff2;
+pub const KEY_braille_dot_3: u32 = 0xfff3;
+pub const KEY_braille_dot_4: u32 = 0xfff4;
+pub const KEY_braille_dot_5: u32 = 0xfff5;
+pub const KEY_braille_dot_6: u32 = 0xfff6;
+pub const KEY_braille_dot_7: u32 = 0xfff7;
+pub const KEY_braille_dot_8: u32 = 0xfff8;
+pub const KEY_braille_dot_9: u32 = 0xfff9;
+pub const KEY_braille_dot_10: u32 = 0xfffa;
+pub const KEY_braille_blank: u32 = 0x1002800;
+pub const KEY_braille_dots_1: u32 = 0x1002801;
+pub const KEY_braille_dots_2: u32 = 0x1002802;
+pub const KEY_braille_dots_12: u32 = 0x1002803;
+pub const KEY_braille_dots_3: u32 = 0x1002804;
+pub const KEY_braille_dots_13: u32 = 0x1002805;
+pub const KEY_braille_dots_23: u32 = 0x1002806;
+pub const KEY_braille_dots_123: u32 = 0x1002807;
+pub const KEY_braille_dots_4: u32 = 0x1002808;
+pub const KEY_braille_dots_14: u32 = 0x1002809;
+pub const KEY_braille_dots_24: u32 = 0x100280a;
+pub const KEY_braille_dots_124: u32 = 0x100280b;
+pub const KEY_braille_dots_34: u32 = 0x100280c;
+pub const KEY_braille_dots_134: u32 = 0x100280d;
+pub const KEY_braille_dots_234: u32 = 0x100280e;
+pub const KEY_braille_dots_1234: u32 = 0x100280f;
+pub const KEY_braille_dots_5: u32 = 0x1002810;
+pub const KEY_braille_dots_15: u32 = 0x1002811;
+pub const KEY_braille_dots_25: u32 = 0x1002812;
+pub const KEY_braille_dots_125: u32 = 0x1002813;
+pub const KEY_braille_dots_35: u32 = 0x1002814;
+pub const KEY_braille_dots_135: u32 = 0x1002815;
+pub const KEY_braille_dots_235: u32 = 0x1002816;
+pub const KEY_braille_dots_1235: u32 = 0x1002817;
+pub const KEY_braille_dots_45: u32 = 0x1002818;
+pub const KEY_braille_dots_145: u32 = 0x1002819;
+pub const KEY_braille_dots_245: u32 = 0x100281a;
+pub const KEY_braille_dots_1245: u32 = 0x100281b;
+pub const KEY_braille_dots_345: u32 = 0x100281c;
+pub const KEY_braille_dots_1345: u32 = 0x100281d;
+pub const KEY_braille_dots_2345: u32 = 0x100281e;
+pub const KEY_braille_dots_12345: u32 = 0x100281f;
+pub const KEY_braille_dots_6: u32 = 0x1002820;
+pub const KEY_braille_dots_16: u32 = 0x1002821;
+pub const KEY_braille_dots_26: u32 = 0x1002822;
+pub const KEY_braille_dots_126: u32 = 0x1002823;
+pub const KEY_braille_dots_36: u32 = 0x1002824;
+pub const KEY_braille_dots_136: u32 = 0x1002825;
+pub const KEY_braille_dots_236: u32 = 0x1002826;
+pub const KEY_braille_dots_1236: u32 = 0x1002827;
+pub const KEY_braille_dots_46: u32 = 0x1002828;
+pub const KEY_braille_dots_146: u32 = 0x1002829;
+pub const KEY_braille_dots_246: u32 = 0x100282a;
+pub const KEY_braille_dots_1246: u32 = 0x100282b;
+pub const KEY_braille_dots_346: u32 = 0x100282c;
+pub const KEY_braille_dots_1346: u32 = 0x100282d;
+pub const KEY_braille_dots_2346: u32 = 0x100282e;
+pub const KEY_braille_dots_12346: u32 = 0x100282f;
+pub const KEY_braille_dots_56: u32 = 0x1002830;
+pub const KEY_braille_dots_156: u32 = 0x1002831;
+pub const KEY_braille_dots_256: u32 = 0x1002832;
+pub const KEY_braille_dots_1256: u32 = 0x1002833;
+pub const KEY_braille_dots_356: u32 = 0x1002834;
+pub const KEY_braille_dots_1356: u32 = 0x1002835;
+pub const KEY_braille_dots_2356: u32 = 0x1002836;
+pub const KEY_braille_dots_12356: u32 = 0x1002837;
+pub const KEY_braille_dots_456: u32 = 0x1002838;
+pub const KEY_braille_dots_1456: u32 = 0x1002839;
+pub const KEY_braille_dots_2456: u32 = 0x100283a;
+pub const KEY_braille_dots_12456: u32 = 0x100283b;
+pub const KEY_braille_dots_3456: u32 = 0x100283c;
+pub const KEY_braille_dots_13456: u32 = 0x100283d;
+pub const KEY_braille_dots_23456: u32 = 0x100283e;
+pub const KEY_braille_dots_123456: u32 = 0x100283f;
+pub const KEY_braille_dots_7: u32 = 0x1002840;
+pub const KEY_braille_dots_17: u32 = 0x1002841;
+pub const KEY_braille_dots_27: u32 = 0x1002842;
+pub const KEY_braille_dots_127: u32 = 0x1002843;
+pub const KEY_braille_dots_37: u32 = 0x1002844;
+pub const KEY_braille_dots_137: u32 = 0x1002845;
+pub const KEY_braille_dots_237: u32 = 0x1002846;
+pub const KEY_braille_dots_1237: u32 = 0x1002847;
+pub const KEY_braille_dots_47: u32 = 0x1002848;
+pub const KEY_braille_dots_147: u32 = 0x1002849;
+pub const KEY_braille_dots_247: u32 = 0x100284a;
+pub const KEY_braille_dots_1247: u32 = 0x100284b;
+pub const KEY_braille_dots_347: u32 = 0x100284c;
+pub const KEY_braille_dots_1347: u32 = 0x100284d;
+pub const KEY_braille_dots_2347: u32 = 0x100284e;
+pub const KEY_braille_dots_12347: u32 = 0x100284f;
+pub const KEY_braille_dots_57: u32 = 0x1002850;
+pub const KEY_braille_dots_157: u32 = 0x1002851;
+pub const KEY_braille_dots_257: u32 = 0x1002852;
+pub const KEY_braille_dots_1257: u32 = 0x1002853;
+pub const KEY_braille_dots_357: u32 = 0x1002854;
+pub const KEY_braille_dots_1357: u32 = 0x1002855;
+pub const KEY_braille_dots_2357: u32 = 0x1002856;
+pub const KEY_braille_dots_12357: u32 = 0x1002857;
+pub const KEY_braille_dots_457: u32 = 0x1002858;
+pub const KEY_braille_dots_1457: u32 = 0x1002859;
+pub const KEY_braille_dots_2457: u32 = 0x100285a;
+pub const KEY_braille_dots_12457: u32 = 0x100285b;
+pub const KEY_braille_dots_3457: u32 = 0x100285c;
+pub const KEY_braille_dots_13457: u32 = 0x100285d;
+pub const KEY_braille_dots_23457: u32 = 0x100285e;
+pub const KEY_braille_dots_123457: u32 = 0x100285f;
+pub const KEY_braille_dots_67: u32 = 0x1002860;
+pub const KEY_braille_dots_167: u32 = 0x1002861;
+pub const KEY_braille_dots_267: u32 = 0x1002862;
+pub const KEY_braille_dots_1267: u32 = 0x1002863;
+pub const KEY_braille_dots_367: u32 = 0x1002864;
+pub const KEY_braille_dots_1367: u32 = 0x1002865;
+pub const KEY_braille_dots_2367: u32 = 0x1002866;
+pub const KEY_braille_dots_12367: u32 = 0x1002867;
+pub const KEY_braille_dots_467: u32 = 0x1002868;
+pub const KEY_braille_dots_1467: u32 = 0x1002869;
+pub const KEY_braille_dots_2467: u32 = 0x100286a;
+pub const KEY_braille_dots_12467: u32 = 0x100286b;
+pub const KEY_braille_dots_3467: u32 = 0x100286c;
+pub const KEY_braille_dots_13467: u32 = 0x100286d;
+pub const KEY_braille_dots_23467: u32 = 0x100286e;
+pub const KEY_braille_dots_123467: u32 = 0x100286f;
+pub const KEY_braille_dots_567: u32 = 0x1002870;
+pub const KEY_braille_dots_1567: u32 = 0x1002871;
+pub const KEY_braille_dots_2567: u32 = 0x1002872;
+pub const KEY_braille_dots_12567: u32 = 0x1002873;
+pub const KEY_braille_dots_3567: u32 = 0x1002874;
+pub const KEY_braille_dots_13567: u32 = 0x1002875;
+pub const KEY_braille_dots_23567: u32 = 0x1002876;
+pub const KEY_braille_dots_123567: u32 = 0x1002877;
+pub const KEY_braille_dots_4567: u32 = 0x1002878;
+pub const KEY_braille_dots_14567: u32 = 0x1002879;
+pub const KEY_braille_dots_24567: u32 = 0x100287a;
+pub const KEY_braille_dots_124567: u32 = 0x100287b;
+pub const KEY_braille_dots_34567: u32 = 0x100287c;
+pub const KEY_braille_dots_134567: u32 = 0x100287d;
+pub const KEY_braille_dots_234567: u32 = 0x100287e;
+pub const KEY_braille_dots_1234567: u32 = 0x100287f;
+pub const KEY_braille_dots_8: u32 = 0x1002880;
+pub const KEY_braille_dots_18: u32 = 0x1002881;
+pub const KEY_braille_dots_28: u32 = 0x1002882;
+pub const KEY_braille_dots_128: u32 = 0x1002883;
+pub const KEY_braille_dots_38: u32 = 0x1002884;
+pub const KEY_braille_dots_138: u32 = 0x1002885;
+pub const KEY_braille_dots_238: u32 = 0x1002886;
+pub const KEY_braille_dots_1238: u32 = 0x1002887;
+pub const KEY_braille_dots_48: u32 = 0x1002888;
+pub const KEY_braille_dots_148: u32 = 0x1002889;
+pub const KEY_braille_dots_248: u32 = 0x100288a;
+pub const KEY_braille_dots_1248: u32 = 0x100288b;
+pub const KEY_braille_dots_348: u32 = 0x100288c;
+pub const KEY_braille_dots_1348: u32 = 0x100288d;
+pub const KEY_braille_dots_2348: u32 = 0x100288e;
+pub const KEY_braille_dots_12348: u32 = 0x100288f;
+pub const KEY_braille_dots_58: u32 = 0x1002890;
+pub const KEY_braille_dots_158: u32 = 0x1002891;
+pub const KEY_braille_dots_258: u32 = 0x1002892;
+pub const KEY_braille_dots_1258: u32 = 0x1002893;
+pub const KEY_braille_dots_358: u32 = 0x1002894;
+pub const KEY_braille_dots_1358: u32 = 0x1002895;
+pub const KEY_braille_dots_2358: u32 = 0x1002896;
+pub const KEY_braille_dots_12358: u32 = 0x1002897;
+pub const KEY_braille_dots_458: u32 = 0x1002898;
+pub const KEY_braille_dots_1458: u32 = 0x1002899;
+pub const KEY_braille_dots_2458: u32 = 0x100289a;
+pub const KEY_braille_dots_12458: u32 = 0x100289b;
+pub const KEY_braille_dots_3458: u32 = 0x100289c;
+pub const KEY_braille_dots_13458: u32 = 0x100289d;
+pub const KEY_braille_dots_23458: u32 = 0x100289e;
+pub const KEY_braille_dots_123458: u32 = 0x100289f;
+pub const KEY_braille_dots_68: u32 = 0x10028a0;
+pub const KEY_braille_dots_168: u32 = 0x10028a1;
+pub const KEY_braille_dots_268: u32 = 0x10028a2;
+pub const KEY_braille_dots_1268: u32 = 0x10028a3;
+pub const KEY_braille_dots_368: u32 = 0x10028a4;
+pub const KEY_braille_dots_1368: u32 = 0x10028a5;
+pub const KEY_braille_dots_2368: u32 = 0x10028a6;
+pub const KEY_braille_dots_12368: u32 = 0x10028a7;
+pub const KEY_braille_dots_468: u32 = 0x10028a8;
+pub const KEY_braille_dots_1468: u32 = 0x10028a9;
+pub const KEY_braille_dots_2468: u32 = 0x10028aa;
+pub const KEY_braille_dots_12468: u32 = 0x10028ab;
+pub const KEY_braille_dots_3468: u32 = 0x10028ac;
+pub const KEY_braille_dots_13468: u32 = 0x10028ad;
+pub const KEY_braille_dots_23468: u32 = 0x10028ae;
+pub const KEY_braille_dots_123468: u32 = 0x10028af;
+pub const KEY_braille_dots_568: u32 = 0x10028b0;
+pub const KEY_braille_dots_1568: u32 = 0x10028b1;
+pub const KEY_braille_dots_2568: u32 = 0x10028b2;
+pub const KEY_braille_dots_12568: u32 = 0x10028b3;
+pub const KEY_braille_dots_3568: u32 = 0x10028b4;
+pub const KEY_braille_dots_13568: u32 = 0x10028b5;
+pub const KEY_braille_dots_23568: u32 = 0x10028b6;
+pub const KEY_braille_dots_123568: u32 = 0x10028b7;
+pub const KEY_braille_dots_4568: u32 = 0x10028b8;
+pub const KEY_braille_dots_14568: u32 = 0x10028b9;
+pub const KEY_braille_dots_24568: u32 = 0x10028ba;
+pub const KEY_braille_dots_124568: u32 = 0x10028bb;
+pub const KEY_braille_dots_34568: u32 = 0x10028bc;
+pub const KEY_braille_dots_134568: u32 = 0x10028bd;
+pub const KEY_braille_dots_234568: u32 = 0x10028be;
+pub const KEY_braille_dots_1234568: u32 = 0x10028bf;
+pub const KEY_braille_dots_78: u32 = 0x10028c0;
+pub const KEY_braille_dots_178: u32 = 0x10028c1;
+pub const KEY_braille_dots_278: u32 = 0x10028c2;
+pub const KEY_braille_dots_1278: u32 = 0x10028c3;
+pub const KEY_braille_dots_378: u32 = 0x10028c4;
+pub const KEY_braille_dots_1378: u32 = 0x10028c5;
+pub const KEY_braille_dots_2378: u32 = 0x10028c6;
+pub const KEY_braille_dots_12378: u32 = 0x10028c7;
+pub const KEY_braille_dots_478: u32 = 0x10028c8;
+pub const KEY_braille_dots_1478: u32 = 0x10028c9;
+pub const KEY_braille_dots_2478: u32 = 0x10028ca;
+pub const KEY_braille_dots_12478: u32 = 0x10028cb;
+pub const KEY_braille_dots_3478: u32 = 0x10028cc;
+pub const KEY_braille_dots_13478: u32 = 0x10028cd;
+pub const KEY_braille_dots_23478: u32 = 0x10028ce;
+pub const KEY_braille_dots_123478: u32 = 0x10028cf;
+pub const KEY_braille_dots_578: u32 = 0x10028d0;
+pub const KEY_braille_dots_1578: u32 = 0x10028d1;
+pub const KEY_braille_dots_2578: u32 = 0x10028d2;
+pub const KEY_braille_dots_12578: u32 = 0x10028d3;
+pub const KEY_braille_dots_3578: u32 = 0x10028d4;
+pub const KEY_braille_dots_13578: u32 = 0x10028d5;
+pub const KEY_braille_dots_23578: u32 = 0x10028d6;
+pub const KEY_braille_dots_123578: u32 = 0x10028d7;
+pub const KEY_braille_dots_4578: u32 = 0x10028d8;
+pub const KEY_braille_dots_14578: u32 = 0x10028d9;
+pub const KEY_braille_dots_24578: u32 = 0x10028da;
+pub const KEY_braille_dots_124578: u32 = 0x10028db;
+pub const KEY_braille_dots_34578: u32 = 0x10028dc;
+pub const KEY_braille_dots_134578: u32 = 0x10028dd;
+pub const KEY_braille_dots_234578: u32 = 0x10028de;
+pub const KEY_braille_dots_1234578: u32 = 0x10028df;
+pub const KEY_braille_dots_678: u32 = 0x10028e0;
+pub const KEY_braille_dots_1678: u32 = 0x10028e1;
+pub const KEY_braille_dots_2678: u32 = 0x10028e2;
+pub const KEY_braille_dots_12678: u32 = 0x10028e3;
+pub const KEY_braille_dots_3678: u32 = 0x10028e4;
+pub const KEY_braille_dots_13678: u32 = 0x10028e5;
+pub const KEY_braille_dots_23678: u32 = 0x10028e6;
+pub const KEY_braille_dots_123678: u32 = 0x10028e7;
+pub const KEY_braille_dots_4678: u32 = 0x10028e8;
+pub const KEY_braille_dots_14678: u32 = 0x10028e9;
+pub const KEY_braille_dots_24678: u32 = 0x10028ea;
+pub const KEY_braille_dots_124678: u32 = 0x10028eb;
+pub const KEY_braille_dots_34678: u32 = 0x10028ec;
+pub const KEY_braille_dots_134678: u32 = 0x10028ed;
+pub const KEY_braille_dots_234678: u32 = 0x10028ee;
+pub const KEY_braille_dots_1234678: u32 = 0x10028ef;
+pub const KEY_braille_dots_5678: u32 = 0x10028f0;
+pub const KEY_braille_dots_15678: u32 = 0x10028f1;
+pub const KEY_braille_dots_25678: u32 = 0x10028f2;
+pub const KEY_braille_dots_125678: u32 = 0x10028f3;
+pub const KEY_braille_dots_35678: u32 = 0x10028f4;
+pub const KEY_braille_dots_135678: u32 = 0x10028f5;
+pub const KEY_braille_dots_235678: u32 = 0x10028f6;
+pub const KEY_braille_dots_1235678: u32 = 0x10028f7;
+pub const KEY_braille_dots_45678: u32 = 0x10028f8;
+pub const KEY_braille_dots_145678: u32 = 0x10028f9;
+pub const KEY_braille_dots_245678: u32 = 0x10028fa;
+pub const KEY_braille_dots_1245678: u32 = 0x10028fb;
+pub const KEY_braille_dots_345678: u32 = 0x10028fc;
+pub const KEY_braille_dots_1345678: u32 = 0x10028fd;
+pub const KEY_braille_dots_2345678: u32 = 0x10028fe;
+pub const KEY_braille_dots_12345678: u32 = 0x10028ff;
+pub const KEY_Sinh_ng: u32 = 0x1000d82;
+pub const KEY_Sinh_h2: u32 = 0x1000d83;
+pub const KEY_Sinh_a: u32 = 0x1000d85;
+pub const KEY_Sinh_aa: u32 = 0x1000d86;
+pub const KEY_Sinh_ae: u32 = 0x1000d87;
+pub const KEY_Sinh_aee: u32 = 0x1000d88;
+pub const KEY_Sinh_i: u32 = 0x1000d89;
+pub const KEY_Sinh_ii: u32 = 0x1000d8a;
+pub const KEY_Sinh_u: u32 = 0x1000d8b;
+pub const KEY_Sinh_uu: u32 = 0x1000d8c;
+pub const KEY_Sinh_ri: u32 = 0x1000d8d;
+pub const KEY_Sinh_rii: u32 = 0x1000d8e;
+pub const KEY_Sinh_lu: u32 = 0x1000d8f;
+pub const KEY_Sinh_luu: u32 = 0x1000d90;
+pub const KEY_Sinh_e: u32 = 0x1000d91;
+pub const KEY_Sinh_ee: u32 = 0x1000d92;
+pub const KEY_Sinh_ai: u32 = 0x1000d93;
+pub const KEY_Sinh_o: u32 = 0x1000d94;
+pub const KEY_Sinh_oo: u32 = 0x1000d95;
+pub const KEY_Sinh_au: u32 = 0x1000d96;
+pub const KEY_Sinh_ka: u32 = 0x1000d9a;
+pub const KEY_Sinh_kha: u32 = 0x1000d9b;
+pub const KEY_Sinh_ga: u32 = 0x1000d9c;
+pub const KEY_Sinh_gha: u32 = 0x1000d9d;
+pub const KEY_Sinh_ng2: u32 = 0x1000d9e;
+pub const KEY_Sinh_nga: u32 = 0x1000d9f;
+pub const KEY_Sinh_ca: u32 = 0x1000da0;
+pub const KEY_Sinh_cha: u32 = 0x1000da1;
+pub const KEY_Sinh_ja: u32 = 0x1000da2;
+pub const KEY_Sinh_jha: u32 = 0x1000da3;
+pub const KEY_Sinh_nya: u32 = 0x1000da4;
+pub const KEY_Sinh_jnya: u32 = 0x1000da5;
+pub const KEY_Sinh_nja: u32 = 0x1000da6;
+pub const KEY_Sinh_tta: u32 = 0x1000da7;
+pub const KEY_Sinh_ttha: u32 = 0x1000da8;
+pub const KEY_Sinh_dda: u32 = 0x1000da9;
+pub const KEY_Sinh_ddha: u32 = 0x1000daa;
+pub const KEY_Sinh_nna: u32 = 0x1000dab;
+pub const KEY_Sinh_ndda: u32 = 0x1000dac;
+pub const KEY_Sinh_tha: u32 = 0x1000dad;
+pub const KEY_Sinh_thha: u32 = 0x1000dae;
+pub const KEY_Sinh_dha: u32 = 0x1000daf;
+pub const KEY_Sinh_dhha: u32 = 0x1000db0;
+pub const KEY_Sinh_na: u32 = 0x1000db1;
+pub const KEY_Sinh_ndha: u32 = 0x1000db3;
+pub const KEY_Sinh_pa: u32 = 0x1000db4;
+pub const KEY_Sinh_pha: u32 = 0x1000db5;
+pub const KEY_Sinh_ba: u32 = 0x1000db6;
+pub const KEY_Sinh_bha: u32 = 0x1000db7;
+pub const KEY_Sinh_ma: u32 = 0x1000db8;
+pub const KEY_Sinh_mba: u32 = 0x1000db9;
+pub const KEY_Sinh_ya: u32 = 0x1000dba;
+pub const KEY_Sinh_ra: u32 = 0x1000dbb;
+pub const KEY_Sinh_la: u32 = 0x1000dbd;
+pub const KEY_Sinh_va: u32 = 0x1000dc0;
+pub const KEY_Sinh_sha: u32 = 0x1000dc1;
+pub const KEY_Sinh_ssha: u32 = 0x1000dc2;
+pub const KEY_Sinh_sa: u32 = 0x1000dc3;
+pub const KEY_Sinh_ha: u32 = 0x1000dc4;
+pub const KEY_Sinh_lla: u32 = 0x1000dc5;
+pub const KEY_Sinh_fa: u32 = 0x1000dc6;
+pub const KEY_Sinh_al: u32 = 0x1000dca;
+pub const KEY_Sinh_aa2: u32 = 0x1000dcf;
+pub const KEY_Sinh_ae2: u32 = 0x1000dd0;
+pub const KEY_Sinh_aee2: u32 = 0x1000dd1;
+pub const KEY_Sinh_i2: u32 = 0x1000dd2;
+pub const KEY_Sinh_ii2: u32 = 0x1000dd3;
+pub const KEY_Sinh_u2: u32 = 0x1000dd4;
+pub const KEY_Sinh_uu2: u32 = 0x1000dd6;
+pub const KEY_Sinh_ru2: u32 = 0x1000dd8;
+pub const KEY_Sinh_e2: u32 = 0x1000dd9;
+pub const KEY_Sinh_ee2: u32 = 0x1000dda;
+pub const KEY_Sinh_ai2: u32 = 0x1000ddb;
+pub const KEY_Sinh_o2: u32 = 0x1000ddc;
+pub const KEY_Sinh_oo2: u32 = 0x1000ddd;
+pub const KEY_Sinh_au2: u32 = 0x1000dde;
+pub const KEY_Sinh_lu2: u32 = 0x1000ddf;
+pub const KEY_Sinh_ruu2: u32 = 0x1000df2;
+pub const KEY_Sinh_luu2: u32 = 0x1000df3;
+pub const KEY_Sinh_kunddaliya: u32 = 0x1000df4;
+
+/// Sorted by name
+pub(crate) const BY_NAME: &[(&str, u32)] = &[
+    ("0", KEY_0),
+    ("1", KEY_1),
+    ("2", KEY_2),
+    ("3", KEY_3),
+    ("3270_AltCursor", KEY_3270_AltCursor),
+    ("3270_Attn", KEY_3270_Attn),
+    ("3270_BackTab", KEY_3270_BackTab),
+    ("3270_ChangeScreen", KEY_3270_ChangeScreen),
+    ("3270_Copy", KEY_3270_Copy),
+    ("3270_CursorBlink", KEY_3270_CursorBlink),
+    ("3270_CursorSelect", KEY_3270_CursorSelect),
+    ("3270_DeleteWord", KEY_3270_DeleteWord),
+    ("3270_Duplicate", KEY_3270_Duplicate),
+    ("3270_Enter", KEY_3270_Enter),
+    ("3270_EraseEOF", KEY_3270_EraseEOF),
+    ("3270_EraseInput", KEY_3270_EraseInput),
+    ("3270_ExSelect", KEY_3270_ExSelect),
+    ("3270_FieldMark", KEY_3270_FieldMark),
+    ("3270_Ident", KEY_3270_Ident),
+    ("3270_Jump", KEY_3270_Jump),
+    ("3270_KeyClick", KEY_3270_KeyClick),
+    ("3270_Left2", KEY_3270_Left2),
+    ("3270_PA1", KEY_3270_PA1),
+    ("3270_PA2", KEY_3270_PA2),
+    ("3270_PA3", KEY_3270_PA3),
+    ("3270_Play", KEY_3270_Play),
+    ("3270_PrintScreen", KEY_3270_PrintScreen),
+    ("3270_Quit", KEY_3270_Quit),
+    ("3270_Record", KEY_3270_Record),
+    ("3270_Reset", KEY_3270_Reset),
+    ("3270_Right2", KEY_3270_Right2),
+    ("3270_Rule", KEY_3270_Rule),
+    ("3270_Setup", KEY_3270_Setup),
+    ("3270_Test", KEY_3270_Test),
+    ("4", KEY_4),
+    ("5", KEY_5),
+    ("6", KEY_6),
+    ("7", KEY_7),
+    ("8", KEY_8),
+    ("9", KEY_9),
+    ("A", KEY_A),
+    ("AE", KEY_AE),
+    ("Aacute", KEY_Aacute),
+    ("Abelowdot", KEY_Abelowdot),
+    ("Abreve", KEY_Abreve),
+    ("Abreveacute", KEY_Abreveacute),
+    ("Abrevebelowdot", KEY_Abrevebelowdot),
+    ("Abrevegrave", KEY_Abrevegrave),
+    ("Abrevehook", KEY_Abrevehook),
+    ("Abrevetilde", KEY_Abrevetilde),
+    ("AccessX_Enable", KEY_AccessX_Enable),
+    ("AccessX_Feedback_Enable", KEY_AccessX_Feedback_Enable),
+    ("Acircumflex", KEY_Acircumflex),
+    ("Acircumflexacute", KEY_Acircumflexacute),
+    ("Acircumflexbelowdot", KEY_Acircumflexbelowdot),
+    ("Acircumflexgrave", KEY_Acircumflexgrave),
+    ("Acircumflexhook", KEY_Acircumflexhook),
+    ("Acircumflextilde", KEY_Acircumflextilde),
+    ("Adiaeresis", KEY_Adiaeresis),
+    ("Agrave", KEY_Agrave),
+    ("Ahook", KEY_Ahook),
+    ("Alt_L", KEY_Alt_L),
+    ("Alt_R", KEY_Alt_R),
+    ("Amacron", KEY_Amacron),
+    ("Aogonek", KEY_Aogonek),
+    ("Arabic_0", KEY_Arabic_0),
+    ("Arabic_1", KEY_Arabic_1),
+    ("Arabic_2", KEY_Arabic_2),
+    ("Arabic_3", KEY_Arabic_3),
+    ("Arabic_4", KEY_Arabic_4),
+    ("Arabic_5", KEY_Arabic_5),
+    ("Arabic_6", KEY_Arabic_6),
+    ("Arabic_7", KEY_Arabic_7),
+    ("Arabic_8", KEY_Arabic_8),
+    ("Arabic_9", KEY_Arabic_9),
+    ("Arabic_ain", KEY_Arabic_ain),
+    ("Arabic_alef", KEY_Arabic_alef),
+    ("Arabic_alefmaksura", KEY_Arabic_alefmaksura),
+    ("Arabic_beh", KEY_Arabic_beh),
+    ("Arabic_comma", KEY_Arabic_comma),
+    ("Arabic_dad", KEY_Arabic_dad),
+    ("Arabic_dal", KEY_Arabic_dal),
+    ("Arabic_damma", KEY_Arabic_damma),
+    ("Arabic_dammatan", KEY_Arabic_dammatan),
+    ("Arabic_ddal", KEY_Arabic_ddal),
+    ("Arabic_farsi_yeh", KEY_Arabic_farsi_yeh),
+    ("Arabic_fatha", KEY_Arabic_fatha),
+    ("Arabic_fathatan", KEY_Arabic_fathatan),
+    ("Arabic_feh", KEY_Arabic_feh),
+    ("Arabic_fullstop", KEY_Arabic_fullstop),
+    ("Arabic_gaf", KEY_Arabic_gaf),
+    ("Arabic_ghain", KEY_Arabic_ghain),
+    ("Arabic_ha", KEY_Arabic_ha),
+    ("Arabic_hah", KEY_Arabic_hah),
+    ("Arabic_hamza", KEY_Arabic_hamza),
+    ("Arabic_hamza_above", KEY_Arabic_hamza_above),
+    ("Arabic_hamza_below", KEY_Arabic_hamza_below),
+    ("Arabic_hamzaonalef", KEY_Arabic_hamzaonalef),
+    ("Arabic_hamzaonwaw", KEY_Arabic_hamzaonwaw),
+    ("Arabic_hamzaonyeh", KEY_Arabic_hamzaonyeh),
+    ("Arabic_hamzaunderalef", KEY_Arabic_hamzaunderalef),
+    ("Arabic_heh", KEY_Arabic_heh),
+    ("Arabic_heh_doachashmee", KEY_Arabic_heh_doachashmee),
+    ("Arabic_heh_goal", KEY_Arabic_heh_goal),
+    ("Arabic_jeem", KEY_Arabic_jeem),
+    ("Arabic_jeh", KEY_Arabic_jeh),
+    ("Arabic_kaf", KEY_Arabic_kaf),
+    ("Arabic_kasra", KEY_Arabic_kasra),
+    ("Arabic_kasratan", KEY_Arabic_kasratan),
+    ("Arabic_keheh", KEY_Arabic_keheh),
+    ("Arabic_khah", KEY_Arabic_khah),
+    ("Arabic_lam", KEY_Arabic_lam),
+    ("Arabic_madda_above", KEY_Arabic_madda_above),
+    ("Arabic_maddaonalef", KEY_Arabic_maddaonalef),
+    ("Arabic_meem", KEY_Arabic_meem),
+    ("Arabic_noon", KEY_Arabic_noon),
+    ("Arabic_noon_ghunna", KEY_Arabic_noon_ghunna),
+    ("Arabic_peh", KEY_Arabic_peh),
+    ("Arabic_percent", KEY_Arabic_percent),
+    ("Arabic_qaf", KEY_Arabic_qaf),
+    ("Arabic_question_mark", KEY_Arabic_question_mark),
+    ("Arabic_ra", KEY_Arabic_ra),
+    ("Arabic_rreh", KEY_Arabic_rreh),
+    ("Arabic_sad", KEY_Arabic_sad),
+    ("Arabic_seen", KEY_Arabic_seen),
+    ("Arabic_semicolon", KEY_Arabic_semicolon),
+    ("Arabic_shadda", KEY_Arabic_shadda),
+    ("Arabic_sheen", KEY_Arabic_sheen),
+    ("Arabic_sukun", KEY_Arabic_sukun),
+    ("Arabic_superscript_alef", KEY_Arabic_superscript_alef),
+    ("Arabic_switch", KEY_Arabic_switch),
+    ("Arabic_tah", KEY_Arabic_tah),
+    ("Arabic_tatweel", KEY_Arabic_tatweel),
+    ("Arabic_tcheh", KEY_Arabic_tcheh),
+    ("Arabic_teh", KEY_Arabic_teh),
+    ("Arabic_tehmarbuta", KEY_Arabic_tehmarbuta),
+    ("Arabic_thal", KEY_Arabic_thal),
+    ("Arabic_theh", KEY_Arabic_theh),
+    ("Arabic_tteh", KEY_Arabic_tteh),
+    ("Arabic_veh", KEY_Arabic_veh),
+    ("Arabic_waw", KEY_Arabic_waw),
+    ("Arabic_yeh", KEY_Arabic_yeh),
+    ("Arabic_yeh_baree", KEY_Arabic_yeh_baree),
+    ("Arabic_zah", KEY_Arabic_zah),
+    ("Arabic_zain", KEY_Arabic_zain),
+    ("Aring", KEY_Aring),
+    ("Armenian_AT", KEY_Armenian_AT),
+    ("Armenian_AYB", KEY_Armenian_AYB),
+    ("Armenian_BEN", KEY_Armenian_BEN),
+    ("Armenian_CHA", KEY_Armenian_CHA),
+    ("Armenian_DA", KEY_Armenian_DA),
+    ("Armenian_DZA", KEY_Armenian_DZA),
+    ("Armenian_E", KEY_Armenian_E),
+    ("Armenian_FE", KEY_Armenian_FE),
+    ("Armenian_GHAT", KEY_Armenian_GHAT),
+    ("Armenian_GIM", KEY_Armenian_GIM),
+    ("Armenian_HI", KEY_Armenian_HI),
+    ("Armenian_HO", KEY_Armenian_HO),
+    ("Armenian_INI", KEY_Armenian_INI),
+    ("Armenian_JE", KEY_Armenian_JE),
+    ("Armenian_KE", KEY_Armenian_KE),
+    ("Armenian_KEN", KEY_Armenian_KEN),
+    ("Armenian_KHE", KEY_Armenian_KHE),
+    ("Armenian_LYUN", KEY_Armenian_LYUN),
+    ("Armenian_MEN", KEY_Armenian_MEN),
+    ("Armenian_NU", KEY_Armenian_NU),
+    ("Armenian_O", KEY_Armenian_O),
+    ("Armenian_PE", KEY_Armenian_PE),
+    ("Armenian_PYUR", KEY_Armenian_PYUR),
+    ("Armenian_RA", KEY_Armenian_RA),
+    ("Armenian_RE", KEY_Armenian_RE),
+    ("Armenian_SE", KEY_Armenian_SE),
+    ("Armenian_SHA", KEY_Armenian_SHA),
+    ("Armenian_TCHE", KEY_Armenian_TCHE),
+    ("Armenian_TO", KEY_Armenian_TO),
+    ("Armenian_TSA", KEY_Armenian_TSA),
+    ("Armenian_TSO", KEY_Armenian_TSO),
+    ("Armenian_TYUN", KEY_Armenian_TYUN),
+    ("Armenian_VEV", KEY_Armenian_VEV),
+    ("Armenian_VO", KEY_Armenian_VO),
+    ("Armenian_VYUN", KEY_Armenian_VYUN),
+    ("Armenian_YECH", KEY_Armenian_YECH),
+    ("Armenian_ZA", KEY_Armenian_ZA),
+    ("Armenian_ZHE", KEY_Armenian_ZHE),
+    ("Armenian_accent", KEY_Armenian_accent),
+    ("Armenian_amanak", KEY_Armenian_amanak),
+    ("Armenian_apostrophe", KEY_Armenian_apostrophe),
+    ("Armenian_at", KEY_Armenian_at),
+    ("Armenian_ayb", KEY_Armenian_ayb),
+    ("Armenian_ben", KEY_Armenian_ben),
+    ("Armenian_but", KEY_Armenian_but),
+    ("Armenian_cha", KEY_Armenian_cha),
+    ("Armenian_da", KEY_Armenian_da),
+    ("Armenian_dza", KEY_Armenian_dza),
+    ("Armenian_e", KEY_Armenian_e),
+    ("Armenian_exclam", KEY_Armenian_exclam),
+    ("Armenian_fe", KEY_Armenian_fe),
+    ("Armenian_full_stop", KEY_Armenian_full_stop),
+    ("Armenian_ghat", KEY_Armenian_ghat),
+    ("Armenian_gim", KEY_Armenian_gim),
+    ("Armenian_hi", KEY_Armenian_hi),
+    ("Armenian_ho", KEY_Armenian_ho),
+    ("Armenian_hyphen", KEY_Armenian_hyphen),
+    ("Armenian_ini", KEY_Armenian_ini),
+    ("Armenian_je", KEY_Armenian_je),
+    ("Armenian_ke", KEY_Armenian_ke),
+    ("Armenian_ken", KEY_Armenian_ken),
+    ("Armenian_khe", KEY_Armenian_khe),
+    ("Armenian_ligature_ew", KEY_Armenian_ligature_ew),
+    ("Armenian_lyun", KEY_Armenian_lyun),
+    ("Armenian_men", KEY_Armenian_men),
+    ("Armenian_nu", KEY_Armenian_nu),
+    ("Armenian_o", KEY_Armenian_o),
+    ("Armenian_paruyk", KEY_Armenian_paruyk),
+    ("Armenian_pe", KEY_Armenian_pe),
+    ("Armenian_pyur", KEY_Armenian_pyur),
+    ("Armenian_question", KEY_Armenian_question),
+    ("Armenian_ra", KEY_Armenian_ra),
+    ("Armenian_re", KEY_Armenian_re),
+    ("Armenian_se", KEY_Armenian_se),
+    ("Armenian_separation_mark", KEY_Armenian_separation_mark),
+    ("Armenian_sha", KEY_Armenian_sha),
+    ("Armenian_shesht", KEY_Armenian_shesht),
+    ("Armenian_tche", KEY_Armenian_tche),
+    ("Armenian_to", KEY_Armenian_to),
+    ("Armenian_tsa", KEY_Armenian_tsa),
+    ("Armenian_tso", KEY_Armenian_tso),
+    ("Armenian_tyun", KEY_Armenian_tyun),
+    ("Armenian_verjaket", KEY_Armenian_verjaket),
+    ("Armenian_vev", KEY_Armenian_vev),
+    ("Armenian_vo", KEY_Armenian_vo),
+    ("Armenian_vyun", KEY_Armenian_vyun),
+    ("Armenian_yech", KEY_Armenian_yech),
+    ("Armenian_yentamna", KEY_Armenian_yentamna),
+    ("Armenian_za", KEY_Armenian_za),
+    ("Armenian_zhe", KEY_Armenian_zhe),
+    ("Atilde", KEY_Atilde),
+    ("AudibleBell_Enable", KEY_AudibleBell_Enable),
+    ("B", KEY_B),
+    ("Babovedot", KEY_Babovedot),
+    ("BackSpace", KEY_BackSpace),
+    ("Begin", KEY_Begin),
+    ("BounceKeys_Enable", KEY_BounceKeys_Enable),
+    ("Break", KEY_Break),
+    ("Byelorussian_SHORTU", KEY_Byelorussian_SHORTU),
+    ("Byelorussian_shortu", KEY_Byelorussian_shortu),
+    ("C", KEY_C),
+    ("CH", KEY_CH),
+    ("C_H", KEY_C_H),
+    ("C_h", KEY_C_h),
+    ("Cabovedot", KEY_Cabovedot),
+    ("Cacute", KEY_Cacute),
+    ("Cancel", KEY_Cancel),
+    ("Caps_Lock", KEY_Caps_Lock),
+    ("Ccaron", KEY_Ccaron),
+    ("Ccedilla", KEY_Ccedilla),
+    ("Ccircumflex", KEY_Ccircumflex),
+    ("Ch", KEY_Ch),
+    ("Clear", KEY_Clear),
+    ("Codeinput", KEY_Codeinput),
+    ("ColonSign", KEY_ColonSign),
+    ("Control_L", KEY_Control_L),
+    ("Control_R", KEY_Control_R),
+    ("CruzeiroSign", KEY_CruzeiroSign),
+    ("Cyrillic_A", KEY_Cyrillic_A),
+    ("Cyrillic_BE", KEY_Cyrillic_BE),
+    ("Cyrillic_CHE", KEY_Cyrillic_CHE),
+    ("Cyrillic_CHE_descender", KEY_Cyrillic_CHE_descender),
+    ("Cyrillic_CHE_vertstroke", KEY_Cyrillic_CHE_vertstroke),
+    ("Cyrillic_DE", KEY_Cyrillic_DE),
+    ("Cyrillic_DZHE", KEY_Cyrillic_DZHE),
+    ("Cyrillic_E", KEY_Cyrillic_E),
+    ("Cyrillic_EF", KEY_Cyrillic_EF),
+    ("Cyrillic_EL", KEY_Cyrillic_EL),
+    ("Cyrillic_EM", KEY_Cyrillic_EM),
+    ("Cyrillic_EN", KEY_Cyrillic_EN),
+    ("Cyrillic_EN_descender", KEY_Cyrillic_EN_descender),
+    ("Cyrillic_ER", KEY_Cyrillic_ER),
+    ("Cyrillic_ES", KEY_Cyrillic_ES),
+    ("Cyrillic_GHE", KEY_Cyrillic_GHE),
+    ("Cyrillic_GHE_bar", KEY_Cyrillic_GHE_bar),
+    ("Cyrillic_HA", KEY_Cyrillic_HA),
+    ("Cyrillic_HARDSIGN", KEY_Cyrillic_HARDSIGN),
+    ("Cyrillic_HA_descender", KEY_Cyrillic_HA_descender),
+    ("Cyrillic_I", KEY_Cyrillic_I),
+    ("Cyrillic_IE", KEY_Cyrillic_IE),
+    ("Cyrillic_IO", KEY_Cyrillic_IO),
+    ("Cyrillic_I_macron", KEY_Cyrillic_I_macron),
+    ("Cyrillic_JE", KEY_Cyrillic_JE),
+    ("Cyrillic_KA", KEY_Cyrillic_KA),
+    ("Cyrillic_KA_descender", KEY_Cyrillic_KA_descender),
+    ("Cyrillic_KA_vertstroke", KEY_Cyrillic_KA_vertstroke),
+    ("Cyrillic_LJE", KEY_Cyrillic_LJE),
+    ("Cyrillic_NJE", KEY_Cyrillic_NJE),
+    ("Cyrillic_O", KEY_Cyrillic_O),
+    ("Cyrillic_O_bar", KEY_Cyrillic_O_bar),
+    ("Cyrillic_PE", KEY_Cyrillic_PE),
+    ("Cyrillic_SCHWA", KEY_Cyrillic_SCHWA),
+    ("Cyrillic_SHA", KEY_Cyrillic_SHA),
+    ("Cyrillic_SHCHA", KEY_Cyrillic_SHCHA),
+    ("Cyrillic_SHHA", KEY_Cyrillic_SHHA),
+    ("Cyrillic_SHORTI", KEY_Cyrillic_SHORTI),
+    ("Cyrillic_SOFTSIGN", KEY_Cyrillic_SOFTSIGN),
+    ("Cyrillic_TE", KEY_Cyrillic_TE),
+    ("Cyrillic_TSE", KEY_Cyrillic_TSE),
+    ("Cyrillic_U", KEY_Cyrillic_U),
+    ("Cyrillic_U_macron", KEY_Cyrillic_U_macron),
+    ("Cyrillic_U_straight", KEY_Cyrillic_U_straight),
+    ("Cyrillic_U_straight_bar", KEY_Cyrillic_U_straight_bar),
+    ("Cyrillic_VE", KEY_Cyrillic_VE),
+    ("Cyrillic_YA", KEY_Cyrillic_YA),
+    ("Cyrillic_YERU", KEY_Cyrillic_YERU),
+    ("Cyrillic_YU", KEY_Cyrillic_YU),
+    ("Cyrillic_ZE", KEY_Cyrillic_ZE),
+    ("Cyrillic_ZHE", KEY_Cyrillic_ZHE),
+    ("Cyrillic_ZHE_descender", KEY_Cyrillic_ZHE_descender),
+    ("Cyrillic_a", KEY_Cyrillic_a),
+    ("Cyrillic_be", KEY_Cyrillic_be),
+    ("Cyrillic_che", KEY_Cyrillic_che),
+    ("Cyrillic_che_descender", KEY_Cyrillic_che_descender),
+    ("Cyrillic_che_vertstroke", KEY_Cyrillic_che_vertstroke),
+    ("Cyrillic_de", KEY_Cyrillic_de),
+    ("Cyrillic_dzhe", KEY_Cyrillic_dzhe),
+    ("Cyrillic_e", KEY_Cyrillic_e),
+    ("Cyrillic_ef", KEY_Cyrillic_ef),
+    ("Cyrillic_el", KEY_Cyrillic_el),
+    ("Cyrillic_em", KEY_Cyrillic_em),
+    ("Cyrillic_en", KEY_Cyrillic_en),
+    ("Cyrillic_en_descender", KEY_Cyrillic_en_descender),
+    ("Cyrillic_er", KEY_Cyrillic_er),
+    ("Cyrillic_es", KEY_Cyrillic_es),
+    ("Cyrillic_ghe", KEY_Cyrillic_ghe),
+    ("Cyrillic_ghe_bar", KEY_Cyrillic_ghe_bar),
+    ("Cyrillic_ha", KEY_Cyrillic_ha),
+    ("Cyrillic_ha_descender", KEY_Cyrillic_ha_descender),
+    ("Cyrillic_hardsign", KEY_Cyrillic_hardsign),
+    ("Cyrillic_i", KEY_Cyrillic_i),
+    ("Cyrillic_i_macron", KEY_Cyrillic_i_macron),
+    ("Cyrillic_ie", KEY_Cyrillic_ie),
+    ("Cyrillic_io", KEY_Cyrillic_io),
+    ("Cyrillic_je", KEY_Cyrillic_je),
+    ("Cyrillic_ka", KEY_Cyrillic_ka),
+    ("Cyrillic_ka_descender", KEY_Cyrillic_ka_descender),
+    ("Cyrillic_ka_vertstroke", KEY_Cyrillic_ka_vertstroke),
+    ("Cyrillic_lje", KEY_Cyrillic_lje),
+    ("Cyrillic_nje", KEY_Cyrillic_nje),
+    ("Cyrillic_o", KEY_Cyrillic_o),
+    ("Cyrillic_o_bar", KEY_Cyrillic_o_bar),
+    ("Cyrillic_pe", KEY_Cyrillic_pe),
+    ("Cyrillic_schwa", KEY_Cyrillic_schwa),
+    ("Cyrillic_sha", KEY_Cyrillic_sha),
+    ("Cyrillic_shcha", KEY_Cyrillic_shcha),
+    ("Cyrillic_shha", KEY_Cyrillic_shha),
+    ("Cyrillic_shorti", KEY_Cyrillic_shorti),
+    ("Cyrillic_softsign", KEY_Cyrillic_softsign),
+    ("Cyrillic_te", KEY_Cyrillic_te),
+    ("Cyrillic_tse", KEY_Cyrillic_tse),
+    ("Cyrillic_u", KEY_Cyrillic_u),
+    ("Cyrillic_u_macron", KEY_Cyrillic_u_macron),
+    ("Cyrillic_u_straight", KEY_Cyrillic_u_straight),
+    ("Cyrillic_u_straight_bar", KEY_Cyrillic_u_straight_bar),
+    ("Cyrillic_ve", KEY_Cyrillic_ve),
+    ("Cyrillic_ya", KEY_Cyrillic_ya),
+    ("Cyrillic_yeru", KEY_Cyrillic_yeru),
+    ("Cyrillic_yu", KEY_Cyrillic_yu),
+    ("Cyrillic_ze", KEY_Cyrillic_ze),
+    ("Cyrillic_zhe", KEY_Cyrillic_zhe),
+    ("Cyrillic_zhe_descender", KEY_Cyrillic_zhe_descender),
+    ("D", KEY_D),
+    ("Dabovedot", KEY_Dabovedot),
+    ("Dcaron", KEY_Dcaron),
+    ("Delete", KEY_Delete),
+    ("DongSign", KEY_DongSign),
+    ("Down", KEY_Down),
+    ("Dstroke", KEY_Dstroke),
+    ("E", KEY_E),
+    ("ENG", KEY_ENG),
+    ("ETH", KEY_ETH),
+    ("EZH", KEY_EZH),
+    ("Eabovedot", KEY_Eabovedot),
+    ("Eacute", KEY_Eacute),
+    ("Ebelowdot", KEY_Ebelowdot),
+    ("Ecaron", KEY_Ecaron),
+    ("Ecircumflex", KEY_Ecircumflex),
+    ("Ecircumflexacute", KEY_Ecircumflexacute),
+    ("Ecircumflexbelowdot", KEY_Ecircumflexbelowdot),
+    ("Ecircumflexgrave", KEY_Ecircumflexgrave),
+    ("Ecircumflexhook", KEY_Ecircumflexhook),
+    ("Ecircumflextilde", KEY_Ecircumflextilde),
+    ("EcuSign", KEY_EcuSign),
+    ("Ediaeresis", KEY_Ediaeresis),
+    ("Egrave", KEY_Egrave),
+    ("Ehook", KEY_Ehook),
+    ("Eisu_Shift", KEY_Eisu_Shift),
+    ("Eisu_toggle", KEY_Eisu_toggle),
+    ("Emacron", KEY_Emacron),
+    ("End", KEY_End),
+    ("Eogonek", KEY_Eogonek),
+    ("Escape", KEY_Escape),
+    ("Eth", KEY_Eth),
+    ("Etilde", KEY_Etilde),
+    ("EuroSign", KEY_EuroSign),
+    ("Execute", KEY_Execute),
+    ("F", KEY_F),
+    ("F1", KEY_F1),
+    ("F10", KEY_F10),
+    ("F11", KEY_F11),
+    ("F12", KEY_F12),
+    ("F13", KEY_F13),
+    ("F14", KEY_F14),
+    ("F15", KEY_F15),
+    ("F16", KEY_F16),
+    ("F17", KEY_F17),
+    ("F18", KEY_F18),
+    ("F19", KEY_F19),
+    ("F2", KEY_F2),
+    ("F20", KEY_F20),
+    ("F21", KEY_F21),
+    ("F22", KEY_F22),
+    ("F23", KEY_F23),
+    ("F24", KEY_F24),
+    ("F25", KEY_F25),
+    ("F26", KEY_F26),
+    ("F27", KEY_F27),
+    ("F28", KEY_F28),
+    ("F29", KEY_F29),
+    ("F3", KEY_F3),
+    ("F30", KEY_F30),
+    ("F31", KEY_F31),
+    ("F32", KEY_F32),
+    ("F33", KEY_F33),
+    ("F34", KEY_F34),
+    ("F35", KEY_F35),
+    ("F4", KEY_F4),
+    ("F5", KEY_F5),
+    ("F6", KEY_F6),
+    ("F7", KEY_F7),
+    ("F8", KEY_F8),
+    ("F9", KEY_F9),
+    ("FFrancSign", KEY_FFrancSign),
+    ("Fabovedot", KEY_Fabovedot),
+    ("Farsi_0", KEY_Farsi_0),
+    ("Farsi_1", KEY_Farsi_1),
+    ("Farsi_2", KEY_Farsi_2),
+    ("Farsi_3", KEY_Farsi_3),
+    ("Farsi_4", KEY_Farsi_4),
+    ("Farsi_5", KEY_Farsi_5),
+    ("Farsi_6", KEY_Farsi_6),
+    ("Farsi_7", KEY_Farsi_7),
+    ("Farsi_8", KEY_Farsi_8),
+    ("Farsi_9", KEY_Farsi_9),
+    ("Farsi_yeh", KEY_Farsi_yeh),
+    ("Find", KEY_Find),
+    ("First_Virtual_Screen", KEY_First_Virtual_Screen),
+    ("G", KEY_G),
+    ("Gabovedot", KEY_Gabovedot),
+    ("Gbreve", KEY_Gbreve),
+    ("Gcaron", KEY_Gcaron),
+    ("Gcedilla", KEY_Gcedilla),
+    ("Gcircumflex", KEY_Gcircumflex),
+    ("Georgian_an", KEY_Georgian_an),
+    ("Georgian_ban", KEY_Georgian_ban),
+    ("Georgian_can", KEY_Georgian_can),
+    ("Georgian_char", KEY_Georgian_char),
+    ("Georgian_chin", KEY_Georgian_chin),
+    ("Georgian_cil", KEY_Georgian_cil),
+    ("Georgian_don", KEY_Georgian_don),
+    ("Georgian_en", KEY_Georgian_en),
+    ("Georgian_fi", KEY_Georgian_fi),
+    ("Georgian_gan", KEY_Georgian_gan),
+    ("Georgian_ghan", KEY_Georgian_ghan),
+    ("Georgian_hae", KEY_Georgian_hae),
+    ("Georgian_har", KEY_Georgian_har),
+    ("Georgian_he", KEY_Georgian_he),
+    ("Georgian_hie", KEY_Georgian_hie),
+    ("Georgian_hoe", KEY_Georgian_hoe),
+    ("Georgian_in", KEY_Georgian_in),
+    ("Georgian_jhan", KEY_Georgian_jhan),
+    ("Georgian_jil", KEY_Georgian_jil),
+    ("Georgian_kan", KEY_Georgian_kan),
+    ("Georgian_khar", KEY_Georgian_khar),
+    ("Georgian_las", KEY_Georgian_las),
+    ("Georgian_man", KEY_Georgian_man),
+    ("Georgian_nar", KEY_Georgian_nar),
+    ("Georgian_on", KEY_Georgian_on),
+    ("Georgian_par", KEY_Georgian_par),
+    ("Georgian_phar", KEY_Georgian_phar),
+    ("Georgian_qar", KEY_Georgian_qar),
+    ("Georgian_rae", KEY_Georgian_rae),
+    ("Georgian_san", KEY_Georgian_san),
+    ("Georgian_shin", KEY_Georgian_shin),
+    ("Georgian_tan", KEY_Georgian_tan),
+    ("Georgian_tar", KEY_Georgian_tar),
+    ("Georgian_un", KEY_Georgian_un),
+    ("Georgian_vin", KEY_Georgian_vin),
+    ("Georgian_we", KEY_Georgian_we),
+    ("Georgian_xan", KEY_Georgian_xan),
+    ("Georgian_zen", KEY_Georgian_zen),
+    ("Georgian_zhar", KEY_Georgian_zhar),
+    ("Greek_ALPHA", KEY_Greek_ALPHA),
+    ("Greek_ALPHAaccent", KEY_Greek_ALPHAaccent),
+    ("Greek_BETA", KEY_Greek_BETA),
+    ("Greek_CHI", KEY_Greek_CHI),
+    ("Greek_DELTA", KEY_Greek_DELTA),
+    ("Greek_EPSILON", KEY_Greek_EPSILON),
+    ("Greek_EPSILONaccent", KEY_Greek_EPSILONaccent),
+    ("Greek_ETA", KEY_Greek_ETA),
+    ("Greek_ETAaccent", KEY_Greek_ETAaccent),
+    ("Greek_GAMMA", KEY_Greek_GAMMA),
+    ("Greek_IOTA", KEY_Greek_IOTA),
+    ("Greek_IOTAaccent", KEY_Greek_IOTAaccent),
+    ("Greek_IOTAdiaeresis", KEY_Greek_IOTAdiaeresis),
+    ("Greek_IOTAdieresis", KEY_Greek_IOTAdieresis),
+    ("Greek_KAPPA", KEY_Greek_KAPPA),
+    ("Greek_LAMBDA", KEY_Greek_LAMBDA),
+    ("Greek_LAMDA", KEY_Greek_LAMDA),
+    ("Greek_MU", KEY_Greek_MU),
+    ("Greek_NU", KEY_Greek_NU),
+    ("Greek_OMEGA", KEY_Greek_OMEGA),
+    ("Greek_OMEGAaccent", KEY_Greek_OMEGAaccent),
+    ("Greek_OMICRON", KEY_Greek_OMICRON),
+    ("Greek_OMICRONaccent", KEY_Greek_OMICRONaccent),
+    ("Greek_PHI", KEY_Greek_PHI),
+    ("Greek_PI", KEY_Greek_PI),
+    ("Greek_PSI", KEY_Greek_PSI),
+    ("Greek_RHO", KEY_Greek_RHO),
+    ("Greek_SIGMA", KEY_Greek_SIGMA),
+    ("Greek_TAU", KEY_Greek_TAU),
+    ("Greek_THETA", KEY_Greek_THETA),
+    ("Greek_UPSILON", KEY_Greek_UPSILON),
+    ("Greek_UPSILONaccent", KEY_Greek_UPSILONaccent),
+    ("Greek_UPSILONdieresis", KEY_Greek_UPSILONdieresis),
+    ("Greek_XI", KEY_Greek_XI),
+    ("Greek_ZETA", KEY_Greek_ZETA),
+    ("Greek_accentdieresis", KEY_Greek_accentdieresis),
+    ("Greek_alpha", KEY_Greek_alpha),
+    ("Greek_alphaaccent", KEY_Greek_alphaaccent),
+    ("Greek_beta", KEY_Greek_beta),
+    ("Greek_chi", KEY_Greek_chi),
+    ("Greek_delta", KEY_Greek_delta),
+    ("Greek_epsilon", KEY_Greek_epsilon),
+    ("Greek_epsilonaccent", KEY_Greek_epsilonaccent),
+    ("Greek_eta", KEY_Greek_eta),
+    ("Greek_etaaccent", KEY_Greek_etaaccent),
+    ("Greek_finalsmallsigma", KEY_Greek_finalsmallsigma),
+    ("Greek_gamma", KEY_Greek_gamma),
+    ("Greek_horizbar", KEY_Greek_horizbar),
+    ("Greek_iota", KEY_Greek_iota),
+    ("Greek_iotaaccent", KEY_Greek_iotaaccent),
+    ("Greek_iotaaccentdieresis", KEY_Greek_iotaaccentdieresis),
+    ("Greek_iotadieresis", KEY_Greek_iotadieresis),
+    ("Greek_kappa", KEY_Greek_kappa),
+    ("Greek_lambda", KEY_Greek_lambda),
+    ("Greek_lamda", KEY_Greek_lamda),
+    ("Greek_mu", KEY_Greek_mu),
+    ("Greek_nu", KEY_Greek_nu),
+    ("Greek_omega", KEY_Greek_omega),
+    ("Greek_omegaaccent", KEY_Greek_omegaaccent),
+    ("Greek_omicron", KEY_Greek_omicron),
+    ("Greek_omicronaccent", KEY_Greek_omicronaccent),
+    ("Greek_phi", KEY_Greek_phi),
+    ("Greek_pi", KEY_Greek_pi),
+    ("Greek_psi", KEY_Greek_psi),
+    ("Greek_rho", KEY_Greek_rho),
+    ("Greek_sigma", KEY_Greek_sigma),
+    ("Greek_switch", KEY_Greek_switch),
+    ("Greek_tau", KEY_Greek_tau),
+    ("Greek_theta", KEY_Greek_theta),
+    ("Greek_upsilon", KEY_Greek_upsilon),
+    ("Greek_upsilonaccent", KEY_Greek_upsilonaccent),
+    ("Greek_upsilonaccentdieresis", KEY_Greek_upsilonaccentdieresis),
+    ("Greek_upsilondieresis", KEY_Greek_upsilondieresis),
+    ("Greek_xi", KEY_Greek_xi),
+    ("Greek_zeta", KEY_Greek_zeta),
+    ("H", KEY_H),
+    ("Hangul", KEY_Hangul),
+    ("Hangul_A", KEY_Hangul_A),
+    ("Hangul_AE", KEY_Hangul_AE),
+    ("Hangul_AraeA", KEY_Hangul_AraeA),
+    ("Hangul_AraeAE", KEY_Hangul_AraeAE),
+    ("Hangul_Banja", KEY_Hangul_Banja),
+    ("Hangul_Cieuc", KEY_Hangul_Cieuc),
+    ("Hangul_Codeinput", KEY_Hangul_Codeinput),
+    ("Hangul_Dikeud", KEY_Hangul_Dikeud),
+    ("Hangul_E", KEY_Hangul_E),
+    ("Hangul_EO", KEY_Hangul_EO),
+    ("Hangul_EU", KEY_Hangul_EU),
+    ("Hangul_End", KEY_Hangul_End),
+    ("Hangul_Hanja", KEY_Hangul_Hanja),
+    ("Hangul_Hieuh", KEY_Hangul_Hieuh),
+    ("Hangul_I", KEY_Hangul_I),
+    ("Hangul_Ieung", KEY_Hangul_Ieung),
+    ("Hangul_J_Cieuc", KEY_Hangul_J_Cieuc),
+    ("Hangul_J_Dikeud", KEY_Hangul_J_Dikeud),
+    ("Hangul_J_Hieuh", KEY_Hangul_J_Hieuh),
+    ("Hangul_J_Ieung", KEY_Hangul_J_Ieung),
+    ("Hangul_J_Jieuj", KEY_Hangul_J_Jieuj),
+    ("Hangul_J_Khieuq", KEY_Hangul_J_Khieuq),
+    ("Hangul_J_Kiyeog", KEY_Hangul_J_Kiyeog),
+    ("Hangul_J_KiyeogSios", KEY_Hangul_J_KiyeogSios),
+    ("Hangul_J_KkogjiDalrinIeung", KEY_Hangul_J_KkogjiDalrinIeung),
+    ("Hangul_J_Mieum", KEY_Hangul_J_Mieum),
+    ("Hangul_J_Nieun", KEY_Hangul_J_Nieun),
+    ("Hangul_J_NieunHieuh", KEY_Hangul_J_NieunHieuh),
+    ("Hangul_J_NieunJieuj", KEY_Hangul_J_NieunJieuj),
+    ("Hangul_J_PanSios", KEY_Hangul_J_PanSios),
+    ("Hangul_J_Phieuf", KEY_Hangul_J_Phieuf),
+    ("Hangul_J_Pieub", KEY_Hangul_J_Pieub),
+    ("Hangul_J_PieubSios", KEY_Hangul_J_PieubSios),
+    ("Hangul_J_Rieul", KEY_Hangul_J_Rieul),
+    ("Hangul_J_RieulHieuh", KEY_Hangul_J_RieulHieuh),
+    ("Hangul_J_RieulKiyeog", KEY_Hangul_J_RieulKiyeog),
+    ("Hangul_J_RieulMieum", KEY_Hangul_J_RieulMieum),
+    ("Hangul_J_RieulPhieuf", KEY_Hangul_J_RieulPhieuf),
+    ("Hangul_J_RieulPieub", KEY_Hangul_J_RieulPieub),
+    ("Hangul_J_RieulSios", KEY_Hangul_J_RieulSios),
+    ("Hangul_J_RieulTieut", KEY_Hangul_J_RieulTieut),
+    ("Hangul_J_Sios", KEY_Hangul_J_Sios),
+    ("Hangul_J_SsangKiyeog", KEY_Hangul_J_SsangKiyeog),
+    ("Hangul_J_SsangSios", KEY_Hangul_J_SsangSios),
+    ("Hangul_J_Tieut", KEY_Hangul_J_Tieut),
+    ("Hangul_J_YeorinHieuh", KEY_Hangul_J_YeorinHieuh),
+    ("Hangul_Jamo", KEY_Hangul_Jamo),
+    ("Hangul_Jeonja", KEY_Hangul_Jeonja),
+    ("Hangul_Jieuj", KEY_Hangul_Jieuj),
+    ("Hangul_Khieuq", KEY_Hangul_Khieuq),
+    ("Hangul_Kiyeog", KEY_Hangul_Kiyeog),
+    ("Hangul_KiyeogSios", KEY_Hangul_KiyeogSios),
+    ("Hangul_KkogjiDalrinIeung", KEY_Hangul_KkogjiDalrinIeung),
+    ("Hangul_Mieum", KEY_Hangul_Mieum),
+    ("Hangul_MultipleCandidate", KEY_Hangul_MultipleCandidate),
+    ("Hangul_Nieun", KEY_Hangul_Nieun),
+    ("Hangul_NieunHieuh", KEY_Hangul_NieunHieuh),
+    ("Hangul_NieunJieuj", KEY_Hangul_NieunJieuj),
+    ("Hangul_O", KEY_Hangul_O),
+    ("Hangul_OE", KEY_Hangul_OE),
+    ("Hangul_PanSios", KEY_Hangul_PanSios),
+    ("Hangul_Phieuf", KEY_Hangul_Phieuf),
+    ("Hangul_Pieub", KEY_Hangul_Pieub),
+    ("Hangul_PieubSios", KEY_Hangul_PieubSios),
+    ("Hangul_PostHanja", KEY_Hangul_PostHanja),
+    ("Hangul_PreHanja", KEY_Hangul_PreHanja),
+    ("Hangul_PreviousCandidate", KEY_Hangul_PreviousCandidate),
+    ("Hangul_Rieul", KEY_Hangul_Rieul),
+    ("Hangul_RieulHieuh", KEY_Hangul_RieulHieuh),
+    ("Hangul_RieulKiyeog", KEY_Hangul_RieulKiyeog),
+    ("Hangul_RieulMieum", KEY_Hangul_RieulMieum),
+    ("Hangul_RieulPhieuf", KEY_Hangul_RieulPhieuf),
+    ("Hangul_RieulPieub", KEY_Hangul_RieulPieub),
+    ("Hangul_RieulSios", KEY_Hangul_RieulSios),
+    ("Hangul_RieulTieut", KEY_Hangul_RieulTieut),
+    ("Hangul_RieulYeorinHieuh", KEY_Hangul_RieulYeorinHieuh),
+    ("Hangul_Romaja", KEY_Hangul_Romaja),
+    ("Hangul_SingleCandidate", KEY_Hangul_SingleCandidate),
+    ("Hangul_Sios", KEY_Hangul_Sios),
+    ("Hangul_Special", KEY_Hangul_Special),
+    ("Hangul_SsangDikeud", KEY_Hangul_SsangDikeud),
+    ("Hangul_SsangJieuj", KEY_Hangul_SsangJieuj),
+    ("Hangul_SsangKiyeog", KEY_Hangul_SsangKiyeog),
+    ("Hangul_SsangPieub", KEY_Hangul_SsangPieub),
+    ("Hangul_SsangSios", KEY_Hangul_SsangSios),
+    ("Hangul_Start", KEY_Hangul_Start),
+    ("Hangul_SunkyeongeumMieum", KEY_Hangul_SunkyeongeumMieum),
+    ("Hangul_SunkyeongeumPhieuf", KEY_Hangul_SunkyeongeumPhieuf),
+    ("Hangul_SunkyeongeumPieub", KEY_Hangul_SunkyeongeumPieub),
+    ("Hangul_Tieut", KEY_Hangul_Tieut),
+    ("Hangul_U", KEY_Hangul_U),
+    ("Hangul_WA", KEY_Hangul_WA),
+    ("Hangul_WAE", KEY_Hangul_WAE),
+    ("Hangul_WE", KEY_Hangul_WE),
+    ("Hangul_WEO", KEY_Hangul_WEO),
+    ("Hangul_WI", KEY_Hangul_WI),
+    ("Hangul_YA", KEY_Hangul_YA),
+    ("Hangul_YAE", KEY_Hangul_YAE),
+    ("Hangul_YE", KEY_Hangul_YE),
+    ("Hangul_YEO", KEY_Hangul_YEO),
+    ("Hangul_YI", KEY_Hangul_YI),
+    ("Hangul_YO", KEY_Hangul_YO),
+    ("Hangul_YU", KEY_Hangul_YU),
+    ("Hangul_YeorinHieuh", KEY_Hangul_YeorinHieuh),
+    ("Hangul_switch", KEY_Hangul_switch),
+    ("Hankaku", KEY_Hankaku),
+    ("Hcircumflex", KEY_Hcircumflex),
+    ("Hebrew_switch", KEY_Hebrew_switch),
+    ("Help", KEY_Help),
+    ("Henkan", KEY_Henkan),
+    ("Henkan_Mode", KEY_Henkan_Mode),
+    ("Hiragana", KEY_Hiragana),
+    ("Hiragana_Katakana", KEY_Hiragana_Katakana),
+    ("Home", KEY_Home),
+    ("Hstroke", KEY_Hstroke),
+    ("Hyper_L", KEY_Hyper_L),
+    ("Hyper_R", KEY_Hyper_R),
+    ("I", KEY_I),
+    ("ISO_Center_Object", KEY_ISO_Center_Object),
+    ("ISO_Continuous_Underline", KEY_ISO_Continuous_Underline),
+    ("ISO_Discontinuous_Underline", KEY_ISO_Discontinuous_Underline),
+    ("ISO_Emphasize", KEY_ISO_Emphasize),
+    ("ISO_Enter", KEY_ISO_Enter),
+    ("ISO_Fast_Cursor_Down", KEY_ISO_Fast_Cursor_Down),
+    ("ISO_Fast_Cursor_Left", KEY_ISO_Fast_Cursor_Left),
+    ("ISO_Fast_Cursor_Right", KEY_ISO_Fast_Cursor_Right),
+    ("ISO_Fast_Cursor_Up", KEY_ISO_Fast_Cursor_Up),
+    ("ISO_First_Group", KEY_ISO_First_Group),
+    ("ISO_First_Group_Lock", KEY_ISO_First_Group_Lock),
+    ("ISO_Group_Latch", KEY_ISO_Group_Latch),
+    ("ISO_Group_Lock", KEY_ISO_Group_Lock),
+    ("ISO_Group_Shift", KEY_ISO_Group_Shift),
+    ("ISO_Last_Group", KEY_ISO_Last_Group),
+    ("ISO_Last_Group_Lock", KEY_ISO_Last_Group_Lock),
+    ("ISO_Left_Tab", KEY_ISO_Left_Tab),
+    ("ISO_Level2_Latch", KEY_ISO_Level2_Latch),
+    ("ISO_Level3_Latch", KEY_ISO_Level3_Latch),
+    ("ISO_Level3_Lock", KEY_ISO_Level3_Lock),
+    ("ISO_Level3_Shift", KEY_ISO_Level3_Shift),
+    ("ISO_Level5_Latch", KEY_ISO_Level5_Latch),
+    ("ISO_Level5_Lock", KEY_ISO_Level5_Lock),
+    ("ISO_Level5_Shift", KEY_ISO_Level5_Shift),
+    ("ISO_Lock", KEY_ISO_Lock),
+    ("ISO_Move_Line_Down", KEY_ISO_Move_Line_Down),
+    ("ISO_Move_Line_Up", KEY_ISO_Move_Line_Up),
+    ("ISO_Next_Group", KEY_ISO_Next_Group),
+    ("ISO_Next_Group_Lock", KEY_ISO_Next_Group_Lock),
+    ("ISO_Partial_Line_Down", KEY_ISO_Partial_Line_Down),
+    ("ISO_Partial_Line_Up", KEY_ISO_Partial_Line_Up),
+    ("ISO_Partial_Space_Left", KEY_ISO_Partial_Space_Left),
+    ("ISO_Partial_Space_Right", KEY_ISO_Partial_Space_Right),
+    ("ISO_Prev_Group", KEY_ISO_Prev_Group),
+    ("ISO_Prev_Group_Lock", KEY_ISO_Prev_Group_Lock),
+    ("ISO_Release_Both_Margins", KEY_ISO_Release_Both_Margins),
+    ("ISO_Release_Margin_Left", KEY_ISO_Release_Margin_Left),
+    ("ISO_Release_Margin_Right", KEY_ISO_Release_Margin_Right),
+    ("ISO_Set_Margin_Left", KEY_ISO_Set_Margin_Left),
+    ("ISO_Set_Margin_Right", KEY_ISO_Set_Margin_Right),
+    ("Iabovedot", KEY_Iabovedot),
+    ("Iacute", KEY_Iacute),
+    ("Ibelowdot", KEY_Ibelowdot),
+    ("Ibreve", KEY_Ibreve),
+    ("Icircumflex", KEY_Icircumflex),
+    ("Idiaeresis", KEY_Idiaeresis),
+    ("Igrave", KEY_Igrave),
+    ("Ihook", KEY_Ihook),
+    ("Imacron", KEY_Imacron),
+    ("Insert", KEY_Insert),
+    ("Iogonek", KEY_Iogonek),
+    ("Itilde", KEY_Itilde),
+    ("J", KEY_J),
+    ("Jcircumflex", KEY_Jcircumflex),
+    ("K", KEY_K),
+    ("KP_0", KEY_KP_0),
+    ("KP_1", KEY_KP_1),
+    ("KP_2", KEY_KP_2),
+    ("KP_3", KEY_KP_3),
+    ("KP_4", KEY_KP_4),
+    ("KP_5", KEY_KP_5),
+    ("KP_6", KEY_KP_6),
+    ("KP_7", KEY_KP_7),
+    ("KP_8", KEY_KP_8),
+    ("KP_9", KEY_KP_9),
+    ("KP_Add", KEY_KP_Add),
+    ("KP_Begin", KEY_KP_Begin),
+    ("KP_Decimal", KEY_KP_Decimal),
+    ("KP_Delete", KEY_KP_Delete),
+    ("KP_Divide", KEY_KP_Divide),
+    ("KP_Down", KEY_KP_Down),
+    ("KP_End", KEY_KP_End),
+    ("KP_Enter", KEY_KP_Enter),
+    ("KP_Equal", KEY_KP_Equal),
+    ("KP_F1", KEY_KP_F1),
+    ("KP_F2", KEY_KP_F2),
+    ("KP_F3", KEY_KP_F3),
+    ("KP_F4", KEY_KP_F4),
+    ("KP_Home", KEY_KP_Home),
+    ("KP_Insert", KEY_KP_Insert),
+    ("KP_Left", KEY_KP_Left),
+    ("KP_Multiply", KEY_KP_Multiply),
+    ("KP_Next", KEY_KP_Next),
+    ("KP_Page_Down", KEY_KP_Page_Down),
+    ("KP_Page_Up", KEY_KP_Page_Up),
+    ("KP_Prior", KEY_KP_Prior),
+    ("KP_Right", KEY_KP_Right),
+    ("KP_Separator", KEY_KP_Separator),
+    ("KP_Space", KEY_KP_Space),
+    ("KP_Subtract", KEY_KP_Subtract),
+    ("KP_Tab", KEY_KP_Tab),
+    ("KP_Up", KEY_KP_Up),
+    ("Kana_Lock", KEY_Kana_Lock),
+    ("Kana_Shift", KEY_Kana_Shift),
+    ("Kanji", KEY_Kanji),
+    ("Kanji_Bangou", KEY_Kanji_Bangou),
+    ("Katakana", KEY_Katakana),
+    ("Kcedilla", KEY_Kcedilla),
+    ("Korean_Won", KEY_Korean_Won),
+    ("L", KEY_L),
+    ("L1", KEY_L1),
+    ("L10", KEY_L10),
+    ("L2", KEY_L2),
+    ("L3", KEY_L3),
+    ("L4", KEY_L4),
+    ("L5", KEY_L5),
+    ("L6", KEY_L6),
+    ("L7", KEY_L7),
+    ("L8", KEY_L8),
+    ("L9", KEY_L9),
+    ("Lacute", KEY_Lacute),
+    ("Last_Virtual_Screen", KEY_Last_Virtual_Screen),
+    ("Lbelowdot", KEY_Lbelowdot),
+    ("Lcaron", KEY_Lcaron),
+    ("Lcedilla", KEY_Lcedilla),
+    ("Left", KEY_Left),
+    ("Linefeed", KEY_Linefeed),
+    ("LiraSign", KEY_LiraSign),
+    ("Lstroke", KEY_Lstroke),
+    ("M", KEY_M),
+    ("Mabovedot", KEY_Mabovedot),
+    ("Macedonia_DSE", KEY_Macedonia_DSE),
+    ("Macedonia_GJE", KEY_Macedonia_GJE),
+    ("Macedonia_KJE", KEY_Macedonia_KJE),
+    ("Macedonia_dse", KEY_Macedonia_dse),
+    ("Macedonia_gje", KEY_Macedonia_gje),
+    ("Macedonia_kje", KEY_Macedonia_kje),
+    ("Mae_Koho", KEY_Mae_Koho),
+    ("Massyo", KEY_Massyo),
+    ("Menu", KEY_Menu),
+    ("Meta_L", KEY_Meta_L),
+    ("Meta_R", KEY_Meta_R),
+    ("MillSign", KEY_MillSign),
+    ("Mode_switch", KEY_Mode_switch),
+    ("MouseKeys_Accel_Enable", KEY_MouseKeys_Accel_Enable),
+    ("MouseKeys_Enable", KEY_MouseKeys_Enable),
+    ("Muhenkan", KEY_Muhenkan),
+    ("Multi_key", KEY_Multi_key),
+    ("MultipleCandidate", KEY_MultipleCandidate),
+    ("N", KEY_N),
+    ("Nacute", KEY_Nacute),
+    ("NairaSign", KEY_NairaSign),
+    ("Ncaron", KEY_Ncaron),
+    ("Ncedilla", KEY_Ncedilla),
+    ("NewSheqelSign", KEY_NewSheqelSign),
+    ("Next", KEY_Next),
+    ("Next_Virtual_Screen", KEY_Next_Virtual_Screen),
+    ("Ntilde", KEY_Ntilde),
+    ("Num_Lock", KEY_Num_Lock),
+    ("O", KEY_O),
+    ("OE", KEY_OE),
+    ("Oacute", KEY_Oacute),
+    ("Obarred", KEY_Obarred),
+    ("Obelowdot", KEY_Obelowdot),
+    ("Ocaron", KEY_Ocaron),
+    ("Ocircumflex", KEY_Ocircumflex),
+    ("Ocircumflexacute", KEY_Ocircumflexacute),
+    ("Ocircumflexbelowdot", KEY_Ocircumflexbelowdot),
+    ("Ocircumflexgrave", KEY_Ocircumflexgrave),
+    ("Ocircumflexhook", KEY_Ocircumflexhook),
+    ("Ocircumflextilde", KEY_Ocircumflextilde),
+    ("Odiaeresis", KEY_Odiaeresis),
+    ("Odoubleacute", KEY_Odoubleacute),
+    ("Ograve", KEY_Ograve),
+    ("Ohook", KEY_Ohook),
+    ("Ohorn", KEY_Ohorn),
+    ("Ohornacute", KEY_Ohornacute),
+    ("Ohornbelowdot", KEY_Ohornbelowdot),
+    ("Ohorngrave", KEY_Ohorngrave),
+    ("Ohornhook", KEY_Ohornhook),
+    ("Ohorntilde", KEY_Ohorntilde),
+    ("Omacron", KEY_Omacron),
+    ("Ooblique", KEY_Ooblique),
+    ("Oslash", KEY_Oslash),
+    ("Otilde", KEY_Otilde),
+    ("Overlay1_Enable", KEY_Overlay1_Enable),
+    ("Overlay2_Enable", KEY_Overlay2_Enable),
+    ("P", KEY_P),
+    ("Pabovedot", KEY_Pabovedot),
+    ("Page_Down", KEY_Page_Down),
+    ("Page_Up", KEY_Page_Up),
+    ("Pause", KEY_Pause),
+    ("PesetaSign", KEY_PesetaSign),
+    ("Pointer_Accelerate", KEY_Pointer_Accelerate),
+    ("Pointer_Button1", KEY_Pointer_Button1),
+    ("Pointer_Button2", KEY_Pointer_Button2),
+    ("Pointer_Button3", KEY_Pointer_Button3),
+    ("Pointer_Button4", KEY_Pointer_Button4),
+    ("Pointer_Button5", KEY_Pointer_Button5),
+    ("Pointer_Button_Dflt", KEY_Pointer_Button_Dflt),
+    ("Pointer_DblClick1", KEY_Pointer_DblClick1),
+    ("Pointer_DblClick2", KEY_Pointer_DblClick2),
+    ("Pointer_DblClick3", KEY_Pointer_DblClick3),
+    ("Pointer_DblClick4", KEY_Pointer_DblClick4),
+    ("Pointer_DblClick5", KEY_Pointer_DblClick5),
+    ("Pointer_DblClick_Dflt", KEY_Pointer_DblClick_Dflt),
+    ("Pointer_DfltBtnNext", KEY_Pointer_DfltBtnNext),
+    ("Pointer_DfltBtnPrev", KEY_Pointer_DfltBtnPrev),
+    ("Pointer_Down", KEY_Pointer_Down),
+    ("Pointer_DownLeft", KEY_Pointer_DownLeft),
+    ("Pointer_DownRight", KEY_Pointer_DownRight),
+    ("Pointer_Drag1", KEY_Pointer_Drag1),
+    ("Pointer_Drag2", KEY_Pointer_Drag2),
+    ("Pointer_Drag3", KEY_Pointer_Drag3),
+    ("Pointer_Drag4", KEY_Pointer_Drag4),
+    ("Pointer_Drag5", KEY_Pointer_Drag5),
+    ("Pointer_Drag_Dflt", KEY_Pointer_Drag_Dflt),
+    ("Pointer_EnableKeys", KEY_Pointer_EnableKeys),
+    ("Pointer_Left", KEY_Pointer_Left),
+    ("Pointer_Right", KEY_Pointer_Right),
+    ("Pointer_Up", KEY_Pointer_Up),
+    ("Pointer_UpLeft", KEY_Pointer_UpLeft),
+    ("Pointer_UpRight", KEY_Pointer_UpRight),
+    ("Prev_Virtual_Screen", KEY_Prev_Virtual_Screen),
+    ("PreviousCandidate", KEY_PreviousCandidate),
+    ("Print", KEY_Print),
+    ("Prior", KEY_Prior),
+    ("Q", KEY_Q),
+    ("R", KEY_R),
+    ("R1", KEY_R1),
+    ("R10", KEY_R10),
+    ("R11", KEY_R11),
+    ("R12", KEY_R12),
+    ("R13", KEY_R13),
+    ("R14", KEY_R14),
+    ("R15", KEY_R15),
+    ("R2", KEY_R2),
+    ("R3", KEY_R3),
+    ("R4", KEY_R4),
+    ("R5", KEY_R5),
+    ("R6", KEY_R6),
+    ("R7", KEY_R7),
+    ("R8", KEY_R8),
+    ("R9", KEY_R9),
+    ("Racute", KEY_Racute),
+    ("Rcaron", KEY_Rcaron),
+    ("Rcedilla", KEY_Rcedilla),
+    ("Redo", KEY_Redo),
+    ("RepeatKeys_Enable", KEY_RepeatKeys_Enable),
+    ("Return", KEY_Return),
+    ("Right", KEY_Right),
+    ("Romaji", KEY_Romaji),
+    ("RupeeSign", KEY_RupeeSign),
+    ("S", KEY_S),
+    ("SCHWA", KEY_SCHWA),
+    ("Sabovedot", KEY_Sabovedot),
+    ("Sacute", KEY_Sacute),
+    ("Scaron", KEY_Scaron),
+    ("Scedilla", KEY_Scedilla),
+    ("Scircumflex", KEY_Scircumflex),
+    ("Scroll_Lock", KEY_Scroll_Lock),
+    ("Select", KEY_Select),
+    ("Serbian_DJE", KEY_Serbian_DJE),
+    ("Serbian_DZE", KEY_Serbian_DZE),
+    ("Serbian_JE", KEY_Serbian_JE),
+    ("Serbian_LJE", KEY_Serbian_LJE),
+    ("Serbian_NJE", KEY_Serbian_NJE),
+    ("Serbian_TSHE", KEY_Serbian_TSHE),
+    ("Serbian_dje", KEY_Serbian_dje),
+    ("Serbian_dze", KEY_Serbian_dze),
+    ("Serbian_je", KEY_Serbian_je),
+    ("Serbian_lje", KEY_Serbian_lje),
+    ("Serbian_nje", KEY_Serbian_nje),
+    ("Serbian_tshe", KEY_Serbian_tshe),
+    ("Shift_L", KEY_Shift_L),
+    ("Shift_Lock", KEY_Shift_Lock),
+    ("Shift_R", KEY_Shift_R),
+    ("SingleCandidate", KEY_SingleCandidate),
+    ("Sinh_a", KEY_Sinh_a),
+    ("Sinh_aa", KEY_Sinh_aa),
+    ("Sinh_aa2", KEY_Sinh_aa2),
+    ("Sinh_ae", KEY_Sinh_ae),
+    ("Sinh_ae2", KEY_Sinh_ae2),
+    ("Sinh_aee", KEY_Sinh_aee),
+    ("Sinh_aee2", KEY_Sinh_aee2),
+    ("Sinh_ai", KEY_Sinh_ai),
+    ("Sinh_ai2", KEY_Sinh_ai2),
+    ("Sinh_al", KEY_Sinh_al),
+    ("Sinh_au", KEY_Sinh_au),
+    ("Sinh_au2", KEY_Sinh_au2),
+    ("Sinh_ba", KEY_Sinh_ba),
+    ("Sinh_bha", KEY_Sinh_bha),
+    ("Sinh_ca", KEY_Sinh_ca),
+    ("Sinh_cha", KEY_Sinh_cha),
+    ("Sinh_dda", KEY_Sinh_dda),
+    ("Sinh_ddha", KEY_Sinh_ddha),
+    ("Sinh_dha", KEY_Sinh_dha),
+    ("Sinh_dhha", KEY_Sinh_dhha),
+    ("Sinh_e", KEY_Sinh_e),
+    ("Sinh_e2", KEY_Sinh_e2),
+    ("Sinh_ee", KEY_Sinh_ee),
+    ("Sinh_ee2", KEY_Sinh_ee2),
+    ("Sinh_fa", KEY_Sinh_fa),
+    ("Sinh_ga", KEY_Sinh_ga),
+    ("Sinh_gha", KEY_Sinh_gha),
+    ("Sinh_h2", KEY_Sinh_h2),
+    ("Sinh_ha", KEY_Sinh_ha),
+    ("Sinh_i", KEY_Sinh_i),
+    ("Sinh_i2", KEY_Sinh_i2),
+    ("Sinh_ii", KEY_Sinh_ii),
+    ("Sinh_ii2", KEY_Sinh_ii2),
+    ("Sinh_ja", KEY_Sinh_ja),
+    ("Sinh_jha", KEY_Sinh_jha),
+    ("Sinh_jnya", KEY_Sinh_jnya),
+    ("Sinh_ka", KEY_Sinh_ka),
+    ("Sinh_kha", KEY_Sinh_kha),
+    ("Sinh_kunddaliya", KEY_Sinh_kunddaliya),
+    ("Sinh_la", KEY_Sinh_la),
+    ("Sinh_lla", KEY_Sinh_lla),
+    ("Sinh_lu", KEY_Sinh_lu),
+    ("Sinh_lu2", KEY_Sinh_lu2),
+    ("Sinh_luu", KEY_Sinh_luu),
+    ("Sinh_luu2", KEY_Sinh_luu2),
+    ("Sinh_ma", KEY_Sinh_ma),
+    ("Sinh_mba", KEY_Sinh_mba),
+    ("Sinh_na", KEY_Sinh_na),
+    ("Sinh_ndda", KEY_Sinh_ndda),
+    ("Sinh_ndha", KEY_Sinh_ndha),
+    ("Sinh_ng", KEY_Sinh_ng),
+    ("Sinh_ng2", KEY_Sinh_ng2),
+    ("Sinh_nga", KEY_Sinh_nga),
+    ("Sinh_nja", KEY_Sinh_nja),
+    ("Sinh_nna", KEY_Sinh_nna),
+    ("Sinh_nya", KEY_Sinh_nya),
+    ("Sinh_o", KEY_Sinh_o),
+    ("Sinh_o2", KEY_Sinh_o2),
+    ("Sinh_oo", KEY_Sinh_oo),
+    ("Sinh_oo2", KEY_Sinh_oo2),
+    ("Sinh_pa", KEY_Sinh_pa),
+    ("Sinh_pha", KEY_Sinh_pha),
+    ("Sinh_ra", KEY_Sinh_ra),
+    ("Sinh_ri", KEY_Sinh_ri),
+    ("Sinh_rii", KEY_Sinh_rii),
+    ("Sinh_ru2", KEY_Sinh_ru2),
+    ("Sinh_ruu2", KEY_Sinh_ruu2),
+    ("Sinh_sa", KEY_Sinh_sa),
+    ("Sinh_sha", KEY_Sinh_sha),
+    ("Sinh_ssha", KEY_Sinh_ssha),
+    ("Sinh_tha", KEY_Sinh_tha),
+    ("Sinh_thha", KEY_Sinh_thha),
+    ("Sinh_tta", KEY_Sinh_tta),
+    ("Sinh_ttha", KEY_Sinh_ttha),
+    ("Sinh_u", KEY_Sinh_u),
+    ("Sinh_u2", KEY_Sinh_u2),
+    ("Sinh_uu", KEY_Sinh_uu),
+    ("Sinh_uu2", KEY_Sinh_uu2),
+    ("Sinh_va", KEY_Sinh_va),
+    ("Sinh_ya", KEY_Sinh_ya),
+    ("SlowKeys_Enable", KEY_SlowKeys_Enable),
+    ("StickyKeys_Enable", KEY_StickyKeys_Enable),
+    ("Super_L", KEY_Super_L),
+    ("Super_R", KEY_Super_R),
+    ("Sys_Req", KEY_Sys_Req),
+    ("T", KEY_T),
+    ("THORN", KEY_THORN),
+    ("Tab", KEY_Tab),
+    ("Tabovedot", KEY_Tabovedot),
+    ("Tcaron", KEY_Tcaron),
+    ("Tcedilla", KEY_Tcedilla),
+    ("Terminate_Server", KEY_Terminate_Server),
+    ("Thai_baht", KEY_Thai_baht),
+    ("Thai_bobaimai", KEY_Thai_bobaimai),
+    ("Thai_chochan", KEY_Thai_chochan),
+    ("Thai_chochang", KEY_Thai_chochang),
+    ("Thai_choching", KEY_Thai_choching),
+    ("Thai_chochoe", KEY_Thai_chochoe),
+    ("Thai_dochada", KEY_Thai_dochada),
+    ("Thai_dodek", KEY_Thai_dodek),
+    ("Thai_fofa", KEY_Thai_fofa),
+    ("Thai_fofan", KEY_Thai_fofan),
+    ("Thai_hohip", KEY_Thai_hohip),
+    ("Thai_honokhuk", KEY_Thai_honokhuk),
+    ("Thai_khokhai", KEY_Thai_khokhai),
+    ("Thai_khokhon", KEY_Thai_khokhon),
+    ("Thai_khokhuat", KEY_Thai_khokhuat),
+    ("Thai_khokhwai", KEY_Thai_khokhwai),
+    ("Thai_khorakhang", KEY_Thai_khorakhang),
+    ("Thai_kokai", KEY_Thai_kokai),
+    ("Thai_lakkhangyao", KEY_Thai_lakkhangyao),
+    ("Thai_lekchet", KEY_Thai_lekchet),
+    ("Thai_lekha", KEY_Thai_lekha),
+    ("Thai_lekhok", KEY_Thai_lekhok),
+    ("Thai_lekkao", KEY_Thai_lekkao),
+    ("Thai_leknung", KEY_Thai_leknung),
+    ("Thai_lekpaet", KEY_Thai_lekpaet),
+    ("Thai_leksam", KEY_Thai_leksam),
+    ("Thai_leksi", KEY_Thai_leksi),
+    ("Thai_leksong", KEY_Thai_leksong),
+    ("Thai_leksun", KEY_Thai_leksun),
+    ("Thai_lochula", KEY_Thai_lochula),
+    ("Thai_loling", KEY_Thai_loling),
+    ("Thai_lu", KEY_Thai_lu),
+    ("Thai_maichattawa", KEY_Thai_maichattawa),
+    ("Thai_maiek", KEY_Thai_maiek),
+    ("Thai_maihanakat", KEY_Thai_maihanakat),
+    ("Thai_maihanakat_maitho", KEY_Thai_maihanakat_maitho),
+    ("Thai_maitaikhu", KEY_Thai_maitaikhu),
+    ("Thai_maitho", KEY_Thai_maitho),
+    ("Thai_maitri", KEY_Thai_maitri),
+    ("Thai_maiyamok", KEY_Thai_maiyamok),
+    ("Thai_moma", KEY_Thai_moma),
+    ("Thai_ngongu", KEY_Thai_ngongu),
+    ("Thai_nikhahit", KEY_Thai_nikhahit),
+    ("Thai_nonen", KEY_Thai_nonen),
+    ("Thai_nonu", KEY_Thai_nonu),
+    ("Thai_oang", KEY_Thai_oang),
+    ("Thai_paiyannoi", KEY_Thai_paiyannoi),
+    ("Thai_phinthu", KEY_Thai_phinthu),
+    ("Thai_phophan", KEY_Thai_phophan),
+    ("Thai_phophung", KEY_Thai_phophung),
+    ("Thai_phosamphao", KEY_Thai_phosamphao),
+    ("Thai_popla", KEY_Thai_popla),
+    ("Thai_rorua", KEY_Thai_rorua),
+    ("Thai_ru", KEY_Thai_ru),
+    ("Thai_saraa", KEY_Thai_saraa),
+    ("Thai_saraaa", KEY_Thai_saraaa),
+    ("Thai_saraae", KEY_Thai_saraae),
+    ("Thai_saraaimaimalai", KEY_Thai_saraaimaimalai),
+    ("Thai_saraaimaimuan", KEY_Thai_saraaimaimuan),
+    ("Thai_saraam", KEY_Thai_saraam),
+    ("Thai_sarae", KEY_Thai_sarae),
+    ("Thai_sarai", KEY_Thai_sarai),
+    ("Thai_saraii", KEY_Thai_saraii),
+    ("Thai_sarao", KEY_Thai_sarao),
+    ("Thai_sarau", KEY_Thai_sarau),
+    ("Thai_saraue", KEY_Thai_saraue),
+    ("Thai_sarauee", KEY_Thai_sarauee),
+    ("Thai_sarauu", KEY_Thai_sarauu),
+    ("Thai_sorusi", KEY_Thai_sorusi),
+    ("Thai_sosala", KEY_Thai_sosala),
+    ("Thai_soso", KEY_Thai_soso),
+    ("Thai_sosua", KEY_Thai_sosua),
+    ("Thai_thanthakhat", KEY_Thai_thanthakhat),
+    ("Thai_thonangmontho", KEY_Thai_thonangmontho),
+    ("Thai_thophuthao", KEY_Thai_thophuthao),
+    ("Thai_thothahan", KEY_Thai_thothahan),
+    ("Thai_thothan", KEY_Thai_thothan),
+    ("Thai_thothong", KEY_Thai_thothong),
+    ("Thai_thothung", KEY_Thai_thothung),
+    ("Thai_topatak", KEY_Thai_topatak),
+    ("Thai_totao", KEY_Thai_totao),
+    ("Thai_wowaen", KEY_Thai_wowaen),
+    ("Thai_yoyak", KEY_Thai_yoyak),
+    ("Thai_yoying", KEY_Thai_yoying),
+    ("Thorn", KEY_Thorn),
+    ("Touroku", KEY_Touroku),
+    ("Tslash", KEY_Tslash),
+    ("U", KEY_U),
+    ("Uacute", KEY_Uacute),
+    ("Ubelowdot", KEY_Ubelowdot),
+    ("Ubreve", KEY_Ubreve),
+    ("Ucircumflex", KEY_Ucircumflex),
+    ("Udiaeresis", KEY_Udiaeresis),
+    ("Udoubleacute", KEY_Udoubleacute),
+    ("Ugrave", KEY_Ugrave),
+    ("Uhook", KEY_Uhook),
+    ("Uhorn", KEY_Uhorn),
+    ("Uhornacute", KEY_Uhornacute),
+    ("Uhornbelowdot", KEY_Uhornbelowdot),
+    ("Uhorngrave", KEY_Uhorngrave),
+    ("Uhornhook", KEY_Uhornhook),
+    ("Uhorntilde", KEY_Uhorntilde),
+    ("Ukrainian_GHE_WITH_UPTURN", KEY_Ukrainian_GHE_WITH_UPTURN),
+    ("Ukrainian_I", KEY_Ukrainian_I),
+    ("Ukrainian_IE", KEY_Ukrainian_IE),
+    ("Ukrainian_YI", KEY_Ukrainian_YI),
+    ("Ukrainian_ghe_with_upturn", KEY_Ukrainian_ghe_with_upturn),
+    ("Ukrainian_i", KEY_Ukrainian_i),
+    ("Ukrainian_ie", KEY_Ukrainian_ie),
+    ("Ukrainian_yi", KEY_Ukrainian_yi),
+    ("Ukranian_I", KEY_Ukranian_I),
+    ("Ukranian_JE", KEY_Ukranian_JE),
+    ("Ukranian_YI", KEY_Ukranian_YI),
+    ("Ukranian_i", KEY_Ukranian_i),
+    ("Ukranian_je", KEY_Ukranian_je),
+    ("Ukranian_yi", KEY_Ukranian_yi),
+    ("Umacron", KEY_Umacron),
+    ("Undo", KEY_Undo),
+    ("Uogonek", KEY_Uogonek),
+    ("Up", KEY_Up),
+    ("Uring", KEY_Uring),
+    ("Utilde", KEY_Utilde),
+    ("V", KEY_V),
+    ("VoidSymbol", KEY_VoidSymbol),
+    ("W", KEY_W),
+    ("Wacute", KEY_Wacute),
+    ("Wcircumflex", KEY_Wcircumflex),
+    ("Wdiaeresis", KEY_Wdiaeresis),
+    ("Wgrave", KEY_Wgrave),
+    ("WonSign", KEY_WonSign),
+    ("X", KEY_X),
+    ("Xabovedot", KEY_Xabovedot),
+    ("Y", KEY_Y),
+    ("Yacute", KEY_Yacute),
+    ("Ybelowdot", KEY_Ybelowdot),
+    ("Ycircumflex", KEY_Ycircumflex),
+    ("Ydiaeresis", KEY_Ydiaeresis),
+    ("Ygrave", KEY_Ygrave),
+    ("Yhook", KEY_Yhook),
+    ("Ytilde", KEY_Ytilde),
+    ("Z", KEY_Z),
+    ("Zabovedot", KEY_Zabovedot),
+    ("Zacute", KEY_Zacute),
+    ("Zcaron", KEY_Zcaron),
+    ("Zen_Koho", KEY_Zen_Koho),
+    ("Zenkaku", KEY_Zenkaku),
+    ("Zenkaku_Hankaku", KEY_Zenkaku_Hankaku),
+    ("Zstroke", KEY_Zstroke),
+    ("a", KEY_a),
+    ("aacute", KEY_aacute),
+    ("abelowdot", KEY_abelowdot),
+    ("abovedot", KEY_abovedot),
+    ("abreve", KEY_abreve),
+    ("abreveacute", KEY_abreveacute),
+    ("abrevebelowdot", KEY_abrevebelowdot),
+    ("abrevegrave", KEY_abrevegrave),
+    ("abrevehook", KEY_abrevehook),
+    ("abrevetilde", KEY_abrevetilde),
+    ("acircumflex", KEY_acircumflex),
+    ("acircumflexacute", KEY_acircumflexacute),
+    ("acircumflexbelowdot", KEY_acircumflexbelowdot),
+    ("acircumflexgrave", KEY_acircumflexgrave),
+    ("acircumflexhook", KEY_acircumflexhook),
+    ("acircumflextilde", KEY_acircumflextilde),
+    ("acute", KEY_acute),
+    ("adiaeresis", KEY_adiaeresis),
+    ("ae", KEY_ae),
+    ("agrave", KEY_agrave),
+    ("ahook", KEY_ahook),
+    ("amacron", KEY_amacron),
+    ("ampersand", KEY_ampersand),
+    ("aogonek", KEY_aogonek),
+    ("apostrophe", KEY_apostrophe),
+    ("approxeq", KEY_approxeq),
+    ("approximate", KEY_approximate),
+    ("aring", KEY_aring),
+    ("asciicircum", KEY_asciicircum),
+    ("asciitilde", KEY_asciitilde),
+    ("asterisk", KEY_asterisk),
+    ("at", KEY_at),
+    ("atilde", KEY_atilde),
+    ("b", KEY_b),
+    ("babovedot", KEY_babovedot),
+    ("backslash", KEY_backslash),
+    ("ballotcross", KEY_ballotcross),
+    ("bar", KEY_bar),
+    ("because", KEY_because),
+    ("blank", KEY_blank),
+    ("botintegral", KEY_botintegral),
+    ("botleftparens", KEY_botleftparens),
+    ("botleftsqbracket", KEY_botleftsqbracket),
+    ("botleftsummation", KEY_botleftsummation),
+    ("botrightparens", KEY_botrightparens),
+    ("botrightsqbracket", KEY_botrightsqbracket),
+    ("botrightsummation", KEY_botrightsummation),
+    ("bott", KEY_bott),
+    ("botvertsummationconnector", KEY_botvertsummationconnector),
+    ("braceleft", KEY_braceleft),
+    ("braceright", KEY_braceright),
+    ("bracketleft", KEY_bracketleft),
+    ("bracketright", KEY_bracketright),
+    ("braille_blank", KEY_braille_blank),
+    ("braille_dot_1", KEY_braille_dot_1),
+    ("braille_dot_10", KEY_braille_dot_10),
+    ("braille_dot_2", KEY_braille_dot_2),
+    ("braille_dot_3", KEY_braille_dot_3),
+    ("braille_dot_4", KEY_braille_dot_4),
+    ("braille_dot_5", KEY_braille_dot_5),
+    ("braille_dot_6", KEY_braille_dot_6),
+    ("braille_dot_7", KEY_braille_dot_7),
+    ("braille_dot_8", KEY_braille_dot_8),
+    ("braille_dot_9", KEY_braille_dot_9),
+    ("braille_dots_1", KEY_braille_dots_1),
+    ("braille_dots_12", KEY_braille_dots_12),
+    ("braille_dots_123", KEY_braille_dots_123),
+    ("braille_dots_1234", KEY_braille_dots_1234),
+    ("braille_dots_12345", KEY_braille_dots_12345),
+    ("braille_dots_123456", KEY_braille_dots_123456),
+    ("braille_dots_1234567", KEY_braille_dots_1234567),
+    ("braille_dots_12345678", KEY_braille_dots_12345678),
+    ("braille_dots_1234568", KEY_braille_dots_1234568),
+    ("braille_dots_123457", KEY_braille_dots_123457),
+    ("braille_dots_1234578", KEY_braille_dots_1234578),
+    ("braille_dots_123458", KEY_braille_dots_123458),
+    ("braille_dots_12346", KEY_braille_dots_12346),
+    ("braille_dots_123467", KEY_braille_dots_123467),
+    ("braille_dots_1234678", KEY_braille_dots_1234678),
+    ("braille_dots_123468", KEY_braille_dots_123468),
+    ("braille_dots_12347", KEY_braille_dots_12347),
+    ("braille_dots_123478", KEY_braille_dots_123478),
+    ("braille_dots_12348", KEY_braille_dots_12348),
+    ("braille_dots_1235", KEY_braille_dots_1235),
+    ("braille_dots_12356", KEY_braille_dots_12356),
+    ("braille_dots_123567", KEY_braille_dots_123567),
+    ("braille_dots_1235678", KEY_braille_dots_1235678),
+    ("braille_dots_123568", KEY_braille_dots_123568),
+    ("braille_dots_12357", KEY_braille_dots_12357),
+    ("braille_dots_123578", KEY_braille_dots_123578),
+    ("braille_dots_12358", KEY_braille_dots_12358),
+    ("braille_dots_1236", KEY_braille_dots_1236),
+    ("braille_dots_12367", KEY_braille_dots_12367),
+    ("braille_dots_123678", KEY_braille_dots_123678),
+    ("braille_dots_12368", KEY_braille_dots_12368),
+    ("braille_dots_1237", KEY_braille_dots_1237),
+    ("braille_dots_12378", KEY_braille_dots_12378),
+    ("braille_dots_1238", KEY_braille_dots_1238),
+    ("braille_dots_124", KEY_braille_dots_124),
+    ("braille_dots_1245", KEY_braille_dots_1245),
+    ("braille_dots_12456", KEY_braille_dots_12456),
+    ("braille_dots_124567", KEY_braille_dots_124567),
+    ("braille_dots_1245678", KEY_braille_dots_1245678),
+    ("braille_dots_124568", KEY_braille_dots_124568),
+    ("braille_dots_12457", KEY_braille_dots_12457),
+    ("braille_dots_124578", KEY_braille_dots_124578),
+    ("braille_dots_12458", KEY_braille_dots_12458),
+    ("braille_dots_1246", KEY_braille_dots_1246),
+    ("braille_dots_12467", KEY_braille_dots_12467),
+    ("braille_dots_124678", KEY_braille_dots_124678),
+    ("braille_dots_12468", KEY_braille_dots_12468),
+    ("braille_dots_1247", KEY_braille_dots_1247),
+    ("braille_dots_12478", KEY_braille_dots_12478),
+    ("braille_dots_1248", KEY_braille_dots_1248),
+    ("braille_dots_125", KEY_braille_dots_125),
+    ("braille_dots_1256", KEY_braille_dots_1256),
+    ("braille_dots_12567", KEY_braille_dots_12567),
+    ("braille_dots_125678", KEY_braille_dots_125678),
+    ("braille_dots_12568", KEY_braille_dots_12568),
+    ("braille_dots_1257", KEY_braille_dots_1257),
+    ("braille_dots_12578", KEY_braille_dots_12578),
+    ("braille_dots_1258", KEY_braille_dots_1258),
+    ("braille_dots_126", KEY_braille_dots_126),
+    ("braille_dots_1267", KEY_braille_dots_1267),
+    ("braille_dots_12678", KEY_braille_dots_12678),
+    ("braille_dots_1268", KEY_braille_dots_1268),
+    ("braille_dots_127", KEY_braille_dots_127),
+    ("braille_dots_1278", KEY_braille_dots_1278),
+    ("braille_dots_128", KEY_braille_dots_128),
+    ("braille_dots_13", KEY_braille_dots_13),
+    ("braille_dots_134", KEY_braille_dots_134),
+    ("braille_dots_1345", KEY_braille_dots_1345),
+    ("braille_dots_13456", KEY_braille_dots_13456),
+    ("braille_dots_134567", KEY_braille_dots_134567),
+    ("braille_dots_1345678", KEY_braille_dots_1345678),
+    ("braille_dots_134568", KEY_braille_dots_134568),
+    ("braille_dots_13457", KEY_braille_dots_13457),
+    ("braille_dots_134578", KEY_braille_dots_134578),
+    ("braille_dots_13458", KEY_braille_dots_13458),
+    ("braille_dots_1346", KEY_braille_dots_1346),
+    ("braille_dots_13467", KEY_braille_dots_13467),
+    ("braille_dots_134678", KEY_braille_dots_134678),
+    ("braille_dots_13468", KEY_braille_dots_13468),
+    ("braille_dots_1347", KEY_braille_dots_1347),
+    ("braille_dots_13478", KEY_braille_dots_13478),
+    ("braille_dots_1348", KEY_braille_dots_1348),
+    ("braille_dots_135", KEY_braille_dots_135),
+    ("braille_dots_1356", KEY_braille_dots_1356),
+    ("braille_dots_13567", KEY_braille_dots_13567),
+    ("braille_dots_135678", KEY_braille_dots_135678),
+    ("braille_dots_13568", KEY_braille_dots_13568),
+    ("braille_dots_1357", KEY_braille_dots_1357),
+    ("braille_dots_13578", KEY_braille_dots_13578),
+    ("braille_dots_1358", KEY_braille_dots_1358),
+    ("braille_dots_136", KEY_braille_dots_136),
+    ("braille_dots_1367", KEY_braille_dots_1367),
+    ("braille_dots_13678", KEY_braille_dots_13678),
+    ("braille_dots_1368", KEY_braille_dots_1368),
+    ("braille_dots_137", KEY_braille_dots_137),
+    ("braille_dots_1378", KEY_braille_dots_1378),
+    ("braille_dots_138", KEY_braille_dots_138),
+    ("braille_dots_14", KEY_braille_dots_14),
+    ("braille_dots_145", KEY_braille_dots_145),
+    ("braille_dots_1456", KEY_braille_dots_1456),
+    ("braille_dots_14567", KEY_braille_dots_14567),
+    ("braille_dots_145678", KEY_braille_dots_145678),
+    ("braille_dots_14568", KEY_braille_dots_14568),
+    ("braille_dots_1457", KEY_braille_dots_1457),
+    ("braille_dots_14578", KEY_braille_dots_14578),
+    ("braille_dots_1458", KEY_braille_dots_1458),
+    ("braille_dots_146", KEY_braille_dots_146),
+    ("braille_dots_1467", KEY_braille_dots_1467),
+    ("braille_dots_14678", KEY_braille_dots_14678),
+    ("braille_dots_1468", KEY_braille_dots_1468),
+    ("braille_dots_147", KEY_braille_dots_147),
+    ("braille_dots_1478", KEY_braille_dots_1478),
+    ("braille_dots_148", KEY_braille_dots_148),
+    ("braille_dots_15", KEY_braille_dots_15),
+    ("braille_dots_156", KEY_braille_dots_156),
+    ("braille_dots_1567", KEY_braille_dots_1567),
+    ("braille_dots_15678", KEY_braille_dots_15678),
+    ("braille_dots_1568", KEY_braille_dots_1568),
+    ("braille_dots_157", KEY_braille_dots_157),
+    ("braille_dots_1578", KEY_braille_dots_1578),
+    ("braille_dots_158", KEY_braille_dots_158),
+    ("braille_dots_16", KEY_braille_dots_16),
+    ("braille_dots_167", KEY_braille_dots_167),
+    ("braille_dots_1678", KEY_braille_dots_1678),
+    ("braille_dots_168", KEY_braille_dots_168),
+    ("braille_dots_17", KEY_braille_dots_17),
+    ("braille_dots_178", KEY_braille_dots_178),
+    ("braille_dots_18", KEY_braille_dots_18),
+    ("braille_dots_2", KEY_braille_dots_2),
+    ("braille_dots_23", KEY_braille_dots_23),
+    ("braille_dots_234", KEY_braille_dots_234),
+    ("braille_dots_2345", KEY_braille_dots_2345),
+    ("braille_dots_23456", KEY_braille_dots_23456),
+    ("braille_dots_234567", KEY_braille_dots_234567),
+    ("braille_dots_2345678", KEY_braille_dots_2345678),
+    ("braille_dots_234568", KEY_braille_dots_234568),
+    ("braille_dots_23457", KEY_braille_dots_23457),
+    ("braille_dots_234578", KEY_braille_dots_234578),
+    ("braille_dots_23458", KEY_braille_dots_23458),
+    ("braille_dots_2346", KEY_braille_dots_2346),
+    ("braille_dots_23467", KEY_braille_dots_23467),
+    ("braille_dots_234678", KEY_braille_dots_234678),
+    ("braille_dots_23468", KEY_braille_dots_23468),
+    ("braille_dots_2347", KEY_braille_dots_2347),
+    ("braille_dots_23478", KEY_braille_dots_23478),
+    ("braille_dots_2348", KEY_braille_dots_2348),
+    ("braille_dots_235", KEY_braille_dots_235),
+    ("braille_dots_2356", KEY_braille_dots_2356),
+    ("braille_dots_23567", KEY_braille_dots_23567),
+    ("braille_dots_235678", KEY_braille_dots_235678),
+    ("braille_dots_23568", KEY_braille_dots_23568),
+    ("braille_dots_2357", KEY_braille_dots_2357),
+    ("braille_dots_23578", KEY_braille_dots_23578),
+    ("braille_dots_2358", KEY_braille_dots_2358),
+    ("braille_dots_236", KEY_braille_dots_236),
+    ("braille_dots_2367", KEY_braille_dots_2367),
+    ("braille_dots_23678", KEY_braille_dots_23678),
+    ("braille_dots_2368", KEY_braille_dots_2368),
+    ("braille_dots_237", KEY_braille_dots_237),
+    ("braille_dots_2378", KEY_braille_dots_2378),
+    ("braille_dots_238", KEY_braille_dots_238),
+    ("braille_dots_24", KEY_braille_dots_24),
+    ("braille_dots_245", KEY_braille_dots_245),
+    ("braille_dots_2456", KEY_braille_dots_2456),
+    ("braille_dots_24567", KEY_braille_dots_24567),
+    ("braille_dots_245678", KEY_braille_dots_245678),
+    ("braille_dots_24568", KEY_braille_dots_24568),
+    ("braille_dots_2457", KEY_braille_dots_2457),
+    ("braille_dots_24578", KEY_braille_dots_24578),
+    ("braille_dots_2458", KEY_braille_dots_2458),
+    ("braille_dots_246", KEY_braille_dots_246),
+    ("braille_dots_2467", KEY_braille_dots_2467),
+    ("braille_dots_24678", KEY_braille_dots_24678),
+    ("braille_dots_2468", KEY_braille_dots_2468),
+    ("braille_dots_247", KEY_braille_dots_247),
+    ("braille_dots_2478", KEY_braille_dots_2478),
+    ("braille_dots_248", KEY_braille_dots_248),
+    ("braille_dots_25", KEY_braille_dots_25),
+    ("braille_dots_256", KEY_braille_dots_256),
+    ("braille_dots_2567", KEY_braille_dots_2567),
+    ("braille_dots_25678", KEY_braille_dots_25678),
+    ("braille_dots_2568", KEY_braille_dots_2568),
+    ("braille_dots_257", KEY_braille_dots_257),
+    ("braille_dots_2578", KEY_braille_dots_2578),
+    ("braille_dots_258", KEY_braille_dots_258),
+    ("braille_dots_26", KEY_braille_dots_26),
+    ("braille_dots_267", KEY_braille_dots_267),
+    ("braille_dots_2678", KEY_braille_dots_2678),
+    ("braille_dots_268", KEY_braille_dots_268),
+    ("braille_dots_27", KEY_braille_dots_27),
+    ("braille_dots_278", KEY_braille_dots_278),
+    ("braille_dots_28", KEY_braille_dots_28),
+    ("braille_dots_3", KEY_braille_dots_3),
+    ("braille_dots_34", KEY_braille_dots_34),
+    ("braille_dots_345", KEY_braille_dots_345),
+    ("braille_dots_3456", KEY_braille_dots_3456),
+    ("braille_dots_34567", KEY_braille_dots_34567),
+    ("braille_dots_345678", KEY_braille_dots_345678),
+    ("braille_dots_34568", KEY_braille_dots_34568),
+    ("braille_dots_3457", KEY_braille_dots_3457),
+    ("braille_dots_34578", KEY_braille_dots_34578),
+    ("braille_dots_3458", KEY_braille_dots_3458),
+    ("braille_dots_346", KEY_braille_dots_346),
+    ("braille_dots_3467", KEY_braille_dots_3467),
+    ("braille_dots_34678", KEY_braille_dots_34678),
+    ("braille_dots_3468", KEY_braille_dots_3468),
+    ("braille_dots_347", KEY_braille_dots_347),
+    ("braille_dots_3478", KEY_braille_dots_3478),
+    ("braille_dots_348", KEY_braille_dots_348),
+    ("braille_dots_35", KEY_braille_dots_35),
+    ("braille_dots_356", KEY_braille_dots_356),
+    ("braille_dots_3567", KEY_braille_dots_3567),
+    ("braille_dots_35678", KEY_braille_dots_35678),
+    ("braille_dots_3568", KEY_braille_dots_3568),
+    ("braille_dots_357", KEY_braille_dots_357),
+    ("braille_dots_3578", KEY_braille_dots_3578),
+    ("braille_dots_358", KEY_braille_dots_358),
+    ("braille_dots_36", KEY_braille_dots_36),
+    ("braille_dots_367", KEY_braille_dots_367),
+    ("braille_dots_3678", KEY_braille_dots_3678),
+    ("braille_dots_368", KEY_braille_dots_368),
+    ("braille_dots_37", KEY_braille_dots_37),
+    ("braille_dots_378", KEY_braille_dots_378),
+    ("braille_dots_38", KEY_braille_dots_38),
+    ("braille_dots_4", KEY_braille_dots_4),
+    ("braille_dots_45", KEY_braille_dots_45),
+    ("braille_dots_456", KEY_braille_dots_456),
+    ("braille_dots_4567", KEY_braille_dots_4567),
+    ("braille_dots_45678", KEY_braille_dots_45678),
+    ("braille_dots_4568", KEY_braille_dots_4568),
+    ("braille_dots_457", KEY_braille_dots_457),
+    ("braille_dots_4578", KEY_braille_dots_4578),
+    ("braille_dots_458", KEY_braille_dots_458),
+    ("braille_dots_46", KEY_braille_dots_46),
+    ("braille_dots_467", KEY_braille_dots_467),
+    ("braille_dots_4678", KEY_braille_dots_4678),
+    ("braille_dots_468", KEY_braille_dots_468),
+    ("braille_dots_47", KEY_braille_dots_47),
+    ("braille_dots_478", KEY_braille_dots_478),
+    ("braille_dots_48", KEY_braille_dots_48),
+    ("braille_dots_5", KEY_braille_dots_5),
+    ("braille_dots_56", KEY_braille_dots_56),
+    ("braille_dots_567", KEY_braille_dots_567),
+    ("braille_dots_5678", KEY_braille_dots_5678),
+    ("braille_dots_568", KEY_braille_dots_568),
+    ("braille_dots_57", KEY_braille_dots_57),
+    ("braille_dots_578", KEY_braille_dots_578),
+    ("braille_dots_58", KEY_braille_dots_58),
+    ("braille_dots_6", KEY_braille_dots_6),
+    ("braille_dots_67", KEY_braille_dots_67),
+    ("braille_dots_678", KEY_braille_dots_678),
+    ("braille_dots_68", KEY_braille_dots_68),
+    ("braille_dots_7", KEY_braille_dots_7),
+    ("braille_dots_78", KEY_braille_dots_78),
+    ("braille_dots_8", KEY_braille_dots_8),
+    ("breve", KEY_breve),
+    ("brokenbar", KEY_brokenbar),
+    ("c", KEY_c),
+    ("c_h", KEY_c_h),
+    ("cabovedot", KEY_cabovedot),
+    ("cacute", KEY_cacute),
+    ("careof", KEY_careof),
+    ("caret", KEY_caret),
+    ("caron", KEY_caron),
+    ("ccaron", KEY_ccaron),
+    ("ccedilla", KEY_ccedilla),
+    ("ccircumflex", KEY_ccircumflex),
+    ("cedilla", KEY_cedilla),
+    ("cent", KEY_cent),
+    ("ch", KEY_ch),
+    ("checkerboard", KEY_checkerboard),
+    ("checkmark", KEY_checkmark),
+    ("circle", KEY_circle),
+    ("club", KEY_club),
+    ("colon", KEY_colon),
+    ("combining_acute", KEY_combining_acute),
+    ("combining_belowdot", KEY_combining_belowdot),
+    ("combining_grave", KEY_combining_grave),
+    ("combining_hook", KEY_combining_hook),
+    ("combining_tilde", KEY_combining_tilde),
+    ("comma", KEY_comma),
+    ("containsas", KEY_containsas),
+    ("copyright", KEY_copyright),
+    ("cr", KEY_cr),
+    ("crossinglines", KEY_crossinglines),
+    ("cuberoot", KEY_cuberoot),
+    ("currency", KEY_currency),
+    ("cursor", KEY_cursor),
+    ("d", KEY_d),
+    ("dabovedot", KEY_dabovedot),
+    ("dagger", KEY_dagger),
+    ("dcaron", KEY_dcaron),
+    ("dead_A", KEY_dead_A),
+    ("dead_E", KEY_dead_E),
+    ("dead_I", KEY_dead_I),
+    ("dead_O", KEY_dead_O),
+    ("dead_U", KEY_dead_U),
+    ("dead_a", KEY_dead_a),
+    ("dead_abovecomma", KEY_dead_abovecomma),
+    ("dead_abovedot", KEY_dead_abovedot),
+    ("dead_abovereversedcomma", KEY_dead_abovereversedcomma),
+    ("dead_abovering", KEY_dead_abovering),
+    ("dead_aboveverticalline", KEY_dead_aboveverticalline),
+    ("dead_acute", KEY_dead_acute),
+    ("dead_belowbreve", KEY_dead_belowbreve),
+    ("dead_belowcircumflex", KEY_dead_belowcircumflex),
+    ("dead_belowcomma", KEY_dead_belowcomma),
+    ("dead_belowdiaeresis", KEY_dead_belowdiaeresis),
+    ("dead_belowdot", KEY_dead_belowdot),
+    ("dead_belowmacron", KEY_dead_belowmacron),
+    ("dead_belowring", KEY_dead_belowring),
+    ("dead_belowtilde", KEY_dead_belowtilde),
+    ("dead_belowverticalline", KEY_dead_belowverticalline),
+    ("dead_breve", KEY_dead_breve),
+    ("dead_capital_schwa", KEY_dead_capital_schwa),
+    ("dead_caron", KEY_dead_caron),
+    ("dead_cedilla", KEY_dead_cedilla),
+    ("dead_circumflex", KEY_dead_circumflex),
+    ("dead_currency", KEY_dead_currency),
+    ("dead_dasia", KEY_dead_dasia),
+    ("dead_diaeresis", KEY_dead_diaeresis),
+    ("dead_doubleacute", KEY_dead_doubleacute),
+    ("dead_doublegrave", KEY_dead_doublegrave),
+    ("dead_e", KEY_dead_e),
+    ("dead_grave", KEY_dead_grave),
+    ("dead_greek", KEY_dead_greek),
+    ("dead_hook", KEY_dead_hook),
+    ("dead_horn", KEY_dead_horn),
+    ("dead_i", KEY_dead_i),
+    ("dead_invertedbreve", KEY_dead_invertedbreve),
+    ("dead_iota", KEY_dead_iota),
+    ("dead_longsolidusoverlay", KEY_dead_longsolidusoverlay),
+    ("dead_lowline", KEY_dead_lowline),
+    ("dead_macron", KEY_dead_macron),
+    ("dead_o", KEY_dead_o),
+    ("dead_ogonek", KEY_dead_ogonek),
+    ("dead_perispomeni", KEY_dead_perispomeni),
+    ("dead_psili", KEY_dead_psili),
+    ("dead_semivoiced_sound", KEY_dead_semivoiced_sound),
+    ("dead_small_schwa", KEY_dead_small_schwa),
+    ("dead_stroke", KEY_dead_stroke),
+    ("dead_tilde", KEY_dead_tilde),
+    ("dead_u", KEY_dead_u),
+    ("dead_voiced_sound", KEY_dead_voiced_sound),
+    ("decimalpoint", KEY_decimalpoint),
+    ("degree", KEY_degree),
+    ("diaeresis", KEY_diaeresis),
+    ("diamond", KEY_diamond),
+    ("digitspace", KEY_digitspace),
+    ("dintegral", KEY_dintegral),
+    ("division", KEY_division),
+    ("dollar", KEY_dollar),
+    ("doubbaselinedot", KEY_doubbaselinedot),
+    ("doubleacute", KEY_doubleacute),
+    ("doubledagger", KEY_doubledagger),
+    ("doublelowquotemark", KEY_doublelowquotemark),
+    ("downarrow", KEY_downarrow),
+    ("downcaret", KEY_downcaret),
+    ("downshoe", KEY_downshoe),
+    ("downstile", KEY_downstile),
+    ("downtack", KEY_downtack),
+    ("dstroke", KEY_dstroke),
+    ("e", KEY_e),
+    ("eabovedot", KEY_eabovedot),
+    ("eacute", KEY_eacute),
+    ("ebelowdot", KEY_ebelowdot),
+    ("ecaron", KEY_ecaron),
+    ("ecircumflex", KEY_ecircumflex),
+    ("ecircumflexacute", KEY_ecircumflexacute),
+    ("ecircumflexbelowdot", KEY_ecircumflexbelowdot),
+    ("ecircumflexgrave", KEY_ecircumflexgrave),
+    ("ecircumflexhook", KEY_ecircumflexhook),
+    ("ecircumflextilde", KEY_ecircumflextilde),
+    ("ediaeresis", KEY_ediaeresis),
+    ("egrave", KEY_egrave),
+    ("ehook", KEY_ehook),
+    ("eightsubscript", KEY_eightsubscript),
+    ("eightsuperior", KEY_eightsuperior),
+    ("elementof", KEY_elementof),
+    ("ellipsis", KEY_ellipsis),
+    ("em3space", KEY_em3space),
+    ("em4space", KEY_em4space),
+    ("emacron", KEY_emacron),
+    ("emdash", KEY_emdash),
+    ("emfilledcircle", KEY_emfilledcircle),
+    ("emfilledrect", KEY_emfilledrect),
+    ("emopencircle", KEY_emopencircle),
+    ("emopenrectangle", KEY_emopenrectangle),
+    ("emptyset", KEY_emptyset),
+    ("emspace", KEY_emspace),
+    ("endash", KEY_endash),
+    ("enfilledcircbullet", KEY_enfilledcircbullet),
+    ("enfilledsqbullet", KEY_enfilledsqbullet),
+    ("eng", KEY_eng),
+    ("enopencircbullet", KEY_enopencircbullet),
+    ("enopensquarebullet", KEY_enopensquarebullet),
+    ("enspace", KEY_enspace),
+    ("eogonek", KEY_eogonek),
+    ("equal", KEY_equal),
+    ("eth", KEY_eth),
+    ("etilde", KEY_etilde),
+    ("exclam", KEY_exclam),
+    ("exclamdown", KEY_exclamdown),
+    ("ezh", KEY_ezh),
+    ("f", KEY_f),
+    ("fabovedot", KEY_fabovedot),
+    ("femalesymbol", KEY_femalesymbol),
+    ("ff", KEY_ff),
+    ("figdash", KEY_figdash),
+    ("filledlefttribullet", KEY_filledlefttribullet),
+    ("filledrectbullet", KEY_filledrectbullet),
+    ("filledrighttribullet", KEY_filledrighttribullet),
+    ("filledtribulletdown", KEY_filledtribulletdown),
+    ("filledtribulletup", KEY_filledtribulletup),
+    ("fiveeighths", KEY_fiveeighths),
+    ("fivesixths", KEY_fivesixths),
+    ("fivesubscript", KEY_fivesubscript),
+    ("fivesuperior", KEY_fivesuperior),
+    ("fourfifths", KEY_fourfifths),
+    ("foursubscript", KEY_foursubscript),
+    ("foursuperior", KEY_foursuperior),
+    ("fourthroot", KEY_fourthroot),
+    ("function", KEY_function),
+    ("g", KEY_g),
+    ("gabovedot", KEY_gabovedot),
+    ("gbreve", KEY_gbreve),
+    ("gcaron", KEY_gcaron),
+    ("gcedilla", KEY_gcedilla),
+    ("gcircumflex", KEY_gcircumflex),
+    ("grave", KEY_grave),
+    ("greater", KEY_greater),
+    ("greaterthanequal", KEY_greaterthanequal),
+    ("guillemotleft", KEY_guillemotleft),
+    ("guillemotright", KEY_guillemotright),
+    ("h", KEY_h),
+    ("hairspace", KEY_hairspace),
+    ("hcircumflex", KEY_hcircumflex),
+    ("heart", KEY_heart),
+    ("hebrew_aleph", KEY_hebrew_aleph),
+    ("hebrew_ayin", KEY_hebrew_ayin),
+    ("hebrew_bet", KEY_hebrew_bet),
+    ("hebrew_beth", KEY_hebrew_beth),
+    ("hebrew_chet", KEY_hebrew_chet),
+    ("hebrew_dalet", KEY_hebrew_dalet),
+    ("hebrew_daleth", KEY_hebrew_daleth),
+    ("hebrew_doublelowline", KEY_hebrew_doublelowline),
+    ("hebrew_finalkaph", KEY_hebrew_finalkaph),
+    ("hebrew_finalmem", KEY_hebrew_finalmem),
+    ("hebrew_finalnun", KEY_hebrew_finalnun),
+    ("hebrew_finalpe", KEY_hebrew_finalpe),
+    ("hebrew_finalzade", KEY_hebrew_finalzade),
+    ("hebrew_finalzadi", KEY_hebrew_finalzadi),
+    ("hebrew_gimel", KEY_hebrew_gimel),
+    ("hebrew_gimmel", KEY_hebrew_gimmel),
+    ("hebrew_he", KEY_hebrew_he),
+    ("hebrew_het", KEY_hebrew_het),
+    ("hebrew_kaph", KEY_hebrew_kaph),
+    ("hebrew_kuf", KEY_hebrew_kuf),
+    ("hebrew_lamed", KEY_hebrew_lamed),
+    ("hebrew_mem", KEY_hebrew_mem),
+    ("hebrew_nun", KEY_hebrew_nun),
+    ("hebrew_pe", KEY_hebrew_pe),
+    ("hebrew_qoph", KEY_hebrew_qoph),
+    ("hebrew_resh", KEY_hebrew_resh),
+    ("hebrew_samech", KEY_hebrew_samech),
+    ("hebrew_samekh", KEY_hebrew_samekh),
+    ("hebrew_shin", KEY_hebrew_shin),
+    ("hebrew_taf", KEY_hebrew_taf),
+    ("hebrew_taw", KEY_hebrew_taw),
+    ("hebrew_tet", KEY_hebrew_tet),
+    ("hebrew_teth", KEY_hebrew_teth),
+    ("hebrew_waw", KEY_hebrew_waw),
+    ("hebrew_yod", KEY_hebrew_yod),
+    ("hebrew_zade", KEY_hebrew_zade),
+    ("hebrew_zadi", KEY_hebrew_zadi),
+    ("hebrew_zain", KEY_hebrew_zain),
+    ("hebrew_zayin", KEY_hebrew_zayin),
+    ("hexagram", KEY_hexagram),
+    ("horizconnector", KEY_horizconnector),
+    ("horizlinescan1", KEY_horizlinescan1),
+    ("horizlinescan3", KEY_horizlinescan3),
+    ("horizlinescan5", KEY_horizlinescan5),
+    ("horizlinescan7", KEY_horizlinescan7),
+    ("horizlinescan9", KEY_horizlinescan9),
+    ("hstroke", KEY_hstroke),
+    ("ht", KEY_ht),
+    ("hyphen", KEY_hyphen),
+    ("i", KEY_i),
+    ("iacute", KEY_iacute),
+    ("ibelowdot", KEY_ibelowdot),
+    ("ibreve", KEY_ibreve),
+    ("icircumflex", KEY_icircumflex),
+    ("identical", KEY_identical),
+    ("idiaeresis", KEY_idiaeresis),
+    ("idotless", KEY_idotless),
+    ("ifonlyif", KEY_ifonlyif),
+    ("igrave", KEY_igrave),
+    ("ihook", KEY_ihook),
+    ("imacron", KEY_imacron),
+    ("implies", KEY_implies),
+    ("includedin", KEY_includedin),
+    ("includes", KEY_includes),
+    ("infinity", KEY_infinity),
+    ("integral", KEY_integral),
+    ("intersection", KEY_intersection),
+    ("iogonek", KEY_iogonek),
+    ("itilde", KEY_itilde),
+    ("j", KEY_j),
+    ("jcircumflex", KEY_jcircumflex),
+    ("jot", KEY_jot),
+    ("k", KEY_k),
+    ("kana_A", KEY_kana_A),
+    ("kana_CHI", KEY_kana_CHI),
+    ("kana_E", KEY_kana_E),
+    ("kana_FU", KEY_kana_FU),
+    ("kana_HA", KEY_kana_HA),
+    ("kana_HE", KEY_kana_HE),
+    ("kana_HI", KEY_kana_HI),
+    ("kana_HO", KEY_kana_HO),
+    ("kana_HU", KEY_kana_HU),
+    ("kana_I", KEY_kana_I),
+    ("kana_KA", KEY_kana_KA),
+    ("kana_KE", KEY_kana_KE),
+    ("kana_KI", KEY_kana_KI),
+    ("kana_KO", KEY_kana_KO),
+    ("kana_KU", KEY_kana_KU),
+    ("kana_MA", KEY_kana_MA),
+    ("kana_ME", KEY_kana_ME),
+    ("kana_MI", KEY_kana_MI),
+    ("kana_MO", KEY_kana_MO),
+    ("kana_MU", KEY_kana_MU),
+    ("kana_N", KEY_kana_N),
+    ("kana_NA", KEY_kana_NA),
+    ("kana_NE", KEY_kana_NE),
+    ("kana_NI", KEY_kana_NI),
+    ("kana_NO", KEY_kana_NO),
+    ("kana_NU", KEY_kana_NU),
+    ("kana_O", KEY_kana_O),
+    ("kana_RA", KEY_kana_RA),
+    ("kana_RE", KEY_kana_RE),
+    ("kana_RI", KEY_kana_RI),
+    ("kana_RO", KEY_kana_RO),
+    ("kana_RU", KEY_kana_RU),
+    ("kana_SA", KEY_kana_SA),
+    ("kana_SE", KEY_kana_SE),
+    ("kana_SHI", KEY_kana_SHI),
+    ("kana_SO", KEY_kana_SO),
+    ("kana_SU", KEY_kana_SU),
+    ("kana_TA", KEY_kana_TA),
+    ("kana_TE", KEY_kana_TE),
+    ("kana_TI", KEY_kana_TI),
+    ("kana_TO", KEY_kana_TO),
+    ("kana_TSU", KEY_kana_TSU),
+    ("kana_TU", KEY_kana_TU),
+    ("kana_U", KEY_kana_U),
+    ("kana_WA", KEY_kana_WA),
+    ("kana_WO", KEY_kana_WO),
+    ("kana_YA", KEY_kana_YA),
+    ("kana_YO", KEY_kana_YO),
+    ("kana_YU", KEY_kana_YU),
+    ("kana_a", KEY_kana_a),
+    ("kana_closingbracket", KEY_kana_closingbracket),
+    ("kana_comma", KEY_kana_comma),
+    ("kana_conjunctive", KEY_kana_conjunctive),
+    ("kana_e", KEY_kana_e),
+    ("kana_fullstop", KEY_kana_fullstop),
+    ("kana_i", KEY_kana_i),
+    ("kana_middledot", KEY_kana_middledot),
+    ("kana_o", KEY_kana_o),
+    ("kana_openingbracket", KEY_kana_openingbracket),
+    ("kana_switch", KEY_kana_switch),
+    ("kana_tsu", KEY_kana_tsu),
+    ("kana_tu", KEY_kana_tu),
+    ("kana_u", KEY_kana_u),
+    ("kana_ya", KEY_kana_ya),
+    ("kana_yo", KEY_kana_yo),
+    ("kana_yu", KEY_kana_yu),
+    ("kappa", KEY_kappa),
+    ("kcedilla", KEY_kcedilla),
+    ("kra", KEY_kra),
+    ("l", KEY_l),
+    ("lacute", KEY_lacute),
+    ("latincross", KEY_latincross),
+    ("lbelowdot", KEY_lbelowdot),
+    ("lcaron", KEY_lcaron),
+    ("lcedilla", KEY_lcedilla),
+    ("leftanglebracket", KEY_leftanglebracket),
+    ("leftarrow", KEY_leftarrow),
+    ("leftcaret", KEY_leftcaret),
+    ("leftdoublequotemark", KEY_leftdoublequotemark),
+    ("leftmiddlecurlybrace", KEY_leftmiddlecurlybrace),
+    ("leftopentriangle", KEY_leftopentriangle),
+    ("leftpointer", KEY_leftpointer),
+    ("leftradical", KEY_leftradical),
+    ("leftshoe", KEY_leftshoe),
+    ("leftsinglequotemark", KEY_leftsinglequotemark),
+    ("leftt", KEY_leftt),
+    ("lefttack", KEY_lefttack),
+    ("less", KEY_less),
+    ("lessthanequal", KEY_lessthanequal),
+    ("lf", KEY_lf),
+    ("logicaland", KEY_logicaland),
+    ("logicalor", KEY_logicalor),
+    ("lowleftcorner", KEY_lowleftcorner),
+    ("lowrightcorner", KEY_lowrightcorner),
+    ("lstroke", KEY_lstroke),
+    ("m", KEY_m),
+    ("mabovedot", KEY_mabovedot),
+    ("macron", KEY_macron),
+    ("malesymbol", KEY_malesymbol),
+    ("maltesecross", KEY_maltesecross),
+    ("marker", KEY_marker),
+    ("masculine", KEY_masculine),
+    ("minus", KEY_minus),
+    ("minutes", KEY_minutes),
+    ("mu", KEY_mu),
+    ("multiply", KEY_multiply),
+    ("musicalflat", KEY_musicalflat),
+    ("musicalsharp", KEY_musicalsharp),
+    ("n", KEY_n),
+    ("nabla", KEY_nabla),
+    ("nacute", KEY_nacute),
+    ("ncaron", KEY_ncaron),
+    ("ncedilla", KEY_ncedilla),
+    ("ninesubscript", KEY_ninesubscript),
+    ("ninesuperior", KEY_ninesuperior),
+    ("nl", KEY_nl),
+    ("nobreakspace", KEY_nobreakspace),
+    ("notapproxeq", KEY_notapproxeq),
+    ("notelementof", KEY_notelementof),
+    ("notequal", KEY_notequal),
+    ("notidentical", KEY_notidentical),
+    ("notsign", KEY_notsign),
+    ("ntilde", KEY_ntilde),
+    ("numbersign", KEY_numbersign),
+    ("numerosign", KEY_numerosign),
+    ("o", KEY_o),
+    ("oacute", KEY_oacute),
+    ("obarred", KEY_obarred),
+    ("obelowdot", KEY_obelowdot),
+    ("ocaron", KEY_ocaron),
+    ("ocircumflex", KEY_ocircumflex),
+    ("ocircumflexacute", KEY_ocircumflexacute),
+    ("ocircumflexbelowdot", KEY_ocircumflexbelowdot),
+    ("ocircumflexgrave", KEY_ocircumflexgrave),
+    ("ocircumflexhook", KEY_ocircumflexhook),
+    ("ocircumflextilde", KEY_ocircumflextilde),
+    ("odiaeresis", KEY_odiaeresis),
+    ("odoubleacute", KEY_odoubleacute),
+    ("oe", KEY_oe),
+    ("ogonek", KEY_ogonek),
+    ("ograve", KEY_ograve),
+    ("ohook", KEY_ohook),
+    ("ohorn", KEY_ohorn),
+    ("ohornacute", KEY_ohornacute),
+    ("ohornbelowdot", KEY_ohornbelowdot),
+    ("ohorngrave", KEY_ohorngrave),
+    ("ohornhook", KEY_ohornhook),
+    ("ohorntilde", KEY_ohorntilde),
+    ("omacron", KEY_omacron),
+    ("oneeighth", KEY_oneeighth),
+    ("onefifth", KEY_onefifth),
+    ("onehalf", KEY_onehalf),
+    ("onequarter", KEY_onequarter),
+    ("onesixth", KEY_onesixth),
+    ("onesubscript", KEY_onesubscript),
+    ("onesuperior", KEY_onesuperior),
+    ("onethird", KEY_onethird),
+    ("ooblique", KEY_ooblique),
+    ("openrectbullet", KEY_openrectbullet),
+    ("openstar", KEY_openstar),
+    ("opentribulletdown", KEY_opentribulletdown),
+    ("opentribulletup", KEY_opentribulletup),
+    ("ordfeminine", KEY_ordfeminine),
+    ("oslash", KEY_oslash),
+    ("otilde", KEY_otilde),
+    ("overbar", KEY_overbar),
+    ("overline", KEY_overline),
+    ("p", KEY_p),
+    ("pabovedot", KEY_pabovedot),
+    ("paragraph", KEY_paragraph),
+    ("parenleft", KEY_parenleft),
+    ("parenright", KEY_parenright),
+    ("partdifferential", KEY_partdifferential),
+    ("partialderivative", KEY_partialderivative),
+    ("percent", KEY_percent),
+    ("period", KEY_period),
+    ("periodcentered", KEY_periodcentered),
+    ("permille", KEY_permille),
+    ("phonographcopyright", KEY_phonographcopyright),
+    ("plus", KEY_plus),
+    ("plusminus", KEY_plusminus),
+    ("prescription", KEY_prescription),
+    ("prolongedsound", KEY_prolongedsound),
+    ("punctspace", KEY_punctspace),
+    ("q", KEY_q),
+    ("quad", KEY_quad),
+    ("question", KEY_question),
+    ("questiondown", KEY_questiondown),
+    ("quotedbl", KEY_quotedbl),
+    ("quoteleft", KEY_quoteleft),
+    ("quoteright", KEY_quoteright),
+    ("r", KEY_r),
+    ("racute", KEY_racute),
+    ("radical", KEY_radical),
+    ("rcaron", KEY_rcaron),
+    ("rcedilla", KEY_rcedilla),
+    ("registered", KEY_registered),
+    ("rightanglebracket", KEY_rightanglebracket),
+    ("rightarrow", KEY_rightarrow),
+    ("rightcaret", KEY_rightcaret),
+    ("rightdoublequotemark", KEY_rightdoublequotemark),
+    ("rightmiddlecurlybrace", KEY_rightmiddlecurlybrace),
+    ("rightmiddlesummation", KEY_rightmiddlesummation),
+    ("rightopentriangle", KEY_rightopentriangle),
+    ("rightpointer", KEY_rightpointer),
+    ("rightshoe", KEY_rightshoe),
+    ("rightsinglequotemark", KEY_rightsinglequotemark),
+    ("rightt", KEY_rightt),
+    ("righttack", KEY_righttack),
+    ("s", KEY_s),
+    ("sabovedot", KEY_sabovedot),
+    ("sacute", KEY_sacute),
+    ("scaron", KEY_scaron),
+    ("scedilla", KEY_scedilla),
+    ("schwa", KEY_schwa),
+    ("scircumflex", KEY_scircumflex),
+    ("script_switch", KEY_script_switch),
+    ("seconds", KEY_seconds),
+    ("section", KEY_section),
+    ("semicolon", KEY_semicolon),
+    ("semivoicedsound", KEY_semivoicedsound),
+    ("seveneighths", KEY_seveneighths),
+    ("sevensubscript", KEY_sevensubscript),
+    ("sevensuperior", KEY_sevensuperior),
+    ("signaturemark", KEY_signaturemark),
+    ("signifblank", KEY_signifblank),
+    ("similarequal", KEY_similarequal),
+    ("singlelowquotemark", KEY_singlelowquotemark),
+    ("sixsubscript", KEY_sixsubscript),
+    ("sixsuperior", KEY_sixsuperior),
+    ("slash", KEY_slash),
+    ("soliddiamond", KEY_soliddiamond),
+    ("space", KEY_space),
+    ("squareroot", KEY_squareroot),
+    ("ssharp", KEY_ssharp),
+    ("sterling", KEY_sterling),
+    ("stricteq", KEY_stricteq),
+    ("t", KEY_t),
+    ("tabovedot", KEY_tabovedot),
+    ("tcaron", KEY_tcaron),
+    ("tcedilla", KEY_tcedilla),
+    ("telephone", KEY_telephone),
+    ("telephonerecorder", KEY_telephonerecorder),
+    ("therefore", KEY_therefore),
+    ("thinspace", KEY_thinspace),
+    ("thorn", KEY_thorn),
+    ("threeeighths", KEY_threeeighths),
+    ("threefifths", KEY_threefifths),
+    ("threequarters", KEY_threequarters),
+    ("threesubscript", KEY_threesubscript),
+    ("threesuperior", KEY_threesuperior),
+    ("tintegral", KEY_tintegral),
+    ("topintegral", KEY_topintegral),
+    ("topleftparens", KEY_topleftparens),
+    ("topleftradical", KEY_topleftradical),
+    ("topleftsqbracket", KEY_topleftsqbracket),
+    ("topleftsummation", KEY_topleftsummation),
+    ("toprightparens", KEY_toprightparens),
+    ("toprightsqbracket", KEY_toprightsqbracket),
+    ("toprightsummation", KEY_toprightsummation),
+    ("topt", KEY_topt),
+    ("topvertsummationconnector", KEY_topvertsummationconnector),
+    ("trademark", KEY_trademark),
+    ("trademarkincircle", KEY_trademarkincircle),
+    ("tslash", KEY_tslash),
+    ("twofifths", KEY_twofifths),
+    ("twosubscript", KEY_twosubscript),
+    ("twosuperior", KEY_twosuperior),
+    ("twothirds", KEY_twothirds),
+    ("u", KEY_u),
+    ("uacute", KEY_uacute),
+    ("ubelowdot", KEY_ubelowdot),
+    ("ubreve", KEY_ubreve),
+    ("ucircumflex", KEY_ucircumflex),
+    ("udiaeresis", KEY_udiaeresis),
+    ("udoubleacute", KEY_udoubleacute),
+    ("ugrave", KEY_ugrave),
+    ("uhook", KEY_uhook),
+    ("uhorn", KEY_uhorn),
+    ("uhornacute", KEY_uhornacute),
+    ("uhornbelowdot", KEY_uhornbelowdot),
+    ("uhorngrave", KEY_uhorngrave),
+    ("uhornhook", KEY_uhornhook),
+    ("uhorntilde", KEY_uhorntilde),
+    ("umacron", KEY_umacron),
+    ("underbar", KEY_underbar),
+    ("underscore", KEY_underscore),
+    ("union", KEY_union),
+    ("uogonek", KEY_uogonek),
+    ("uparrow", KEY_uparrow),
+    ("upcaret", KEY_upcaret),
+    ("upleftcorner", KEY_upleftcorner),
+    ("uprightcorner", KEY_uprightcorner),
+    ("upshoe", KEY_upshoe),
+    ("upstile", KEY_upstile),
+    ("uptack", KEY_uptack),
+    ("uring", KEY_uring),
+    ("utilde", KEY_utilde),
+    ("v", KEY_v),
+    ("variation", KEY_variation),
+    ("vertbar", KEY_vertbar),
+    ("vertconnector", KEY_vertconnector),
+    ("voicedsound", KEY_voicedsound),
+    ("vt", KEY_vt),
+    ("w", KEY_w),
+    ("wacute", KEY_wacute),
+    ("wcircumflex", KEY_wcircumflex),
+    ("wdiaeresis", KEY_wdiaeresis),
+    ("wgrave", KEY_wgrave),
+    ("x", KEY_x),
+    ("xabovedot", KEY_xabovedot),
+    ("y", KEY_y),
+    ("yacute", KEY_yacute),
+    ("ybelowdot", KEY_ybelowdot),
+    ("ycircumflex", KEY_ycircumflex),
+    ("ydiaeresis", KEY_ydiaeresis),
+    ("yen", KEY_yen),
+    ("ygrave", KEY_ygrave),
+    ("yhook", KEY_yhook),
+    ("ytilde", KEY_ytilde),
+    ("z", KEY_z),
+    ("zabovedot", KEY_zabovedot),
+    ("zacute", KEY_zacute),
+    ("zcaron", KEY_zcaron),
+    ("zerosubscript", KEY_zerosubscript),
+    ("zerosuperior", KEY_zerosuperior),
+    ("zstroke", KEY_zstroke),
+];
+
+/// Sorted by value, containing only the canonical name for each value
+pub(crate) const BY_VALUE: &[(u32, &str)] = &[
+    (KEY_space, "space"),
+    (KEY_exclam, "exclam"),
+    (KEY_quotedbl, "quotedbl"),
+    (KEY_numbersign, "numbersign"),
+    (KEY_dollar, "dollar"),
+    (KEY_percent, "percent"),
+    (KEY_ampersand, "ampersand"),
+    (KEY_apostrophe, "apostrophe"),
+    (KEY_parenleft, "parenleft"),
+    (KEY_parenright, "parenright"),
+    (KEY_asterisk, "asterisk"),
+    (KEY_plus, "plus"),
+    (KEY_comma, "comma"),
+    (KEY_minus, "minus"),
+    (KEY_period, "period"),
+    (KEY_slash, "slash"),
+    (KEY_0, "0"),
+    (KEY_1, "1"),
+    (KEY_2, "2"),
+    (KEY_3, "3"),
+    (KEY_4, "4"),
+    (KEY_5, "5"),
+    (KEY_6, "6"),
+    (KEY_7, "7"),
+    (KEY_8, "8"),
+    (KEY_9, "9"),
+    (KEY_colon, "colon"),
+    (KEY_semicolon, "semicolon"),
+    (KEY_less, "less"),
+    (KEY_equal, "equal"),
+    (KEY_greater, "greater"),
+    (KEY_question, "question"),
+    (KEY_at, "at"),
+    (KEY_A, "A"),
+    (KEY_B, "B"),
+    (KEY_C, "C"),
+    (KEY_D, "D"),
+    (KEY_E, "E"),
+    (KEY_F, "F"),
+    (KEY_G, "G"),
+    (KEY_H, "H"),
+    (KEY_I, "I"),
+    (KEY_J, "J"),
+    (KEY_K, "K"),
+    (KEY_L, "L"),
+    (KEY_M, "M"),
+    (KEY_N, "N"),
+    (KEY_O, "O"),
+    (KEY_P, "P"),
+    (KEY_Q, "Q"),
+    (KEY_R, "R"),
+    (KEY_S, "S"),
+    (KEY_T, "T"),
+    (KEY_U, "U"),
+    (KEY_V, "V"),
+    (KEY_W, "W"),
+    (KEY_X, "X"),
+    (KEY_Y, "Y"),
+    (KEY_Z, "Z"),
+    (KEY_bracketleft, "bracketleft"),
+    (KEY_backslash, "backslash"),
+    (KEY_bracketright, "bracketright"),
+    (KEY_asciicircum, "asciicircum"),
+    (KEY_underscore, "underscore"),
+    (KEY_grave, "grave"),
+    (KEY_a, "a"),
+    (KEY_b, "b"),
+    (KEY_c, "c"),
+    (KEY_d, "d"),
+    (KEY_e, "e"),
+    (KEY_f, "f"),
+    (KEY_g, "g"),
+    (KEY_h, "h"),
+    (KEY_i, "i"),
+    (KEY_j, "j"),
+    (KEY_k, "k"),
+    (KEY_l, "l"),
+    (KEY_m, "m"),
+    (KEY_n, "n"),
+    (KEY_o, "o"),
+    (KEY_p, "p"),
+    (KEY_q, "q"),
+    (KEY_r, "r"),
+    (KEY_s, "s"),
+    (KEY_t, "t"),
+    (KEY_u, "u"),
+    (KEY_v, "v"),
+    (KEY_w, "w"),
+    (KEY_x, "x"),
+    (KEY_y, "y"),
+    (KEY_z, "z"),
+    (KEY_braceleft, "braceleft"),
+    (KEY_bar, "bar"),
+    (KEY_braceright, "braceright"),
+    (KEY_asciitilde, "asciitilde"),
+    (KEY_nobreakspace, "nobreakspace"),
+    (KEY_exclamdown, "exclamdown"),
+    (KEY_cent, "cent"),
+    (KEY_sterling, "sterling"),
+    (KEY_currency, "currency"),
+    (KEY_yen, "yen"),
+    (KEY_brokenbar, "brokenbar"),
+    (KEY_section, "section"),
+    (KEY_diaeresis, "diaeresis"),
+    (KEY_copyright, "copyright"),
+    (KEY_ordfeminine, "ordfeminine"),
+    (KEY_guillemotleft, "guillemotleft"),
+    (KEY_notsign, "notsign"),
+    (KEY_hyphen, "hyphen"),
+    (KEY_registered, "registered"),
+    (KEY_macron, "macron"),
+    (KEY_degree, "degree"),
+    (KEY_plusminus, "plusminus"),
+    (KEY_twosuperior, "twosuperior"),
+    (KEY_threesuperior, "threesuperior"),
+    (KEY_acute, "acute"),
+    (KEY_mu, "mu"),
+    (KEY_paragraph, "paragraph"),
+    (KEY_periodcentered, "periodcentered"),
+    (KEY_cedilla, "cedilla"),
+    (KEY_onesuperior, "onesuperior"),
+    (KEY_masculine, "masculine"),
+    (KEY_guillemotright, "guillemotright"),
+    (KEY_onequarter, "onequarter"),
+    (KEY_onehalf, "onehalf"),
+    (KEY_threequarters, "threequarters"),
+    (KEY_questiondown, "questiondown"),
+    (KEY_Agrave, "Agrave"),
+    (KEY_Aacute, "Aacute"),
+    (KEY_Acircumflex, "Acircumflex"),
+    (KEY_Atilde, "Atilde"),
+    (KEY_Adiaeresis, "Adiaeresis"),
+    (KEY_Aring, "Aring"),
+    (KEY_AE, "AE"),
+    (KEY_Ccedilla, "Ccedilla"),
+    (KEY_Egrave, "Egrave"),
+    (KEY_Eacute, "Eacute"),
+    (KEY_Ecircumflex, "Ecircumflex"),
+    (KEY_Ediaeresis, "Ediaeresis"),
+    (KEY_Igrave, "Igrave"),
+    (KEY_Iacute, "Iacute"),
+    (KEY_Icircumflex, "Icircumflex"),
+    (KEY_Idiaeresis, "Idiaeresis"),
+    (KEY_ETH, "ETH"),
+    (KEY_Ntilde, "Ntilde"),
+    (KEY_Ograve, "Ograve"),
+    (KEY_Oacute, "Oacute"),
+    (KEY_Ocircumflex, "Ocircumflex"),
+    (KEY_Otilde, "Otilde"),
+    (KEY_Odiaeresis, "Odiaeresis"),
+    (KEY_multiply, "multiply"),
+    (KEY_Oslash, "Oslash"),
+    (KEY_Ugrave, "Ugrave"),
+    (KEY_Uacute, "Uacute"),
+    (KEY_Ucircumflex, "Ucircumflex"),
+    (KEY_Udiaeresis, "Udiaeresis"),
+    (KEY_Yacute, "Yacute"),
+    (KEY_THORN, "THORN"),
+    (KEY_ssharp, "ssharp"),
+    (KEY_agrave, "agrave"),
+    (KEY_aacute, "aacute"),
+    (KEY_acircumflex, "acircumflex"),
+    (KEY_atilde, "atilde"),
+    (KEY_adiaeresis, "adiaeresis"),
+    (KEY_aring, "aring"),
+    (KEY_ae, "ae"),
+    (KEY_ccedilla, "ccedilla"),
+    (KEY_egrave, "egrave"),
+    (KEY_eacute, "eacute"),
+    (KEY_ecircumflex, "ecircumflex"),
+    (KEY_ediaeresis, "ediaeresis"),
+    (KEY_igrave, "igrave"),
+    (KEY_iacute, "iacute"),
+    (KEY_icircumflex, "icircumflex"),
+    (KEY_idiaeresis, "idiaeresis"),
+    (KEY_eth, "eth"),
+    (KEY_ntilde, "ntilde"),
+    (KEY_ograve, "ograve"),
+    (KEY_oacute, "oacute"),
+    (KEY_ocircumflex, "ocircumflex"),
+    (KEY_otilde, "otilde"),
+    (KEY_odiaeresis, "odiaeresis"),
+    (KEY_division, "division"),
+    (KEY_oslash, "oslash"),
+    (KEY_ugrave, "ugrave"),
+    (KEY_uacute, "uacute"),
+    (KEY_ucircumflex, "ucircumflex"),
+    (KEY_udiaeresis, "udiaeresis"),
+    (KEY_yacute, "yacute"),
+    (KEY_thorn, "thorn"),
+    (KEY_ydiaeresis, "ydiaeresis"),
+    (KEY_Aogonek, "Aogonek"),
+    (KEY_breve, "breve"),
+    (KEY_Lstroke, "Lstroke"),
+    (KEY_Lcaron, "Lcaron"),
+    (KEY_Sacute, "Sacute"),
+    (KEY_Scaron, "Scaron"),
+    (KEY_Scedilla, "Scedilla"),
+    (KEY_Tcaron, "Tcaron"),
+    (KEY_Zacute, "Zacute"),
+    (KEY_Zcaron, "Zcaron"),
+    (KEY_Zabovedot, "Zabovedot"),
+    (KEY_aogonek, "aogonek"),
+    (KEY_ogonek, "ogonek"),
+    (KEY_lstroke, "lstroke"),
+    (KEY_lcaron, "lcaron"),
+    (KEY_sacute, "sacute"),
+    (KEY_caron, "caron"),
+    (KEY_scaron, "scaron"),
+    (KEY_scedilla, "scedilla"),
+    (KEY_tcaron, "tcaron"),
+    (KEY_zacute, "zacute"),
+    (KEY_doubleacute, "doubleacute"),
+    (KEY_zcaron, "zcaron"),
+    (KEY_zabovedot, "zabovedot"),
+    (KEY_Racute, "Racute"),
+    (KEY_Abreve, "Abreve"),
+    (KEY_Lacute, "Lacute"),
+    (KEY_Cacute, "Cacute"),
+    (KEY_Ccaron, "Ccaron"),
+    (KEY_Eogonek, "Eogonek"),
+    (KEY_Ecaron, "Ecaron"),
+    (KEY_Dcaron, "Dcaron"),
+    (KEY_Dstroke, "Dstroke"),
+    (KEY_Nacute, "Nacute"),
+    (KEY_Ncaron, "Ncaron"),
+    (KEY_Odoubleacute, "Odoubleacute"),
+    (KEY_Rcaron, "Rcaron"),
+    (KEY_Uring, "Uring"),
+    (KEY_Udoubleacute, "Udoubleacute"),
+    (KEY_Tcedilla, "Tcedilla"),
+    (KEY_racute, "racute"),
+    (KEY_abreve, "abreve"),
+    (KEY_lacute, "lacute"),
+    (KEY_cacute, "cacute"),
+    (KEY_ccaron, "ccaron"),
+    (KEY_eogonek, "eogonek"),
+    (KEY_ecaron, "ecaron"),
+    (KEY_dcaron, "dcaron"),
+    (KEY_dstroke, "dstroke"),
+    (KEY_nacute, "nacute"),
+    (KEY_ncaron, "ncaron"),
+    (KEY_odoubleacute, "odoubleacute"),
+    (KEY_rcaron, "rcaron"),
+    (KEY_uring, "uring"),
+    (KEY_udoubleacute, "udoubleacute"),
+    (KEY_tcedilla, "tcedilla"),
+    (KEY_abovedot, "abovedot"),
+    (KEY_Hstroke, "Hstroke"),
+    (KEY_Hcircumflex, "Hcircumflex"),
+    (KEY_Iabovedot, "Iabovedot"),
+    (KEY_Gbreve, "Gbreve"),
+    (KEY_Jcircumflex, "Jcircumflex"),
+    (KEY_hstroke, "hstroke"),
+    (KEY_hcircumflex, "hcircumflex"),
+    (KEY_idotless, "idotless"),
+    (KEY_gbreve, "gbreve"),
+    (KEY_jcircumflex, "jcircumflex"),
+    (KEY_Cabovedot, "Cabovedot"),
+    (KEY_Ccircumflex, "Ccircumflex"),
+    (KEY_Gabovedot, "Gabovedot"),
+    (KEY_Gcircumflex, "Gcircumflex"),
+    (KEY_Ubreve, "Ubreve"),
+    (KEY_Scircumflex, "Scircumflex"),
+    (KEY_cabovedot, "cabovedot"),
+    (KEY_ccircumflex, "ccircumflex"),
+    (KEY_gabovedot, "gabovedot"),
+    (KEY_gcircumflex, "gcircumflex"),
+    (KEY_ubreve, "ubreve"),
+    (KEY_scircumflex, "scircumflex"),
+    (KEY_kra, "kra"),
+    (KEY_Rcedilla, "Rcedilla"),
+    (KEY_Itilde, "Itilde"),
+    (KEY_Lcedilla, "Lcedilla"),
+    (KEY_Emacron, "Emacron"),
+    (KEY_Gcedilla, "Gcedilla"),
+    (KEY_Tslash, "Tslash"),
+    (KEY_rcedilla, "rcedilla"),
+    (KEY_itilde, "itilde"),
+    (KEY_lcedilla, "lcedilla"),
+    (KEY_emacron, "emacron"),
+    (KEY_gcedilla, "gcedilla"),
+    (KEY_tslash, "tslash"),
+    (KEY_ENG, "ENG"),
+    (KEY_eng, "eng"),
+    (KEY_Amacron, "Amacron"),
+    (KEY_Iogonek, "Iogonek"),
+    (KEY_Eabovedot, "Eabovedot"),
+    (KEY_Imacron, "Imacron"),
+    (KEY_Ncedilla, "Ncedilla"),
+    (KEY_Omacron, "Omacron"),
+    (KEY_Kcedilla, "Kcedilla"),
+    (KEY_Uogonek, "Uogonek"),
+    (KEY_Utilde, "Utilde"),
+    (KEY_Umacron, "Umacron"),
+    (KEY_amacron, "amacron"),
+    (KEY_iogonek, "iogonek"),
+    (KEY_eabovedot, "eabovedot"),
+    (KEY_imacron, "imacron"),
+    (KEY_ncedilla, "ncedilla"),
+    (KEY_omacron, "omacron"),
+    (KEY_kcedilla, "kcedilla"),
+    (KEY_uogonek, "uogonek"),
+    (KEY_utilde, "utilde"),
+    (KEY_umacron, "umacron"),
+    (KEY_overline, "overline"),
+    (KEY_kana_fullstop, "kana_fullstop"),
+    (KEY_kana_openingbracket, "kana_openingbracket"),
+    (KEY_kana_closingbracket, "kana_closingbracket"),
+    (KEY_kana_comma, "kana_comma"),
+    (KEY_kana_conjunctive, "kana_conjunctive"),
+    (KEY_kana_WO, "kana_WO"),
+    (KEY_kana_a, "kana_a"),
+    (KEY_kana_i, "kana_i"),
+    (KEY_kana_u, "kana_u"),
+    (KEY_kana_e, "kana_e"),
+    (KEY_kana_o, "kana_o"),
+    (KEY_kana_ya, "kana_ya"),
+    (KEY_kana_yu, "kana_yu"),
+    (KEY_kana_yo, "kana_yo"),
+    (KEY_kana_tsu, "kana_tsu"),
+    (KEY_prolongedsound, "prolongedsound"),
+    (KEY_kana_A, "kana_A"),
+    (KEY_kana_I, "kana_I"),
+    (KEY_kana_U, "kana_U"),
+    (KEY_kana_E, "kana_E"),
+    (KEY_kana_O, "kana_O"),
+    (KEY_kana_KA, "kana_KA"),
+    (KEY_kana_KI, "kana_KI"),
+    (KEY_kana_KU, "kana_KU"),
+    (KEY_kana_KE, "kana_KE"),
+    (KEY_kana_KO, "kana_KO"),
+    (KEY_kana_SA, "kana_SA"),
+    (KEY_kana_SHI, "kana_SHI"),
+    (KEY_kana_SU, "kana_SU"),
+    (KEY_kana_SE, "kana_SE"),
+    (KEY_kana_SO, "kana_SO"),
+    (KEY_kana_TA, "kana_TA"),
+    (KEY_kana_CHI, "kana_CHI"),
+    (KEY_kana_TSU, "kana_TSU"),
+    (KEY_kana_TE, "kana_TE"),
+    (KEY_kana_TO, "kana_TO"),
+    (KEY_kana_NA, "kana_NA"),
+    (KEY_kana_NI, "kana_NI"),
+    (KEY_kana_NU, "kana_NU"),
+    (KEY_kana_NE, "kana_NE"),
+    (KEY_kana_NO, "kana_NO"),
+    (KEY_kana_HA, "kana_HA"),
+    (KEY_kana_HI, "kana_HI"),
+    (KEY_kana_FU, "kana_FU"),
+    (KEY_kana_HE, "kana_HE"),
+    (KEY_kana_HO, "kana_HO"),
+    (KEY_kana_MA, "kana_MA"),
+    (KEY_kana_MI, "kana_MI"),
+    (KEY_kana_MU, "kana_MU"),
+    (KEY_kana_ME, "kana_ME"),
+    (KEY_kana_MO, "kana_MO"),
+    (KEY_kana_YA, "kana_YA"),
+    (KEY_kana_YU, "kana_YU"),
+    (KEY_kana_YO, "kana_YO"),
+    (KEY_kana_RA, "kana_RA"),
+    (KEY_kana_RI, "kana_RI"),
+    (KEY_kana_RU, "kana_RU"),
+    (KEY_kana_RE, "kana_RE"),
+    (KEY_kana_RO, "kana_RO"),
+    (KEY_kana_WA, "kana_WA"),
+    (KEY_kana_N, "kana_N"),
+    (KEY_voicedsound, "voicedsound"),
+    (KEY_semivoicedsound, "semivoicedsound"),
+    (KEY_Arabic_comma, "Arabic_comma"),
+    (KEY_Arabic_semicolon, "Arabic_semicolon"),
+    (KEY_Arabic_question_mark, "Arabic_question_mark"),
+    (KEY_Arabic_hamza, "Arabic_hamza"),
+    (KEY_Arabic_maddaonalef, "Arabic_maddaonalef"),
+    (KEY_Arabic_hamzaonalef, "Arabic_hamzaonalef"),
+    (KEY_Arabic_hamzaonwaw, "Arabic_hamzaonwaw"),
+    (KEY_Arabic_hamzaunderalef, "Arabic_hamzaunderalef"),
+    (KEY_Arabic_hamzaonyeh, "Arabic_hamzaonyeh"),
+    (KEY_Arabic_alef, "Arabic_alef"),
+    (KEY_Arabic_beh, "Arabic_beh"),
+    (KEY_Arabic_tehmarbuta, "Arabic_tehmarbuta"),
+    (KEY_Arabic_teh, "Arabic_teh"),
+    (KEY_Arabic_theh, "Arabic_theh"),
+    (KEY_Arabic_jeem, "Arabic_jeem"),
+    (KEY_Arabic_hah, "Arabic_hah"),
+    (KEY_Arabic_khah, "Arabic_khah"),
+    (KEY_Arabic_dal, "Arabic_dal"),
+    (KEY_Arabic_thal, "Arabic_thal"),
+    (KEY_Arabic_ra, "Arabic_ra"),
+    (KEY_Arabic_zain, "Arabic_zain"),
+    (KEY_Arabic_seen, "Arabic_seen"),
+    (KEY_Arabic_sheen, "Arabic_sheen"),
+    (KEY_Arabic_sad, "Arabic_sad"),
+    (KEY_Arabic_dad, "Arabic_dad"),
+    (KEY_Arabic_tah, "Arabic_tah"),
+    (KEY_Arabic_zah, "Arabic_zah"),
+    (KEY_Arabic_ain, "Arabic_ain"),
+    (KEY_Arabic_ghain, "Arabic_ghain"),
+    (KEY_Arabic_tatweel, "Arabic_tatweel"),
+    (KEY_Arabic_feh, "Arabic_feh"),
+    (KEY_Arabic_qaf, "Arabic_qaf"),
+    (KEY_Arabic_kaf, "Arabic_kaf"),
+    (KEY_Arabic_lam, "Arabic_lam"),
+    (KEY_Arabic_meem, "Arabic_meem"),
+    (KEY_Arabic_noon, "Arabic_noon"),
+    (KEY_Arabic_ha, "Arabic_ha"),
+    (KEY_Arabic_waw, "Arabic_waw"),
+    (KEY_Arabic_alefmaksura, "Arabic_alefmaksura"),
+    (KEY_Arabic_yeh, "Arabic_yeh"),
+    (KEY_Arabic_fathatan, "Arabic_fathatan"),
+    (KEY_Arabic_dammatan, "Arabic_dammatan"),
+    (KEY_Arabic_kasratan, "Arabic_kasratan"),
+    (KEY_Arabic_fatha, "Arabic_fatha"),
+    (KEY_Arabic_damma, "Arabic_damma"),
+    (KEY_Arabic_kasra, "Arabic_kasra"),
+    (KEY_Arabic_shadda, "Arabic_shadda"),
+    (KEY_Arabic_sukun, "Arabic_sukun"),
+    (KEY_Serbian_dje, "Serbian_dje"),
+    (KEY_Macedonia_gje, "Macedonia_gje"),
+    (KEY_Cyrillic_io, "Cyrillic_io"),
+    (KEY_Ukrainian_ie, "Ukrainian_ie"),
+    (KEY_Macedonia_dse, "Macedonia_dse"),
+    (KEY_Ukrainian_i, "Ukrainian_i"),
+    (KEY_Ukrainian_yi, "Ukrainian_yi"),
+    (KEY_Cyrillic_je, "Cyrillic_je"),
+    (KEY_Cyrillic_lje, "Cyrillic_lje"),
+    (KEY_Cyrillic_nje, "Cyrillic_nje"),
+    (KEY_Serbian_tshe, "Serbian_tshe"),
+    (KEY_Macedonia_kje, "Macedonia_kje"),
+    (KEY_Ukrainian_ghe_with_upturn, "Ukrainian_ghe_with_upturn"),
+    (KEY_Byelorussian_shortu, "Byelorussian_shortu"),
+    (KEY_Cyrillic_dzhe, "Cyrillic_dzhe"),
+    (KEY_numerosign, "numerosign"),
+    (KEY_Serbian_DJE, "Serbian_DJE"),
+    (KEY_Macedonia_GJE, "Macedonia_GJE"),
+    (KEY_Cyrillic_IO, "Cyrillic_IO"),
+    (KEY_Ukrainian_IE, "Ukrainian_IE"),
+    (KEY_Macedonia_DSE, "Macedonia_DSE"),
+    (KEY_Ukrainian_I, "Ukrainian_I"),
+    (KEY_Ukrainian_YI, "Ukrainian_YI"),
+    (KEY_Cyrillic_JE, "Cyrillic_JE"),
+    (KEY_Cyrillic_LJE, "Cyrillic_LJE"),
+    (KEY_Cyrillic_NJE, "Cyrillic_NJE"),
+    (KEY_Serbian_TSHE, "Serbian_TSHE"),
+    (KEY_Macedonia_KJE, "Macedonia_KJE"),
+    (KEY_Ukrainian_GHE_WITH_UPTURN, "Ukrainian_GHE_WITH_UPTURN"),
+    (KEY_Byelorussian_SHORTU, "Byelorussian_SHORTU"),
+    (KEY_Cyrillic_DZHE, "Cyrillic_DZHE"),
+    (KEY_Cyrillic_yu, "Cyrillic_yu"),
+    (KEY_Cyrillic_a, "Cyrillic_a"),
+    (KEY_Cyrillic_be, "Cyrillic_be"),
+    (KEY_Cyrillic_tse, "Cyrillic_tse"),
+    (KEY_Cyrillic_de, "Cyrillic_de"),
+    (KEY_Cyrillic_ie, "Cyrillic_ie"),
+    (KEY_Cyrillic_ef, "Cyrillic_ef"),
+    (KEY_Cyrillic_ghe, "Cyrillic_ghe"),
+    (KEY_Cyrillic_ha, "Cyrillic_ha"),
+    (KEY_Cyrillic_i, "Cyrillic_i"),
+    (KEY_Cyrillic_shorti, "Cyrillic_shorti"),
+    (KEY_Cyrillic_ka, "Cyrillic_ka"),
+    (KEY_Cyrillic_el, "Cyrillic_el"),
+    (KEY_Cyrillic_em, "Cyrillic_em"),
+    (KEY_Cyrillic_en, "Cyrillic_en"),
+    (KEY_Cyrillic_o, "Cyrillic_o"),
+    (KEY_Cyrillic_pe, "Cyrillic_pe"),
+    (KEY_Cyrillic_ya, "Cyrillic_ya"),
+    (KEY_Cyrillic_er, "Cyrillic_er"),
+    (KEY_Cyrillic_es, "Cyrillic_es"),
+    (KEY_Cyrillic_te, "Cyrillic_te"),
+    (KEY_Cyrillic_u, "Cyrillic_u"),
+    (KEY_Cyrillic_zhe, "Cyrillic_zhe"),
+    (KEY_Cyrillic_ve, "Cyrillic_ve"),
+    (KEY_Cyrillic_softsign, "Cyrillic_softsign"),
+    (KEY_Cyrillic_yeru, "Cyrillic_yeru"),
+    (KEY_Cyrillic_ze, "Cyrillic_ze"),
+    (KEY_Cyrillic_sha, "Cyrillic_sha"),
+    (KEY_Cyrillic_e, "Cyrillic_e"),
+    (KEY_Cyrillic_shcha, "Cyrillic_shcha"),
+    (KEY_Cyrillic_che, "Cyrillic_che"),
+    (KEY_Cyrillic_hardsign, "Cyrillic_hardsign"),
+    (KEY_Cyrillic_YU, "Cyrillic_YU"),
+    (KEY_Cyrillic_A, "Cyrillic_A"),
+    (KEY_Cyrillic_BE, "Cyrillic_BE"),
+    (KEY_Cyrillic_TSE, "Cyrillic_TSE"),
+    (KEY_Cyrillic_DE, "Cyrillic_DE"),
+    (KEY_Cyrillic_IE, "Cyrillic_IE"),
+    (KEY_Cyrillic_EF, "Cyrillic_EF"),
+    (KEY_Cyrillic_GHE, "Cyrillic_GHE"),
+    (KEY_Cyrillic_HA, "Cyrillic_HA"),
+    (KEY_Cyrillic_I, "Cyrillic_I"),
+    (KEY_Cyrillic_SHORTI, "Cyrillic_SHORTI"),
+    (KEY_Cyrillic_KA, "Cyrillic_KA"),
+    (KEY_Cyrillic_EL, "Cyrillic_EL"),
+    (KEY_Cyrillic_EM, "Cyrillic_EM"),
+    (KEY_Cyrillic_EN, "Cyrillic_EN"),
+    (KEY_Cyrillic_O, "Cyrillic_O"),
+    (KEY_Cyrillic_PE, "Cyrillic_PE"),
+    (KEY_Cyrillic_YA, "Cyrillic_YA"),
+    (KEY_Cyrillic_ER, "Cyrillic_ER"),
+    (KEY_Cyrillic_ES, "Cyrillic_ES"),
+    (KEY_Cyrillic_TE, "Cyrillic_TE"),
+    (KEY_Cyrillic_U, "Cyrillic_U"),
+    (KEY_Cyrillic_ZHE, "Cyrillic_ZHE"),
+    (KEY_Cyrillic_VE, "Cyrillic_VE"),
+    (KEY_Cyrillic_SOFTSIGN, "Cyrillic_SOFTSIGN"),
+    (KEY_Cyrillic_YERU, "Cyrillic_YERU"),
+    (KEY_Cyrillic_ZE, "Cyrillic_ZE"),
+    (KEY_Cyrillic_SHA, "Cyrillic_SHA"),
+    (KEY_Cyrillic_E, "Cyrillic_E"),
+    (KEY_Cyrillic_SHCHA, "Cyrillic_SHCHA"),
+    (KEY_Cyrillic_CHE, "Cyrillic_CHE"),
+    (KEY_Cyrillic_HARDSIGN, "Cyrillic_HARDSIGN"),
+    (KEY_Greek_ALPHAaccent, "Greek_ALPHAaccent"),
+    (KEY_Greek_EPSILONaccent, "Greek_EPSILONaccent"),
+    (KEY_Greek_ETAaccent, "Greek_ETAaccent"),
+    (KEY_Greek_IOTAaccent, "Greek_IOTAaccent"),
+    (KEY_Greek_IOTAdieresis, "Greek_IOTAdieresis"),
+    (KEY_Greek_OMICRONaccent, "Greek_OMICRONaccent"),
+    (KEY_Greek_UPSILONaccent, "Greek_UPSILONaccent"),
+    (KEY_Greek_UPSILONdieresis, "Greek_UPSILONdieresis"),
+    (KEY_Greek_OMEGAaccent, "Greek_OMEGAaccent"),
+    (KEY_Greek_accentdieresis, "Greek_accentdieresis"),
+    (KEY_Greek_horizbar, "Greek_horizbar"),
+    (KEY_Greek_alphaaccent, "Greek_alphaaccent"),
+    (KEY_Greek_epsilonaccent, "Greek_epsilonaccent"),
+    (KEY_Greek_etaaccent, "Greek_etaaccent"),
+    (KEY_Greek_iotaaccent, "Greek_iotaaccent"),
+    (KEY_Greek_iotadieresis, "Greek_iotadieresis"),
+    (KEY_Greek_iotaaccentdieresis, "Greek_iotaaccentdieresis"),
+    (KEY_Greek_omicronaccent, "Greek_omicronaccent"),
+    (KEY_Greek_upsilonaccent, "Greek_upsilonaccent"),
+    (KEY_Greek_upsilondieresis, "Greek_upsilondieresis"),
+    (KEY_Greek_upsilonaccentdieresis, "Greek_upsilonaccentdieresis"),
+    (KEY_Greek_omegaaccent, "Greek_omegaaccent"),
+    (KEY_Greek_ALPHA, "Greek_ALPHA"),
+    (KEY_Greek_BETA, "Greek_BETA"),
+    (KEY_Greek_GAMMA, "Greek_GAMMA"),
+    (KEY_Greek_DELTA, "Greek_DELTA"),
+    (KEY_Greek_EPSILON, "Greek_EPSILON"),
+    (KEY_Greek_ZETA, "Greek_ZETA"),
+    (KEY_Greek_ETA, "Greek_ETA"),
+    (KEY_Greek_THETA, "Greek_THETA"),
+    (KEY_Greek_IOTA, "Greek_IOTA"),
+    (KEY_Greek_KAPPA, "Greek_KAPPA"),
+    (KEY_Greek_LAMDA, "Greek_LAMDA"),
+    (KEY_Greek_MU, "Greek_MU"),
+    (KEY_Greek_NU, "Greek_NU"),
+    (KEY_Greek_XI, "Greek_XI"),
+    (KEY_Greek_OMICRON, "Greek_OMICRON"),
+    (KEY_Greek_PI, "Greek_PI"),
+    (KEY_Greek_RHO, "Greek_RHO"),
+    (KEY_Greek_SIGMA, "Greek_SIGMA"),
+    (KEY_Greek_TAU, "Greek_TAU"),
+    (KEY_Greek_UPSILON, "Greek_UPSILON"),
+    (KEY_Greek_PHI, "Greek_PHI"),
+    (KEY_Greek_CHI, "Greek_CHI"),
+    (KEY_Greek_PSI, "Greek_PSI"),
+    (KEY_Greek_OMEGA, "Greek_OMEGA"),
+    (KEY_Greek_alpha, "Greek_alpha"),
+    (KEY_Greek_beta, "Greek_beta"),
+    (KEY_Greek_gamma, "Greek_gamma"),
+    (KEY_Greek_delta, "Greek_delta"),
+    (KEY_Greek_epsilon, "Greek_epsilon"),
+    (KEY_Greek_zeta, "Greek_zeta"),
+    (KEY_Greek_eta, "Greek_eta"),
+    (KEY_Greek_theta, "Greek_theta"),
+    (KEY_Greek_iota, "Greek_iota"),
+    (KEY_Greek_kappa, "Greek_kappa"),
+    (KEY_Greek_lamda, "Greek_lamda"),
+    (KEY_Greek_mu, "Greek_mu"),
+    (KEY_Greek_nu, "Greek_nu"),
+    (KEY_Greek_xi, "Greek_xi"),
+    (KEY_Greek_omicron, "Greek_omicron"),
+    (KEY_Greek_pi, "Greek_pi"),
+    (KEY_Greek_rho, "Greek_rho"),
+    (KEY_Greek_sigma, "Greek_sigma"),
+    (KEY_Greek_finalsmallsigma, "Greek_finalsmallsigma"),
+    (KEY_Greek_tau, "Greek_tau"),
+    (KEY_Greek_upsilon, "Greek_upsilon"),
+    (KEY_Greek_phi, "Greek_phi"),
+    (KEY_Greek_chi, "Greek_chi"),
+    (KEY_Greek_psi, "Greek_psi"),
+    (KEY_Greek_omega, "Greek_omega"),
+    (KEY_leftradical, "leftradical"),
+    (KEY_topleftradical, "topleftradical"),
+    (KEY_horizconnector, "horizconnector"),
+    (KEY_topintegral, "topintegral"),
+    (KEY_botintegral, "botintegral"),
+    (KEY_vertconnector, "vertconnector"),
+    (KEY_topleftsqbracket, "topleftsqbracket"),
+    (KEY_botleftsqbracket, "botleftsqbracket"),
+    (KEY_toprightsqbracket, "toprightsqbracket"),
+    (KEY_botrightsqbracket, "botrightsqbracket"),
+    (KEY_topleftparens, "topleftparens"),
+    (KEY_botleftparens, "botleftparens"),
+    (KEY_toprightparens, "toprightparens"),
+    (KEY_botrightparens, "botrightparens"),
+    (KEY_leftmiddlecurlybrace, "leftmiddlecurlybrace"),
+    (KEY_rightmiddlecurlybrace, "rightmiddlecurlybrace"),
+    (KEY_topleftsummation, "topleftsummation"),
+    (KEY_botleftsummation, "botleftsummation"),
+    (KEY_topvertsummationconnector, "topvertsummationconnector"),
+    (KEY_botvertsummationconnector, "botvertsummationconnector"),
+    (KEY_toprightsummation, "toprightsummation"),
+    (KEY_botrightsummation, "botrightsummation"),
+    (KEY_rightmiddlesummation, "rightmiddlesummation"),
+    (KEY_lessthanequal, "lessthanequal"),
+    (KEY_notequal, "notequal"),
+    (KEY_greaterthanequal, "greaterthanequal"),
+    (KEY_integral, "integral"),
+    (KEY_therefore, "therefore"),
+    (KEY_variation, "variation"),
+    (KEY_infinity, "infinity"),
+    (KEY_nabla, "nabla"),
+    (KEY_approximate, "approximate"),
+    (KEY_similarequal, "similarequal"),
+    (KEY_ifonlyif, "ifonlyif"),
+    (KEY_implies, "implies"),
+    (KEY_identical, "identical"),
+    (KEY_radical, "radical"),
+    (KEY_includedin, "includedin"),
+    (KEY_includes, "includes"),
+    (KEY_intersection, "intersection"),
+    (KEY_union, "union"),
+    (KEY_logicaland, "logicaland"),
+    (KEY_logicalor, "logicalor"),
+    (KEY_partialderivative, "partialderivative"),
+    (KEY_function, "function"),
+    (KEY_leftarrow, "leftarrow"),
+    (KEY_uparrow, "uparrow"),
+    (KEY_rightarrow, "rightarrow"),
+    (KEY_downarrow, "downarrow"),
+    (KEY_blank, "blank"),
+    (KEY_soliddiamond, "soliddiamond"),
+    (KEY_checkerboard, "checkerboard"),
+    (KEY_ht, "ht"),
+    (KEY_ff, "ff"),
+    (KEY_cr, "cr"),
+    (KEY_lf, "lf"),
+    (KEY_nl, "nl"),
+    (KEY_vt, "vt"),
+    (KEY_lowrightcorner, "lowrightcorner"),
+    (KEY_uprightcorner, "uprightcorner"),
+    (KEY_upleftcorner, "upleftcorner"),
+    (KEY_lowleftcorner, "lowleftcorner"),
+    (KEY_crossinglines, "crossinglines"),
+    (KEY_horizlinescan1, "horizlinescan1"),
+    (KEY_horizlinescan3, "horizlinescan3"),
+    (KEY_horizlinescan5, "horizlinescan5"),
+    (KEY_horizlinescan7, "horizlinescan7"),
+    (KEY_horizlinescan9, "horizlinescan9"),
+    (KEY_leftt, "leftt"),
+    (KEY_rightt, "rightt"),
+    (KEY_bott, "bott"),
+    (KEY_topt, "topt"),
+    (KEY_vertbar, "vertbar"),
+    (KEY_emspace, "emspace"),
+    (KEY_enspace, "enspace"),
+    (KEY_em3space, "em3space"),
+    (KEY_em4space, "em4space"),
+    (KEY_digitspace, "digitspace"),
+    (KEY_punctspace, "punctspace"),
+    (KEY_thinspace, "thinspace"),
+    (KEY_hairspace, "hairspace"),
+    (KEY_emdash, "emdash"),
+    (KEY_endash, "endash"),
+    (KEY_signifblank, "signifblank"),
+    (KEY_ellipsis, "ellipsis"),
+    (KEY_doubbaselinedot, "doubbaselinedot"),
+    (KEY_onethird, "onethird"),
+    (KEY_twothirds, "twothirds"),
+    (KEY_onefifth, "onefifth"),
+    (KEY_twofifths, "twofifths"),
+    (KEY_threefifths, "threefifths"),
+    (KEY_fourfifths, "fourfifths"),
+    (KEY_onesixth, "onesixth"),
+    (KEY_fivesixths, "fivesixths"),
+    (KEY_careof, "careof"),
+    (KEY_figdash, "figdash"),
+    (KEY_leftanglebracket, "leftanglebracket"),
+    (KEY_decimalpoint, "decimalpoint"),
+    (KEY_rightanglebracket, "rightanglebracket"),
+    (KEY_marker, "marker"),
+    (KEY_oneeighth, "oneeighth"),
+    (KEY_threeeighths, "threeeighths"),
+    (KEY_fiveeighths, "fiveeighths"),
+    (KEY_seveneighths, "seveneighths"),
+    (KEY_trademark, "trademark"),
+    (KEY_signaturemark, "signaturemark"),
+    (KEY_trademarkincircle, "trademarkincircle"),
+    (KEY_leftopentriangle, "leftopentriangle"),
+    (KEY_rightopentriangle, "rightopentriangle"),
+    (KEY_emopencircle, "emopencircle"),
+    (KEY_emopenrectangle, "emopenrectangle"),
+    (KEY_leftsinglequotemark, "leftsinglequotemark"),
+    (KEY_rightsinglequotemark, "rightsinglequotemark"),
+    (KEY_leftdoublequotemark, "leftdoublequotemark"),
+    (KEY_rightdoublequotemark, "rightdoublequotemark"),
+    (KEY_prescription, "prescription"),
+    (KEY_permille, "permille"),
+    (KEY_minutes, "minutes"),
+    (KEY_seconds, "seconds"),
+    (KEY_latincross, "latincross"),
+    (KEY_hexagram, "hexagram"),
+    (KEY_filledrectbullet, "filledrectbullet"),
+    (KEY_filledlefttribullet, "filledlefttribullet"),
+    (KEY_filledrighttribullet, "filledrighttribullet"),
+    (KEY_emfilledcircle, "emfilledcircle"),
+    (KEY_emfilledrect, "emfilledrect"),
+    (KEY_enopencircbullet, "enopencircbullet"),
+    (KEY_enopensquarebullet, "enopensquarebullet"),
+    (KEY_openrectbullet, "openrectbullet"),
+    (KEY_opentribulletup, "opentribulletup"),
+    (KEY_opentribulletdown, "opentribulletdown"),
+    (KEY_openstar, "openstar"),
+    (KEY_enfilledcircbullet, "enfilledcircbullet"),
+    (KEY_enfilledsqbullet, "enfilledsqbullet"),
+    (KEY_filledtribulletup, "filledtribulletup"),
+    (KEY_filledtribulletdown, "filledtribulletdown"),
+    (KEY_leftpointer, "leftpointer"),
+    (KEY_rightpointer, "rightpointer"),
+    (KEY_club, "club"),
+    (KEY_diamond, "diamond"),
+    (KEY_heart, "heart"),
+    (KEY_maltesecross, "maltesecross"),
+    (KEY_dagger, "dagger"),
+    (KEY_doubledagger, "doubledagger"),
+    (KEY_checkmark, "checkmark"),
+    (KEY_ballotcross, "ballotcross"),
+    (KEY_musicalsharp, "musicalsharp"),
+    (KEY_musicalflat, "musicalflat"),
+    (KEY_malesymbol, "malesymbol"),
+    (KEY_femalesymbol, "femalesymbol"),
+    (KEY_telephone, "telephone"),
+    (KEY_telephonerecorder, "telephonerecorder"),
+    (KEY_phonographcopyright, "phonographcopyright"),
+    (KEY_caret, "caret"),
+    (KEY_singlelowquotemark, "singlelowquotemark"),
+    (KEY_doublelowquotemark, "doublelowquotemark"),
+    (KEY_cursor, "cursor"),
+    (KEY_leftcaret, "leftcaret"),
+    (KEY_rightcaret, "rightcaret"),
+    (KEY_downcaret, "downcaret"),
+    (KEY_upcaret, "upcaret"),
+    (KEY_overbar, "overbar"),
+    (KEY_downtack, "downtack"),
+    (KEY_upshoe, "upshoe"),
+    (KEY_downstile, "downstile"),
+    (KEY_underbar, "underbar"),
+    (KEY_jot, "jot"),
+    (KEY_quad, "quad"),
+    (KEY_uptack, "uptack"),
+    (KEY_circle, "circle"),
+    (KEY_upstile, "upstile"),
+    (KEY_downshoe, "downshoe"),
+    (KEY_rightshoe, "rightshoe"),
+    (KEY_leftshoe, "leftshoe"),
+    (KEY_lefttack, "lefttack"),
+    (KEY_righttack, "righttack"),
+    (KEY_hebrew_doublelowline, "hebrew_doublelowline"),
+    (KEY_hebrew_aleph, "hebrew_aleph"),
+    (KEY_hebrew_bet, "hebrew_bet"),
+    (KEY_hebrew_gimel, "hebrew_gimel"),
+    (KEY_hebrew_dalet, "hebrew_dalet"),
+    (KEY_hebrew_he, "hebrew_he"),
+    (KEY_hebrew_waw, "hebrew_waw"),
+    (KEY_hebrew_zain, "hebrew_zain"),
+    (KEY_hebrew_chet, "hebrew_chet"),
+    (KEY_hebrew_tet, "hebrew_tet"),
+    (KEY_hebrew_yod, "hebrew_yod"),
+    (KEY_hebrew_finalkaph, "hebrew_finalkaph"),
+    (KEY_hebrew_kaph, "hebrew_kaph"),
+    (KEY_hebrew_lamed, "hebrew_lamed"),
+    (KEY_hebrew_finalmem, "hebrew_finalmem"),
+    (KEY_hebrew_mem, "hebrew_mem"),
+    (KEY_hebrew_finalnun, "hebrew_finalnun"),
+    (KEY_hebrew_nun, "hebrew_nun"),
+    (KEY_hebrew_samech, "hebrew_samech"),
+    (KEY_hebrew_ayin, "hebrew_ayin"),
+    (KEY_hebrew_finalpe, "hebrew_finalpe"),
+    (KEY_hebrew_pe, "hebrew_pe"),
+    (KEY_hebrew_finalzade, "hebrew_finalzade"),
+    (KEY_hebrew_zade, "hebrew_zade"),
+    (KEY_hebrew_qoph, "hebrew_qoph"),
+    (KEY_hebrew_resh, "hebrew_resh"),
+    (KEY_hebrew_shin, "hebrew_shin"),
+    (KEY_hebrew_taw, "hebrew_taw"),
+    (KEY_Thai_kokai, "Thai_kokai"),
+    (KEY_Thai_khokhai, "Thai_khokhai"),
+    (KEY_Thai_khokhuat, "Thai_khokhuat"),
+    (KEY_Thai_khokhwai, "Thai_khokhwai"),
+    (KEY_Thai_khokhon, "Thai_khokhon"),
+    (KEY_Thai_khorakhang, "Thai_khorakhang"),
+    (KEY_Thai_ngongu, "Thai_ngongu"),
+    (KEY_Thai_chochan, "Thai_chochan"),
+    (KEY_Thai_choching, "Thai_choching"),
+    (KEY_Thai_chochang, "Thai_chochang"),
+    (KEY_Thai_soso, "Thai_soso"),
+    (KEY_Thai_chochoe, "Thai_chochoe"),
+    (KEY_Thai_yoying, "Thai_yoying"),
+    (KEY_Thai_dochada, "Thai_dochada"),
+    (KEY_Thai_topatak, "Thai_topatak"),
+    (KEY_Thai_thothan, "Thai_thothan"),
+    (KEY_Thai_thonangmontho, "Thai_thonangmontho"),
+    (KEY_Thai_thophuthao, "Thai_thophuthao"),
+    (KEY_Thai_nonen, "Thai_nonen"),
+    (KEY_Thai_dodek, "Thai_dodek"),
+    (KEY_Thai_totao, "Thai_totao"),
+    (KEY_Thai_thothung, "Thai_thothung"),
+    (KEY_Thai_thothahan, "Thai_thothahan"),
+    (KEY_Thai_thothong, "Thai_thothong"),
+    (KEY_Thai_nonu, "Thai_nonu"),
+    (KEY_Thai_bobaimai, "Thai_bobaimai"),
+    (KEY_Thai_popla, "Thai_popla"),
+    (KEY_Thai_phophung, "Thai_phophung"),
+    (KEY_Thai_fofa, "Thai_fofa"),
+    (KEY_Thai_phophan, "Thai_phophan"),
+    (KEY_Thai_fofan, "Thai_fofan"),
+    (KEY_Thai_phosamphao, "Thai_phosamphao"),
+    (KEY_Thai_moma, "Thai_moma"),
+    (KEY_Thai_yoyak, "Thai_yoyak"),
+    (KEY_Thai_rorua, "Thai_rorua"),
+    (KEY_Thai_ru, "Thai_ru"),
+    (KEY_Thai_loling, "Thai_loling"),
+    (KEY_Thai_lu, "Thai_lu"),
+    (KEY_Thai_wowaen, "Thai_wowaen"),
+    (KEY_Thai_sosala, "Thai_sosala"),
+    (KEY_Thai_sorusi, "Thai_sorusi"),
+    (KEY_Thai_sosua, "Thai_sosua"),
+    (KEY_Thai_hohip, "Thai_hohip"),
+    (KEY_Thai_lochula, "Thai_lochula"),
+    (KEY_Thai_oang, "Thai_oang"),
+    (KEY_Thai_honokhuk, "Thai_honokhuk"),
+    (KEY_Thai_paiyannoi, "Thai_paiyannoi"),
+    (KEY_Thai_saraa, "Thai_saraa"),
+    (KEY_Thai_maihanakat, "Thai_maihanakat"),
+    (KEY_Thai_saraaa, "Thai_saraaa"),
+    (KEY_Thai_saraam, "Thai_saraam"),
+    (KEY_Thai_sarai, "Thai_sarai"),
+    (KEY_Thai_saraii, "Thai_saraii"),
+    (KEY_Thai_saraue, "Thai_saraue"),
+    (KEY_Thai_sarauee, "Thai_sarauee"),
+    (KEY_Thai_sarau, "Thai_sarau"),
+    (KEY_Thai_sarauu, "Thai_sarauu"),
+    (KEY_Thai_phinthu, "Thai_phinthu"),
+    (KEY_Thai_maihanakat_maitho, "Thai_maihanakat_maitho"),
+    (KEY_Thai_baht, "Thai_baht"),
+    (KEY_Thai_sarae, "Thai_sarae"),
+    (KEY_Thai_saraae, "Thai_saraae"),
+    (KEY_Thai_sarao, "Thai_sarao"),
+    (KEY_Thai_saraaimaimuan, "Thai_saraaimaimuan"),
+    (KEY_Thai_saraaimaimalai, "Thai_saraaimaimalai"),
+    (KEY_Thai_lakkhangyao, "Thai_lakkhangyao"),
+    (KEY_Thai_maiyamok, "Thai_maiyamok"),
+    (KEY_Thai_maitaikhu, "Thai_maitaikhu"),
+    (KEY_Thai_maiek, "Thai_maiek"),
+    (KEY_Thai_maitho, "Thai_maitho"),
+    (KEY_Thai_maitri, "Thai_maitri"),
+    (KEY_Thai_maichattawa, "Thai_maichattawa"),
+    (KEY_Thai_thanthakhat, "Thai_thanthakhat"),
+    (KEY_Thai_nikhahit, "Thai_nikhahit"),
+    (KEY_Thai_leksun, "Thai_leksun"),
+    (KEY_Thai_leknung, "Thai_leknung"),
+    (KEY_Thai_leksong, "Thai_leksong"),
+    (KEY_Thai_leksam, "Thai_leksam"),
+    (KEY_Thai_leksi, "Thai_leksi"),
+    (KEY_Thai_lekha, "Thai_lekha"),
+    (KEY_Thai_lekhok, "Thai_lekhok"),
+    (KEY_Thai_lekchet, "Thai_lekchet"),
+    (KEY_Thai_lekpaet, "Thai_lekpaet"),
+    (KEY_Thai_lekkao, "Thai_lekkao"),
+    (KEY_Hangul_Kiyeog, "Hangul_Kiyeog"),
+    (KEY_Hangul_SsangKiyeog, "Hangul_SsangKiyeog"),
+    (KEY_Hangul_KiyeogSios, "Hangul_KiyeogSios"),
+    (KEY_Hangul_Nieun, "Hangul_Nieun"),
+    (KEY_Hangul_NieunJieuj, "Hangul_NieunJieuj"),
+    (KEY_Hangul_NieunHieuh, "Hangul_NieunHieuh"),
+    (KEY_Hangul_Dikeud, "Hangul_Dikeud"),
+    (KEY_Hangul_SsangDikeud, "Hangul_SsangDikeud"),
+    (KEY_Hangul_Rieul, "Hangul_Rieul"),
+    (KEY_Hangul_RieulKiyeog, "Hangul_RieulKiyeog"),
+    (KEY_Hangul_RieulMieum, "Hangul_RieulMieum"),
+    (KEY_Hangul_RieulPieub, "Hangul_RieulPieub"),
+    (KEY_Hangul_RieulSios, "Hangul_RieulSios"),
+    (KEY_Hangul_RieulTieut, "Hangul_RieulTieut"),
+    (KEY_Hangul_RieulPhieuf, "Hangul_RieulPhieuf"),
+    (KEY_Hangul_RieulHieuh, "Hangul_RieulHieuh"),
+    (KEY_Hangul_Mieum, "Hangul_Mieum"),
+    (KEY_Hangul_Pieub, "Hangul_Pieub"),
+    (KEY_Hangul_SsangPieub, "Hangul_SsangPieub"),
+    (KEY_Hangul_PieubSios, "Hangul_PieubSios"),
+    (KEY_Hangul_Sios, "Hangul_Sios"),
+    (KEY_Hangul_SsangSios, "Hangul_SsangSios"),
+    (KEY_Hangul_Ieung, "Hangul_Ieung"),
+    (KEY_Hangul_Jieuj, "Hangul_Jieuj"),
+    (KEY_Hangul_SsangJieuj, "Hangul_SsangJieuj"),
+    (KEY_Hangul_Cieuc, "Hangul_Cieuc"),
+    (KEY_Hangul_Khieuq, "Hangul_Khieuq"),
+    (KEY_Hangul_Tieut, "Hangul_Tieut"),
+    (KEY_Hangul_Phieuf, "Hangul_Phieuf"),
+    (KEY_Hangul_Hieuh, "Hangul_Hieuh"),
+    (KEY_Hangul_A, "Hangul_A"),
+    (KEY_Hangul_AE, "Hangul_AE"),
+    (KEY_Hangul_YA, "Hangul_YA"),
+    (KEY_Hangul_YAE, "Hangul_YAE"),
+    (KEY_Hangul_EO, "Hangul_EO"),
+    (KEY_Hangul_E, "Hangul_E"),
+    (KEY_Hangul_YEO, "Hangul_YEO"),
+    (KEY_Hangul_YE, "Hangul_YE"),
+    (KEY_Hangul_O, "Hangul_O"),
+    (KEY_Hangul_WA, "Hangul_WA"),
+    (KEY_Hangul_WAE, "Hangul_WAE"),
+    (KEY_Hangul_OE, "Hangul_OE"),
+    (KEY_Hangul_YO, "Hangul_YO"),
+    (KEY_Hangul_U, "Hangul_U"),
+    (KEY_Hangul_WEO, "Hangul_WEO"),
+    (KEY_Hangul_WE, "Hangul_WE"),
+    (KEY_Hangul_WI, "Hangul_WI"),
+    (KEY_Hangul_YU, "Hangul_YU"),
+    (KEY_Hangul_EU, "Hangul_EU"),
+    (KEY_Hangul_YI, "Hangul_YI"),
+    (KEY_Hangul_I, "Hangul_I"),
+    (KEY_Hangul_J_Kiyeog, "Hangul_J_Kiyeog"),
+    (KEY_Hangul_J_SsangKiyeog, "Hangul_J_SsangKiyeog"),
+    (KEY_Hangul_J_KiyeogSios, "Hangul_J_KiyeogSios"),
+    (KEY_Hangul_J_Nieun, "Hangul_J_Nieun"),
+    (KEY_Hangul_J_NieunJieuj, "Hangul_J_NieunJieuj"),
+    (KEY_Hangul_J_NieunHieuh, "Hangul_J_NieunHieuh"),
+    (KEY_Hangul_J_Dikeud, "Hangul_J_Dikeud"),
+    (KEY_Hangul_J_Rieul, "Hangul_J_Rieul"),
+    (KEY_Hangul_J_RieulKiyeog, "Hangul_J_RieulKiyeog"),
+    (KEY_Hangul_J_RieulMieum, "Hangul_J_RieulMieum"),
+    (KEY_Hangul_J_RieulPieub, "Hangul_J_RieulPieub"),
+    (KEY_Hangul_J_RieulSios, "Hangul_J_RieulSios"),
+    (KEY_Hangul_J_RieulTieut, "Hangul_J_RieulTieut"),
+    (KEY_Hangul_J_RieulPhieuf, "Hangul_J_RieulPhieuf"),
+    (KEY_Hangul_J_RieulHieuh, "Hangul_J_RieulHieuh"),
+    (KEY_Hangul_J_Mieum, "Hangul_J_Mieum"),
+    (KEY_Hangul_J_Pieub, "Hangul_J_Pieub"),
+    (KEY_Hangul_J_PieubSios, "Hangul_J_PieubSios"),
+    (KEY_Hangul_J_Sios, "Hangul_J_Sios"),
+    (KEY_Hangul_J_SsangSios, "Hangul_J_SsangSios"),
+    (KEY_Hangul_J_Ieung, "Hangul_J_Ieung"),
+    (KEY_Hangul_J_Jieuj, "Hangul_J_Jieuj"),
+    (KEY_Hangul_J_Cieuc, "Hangul_J_Cieuc"),
+    (KEY_Hangul_J_Khieuq, "Hangul_J_Khieuq"),
+    (KEY_Hangul_J_Tieut, "Hangul_J_Tieut"),
+    (KEY_Hangul_J_Phieuf, "Hangul_J_Phieuf"),
+    (KEY_Hangul_J_Hieuh, "Hangul_J_Hieuh"),
+    (KEY_Hangul_RieulYeorinHieuh, "Hangul_RieulYeorinHieuh"),
+    (KEY_Hangul_SunkyeongeumMieum, "Hangul_SunkyeongeumMieum"),
+    (KEY_Hangul_SunkyeongeumPieub, "Hangul_SunkyeongeumPieub"),
+    (KEY_Hangul_PanSios, "Hangul_PanSios"),
+    (KEY_Hangul_KkogjiDalrinIeung, "Hangul_KkogjiDalrinIeung"),
+    (KEY_Hangul_SunkyeongeumPhieuf, "Hangul_SunkyeongeumPhieuf"),
+    (KEY_Hangul_YeorinHieuh, "Hangul_YeorinHieuh"),
+    (KEY_Hangul_AraeA, "Hangul_AraeA"),
+    (KEY_Hangul_AraeAE, "Hangul_AraeAE"),
+    (KEY_Hangul_J_PanSios, "Hangul_J_PanSios"),
+    (KEY_Hangul_J_KkogjiDalrinIeung, "Hangul_J_KkogjiDalrinIeung"),
+    (KEY_Hangul_J_YeorinHieuh, "Hangul_J_YeorinHieuh"),
+    (KEY_Korean_Won, "Korean_Won"),
+    (KEY_OE, "OE"),
+    (KEY_oe, "oe"),
+    (KEY_Ydiaeresis, "Ydiaeresis"),
+    (KEY_EuroSign, "EuroSign"),
+    (KEY_3270_Duplicate, "3270_Duplicate"),
+    (KEY_3270_FieldMark, "3270_FieldMark"),
+    (KEY_3270_Right2, "3270_Right2"),
+    (KEY_3270_Left2, "3270_Left2"),
+    (KEY_3270_BackTab, "3270_BackTab"),
+    (KEY_3270_EraseEOF, "3270_EraseEOF"),
+    (KEY_3270_EraseInput, "3270_EraseInput"),
+    (KEY_3270_Reset, "3270_Reset"),
+    (KEY_3270_Quit, "3270_Quit"),
+    (KEY_3270_PA1, "3270_PA1"),
+    (KEY_3270_PA2, "3270_PA2"),
+    (KEY_3270_PA3, "3270_PA3"),
+    (KEY_3270_Test, "3270_Test"),
+    (KEY_3270_Attn, "3270_Attn"),
+    (KEY_3270_CursorBlink, "3270_CursorBlink"),
+    (KEY_3270_AltCursor, "3270_AltCursor"),
+    (KEY_3270_KeyClick, "3270_KeyClick"),
+    (KEY_3270_Jump, "3270_Jump"),
+    (KEY_3270_Ident, "3270_Ident"),
+    (KEY_3270_Rule, "3270_Rule"),
+    (KEY_3270_Copy, "3270_Copy"),
+    (KEY_3270_Play, "3270_Play"),
+    (KEY_3270_Setup, "3270_Setup"),
+    (KEY_3270_Record, "3270_Record"),
+    (KEY_3270_ChangeScreen, "3270_ChangeScreen"),
+    (KEY_3270_DeleteWord, "3270_DeleteWord"),
+    (KEY_3270_ExSelect, "3270_ExSelect"),
+    (KEY_3270_CursorSelect, "3270_CursorSelect"),
+    (KEY_3270_PrintScreen, "3270_PrintScreen"),
+    (KEY_3270_Enter, "3270_Enter"),
+    (KEY_ISO_Lock, "ISO_Lock"),
+    (KEY_ISO_Level2_Latch, "ISO_Level2_Latch"),
+    (KEY_ISO_Level3_Shift, "ISO_Level3_Shift"),
+    (KEY_ISO_Level3_Latch, "ISO_Level3_Latch"),
+    (KEY_ISO_Level3_Lock, "ISO_Level3_Lock"),
+    (KEY_ISO_Group_Latch, "ISO_Group_Latch"),
+    (KEY_ISO_Group_Lock, "ISO_Group_Lock"),
+    (KEY_ISO_Next_Group, "ISO_Next_Group"),
+    (KEY_ISO_Next_Group_Lock, "ISO_Next_Group_Lock"),
+    (KEY_ISO_Prev_Group, "ISO_Prev_Group"),
+    (KEY_ISO_Prev_Group_Lock, "ISO_Prev_Group_Lock"),
+    (KEY_ISO_First_Group, "ISO_First_Group"),
+    (KEY_ISO_First_Group_Lock, "ISO_First_Group_Lock"),
+    (KEY_ISO_Last_Group, "ISO_Last_Group"),
+    (KEY_ISO_Last_Group_Lock, "ISO_Last_Group_Lock"),
+    (KEY_ISO_Level5_Shift, "ISO_Level5_Shift"),
+    (KEY_ISO_Level5_Latch, "ISO_Level5_Latch"),
+    (KEY_ISO_Level5_Lock, "ISO_Level5_Lock"),
+    (KEY_ISO_Left_Tab, "ISO_Left_Tab"),
+    (KEY_ISO_Move_Line_Up, "ISO_Move_Line_Up"),
+    (KEY_ISO_Move_Line_Down, "ISO_Move_Line_Down"),
+    (KEY_ISO_Partial_Line_Up, "ISO_Partial_Line_Up"),
+    (KEY_ISO_Partial_Line_Down, "ISO_Partial_Line_Down"),
+    (KEY_ISO_Partial_Space_Left, "ISO_Partial_Space_Left"),
+    (KEY_ISO_Partial_Space_Right, "ISO_Partial_Space_Right"),
+    (KEY_ISO_Set_Margin_Left, "ISO_Set_Margin_Left"),
+    (KEY_ISO_Set_Margin_Right, "ISO_Set_Margin_Right"),
+    (KEY_ISO_Release_Margin_Left, "ISO_Release_Margin_Left"),
+    (KEY_ISO_Release_Margin_Right, "ISO_Release_Margin_Right"),
+    (KEY_ISO_Release_Both_Margins, "ISO_Release_Both_Margins"),
+    (KEY_ISO_Fast_Cursor_Left, "ISO_Fast_Cursor_Left"),
+    (KEY_ISO_Fast_Cursor_Right, "ISO_Fast_Cursor_Right"),
+    (KEY_ISO_Fast_Cursor_Up, "ISO_Fast_Cursor_Up"),
+    (KEY_ISO_Fast_Cursor_Down, "ISO_Fast_Cursor_Down"),
+    (KEY_ISO_Continuous_Underline, "ISO_Continuous_Underline"),
+    (KEY_ISO_Discontinuous_Underline, "ISO_Discontinuous_Underline"),
+    (KEY_ISO_Emphasize, "ISO_Emphasize"),
+    (KEY_ISO_Center_Object, "ISO_Center_Object"),
+    (KEY_ISO_Enter, "ISO_Enter"),
+    (KEY_dead_grave, "dead_grave"),
+    (KEY_dead_acute, "dead_acute"),
+    (KEY_dead_circumflex, "dead_circumflex"),
+    (KEY_dead_tilde, "dead_tilde"),
+    (KEY_dead_macron, "dead_macron"),
+    (KEY_dead_breve, "dead_breve"),
+    (KEY_dead_abovedot, "dead_abovedot"),
+    (KEY_dead_diaeresis, "dead_diaeresis"),
+    (KEY_dead_abovering, "dead_abovering"),
+    (KEY_dead_doubleacute, "dead_doubleacute"),
+    (KEY_dead_caron, "dead_caron"),
+    (KEY_dead_cedilla, "dead_cedilla"),
+    (KEY_dead_ogonek, "dead_ogonek"),
+    (KEY_dead_iota, "dead_iota"),
+    (KEY_dead_voiced_sound, "dead_voiced_sound"),
+    (KEY_dead_semivoiced_sound, "dead_semivoiced_sound"),
+    (KEY_dead_belowdot, "dead_belowdot"),
+    (KEY_dead_hook, "dead_hook"),
+    (KEY_dead_horn, "dead_horn"),
+    (KEY_dead_stroke, "dead_stroke"),
+    (KEY_dead_abovecomma, "dead_abovecomma"),
+    (KEY_dead_abovereversedcomma, "dead_abovereversedcomma"),
+    (KEY_dead_doublegrave, "dead_doublegrave"),
+    (KEY_dead_belowring, "dead_belowring"),
+    (KEY_dead_belowmacron, "dead_belowmacron"),
+    (KEY_dead_belowcircumflex, "dead_belowcircumflex"),
+    (KEY_dead_belowtilde, "dead_belowtilde"),
+    (KEY_dead_belowbreve, "dead_belowbreve"),
+    (KEY_dead_belowdiaeresis, "dead_belowdiaeresis"),
+    (KEY_dead_invertedbreve, "dead_invertedbreve"),
+    (KEY_dead_belowcomma, "dead_belowcomma"),
+    (KEY_dead_currency, "dead_currency"),
+    (KEY_AccessX_Enable, "AccessX_Enable"),
+    (KEY_AccessX_Feedback_Enable, "AccessX_Feedback_Enable"),
+    (KEY_RepeatKeys_Enable, "RepeatKeys_Enable"),
+    (KEY_SlowKeys_Enable, "SlowKeys_Enable"),
+    (KEY_BounceKeys_Enable, "BounceKeys_Enable"),
+    (KEY_StickyKeys_Enable, "StickyKeys_Enable"),
+    (KEY_MouseKeys_Enable, "MouseKeys_Enable"),
+    (KEY_MouseKeys_Accel_Enable, "MouseKeys_Accel_Enable"),
+    (KEY_Overlay1_Enable, "Overlay1_Enable"),
+    (KEY_Overlay2_Enable, "Overlay2_Enable"),
+    (KEY_AudibleBell_Enable, "AudibleBell_Enable"),
+    (KEY_dead_a, "dead_a"),
+    (KEY_dead_A, "dead_A"),
+    (KEY_dead_e, "dead_e"),
+    (KEY_dead_E, "dead_E"),
+    (KEY_dead_i, "dead_i"),
+    (KEY_dead_I, "dead_I"),
+    (KEY_dead_o, "dead_o"),
+    (KEY_dead_O, "dead_O"),
+    (KEY_dead_u, "dead_u"),
+    (KEY_dead_U, "dead_U"),
+    (KEY_dead_small_schwa, "dead_small_schwa"),
+    (KEY_dead_capital_schwa, "dead_capital_schwa"),
+    (KEY_dead_greek, "dead_greek"),
+    (KEY_dead_lowline, "dead_lowline"),
+    (KEY_dead_aboveverticalline, "dead_aboveverticalline"),
+    (KEY_dead_belowverticalline, "dead_belowverticalline"),
+    (KEY_dead_longsolidusoverlay, "dead_longsolidusoverlay"),
+    (KEY_ch, "ch"),
+    (KEY_Ch, "Ch"),
+    (KEY_CH, "CH"),
+    (KEY_c_h, "c_h"),
+    (KEY_C_h, "C_h"),
+    (KEY_C_H, "C_H"),
+    (KEY_First_Virtual_Screen, "First_Virtual_Screen"),
+    (KEY_Prev_Virtual_Screen, "Prev_Virtual_Screen"),
+    (KEY_Next_Virtual_Screen, "Next_Virtual_Screen"),
+    (KEY_Last_Virtual_Screen, "Last_Virtual_Screen"),
+    (KEY_Terminate_Server, "Terminate_Server"),
+    (KEY_Pointer_Left, "Pointer_Left"),
+    (KEY_Pointer_Right, "Pointer_Right"),
+    (KEY_Pointer_Up, "Pointer_Up"),
+    (KEY_Pointer_Down, "Pointer_Down"),
+    (KEY_Pointer_UpLeft, "Pointer_UpLeft"),
+    (KEY_Pointer_UpRight, "Pointer_UpRight"),
+    (KEY_Pointer_DownLeft, "Pointer_DownLeft"),
+    (KEY_Pointer_DownRight, "Pointer_DownRight"),
+    (KEY_Pointer_Button_Dflt, "Pointer_Button_Dflt"),
+    (KEY_Pointer_Button1, "Pointer_Button1"),
+    (KEY_Pointer_Button2, "Pointer_Button2"),
+    (KEY_Pointer_Button3, "Pointer_Button3"),
+    (KEY_Pointer_Button4, "Pointer_Button4"),
+    (KEY_Pointer_Button5, "Pointer_Button5"),
+    (KEY_Pointer_DblClick_Dflt, "Pointer_DblClick_Dflt"),
+    (KEY_Pointer_DblClick1, "Pointer_DblClick1"),
+    (KEY_Pointer_DblClick2, "Pointer_DblClick2"),
+    (KEY_Pointer_DblClick3, "Pointer_DblClick3"),
+    (KEY_Pointer_DblClick4, "Pointer_DblClick4"),
+    (KEY_Pointer_DblClick5, "Pointer_DblClick5"),
+    (KEY_Pointer_Drag_Dflt, "Pointer_Drag_Dflt"),
+    (KEY_Pointer_Drag1, "Pointer_Drag1"),
+    (KEY_Pointer_Drag2, "Pointer_Drag2"),
+    (KEY_Pointer_Drag3, "Pointer_Drag3"),
+    (KEY_Pointer_Drag4, "Pointer_Drag4"),
+    (KEY_Pointer_EnableKeys, "Pointer_EnableKeys"),
+    (KEY_Pointer_Accelerate, "Pointer_Accelerate"),
+    (KEY_Pointer_DfltBtnNext, "Pointer_DfltBtnNext"),
+    (KEY_Pointer_DfltBtnPrev, "Pointer_DfltBtnPrev"),
+    (KEY_Pointer_Drag5, "Pointer_Drag5"),
+    (KEY_BackSpace, "BackSpace"),
+    (KEY_Tab, "Tab"),
+    (KEY_Linefeed, "Linefeed"),
+    (KEY_Clear, "Clear"),
+    (KEY_Return, "Return"),
+    (KEY_Pause, "Pause"),
+    (KEY_Scroll_Lock, "Scroll_Lock"),
+    (KEY_Sys_Req, "Sys_Req"),
+    (KEY_Escape, "Escape"),
+    (KEY_Multi_key, "Multi_key"),
+    (KEY_Kanji, "Kanji"),
+    (KEY_Muhenkan, "Muhenkan"),
+    (KEY_Henkan_Mode, "Henkan_Mode"),
+    (KEY_Romaji, "Romaji"),
+    (KEY_Hiragana, "Hiragana"),
+    (KEY_Katakana, "Katakana"),
+    (KEY_Hiragana_Katakana, "Hiragana_Katakana"),
+    (KEY_Zenkaku, "Zenkaku"),
+    (KEY_Hankaku, "Hankaku"),
+    (KEY_Zenkaku_Hankaku, "Zenkaku_Hankaku"),
+    (KEY_Touroku, "Touroku"),
+    (KEY_Massyo, "Massyo"),
+    (KEY_Kana_Lock, "Kana_Lock"),
+    (KEY_Kana_Shift, "Kana_Shift"),
+    (KEY_Eisu_Shift, "Eisu_Shift"),
+    (KEY_Eisu_toggle, "Eisu_toggle"),
+    (KEY_Hangul, "Hangul"),
+    (KEY_Hangul_Start, "Hangul_Start"),
+    (KEY_Hangul_End, "Hangul_End"),
+    (KEY_Hangul_Hanja, "Hangul_Hanja"),
+    (KEY_Hangul_Jamo, "Hangul_Jamo"),
+    (KEY_Hangul_Romaja, "Hangul_Romaja"),
+    (KEY_Codeinput, "Codeinput"),
+    (KEY_Hangul_Jeonja, "Hangul_Jeonja"),
+    (KEY_Hangul_Banja, "Hangul_Banja"),
+    (KEY_Hangul_PreHanja, "Hangul_PreHanja"),
+    (KEY_Hangul_PostHanja, "Hangul_PostHanja"),
+    (KEY_SingleCandidate, "SingleCandidate"),
+    (KEY_MultipleCandidate, "MultipleCandidate"),
+    (KEY_PreviousCandidate, "PreviousCandidate"),
+    (KEY_Hangul_Special, "Hangul_Special"),
+    (KEY_Home, "Home"),
+    (KEY_Left, "Left"),
+    (KEY_Up, "Up"),
+    (KEY_Right, "Right"),
+    (KEY_Down, "Down"),
+    (KEY_Prior, "Prior"),
+    (KEY_Next, "Next"),
+    (KEY_End, "End"),
+    (KEY_Begin, "Begin"),
+    (KEY_Select, "Select"),
+    (KEY_Print, "Print"),
+    (KEY_Execute, "Execute"),
+    (KEY_Insert, "Insert"),
+    (KEY_Undo, "Undo"),
+    (KEY_Redo, "Redo"),
+    (KEY_Menu, "Menu"),
+    (KEY_Find, "Find"),
+    (KEY_Cancel, "Cancel"),
+    (KEY_Help, "Help"),
+    (KEY_Break, "Break"),
+    (KEY_Mode_switch, "Mode_switch"),
+    (KEY_Num_Lock, "Num_Lock"),
+    (KEY_KP_Space, "KP_Space"),
+    (KEY_KP_Tab, "KP_Tab"),
+    (KEY_KP_Enter, "KP_Enter"),
+    (KEY_KP_F1, "KP_F1"),
+    (KEY_KP_F2, "KP_F2"),
+    (KEY_KP_F3, "KP_F3"),
+    (KEY_KP_F4, "KP_F4"),
+    (KEY_KP_Home, "KP_Home"),
+    (KEY_KP_Left, "KP_Left"),
+    (KEY_KP_Up, "KP_Up"),
+    (KEY_KP_Right, "KP_Right"),
+    (KEY_KP_Down, "KP_Down"),
+    (KEY_KP_Prior, "KP_Prior"),
+    (KEY_KP_Next, "KP_Next"),
+    (KEY_KP_End, "KP_End"),
+    (KEY_KP_Begin, "KP_Begin"),
+    (KEY_KP_Insert, "KP_Insert"),
+    (KEY_KP_Delete, "KP_Delete"),
+    (KEY_KP_Multiply, "KP_Multiply"),
+    (KEY_KP_Add, "KP_Add"),
+    (KEY_KP_Separator, "KP_Separator"),
+    (KEY_KP_Subtract, "KP_Subtract"),
+    (KEY_KP_Decimal, "KP_Decimal"),
+    (KEY_KP_Divide, "KP_Divide"),
+    (KEY_KP_0, "KP_0"),
+    (KEY_KP_1, "KP_1"),
+    (KEY_KP_2, "KP_2"),
+    (KEY_KP_3, "KP_3"),
+    (KEY_KP_4, "KP_4"),
+    (KEY_KP_5, "KP_5"),
+    (KEY_KP_6, "KP_6"),
+    (KEY_KP_7, "KP_7"),
+    (KEY_KP_8, "KP_8"),
+    (KEY_KP_9, "KP_9"),
+    (KEY_KP_Equal, "KP_Equal"),
+    (KEY_F1, "F1"),
+    (KEY_F2, "F2"),
+    (KEY_F3, "F3"),
+    (KEY_F4, "F4"),
+    (KEY_F5, "F5"),
+    (KEY_F6, "F6"),
+    (KEY_F7, "F7"),
+    (KEY_F8, "F8"),
+    (KEY_F9, "F9"),
+    (KEY_F10, "F10"),
+    (KEY_F11, "F11"),
+    (KEY_F12, "F12"),
+    (KEY_F13, "F13"),
+    (KEY_F14, "F14"),
+    (KEY_F15, "F15"),
+    (KEY_F16, "F16"),
+    (KEY_F17, "F17"),
+    (KEY_F18, "F18"),
+    (KEY_F19, "F19"),
+    (KEY_F20, "F20"),
+    (KEY_F21, "F21"),
+    (KEY_F22, "F22"),
+    (KEY_F23, "F23"),
+    (KEY_F24, "F24"),
+    (KEY_F25, "F25"),
+    (KEY_F26, "F26"),
+    (KEY_F27, "F27"),
+    (KEY_F28, "F28"),
+    (KEY_F29, "F29"),
+    (KEY_F30, "F30"),
+    (KEY_F31, "F31"),
+    (KEY_F32, "F32"),
+    (KEY_F33, "F33"),
+    (KEY_F34, "F34"),
+    (KEY_F35, "F35"),
+    (KEY_Shift_L, "Shift_L"),
+    (KEY_Shift_R, "Shift_R"),
+    (KEY_Control_L, "Control_L"),
+    (KEY_Control_R, "Control_R"),
+    (KEY_Caps_Lock, "Caps_Lock"),
+    (KEY_Shift_Lock, "Shift_Lock"),
+    (KEY_Meta_L, "Meta_L"),
+    (KEY_Meta_R, "Meta_R"),
+    (KEY_Alt_L, "Alt_L"),
+    (KEY_Alt_R, "Alt_R"),
+    (KEY_Super_L, "Super_L"),
+    (KEY_Super_R, "Super_R"),
+    (KEY_Hyper_L, "Hyper_L"),
+    (KEY_Hyper_R, "Hyper_R"),
+    (KEY_braille_dot_1, "braille_dot_1"),
+    (KEY_braille_dot_2, "braille_dot_2"),
+    (KEY_braille_dot_3, "braille_dot_3"),
+    (KEY_braille_dot_4, "braille_dot_4"),
+    (KEY_braille_dot_5, "braille_dot_5"),
+    (KEY_braille_dot_6, "braille_dot_6"),
+    (KEY_braille_dot_7, "braille_dot_7"),
+    (KEY_braille_dot_8, "braille_dot_8"),
+    (KEY_braille_dot_9, "braille_dot_9"),
+    (KEY_braille_dot_10, "braille_dot_10"),
+    (KEY_Delete, "Delete"),
+    (KEY_VoidSymbol, "VoidSymbol"),
+    (KEY_Ibreve, "Ibreve"),
+    (KEY_ibreve, "ibreve"),
+    (KEY_Wcircumflex, "Wcircumflex"),
+    (KEY_wcircumflex, "wcircumflex"),
+    (KEY_Ycircumflex, "Ycircumflex"),
+    (KEY_ycircumflex, "ycircumflex"),
+    (KEY_SCHWA, "SCHWA"),
+    (KEY_Obarred, "Obarred"),
+    (KEY_Ohorn, "Ohorn"),
+    (KEY_ohorn, "ohorn"),
+    (KEY_Uhorn, "Uhorn"),
+    (KEY_uhorn, "uhorn"),
+    (KEY_Zstroke, "Zstroke"),
+    (KEY_zstroke, "zstroke"),
+    (KEY_EZH, "EZH"),
+    (KEY_Ocaron, "Ocaron"),
+    (KEY_ocaron, "ocaron"),
+    (KEY_Gcaron, "Gcaron"),
+    (KEY_gcaron, "gcaron"),
+    (KEY_schwa, "schwa"),
+    (KEY_obarred, "obarred"),
+    (KEY_ezh, "ezh"),
+    (KEY_combining_grave, "combining_grave"),
+    (KEY_combining_acute, "combining_acute"),
+    (KEY_combining_tilde, "combining_tilde"),
+    (KEY_combining_hook, "combining_hook"),
+    (KEY_combining_belowdot, "combining_belowdot"),
+    (KEY_Cyrillic_GHE_bar, "Cyrillic_GHE_bar"),
+    (KEY_Cyrillic_ghe_bar, "Cyrillic_ghe_bar"),
+    (KEY_Cyrillic_ZHE_descender, "Cyrillic_ZHE_descender"),
+    (KEY_Cyrillic_zhe_descender, "Cyrillic_zhe_descender"),
+    (KEY_Cyrillic_KA_descender, "Cyrillic_KA_descender"),
+    (KEY_Cyrillic_ka_descender, "Cyrillic_ka_descender"),
+    (KEY_Cyrillic_KA_vertstroke, "Cyrillic_KA_vertstroke"),
+    (KEY_Cyrillic_ka_vertstroke, "Cyrillic_ka_vertstroke"),
+    (KEY_Cyrillic_EN_descender, "Cyrillic_EN_descender"),
+    (KEY_Cyrillic_en_descender, "Cyrillic_en_descender"),
+    (KEY_Cyrillic_U_straight, "Cyrillic_U_straight"),
+    (KEY_Cyrillic_u_straight, "Cyrillic_u_straight"),
+    (KEY_Cyrillic_U_straight_bar, "Cyrillic_U_straight_bar"),
+    (KEY_Cyrillic_u_straight_bar, "Cyrillic_u_straight_bar"),
+    (KEY_Cyrillic_HA_descender, "Cyrillic_HA_descender"),
+    (KEY_Cyrillic_ha_descender, "Cyrillic_ha_descender"),
+    (KEY_Cyrillic_CHE_descender, "Cyrillic_CHE_descender"),
+    (KEY_Cyrillic_che_descender, "Cyrillic_che_descender"),
+    (KEY_Cyrillic_CHE_vertstroke, "Cyrillic_CHE_vertstroke"),
+    (KEY_Cyrillic_che_vertstroke, "Cyrillic_che_vertstroke"),
+    (KEY_Cyrillic_SHHA, "Cyrillic_SHHA"),
+    (KEY_Cyrillic_shha, "Cyrillic_shha"),
+    (KEY_Cyrillic_SCHWA, "Cyrillic_SCHWA"),
+    (KEY_Cyrillic_schwa, "Cyrillic_schwa"),
+    (KEY_Cyrillic_I_macron, "Cyrillic_I_macron"),
+    (KEY_Cyrillic_i_macron, "Cyrillic_i_macron"),
+    (KEY_Cyrillic_O_bar, "Cyrillic_O_bar"),
+    (KEY_Cyrillic_o_bar, "Cyrillic_o_bar"),
+    (KEY_Cyrillic_U_macron, "Cyrillic_U_macron"),
+    (KEY_Cyrillic_u_macron, "Cyrillic_u_macron"),
+    (KEY_Armenian_AYB, "Armenian_AYB"),
+    (KEY_Armenian_BEN, "Armenian_BEN"),
+    (KEY_Armenian_GIM, "Armenian_GIM"),
+    (KEY_Armenian_DA, "Armenian_DA"),
+    (KEY_Armenian_YECH, "Armenian_YECH"),
+    (KEY_Armenian_ZA, "Armenian_ZA"),
+    (KEY_Armenian_E, "Armenian_E"),
+    (KEY_Armenian_AT, "Armenian_AT"),
+    (KEY_Armenian_TO, "Armenian_TO"),
+    (KEY_Armenian_ZHE, "Armenian_ZHE"),
+    (KEY_Armenian_INI, "Armenian_INI"),
+    (KEY_Armenian_LYUN, "Armenian_LYUN"),
+    (KEY_Armenian_KHE, "Armenian_KHE"),
+    (KEY_Armenian_TSA, "Armenian_TSA"),
+    (KEY_Armenian_KEN, "Armenian_KEN"),
+    (KEY_Armenian_HO, "Armenian_HO"),
+    (KEY_Armenian_DZA, "Armenian_DZA"),
+    (KEY_Armenian_GHAT, "Armenian_GHAT"),
+    (KEY_Armenian_TCHE, "Armenian_TCHE"),
+    (KEY_Armenian_MEN, "Armenian_MEN"),
+    (KEY_Armenian_HI, "Armenian_HI"),
+    (KEY_Armenian_NU, "Armenian_NU"),
+    (KEY_Armenian_SHA, "Armenian_SHA"),
+    (KEY_Armenian_VO, "Armenian_VO"),
+    (KEY_Armenian_CHA, "Armenian_CHA"),
+    (KEY_Armenian_PE, "Armenian_PE"),
+    (KEY_Armenian_JE, "Armenian_JE"),
+    (KEY_Armenian_RA, "Armenian_RA"),
+    (KEY_Armenian_SE, "Armenian_SE"),
+    (KEY_Armenian_VEV, "Armenian_VEV"),
+    (KEY_Armenian_TYUN, "Armenian_TYUN"),
+    (KEY_Armenian_RE, "Armenian_RE"),
+    (KEY_Armenian_TSO, "Armenian_TSO"),
+    (KEY_Armenian_VYUN, "Armenian_VYUN"),
+    (KEY_Armenian_PYUR, "Armenian_PYUR"),
+    (KEY_Armenian_KE, "Armenian_KE"),
+    (KEY_Armenian_O, "Armenian_O"),
+    (KEY_Armenian_FE, "Armenian_FE"),
+    (KEY_Armenian_apostrophe, "Armenian_apostrophe"),
+    (KEY_Armenian_accent, "Armenian_accent"),
+    (KEY_Armenian_exclam, "Armenian_exclam"),
+    (KEY_Armenian_separation_mark, "Armenian_separation_mark"),
+    (KEY_Armenian_question, "Armenian_question"),
+    (KEY_Armenian_ayb, "Armenian_ayb"),
+    (KEY_Armenian_ben, "Armenian_ben"),
+    (KEY_Armenian_gim, "Armenian_gim"),
+    (KEY_Armenian_da, "Armenian_da"),
+    (KEY_Armenian_yech, "Armenian_yech"),
+    (KEY_Armenian_za, "Armenian_za"),
+    (KEY_Armenian_e, "Armenian_e"),
+    (KEY_Armenian_at, "Armenian_at"),
+    (KEY_Armenian_to, "Armenian_to"),
+    (KEY_Armenian_zhe, "Armenian_zhe"),
+    (KEY_Armenian_ini, "Armenian_ini"),
+    (KEY_Armenian_lyun, "Armenian_lyun"),
+    (KEY_Armenian_khe, "Armenian_khe"),
+    (KEY_Armenian_tsa, "Armenian_tsa"),
+    (KEY_Armenian_ken, "Armenian_ken"),
+    (KEY_Armenian_ho, "Armenian_ho"),
+    (KEY_Armenian_dza, "Armenian_dza"),
+    (KEY_Armenian_ghat, "Armenian_ghat"),
+    (KEY_Armenian_tche, "Armenian_tche"),
+    (KEY_Armenian_men, "Armenian_men"),
+    (KEY_Armenian_hi, "Armenian_hi"),
+    (KEY_Armenian_nu, "Armenian_nu"),
+    (KEY_Armenian_sha, "Armenian_sha"),
+    (KEY_Armenian_vo, "Armenian_vo"),
+    (KEY_Armenian_cha, "Armenian_cha"),
+    (KEY_Armenian_pe, "Armenian_pe"),
+    (KEY_Armenian_je, "Armenian_je"),
+    (KEY_Armenian_ra, "Armenian_ra"),
+    (KEY_Armenian_se, "Armenian_se"),
+    (KEY_Armenian_vev, "Armenian_vev"),
+    (KEY_Armenian_tyun, "Armenian_tyun"),
+    (KEY_Armenian_re, "Armenian_re"),
+    (KEY_Armenian_tso, "Armenian_tso"),
+    (KEY_Armenian_vyun, "Armenian_vyun"),
+    (KEY_Armenian_pyur, "Armenian_pyur"),
+    (KEY_Armenian_ke, "Armenian_ke"),
+    (KEY_Armenian_o, "Armenian_o"),
+    (KEY_Armenian_fe, "Armenian_fe"),
+    (KEY_Armenian_ligature_ew, "Armenian_ligature_ew"),
+    (KEY_Armenian_full_stop, "Armenian_full_stop"),
+    (KEY_Armenian_hyphen, "Armenian_hyphen"),
+    (KEY_Arabic_madda_above, "Arabic_madda_above"),
+    (KEY_Arabic_hamza_above, "Arabic_hamza_above"),
+    (KEY_Arabic_hamza_below, "Arabic_hamza_below"),
+    (KEY_Arabic_0, "Arabic_0"),
+    (KEY_Arabic_1, "Arabic_1"),
+    (KEY_Arabic_2, "Arabic_2"),
+    (KEY_Arabic_3, "Arabic_3"),
+    (KEY_Arabic_4, "Arabic_4"),
+    (KEY_Arabic_5, "Arabic_5"),
+    (KEY_Arabic_6, "Arabic_6"),
+    (KEY_Arabic_7, "Arabic_7"),
+    (KEY_Arabic_8, "Arabic_8"),
+    (KEY_Arabic_9, "Arabic_9"),
+    (KEY_Arabic_percent, "Arabic_percent"),
+    (KEY_Arabic_superscript_alef, "Arabic_superscript_alef"),
+    (KEY_Arabic_tteh, "Arabic_tteh"),
+    (KEY_Arabic_peh, "Arabic_peh"),
+    (KEY_Arabic_tcheh, "Arabic_tcheh"),
+    (KEY_Arabic_ddal, "Arabic_ddal"),
+    (KEY_Arabic_rreh, "Arabic_rreh"),
+    (KEY_Arabic_jeh, "Arabic_jeh"),
+    (KEY_Arabic_veh, "Arabic_veh"),
+    (KEY_Arabic_keheh, "Arabic_keheh"),
+    (KEY_Arabic_gaf, "Arabic_gaf"),
+    (KEY_Arabic_noon_ghunna, "Arabic_noon_ghunna"),
+    (KEY_Arabic_heh_doachashmee, "Arabic_heh_doachashmee"),
+    (KEY_Arabic_heh_goal, "Arabic_heh_goal"),
+    (KEY_Farsi_yeh, "Farsi_yeh"),
+    (KEY_Arabic_yeh_baree, "Arabic_yeh_baree"),
+    (KEY_Arabic_fullstop, "Arabic_fullstop"),
+    (KEY_Farsi_0, "Farsi_0"),
+    (KEY_Farsi_1, "Farsi_1"),
+    (KEY_Farsi_2, "Farsi_2"),
+    (KEY_Farsi_3, "Farsi_3"),
+    (KEY_Farsi_4, "Farsi_4"),
+    (KEY_Farsi_5, "Farsi_5"),
+    (KEY_Farsi_6, "Farsi_6"),
+    (KEY_Farsi_7, "Farsi_7"),
+    (KEY_Farsi_8, "Farsi_8"),
+    (KEY_Farsi_9, "Farsi_9"),
+    (KEY_Sinh_ng, "Sinh_ng"),
+    (KEY_Sinh_h2, "Sinh_h2"),
+    (KEY_Sinh_a, "Sinh_a"),
+    (KEY_Sinh_aa, "Sinh_aa"),
+    (KEY_Sinh_ae, "Sinh_ae"),
+    (KEY_Sinh_aee, "Sinh_aee"),
+    (KEY_Sinh_i, "Sinh_i"),
+    (KEY_Sinh_ii, "Sinh_ii"),
+    (KEY_Sinh_u, "Sinh_u"),
+    (KEY_Sinh_uu, "Sinh_uu"),
+    (KEY_Sinh_ri, "Sinh_ri"),
+    (KEY_Sinh_rii, "Sinh_rii"),
+    (KEY_Sinh_lu, "Sinh_lu"),
+    (KEY_Sinh_luu, "Sinh_luu"),
+    (KEY_Sinh_e, "Sinh_e"),
+    (KEY_Sinh_ee, "Sinh_ee"),
+    (KEY_Sinh_ai, "Sinh_ai"),
+    (KEY_Sinh_o, "Sinh_o"),
+    (KEY_Sinh_oo, "Sinh_oo"),
+    (KEY_Sinh_au, "Sinh_au"),
+    (KEY_Sinh_ka, "Sinh_ka"),
+    (KEY_Sinh_kha, "Sinh_kha"),
+    (KEY_Sinh_ga, "Sinh_ga"),
+    (KEY_Sinh_gha, "Sinh_gha"),
+    (KEY_Sinh_ng2, "Sinh_ng2"),
+    (KEY_Sinh_nga, "Sinh_nga"),
+    (KEY_Sinh_ca, "Sinh_ca"),
+    (KEY_Sinh_cha, "Sinh_cha"),
+    (KEY_Sinh_ja, "Sinh_ja"),
+    (KEY_Sinh_jha, "Sinh_jha"),
+    (KEY_Sinh_nya, "Sinh_nya"),
+    (KEY_Sinh_jnya, "Sinh_jnya"),
+    (KEY_Sinh_nja, "Sinh_nja"),
+    (KEY_Sinh_tta, "Sinh_tta"),
+    (KEY_Sinh_ttha, "Sinh_ttha"),
+    (KEY_Sinh_dda, "Sinh_dda"),
+    (KEY_Sinh_ddha, "Sinh_ddha"),
+    (KEY_Sinh_nna, "Sinh_nna"),
+    (KEY_Sinh_ndda, "Sinh_ndda"),
+    (KEY_Sinh_tha, "Sinh_tha"),
+    (KEY_Sinh_thha, "Sinh_thha"),
+    (KEY_Sinh_dha, "Sinh_dha"),
+    (KEY_Sinh_dhha, "Sinh_dhha"),
+    (KEY_Sinh_na, "Sinh_na"),
+    (KEY_Sinh_ndha, "Sinh_ndha"),
+    (KEY_Sinh_pa, "Sinh_pa"),
+    (KEY_Sinh_pha, "Sinh_pha"),
+    (KEY_Sinh_ba, "Sinh_ba"),
+    (KEY_Sinh_bha, "Sinh_bha"),
+    (KEY_Sinh_ma, "Sinh_ma"),
+    (KEY_Sinh_mba, "Sinh_mba"),
+    (KEY_Sinh_ya, "Sinh_ya"),
+    (KEY_Sinh_ra, "Sinh_ra"),
+    (KEY_Sinh_la, "Sinh_la"),
+    (KEY_Sinh_va, "Sinh_va"),
+    (KEY_Sinh_sha, "Sinh_sha"),
+    (KEY_Sinh_ssha, "Sinh_ssha"),
+    (KEY_Sinh_sa, "Sinh_sa"),
+    (KEY_Sinh_ha, "Sinh_ha"),
+    (KEY_Sinh_lla, "Sinh_lla"),
+    (KEY_Sinh_fa, "Sinh_fa"),
+    (KEY_Sinh_al, "Sinh_al"),
+    (KEY_Sinh_aa2, "Sinh_aa2"),
+    (KEY_Sinh_ae2, "Sinh_ae2"),
+    (KEY_Sinh_aee2, "Sinh_aee2"),
+    (KEY_Sinh_i2, "Sinh_i2"),
+    (KEY_Sinh_ii2, "Sinh_ii2"),
+    (KEY_Sinh_u2, "Sinh_u2"),
+    (KEY_Sinh_uu2, "Sinh_uu2"),
+    (KEY_Sinh_ru2, "Sinh_ru2"),
+    (KEY_Sinh_e2, "Sinh_e2"),
+    (KEY_Sinh_ee2, "Sinh_ee2"),
+    (KEY_Sinh_ai2, "Sinh_ai2"),
+    (KEY_Sinh_o2, "Sinh_o2"),
+    (KEY_Sinh_oo2, "Sinh_oo2"),
+    (KEY_Sinh_au2, "Sinh_au2"),
+    (KEY_Sinh_lu2, "Sinh_lu2"),
+    (KEY_Sinh_ruu2, "Sinh_ruu2"),
+    (KEY_Sinh_luu2, "Sinh_luu2"),
+    (KEY_Sinh_kunddaliya, "Sinh_kunddaliya"),
+    (KEY_Georgian_an, "Georgian_an"),
+    (KEY_Georgian_ban, "Georgian_ban"),
+    (KEY_Georgian_gan, "Georgian_gan"),
+    (KEY_Georgian_don, "Georgian_don"),
+    (KEY_Georgian_en, "Georgian_en"),
+    (KEY_Georgian_vin, "Georgian_vin"),
+    (KEY_Georgian_zen, "Georgian_zen"),
+    (KEY_Georgian_tan, "Georgian_tan"),
+    (KEY_Georgian_in, "Georgian_in"),
+    (KEY_Georgian_kan, "Georgian_kan"),
+    (KEY_Georgian_las, "Georgian_las"),
+    (KEY_Georgian_man, "Georgian_man"),
+    (KEY_Georgian_nar, "Georgian_nar"),
+    (KEY_Georgian_on, "Georgian_on"),
+    (KEY_Georgian_par, "Georgian_par"),
+    (KEY_Georgian_zhar, "Georgian_zhar"),
+    (KEY_Georgian_rae, "Georgian_rae"),
+    (KEY_Georgian_san, "Georgian_san"),
+    (KEY_Georgian_tar, "Georgian_tar"),
+    (KEY_Georgian_un, "Georgian_un"),
+    (KEY_Georgian_phar, "Georgian_phar"),
+    (KEY_Georgian_khar, "Georgian_khar"),
+    (KEY_Georgian_ghan, "Georgian_ghan"),
+    (KEY_Georgian_qar, "Georgian_qar"),
+    (KEY_Georgian_shin, "Georgian_shin"),
+    (KEY_Georgian_chin, "Georgian_chin"),
+    (KEY_Georgian_can, "Georgian_can"),
+    (KEY_Georgian_jil, "Georgian_jil"),
+    (KEY_Georgian_cil, "Georgian_cil"),
+    (KEY_Georgian_char, "Georgian_char"),
+    (KEY_Georgian_xan, "Georgian_xan"),
+    (KEY_Georgian_jhan, "Georgian_jhan"),
+    (KEY_Georgian_hae, "Georgian_hae"),
+    (KEY_Georgian_he, "Georgian_he"),
+    (KEY_Georgian_hie, "Georgian_hie"),
+    (KEY_Georgian_we, "Georgian_we"),
+    (KEY_Georgian_har, "Georgian_har"),
+    (KEY_Georgian_hoe, "Georgian_hoe"),
+    (KEY_Georgian_fi, "Georgian_fi"),
+    (KEY_Babovedot, "Babovedot"),
+    (KEY_babovedot, "babovedot"),
+    (KEY_Dabovedot, "Dabovedot"),
+    (KEY_dabovedot, "dabovedot"),
+    (KEY_Fabovedot, "Fabovedot"),
+    (KEY_fabovedot, "fabovedot"),
+    (KEY_Lbelowdot, "Lbelowdot"),
+    (KEY_lbelowdot, "lbelowdot"),
+    (KEY_Mabovedot, "Mabovedot"),
+    (KEY_mabovedot, "mabovedot"),
+    (KEY_Pabovedot, "Pabovedot"),
+    (KEY_pabovedot, "pabovedot"),
+    (KEY_Sabovedot, "Sabovedot"),
+    (KEY_sabovedot, "sabovedot"),
+    (KEY_Tabovedot, "Tabovedot"),
+    (KEY_tabovedot, "tabovedot"),
+    (KEY_Wgrave, "Wgrave"),
+    (KEY_wgrave, "wgrave"),
+    (KEY_Wacute, "Wacute"),
+    (KEY_wacute, "wacute"),
+    (KEY_Wdiaeresis, "Wdiaeresis"),
+    (KEY_wdiaeresis, "wdiaeresis"),
+    (KEY_Xabovedot, "Xabovedot"),
+    (KEY_xabovedot, "xabovedot"),
+    (KEY_Abelowdot, "Abelowdot"),
+    (KEY_abelowdot, "abelowdot"),
+    (KEY_Ahook, "Ahook"),
+    (KEY_ahook, "ahook"),
+    (KEY_Acircumflexacute, "Acircumflexacute"),
+    (KEY_acircumflexacute, "acircumflexacute"),
+    (KEY_Acircumflexgrave, "Acircumflexgrave"),
+    (KEY_acircumflexgrave, "acircumflexgrave"),
+    (KEY_Acircumflexhook, "Acircumflexhook"),
+    (KEY_acircumflexhook, "acircumflexhook"),
+    (KEY_Acircumflextilde, "Acircumflextilde"),
+    (KEY_acircumflextilde, "acircumflextilde"),
+    (KEY_Acircumflexbelowdot, "Acircumflexbelowdot"),
+    (KEY_acircumflexbelowdot, "acircumflexbelowdot"),
+    (KEY_Abreveacute, "Abreveacute"),
+    (KEY_abreveacute, "abreveacute"),
+    (KEY_Abrevegrave, "Abrevegrave"),
+    (KEY_abrevegrave, "abrevegrave"),
+    (KEY_Abrevehook, "Abrevehook"),
+    (KEY_abrevehook, "abrevehook"),
+    (KEY_Abrevetilde, "Abrevetilde"),
+    (KEY_abrevetilde, "abrevetilde"),
+    (KEY_Abrevebelowdot, "Abrevebelowdot"),
+    (KEY_abrevebelowdot, "abrevebelowdot"),
+    (KEY_Ebelowdot, "Ebelowdot"),
+    (KEY_ebelowdot, "ebelowdot"),
+    (KEY_Ehook, "Ehook"),
+    (KEY_ehook, "ehook"),
+    (KEY_Etilde, "Etilde"),
+    (KEY_etilde, "etilde"),
+    (KEY_Ecircumflexacute, "Ecircumflexacute"),
+    (KEY_ecircumflexacute, "ecircumflexacute"),
+    (KEY_Ecircumflexgrave, "Ecircumflexgrave"),
+    (KEY_ecircumflexgrave, "ecircumflexgrave"),
+    (KEY_Ecircumflexhook, "Ecircumflexhook"),
+    (KEY_ecircumflexhook, "ecircumflexhook"),
+    (KEY_Ecircumflextilde, "Ecircumflextilde"),
+    (KEY_ecircumflextilde, "ecircumflextilde"),
+    (KEY_Ecircumflexbelowdot, "Ecircumflexbelowdot"),
+    (KEY_ecircumflexbelowdot, "ecircumflexbelowdot"),
+    (KEY_Ihook, "Ihook"),
+    (KEY_ihook, "ihook"),
+    (KEY_Ibelowdot, "Ibelowdot"),
+    (KEY_ibelowdot, "ibelowdot"),
+    (KEY_Obelowdot, "Obelowdot"),
+    (KEY_obelowdot, "obelowdot"),
+    (KEY_Ohook, "Ohook"),
+    (KEY_ohook, "ohook"),
+    (KEY_Ocircumflexacute, "Ocircumflexacute"),
+    (KEY_ocircumflexacute, "ocircumflexacute"),
+    (KEY_Ocircumflexgrave, "Ocircumflexgrave"),
+    (KEY_ocircumflexgrave, "ocircumflexgrave"),
+    (KEY_Ocircumflexhook, "Ocircumflexhook"),
+    (KEY_ocircumflexhook, "ocircumflexhook"),
+    (KEY_Ocircumflextilde, "Ocircumflextilde"),
+    (KEY_ocircumflextilde, "ocircumflextilde"),
+    (KEY_Ocircumflexbelowdot, "Ocircumflexbelowdot"),
+    (KEY_ocircumflexbelowdot, "ocircumflexbelowdot"),
+    (KEY_Ohornacute, "Ohornacute"),
+    (KEY_ohornacute, "ohornacute"),
+    (KEY_Ohorngrave, "Ohorngrave"),
+    (KEY_ohorngrave, "ohorngrave"),
+    (KEY_Ohornhook, "Ohornhook"),
+    (KEY_ohornhook, "ohornhook"),
+    (KEY_Ohorntilde, "Ohorntilde"),
+    (KEY_ohorntilde, "ohorntilde"),
+    (KEY_Ohornbelowdot, "Ohornbelowdot"),
+    (KEY_ohornbelowdot, "ohornbelowdot"),
+    (KEY_Ubelowdot, "Ubelowdot"),
+    (KEY_ubelowdot, "ubelowdot"),
+    (KEY_Uhook, "Uhook"),
+    (KEY_uhook, "uhook"),
+    (KEY_Uhornacute, "Uhornacute"),
+    (KEY_uhornacute, "uhornacute"),
+    (KEY_Uhorngrave, "Uhorngrave"),
+    (KEY_uhorngrave, "uhorngrave"),
+    (KEY_Uhornhook, "Uhornhook"),
+    (KEY_uhornhook, "uhornhook"),
+    (KEY_Uhorntilde, "Uhorntilde"),
+    (KEY_uhorntilde, "uhorntilde"),
+    (KEY_Uhornbelowdot, "Uhornbelowdot"),
+    (KEY_uhornbelowdot, "uhornbelowdot"),
+    (KEY_Ygrave, "Ygrave"),
+    (KEY_ygrave, "ygrave"),
+    (KEY_Ybelowdot, "Ybelowdot"),
+    (KEY_ybelowdot, "ybelowdot"),
+    (KEY_Yhook, "Yhook"),
+    (KEY_yhook, "yhook"),
+    (KEY_Ytilde, "Ytilde"),
+    (KEY_ytilde, "ytilde"),
+    (KEY_zerosuperior, "zerosuperior"),
+    (KEY_foursuperior, "foursuperior"),
+    (KEY_fivesuperior, "fivesuperior"),
+    (KEY_sixsuperior, "sixsuperior"),
+    (KEY_sevensuperior, "sevensuperior"),
+    (KEY_eightsuperior, "eightsuperior"),
+    (KEY_ninesuperior, "ninesuperior"),
+    (KEY_zerosubscript, "zerosubscript"),
+    (KEY_onesubscript, "onesubscript"),
+    (KEY_twosubscript, "twosubscript"),
+    (KEY_threesubscript, "threesubscript"),
+    (KEY_foursubscript, "foursubscript"),
+    (KEY_fivesubscript, "fivesubscript"),
+    (KEY_sixsubscript, "sixsubscript"),
+    (KEY_sevensubscript, "sevensubscript"),
+    (KEY_eightsubscript, "eightsubscript"),
+    (KEY_ninesubscript, "ninesubscript"),
+    (KEY_EcuSign, "EcuSign"),
+    (KEY_ColonSign, "ColonSign"),
+    (KEY_CruzeiroSign, "CruzeiroSign"),
+    (KEY_FFrancSign, "FFrancSign"),
+    (KEY_LiraSign, "LiraSign"),
+    (KEY_MillSign, "MillSign"),
+    (KEY_NairaSign, "NairaSign"),
+    (KEY_PesetaSign, "PesetaSign"),
+    (KEY_RupeeSign, "RupeeSign"),
+    (KEY_WonSign, "WonSign"),
+    (KEY_NewSheqelSign, "NewSheqelSign"),
+    (KEY_DongSign, "DongSign"),
+    (KEY_partdifferential, "partdifferential"),
+    (KEY_emptyset, "emptyset"),
+    (KEY_elementof, "elementof"),
+    (KEY_notelementof, "notelementof"),
+    (KEY_containsas, "containsas"),
+    (KEY_squareroot, "squareroot"),
+    (KEY_cuberoot, "cuberoot"),
+    (KEY_fourthroot, "fourthroot"),
+    (KEY_dintegral, "dintegral"),
+    (KEY_tintegral, "tintegral"),
+    (KEY_because, "because"),
+    (KEY_notapproxeq, "notapproxeq"),
+    (KEY_approxeq, "approxeq"),
+    (KEY_notidentical, "notidentical"),
+    (KEY_stricteq, "stricteq"),
+    (KEY_braille_blank, "braille_blank"),
+    (KEY_braille_dots_1, "braille_dots_1"),
+    (KEY_braille_dots_2, "braille_dots_2"),
+    (KEY_braille_dots_12, "braille_dots_12"),
+    (KEY_braille_dots_3, "braille_dots_3"),
+    (KEY_braille_dots_13, "braille_dots_13"),
+    (KEY_braille_dots_23, "braille_dots_23"),
+    (KEY_braille_dots_123, "braille_dots_123"),
+    (KEY_braille_dots_4, "braille_dots_4"),
+    (KEY_braille_dots_14, "braille_dots_14"),
+    (KEY_braille_dots_24, "braille_dots_24"),
+    (KEY_braille_dots_124, "braille_dots_124"),
+    (KEY_braille_dots_34, "braille_dots_34"),
+    (KEY_braille_dots_134, "braille_dots_134"),
+    (KEY_braille_dots_234, "braille_dots_234"),
+    (KEY_braille_dots_1234, "braille_dots_1234"),
+    (KEY_braille_dots_5, "braille_dots_5"),
+    (KEY_braille_dots_15, "braille_dots_15"),
+    (KEY_braille_dots_25, "braille_dots_25"),
+    (KEY_braille_dots_125, "braille_dots_125"),
+    (KEY_braille_dots_35, "braille_dots_35"),
+    (KEY_braille_dots_135, "braille_dots_135"),
+    (KEY_braille_dots_235, "braille_dots_235"),
+    (KEY_braille_dots_1235, "braille_dots_1235"),
+    (KEY_braille_dots_45, "braille_dots_45"),
+    (KEY_braille_dots_145, "braille_dots_145"),
+    (KEY_braille_dots_245, "braille_dots_245"),
+    (KEY_braille_dots_1245, "braille_dots_1245"),
+    (KEY_braille_dots_345, "braille_dots_345"),
+    (KEY_braille_dots_1345, "braille_dots_1345"),
+    (KEY_braille_dots_2345, "braille_dots_2345"),
+    (KEY_braille_dots_12345, "braille_dots_12345"),
+    (KEY_braille_dots_6, "braille_dots_6"),
+    (KEY_braille_dots_16, "braille_dots_16"),
+    (KEY_braille_dots_26, "braille_dots_26"),
+    (KEY_braille_dots_126, "braille_dots_126"),
+    (KEY_braille_dots_36, "braille_dots_36"),
+    (KEY_braille_dots_136, "braille_dots_136"),
+    (KEY_braille_dots_236, "braille_dots_236"),
+    (KEY_braille_dots_1236, "braille_dots_1236"),
+    (KEY_braille_dots_46, "braille_dots_46"),
+    (KEY_braille_dots_146, "braille_dots_146"),
+    (KEY_braille_dots_246, "braille_dots_246"),
+    (KEY_braille_dots_1246, "braille_dots_1246"),
+    (KEY_braille_dots_346, "braille_dots_346"),
+    (KEY_braille_dots_1346, "braille_dots_1346"),
+    (KEY_braille_dots_2346, "braille_dots_2346"),
+    (KEY_braille_dots_12346, "braille_dots_12346"),
+    (KEY_braille_dots_56, "braille_dots_56"),
+    (KEY_braille_dots_156, "braille_dots_156"),
+    (KEY_braille_dots_256, "braille_dots_256"),
+    (KEY_braille_dots_1256, "braille_dots_1256"),
+    (KEY_braille_dots_356, "braille_dots_356"),
+    (KEY_braille_dots_1356, "braille_dots_1356"),
+    (KEY_braille_dots_2356, "braille_dots_2356"),
+    (KEY_braille_dots_12356, "braille_dots_12356"),
+    (KEY_braille_dots_456, "braille_dots_456"),
+    (KEY_braille_dots_1456, "braille_dots_1456"),
+    (KEY_braille_dots_2456, "braille_dots_2456"),
+    (KEY_braille_dots_12456, "braille_dots_12456"),
+    (KEY_braille_dots_3456, "braille_dots_3456"),
+    (KEY_braille_dots_13456, "braille_dots_13456"),
+    (KEY_braille_dots_23456, "braille_dots_23456"),
+    (KEY_braille_dots_123456, "braille_dots_123456"),
+    (KEY_braille_dots_7, "braille_dots_7"),
+    (KEY_braille_dots_17, "braille_dots_17"),
+    (KEY_braille_dots_27, "braille_dots_27"),
+    (KEY_braille_dots_127, "braille_dots_127"),
+    (KEY_braille_dots_37, "braille_dots_37"),
+    (KEY_braille_dots_137, "braille_dots_137"),
+    (KEY_braille_dots_237, "braille_dots_237"),
+    (KEY_braille_dots_1237, "braille_dots_1237"),
+    (KEY_braille_dots_47, "braille_dots_47"),
+    (KEY_braille_dots_147, "braille_dots_147"),
+    (KEY_braille_dots_247, "braille_dots_247"),
+    (KEY_braille_dots_1247, "braille_dots_1247"),
+    (KEY_braille_dots_347, "braille_dots_347"),
+    (KEY_braille_dots_1347, "braille_dots_1347"),
+    (KEY_braille_dots_2347, "braille_dots_2347"),
+    (KEY_braille_dots_12347, "braille_dots_12347"),
+    (KEY_braille_dots_57, "braille_dots_57"),
+    (KEY_braille_dots_157, "braille_dots_157"),
+    (KEY_braille_dots_257, "braille_dots_257"),
+    (KEY_braille_dots_1257, "braille_dots_1257"),
+    (KEY_braille_dots_357, "braille_dots_357"),
+    (KEY_braille_dots_1357, "braille_dots_1357"),
+    (KEY_braille_dots_2357, "braille_dots_2357"),
+    (KEY_braille_dots_12357, "braille_dots_12357"),
+    (KEY_braille_dots_457, "braille_dots_457"),
+    (KEY_braille_dots_1457, "braille_dots_1457"),
+    (KEY_braille_dots_2457, "braille_dots_2457"),
+    (KEY_braille_dots_12457, "braille_dots_12457"),
+    (KEY_braille_dots_3457, "braille_dots_3457"),
+    (KEY_braille_dots_13457, "braille_dots_13457"),
+    (KEY_braille_dots_23457, "braille_dots_23457"),
+    (KEY_braille_dots_123457, "braille_dots_123457"),
+    (KEY_braille_dots_67, "braille_dots_67"),
+    (KEY_braille_dots_167, "braille_dots_167"),
+    (KEY_braille_dots_267, "braille_dots_267"),
+    (KEY_braille_dots_1267, "braille_dots_1267"),
+    (KEY_braille_dots_367, "braille_dots_367"),
+    (KEY_braille_dots_1367, "braille_dots_1367"),
+    (KEY_braille_dots_2367, "braille_dots_2367"),
+    (KEY_braille_dots_12367, "braille_dots_12367"),
+    (KEY_braille_dots_467, "braille_dots_467"),
+    (KEY_braille_dots_1467, "braille_dots_1467"),
+    (KEY_braille_dots_2467, "braille_dots_2467"),
+    (KEY_braille_dots_12467, "braille_dots_12467"),
+    (KEY_braille_dots_3467, "braille_dots_3467"),
+    (KEY_braille_dots_13467, "braille_dots_13467"),
+    (KEY_braille_dots_23467, "braille_dots_23467"),
+    (KEY_braille_dots_123467, "braille_dots_123467"),
+    (KEY_braille_dots_567, "braille_dots_567"),
+    (KEY_braille_dots_1567, "braille_dots_1567"),
+    (KEY_braille_dots_2567, "braille_dots_2567"),
+    (KEY_braille_dots_12567, "braille_dots_12567"),
+    (KEY_braille_dots_3567, "braille_dots_3567"),
+    (KEY_braille_dots_13567, "braille_dots_13567"),
+    (KEY_braille_dots_23567, "braille_dots_23567"),
+    (KEY_braille_dots_123567, "braille_dots_123567"),
+    (KEY_braille_dots_4567, "braille_dots_4567"),
+    (KEY_braille_dots_14567, "braille_dots_14567"),
+    (KEY_braille_dots_24567, "braille_dots_24567"),
+    (KEY_braille_dots_124567, "braille_dots_124567"),
+    (KEY_braille_dots_34567, "braille_dots_34567"),
+    (KEY_braille_dots_134567, "braille_dots_134567"),
+    (KEY_braille_dots_234567, "braille_dots_234567"),
+    (KEY_braille_dots_1234567, "braille_dots_1234567"),
+    (KEY_braille_dots_8, "braille_dots_8"),
+    (KEY_braille_dots_18, "braille_dots_18"),
+    (KEY_braille_dots_28, "braille_dots_28"),
+    (KEY_braille_dots_128, "braille_dots_128"),
+    (KEY_braille_dots_38, "braille_dots_38"),
+    (KEY_braille_dots_138, "braille_dots_138"),
+    (KEY_braille_dots_238, "braille_dots_238"),
+    (KEY_braille_dots_1238, "braille_dots_1238"),
+    (KEY_braille_dots_48, "braille_dots_48"),
+    (KEY_braille_dots_148, "braille_dots_148"),
+    (KEY_braille_dots_248, "braille_dots_248"),
+    (KEY_braille_dots_1248, "braille_dots_1248"),
+    (KEY_braille_dots_348, "braille_dots_348"),
+    (KEY_braille_dots_1348, "braille_dots_1348"),
+    (KEY_braille_dots_2348, "braille_dots_2348"),
+    (KEY_braille_dots_12348, "braille_dots_12348"),
+    (KEY_braille_dots_58, "braille_dots_58"),
+    (KEY_braille_dots_158, "braille_dots_158"),
+    (KEY_braille_dots_258, "braille_dots_258"),
+    (KEY_braille_dots_1258, "braille_dots_1258"),
+    (KEY_braille_dots_358, "braille_dots_358"),
+    (KEY_braille_dots_1358, "braille_dots_1358"),
+    (KEY_braille_dots_2358, "braille_dots_2358"),
+    (KEY_braille_dots_12358, "braille_dots_12358"),
+    (KEY_braille_dots_458, "braille_dots_458"),
+    (KEY_braille_dots_1458, "braille_dots_1458"),
+    (KEY_braille_dots_2458, "braille_dots_2458"),
+    (KEY_braille_dots_12458, "braille_dots_12458"),
+    (KEY_braille_dots_3458, "braille_dots_3458"),
+    (KEY_braille_dots_13458, "braille_dots_13458"),
+    (KEY_braille_dots_23458, "braille_dots_23458"),
+    (KEY_braille_dots_123458, "braille_dots_123458"),
+    (KEY_braille_dots_68, "braille_dots_68"),
+    (KEY_braille_dots_168, "braille_dots_168"),
+    (KEY_braille_dots_268, "braille_dots_268"),
+    (KEY_braille_dots_1268, "braille_dots_1268"),
+    (KEY_braille_dots_368, "braille_dots_368"),
+    (KEY_braille_dots_1368, "braille_dots_1368"),
+    (KEY_braille_dots_2368, "braille_dots_2368"),
+    (KEY_braille_dots_12368, "braille_dots_12368"),
+    (KEY_braille_dots_468, "braille_dots_468"),
+    (KEY_braille_dots_1468, "braille_dots_1468"),
+    (KEY_braille_dots_2468, "braille_dots_2468"),
+    (KEY_braille_dots_12468, "braille_dots_12468"),
+    (KEY_braille_dots_3468, "braille_dots_3468"),
+    (KEY_braille_dots_13468, "braille_dots_13468"),
+    (KEY_braille_dots_23468, "braille_dots_23468"),
+    (KEY_braille_dots_123468, "braille_dots_123468"),
+    (KEY_braille_dots_568, "braille_dots_568"),
+    (KEY_braille_dots_1568, "braille_dots_1568"),
+    (KEY_braille_dots_2568, "braille_dots_2568"),
+    (KEY_braille_dots_12568, "braille_dots_12568"),
+    (KEY_braille_dots_3568, "braille_dots_3568"),
+    (KEY_braille_dots_13568, "braille_dots_13568"),
+    (KEY_braille_dots_23568, "braille_dots_23568"),
+    (KEY_braille_dots_123568, "braille_dots_123568"),
+    (KEY_braille_dots_4568, "braille_dots_4568"),
+    (KEY_braille_dots_14568, "braille_dots_14568"),
+    (KEY_braille_dots_24568, "braille_dots_24568"),
+    (KEY_braille_dots_124568, "braille_dots_124568"),
+    (KEY_braille_dots_34568, "braille_dots_34568"),
+    (KEY_braille_dots_134568, "braille_dots_134568"),
+    (KEY_braille_dots_234568, "braille_dots_234568"),
+    (KEY_braille_dots_1234568, "braille_dots_1234568"),
+    (KEY_braille_dots_78, "braille_dots_78"),
+    (KEY_braille_dots_178, "braille_dots_178"),
+    (KEY_braille_dots_278, "braille_dots_278"),
+    (KEY_braille_dots_1278, "braille_dots_1278"),
+    (KEY_braille_dots_378, "braille_dots_378"),
+    (KEY_braille_dots_1378, "braille_dots_1378"),
+    (KEY_braille_dots_2378, "braille_dots_2378"),
+    (KEY_braille_dots_12378, "braille_dots_12378"),
+    (KEY_braille_dots_478, "braille_dots_478"),
+    (KEY_braille_dots_1478, "braille_dots_1478"),
+    (KEY_braille_dots_2478, "braille_dots_2478"),
+    (KEY_braille_dots_12478, "braille_dots_12478"),
+    (KEY_braille_dots_3478, "braille_dots_3478"),
+    (KEY_braille_dots_13478, "braille_dots_13478"),
+    (KEY_braille_dots_23478, "braille_dots_23478"),
+    (KEY_braille_dots_123478, "braille_dots_123478"),
+    (KEY_braille_dots_578, "braille_dots_578"),
+    (KEY_braille_dots_1578, "braille_dots_1578"),
+    (KEY_braille_dots_2578, "braille_dots_2578"),
+    (KEY_braille_dots_12578, "braille_dots_12578"),
+    (KEY_braille_dots_3578, "braille_dots_3578"),
+    (KEY_braille_dots_13578, "braille_dots_13578"),
+    (KEY_braille_dots_23578, "braille_dots_23578"),
+    (KEY_braille_dots_123578, "braille_dots_123578"),
+    (KEY_braille_dots_4578, "braille_dots_4578"),
+    (KEY_braille_dots_14578, "braille_dots_14578"),
+    (KEY_braille_dots_24578, "braille_dots_24578"),
+    (KEY_braille_dots_124578, "braille_dots_124578"),
+    (KEY_braille_dots_34578, "braille_dots_34578"),
+    (KEY_braille_dots_134578, "braille_dots_134578"),
+    (KEY_braille_dots_234578, "braille_dots_234578"),
+    (KEY_braille_dots_1234578, "braille_dots_1234578"),
+    (KEY_braille_dots_678, "braille_dots_678"),
+    (KEY_braille_dots_1678, "braille_dots_1678"),
+    (KEY_braille_dots_2678, "braille_dots_2678"),
+    (KEY_braille_dots_12678, "braille_dots_12678"),
+    (KEY_braille_dots_3678, "braille_dots_3678"),
+    (KEY_braille_dots_13678, "braille_dots_13678"),
+    (KEY_braille_dots_23678, "braille_dots_23678"),
+    (KEY_braille_dots_123678, "braille_dots_123678"),
+    (KEY_braille_dots_4678, "braille_dots_4678"),
+    (KEY_braille_dots_14678, "braille_dots_14678"),
+    (KEY_braille_dots_24678, "braille_dots_24678"),
+    (KEY_braille_dots_124678, "braille_dots_124678"),
+    (KEY_braille_dots_34678, "braille_dots_34678"),
+    (KEY_braille_dots_134678, "braille_dots_134678"),
+    (KEY_braille_dots_234678, "braille_dots_234678"),
+    (KEY_braille_dots_1234678, "braille_dots_1234678"),
+    (KEY_braille_dots_5678, "braille_dots_5678"),
+    (KEY_braille_dots_15678, "braille_dots_15678"),
+    (KEY_braille_dots_25678, "braille_dots_25678"),
+    (KEY_braille_dots_125678, "braille_dots_125678"),
+    (KEY_braille_dots_35678, "braille_dots_35678"),
+    (KEY_braille_dots_135678, "braille_dots_135678"),
+    (KEY_braille_dots_235678, "braille_dots_235678"),
+    (KEY_braille_dots_1235678, "braille_dots_1235678"),
+    (KEY_braille_dots_45678, "braille_dots_45678"),
+    (KEY_braille_dots_145678, "braille_dots_145678"),
+    (KEY_braille_dots_245678, "braille_dots_245678"),
+    (KEY_braille_dots_1245678, "braille_dots_1245678"),
+    (KEY_braille_dots_345678, "braille_dots_345678"),
+    (KEY_braille_dots_1345678, "braille_dots_1345678"),
+    (KEY_braille_dots_2345678, "braille_dots_2345678"),
+    (KEY_braille_dots_12345678, "braille_dots_12345678"),
+];
+
+/// Sorted by keysym, the characters that keysyms outside of the Unicode
+/// keysym range (`0x01000000` + code point) correspond to
+pub(crate) const TO_UNICODE: &[(u32, char)] = &[
+    (0x20, '\u{20}'),
+    (0x21, '\u{21}'),
+    (0x22, '\u{22}'),
+    (0x23, '\u{23}'),
+    (0x24, '\u{24}'),
+    (0x25, '\u{25}'),
+    (0x26, '\u{26}'),
+    (0x27, '\u{27}'),
+    (0x28, '\u{28}'),
+    (0x29, '\u{29}'),
+    (0x2a, '\u{2a}'),
+    (0x2b, '\u{2b}'),
+    (0x2c, '\u{2c}'),
+    (0x2d, '\u{2d}'),
+    (0x2e, '\u{2e}'),
+    (0x2f, '\u{2f}'),
+    (0x30, '\u{30}'),
+    (0x31, '\u{31}'),
+    (0x32, '\u{32}'),
+    (0x33, '\u{33}'),
+    (0x34, '\u{34}'),
+    (0x35, '\u{35}'),
+    (0x36, '\u{36}'),
+    (0x37, '\u{37}'),
+    (0x38, '\u{38}'),
+    (0x39, '\u{39}'),
+    (0x3a, '\u{3a}'),
+    (0x3b, '\u{3b}'),
+    (0x3c, '\u{3c}'),
+    (0x3d, '\u{3d}'),
+    (0x3e, '\u{3e}'),
+    (0x3f, '\u{3f}'),
+    (0x40, '\u{40}'),
+    (0x41, '\u{41}'),
+    (0x42, '\u{42}'),
+    (0x43, '\u{43}'),
+    (0x44, '\u{44}'),
+    (0x45, '\u{45}'),
+    (0x46, '\u{46}'),
+    (0x47, '\u{47}'),
+    (0x48, '\u{48}'),
+    (0x49, '\u{49}'),
+    (0x4a, '\u{4a}'),
+    (0x4b, '\u{4b}'),
+    (0x4c, '\u{4c}'),
+    (0x4d, '\u{4d}'),
+    (0x4e, '\u{4e}'),
+    (0x4f, '\u{4f}'),
+    (0x50, '\u{50}'),
+    (0x51, '\u{51}'),
+    (0x52, '\u{52}'),
+    (0x53, '\u{53}'),
+    (0x54, '\u{54}'),
+    (0x55, '\u{55}'),
+    (0x56, '\u{56}'),
+    (0x57, '\u{57}'),
+    (0x58, '\u{58}'),
+    (0x59, '\u{59}'),
+    (0x5a, '\u{5a}'),
+    (0x5b, '\u{5b}'),
+    (0x5c, '\u{5c}'),
+    (0x5d, '\u{5d}'),
+    (0x5e, '\u{5e}'),
+    (0x5f, '\u{5f}'),
+    (0x60, '\u{60}'),
+    (0x61, '\u{61}'),
+    (0x62, '\u{62}'),
+    (0x63, '\u{63}'),
+    (0x64, '\u{64}'),
+    (0x65, '\u{65}'),
+    (0x66, '\u{66}'),
+    (0x67, '\u{67}'),
+    (0x68, '\u{68}'),
+    (0x69, '\u{69}'),
+    (0x6a, '\u{6a}'),
+    (0x6b, '\u{6b}'),
+    (0x6c, '\u{6c}'),
+    (0x6d, '\u{6d}'),
+    (0x6e, '\u{6e}'),
+    (0x6f, '\u{6f}'),
+    (0x70, '\u{70}'),
+    (0x71, '\u{71}'),
+    (0x72, '\u{72}'),
+    (0x73, '\u{73}'),
+    (0x74, '\u{74}'),
+    (0x75, '\u{75}'),
+    (0x76, '\u{76}'),
+    (0x77, '\u{77}'),
+    (0x78, '\u{78}'),
+    (0x79, '\u{79}'),
+    (0x7a, '\u{7a}'),
+    (0x7b, '\u{7b}'),
+    (0x7c, '\u{7c}'),
+    (0x7d, '\u{7d}'),
+    (0x7e, '\u{7e}'),
+    (0xa0, '\u{a0}'),
+    (0xa1, '\u{a1}'),
+    (0xa2, '\u{a2}'),
+    (0xa3, '\u{a3}'),
+    (0xa4, '\u{a4}'),
+    (0xa5, '\u{a5}'),
+    (0xa6, '\u{a6}'),
+    (0xa7, '\u{a7}'),
+    (0xa8, '\u{a8}'),
+    (0xa9, '\u{a9}'),
+    (0xaa, '\u{aa}'),
+    (0xab, '\u{ab}'),
+    (0xac, '\u{ac}'),
+    (0xad, '\u{ad}'),
+    (0xae, '\u{ae}'),
+    (0xaf, '\u{af}'),
+    (0xb0, '\u{b0}'),
+    (0xb1, '\u{b1}'),
+    (0xb2, '\u{b2}'),
+    (0xb3, '\u{b3}'),
+    (0xb4, '\u{b4}'),
+    (0xb5, '\u{b5}'),
+    (0xb6, '\u{b6}'),
+    (0xb7, '\u{b7}'),
+    (0xb8, '\u{b8}'),
+    (0xb9, '\u{b9}'),
+    (0xba, '\u{ba}'),
+    (0xbb, '\u{bb}'),
+    (0xbc, '\u{bc}'),
+    (0xbd, '\u{bd}'),
+    (0xbe, '\u{be}'),
+    (0xbf, '\u{bf}'),
+    (0xc0, '\u{c0}'),
+    (0xc1, '\u{c1}'),
+    (0xc2, '\u{c2}'),
+    (0xc3, '\u{c3}'),
+    (0xc4, '\u{c4}'),
+    (0xc5, '\u{c5}'),
+    (0xc6, '\u{c6}'),
+    (0xc7, '\u{c7}'),
+    (0xc8, '\u{c8}'),
+    (0xc9, '\u{c9}'),
+    (0xca, '\u{ca}'),
+    (0xcb, '\u{cb}'),
+    (0xcc, '\u{cc}'),
+    (0xcd, '\u{cd}'),
+    (0xce, '\u{ce}'),
+    (0xcf, '\u{cf}'),
+    (0xd0, '\u{d0}'),
+    (0xd1, '\u{d1}'),
+    (0xd2, '\u{d2}'),
+    (0xd3, '\u{d3}'),
+    (0xd4, '\u{d4}'),
+    (0xd5, '\u{d5}'),
+    (0xd6, '\u{d6}'),
+    (0xd7, '\u{d7}'),
+    (0xd8, '\u{d8}'),
+    (0xd9, '\u{d9}'),
+    (0xda, '\u{da}'),
+    (0xdb, '\u{db}'),
+    (0xdc, '\u{dc}'),
+    (0xdd, '\u{dd}'),
+    (0xde, '\u{de}'),
+    (0xdf, '\u{df}'),
+    (0xe0, '\u{e0}'),
+    (0xe1, '\u{e1}'),
+    (0xe2, '\u{e2}'),
+    (0xe3, '\u{e3}'),
+    (0xe4, '\u{e4}'),
+    (0xe5, '\u{e5}'),
+    (0xe6, '\u{e6}'),
+    (0xe7, '\u{e7}'),
+    (0xe8, '\u{e8}'),
+    (0xe9, '\u{e9}'),
+    (0xea, '\u{ea}'),
+    (0xeb, '\u{eb}'),
+    (0xec, '\u{ec}'),
+    (0xed, '\u{ed}'),
+    (0xee, '\u{ee}'),
+    (0xef, '\u{ef}'),
+    (0xf0, '\u{f0}'),
+    (0xf1, '\u{f1}'),
+    (0xf2, '\u{f2}'),
+    (0xf3, '\u{f3}'),
+    (0xf4, '\u{f4}'),
+    (0xf5, '\u{f5}'),
+    (0xf6, '\u{f6}'),
+    (0xf7, '\u{f7}'),
+    (0xf8, '\u{f8}'),
+    (0xf9, '\u{f9}'),
+    (0xfa, '\u{fa}'),
+    (0xfb, '\u{fb}'),
+    (0xfc, '\u{fc}'),
+    (0xfd, '\u{fd}'),
+    (0xfe, '\u{fe}'),
+    (0xff, '\u{ff}'),
+    (0x1a1, '\u{104}'),
+    (0x1a2, '\u{2d8}'),
+    (0x1a3, '\u{141}'),
+    (0x1a5, '\u{13d}'),
+    (0x1a6, '\u{15a}'),
+    (0x1a9, '\u{160}'),
+    (0x1aa, '\u{15e}'),
+    (0x1ab, '\u{164}'),
+    (0x1ac, '\u{179}'),
+    (0x1ae, '\u{17d}'),
+    (0x1af, '\u{17b}'),
+    (0x1b1, '\u{105}'),
+    (0x1b2, '\u{2db}'),
+    (0x1b3, '\u{142}'),
+    (0x1b5, '\u{13e}'),
+    (0x1b6, '\u{15b}'),
+    (0x1b7, '\u{2c7}'),
+    (0x1b9, '\u{161}'),
+    (0x1ba, '\u{15f}'),
+    (0x1bb, '\u{165}'),
+    (0x1bc, '\u{17a}'),
+    (0x1bd, '\u{2dd}'),
+    (0x1be, '\u{17e}'),
+    (0x1bf, '\u{17c}'),
+    (0x1c0, '\u{154}'),
+    (0x1c3, '\u{102}'),
+    (0x1c5, '\u{139}'),
+    (0x1c6, '\u{106}'),
+    (0x1c8, '\u{10c}'),
+    (0x1ca, '\u{118}'),
+    (0x1cc, '\u{11a}'),
+    (0x1cf, '\u{10e}'),
+    (0x1d0, '\u{110}'),
+    (0x1d1, '\u{143}'),
+    (0x1d2, '\u{147}'),
+    (0x1d5, '\u{150}'),
+    (0x1d8, '\u{158}'),
+    (0x1d9, '\u{16e}'),
+    (0x1db, '\u{170}'),
+    (0x1de, '\u{162}'),
+    (0x1e0, '\u{155}'),
+    (0x1e3, '\u{103}'),
+    (0x1e5, '\u{13a}'),
+    (0x1e6, '\u{107}'),
+    (0x1e8, '\u{10d}'),
+    (0x1ea, '\u{119}'),
+    (0x1ec, '\u{11b}'),
+    (0x1ef, '\u{10f}'),
+    (0x1f0, '\u{111}'),
+    (0x1f1, '\u{144}'),
+    (0x1f2, '\u{148}'),
+    (0x1f5, '\u{151}'),
+    (0x1f8, '\u{159}'),
+    (0x1f9, '\u{16f}'),
+    (0x1fb, '\u{171}'),
+    (0x1fe, '\u{163}'),
+    (0x1ff, '\u{2d9}'),
+    (0x2a1, '\u{126}'),
+    (0x2a6, '\u{124}'),
+    (0x2a9, '\u{130}'),
+    (0x2ab, '\u{11e}'),
+    (0x2ac, '\u{134}'),
+    (0x2b1, '\u{127}'),
+    (0x2b6, '\u{125}'),
+    (0x2b9, '\u{131}'),
+    (0x2bb, '\u{11f}'),
+    (0x2bc, '\u{135}'),
+    (0x2c5, '\u{10a}'),
+    (0x2c6, '\u{108}'),
+    (0x2d5, '\u{120}'),
+    (0x2d8, '\u{11c}'),
+    (0x2dd, '\u{16c}'),
+    (0x2de, '\u{15c}'),
+    (0x2e5, '\u{10b}'),
+    (0x2e6, '\u{109}'),
+    (0x2f5, '\u{121}'),
+    (0x2f8, '\u{11d}'),
+    (0x2fd, '\u{16d}'),
+    (0x2fe, '\u{15d}'),
+    (0x3a2, '\u{138}'),
+    (0x3a3, '\u{156}'),
+    (0x3a5, '\u{128}'),
+    (0x3a6, '\u{13b}'),
+    (0x3aa, '\u{112}'),
+    (0x3ab, '\u{122}'),
+    (0x3ac, '\u{166}'),
+    (0x3b3, '\u{157}'),
+    (0x3b5, '\u{129}'),
+    (0x3b6, '\u{13c}'),
+    (0x3ba, '\u{113}'),
+    (0x3bb, '\u{123}'),
+    (0x3bc, '\u{167}'),
+    (0x3bd, '\u{14a}'),
+    (0x3bf, '\u{14b}'),
+    (0x3c0, '\u{100}'),
+    (0x3c7, '\u{12e}'),
+    (0x3cc, '\u{116}'),
+    (0x3cf, '\u{12a}'),
+    (0x3d1, '\u{145}'),
+    (0x3d2, '\u{14c}'),
+    (0x3d3, '\u{136}'),
+    (0x3d9, '\u{172}'),
+    (0x3dd, '\u{168}'),
+    (0x3de, '\u{16a}'),
+    (0x3e0, '\u{101}'),
+    (0x3e7, '\u{12f}'),
+    (0x3ec, '\u{117}'),
+    (0x3ef, '\u{12b}'),
+    (0x3f1, '\u{146}'),
+    (0x3f2, '\u{14d}'),
+    (0x3f3, '\u{137}'),
+    (0x3f9, '\u{173}'),
+    (0x3fd, '\u{169}'),
+    (0x3fe, '\u{16b}'),
+    (0x47e, '\u{203e}'),
+    (0x4a1, '\u{3002}'),
+    (0x4a2, '\u{300c}'),
+    (0x4a3, '\u{300d}'),
+    (0x4a4, '\u{3001}'),
+    (0x4a5, '\u{30fb}'),
+    (0x4a6, '\u{30f2}'),
+    (0x4a7, '\u{30a1}'),
+    (0x4a8, '\u{30a3}'),
+    (0x4a9, '\u{30a5}'),
+    (0x4aa, '\u{30a7}'),
+    (0x4ab, '\u{30a9}'),
+    (0x4ac, '\u{30e3}'),
+    (0x4ad, '\u{30e5}'),
+    (0x4ae, '\u{30e7}'),
+    (0x4af, '\u{30c3}'),
+    (0x4b0, '\u{30fc}'),
+    (0x4b1, '\u{30a2}'),
+    (0x4b2, '\u{30a4}'),
+    (0x4b3, '\u{30a6}'),
+    (0x4b4, '\u{30a8}'),
+    (0x4b5, '\u{30aa}'),
+    (0x4b6, '\u{30ab}'),
+    (0x4b7, '\u{30ad}'),
+    (0x4b8, '\u{30af}'),
+    (0x4b9, '\u{30b1}'),
+    (0x4ba, '\u{30b3}'),
+    (0x4bb, '\u{30b5}'),
+    (0x4bc, '\u{30b7}'),
+    (0x4bd, '\u{30b9}'),
+    (0x4be, '\u{30bb}'),
+    (0x4bf, '\u{30bd}'),
+    (0x4c0, '\u{30bf}'),
+    (0x4c1, '\u{30c1}'),
+    (0x4c2, '\u{30c4}'),
+    (0x4c3, '\u{30c6}'),
+    (0x4c4, '\u{30c8}'),
+    (0x4c5, '\u{30ca}'),
+    (0x4c6, '\u{30cb}'),
+    (0x4c7, '\u{30cc}'),
+    (0x4c8, '\u{30cd}'),
+    (0x4c9, '\u{30ce}'),
+    (0x4ca, '\u{30cf}'),
+    (0x4cb, '\u{30d2}'),
+    (0x4cc, '\u{30d5}'),
+    (0x4cd, '\u{30d8}'),
+    (0x4ce, '\u{30db}'),
+    (0x4cf, '\u{30de}'),
+    (0x4d0, '\u{30df}'),
+    (0x4d1, '\u{30e0}'),
+    (0x4d2, '\u{30e1}'),
+    (0x4d3, '\u{30e2}'),
+    (0x4d4, '\u{30e4}'),
+    (0x4d5, '\u{30e6}'),
+    (0x4d6, '\u{30e8}'),
+    (0x4d7, '\u{30e9}'),
+    (0x4d8, '\u{30ea}'),
+    (0x4d9, '\u{30eb}'),
+    (0x4da, '\u{30ec}'),
+    (0x4db, '\u{30ed}'),
+    (0x4dc, '\u{30ef}'),
+    (0x4dd, '\u{30f3}'),
+    (0x4de, '\u{309b}'),
+    (0x4df, '\u{309c}'),
+    (0x5ac, '\u{60c}'),
+    (0x5bb, '\u{61b}'),
+    (0x5bf, '\u{61f}'),
+    (0x5c1, '\u{621}'),
+    (0x5c2, '\u{622}'),
+    (0x5c3, '\u{623}'),
+    (0x5c4, '\u{624}'),
+    (0x5c5, '\u{625}'),
+    (0x5c6, '\u{626}'),
+    (0x5c7, '\u{627}'),
+    (0x5c8, '\u{628}'),
+    (0x5c9, '\u{629}'),
+    (0x5ca, '\u{62a}'),
+    (0x5cb, '\u{62b}'),
+    (0x5cc, '\u{62c}'),
+    (0x5cd, '\u{62d}'),
+    (0x5ce, '\u{62e}'),
+    (0x5cf, '\u{62f}'),
+    (0x5d0, '\u{630}'),
+    (0x5d1, '\u{631}'),
+    (0x5d2, '\u{632}'),
+    (0x5d3, '\u{633}'),
+    (0x5d4, '\u{634}'),
+    (0x5d5, '\u{635}'),
+    (0x5d6, '\u{636}'),
+    (0x5d7, '\u{637}'),
+    (0x5d8, '\u{638}'),
+    (0x5d9, '\u{639}'),
+    (0x5da, '\u{63a}'),
+    (0x5e0, '\u{640}'),
+    (0x5e1, '\u{641}'),
+    (0x5e2, '\u{642}'),
+    (0x5e3, '\u{643}'),
+    (0x5e4, '\u{644}'),
+    (0x5e5, '\u{645}'),
+    (0x5e6, '\u{646}'),
+    (0x5e7, '\u{647}'),
+    (0x5e8, '\u{648}'),
+    (0x5e9, '\u{649}'),
+    (0x5ea, '\u{64a}'),
+    (0x5eb, '\u{64b}'),
+    (0x5ec, '\u{64c}'),
+    (0x5ed, '\u{64d}'),
+    (0x5ee, '\u{64e}'),
+    (0x5ef, '\u{64f}'),
+    (0x5f0, '\u{650}'),
+    (0x5f1, '\u{651}'),
+    (0x5f2, '\u{652}'),
+    (0x6a1, '\u{452}'),
+    (0x6a2, '\u{453}'),
+    (0x6a3, '\u{451}'),
+    (0x6a4, '\u{454}'),
+    (0x6a5, '\u{455}'),
+    (0x6a6, '\u{456}'),
+    (0x6a7, '\u{457}'),
+    (0x6a8, '\u{458}'),
+    (0x6a9, '\u{459}'),
+    (0x6aa, '\u{45a}'),
+    (0x6ab, '\u{45b}'),
+    (0x6ac, '\u{45c}'),
+    (0x6ad, '\u{491}'),
+    (0x6ae, '\u{45e}'),
+    (0x6af, '\u{45f}'),
+    (0x6b0, '\u{2116}'),
+    (0x6b1, '\u{402}'),
+    (0x6b2, '\u{403}'),
+    (0x6b3, '\u{401}'),
+    (0x6b4, '\u{404}'),
+    (0x6b5, '\u{405}'),
+    (0x6b6, '\u{406}'),
+    (0x6b7, '\u{407}'),
+    (0x6b8, '\u{408}'),
+    (0x6b9, '\u{409}'),
+    (0x6ba, '\u{40a}'),
+    (0x6bb, '\u{40b}'),
+    (0x6bc, '\u{40c}'),
+    (0x6bd, '\u{490}'),
+    (0x6be, '\u{40e}'),
+    (0x6bf, '\u{40f}'),
+    (0x6c0, '\u{44e}'),
+    (0x6c1, '\u{430}'),
+    (0x6c2, '\u{431}'),
+    (0x6c3, '\u{446}'),
+    (0x6c4, '\u{434}'),
+    (0x6c5, '\u{435}'),
+    (0x6c6, '\u{444}'),
+    (0x6c7, '\u{433}'),
+    (0x6c8, '\u{445}'),
+    (0x6c9, '\u{438}'),
+    (0x6ca, '\u{439}'),
+    (0x6cb, '\u{43a}'),
+    (0x6cc, '\u{43b}'),
+    (0x6cd, '\u{43c}'),
+    (0x6ce, '\u{43d}'),
+    (0x6cf, '\u{43e}'),
+    (0x6d0, '\u{43f}'),
+    (0x6d1, '\u{44f}'),
+    (0x6d2, '\u{440}'),
+    (0x6d3, '\u{441}'),
+    (0x6d4, '\u{442}'),
+    (0x6d5, '\u{443}'),
+    (0x6d6, '\u{436}'),
+    (0x6d7, '\u{432}'),
+    (0x6d8, '\u{44c}'),
+    (0x6d9, '\u{44b}'),
+    (0x6da, '\u{437}'),
+    (0x6db, '\u{448}'),
+    (0x6dc, '\u{44d}'),
+    (0x6dd, '\u{449}'),
+    (0x6de, '\u{447}'),
+    (0x6df, '\u{44a}'),
+    (0x6e0, '\u{42e}'),
+    (0x6e1, '\u{410}'),
+    (0x6e2, '\u{411}'),
+    (0x6e3, '\u{426}'),
+    (0x6e4, '\u{414}'),
+    (0x6e5, '\u{415}'),
+    (0x6e6, '\u{424}'),
+    (0x6e7, '\u{413}'),
+    (0x6e8, '\u{425}'),
+    (0x6e9, '\u{418}'),
+    (0x6ea, '\u{419}'),
+    (0x6eb, '\u{41a}'),
+    (0x6ec, '\u{41b}'),
+    (0x6ed, '\u{41c}'),
+    (0x6ee, '\u{41d}'),
+    (0x6ef, '\u{41e}'),
+    (0x6f0, '\u{41f}'),
+    (0x6f1, '\u{42f}'),
+    (0x6f2, '\u{420}'),
+    (0x6f3, '\u{421}'),
+    (0x6f4, '\u{422}'),
+    (0x6f5, '\u{423}'),
+    (0x6f6, '\u{416}'),
+    (0x6f7, '\u{412}'),
+    (0x6f8, '\u{42c}'),
+    (0x6f9, '\u{42b}'),
+    (0x6fa, '\u{417}'),
+    (0x6fb, '\u{428}'),
+    (0x6fc, '\u{42d}'),
+    (0x6fd, '\u{429}'),
+    (0x6fe, '\u{427}'),
+    (0x6ff, '\u{42a}'),
+    (0x7a1, '\u{386}'),
+    (0x7a2, '\u{388}'),
+    (0x7a3, '\u{389}'),
+    (0x7a4, '\u{38a}'),
+    (0x7a5, '\u{3aa}'),
+    (0x7a7, '\u{38c}'),
+    (0x7a8, '\u{38e}'),
+    (0x7a9, '\u{3ab}'),
+    (0x7ab, '\u{38f}'),
+    (0x7ae, '\u{385}'),
+    (0x7af, '\u{2015}'),
+    (0x7b1, '\u{3ac}'),
+    (0x7b2, '\u{3ad}'),
+    (0x7b3, '\u{3ae}'),
+    (0x7b4, '\u{3af}'),
+    (0x7b5, '\u{3ca}'),
+    (0x7b6, '\u{390}'),
+    (0x7b7, '\u{3cc}'),
+    (0x7b8, '\u{3cd}'),
+    (0x7b9, '\u{3cb}'),
+    (0x7ba, '\u{3b0}'),
+    (0x7bb, '\u{3ce}'),
+    (0x7c1, '\u{391}'),
+    (0x7c2, '\u{392}'),
+    (0x7c3, '\u{393}'),
+    (0x7c4, '\u{394}'),
+    (0x7c5, '\u{395}'),
+    (0x7c6, '\u{396}'),
+    (0x7c7, '\u{397}'),
+    (0x7c8, '\u{398}'),
+    (0x7c9, '\u{399}'),
+    (0x7ca, '\u{39a}'),
+    (0x7cb, '\u{39b}'),
+    (0x7cc, '\u{39c}'),
+    (0x7cd, '\u{39d}'),
+    (0x7ce, '\u{39e}'),
+    (0x7cf, '\u{39f}'),
+    (0x7d0, '\u{3a0}'),
+    (0x7d1, '\u{3a1}'),
+    (0x7d2, '\u{3a3}'),
+    (0x7d4, '\u{3a4}'),
+    (0x7d5, '\u{3a5}'),
+    (0x7d6, '\u{3a6}'),
+    (0x7d7, '\u{3a7}'),
+    (0x7d8, '\u{3a8}'),
+    (0x7d9, '\u{3a9}'),
+    (0x7e1, '\u{3b1}'),
+    (0x7e2, '\u{3b2}'),
+    (0x7e3, '\u{3b3}'),
+    (0x7e4, '\u{3b4}'),
+    (0x7e5, '\u{3b5}'),
+    (0x7e6, '\u{3b6}'),
+    (0x7e7, '\u{3b7}'),
+    (0x7e8, '\u{3b8}'),
+    (0x7e9, '\u{3b9}'),
+    (0x7ea, '\u{3ba}'),
+    (0x7eb, '\u{3bb}'),
+    (0x7ec, '\u{3bc}'),
+    (0x7ed, '\u{3bd}'),
+    (0x7ee, '\u{3be}'),
+    (0x7ef, '\u{3bf}'),
+    (0x7f0, '\u{3c0}'),
+    (0x7f1, '\u{3c1}'),
+    (0x7f2, '\u{3c3}'),
+    (0x7f3, '\u{3c2}'),
+    (0x7f4, '\u{3c4}'),
+    (0x7f5, '\u{3c5}'),
+    (0x7f6, '\u{3c6}'),
+    (0x7f7, '\u{3c7}'),
+    (0x7f8, '\u{3c8}'),
+    (0x7f9, '\u{3c9}'),
+    (0x8a1, '\u{23b7}'),
+    (0x8a4, '\u{2320}'),
+    (0x8a5, '\u{2321}'),
+    (0x8a7, '\u{23a1}'),
+    (0x8a8, '\u{23a3}'),
+    (0x8a9, '\u{23a4}'),
+    (0x8aa, '\u{23a6}'),
+    (0x8ab, '\u{239b}'),
+    (0x8ac, '\u{239d}'),
+    (0x8ad, '\u{239e}'),
+    (0x8ae, '\u{23a0}'),
+    (0x8af, '\u{23a8}'),
+    (0x8b0, '\u{23ac}'),
+    (0x8bc, '\u{2264}'),
+    (0x8bd, '\u{2260}'),
+    (0x8be, '\u{2265}'),
+    (0x8bf, '\u{222b}'),
+    (0x8c0, '\u{2234}'),
+    (0x8c1, '\u{221d}'),
+    (0x8c2, '\u{221e}'),
+    (0x8c5, '\u{2207}'),
+    (0x8c8, '\u{223c}'),
+    (0x8c9, '\u{2243}'),
+    (0x8cd, '\u{21d4}'),
+    (0x8ce, '\u{21d2}'),
+    (0x8cf, '\u{2261}'),
+    (0x8d6, '\u{221a}'),
+    (0x8da, '\u{2282}'),
+    (0x8db, '\u{2283}'),
+    (0x8dc, '\u{2229}'),
+    (0x8dd, '\u{222a}'),
+    (0x8de, '\u{2227}'),
+    (0x8df, '\u{2228}'),
+    (0x8ef, '\u{2202}'),
+    (0x8f6, '\u{192}'),
+    (0x8fb, '\u{2190}'),
+    (0x8fc, '\u{2191}'),
+    (0x8fd, '\u{2192}'),
+    (0x8fe, '\u{2193}'),
+    (0x9e0, '\u{25c6}'),
+    (0x9e1, '\u{2592}'),
+    (0x9e2, '\u{2409}'),
+    (0x9e3, '\u{240c}'),
+    (0x9e4, '\u{240d}'),
+    (0x9e5, '\u{240a}'),
+    (0x9e8, '\u{2424}'),
+    (0x9e9, '\u{240b}'),
+    (0x9ea, '\u{2518}'),
+    (0x9eb, '\u{2510}'),
+    (0x9ec, '\u{250c}'),
+    (0x9ed, '\u{2514}'),
+    (0x9ee, '\u{253c}'),
+    (0x9ef, '\u{23ba}'),
+    (0x9f0, '\u{23bb}'),
+    (0x9f1, '\u{2500}'),
+    (0x9f2, '\u{23bc}'),
+    (0x9f3, '\u{23bd}'),
+    (0x9f4, '\u{251c}'),
+    (0x9f5, '\u{2524}'),
+    (0x9f6, '\u{2534}'),
+    (0x9f7, '\u{252c}'),
+    (0x9f8, '\u{2502}'),
+    (0xaa1, '\u{2003}'),
+    (0xaa2, '\u{2002}'),
+    (0xaa3, '\u{2004}'),
+    (0xaa4, '\u{2005}'),
+    (0xaa5, '\u{2007}'),
+    (0xaa6, '\u{2008}'),
+    (0xaa7, '\u{2009}'),
+    (0xaa8, '\u{200a}'),
+    (0xaa9, '\u{2014}'),
+    (0xaaa, '\u{2013}'),
+    (0xaae, '\u{2026}'),
+    (0xaaf, '\u{2025}'),
+    (0xab0, '\u{2153}'),
+    (0xab1, '\u{2154}'),
+    (0xab2, '\u{2155}'),
+    (0xab3, '\u{2156}'),
+    (0xab4, '\u{2157}'),
+    (0xab5, '\u{2158}'),
+    (0xab6, '\u{2159}'),
+    (0xab7, '\u{215a}'),
+    (0xab8, '\u{2105}'),
+    (0xabb, '\u{2012}'),
+    (0xac3, '\u{215b}'),
+    (0xac4, '\u{215c}'),
+    (0xac5, '\u{215d}'),
+    (0xac6, '\u{215e}'),
+    (0xac9, '\u{2122}'),
+    (0xad0, '\u{2018}'),
+    (0xad1, '\u{2019}'),
+    (0xad2, '\u{201c}'),
+    (0xad3, '\u{201d}'),
+    (0xad4, '\u{211e}'),
+    (0xad5, '\u{2030}'),
+    (0xad6, '\u{2032}'),
+    (0xad7, '\u{2033}'),
+    (0xad9, '\u{271d}'),
+    (0xaec, '\u{2663}'),
+    (0xaed, '\u{2666}'),
+    (0xaee, '\u{2665}'),
+    (0xaf0, '\u{2720}'),
+    (0xaf1, '\u{2020}'),
+    (0xaf2, '\u{2021}'),
+    (0xaf3, '\u{2713}'),
+    (0xaf4, '\u{2717}'),
+    (0xaf5, '\u{266f}'),
+    (0xaf6, '\u{266d}'),
+    (0xaf7, '\u{2642}'),
+    (0xaf8, '\u{2640}'),
+    (0xaf9, '\u{260e}'),
+    (0xafa, '\u{2315}'),
+    (0xafb, '\u{2117}'),
+    (0xafc, '\u{2038}'),
+    (0xafd, '\u{201a}'),
+    (0xafe, '\u{201e}'),
+    (0xbc2, '\u{22a4}'),
+    (0xbc4, '\u{230a}'),
+    (0xbca, '\u{2218}'),
+    (0xbcc, '\u{2395}'),
+    (0xbce, '\u{22a5}'),
+    (0xbcf, '\u{25cb}'),
+    (0xbd3, '\u{2308}'),
+    (0xbdc, '\u{22a3}'),
+    (0xbfc, '\u{22a2}'),
+    (0xcdf, '\u{2017}'),
+    (0xce0, '\u{5d0}'),
+    (0xce1, '\u{5d1}'),
+    (0xce2, '\u{5d2}'),
+    (0xce3, '\u{5d3}'),
+    (0xce4, '\u{5d4}'),
+    (0xce5, '\u{5d5}'),
+    (0xce6, '\u{5d6}'),
+    (0xce7, '\u{5d7}'),
+    (0xce8, '\u{5d8}'),
+    (0xce9, '\u{5d9}'),
+    (0xcea, '\u{5da}'),
+    (0xceb, '\u{5db}'),
+    (0xcec, '\u{5dc}'),
+    (0xced, '\u{5dd}'),
+    (0xcee, '\u{5de}'),
+    (0xcef, '\u{5df}'),
+    (0xcf0, '\u{5e0}'),
+    (0xcf1, '\u{5e1}'),
+    (0xcf2, '\u{5e2}'),
+    (0xcf3, '\u{5e3}'),
+    (0xcf4, '\u{5e4}'),
+    (0xcf5, '\u{5e5}'),
+    (0xcf6, '\u{5e6}'),
+    (0xcf7, '\u{5e7}'),
+    (0xcf8, '\u{5e8}'),
+    (0xcf9, '\u{5e9}'),
+    (0xcfa, '\u{5ea}'),
+    (0xda1, '\u{e01}'),
+    (0xda2, '\u{e02}'),
+    (0xda3, '\u{e03}'),
+    (0xda4, '\u{e04}'),
+    (0xda5, '\u{e05}'),
+    (0xda6, '\u{e06}'),
+    (0xda7, '\u{e07}'),
+    (0xda8, '\u{e08}'),
+    (0xda9, '\u{e09}'),
+    (0xdaa, '\u{e0a}'),
+    (0xdab, '\u{e0b}'),
+    (0xdac, '\u{e0c}'),
+    (0xdad, '\u{e0d}'),
+    (0xdae, '\u{e0e}'),
+    (0xdaf, '\u{e0f}'),
+    (0xdb0, '\u{e10}'),
+    (0xdb1, '\u{e11}'),
+    (0xdb2, '\u{e12}'),
+    (0xdb3, '\u{e13}'),
+    (0xdb4, '\u{e14}'),
+    (0xdb5, '\u{e15}'),
+    (0xdb6, '\u{e16}'),
+    (0xdb7, '\u{e17}'),
+    (0xdb8, '\u{e18}'),
+    (0xdb9, '\u{e19}'),
+    (0xdba, '\u{e1a}'),
+    (0xdbb, '\u{e1b}'),
+    (0xdbc, '\u{e1c}'),
+    (0xdbd, '\u{e1d}'),
+    (0xdbe, '\u{e1e}'),
+    (0xdbf, '\u{e1f}'),
+    (0xdc0, '\u{e20}'),
+    (0xdc1, '\u{e21}'),
+    (0xdc2, '\u{e22}'),
+    (0xdc3, '\u{e23}'),
+    (0xdc4, '\u{e24}'),
+    (0xdc5, '\u{e25}'),
+    (0xdc6, '\u{e26}'),
+    (0xdc7, '\u{e27}'),
+    (0xdc8, '\u{e28}'),
+    (0xdc9, '\u{e29}'),
+    (0xdca, '\u{e2a}'),
+    (0xdcb, '\u{e2b}'),
+    (0xdcc, '\u{e2c}'),
+    (0xdcd, '\u{e2d}'),
+    (0xdce, '\u{e2e}'),
+    (0xdcf, '\u{e2f}'),
+    (0xdd0, '\u{e30}'),
+    (0xdd1, '\u{e31}'),
+    (0xdd2, '\u{e32}'),
+    (0xdd3, '\u{e33}'),
+    (0xdd4, '\u{e34}'),
+    (0xdd5, '\u{e35}'),
+    (0xdd6, '\u{e36}'),
+    (0xdd7, '\u{e37}'),
+    (0xdd8, '\u{e38}'),
+    (0xdd9, '\u{e39}'),
+    (0xdda, '\u{e3a}'),
+    (0xddf, '\u{e3f}'),
+    (0xde0, '\u{e40}'),
+    (0xde1, '\u{e41}'),
+    (0xde2, '\u{e42}'),
+    (0xde3, '\u{e43}'),
+    (0xde4, '\u{e44}'),
+    (0xde5, '\u{e45}'),
+    (0xde6, '\u{e46}'),
+    (0xde7, '\u{e47}'),
+    (0xde8, '\u{e48}'),
+    (0xde9, '\u{e49}'),
+    (0xdea, '\u{e4a}'),
+    (0xdeb, '\u{e4b}'),
+    (0xdec, '\u{e4c}'),
+    (0xded, '\u{e4d}'),
+    (0xdf0, '\u{e50}'),
+    (0xdf1, '\u{e51}'),
+    (0xdf2, '\u{e52}'),
+    (0xdf3, '\u{e53}'),
+    (0xdf4, '\u{e54}'),
+    (0xdf5, '\u{e55}'),
+    (0xdf6, '\u{e56}'),
+    (0xdf7, '\u{e57}'),
+    (0xdf8, '\u{e58}'),
+    (0xdf9, '\u{e59}'),
+    (0xea1, '\u{3131}'),
+    (0xea2, '\u{3132}'),
+    (0xea3, '\u{3133}'),
+    (0xea4, '\u{3134}'),
+    (0xea5, '\u{3135}'),
+    (0xea6, '\u{3136}'),
+    (0xea7, '\u{3137}'),
+    (0xea8, '\u{3138}'),
+    (0xea9, '\u{3139}'),
+    (0xeaa, '\u{313a}'),
+    (0xeab, '\u{313b}'),
+    (0xeac, '\u{313c}'),
+    (0xead, '\u{313d}'),
+    (0xeae, '\u{313e}'),
+    (0xeaf, '\u{313f}'),
+    (0xeb0, '\u{3140}'),
+    (0xeb1, '\u{3141}'),
+    (0xeb2, '\u{3142}'),
+    (0xeb3, '\u{3143}'),
+    (0xeb4, '\u{3144}'),
+    (0xeb5, '\u{3145}'),
+    (0xeb6, '\u{3146}'),
+    (0xeb7, '\u{3147}'),
+    (0xeb8, '\u{3148}'),
+    (0xeb9, '\u{3149}'),
+    (0xeba, '\u{314a}'),
+    (0xebb, '\u{314b}'),
+    (0xebc, '\u{314c}'),
+    (0xebd, '\u{314d}'),
+    (0xebe, '\u{314e}'),
+    (0xebf, '\u{314f}'),
+    (0xec0, '\u{3150}'),
+    (0xec1, '\u{3151}'),
+    (0xec2, '\u{3152}'),
+    (0xec3, '\u{3153}'),
+    (0xec4, '\u{3154}'),
+    (0xec5, '\u{3155}'),
+    (0xec6, '\u{3156}'),
+    (0xec7, '\u{3157}'),
+    (0xec8, '\u{3158}'),
+    (0xec9, '\u{3159}'),
+    (0xeca, '\u{315a}'),
+    (0xecb, '\u{315b}'),
+    (0xecc, '\u{315c}'),
+    (0xecd, '\u{315d}'),
+    (0xece, '\u{315e}'),
+    (0xecf, '\u{315f}'),
+    (0xed0, '\u{3160}'),
+    (0xed1, '\u{3161}'),
+    (0xed2, '\u{3162}'),
+    (0xed3, '\u{3163}'),
+    (0xed4, '\u{11a8}'),
+    (0xed5, '\u{11a9}'),
+    (0xed6, '\u{11aa}'),
+    (0xed7, '\u{11ab}'),
+    (0xed8, '\u{11ac}'),
+    (0xed9, '\u{11ad}'),
+    (0xeda, '\u{11ae}'),
+    (0xedb, '\u{11af}'),
+    (0xedc, '\u{11b0}'),
+    (0xedd, '\u{11b1}'),
+    (0xede, '\u{11b2}'),
+    (0xedf, '\u{11b3}'),
+    (0xee0, '\u{11b4}'),
+    (0xee1, '\u{11b5}'),
+    (0xee2, '\u{11b6}'),
+    (0xee3, '\u{11b7}'),
+    (0xee4, '\u{11b8}'),
+    (0xee5, '\u{11b9}'),
+    (0xee6, '\u{11ba}'),
+    (0xee7, '\u{11bb}'),
+    (0xee8, '\u{11bc}'),
+    (0xee9, '\u{11bd}'),
+    (0xeea, '\u{11be}'),
+    (0xeeb, '\u{11bf}'),
+    (0xeec, '\u{11c0}'),
+    (0xeed, '\u{11c1}'),
+    (0xeee, '\u{11c2}'),
+    (0xeef, '\u{316d}'),
+    (0xef0, '\u{3171}'),
+    (0xef1, '\u{3178}'),
+    (0xef2, '\u{317f}'),
+    (0xef3, '\u{3181}'),
+    (0xef4, '\u{3184}'),
+    (0xef5, '\u{3186}'),
+    (0xef6, '\u{318d}'),
+    (0xef7, '\u{318e}'),
+    (0xef8, '\u{11eb}'),
+    (0xef9, '\u{11f0}'),
+    (0xefa, '\u{11f9}'),
+    (0x13bc, '\u{152}'),
+    (0x13bd, '\u{153}'),
+    (0x13be, '\u{178}'),
+    (0x20ac, '\u{20ac}'),
+];
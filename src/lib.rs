@@ -7,23 +7,114 @@
 use std::{
     io::BufRead,
     path::{Path, PathBuf},
-    sync::Arc,
+    rc::Rc,
 };
 
 use bitflags::bitflags;
+use log::debug;
 use thiserror::Error;
 
 pub use dbus;
-use dbus::{blocking::stdintf::org_freedesktop_dbus::Introspectable, channel::Watch};
+use dbus::channel::{MatchingReceiver, Token, Watch};
+use dispatch::DispatchScope;
 
+pub mod accessibility;
+pub mod bridge;
+#[cfg(feature = "calloop")]
+mod calloop_source;
+mod candidate_popup;
+mod component;
+mod config;
+#[cfg(any(feature = "winit", feature = "iced"))]
+mod context_thread;
+#[cfg(feature = "crossterm")]
+pub mod crossterm;
+mod dead_keys;
+mod desktop_settings;
+mod diagnostics;
+mod dispatch;
+mod dump;
+pub mod engine;
+mod engine_desc;
+mod event_queue;
+mod event_router;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "gio")]
+pub mod gdbus;
+#[cfg(all(test, target_endian = "little"))]
+mod golden;
+mod hotkey;
+#[cfg(feature = "iced")]
+pub mod iced;
+mod ime_event;
 mod input_context;
+mod input_method;
+mod instrument;
+#[cfg(feature = "keyboard-types")]
+pub mod keyboard_types;
+pub mod keysyms;
+mod lookup_table;
+mod metrics;
+#[cfg(feature = "mio")]
+mod mio_source;
+#[cfg(feature = "async")]
+pub mod nonblock;
+mod null;
+pub mod panel;
+mod property;
+mod retry;
+#[cfg(feature = "ropey")]
+mod rope;
+#[cfg(feature = "sdl2")]
+pub mod sdl2;
+mod selection;
+mod session_log;
+mod surrounding;
+mod sync_bus;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod text;
+mod validate;
+mod watchdog;
+#[cfg(feature = "wayland")]
+pub mod wayland;
+#[cfg(feature = "winit")]
+pub mod winit;
+pub mod x11;
+pub mod xim;
 
+pub use candidate_popup::*;
+pub use component::*;
+pub use config::*;
+pub use dead_keys::*;
+pub use desktop_settings::*;
+pub use engine_desc::*;
+pub use event_queue::*;
+pub use event_router::*;
+pub use hotkey::*;
+pub use ime_event::*;
 pub use input_context::*;
+pub use input_method::*;
+pub use keysyms::{keysym_from_char, keysym_from_name, keysym_name, keysym_to_char};
+pub use lookup_table::*;
+pub use metrics::Metrics;
+pub use null::*;
+pub use property::*;
+pub use retry::*;
+pub use selection::*;
+pub use session_log::*;
+pub use surrounding::*;
+pub use sync_bus::*;
 pub use text::*;
+pub use watchdog::*;
 
 pub(crate) const REQ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
 
+/// A filter for `start_receive`, which works with `Connection` and with
+/// `SyncConnection`
+pub(crate) type MessageFilter<C> = Box<dyn FnMut(dbus::Message, &C) -> bool + Send + Sync>;
+
 bitflags! {
     pub struct Capabilites: u32 {
         const PREEDIT_TEXT = 1 << 0;
@@ -66,17 +157,155 @@ bitflags! {
 
         const RELEASE = 1 << 30;
     }
+
+    /// Hints about the text field, that engines can use to adapt their
+    /// behaviour. The naming follows the IBus C API.
+    pub struct InputHints: u32 {
+        const SPELLCHECK = 1 << 0;
+        const NO_SPELLCHECK = 1 << 1;
+        const WORD_COMPLETION = 1 << 2;
+        const LOWERCASE = 1 << 3;
+        const UPPERCASE_CHARS = 1 << 4;
+        const UPPERCASE_WORDS = 1 << 5;
+        const UPPERCASE_SENTENCES = 1 << 6;
+        const INHIBIT_OSK = 1 << 7;
+        const VERTICAL_WRITING = 1 << 8;
+        const EMOJI = 1 << 9;
+        const NO_EMOJI = 1 << 10;
+        const PRIVATE = 1 << 11;
+    }
+}
+
+/// The purpose of a text field. The naming follows the IBus C API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputPurpose {
+    FreeForm,
+    Alpha,
+    Digits,
+    Number,
+    Phone,
+    Url,
+    Email,
+    Name,
+    Password,
+    Pin,
+    Terminal,
+}
+impl InputPurpose {
+    pub(crate) fn to_value(self) -> u32 {
+        match self {
+            Self::FreeForm => 0,
+            Self::Alpha => 1,
+            Self::Digits => 2,
+            Self::Number => 3,
+            Self::Phone => 4,
+            Self::Url => 5,
+            Self::Email => 6,
+            Self::Name => 7,
+            Self::Password => 8,
+            Self::Pin => 9,
+            Self::Terminal => 10,
+        }
+    }
+
+    pub(crate) fn from_value(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(Self::FreeForm),
+            1 => Some(Self::Alpha),
+            2 => Some(Self::Digits),
+            3 => Some(Self::Number),
+            4 => Some(Self::Phone),
+            5 => Some(Self::Url),
+            6 => Some(Self::Email),
+            7 => Some(Self::Name),
+            8 => Some(Self::Password),
+            9 => Some(Self::Pin),
+            10 => Some(Self::Terminal),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum Error {
     DBus(#[from] dbus::Error),
-    Unknown { description: String },
+    /// A method call failed with a D-Bus error
+    Call {
+        call: CallContext,
+        #[source]
+        source: dbus::Error,
+    },
+    Io(#[from] std::io::Error),
+    /// The reply of the call was abandoned, see `nonblock::CancelToken`
+    Cancelled,
+    /// An argument of the call is invalid, so the call wasn't sent
+    InvalidArgument {
+        call: CallContext,
+        description: String,
+    },
+    Unknown {
+        description: String,
+    },
+}
+impl Error {
+    /// The method call that failed, if this is an error of a call
+    pub fn call(&self) -> Option<&CallContext> {
+        match self {
+            Error::Call { call, .. } => Some(call),
+            Error::InvalidArgument { call, .. } => Some(call),
+            _ => None,
+        }
+    }
+
+    /// Turns a D-Bus error into an `Error::Call` of the call
+    pub(crate) fn in_call(self, path: &str, interface: &str, member: &str) -> Self {
+        match self {
+            Error::DBus(source) => Error::Call {
+                call: CallContext::new(path, interface, member),
+                source,
+            },
+            e => e,
+        }
+    }
 }
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Yeah Display is the same as Debug... I'm lazy
-        f.write_fmt(format_args!("{:?}", self))
+        match self {
+            Error::Call { call, source } => write!(
+                f,
+                "{} failed: {}: {}",
+                call,
+                source.name().unwrap_or("unknown error"),
+                source.message().unwrap_or("")
+            ),
+            Error::InvalidArgument { call, description } => {
+                write!(f, "{} not sent: {}", call, description)
+            }
+            // Yeah Display is the same as Debug... I'm lazy
+            _ => f.write_fmt(format_args!("{:?}", self)),
+        }
+    }
+}
+
+/// The method call of an `Error::Call`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallContext {
+    pub path: String,
+    pub interface: String,
+    pub member: String,
+}
+impl CallContext {
+    pub(crate) fn new(path: &str, interface: &str, member: &str) -> Self {
+        CallContext {
+            path: path.to_owned(),
+            interface: interface.to_owned(),
+            member: member.to_owned(),
+        }
+    }
+}
+impl std::fmt::Display for CallContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{} on {}", self.interface, self.member, self.path)
     }
 }
 
@@ -99,25 +328,36 @@ impl AfterCallback {
 }
 
 pub struct Bus {
-    conn: Arc<dbus::blocking::Connection>,
+    pub(crate) conn: Rc<dbus::blocking::Connection>,
 }
 
 impl Bus {
     pub fn new() -> Result<Self, Error> {
         let addr = get_address().map_err(|e| Error::Unknown { description: e })?;
-        let mut channel = dbus::channel::Channel::open_private(&addr)?;
+        Self::with_address(&addr)
+    }
+
+    /// Connects to the daemon at a D-Bus address, instead of the one of the
+    /// current session, e.g. to a `testing::MockServer`
+    pub fn with_address(address: &str) -> Result<Self, Error> {
+        let mut channel = dbus::channel::Channel::open_private(address)?;
         channel.register()?;
         Ok(Bus {
-            conn: Arc::new(dbus::blocking::Connection::from(channel)),
+            conn: Rc::new(dbus::blocking::Connection::from(channel)),
         })
     }
 
     pub fn create_input_context(&self, name: &str) -> Result<InputContext, Error> {
-        let ibus =
-            self.conn
-                .with_proxy("org.freedesktop.IBus", "/org/freedesktop/IBus", REQ_TIMEOUT);
+        let msg = dbus::Message::new_method_call(
+            "org.freedesktop.IBus",
+            "/org/freedesktop/IBus",
+            "org.freedesktop.IBus",
+            "CreateInputContext",
+        )
+        .expect("the method call should be valid")
+        .append1(name);
         let (obj_path,): (dbus::strings::Path,) =
-            ibus.method_call("org.freedesktop.IBus", "CreateInputContext", (name,))?;
+            dump::call(self.conn.channel(), msg).map_err(|e| bus_error(e, "CreateInputContext"))?;
 
         // println!("ibus:\n{}", ibus.introspect().unwrap());
         // println!("----------------------------------------------");
@@ -127,16 +367,167 @@ impl Bus {
         Ok(InputContext {
             conn: self.conn.clone(),
             obj_path,
+            retry: RetryPolicy::NONE,
+            watchdog: None,
         })
     }
 
+    /// Turns on or off the dump of the messages, for comparing the traffic
+    /// of the crate with the output of `dbus-monitor`
+    ///
+    /// Every call of the input contexts, its reply, and every signal that
+    /// reaches a callback or a queue is logged at the debug level, with the
+    /// `ibus::dump` target. This is for all the buses of the process.
+    pub fn set_message_dump(enabled: bool) {
+        dump::set_enabled(enabled);
+    }
+
+    /// Turns on or off the warnings about the capabilities that weren't set
+    /// on an input context, logged with the `ibus::diagnostics` target
+    ///
+    /// When a key event isn't handled, or the engine sends a signal that
+    /// relies on a capability that wasn't negotiated, e.g.
+    /// `RequireSurroundingText` without `Capabilites::SURROUNDING_TEXT`, the
+    /// warning says which one is missing. Only the capabilities set while
+    /// this is on are known. This is for all the buses of the process.
+    pub fn set_diagnostics(enabled: bool) {
+        diagnostics::set_enabled(enabled);
+    }
+
+    /// Installs the receiver of the counters and the latencies, or removes
+    /// it with `None`. Like the message dump, this is for all the buses of
+    /// the process.
+    pub fn set_metrics(metrics: Option<std::sync::Arc<dyn Metrics>>) {
+        metrics::set(metrics);
+    }
+
+    /// Requests a well-known name on the bus
+    ///
+    /// The program of a component must own the name of the component for
+    /// the daemon to consider it running.
+    pub fn request_name(&self, name: &str) -> Result<(), Error> {
+        use dbus::blocking::stdintf::org_freedesktop_dbus::RequestNameReply;
+        let reply = self
+            .conn
+            .request_name(name, false, true, true)
+            .map_err(|e| dbus_error(e, "RequestName"))?;
+        match reply {
+            RequestNameReply::PrimaryOwner | RequestNameReply::AlreadyOwner => Ok(()),
+            reply => Err(Error::Unknown {
+                description: format!("Couldn't get the name `{}`: {:?}", name, reply),
+            }),
+        }
+    }
+
+    /// Gives up a name requested with `request_name`
+    pub fn release_name(&self, name: &str) -> Result<(), Error> {
+        use dbus::blocking::stdintf::org_freedesktop_dbus::ReleaseNameReply;
+        let reply = self
+            .conn
+            .release_name(name)
+            .map_err(|e| dbus_error(e, "ReleaseName"))?;
+        match reply {
+            ReleaseNameReply::Released => Ok(()),
+            reply => Err(Error::Unknown {
+                description: format!("Couldn't release the name `{}`: {:?}", name, reply),
+            }),
+        }
+    }
+
+    /// Tells the daemon about a component that's provided by this program
+    ///
+    /// Unlike installing the component, this doesn't need a restart of the
+    /// daemon, but the daemon forgets about the component when this
+    /// connection is closed.
+    pub fn register_component(&self, component: &Component) -> Result<(), Error> {
+        let ibus =
+            self.conn
+                .with_proxy("org.freedesktop.IBus", "/org/freedesktop/IBus", REQ_TIMEOUT);
+        ibus.method_call::<(), _, _, _>("org.freedesktop.IBus", "RegisterComponent", (component,))
+            .map_err(|e| bus_error(e, "RegisterComponent"))?;
+        Ok(())
+    }
+
+    /// Returns the engine that is used when the input method state is shared
+    /// by all applications (the default)
+    ///
+    /// Fails if no engine has been used yet.
+    pub fn global_engine(&self) -> Result<EngineDesc, Error> {
+        global_engine(&self.conn)
+    }
+
+    /// Decodes the signals of the input contexts into `queue` from
+    /// `process`, until `remove_queue` is called with the returned token
+    ///
+    /// Signals that have a callback registered with the `on_*` methods of
+    /// `InputContext` before this go to that callback instead.
+    pub fn queue_events(&self, queue: EventQueue) -> Token {
+        self.conn
+            .start_receive(ImeEvent::match_rule(), queue_filter(queue))
+    }
+
+    /// Stops filling the queue of `queue_events`
+    pub fn remove_queue(&self, token: Token) {
+        self.conn.stop_receive(token);
+    }
+
+    /// Calls `callback` from `process` when the connection to the daemon is
+    /// lost, e.g. because the daemon exited
+    ///
+    /// It's called by the `process` that finds out, before that returns the
+    /// error. After that, `process` and all the calls fail. An application can
+    /// turn off its input method features, or connect again with a new bus.
+    ///
+    /// The message is delivered once, so only the callback registered first
+    /// is called. Pass the returned token to `remove_disconnect` to stop it.
+    pub fn on_disconnect<F>(&self, callback: F) -> Token
+    where
+        F: FnOnce() + Send + Sync + 'static,
+    {
+        self.conn
+            .start_receive(disconnected_rule(), disconnect_filter(callback))
+    }
+
+    /// Stops a callback of `on_disconnect`
+    pub fn remove_disconnect(&self, token: Token) {
+        self.conn.stop_receive(token);
+    }
+
     /// Returns:
     /// - `Ok(true)` if a new message was successfully processed
     /// - `Ok(false)` if there was no event to process
     /// - `Err(e)` if there was an error
+    ///
+    /// The messages that arrived while a blocking call waited for its reply
+    /// are processed first, see `InputContext::process_key_event`.
+    ///
+    /// The callbacks can make calls, including `process_key_event`. If a
+    /// callback calls this, it returns `Ok(false)` right away and leaves the
+    /// messages to the call that runs the callback.
     pub fn process(&self, timeout: std::time::Duration) -> Result<bool, Error> {
-        let processed = self.conn.process(timeout)?;
-        Ok(processed)
+        let _scope = match DispatchScope::enter(Rc::as_ptr(&self.conn)) {
+            Some(scope) => scope,
+            None => {
+                debug!("Not dispatching from within a callback of the same connection");
+                return Ok(false);
+            }
+        };
+        let _span = instrument::dispatch_span();
+        match self.conn.process(timeout) {
+            Ok(processed) => Ok(processed),
+            Err(e) => {
+                self.dispatch_queued();
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Dispatches the messages that are already queued, after reading failed
+    ///
+    /// libdbus queues the `Disconnected` signal when the connection is lost,
+    /// so the callback of `on_disconnect` runs before `process` fails.
+    fn dispatch_queued(&self) {
+        while let Ok(true) = self.conn.process(std::time::Duration::ZERO) {}
     }
 
     /// Get the underlying file descriptor for the event queue.
@@ -148,6 +539,57 @@ impl Bus {
     }
 }
 
+/// Matches the signal that libdbus queues when the connection is lost
+pub(crate) fn disconnected_rule() -> dbus::message::MatchRule<'static> {
+    dbus::message::MatchRule::new_signal("org.freedesktop.DBus.Local", "Disconnected")
+}
+
+pub(crate) fn disconnect_filter<C, F>(callback: F) -> MessageFilter<C>
+where
+    F: FnOnce() + Send + Sync + 'static,
+{
+    let mut callback = Some(callback);
+    Box::new(move |_, _| {
+        debug!("Disconnected from the daemon");
+        instrument::disconnected();
+        if let Some(callback) = callback.take() {
+            callback();
+        }
+        false
+    })
+}
+
+/// The signature of the whole body of `msg`, `Message` only has the ones of
+/// the arguments
+pub(crate) fn body_signature(msg: &dbus::Message) -> String {
+    let mut signature = String::new();
+    let mut args = msg.iter_init();
+    while args.arg_type() != dbus::arg::ArgType::Invalid {
+        signature.push_str(&args.signature());
+        args.next();
+    }
+    signature
+}
+
+pub(crate) fn global_engine(conn: &dbus::blocking::Connection) -> Result<EngineDesc, Error> {
+    use dbus::blocking::stdintf::org_freedesktop_dbus::Properties;
+    let ibus = conn.with_proxy("org.freedesktop.IBus", "/org/freedesktop/IBus", REQ_TIMEOUT);
+    let desc = ibus
+        .get("org.freedesktop.IBus", "GlobalEngine")
+        .map_err(|e| bus_error(e, "GlobalEngine"))?;
+    Ok(desc)
+}
+
+/// An error of a call on the bus itself
+fn dbus_error(e: dbus::Error, member: &str) -> Error {
+    Error::from(e).in_call("/org/freedesktop/DBus", "org.freedesktop.DBus", member)
+}
+
+/// An error of a call on the main object of the daemon
+pub(crate) fn bus_error(e: dbus::Error, member: &str) -> Error {
+    Error::from(e).in_call("/org/freedesktop/IBus", "org.freedesktop.IBus", member)
+}
+
 fn get_machine_id() -> Result<String, String> {
     if let Ok(id) = std::fs::read_to_string("/etc/machine-id") {
         return Ok(id.trim().to_owned());
@@ -159,7 +601,7 @@ fn get_machine_id() -> Result<String, String> {
 }
 
 // Based on https://seoyoungjin.github.io/ibus/text%20input/IBus/
-fn get_address() -> Result<String, String> {
+pub(crate) fn get_address() -> Result<String, String> {
     if let Ok(addr) = std::env::var("IBUS_ADDRESS") {
         return Ok(addr);
     }
@@ -171,10 +613,9 @@ fn get_address() -> Result<String, String> {
         display = ":0.0".into();
     }
     let mut split = display.split(":");
-    let mut host = split.next().map_or_else(
-        || Err(String::from("Failed to get host from display")),
-        |x| Ok(x),
-    )?;
+    let mut host = split
+        .next()
+        .map_or_else(|| Err(String::from("Failed to get host from display")), Ok)?;
     let disp_num = split.next().map_or_else(
         || {
             Err(String::from(
@@ -184,11 +625,11 @@ fn get_address() -> Result<String, String> {
         |x| {
             x.split(".").next().map_or_else(
                 || Err("Failed to get display number from display (period)".into()),
-                |x| Ok(x),
+                Ok,
             )
         },
     )?;
-    if host.len() == 0 {
+    if host.is_empty() {
         host = "unix";
     }
 
@@ -239,4 +680,26 @@ mod tests {
         const IBUS_MODIFIER_MASK: u32 = 0x5f001fff;
         assert_eq!(Modifiers::all().bits(), IBUS_MODIFIER_MASK);
     }
+
+    #[test]
+    fn error_context() {
+        let e = Error::from(dbus::Error::new_custom(
+            "org.freedesktop.DBus.Error.UnknownMethod",
+            "No such method",
+        ))
+        .in_call(
+            "/org/freedesktop/IBus/InputContext_1",
+            "org.freedesktop.IBus.InputContext",
+            "FocusIn",
+        );
+        assert_eq!(e.call().unwrap().member, "FocusIn");
+        assert_eq!(
+            e.to_string(),
+            "org.freedesktop.IBus.InputContext.FocusIn on /org/freedesktop/IBus/InputContext_1 \
+             failed: org.freedesktop.DBus.Error.UnknownMethod: No such method"
+        );
+        // The innermost call is kept
+        let e = e.in_call("/", "org.example", "Other");
+        assert_eq!(e.call().unwrap().member, "FocusIn");
+    }
 }
@@ -0,0 +1,390 @@
+//! IBusLookupTable
+//!
+//! Contains the list of candidates that an engine offers, along with the
+//! paging and cursor state of the candidate window.
+//!
+
+use std::{any::Any, borrow::Cow, os::raw::c_int};
+
+use log::debug;
+
+use dbus::arg::{Append, Arg, ArgType, Get, PropMap, RefArg, Variant};
+
+use crate::Text;
+
+const LOOKUP_TABLE_NAME: &str = "IBusLookupTable";
+
+/// The labels used for candidates that don't have an explicit label
+const DEFAULT_LABELS: &str = "1234567890";
+
+/// The orientation of the candidate window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+    /// Use the orientation that's configured system wide
+    System,
+}
+impl Orientation {
+    fn to_value(self) -> c_int {
+        match self {
+            Self::Horizontal => 0,
+            Self::Vertical => 1,
+            Self::System => 2,
+        }
+    }
+
+    pub(crate) fn from_value(v: i32) -> Option<Self> {
+        match v {
+            0 => Some(Self::Horizontal),
+            1 => Some(Self::Vertical),
+            2 => Some(Self::System),
+            _ => None,
+        }
+    }
+}
+
+/// A list of candidates, split into pages
+///
+/// The paging and cursor methods follow the behaviour of the IBus C API
+/// (`ibus_lookup_table_page_up` and friends), including the wrap-around
+/// when `round` is true.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LookupTable {
+    pub candidates: Vec<Text<'static>>,
+
+    /// Labels for the candidates of a page. The label at index `i` is used for
+    /// the `i`th candidate of every page. When there's no label for a candidate,
+    /// the numbers 1 to 9 and then 0 are used.
+    pub labels: Vec<Text<'static>>,
+
+    /// The number of candidates shown at once
+    pub page_size: u32,
+
+    /// Zero based index of the highlighted candidate in `candidates`
+    pub cursor_pos: u32,
+    pub cursor_visible: bool,
+
+    /// Whether the cursor wraps around when moving past the first or the last
+    /// candidate
+    pub round: bool,
+    pub orientation: Orientation,
+}
+type SerializedLookupTable = (
+    String,
+    PropMap,
+    u32,
+    u32,
+    bool,
+    bool,
+    i32,
+    Vec<Text<'static>>,
+    Vec<Text<'static>>,
+);
+impl Default for LookupTable {
+    fn default() -> Self {
+        Self::new(5, 0, true, false)
+    }
+}
+impl LookupTable {
+    pub fn new(page_size: u32, cursor_pos: u32, cursor_visible: bool, round: bool) -> Self {
+        LookupTable {
+            candidates: Vec::new(),
+            labels: Vec::new(),
+            page_size,
+            cursor_pos,
+            cursor_visible,
+            round,
+            orientation: Orientation::System,
+        }
+    }
+
+    pub fn append_candidate(&mut self, text: impl Into<Text<'static>>) {
+        self.candidates.push(text.into());
+    }
+
+    pub fn append_label(&mut self, text: impl Into<Text<'static>>) {
+        self.labels.push(text.into());
+    }
+
+    /// Removes all candidates and resets the cursor
+    pub fn clear(&mut self) {
+        self.candidates.clear();
+        self.cursor_pos = 0;
+    }
+
+    #[inline]
+    pub fn number_of_candidates(&self) -> u32 {
+        self.candidates.len() as u32
+    }
+
+    /// Returns the zero based index of the page that contains the candidate
+    /// at `cursor`
+    #[inline]
+    pub fn page_of(&self, cursor: u32) -> u32 {
+        cursor / self.page_size.max(1)
+    }
+
+    /// Returns the index of the first candidate of the page that contains the
+    /// cursor
+    #[inline]
+    pub fn current_page_start(&self) -> u32 {
+        self.page_of(self.cursor_pos) * self.page_size.max(1)
+    }
+
+    /// Returns the position of the cursor relative to the start of the
+    /// current page
+    #[inline]
+    pub fn cursor_pos_in_page(&self) -> u32 {
+        self.cursor_pos % self.page_size.max(1)
+    }
+
+    /// Returns the candidates on the page that contains the cursor
+    pub fn candidates_in_current_page(&self) -> &[Text<'static>] {
+        let len = self.candidates.len();
+        let start = (self.current_page_start() as usize).min(len);
+        let end = (start + self.page_size.max(1) as usize).min(len);
+        &self.candidates[start..end]
+    }
+
+    /// Returns the label of the candidate at `index` (an index into
+    /// `candidates`)
+    ///
+    /// Returns `None` if there's no such candidate, or if the candidate has
+    /// no label and is past the range of the default labels.
+    pub fn label_for(&self, index: u32) -> Option<Cow<'_, str>> {
+        if index >= self.number_of_candidates() {
+            return None;
+        }
+        let index_in_page = (index % self.page_size.max(1)) as usize;
+        if let Some(label) = self.labels.get(index_in_page) {
+            return Some(Cow::Borrowed(label.as_str()));
+        }
+        DEFAULT_LABELS
+            .chars()
+            .nth(index_in_page)
+            .map(|c| Cow::Owned(c.to_string()))
+    }
+
+    /// Returns the index (into `candidates`) of the candidate on the current
+    /// page that has `label` as the first character of its label
+    pub fn select_by_label(&self, label: char) -> Option<u32> {
+        let start = self.current_page_start();
+        (start..start + self.candidates_in_current_page().len() as u32)
+            .find(|&i| self.label_for(i).and_then(|l| l.chars().next()) == Some(label))
+    }
+
+    /// Moves the cursor to the previous page.
+    ///
+    /// Returns false if the cursor couldn't be moved
+    pub fn page_up(&mut self) -> bool {
+        let page_size = self.page_size.max(1);
+        if self.cursor_pos < page_size {
+            if !self.round || self.candidates.is_empty() {
+                return false;
+            }
+            let last_page = self.page_of(self.number_of_candidates() - 1);
+            let pos = last_page * page_size + self.cursor_pos_in_page();
+            self.cursor_pos = pos.min(self.number_of_candidates() - 1);
+            return true;
+        }
+        self.cursor_pos -= page_size;
+        true
+    }
+
+    /// Moves the cursor to the next page.
+    ///
+    /// Returns false if the cursor couldn't be moved
+    pub fn page_down(&mut self) -> bool {
+        if self.candidates.is_empty() {
+            return false;
+        }
+        let page_size = self.page_size.max(1);
+        let mut page = self.page_of(self.cursor_pos);
+        if (page + 1) * page_size >= self.number_of_candidates() {
+            if !self.round {
+                return false;
+            }
+            page = 0;
+        } else {
+            page += 1;
+        }
+        let pos = page * page_size + self.cursor_pos_in_page();
+        self.cursor_pos = pos.min(self.number_of_candidates() - 1);
+        true
+    }
+
+    /// Moves the cursor to the previous candidate.
+    ///
+    /// Returns false if the cursor couldn't be moved
+    pub fn cursor_up(&mut self) -> bool {
+        if self.cursor_pos == 0 {
+            if !self.round || self.candidates.is_empty() {
+                return false;
+            }
+            self.cursor_pos = self.number_of_candidates() - 1;
+            return true;
+        }
+        self.cursor_pos -= 1;
+        true
+    }
+
+    /// Moves the cursor to the next candidate.
+    ///
+    /// Returns false if the cursor couldn't be moved
+    pub fn cursor_down(&mut self) -> bool {
+        if self.cursor_pos + 1 >= self.number_of_candidates() {
+            if !self.round || self.candidates.is_empty() {
+                return false;
+            }
+            self.cursor_pos = 0;
+            return true;
+        }
+        self.cursor_pos += 1;
+        true
+    }
+}
+
+impl RefArg for LookupTable {
+    fn arg_type(&self) -> ArgType {
+        ArgType::Variant
+    }
+
+    fn signature(&self) -> dbus::Signature<'static> {
+        <Self as Arg>::signature()
+    }
+
+    fn append(&self, i: &mut dbus::arg::IterAppend) {
+        i.append(Variant((
+            LOOKUP_TABLE_NAME,
+            PropMap::new(),
+            self.page_size,
+            self.cursor_pos,
+            self.cursor_visible,
+            self.round,
+            self.orientation.to_value(),
+            self.candidates.clone(),
+            self.labels.clone(),
+        )))
+    }
+
+    fn as_any(&self) -> &dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn box_clone(&self) -> Box<dyn RefArg + 'static> {
+        Box::new(self.clone())
+    }
+}
+impl Arg for LookupTable {
+    const ARG_TYPE: ArgType = ArgType::Variant;
+
+    fn signature() -> dbus::Signature<'static> {
+        dbus::Signature::from("v\u{0}")
+    }
+}
+impl Append for LookupTable {
+    fn append_by_ref(&self, i: &mut dbus::arg::IterAppend) {
+        <Self as RefArg>::append(self, i);
+    }
+}
+impl<'a> Get<'a> for LookupTable {
+    fn get(i: &mut dbus::arg::Iter<'a>) -> Option<Self> {
+        let mut table_var: Variant<dbus::arg::Iter<'a>> = i.get()?;
+        let table_struct: SerializedLookupTable = match table_var.0.get() {
+            Some(s) => s,
+            None => {
+                debug!("Couldn't deserialize lookup table {:?}", table_var.0);
+                return None;
+            }
+        };
+        if table_struct.0 != LOOKUP_TABLE_NAME {
+            debug!("Lookup table didn't have the expected name.");
+            return None;
+        }
+        let orientation = Orientation::from_value(table_struct.6).unwrap_or_else(|| {
+            debug!("Unexpected orientation `{}`", table_struct.6);
+            Orientation::System
+        });
+        Some(LookupTable {
+            page_size: table_struct.2,
+            cursor_pos: table_struct.3,
+            cursor_visible: table_struct.4,
+            round: table_struct.5,
+            orientation,
+            candidates: table_struct.7,
+            labels: table_struct.8,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(n: u32, round: bool) -> LookupTable {
+        let mut table = LookupTable::new(3, 0, true, round);
+        for i in 0..n {
+            table.append_candidate(format!("c{}", i));
+        }
+        table
+    }
+
+    #[test]
+    fn paging() {
+        let mut t = table(7, false);
+        assert!(!t.page_up());
+        assert!(t.page_down());
+        assert_eq!(t.cursor_pos, 3);
+        t.cursor_pos = 5;
+        assert!(t.page_down());
+        assert_eq!(t.cursor_pos, 6);
+        assert!(!t.page_down());
+        assert_eq!(t.candidates_in_current_page().len(), 1);
+
+        let mut t = table(7, true);
+        t.cursor_pos = 2;
+        assert!(t.page_up());
+        assert_eq!(t.cursor_pos, 6);
+        assert!(t.page_down());
+        assert_eq!(t.cursor_pos, 0);
+        assert!(t.cursor_up());
+        assert_eq!(t.cursor_pos, 6);
+        assert!(t.cursor_down());
+        assert_eq!(t.cursor_pos, 0);
+    }
+
+    #[test]
+    fn labels() {
+        let mut t = table(7, false);
+        assert_eq!(t.label_for(4).as_deref(), Some("2"));
+        assert_eq!(t.label_for(7), None);
+        t.page_down();
+        assert_eq!(t.select_by_label('2'), Some(4));
+        assert_eq!(t.select_by_label('9'), None);
+        t.labels = vec!["a".into(), "s".into(), "d".into()];
+        assert_eq!(t.select_by_label('d'), Some(5));
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        let mut t = table(4, true);
+        t.append_label("x");
+        t.orientation = Orientation::Vertical;
+        let msg = dbus::Message::new_method_call("a.b", "/a/b", "a.b", "C")
+            .unwrap()
+            .append1(t.clone());
+        let read: LookupTable = msg.read1().unwrap();
+        assert_eq!(read, t);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    time::Duration,
+};
+
+/// Receives the counters and the latencies of the IBus traffic, for
+/// exporting them to a monitoring system like Prometheus or StatsD
+///
+/// Every method does nothing by default. Install an implementation with
+/// `Bus::set_metrics`. The methods are called from the thread that makes the
+/// call or dispatches the signal, so they should be quick.
+pub trait Metrics: Send + Sync {
+    /// A key event was sent to an input context, by any of the buses
+    fn key_event_sent(&self) {}
+
+    /// A signal of an input context reached a callback or a queue
+    fn signal_received(&self, _member: &str) {}
+
+    /// A signal of an input context had arguments that the crate can't
+    /// decode, and it was dropped
+    fn decode_failed(&self, _member: &str) {}
+
+    /// A blocking method call of `Bus` or `SyncBus`, or of their input
+    /// contexts, got its reply or error after `latency`
+    fn round_trip(&self, _method: &str, _latency: Duration) {}
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static METRICS: RwLock<Option<Arc<dyn Metrics>>> = RwLock::new(None);
+
+/// See `Bus::set_metrics`
+pub(crate) fn set(metrics: Option<Arc<dyn Metrics>>) {
+    let installed = metrics.is_some();
+    *METRICS.write().unwrap_or_else(|e| e.into_inner()) = metrics;
+    INSTALLED.store(installed, Ordering::Release);
+}
+
+fn with(f: impl FnOnce(&dyn Metrics)) {
+    if !INSTALLED.load(Ordering::Acquire) {
+        return;
+    }
+    let metrics = METRICS.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(metrics) = &*metrics {
+        f(&**metrics);
+    }
+}
+
+pub(crate) fn key_event_sent() {
+    with(|m| m.key_event_sent());
+}
+
+pub(crate) fn signal_received(member: &str) {
+    with(|m| m.signal_received(member));
+}
+
+pub(crate) fn decode_failed(member: &str) {
+    with(|m| m.decode_failed(member));
+}
+
+pub(crate) fn round_trip(method: &str, latency: Duration) {
+    with(|m| m.round_trip(method, latency));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::AtomicUsize, thread::ThreadId};
+
+    use dbus::Message;
+
+    use super::*;
+    use crate::ImeEvent;
+
+    /// Counts the failures of the thread that created it, the metrics are
+    /// global and the other tests run in parallel
+    struct Counters {
+        thread: ThreadId,
+        failures: AtomicUsize,
+    }
+    impl Metrics for Counters {
+        fn decode_failed(&self, member: &str) {
+            if member == "DeleteSurroundingText" && std::thread::current().id() == self.thread {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_failures_are_counted() {
+        let counters = Arc::new(Counters {
+            thread: std::thread::current().id(),
+            failures: AtomicUsize::new(0),
+        });
+        set(Some(counters.clone()));
+        // The arguments should be "iu"
+        let msg = Message::new_signal(
+            "/org/freedesktop/IBus/InputContext_1",
+            "org.freedesktop.IBus.InputContext",
+            "DeleteSurroundingText",
+        )
+        .unwrap()
+        .append1("not a number");
+        assert_eq!(ImeEvent::from_message(&msg), None);
+        set(None);
+        assert_eq!(counters.failures.load(Ordering::Relaxed), 1);
+    }
+}
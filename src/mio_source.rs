@@ -0,0 +1,83 @@
+use std::io;
+
+use mio::{event::Source, unix::SourceFd, Interest, Registry, Token};
+
+use crate::{Bus, SyncBus};
+
+/// Lets a mio `Poll` wait for the messages of the connection. Needs the
+/// `mio` feature.
+///
+/// mio reports readiness edge-triggered, so after an event call `process`
+/// until it returns `Ok(false)`, otherwise the remaining messages wait for
+/// the next one to arrive.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use mio::{Events, Interest, Poll, Token};
+///
+/// const IBUS: Token = Token(0);
+///
+/// let mut bus = ibus::Bus::new().unwrap();
+/// let mut poll = Poll::new().unwrap();
+/// poll.registry()
+///     .register(&mut bus, IBUS, Interest::READABLE)
+///     .unwrap();
+///
+/// let mut events = Events::with_capacity(16);
+/// loop {
+///     poll.poll(&mut events, None).unwrap();
+///     for event in &events {
+///         if event.token() == IBUS {
+///             while bus.process(Duration::ZERO).unwrap() {}
+///         }
+///     }
+/// }
+/// ```
+impl Source for Bus {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.watch().fd).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.watch().fd).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        SourceFd(&self.watch().fd).deregister(registry)
+    }
+}
+
+/// See the `Source` implementation of `Bus`
+impl Source for SyncBus {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.watch().fd).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.watch().fd).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        SourceFd(&self.watch().fd).deregister(registry)
+    }
+}
@@ -0,0 +1,381 @@
+//! Async access to IBus
+//!
+//! `AsyncBus` and `AsyncInputContext` are the async versions of `Bus` and
+//! `InputContext`. Method calls return futures instead of blocking the
+//! thread until the daemon answers, and signals are delivered as streams.
+//!
+//! The futures don't depend on a runtime. The connection is driven by a
+//! `ConnectionDriver`, a future that waits for the socket with a `Reactor`,
+//! and runs as a task on the runtime of the application:
+//! - with tokio (the `tokio` feature), `AsyncBus::new` spawns it
+//! - with smol or async-std (the `async-io` feature), spawn the driver of
+//!   `AsyncBus::connect::<AsyncIoReactor>()`
+//! - other runtimes can implement `Reactor`
+//!
+//! ```no_run
+//! use futures_util::StreamExt;
+//! use ibus::{nonblock::AsyncBus, Capabilites, CommitTextSignal};
+//!
+//! # #[cfg(feature = "async-io")]
+//! # async fn run() -> Result<(), ibus::Error> {
+//! let (bus, driver) = AsyncBus::connect::<ibus::nonblock::AsyncIoReactor>()?;
+//! smol::spawn(driver).detach();
+//!
+//! let ctx = bus.create_input_context("my-app").await?;
+//! ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS).await?;
+//! ctx.focus_in().await?;
+//!
+//! let mut commits = ctx.signals::<CommitTextSignal>().await?;
+//! while let Some(signal) = commits.next().await {
+//!     println!("Committed {}", signal.text.as_str());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! This module needs the `async` feature, which the `tokio` and `async-io`
+//! features enable.
+//!
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use log::debug;
+
+use dbus::{
+    arg::{AppendAll, ReadAll},
+    channel::{Channel, MatchingReceiver, Sender},
+    message::MatchRule,
+    message::SignalArgs,
+    nonblock::{MethodReply, MsgMatch, Proxy, SyncConnection},
+    strings::Path,
+};
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_util::Stream;
+
+use crate::{
+    get_address, input_context::INTERFACE_NAME, CallContext, Capabilites, EngineDesc, Error,
+    Modifiers, PropState, Text, REQ_TIMEOUT,
+};
+
+#[cfg(feature = "async-io")]
+mod async_io_reactor;
+mod cancel;
+mod driver;
+#[cfg(feature = "tokio")]
+mod tokio_reactor;
+#[cfg(feature = "async-io")]
+pub use async_io_reactor::*;
+pub use cancel::{CancelGuard, CancelToken};
+pub use driver::{ConnectionDriver, Reactor, Sleep};
+#[cfg(feature = "tokio")]
+pub use tokio_reactor::*;
+
+use driver::Shared;
+
+const IBUS_NAME: &str = "org.freedesktop.IBus";
+const IBUS_PATH: &str = "/org/freedesktop/IBus";
+
+/// A connection to the IBus daemon, see the module documentation
+///
+/// Dropping the bus stops the driver of the connection, so the input
+/// contexts created by it stop working.
+pub struct AsyncBus {
+    conn: Arc<SyncConnection>,
+    shared: Arc<Shared>,
+}
+impl AsyncBus {
+    /// Connects to the daemon, and returns the future that drives the
+    /// connection. Nothing is sent or received until the driver is spawned.
+    ///
+    /// This blocks until the connection is set up.
+    pub fn connect<R: Reactor>() -> Result<(Self, ConnectionDriver<R>), Error> {
+        let addr = get_address().map_err(|e| Error::Unknown { description: e })?;
+        let mut channel = Channel::open_private(&addr)?;
+        channel.register()?;
+        let (conn, shared, driver) = driver::connect(channel);
+        Ok((AsyncBus { conn, shared }, driver))
+    }
+
+    /// Connects to the daemon, and drives the connection with a task on the
+    /// current tokio runtime. Needs the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub fn new() -> Result<Self, Error> {
+        let (bus, driver) = Self::connect::<TokioReactor>()?;
+        tokio::spawn(async move {
+            if let Err(e) = driver.await {
+                debug!("{}", e);
+            }
+        });
+        Ok(bus)
+    }
+
+    /// The underlying connection, for calls that this crate doesn't wrap
+    pub fn connection(&self) -> &Arc<SyncConnection> {
+        &self.conn
+    }
+
+    pub fn create_input_context(&self, name: &str) -> Reply<AsyncInputContext> {
+        let ibus = Proxy::new(IBUS_NAME, IBUS_PATH, REQ_TIMEOUT, self.conn.clone());
+        let conn = self.conn.clone();
+        Reply::new(
+            ibus.method_call(IBUS_NAME, "CreateInputContext", (name,))
+                .and_then(|(obj_path,): (Path<'static>,)| Ok(AsyncInputContext { conn, obj_path })),
+            CallContext::new(IBUS_PATH, IBUS_NAME, "CreateInputContext"),
+        )
+    }
+
+    /// See `Bus::global_engine`
+    pub async fn global_engine(&self) -> Result<EngineDesc, Error> {
+        use dbus::nonblock::stdintf::org_freedesktop_dbus::Properties;
+        let ibus = Proxy::new(IBUS_NAME, IBUS_PATH, REQ_TIMEOUT, self.conn.clone());
+        let desc = ibus
+            .get(IBUS_NAME, "GlobalEngine")
+            .await
+            .map_err(|e| Error::from(e).in_call(IBUS_PATH, IBUS_NAME, "GlobalEngine"))?;
+        Ok(desc)
+    }
+}
+impl Drop for AsyncBus {
+    fn drop(&mut self) {
+        self.shared.close();
+    }
+}
+
+/// The reply to a method call
+///
+/// The call is sent when the method is called, not when the reply is first
+/// polled. So the calls reach the daemon in the order they were made, even
+/// if their replies are awaited together, e.g. with `join!`. Dropping the
+/// reply doesn't cancel the call, it only ignores its result, and so does
+/// `cancel_with`.
+pub struct Reply<T> {
+    reply: MethodReply<T>,
+    call: Option<CallContext>,
+    cancel: Option<cancel::Waiter>,
+}
+impl<T> Reply<T> {
+    fn new(reply: MethodReply<T>, call: CallContext) -> Self {
+        Reply {
+            reply,
+            call: Some(call),
+            cancel: None,
+        }
+    }
+
+    /// Makes the reply resolve to `Err(Error::Cancelled)` as soon as `token`
+    /// is cancelled, see `CancelToken`
+    pub fn cancel_with(mut self, token: &CancelToken) -> Self {
+        self.cancel = Some(token.waiter());
+        self
+    }
+}
+impl<T: 'static> Future for Reply<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(waiter) = &self.cancel {
+            if waiter.poll_cancelled(cx) {
+                return Poll::Ready(Err(Error::Cancelled));
+            }
+        }
+        match Pin::new(&mut self.reply).poll(cx) {
+            Poll::Ready(Err(source)) => Poll::Ready(Err(match self.call.take() {
+                Some(call) => Error::Call { call, source },
+                None => Error::DBus(source),
+            })),
+            poll => poll.map_err(Error::from),
+        }
+    }
+}
+
+/// The async version of `InputContext`
+///
+/// The method calls return a `Reply`, so an input context can be set up
+/// with concurrent calls:
+///
+/// ```no_run
+/// # use ibus::{nonblock::AsyncInputContext, Capabilites};
+/// # async fn set_up(ctx: &AsyncInputContext) -> Result<(), ibus::Error> {
+/// use futures_util::future::try_join3;
+///
+/// // Sent in this order, but only one round trip is waited for
+/// try_join3(
+///     ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS),
+///     ctx.focus_in(),
+///     ctx.set_cursor_location(100, 200, 1, 16),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncInputContext {
+    conn: Arc<SyncConnection>,
+    obj_path: Path<'static>,
+}
+impl AsyncInputContext {
+    pub fn path(&self) -> &Path<'static> {
+        &self.obj_path
+    }
+
+    pub fn set_capabilities(&self, caps: Capabilites) -> Reply<()> {
+        crate::diagnostics::set_capabilities(&self.obj_path, caps);
+        self.call("SetCapabilities", (caps.bits(),))
+    }
+
+    /// See `InputContext::process_key_event`
+    pub fn process_key_event(&self, sym: u32, code: u32, modifiers: Modifiers) -> Reply<bool> {
+        crate::metrics::key_event_sent();
+        Reply::new(
+            self.proxy()
+                .method_call(
+                    INTERFACE_NAME,
+                    "ProcessKeyEvent",
+                    (sym, code, modifiers.bits()),
+                )
+                .and_then(|(handled,): (bool,)| Ok(handled)),
+            self.context("ProcessKeyEvent"),
+        )
+    }
+
+    /// See `InputContext::set_cursor_location`
+    pub fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Reply<()> {
+        self.call("SetCursorLocation", (x, y, w, h))
+    }
+
+    pub fn focus_in(&self) -> Reply<()> {
+        self.call("FocusIn", ())
+    }
+
+    pub fn focus_out(&self) -> Reply<()> {
+        self.call("FocusOut", ())
+    }
+
+    pub fn reset(&self) -> Reply<()> {
+        self.call("Reset", ())
+    }
+
+    pub fn set_surrounding_text<'a>(
+        &self,
+        text: impl Into<Text<'a>>,
+        cursor_pos: u32,
+        anchor_pos: u32,
+    ) -> Reply<()> {
+        let text: Text<'a> = text.into();
+        self.call("SetSurroundingText", (text, cursor_pos, anchor_pos))
+    }
+
+    pub fn page_up(&self) -> Reply<()> {
+        self.call("PageUp", ())
+    }
+
+    pub fn page_down(&self) -> Reply<()> {
+        self.call("PageDown", ())
+    }
+
+    pub fn cursor_up(&self) -> Reply<()> {
+        self.call("CursorUp", ())
+    }
+
+    pub fn cursor_down(&self) -> Reply<()> {
+        self.call("CursorDown", ())
+    }
+
+    /// See `InputContext::candidate_clicked`
+    pub fn candidate_clicked(&self, index: u32, button: u32, state: Modifiers) -> Reply<()> {
+        self.call("CandidateClicked", (index, button, state.bits()))
+    }
+
+    pub fn property_activate(&self, name: &str, state: PropState) -> Reply<()> {
+        self.call("PropertyActivate", (name, state.to_value()))
+    }
+
+    /// See `InputContext::engine`
+    pub fn engine(&self) -> Reply<EngineDesc> {
+        Reply::new(
+            self.proxy()
+                .method_call(INTERFACE_NAME, "GetEngine", ())
+                .and_then(|(desc,): (EngineDesc,)| Ok(desc)),
+            self.context("GetEngine"),
+        )
+    }
+
+    /// Returns a stream of the signals of type `S` emitted by this input
+    /// context, e.g. `CommitTextSignal`
+    ///
+    /// The daemon stops sending the signals when the stream is dropped.
+    pub async fn signals<S>(&self) -> Result<SignalStream<S>, Error>
+    where
+        S: SignalArgs + ReadAll + Send + 'static,
+    {
+        let rule = S::match_rule(None, Some(&self.obj_path)).static_clone();
+        let (msg_match, receiver) = self.conn.add_match(rule).await?.stream();
+        Ok(SignalStream {
+            conn: self.conn.clone(),
+            msg_match: Some(msg_match),
+            receiver,
+        })
+    }
+
+    fn call<A: AppendAll>(&self, method: &str, args: A) -> Reply<()> {
+        Reply::new(
+            self.proxy().method_call(INTERFACE_NAME, method, args),
+            self.context(method),
+        )
+    }
+
+    fn context(&self, method: &str) -> CallContext {
+        CallContext::new(&self.obj_path, INTERFACE_NAME, method)
+    }
+
+    fn proxy(&self) -> Proxy<'_, Arc<SyncConnection>> {
+        Proxy::new(IBUS_NAME, &self.obj_path, REQ_TIMEOUT, self.conn.clone())
+    }
+}
+
+/// The signals of an input context, returned by `AsyncInputContext::signals`
+pub struct SignalStream<S> {
+    conn: Arc<SyncConnection>,
+    msg_match: Option<MsgMatch>,
+    receiver: UnboundedReceiver<(dbus::Message, S)>,
+}
+impl<S> Stream for SignalStream<S> {
+    type Item = S;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S>> {
+        Pin::new(&mut self.receiver)
+            .poll_next(cx)
+            .map(|item| item.map(|(_, signal)| signal))
+    }
+}
+impl<S> Drop for SignalStream<S> {
+    fn drop(&mut self) {
+        let token = match self.msg_match.take() {
+            Some(msg_match) => msg_match.token(),
+            None => return,
+        };
+        if let Some((rule, _)) = self.conn.stop_receive(token) {
+            remove_match(&self.conn, &rule);
+        }
+    }
+}
+
+/// Tells the bus to stop sending the messages of the rule, without waiting
+/// for the reply, so that it works in `drop`
+fn remove_match(conn: &SyncConnection, rule: &MatchRule) {
+    let mut msg = dbus::Message::method_call(
+        &"org.freedesktop.DBus".into(),
+        &"/org/freedesktop/DBus".into(),
+        &"org.freedesktop.DBus".into(),
+        &"RemoveMatch".into(),
+    )
+    .append1(rule.match_str());
+    msg.set_no_reply(true);
+    if conn.send(msg).is_err() {
+        debug!("Couldn't remove the match of a signal stream");
+    }
+}
@@ -0,0 +1,49 @@
+use std::{
+    io,
+    os::unix::io::{AsFd, BorrowedFd, RawFd},
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use async_io::{Async, Timer};
+
+use super::{Reactor, Sleep};
+
+/// The socket of the connection, which is owned by libdbus
+struct Socket(RawFd);
+impl AsFd for Socket {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        // The connection outlives the driver, which owns the reactor
+        unsafe { BorrowedFd::borrow_raw(self.0) }
+    }
+}
+
+/// Drives the connection with async-io, the reactor used by smol and
+/// async-std. Needs the `async-io` feature.
+///
+/// async-io runs its reactor on a thread of its own when needed, so this
+/// also works with other executors, e.g. `futures::executor`.
+pub struct AsyncIoReactor {
+    socket: Async<Socket>,
+}
+impl Reactor for AsyncIoReactor {
+    fn register(fd: RawFd) -> io::Result<Self> {
+        Ok(AsyncIoReactor {
+            socket: Async::new(Socket(fd))?,
+        })
+    }
+
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.socket.poll_readable(cx)
+    }
+
+    fn poll_writable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.socket.poll_writable(cx)
+    }
+
+    fn sleep_until(deadline: Instant) -> Sleep {
+        Box::pin(async move {
+            Timer::at(deadline).await;
+        })
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Waker},
+};
+
+#[derive(Default)]
+struct State {
+    cancelled: AtomicBool,
+    next_id: AtomicU64,
+    wakers: Mutex<HashMap<u64, Waker>>,
+}
+
+/// Abandons the replies of calls that are still in flight
+///
+/// Replies made cancellable with `Reply::cancel_with` resolve to
+/// `Err(Error::Cancelled)` once the token is cancelled, even if the daemon
+/// answers later. The call itself still reaches the daemon, only its result
+/// is dropped.
+///
+/// A typical use is a token per focus of a text field, so that the results of
+/// key events aren't applied to a field that lost the focus:
+///
+/// ```no_run
+/// # use ibus::{nonblock::{AsyncInputContext, CancelToken}, Error, Modifiers};
+/// # async fn key(ctx: &AsyncInputContext, focus: &CancelToken) -> Result<(), Error> {
+/// match ctx
+///     .process_key_event(ibus::keysyms::KEY_a, 30, Modifiers::empty())
+///     .cancel_with(focus)
+///     .await
+/// {
+///     Ok(handled) => { /* Forward the key to the field if it wasn't handled */ }
+///     Err(Error::Cancelled) => { /* The field lost the focus, drop the key */ }
+///     Err(e) => return Err(e),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Clones share the state, cancelling one cancels all of them. A cancelled
+/// token stays cancelled, make a new one for the next focus.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    state: Arc<State>,
+}
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the replies that use this token, and the ones that will use
+    /// it
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::SeqCst);
+        let wakers = std::mem::take(&mut *self.state.wakers.lock().unwrap());
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns a guard that cancels the token when it's dropped, e.g. to
+    /// keep with the focus state of a text field
+    pub fn guard(&self) -> CancelGuard {
+        CancelGuard {
+            token: self.clone(),
+            armed: true,
+        }
+    }
+
+    pub(crate) fn waiter(&self) -> Waiter {
+        Waiter {
+            state: self.state.clone(),
+            id: self.state.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+/// Cancels its `CancelToken` when dropped, see `CancelToken::guard`
+pub struct CancelGuard {
+    token: CancelToken,
+    armed: bool,
+}
+impl CancelGuard {
+    pub fn token(&self) -> &CancelToken {
+        &self.token
+    }
+
+    /// Drops the guard without cancelling the token
+    pub fn disarm(mut self) -> CancelToken {
+        self.armed = false;
+        self.token.clone()
+    }
+}
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if self.armed {
+            self.token.cancel();
+        }
+    }
+}
+
+/// The registration of one reply with a token
+pub(crate) struct Waiter {
+    state: Arc<State>,
+    id: u64,
+}
+impl Waiter {
+    /// Returns whether the token is cancelled, otherwise wakes the task of
+    /// `cx` when it is
+    pub(crate) fn poll_cancelled(&self, cx: &mut Context<'_>) -> bool {
+        if self.state.cancelled.load(Ordering::SeqCst) {
+            return true;
+        }
+        self.state
+            .wakers
+            .lock()
+            .unwrap()
+            .insert(self.id, cx.waker().clone());
+        // `cancel` may have run between the check and the insert
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+}
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        self.state.wakers.lock().unwrap().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_wakes_waiters() {
+        use std::task::Wake;
+
+        struct Flag(AtomicBool);
+        impl Wake for Flag {
+            fn wake(self: Arc<Self>) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let flag = Arc::new(Flag(AtomicBool::new(false)));
+        let waker = Waker::from(flag.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let token = CancelToken::new();
+        let waiter = token.waiter();
+        assert!(!waiter.poll_cancelled(&mut cx));
+        drop(token.guard());
+        assert!(flag.0.load(Ordering::SeqCst));
+        assert!(waiter.poll_cancelled(&mut cx));
+
+        let token = CancelToken::new();
+        token.guard().disarm();
+        assert!(!token.is_cancelled());
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    future::Future,
+    io,
+    os::unix::io::RawFd,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use dbus::{
+    channel::Channel,
+    nonblock::{NonblockReply, Process, SyncConnection},
+};
+
+use crate::Error;
+
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send + Sync + 'static>>;
+
+/// Waits for the socket of the connection and for timers, on the reactor of
+/// an async runtime
+///
+/// `TokioReactor` and `AsyncIoReactor` implement it for the common runtimes.
+/// Other runtimes only need these few methods to drive a connection.
+pub trait Reactor: Unpin + Send + Sized + 'static {
+    /// Registers the socket with the reactor. Called from the first poll of
+    /// the `ConnectionDriver`, so from within the runtime.
+    fn register(fd: RawFd) -> io::Result<Self>;
+
+    /// Returns `Ready` if the socket became readable since the previous call
+    /// that returned `Ready`. Otherwise wakes the task when it does.
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+
+    /// Like `poll_readable`, for writing
+    fn poll_writable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+
+    /// A future that completes at `deadline`, for the timeouts of method
+    /// calls
+    fn sleep_until(deadline: Instant) -> Sleep;
+}
+
+enum WakeState {
+    /// `ready` is set when a message was queued before the driver was
+    /// polled
+    Waiting {
+        ready: bool,
+    },
+    Polled(Waker),
+}
+
+/// The state shared by the bus and its driver
+pub(crate) struct Shared {
+    wake: Mutex<WakeState>,
+    closed: AtomicBool,
+}
+impl Shared {
+    fn new() -> Self {
+        Shared {
+            wake: Mutex::new(WakeState::Waiting { ready: false }),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Makes the driver send the queued messages
+    fn wake(&self) -> Result<(), ()> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(());
+        }
+        let state = std::mem::replace(
+            &mut *self.wake.lock().unwrap(),
+            WakeState::Waiting { ready: true },
+        );
+        if let WakeState::Polled(waker) = state {
+            waker.wake();
+        }
+        Ok(())
+    }
+
+    /// Stops the driver
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        if let WakeState::Polled(waker) = &*self.wake.lock().unwrap() {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Sets up a connection for a driver with the reactor `R`
+pub(crate) fn connect<R: Reactor>(
+    mut channel: Channel,
+) -> (Arc<SyncConnection>, Arc<Shared>, ConnectionDriver<R>) {
+    channel.set_watch_enabled(true);
+    let fd = channel.watch().fd;
+    let shared = Arc::new(Shared::new());
+    let mut conn = SyncConnection::from(channel);
+    conn.set_timeout_maker(Some(R::sleep_until));
+    conn.set_waker(Some(Box::new({
+        let shared = shared.clone();
+        move || shared.wake()
+    })));
+    let conn = Arc::new(conn);
+    let driver = ConnectionDriver {
+        conn: conn.clone(),
+        shared: shared.clone(),
+        fd,
+        reactor: None,
+        write_pending: false,
+    };
+    (conn, shared, driver)
+}
+
+/// The future that reads and writes the messages of an `AsyncBus`
+///
+/// It has to be spawned on the runtime of the reactor `R`. It completes with
+/// `Ok` when the bus is dropped, and with an error when the connection to the
+/// daemon is lost.
+#[must_use = "the connection only works while the driver runs"]
+pub struct ConnectionDriver<R> {
+    conn: Arc<SyncConnection>,
+    shared: Arc<Shared>,
+    fd: RawFd,
+    reactor: Option<R>,
+    write_pending: bool,
+}
+impl<R: Reactor> ConnectionDriver<R> {
+    fn poll_io(&mut self, cx: &mut Context<'_>) -> Result<(), Error> {
+        let reactor = match &mut self.reactor {
+            Some(reactor) => reactor,
+            None => self.reactor.insert(R::register(self.fd)?),
+        };
+        let queued = {
+            let mut state = self.shared.wake.lock().unwrap();
+            let previous = std::mem::replace(&mut *state, WakeState::Polled(cx.waker().clone()));
+            matches!(previous, WakeState::Waiting { ready: true })
+        };
+        let mut readable = reactor.poll_readable(cx)?.is_ready();
+        let mut writable = self.write_pending && reactor.poll_writable(cx)?.is_ready();
+        if !(readable || writable || queued) {
+            return Ok(());
+        }
+        let channel: &Channel = (*self.conn).as_ref();
+        loop {
+            channel
+                .read_write(Some(Duration::ZERO))
+                .map_err(|()| Error::Unknown {
+                    description: "Lost the connection to the IBus daemon".into(),
+                })?;
+            self.conn.process_all();
+
+            self.write_pending = channel.has_messages_to_send();
+            if self.write_pending {
+                writable = reactor.poll_writable(cx)?.is_ready();
+            }
+            // libdbus may leave data in the socket, but the reactor only
+            // reports new data
+            readable = has_data(self.fd) || reactor.poll_readable(cx)?.is_ready();
+            if !(readable || (self.write_pending && writable)) {
+                return Ok(());
+            }
+        }
+    }
+}
+impl<R: Reactor> Future for ConnectionDriver<R> {
+    type Output = Result<(), Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.shared.closed.load(Ordering::SeqCst) {
+            return Poll::Ready(Ok(()));
+        }
+        match self.poll_io(cx) {
+            Ok(()) => Poll::Pending,
+            Err(e) => {
+                self.shared.closed.store(true, Ordering::SeqCst);
+                Poll::Ready(Err(e))
+            }
+        }
+    }
+}
+
+fn has_data(fd: RawFd) -> bool {
+    let mut byte = 0u8;
+    let read = unsafe {
+        libc::recv(
+            fd,
+            &mut byte as *mut u8 as *mut libc::c_void,
+            1,
+            libc::MSG_DONTWAIT | libc::MSG_PEEK,
+        )
+    };
+    read == 1
+}
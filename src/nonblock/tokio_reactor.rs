@@ -0,0 +1,40 @@
+use std::{
+    io,
+    os::unix::io::RawFd,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use tokio::io::{unix::AsyncFd, Interest};
+
+use super::{Reactor, Sleep};
+
+/// Drives the connection on the tokio runtime. Needs the `tokio` feature.
+///
+/// The runtime must have the I/O and time drivers enabled.
+pub struct TokioReactor {
+    fd: AsyncFd<RawFd>,
+}
+impl Reactor for TokioReactor {
+    fn register(fd: RawFd) -> io::Result<Self> {
+        let fd = AsyncFd::with_interest(fd, Interest::READABLE | Interest::WRITABLE)?;
+        Ok(TokioReactor { fd })
+    }
+
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // tokio only reports readiness again after it's cleared
+        self.fd
+            .poll_read_ready(cx)
+            .map_ok(|mut guard| guard.clear_ready())
+    }
+
+    fn poll_writable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.fd
+            .poll_write_ready(cx)
+            .map_ok(|mut guard| guard.clear_ready())
+    }
+
+    fn sleep_until(deadline: Instant) -> Sleep {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+}
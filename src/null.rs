@@ -0,0 +1,279 @@
+use std::time::Duration;
+
+use dbus::{channel::Token, strings::Path};
+use log::info;
+
+use crate::{
+    Bus, Capabilites, EngineDesc, Error, EventQueue, InputContext, Modifiers, PropState, Text,
+};
+
+/// A bus for when IBus isn't available, e.g. in a session without the
+/// daemon or in a sandbox that hides it from the application
+///
+/// It has the same methods as `Bus`. They all succeed, but nothing is ever
+/// received: the input contexts don't handle any key, and never emit
+/// signals. `AnyBus` switches to it when there's no daemon.
+#[derive(Debug, Default)]
+pub struct NullBus;
+impl NullBus {
+    pub fn new() -> Self {
+        NullBus
+    }
+
+    pub fn create_input_context(&self, _name: &str) -> Result<NullInputContext, Error> {
+        Ok(NullInputContext)
+    }
+
+    /// Fails, there's no engine
+    pub fn global_engine(&self) -> Result<EngineDesc, Error> {
+        Err(no_engine())
+    }
+
+    /// Nothing is ever queued
+    pub fn queue_events(&self, _queue: EventQueue) -> Token {
+        Token(0)
+    }
+
+    pub fn remove_queue(&self, _token: Token) {}
+
+    /// Sleeps for `timeout`, like `Bus::process` when there's no message,
+    /// so that loops made for `Bus` don't spin. Returns `Ok(false)`.
+    pub fn process(&self, timeout: Duration) -> Result<bool, Error> {
+        std::thread::sleep(timeout);
+        Ok(false)
+    }
+}
+
+/// The input context of a `NullBus`, it accepts all calls
+#[derive(Debug, Default)]
+pub struct NullInputContext;
+impl NullInputContext {
+    pub fn path(&self) -> Path<'static> {
+        "/".into()
+    }
+
+    pub fn set_capabilities(&self, _caps: Capabilites) {}
+
+    pub fn try_set_capabilities(&self, _caps: Capabilites) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Returns `Ok(false)`, so the application handles every key itself
+    pub fn process_key_event(
+        &self,
+        _sym: u32,
+        _code: u32,
+        _modifiers: Modifiers,
+    ) -> Result<bool, Error> {
+        Ok(false)
+    }
+
+    pub fn set_cursor_location(&self, _x: i32, _y: i32, _w: i32, _h: i32) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn focus_in(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn focus_out(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn reset(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn set_surrounding_text<'a>(
+        &self,
+        _text: impl Into<Text<'a>>,
+        _cursor_pos: u32,
+        _anchor_pos: u32,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn page_up(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn page_down(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn cursor_up(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn cursor_down(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn candidate_clicked(
+        &self,
+        _index: u32,
+        _button: u32,
+        _state: Modifiers,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn property_activate(&self, _name: &str, _state: PropState) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Fails, there's no engine
+    pub fn engine(&self) -> Result<EngineDesc, Error> {
+        Err(no_engine())
+    }
+}
+
+fn no_engine() -> Error {
+    Error::Unknown {
+        description: "IBus isn't available".into(),
+    }
+}
+
+/// Either a connection to the daemon, or a `NullBus` if there's none, so
+/// that an application has one code path whether IBus is present or not
+///
+/// ```no_run
+/// use ibus::{AnyBus, Capabilites, Modifiers};
+///
+/// let bus = AnyBus::connect();
+/// let ctx = bus.create_input_context("my-app").unwrap();
+/// ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+/// if !ctx.process_key_event(ibus::keysyms::KEY_a, 30, Modifiers::empty()).unwrap() {
+///     // Insert the character, as without an input method
+/// }
+/// ```
+pub enum AnyBus {
+    IBus(Bus),
+    Null(NullBus),
+}
+impl AnyBus {
+    /// Connects to the daemon, or returns `AnyBus::Null` if that fails
+    pub fn connect() -> Self {
+        match Bus::new() {
+            Ok(bus) => AnyBus::IBus(bus),
+            Err(e) => {
+                info!("IBus isn't available, input methods are turned off: {}", e);
+                AnyBus::Null(NullBus)
+            }
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, AnyBus::Null(_))
+    }
+
+    pub fn create_input_context(&self, name: &str) -> Result<AnyInputContext, Error> {
+        match self {
+            AnyBus::IBus(bus) => bus.create_input_context(name).map(AnyInputContext::IBus),
+            AnyBus::Null(bus) => bus.create_input_context(name).map(AnyInputContext::Null),
+        }
+    }
+
+    pub fn global_engine(&self) -> Result<EngineDesc, Error> {
+        match self {
+            AnyBus::IBus(bus) => bus.global_engine(),
+            AnyBus::Null(bus) => bus.global_engine(),
+        }
+    }
+
+    pub fn queue_events(&self, queue: EventQueue) -> Token {
+        match self {
+            AnyBus::IBus(bus) => bus.queue_events(queue),
+            AnyBus::Null(bus) => bus.queue_events(queue),
+        }
+    }
+
+    pub fn remove_queue(&self, token: Token) {
+        match self {
+            AnyBus::IBus(bus) => bus.remove_queue(token),
+            AnyBus::Null(bus) => bus.remove_queue(token),
+        }
+    }
+
+    pub fn process(&self, timeout: Duration) -> Result<bool, Error> {
+        match self {
+            AnyBus::IBus(bus) => bus.process(timeout),
+            AnyBus::Null(bus) => bus.process(timeout),
+        }
+    }
+}
+
+/// The input context of an `AnyBus`
+pub enum AnyInputContext {
+    IBus(InputContext),
+    Null(NullInputContext),
+}
+
+macro_rules! delegate {
+    ($( $(#[$attr:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty; )*) => {
+        impl AnyInputContext {
+            $(
+                $(#[$attr])*
+                pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                    match self {
+                        AnyInputContext::IBus(ctx) => ctx.$name($($arg),*),
+                        AnyInputContext::Null(ctx) => ctx.$name($($arg),*),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+delegate! {
+    fn set_capabilities(&self, caps: Capabilites) -> ();
+    /// See `InputContext::try_set_capabilities`
+    fn try_set_capabilities(&self, caps: Capabilites) -> Result<(), Error>;
+    /// See `InputContext::process_key_event`
+    fn process_key_event(&self, sym: u32, code: u32, modifiers: Modifiers) -> Result<bool, Error>;
+    /// See `InputContext::set_cursor_location`
+    fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Result<(), Error>;
+    fn focus_in(&self) -> Result<(), Error>;
+    fn focus_out(&self) -> Result<(), Error>;
+    fn reset(&self) -> Result<(), Error>;
+    fn set_surrounding_text(&self, text: Text<'_>, cursor_pos: u32, anchor_pos: u32) -> Result<(), Error>;
+    fn page_up(&self) -> Result<(), Error>;
+    fn page_down(&self) -> Result<(), Error>;
+    fn cursor_up(&self) -> Result<(), Error>;
+    fn cursor_down(&self) -> Result<(), Error>;
+    /// See `InputContext::candidate_clicked`
+    fn candidate_clicked(&self, index: u32, button: u32, state: Modifiers) -> Result<(), Error>;
+    fn property_activate(&self, name: &str, state: PropState) -> Result<(), Error>;
+    fn engine(&self) -> Result<EngineDesc, Error>;
+}
+
+impl AnyInputContext {
+    pub fn is_null(&self) -> bool {
+        matches!(self, AnyInputContext::Null(_))
+    }
+
+    pub fn path(&self) -> Path<'static> {
+        match self {
+            AnyInputContext::IBus(ctx) => ctx.path().clone(),
+            AnyInputContext::Null(ctx) => ctx.path(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null_context_handles_nothing() {
+        let bus = AnyBus::Null(NullBus::new());
+        let ctx = bus.create_input_context("test").unwrap();
+        assert!(ctx.is_null());
+        ctx.set_capabilities(Capabilites::PREEDIT_TEXT);
+        ctx.focus_in().unwrap();
+        assert!(!ctx.process_key_event(0x61, 30, Modifiers::empty()).unwrap());
+        ctx.set_surrounding_text("abc".into(), 3, 3).unwrap();
+        assert!(ctx.engine().is_err());
+        assert!(!bus.process(Duration::ZERO).unwrap());
+    }
+}
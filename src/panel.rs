@@ -0,0 +1,866 @@
+//! Writing panels
+//!
+//! The panel is the part of IBus that displays what the engines want to
+//! show: the candidate window, the preedit and auxiliary texts (for
+//! applications that don't draw them), and the properties of the engine
+//! (usually in a status bar or a tray icon). The daemon sends all of these
+//! to the program that owns the `org.freedesktop.IBus.Panel` name.
+//!
+//! Implement the `Panel` trait and serve it with a `PanelHost`. The method
+//! calls are handled while calling `Bus::process`. The default panel of the
+//! desktop has to be stopped, or the daemon started with `--panel disable`.
+//!
+//! A panel can also host the emoji and Unicode pickers, see `extension`.
+//!
+
+pub mod extension;
+
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use log::{debug, warn};
+
+use dbus::{
+    blocking::Connection,
+    channel::{MatchingReceiver, Sender, Token},
+    message::MatchRule,
+    strings::Path,
+    Message,
+};
+
+use self::extension::ExtensionEvent;
+use crate::{
+    engine::{error_reply, invalid_args, SERVICE_INTERFACE},
+    global_engine,
+    input_context::input_context_engine,
+    Bus, EngineDesc, Error, InputHints, InputPurpose, LookupTable, Modifiers, PropList, PropState,
+    Property, Rect, Text,
+};
+
+pub(crate) const PANEL_NAME: &str = "org.freedesktop.IBus.Panel";
+pub(crate) const PANEL_INTERFACE: &str = "org.freedesktop.IBus.Panel";
+pub(crate) const PANEL_PATH: &str = "/org/freedesktop/IBus/Panel";
+
+/// A panel, see the module documentation
+///
+/// The methods are called by the daemon for the focused input context. All
+/// of them have default implementations that do nothing, so a panel only
+/// needs to implement what it displays.
+pub trait Panel: Send {
+    /// Called when the engine changes the preedit text. The panel only
+    /// needs to show it if the application doesn't draw it itself.
+    fn update_preedit_text(
+        &mut self,
+        ctx: &mut PanelContext,
+        text: Text<'static>,
+        cursor_pos: u32,
+        visible: bool,
+    ) {
+        let _ = (ctx, text, cursor_pos, visible);
+    }
+
+    fn show_preedit_text(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn hide_preedit_text(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the engine changes the auxiliary text, which is usually
+    /// shown above the candidates
+    fn update_auxiliary_text(
+        &mut self,
+        ctx: &mut PanelContext,
+        text: Text<'static>,
+        visible: bool,
+    ) {
+        let _ = (ctx, text, visible);
+    }
+
+    fn show_auxiliary_text(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn hide_auxiliary_text(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the engine changes the candidates or the cursor in the
+    /// lookup table
+    fn update_lookup_table(&mut self, ctx: &mut PanelContext, table: LookupTable, visible: bool) {
+        let _ = (ctx, table, visible);
+    }
+
+    fn show_lookup_table(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn hide_lookup_table(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the cursor of the lookup table should move, without an
+    /// update of the table. Most engines send `update_lookup_table` instead.
+    fn cursor_up_lookup_table(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn cursor_down_lookup_table(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn page_up_lookup_table(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn page_down_lookup_table(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the engine sets its properties, e.g. the input mode
+    fn register_properties(&mut self, ctx: &mut PanelContext, props: PropList) {
+        let _ = (ctx, props);
+    }
+
+    /// Called when one of the registered properties changes, identified by
+    /// its key
+    fn update_property(&mut self, ctx: &mut PanelContext, prop: Property) {
+        let _ = (ctx, prop);
+    }
+
+    /// Called when the position of the cursor changes, so the candidate
+    /// window can be moved next to it
+    ///
+    /// The coordinates are in physical pixels, relative to the top left
+    /// corner of the screen.
+    fn set_cursor_location(&mut self, ctx: &mut PanelContext, location: &CursorLocation) {
+        let _ = (ctx, location);
+    }
+
+    /// Same as `set_cursor_location`, but the coordinates are relative to the
+    /// window of the application
+    fn set_cursor_location_relative(&mut self, ctx: &mut PanelContext, location: &CursorLocation) {
+        let _ = (ctx, location);
+    }
+
+    /// Called when an input context gets focus. `PanelContext::focused` is
+    /// already updated, and `PanelContext::cursor_location` returns the last
+    /// location reported by this input context, if any.
+    fn focus_in(&mut self, ctx: &mut PanelContext, input_context: &Path<'static>) {
+        let _ = (ctx, input_context);
+    }
+
+    /// Called when an input context loses focus
+    fn focus_out(&mut self, ctx: &mut PanelContext, input_context: &Path<'static>) {
+        let _ = (ctx, input_context);
+    }
+
+    /// Called when an input context is destroyed
+    fn destroy_context(&mut self, ctx: &mut PanelContext, input_context: &Path<'static>) {
+        let _ = (ctx, input_context);
+    }
+
+    /// Called when the focused application describes its text field
+    fn set_content_type(
+        &mut self,
+        ctx: &mut PanelContext,
+        purpose: InputPurpose,
+        hints: InputHints,
+    ) {
+        let _ = (ctx, purpose, hints);
+    }
+
+    /// Called when the state of the focused input context changes, e.g.
+    /// because a different engine was selected. `PanelContext::engine` is
+    /// already updated.
+    fn state_changed(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the engine of the focused input context is different from
+    /// the last one reported, either because of a `state_changed` or a
+    /// `focus_in`. `None` means that input methods are turned off.
+    ///
+    /// This is the place to update a status widget that shows the active
+    /// input method.
+    fn engine_changed(&mut self, ctx: &mut PanelContext, engine: Option<&EngineDesc>) {
+        let _ = (ctx, engine);
+    }
+
+    fn show_language_bar(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    fn hide_language_bar(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+
+    /// Called when the daemon asks the panel to open or close an extension,
+    /// e.g. the emoji picker after the user pressed its hotkey
+    fn panel_extension_received(&mut self, ctx: &mut PanelContext, event: ExtensionEvent) {
+        let _ = (ctx, event);
+    }
+
+    /// Called with the key events of the focused application while an
+    /// extension is open. Return true if the panel handled the key.
+    fn process_key_event(
+        &mut self,
+        ctx: &mut PanelContext,
+        sym: u32,
+        code: u32,
+        modifiers: Modifiers,
+    ) -> bool {
+        let _ = (ctx, sym, code, modifiers);
+        false
+    }
+
+    /// Called when the daemon asks the panel to quit
+    fn destroy(&mut self, ctx: &mut PanelContext) {
+        let _ = ctx;
+    }
+}
+
+/// A signal emitted by a panel
+///
+/// The daemon forwards these to the engine of the focused input context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PanelSignal {
+    CandidateClicked {
+        index: u32,
+        button: u32,
+        state: Modifiers,
+    },
+    PageUp,
+    PageDown,
+    CursorUp,
+    CursorDown,
+    PropertyActivate {
+        name: String,
+        state: PropState,
+    },
+    PropertyShow(String),
+    PropertyHide(String),
+    /// Inserts text into the focused application, e.g. the emoji chosen in
+    /// a picker
+    CommitText(Text<'static>),
+    /// Opens or closes an extension
+    PanelExtension(ExtensionEvent),
+}
+impl PanelSignal {
+    pub(crate) fn to_message(&self) -> Message {
+        let signal = |name: &str| {
+            Message::new_signal(PANEL_PATH, PANEL_INTERFACE, name)
+                .expect("the panel signal name should be valid")
+        };
+        match self {
+            PanelSignal::CandidateClicked {
+                index,
+                button,
+                state,
+            } => signal("CandidateClicked").append3(*index, *button, state.bits()),
+            PanelSignal::PageUp => signal("PageUp"),
+            PanelSignal::PageDown => signal("PageDown"),
+            PanelSignal::CursorUp => signal("CursorUp"),
+            PanelSignal::CursorDown => signal("CursorDown"),
+            PanelSignal::PropertyActivate { name, state } => {
+                signal("PropertyActivate").append2(name, state.to_value())
+            }
+            PanelSignal::PropertyShow(name) => signal("PropertyShow").append1(name),
+            PanelSignal::PropertyHide(name) => signal("PropertyHide").append1(name),
+            PanelSignal::CommitText(text) => signal("CommitText").append1(text),
+            PanelSignal::PanelExtension(event) => signal("PanelExtension").append1(event),
+        }
+    }
+}
+
+/// The position of the cursor in an application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorLocation {
+    /// The input context that reported the location
+    ///
+    /// The daemon doesn't include it in the call, it's the input context that
+    /// had focus. It's `None` if the location arrived before any `FocusIn`.
+    pub input_context: Option<Path<'static>>,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    /// Whether the coordinates are relative to the window of the application
+    /// instead of the screen
+    pub relative: bool,
+}
+impl CursorLocation {
+    /// The caret rectangle, e.g. for `place_popup`
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+}
+
+/// Gives a panel access to the state that the daemon reported, and lets it
+/// act on the focused engine
+///
+/// The signals are sent after the panel method returns, or after the closure
+/// given to `PanelHost::with_panel`.
+#[derive(Debug, Default)]
+pub struct PanelContext {
+    focused: Option<Path<'static>>,
+    cursor_locations: HashMap<Path<'static>, CursorLocation>,
+    engines: HashMap<Path<'static>, EngineDesc>,
+    global_engine: Option<EngineDesc>,
+    /// The name of the engine last given to `Panel::engine_changed`
+    reported_engine: Option<String>,
+    /// The location reported without a focused input context
+    unfocused_location: Option<CursorLocation>,
+    pending: Vec<PanelSignal>,
+}
+impl PanelContext {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// The object path of the input context that has focus
+    pub fn focused(&self) -> Option<&Path<'static>> {
+        self.focused.as_ref()
+    }
+
+    /// The engine of the focused input context
+    pub fn engine(&self) -> Option<&EngineDesc> {
+        self.engines.get(self.focused.as_ref()?)
+    }
+
+    /// The engine of an input context, as of its last `focus_in` or
+    /// `state_changed`
+    pub fn engine_of(&self, input_context: &Path<'static>) -> Option<&EngineDesc> {
+        self.engines.get(input_context)
+    }
+
+    /// The engine that is shared by all input contexts, unless the user
+    /// configured IBus to use a separate engine for each window
+    pub fn global_engine(&self) -> Option<&EngineDesc> {
+        self.global_engine.as_ref()
+    }
+
+    /// The last cursor location reported by the focused input context
+    pub fn cursor_location(&self) -> Option<&CursorLocation> {
+        match &self.focused {
+            Some(path) => self.cursor_locations.get(path),
+            None => self.unfocused_location.as_ref(),
+        }
+    }
+
+    /// The last cursor location reported by an input context that hasn't been
+    /// destroyed yet
+    pub fn cursor_location_of(&self, input_context: &Path<'static>) -> Option<&CursorLocation> {
+        self.cursor_locations.get(input_context)
+    }
+
+    fn set_cursor_location(&mut self, location: CursorLocation) {
+        match &self.focused {
+            Some(path) => {
+                self.cursor_locations.insert(path.clone(), location);
+            }
+            None => self.unfocused_location = Some(location),
+        }
+    }
+
+    /// Tells the engine that the user clicked a candidate. The index is
+    /// relative to the current page of the lookup table, and `button` is
+    /// the mouse button (1 for the primary button).
+    pub fn candidate_clicked(&mut self, index: u32, button: u32, state: Modifiers) {
+        self.pending.push(PanelSignal::CandidateClicked {
+            index,
+            button,
+            state,
+        });
+    }
+
+    /// Asks the engine to show the previous page of candidates
+    pub fn page_up(&mut self) {
+        self.pending.push(PanelSignal::PageUp);
+    }
+
+    /// Asks the engine to show the next page of candidates
+    pub fn page_down(&mut self) {
+        self.pending.push(PanelSignal::PageDown);
+    }
+
+    /// Asks the engine to move the cursor of the lookup table up
+    pub fn cursor_up(&mut self) {
+        self.pending.push(PanelSignal::CursorUp);
+    }
+
+    /// Asks the engine to move the cursor of the lookup table down
+    pub fn cursor_down(&mut self) {
+        self.pending.push(PanelSignal::CursorDown);
+    }
+
+    /// Tells the engine that the user activated one of its properties, e.g.
+    /// by clicking a menu item. `state` is the new state for toggle and
+    /// radio properties.
+    pub fn property_activate(&mut self, name: impl Into<String>, state: PropState) {
+        self.pending.push(PanelSignal::PropertyActivate {
+            name: name.into(),
+            state,
+        });
+    }
+
+    /// Tells the engine that the panel started showing one of its
+    /// properties
+    pub fn property_show(&mut self, name: impl Into<String>) {
+        self.pending.push(PanelSignal::PropertyShow(name.into()));
+    }
+
+    /// Tells the engine that the panel stopped showing one of its properties
+    pub fn property_hide(&mut self, name: impl Into<String>) {
+        self.pending.push(PanelSignal::PropertyHide(name.into()));
+    }
+
+    /// Inserts text into the focused application
+    pub fn commit_text(&mut self, text: impl Into<Text<'static>>) {
+        self.pending.push(PanelSignal::CommitText(text.into()));
+    }
+
+    /// Asks the daemon to open or close an extension, e.g. to close the
+    /// picker when the user dismisses it. The daemon stops forwarding the key
+    /// events when it's closed.
+    pub fn panel_extension(&mut self, event: ExtensionEvent) {
+        self.pending.push(PanelSignal::PanelExtension(event));
+    }
+
+    pub(crate) fn take_signals(&mut self) -> Vec<PanelSignal> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+pub(crate) struct PanelObject<P> {
+    pub(crate) panel: P,
+    pub(crate) ctx: PanelContext,
+}
+impl<P: Panel> PanelObject<P> {
+    pub(crate) fn new(panel: P) -> Self {
+        PanelObject {
+            panel,
+            ctx: PanelContext::new(),
+        }
+    }
+
+    /// Calls the panel method that corresponds to the method call message,
+    /// and returns the reply
+    pub(crate) fn dispatch(&mut self, msg: &Message) -> Message {
+        let interface = msg.interface();
+        let member = msg.member();
+        let panel = &mut self.panel;
+        let ctx = &mut self.ctx;
+        match (interface.as_deref(), member.as_deref()) {
+            (Some(PANEL_INTERFACE), Some("UpdatePreeditText")) => {
+                let (text, cursor_pos, visible): (Text, u32, bool) = match msg.read3() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                panel.update_preedit_text(ctx, text, cursor_pos, visible);
+            }
+            (Some(PANEL_INTERFACE), Some("UpdateAuxiliaryText")) => {
+                let (text, visible): (Text, bool) = match msg.read2() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                panel.update_auxiliary_text(ctx, text, visible);
+            }
+            (Some(PANEL_INTERFACE), Some("UpdateLookupTable")) => {
+                let (table, visible): (LookupTable, bool) = match msg.read2() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                panel.update_lookup_table(ctx, table, visible);
+            }
+            (Some(PANEL_INTERFACE), Some("RegisterProperties")) => {
+                let props: PropList = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                panel.register_properties(ctx, props);
+            }
+            (Some(PANEL_INTERFACE), Some("UpdateProperty")) => {
+                let prop: Property = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                panel.update_property(ctx, prop);
+            }
+            (Some(PANEL_INTERFACE), Some(method @ "SetCursorLocation"))
+            | (Some(PANEL_INTERFACE), Some(method @ "SetCursorLocationRelative")) => {
+                let (x, y, width, height): (i32, i32, i32, i32) = match msg.read4() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let location = CursorLocation {
+                    input_context: ctx.focused.clone(),
+                    x,
+                    y,
+                    width,
+                    height,
+                    relative: method == "SetCursorLocationRelative",
+                };
+                ctx.set_cursor_location(location.clone());
+                if location.relative {
+                    panel.set_cursor_location_relative(ctx, &location);
+                } else {
+                    panel.set_cursor_location(ctx, &location);
+                }
+            }
+            (Some(PANEL_INTERFACE), Some(method @ "FocusIn"))
+            | (Some(PANEL_INTERFACE), Some(method @ "FocusOut"))
+            | (Some(PANEL_INTERFACE), Some(method @ "DestroyContext")) => {
+                let path: Path = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let path = path.into_static();
+                match method {
+                    "FocusIn" => {
+                        ctx.focused = Some(path.clone());
+                        panel.focus_in(ctx, &path);
+                    }
+                    "FocusOut" => {
+                        if ctx.focused.as_ref() == Some(&path) {
+                            ctx.focused = None;
+                        }
+                        panel.focus_out(ctx, &path);
+                    }
+                    _ => {
+                        if ctx.focused.as_ref() == Some(&path) {
+                            ctx.focused = None;
+                        }
+                        ctx.cursor_locations.remove(&path);
+                        ctx.engines.remove(&path);
+                        panel.destroy_context(ctx, &path);
+                    }
+                }
+            }
+            (Some(PANEL_INTERFACE), Some("SetContentType")) => {
+                let (purpose, hints): (u32, u32) = match msg.read2() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let purpose = InputPurpose::from_value(purpose).unwrap_or_else(|| {
+                    debug!("Unexpected input purpose `{}`", purpose);
+                    InputPurpose::FreeForm
+                });
+                panel.set_content_type(ctx, purpose, InputHints::from_bits_truncate(hints));
+            }
+            (Some(PANEL_INTERFACE), Some("ProcessKeyEvent")) => {
+                let (sym, code, modifiers): (u32, u32, u32) = match msg.read3() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                let modifiers = Modifiers::from_bits_truncate(modifiers);
+                let handled = panel.process_key_event(ctx, sym, code, modifiers);
+                return msg.method_return().append1(handled);
+            }
+            (Some(PANEL_INTERFACE), Some("PanelExtensionReceived")) => {
+                let event: ExtensionEvent = match msg.read1() {
+                    Ok(args) => args,
+                    Err(e) => return invalid_args(msg, e),
+                };
+                panel.panel_extension_received(ctx, event);
+            }
+            (Some(PANEL_INTERFACE), Some(method)) => match method {
+                "ShowPreeditText" => panel.show_preedit_text(ctx),
+                "HidePreeditText" => panel.hide_preedit_text(ctx),
+                "ShowAuxiliaryText" => panel.show_auxiliary_text(ctx),
+                "HideAuxiliaryText" => panel.hide_auxiliary_text(ctx),
+                "ShowLookupTable" => panel.show_lookup_table(ctx),
+                "HideLookupTable" => panel.hide_lookup_table(ctx),
+                "CursorUpLookupTable" => panel.cursor_up_lookup_table(ctx),
+                "CursorDownLookupTable" => panel.cursor_down_lookup_table(ctx),
+                "PageUpLookupTable" => panel.page_up_lookup_table(ctx),
+                "PageDownLookupTable" => panel.page_down_lookup_table(ctx),
+                "StateChanged" => panel.state_changed(ctx),
+                "ShowLanguageBar" => panel.show_language_bar(ctx),
+                "HideLanguageBar" => panel.hide_language_bar(ctx),
+                // The rest of the methods are acknowledged without doing
+                // anything
+                _ => debug!("Ignoring the panel method {}", method),
+            },
+            (Some(SERVICE_INTERFACE), Some("Destroy")) => panel.destroy(ctx),
+            _ => {
+                return error_reply(
+                    msg,
+                    "org.freedesktop.DBus.Error.UnknownMethod",
+                    format!("Unknown method {:?}.{:?}", interface, member),
+                )
+            }
+        }
+        msg.method_return()
+    }
+
+    /// Handles a method call from the daemon, including the queries about the
+    /// engines that the call needs
+    pub(crate) fn handle(&mut self, msg: &Message, conn: &Connection) -> Message {
+        let input_context = match (msg.interface().as_deref(), msg.member().as_deref()) {
+            (Some(PANEL_INTERFACE), Some("FocusIn")) => {
+                msg.read1::<Path>().ok().map(Path::into_static)
+            }
+            (Some(PANEL_INTERFACE), Some("StateChanged")) => self.ctx.focused.clone(),
+            _ => None,
+        };
+        if let Some(path) = &input_context {
+            self.query_engines(conn, path);
+        }
+        let reply = self.dispatch(msg);
+        if input_context.is_some() {
+            self.report_engine();
+        }
+        reply
+    }
+
+    fn query_engines(&mut self, conn: &Connection, input_context: &Path<'static>) {
+        match input_context_engine(conn, input_context) {
+            Ok(desc) => {
+                self.ctx.engines.insert(input_context.clone(), desc);
+            }
+            Err(e) => {
+                debug!("No engine for {}: {}", input_context, e);
+                self.ctx.engines.remove(input_context);
+            }
+        }
+        match global_engine(conn) {
+            Ok(desc) => self.ctx.global_engine = Some(desc),
+            Err(e) => debug!("No global engine: {}", e),
+        }
+    }
+
+    /// Calls `Panel::engine_changed` if the engine of the focused input
+    /// context is a different one
+    pub(crate) fn report_engine(&mut self) {
+        let name = self.ctx.engine().map(|desc| desc.name.clone());
+        if name != self.ctx.reported_engine {
+            self.ctx.reported_engine = name;
+            let engine = self.ctx.engine().cloned();
+            self.panel.engine_changed(&mut self.ctx, engine.as_ref());
+        }
+    }
+
+    /// Sends the signals emitted by the panel
+    pub(crate) fn flush(&mut self, conn: &Connection) {
+        for signal in self.ctx.take_signals() {
+            if conn.send(signal.to_message()).is_err() {
+                warn!("Failed to send the panel signal {:?}", signal);
+            }
+        }
+    }
+}
+
+/// Serves a `Panel` on the bus
+///
+/// The panel is removed from the bus when the host is dropped.
+pub struct PanelHost<P> {
+    conn: Rc<Connection>,
+    object: Arc<Mutex<PanelObject<P>>>,
+    token: Token,
+}
+impl<P: Panel + 'static> PanelHost<P> {
+    /// Exports the panel, and takes over the panel name from the current
+    /// panel of the session
+    pub fn new(bus: &Bus, panel: P) -> Result<Self, Error> {
+        let object = Arc::new(Mutex::new(PanelObject::new(panel)));
+        let rule = MatchRule::new_method_call().with_path(PANEL_PATH);
+        let token = bus.conn.start_receive(rule, {
+            let object = object.clone();
+            Box::new(move |msg, conn| {
+                let mut object = object.lock().unwrap();
+                let reply = object.handle(&msg, conn);
+                if !msg.get_no_reply() && conn.send(reply).is_err() {
+                    warn!("Failed to send the reply to {:?}", msg.member());
+                }
+                object.flush(conn);
+                true
+            })
+        });
+        let host = PanelHost {
+            conn: bus.conn.clone(),
+            object,
+            token,
+        };
+        bus.request_name(PANEL_NAME)?;
+        Ok(host)
+    }
+
+    /// Calls `f` with the panel outside of a method call, e.g. when the user
+    /// interacts with its window. The signals emitted through the context are
+    /// sent when `f` returns.
+    ///
+    /// ```no_run
+    /// # use ibus::{Bus, panel::{Panel, PanelHost}};
+    /// # struct MyPanel;
+    /// # impl Panel for MyPanel {}
+    /// # let bus = Bus::new().unwrap();
+    /// let host = PanelHost::new(&bus, MyPanel).unwrap();
+    /// // The user clicked the "next page" button of the candidate window
+    /// host.with_panel(|_panel, ctx| ctx.page_down());
+    /// ```
+    pub fn with_panel<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut P, &mut PanelContext) -> R,
+    {
+        let mut object = self.object.lock().unwrap();
+        let result = {
+            let object = &mut *object;
+            f(&mut object.panel, &mut object.ctx)
+        };
+        object.flush(&self.conn);
+        result
+    }
+}
+impl<P> Drop for PanelHost<P> {
+    fn drop(&mut self) {
+        self.conn.stop_receive(self.token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records the calls
+    #[derive(Default)]
+    struct Recorder {
+        log: Arc<Mutex<Vec<String>>>,
+    }
+    impl Panel for Recorder {
+        fn update_lookup_table(
+            &mut self,
+            _ctx: &mut PanelContext,
+            table: LookupTable,
+            visible: bool,
+        ) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("table {} {}", table.candidates.len(), visible));
+        }
+
+        fn focus_in(&mut self, _ctx: &mut PanelContext, input_context: &Path<'static>) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("focus {}", input_context));
+        }
+
+        fn page_down_lookup_table(&mut self, ctx: &mut PanelContext) {
+            ctx.page_down();
+        }
+
+        fn engine_changed(&mut self, _ctx: &mut PanelContext, engine: Option<&EngineDesc>) {
+            let name = engine.map_or("none", |desc| desc.name.as_str());
+            self.log.lock().unwrap().push(format!("engine {}", name));
+        }
+    }
+
+    fn call(method: &str) -> Message {
+        let mut msg = Message::new_method_call("a.b", PANEL_PATH, PANEL_INTERFACE, method).unwrap();
+        msg.set_serial(1);
+        msg
+    }
+
+    #[test]
+    fn dispatch_panel_methods() {
+        let recorder = Recorder::default();
+        let log = recorder.log.clone();
+        let mut object = PanelObject::new(recorder);
+        let mut table = LookupTable::default();
+        table.append_candidate("a");
+        table.append_candidate("b");
+        let ic = Path::from("/org/freedesktop/IBus/InputContext_1");
+        object.dispatch(&call("FocusIn").append1(&ic));
+        assert_eq!(object.ctx.focused(), Some(&ic));
+        object.dispatch(&call("UpdateLookupTable").append2(&table, true));
+        let reply = object.dispatch(&call("ProcessKeyEvent").append3(0u32, 0u32, 0u32));
+        assert!(!reply.read1::<bool>().unwrap());
+        object.dispatch(&call("PageDownLookupTable"));
+        object.ctx.candidate_clicked(1, 1, Modifiers::empty());
+        object
+            .ctx
+            .property_activate("InputMode", PropState::Checked);
+        assert_eq!(
+            object.ctx.take_signals(),
+            [
+                PanelSignal::PageDown,
+                PanelSignal::CandidateClicked {
+                    index: 1,
+                    button: 1,
+                    state: Modifiers::empty()
+                },
+                PanelSignal::PropertyActivate {
+                    name: "InputMode".into(),
+                    state: PropState::Checked
+                },
+            ]
+        );
+        let signal = PanelSignal::PropertyActivate {
+            name: "InputMode".into(),
+            state: PropState::Checked,
+        }
+        .to_message();
+        assert_eq!(signal.read2::<&str, u32>().unwrap(), ("InputMode", 1));
+        object.dispatch(&call("SetCursorLocation").append2(10, 20).append2(1, 16));
+        object.dispatch(&call("FocusOut").append1(&ic));
+        assert_eq!(object.ctx.focused(), None);
+        assert_eq!(object.ctx.cursor_location(), None);
+
+        let other = Path::from("/org/freedesktop/IBus/InputContext_2");
+        object.dispatch(&call("FocusIn").append1(&other));
+        object.dispatch(
+            &call("SetCursorLocationRelative")
+                .append2(1, 2)
+                .append2(1, 16),
+        );
+        object.dispatch(&call("FocusIn").append1(&ic));
+        let location = object.ctx.cursor_location().unwrap();
+        assert_eq!(location.input_context.as_ref(), Some(&ic));
+        assert_eq!((location.x, location.y, location.relative), (10, 20, false));
+        object.dispatch(&call("DestroyContext").append1(&other));
+        assert_eq!(object.ctx.cursor_location_of(&other), None);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "focus /org/freedesktop/IBus/InputContext_1",
+                "table 2 true",
+                "focus /org/freedesktop/IBus/InputContext_2",
+                "focus /org/freedesktop/IBus/InputContext_1",
+            ]
+        );
+    }
+
+    #[test]
+    fn engine_changes_are_reported_once() {
+        let recorder = Recorder::default();
+        let log = recorder.log.clone();
+        let mut object = PanelObject::new(recorder);
+        let ic = Path::from("/org/freedesktop/IBus/InputContext_1");
+        object.dispatch(&call("FocusIn").append1(&ic));
+        let desc = EngineDesc {
+            name: "xkb:us::eng".into(),
+            ..Default::default()
+        };
+        object.ctx.engines.insert(ic.clone(), desc);
+        object.report_engine();
+        object.report_engine();
+        assert_eq!(object.ctx.engine().unwrap().name, "xkb:us::eng");
+        object.ctx.engines.clear();
+        object.report_engine();
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "focus /org/freedesktop/IBus/InputContext_1",
+                "engine xkb:us::eng",
+                "engine none",
+            ]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Panel extensions
+//!
+//! IBus implements the emoji and Unicode pickers as panel extensions. When
+//! the user presses the hotkey of a picker, the daemon sends an
+//! `ExtensionEvent` to the panel with `Panel::panel_extension_received`.
+//! While the picker is open the daemon also forwards the key events of the
+//! focused application to the panel (`Panel::process_key_event`), and the
+//! panel inserts the chosen text with `PanelContext::commit_text`.
+//!
+
+use std::any::Any;
+
+use log::debug;
+
+use dbus::arg::{Append, Arg, ArgType, Get, IterAppend, PropMap, RefArg};
+
+const EXTENSION_EVENT_NAME: &str = "IBusExtensionEvent";
+const EXTENSION_EVENT_SIGNATURE: &str = "(sa{sv}usbbs)\u{0}";
+const EXTENSION_EVENT_VERSION: u32 = 1;
+
+/// The extensions known by the daemon
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExtensionKind {
+    Emoji,
+    Unicode,
+    Other(String),
+}
+impl ExtensionKind {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "emoji" => Self::Emoji,
+            "unicode" => Self::Unicode,
+            _ => Self::Other(name.to_owned()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Emoji => "emoji",
+            Self::Unicode => "unicode",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+/// Turns a panel extension on or off (`IBusExtensionEvent`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExtensionEvent {
+    pub kind: ExtensionKind,
+    /// Whether the extension should be opened, or closed
+    pub is_enabled: bool,
+    /// Whether the event was sent by the extension itself, rather than by
+    /// the daemon because of a hotkey
+    pub is_extension: bool,
+    /// Extension specific parameters, e.g. the text to search for
+    pub params: String,
+}
+impl ExtensionEvent {
+    pub fn new(kind: ExtensionKind, is_enabled: bool) -> Self {
+        ExtensionEvent {
+            kind,
+            is_enabled,
+            is_extension: false,
+            params: String::new(),
+        }
+    }
+}
+
+impl RefArg for ExtensionEvent {
+    fn arg_type(&self) -> ArgType {
+        ArgType::Variant
+    }
+
+    fn signature(&self) -> dbus::Signature<'static> {
+        <Self as Arg>::signature()
+    }
+
+    fn append(&self, i: &mut IterAppend) {
+        i.append_variant(&dbus::Signature::from(EXTENSION_EVENT_SIGNATURE), |i| {
+            i.append_struct(|i| {
+                i.append(EXTENSION_EVENT_NAME);
+                i.append(PropMap::new());
+                i.append(EXTENSION_EVENT_VERSION);
+                i.append(self.kind.name());
+                i.append(self.is_enabled);
+                i.append(self.is_extension);
+                i.append(self.params.as_str());
+            })
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    fn box_clone(&self) -> Box<dyn RefArg + 'static> {
+        Box::new(self.clone())
+    }
+}
+impl Arg for ExtensionEvent {
+    const ARG_TYPE: ArgType = ArgType::Variant;
+
+    fn signature() -> dbus::Signature<'static> {
+        dbus::Signature::from("v\u{0}")
+    }
+}
+impl Append for ExtensionEvent {
+    fn append_by_ref(&self, i: &mut IterAppend) {
+        <Self as RefArg>::append(self, i);
+    }
+}
+impl<'a> Get<'a> for ExtensionEvent {
+    fn get(i: &mut dbus::arg::Iter<'a>) -> Option<Self> {
+        let mut variant = i.recurse(ArgType::Variant)?;
+        let mut s = variant.recurse(ArgType::Struct)?;
+        let struct_name: &str = s.read().ok()?;
+        if struct_name != EXTENSION_EVENT_NAME {
+            debug!("Extension event didn't have the expected name.");
+            return None;
+        }
+        let _: PropMap = s.read().ok()?;
+        let version: u32 = s.read().ok()?;
+        if version != EXTENSION_EVENT_VERSION {
+            debug!("Unexpected extension event version `{}`", version);
+        }
+        let name: &str = s.read().ok()?;
+        Some(ExtensionEvent {
+            kind: ExtensionKind::from_name(name),
+            is_enabled: s.read().ok()?,
+            is_extension: s.read().ok()?,
+            params: s.read().ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbus::Message;
+
+    #[test]
+    fn serialization_round_trip() {
+        let event = ExtensionEvent {
+            params: "smile".into(),
+            ..ExtensionEvent::new(ExtensionKind::Emoji, true)
+        };
+        let msg = Message::new_signal("/a", "a.b", "C")
+            .unwrap()
+            .append1(&event);
+        assert_eq!(msg.read1::<ExtensionEvent>().unwrap(), event);
+        assert_eq!(ExtensionKind::from_name("unicode"), ExtensionKind::Unicode);
+    }
+}
@@ -0,0 +1,341 @@
+//! IBusProperty and IBusPropList
+//!
+//! Properties are the items that an engine shows in the panel, for example
+//! an input mode toggle or a menu for choosing the keyboard layout.
+//!
+
+use std::any::Any;
+
+use log::debug;
+
+use dbus::arg::{Append, Arg, ArgType, Get, IterAppend, PropMap, RefArg};
+
+use crate::Text;
+
+const PROPERTY_NAME: &str = "IBusProperty";
+const PROPERTY_SIGNATURE: &str = "(sa{sv}suvsvbbuvv)";
+const PROP_LIST_NAME: &str = "IBusPropList";
+const PROP_LIST_SIGNATURE: &str = "(sa{sv}av)";
+
+/// The naming follows the IBus C API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropType {
+    Normal,
+    Toggle,
+    Radio,
+    /// A property with sub properties
+    Menu,
+    Separator,
+}
+impl PropType {
+    fn to_value(self) -> u32 {
+        match self {
+            Self::Normal => 0,
+            Self::Toggle => 1,
+            Self::Radio => 2,
+            Self::Menu => 3,
+            Self::Separator => 4,
+        }
+    }
+
+    fn from_value(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(Self::Normal),
+            1 => Some(Self::Toggle),
+            2 => Some(Self::Radio),
+            3 => Some(Self::Menu),
+            4 => Some(Self::Separator),
+            _ => None,
+        }
+    }
+}
+
+/// The naming follows the IBus C API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropState {
+    Unchecked,
+    Checked,
+    Inconsistent,
+}
+impl PropState {
+    pub(crate) fn to_value(self) -> u32 {
+        match self {
+            Self::Unchecked => 0,
+            Self::Checked => 1,
+            Self::Inconsistent => 2,
+        }
+    }
+
+    pub(crate) fn from_value(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(Self::Unchecked),
+            1 => Some(Self::Checked),
+            2 => Some(Self::Inconsistent),
+            _ => None,
+        }
+    }
+}
+
+/// An item shown in the panel
+/// The key of the property that shows the current input mode of an engine
+///
+/// GNOME Shell displays the symbol (or the label) of this property in the top
+/// bar, and the IBus panel shows its icon when the `icon_prop_key` of the
+/// engine is set to this key. See `EngineContext::set_input_mode`.
+pub const INPUT_MODE_PROP: &str = "InputMode";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Property {
+    /// Identifies the property, e.g. when it's activated
+    pub key: String,
+    pub prop_type: PropType,
+    pub label: Text<'static>,
+
+    /// An icon name from the icon theme or a path to an icon file
+    pub icon: String,
+    pub tooltip: Text<'static>,
+    pub sensitive: bool,
+    pub visible: bool,
+    pub state: PropState,
+
+    /// The items of a `Menu` property
+    pub sub_props: PropList,
+
+    /// A short text that can be displayed instead of the icon
+    pub symbol: Text<'static>,
+}
+impl Property {
+    /// Creates a visible and sensitive property without a label
+    pub fn new(key: impl Into<String>, prop_type: PropType) -> Self {
+        Property {
+            key: key.into(),
+            prop_type,
+            label: Text::from(String::new()),
+            icon: String::new(),
+            tooltip: Text::from(String::new()),
+            sensitive: true,
+            visible: true,
+            state: PropState::Unchecked,
+            sub_props: PropList::default(),
+            symbol: Text::from(String::new()),
+        }
+    }
+
+    /// Creates the `INPUT_MODE_PROP` property
+    ///
+    /// To let the user switch modes from the panel, make it a `Menu` and add
+    /// the modes as `Radio` sub properties.
+    pub fn input_mode(symbol: impl Into<Text<'static>>, label: impl Into<Text<'static>>) -> Self {
+        let mut prop = Self::new(INPUT_MODE_PROP, PropType::Normal);
+        prop.symbol = symbol.into();
+        prop.label = label.into();
+        prop
+    }
+
+    fn append_struct(&self, i: &mut IterAppend) {
+        i.append_struct(|i| {
+            i.append(PROPERTY_NAME);
+            i.append(PropMap::new());
+            i.append(self.key.as_str());
+            i.append(self.prop_type.to_value());
+            i.append(&self.label);
+            i.append(self.icon.as_str());
+            i.append(&self.tooltip);
+            i.append(self.sensitive);
+            i.append(self.visible);
+            i.append(self.state.to_value());
+            i.append(&self.sub_props);
+            i.append(&self.symbol);
+        })
+    }
+}
+
+/// A list of properties
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PropList {
+    pub properties: Vec<Property>,
+}
+impl PropList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, property: Property) {
+        self.properties.push(property);
+    }
+
+    /// Returns the property with the given key, searching the sub properties
+    /// as well
+    pub fn get(&self, key: &str) -> Option<&Property> {
+        self.properties.iter().find_map(|p| {
+            if p.key == key {
+                Some(p)
+            } else {
+                p.sub_props.get(key)
+            }
+        })
+    }
+
+    /// Replaces the property (or sub property) that has the same key as
+    /// `property`. Returns false if there's no such property.
+    pub fn update_property(&mut self, property: &Property) -> bool {
+        for p in &mut self.properties {
+            if p.key == property.key {
+                *p = property.clone();
+                return true;
+            }
+            if p.sub_props.update_property(property) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+macro_rules! impl_variant_arg {
+    ($t:ty, $signature:expr, $append:expr) => {
+        impl RefArg for $t {
+            fn arg_type(&self) -> ArgType {
+                ArgType::Variant
+            }
+
+            fn signature(&self) -> dbus::Signature<'static> {
+                <Self as Arg>::signature()
+            }
+
+            fn append(&self, i: &mut IterAppend) {
+                i.append_variant(&dbus::Signature::from($signature), |i| $append(self, i))
+            }
+
+            fn as_any(&self) -> &dyn Any
+            where
+                Self: 'static,
+            {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any
+            where
+                Self: 'static,
+            {
+                self
+            }
+
+            fn box_clone(&self) -> Box<dyn RefArg + 'static> {
+                Box::new(self.clone())
+            }
+        }
+        impl Arg for $t {
+            const ARG_TYPE: ArgType = ArgType::Variant;
+
+            fn signature() -> dbus::Signature<'static> {
+                dbus::Signature::from("v\u{0}")
+            }
+        }
+        impl Append for $t {
+            fn append_by_ref(&self, i: &mut IterAppend) {
+                <Self as RefArg>::append(self, i);
+            }
+        }
+    };
+}
+
+impl_variant_arg!(Property, PROPERTY_SIGNATURE, Property::append_struct);
+impl_variant_arg!(
+    PropList,
+    PROP_LIST_SIGNATURE,
+    |list: &PropList, i: &mut IterAppend| {
+        i.append_struct(|i| {
+            i.append(PROP_LIST_NAME);
+            i.append(PropMap::new());
+            i.append(list.properties.clone());
+        })
+    }
+);
+
+impl<'a> Get<'a> for Property {
+    fn get(i: &mut dbus::arg::Iter<'a>) -> Option<Self> {
+        let mut variant = i.recurse(ArgType::Variant)?;
+        let mut s = variant.recurse(ArgType::Struct)?;
+        let struct_name: &str = s.read().ok()?;
+        if struct_name != PROPERTY_NAME {
+            debug!("Property didn't have the expected name.");
+            return None;
+        }
+        let _: PropMap = s.read().ok()?;
+        let key = s.read().ok()?;
+        let prop_type: u32 = s.read().ok()?;
+        let prop_type = PropType::from_value(prop_type).unwrap_or_else(|| {
+            debug!("Unexpected property type `{}`", prop_type);
+            PropType::Normal
+        });
+        let label = s.read().ok()?;
+        let icon = s.read().ok()?;
+        let tooltip = s.read().ok()?;
+        let sensitive = s.read().ok()?;
+        let visible = s.read().ok()?;
+        let state: u32 = s.read().ok()?;
+        let state = PropState::from_value(state).unwrap_or_else(|| {
+            debug!("Unexpected property state `{}`", state);
+            PropState::Unchecked
+        });
+        let sub_props = s.read().ok()?;
+        // Older versions of IBus don't send the symbol
+        let symbol = s.read().unwrap_or_else(|_| Text::from(String::new()));
+        Some(Property {
+            key,
+            prop_type,
+            label,
+            icon,
+            tooltip,
+            sensitive,
+            visible,
+            state,
+            sub_props,
+            symbol,
+        })
+    }
+}
+impl<'a> Get<'a> for PropList {
+    fn get(i: &mut dbus::arg::Iter<'a>) -> Option<Self> {
+        let mut variant = i.recurse(ArgType::Variant)?;
+        let mut s = variant.recurse(ArgType::Struct)?;
+        let struct_name: &str = s.read().ok()?;
+        if struct_name != PROP_LIST_NAME {
+            debug!("Property list didn't have the expected name.");
+            return None;
+        }
+        let _: PropMap = s.read().ok()?;
+        Some(PropList {
+            properties: s.read().ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_round_trip() {
+        let mut menu = Property::new("InputMode", PropType::Menu);
+        menu.label = "Input mode".into();
+        let mut hiragana = Property::new("InputMode.Hiragana", PropType::Radio);
+        hiragana.state = PropState::Checked;
+        hiragana.symbol = "あ".into();
+        menu.sub_props.push(hiragana);
+        let mut list = PropList::new();
+        list.push(menu);
+        list.push(Property::new("Separator", PropType::Separator));
+
+        let msg = dbus::Message::new_method_call("a.b", "/a/b", "a.b", "C")
+            .unwrap()
+            .append1(list.clone());
+        let read: PropList = msg.read1().unwrap();
+        assert_eq!(read, list);
+        assert_eq!(
+            read.get("InputMode.Hiragana").map(|p| p.state),
+            Some(PropState::Checked)
+        );
+    }
+}
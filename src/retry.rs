@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use log::debug;
+
+use crate::Error;
+
+/// The D-Bus errors that mean the daemon didn't answer in time, rather than
+/// that it refused the call
+const TRANSIENT_ERRORS: &[&str] = &[
+    "org.freedesktop.DBus.Error.NoReply",
+    "org.freedesktop.DBus.Error.Timeout",
+    "org.freedesktop.DBus.Error.TimedOut",
+    "org.freedesktop.DBus.Error.LimitsExceeded",
+];
+
+/// How the input contexts retry the calls that are safe to repeat, when the
+/// daemon doesn't answer in time
+///
+/// Only `SetCapabilities`, `SetCursorLocation`, `FocusIn` and `FocusOut` are
+/// retried, because calling them twice has the same effect as calling them
+/// once. The calling thread sleeps between the attempts, starting with
+/// `backoff` and doubling it up to `max_backoff`.
+///
+/// The default is `RetryPolicy::NONE`, which makes a single attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Including the first one, so 1 means no retries
+    pub max_attempts: u32,
+    pub backoff: Duration,
+    pub max_backoff: Duration,
+}
+impl RetryPolicy {
+    pub const NONE: Self = RetryPolicy {
+        max_attempts: 1,
+        backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+
+    pub fn new(max_attempts: u32, backoff: Duration) -> Self {
+        RetryPolicy {
+            max_attempts,
+            backoff,
+            max_backoff: backoff * 8,
+        }
+    }
+
+    /// Calls `f` until it succeeds, fails with an error that isn't
+    /// transient, or the attempts run out
+    pub(crate) fn run<R>(&self, mut f: impl FnMut() -> Result<R, Error>) -> Result<R, Error> {
+        let mut backoff = self.backoff;
+        let mut attempt = 1;
+        loop {
+            match f() {
+                Err(e) if attempt < self.max_attempts && is_transient(&e) => {
+                    debug!("Retrying after {}", e);
+                    crate::instrument::retry(attempt, &e);
+                    std::thread::sleep(backoff);
+                    backoff = (backoff * 2).min(self.max_backoff);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+pub(crate) fn is_transient(e: &Error) -> bool {
+    match e {
+        Error::DBus(e) | Error::Call { source: e, .. } => e
+            .name()
+            .is_some_and(|name| TRANSIENT_ERRORS.contains(&name)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(name: &str) -> Error {
+        dbus::Error::new_custom(name, "").into()
+    }
+
+    #[test]
+    fn retries_transient_errors() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(1));
+        let mut attempts = 0;
+        let result = policy.run(|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err(error("org.freedesktop.DBus.Error.NoReply"))
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+
+        let mut attempts = 0;
+        let result: Result<(), _> = policy.run(|| {
+            attempts += 1;
+            Err(error("org.freedesktop.DBus.Error.UnknownMethod"))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+
+        let mut attempts = 0;
+        let result: Result<(), _> = RetryPolicy::NONE.run(|| {
+            attempts += 1;
+            Err(error("org.freedesktop.DBus.Error.NoReply"))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops::Range;
+
+use ropey::{Rope, RopeSlice};
+
+use crate::{Selection, SurroundingTextDeleter, SurroundingTextProvider};
+
+fn usize_range(range: Range<u32>) -> Range<usize> {
+    range.start as usize..range.end as usize
+}
+
+impl SurroundingTextProvider for Rope {
+    fn char_len(&self) -> u32 {
+        self.len_chars() as u32
+    }
+
+    fn chars_in(&self, range: Range<u32>) -> String {
+        self.slice(usize_range(range)).to_string()
+    }
+}
+
+impl SurroundingTextProvider for RopeSlice<'_> {
+    fn char_len(&self) -> u32 {
+        self.len_chars() as u32
+    }
+
+    fn chars_in(&self, range: Range<u32>) -> String {
+        self.slice(usize_range(range)).to_string()
+    }
+}
+
+impl SurroundingTextDeleter for Rope {
+    fn delete_chars(&mut self, range: Range<u32>) {
+        self.remove(usize_range(range));
+    }
+}
+
+impl Selection {
+    /// Like `Selection::from_utf8` for a rope, without going through the
+    /// text before the positions
+    ///
+    /// Returns `None` if a byte offset is after the end of `rope` or inside
+    /// of a character.
+    pub fn from_rope_bytes(rope: &Rope, cursor: usize, anchor: usize) -> Option<Self> {
+        let to_char = |byte: usize| {
+            let index = rope.try_byte_to_char(byte).ok()?;
+            (rope.char_to_byte(index) == byte).then_some(index as u32)
+        };
+        Some(Selection::new(to_char(cursor)?, to_char(anchor)?))
+    }
+
+    /// Like `Selection::to_utf8` for a rope, the positions as byte offsets
+    /// into `rope`
+    pub fn to_rope_bytes(&self, rope: &Rope) -> Option<(usize, usize)> {
+        let to_byte = |index: u32| rope.try_char_to_byte(index as usize).ok();
+        Some((to_byte(self.cursor)?, to_byte(self.anchor)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rope_matches_string() {
+        let text = "один два три";
+        let rope = Rope::from_str(text);
+        assert_eq!(
+            rope.surrounding_text(Selection::new(7, 5), 2),
+            text.to_owned().surrounding_text(Selection::new(7, 5), 2)
+        );
+
+        let mut rope = rope;
+        assert_eq!(rope.delete_surrounding_text(8, -3, 3), 5);
+        assert_eq!(rope, "один  три");
+
+        let selection = Selection::from_rope_bytes(&rope, 9, 2).unwrap();
+        assert_eq!(selection, Selection::new(5, 1));
+        assert_eq!(selection.to_rope_bytes(&rope), Some((9, 2)));
+        assert_eq!(Selection::from_rope_bytes(&rope, 1, 0), None);
+    }
+}
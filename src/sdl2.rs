@@ -0,0 +1,337 @@
+//! Sending the keys of an SDL2 application to IBus
+//!
+//! SDL2 has its own IBus client, which only reports the committed text and
+//! the preedit through `Event::TextInput` and `Event::TextEditing`. To use
+//! an `InputContext` of this crate instead, stop the text input of SDL with
+//! `take_over_text_input`, and send the `KeyDown` and `KeyUp` events with
+//! `key_event_args`:
+//!
+//! ```no_run
+//! use ibus::{Bus, Capabilites};
+//!
+//! let sdl = sdl2::init().unwrap();
+//! let video = sdl.video().unwrap();
+//! let window = video.window("app", 800, 600).build().unwrap();
+//! ibus::sdl2::take_over_text_input(&video);
+//!
+//! let bus = Bus::new().unwrap();
+//! let ctx = bus.create_input_context("app").unwrap();
+//! ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+//! let caret = sdl2::rect::Rect::new(100, 40, 2, 18);
+//! let (x, y, w, h) = ibus::sdl2::cursor_location(&window, caret);
+//! ctx.set_cursor_location(x, y, w, h).unwrap();
+//!
+//! for event in sdl.event_pump().unwrap().poll_iter() {
+//!     if let Some((sym, code, state)) = ibus::sdl2::key_event_args(&event) {
+//!         if !ctx.process_key_event(sym, code, state).unwrap_or(false) {
+//!             // Handle the key as usual
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! SDL only reports the unshifted keycode, so the keysyms of the letters
+//! follow Shift and Caps Lock, but the other shifted symbols aren't known:
+//! Shift+1 is `KEY_1` with `Modifiers::SHIFT`, not `KEY_exclam`. The engines
+//! that translate keys mostly look at the letters.
+//!
+//! This module needs the `sdl2` feature.
+//!
+
+use sdl2::{
+    event::Event,
+    keyboard::{Keycode, Mod, Scancode},
+    rect::Rect,
+    video::Window,
+    VideoSubsystem,
+};
+
+use crate::{keysyms, Modifiers};
+
+/// The bit of the SDL keycodes that are made from a scancode, because the
+/// key doesn't produce a character
+const SCANCODE_MASK: i32 = 1 << 30;
+
+/// Stops the IME handling of SDL, so that it doesn't send the keys to its
+/// own input context as well
+///
+/// Call this after every `TextInputUtil::start`, SDL starts the text input
+/// by default on Linux.
+pub fn take_over_text_input(video: &VideoSubsystem) {
+    video.text_input().stop();
+}
+
+/// The keysym, the keycode and the modifiers of a `KeyDown` or `KeyUp`
+/// event, the arguments of `InputContext::process_key_event`
+///
+/// Returns `None` for the other events, and for the keys without a keysym.
+pub fn key_event_args(event: &Event) -> Option<(u32, u32, Modifiers)> {
+    let (keycode, scancode, keymod, released) = match *event {
+        Event::KeyDown {
+            keycode,
+            scancode,
+            keymod,
+            ..
+        } => (keycode, scancode, keymod, false),
+        Event::KeyUp {
+            keycode,
+            scancode,
+            keymod,
+            ..
+        } => (keycode, scancode, keymod, true),
+        _ => return None,
+    };
+    let sym = keysym(keycode?, keymod)?;
+    let code = scancode.map_or(0, evdev_code);
+    let mut state = modifiers(keymod);
+    if released {
+        state |= Modifiers::RELEASE;
+    }
+    Some((sym, code, state))
+}
+
+/// Converts the modifiers of SDL to the ones of IBus
+pub fn modifiers(keymod: Mod) -> Modifiers {
+    let mut state = Modifiers::empty();
+    if keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD) {
+        state |= Modifiers::SHIFT;
+    }
+    if keymod.contains(Mod::CAPSMOD) {
+        state |= Modifiers::LOCK;
+    }
+    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) {
+        state |= Modifiers::CONTROL;
+    }
+    if keymod.intersects(Mod::LALTMOD | Mod::RALTMOD) {
+        state |= Modifiers::MOD1;
+    }
+    if keymod.contains(Mod::NUMMOD) {
+        state |= Modifiers::MOD2;
+    }
+    if keymod.intersects(Mod::LGUIMOD | Mod::RGUIMOD) {
+        state |= Modifiers::SUPER | Modifiers::MOD4;
+    }
+    if keymod.contains(Mod::MODEMOD) {
+        state |= Modifiers::MOD5;
+    }
+    state
+}
+
+/// The keysym of an SDL keycode, the letters are upper case when `keymod`
+/// has either Shift or Caps Lock
+pub fn keysym(keycode: Keycode, keymod: Mod) -> Option<u32> {
+    let value = keycode as i32;
+    if value & SCANCODE_MASK != 0 {
+        return scancode_keysym(value & !SCANCODE_MASK);
+    }
+    use keysyms::*;
+    let sym = match value {
+        0x0d => KEY_Return,
+        0x1b => KEY_Escape,
+        0x08 => KEY_BackSpace,
+        0x09 => KEY_Tab,
+        0x7f => KEY_Delete,
+        _ => {
+            let c = char::from_u32(value as u32)?;
+            let shifted =
+                keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD) != keymod.contains(Mod::CAPSMOD);
+            let c = if shifted && c.is_lowercase() {
+                c.to_uppercase().next().unwrap_or(c)
+            } else {
+                c
+            };
+            keysym_from_char(c)?
+        }
+    };
+    Some(sym)
+}
+
+/// The keys without a character, by their USB HID usage like the scancodes
+fn scancode_keysym(scancode: i32) -> Option<u32> {
+    use keysyms::*;
+    Some(match scancode {
+        57 => KEY_Caps_Lock,
+        58..=69 => KEY_F1 + (scancode - 58) as u32,
+        70 => KEY_Print,
+        71 => KEY_Scroll_Lock,
+        72 => KEY_Pause,
+        73 => KEY_Insert,
+        74 => KEY_Home,
+        75 => KEY_Page_Up,
+        77 => KEY_End,
+        78 => KEY_Page_Down,
+        79 => KEY_Right,
+        80 => KEY_Left,
+        81 => KEY_Down,
+        82 => KEY_Up,
+        83 => KEY_Num_Lock,
+        84 => KEY_KP_Divide,
+        85 => KEY_KP_Multiply,
+        86 => KEY_KP_Subtract,
+        87 => KEY_KP_Add,
+        88 => KEY_KP_Enter,
+        89..=97 => KEY_KP_1 + (scancode - 89) as u32,
+        98 => KEY_KP_0,
+        99 => KEY_KP_Decimal,
+        101 => KEY_Menu,
+        103 => KEY_KP_Equal,
+        104..=115 => KEY_F13 + (scancode - 104) as u32,
+        117 => KEY_Help,
+        119 => KEY_Select,
+        122 => KEY_Undo,
+        126 => KEY_Find,
+        133 => KEY_KP_Separator,
+        224 => KEY_Control_L,
+        225 => KEY_Shift_L,
+        226 => KEY_Alt_L,
+        227 => KEY_Super_L,
+        228 => KEY_Control_R,
+        229 => KEY_Shift_R,
+        230 => KEY_Alt_R,
+        231 => KEY_Super_R,
+        257 => KEY_Mode_switch,
+        _ => return None,
+    })
+}
+
+/// The evdev code of a scancode, the keycode that IBus expects
+pub fn evdev_code(scancode: Scancode) -> u32 {
+    let usage = scancode as i32;
+    let code = match usage {
+        4..=29 => [
+            30, 48, 46, 32, 18, 33, 34, 35, 23, 36, 37, 38, 50, 49, 24, 25, 16, 19, 31, 20, 22, 47,
+            17, 45, 21, 44,
+        ][(usage - 4) as usize],
+        // 1 to 9, then 0
+        30..=39 => usage - 28,
+        40 => 28,
+        41 => 1,
+        42 => 14,
+        43 => 15,
+        44 => 57,
+        45 => 12,
+        46 => 13,
+        47 => 26,
+        48 => 27,
+        49 | 50 => 43,
+        51 => 39,
+        52 => 40,
+        53 => 41,
+        54 => 51,
+        55 => 52,
+        56 => 53,
+        57 => 58,
+        58..=67 => usage + 1,
+        68 => 87,
+        69 => 88,
+        70 => 99,
+        71 => 70,
+        72 => 119,
+        73 => 110,
+        74 => 102,
+        75 => 104,
+        76 => 111,
+        77 => 107,
+        78 => 109,
+        79 => 106,
+        80 => 105,
+        81 => 108,
+        82 => 103,
+        83 => 69,
+        84 => 98,
+        85 => 55,
+        86 => 74,
+        87 => 78,
+        88 => 96,
+        89 => 79,
+        90 => 80,
+        91 => 81,
+        92 => 75,
+        93 => 76,
+        94 => 77,
+        95 => 71,
+        96 => 72,
+        97 => 73,
+        98 => 82,
+        99 => 83,
+        100 => 86,
+        101 => 127,
+        103 => 117,
+        104..=115 => usage + 79,
+        135 => 89,
+        136 => 93,
+        137 => 124,
+        138 => 92,
+        139 => 94,
+        144 => 122,
+        145 => 123,
+        146 => 90,
+        147 => 91,
+        148 => 85,
+        224 => 29,
+        225 => 42,
+        226 => 56,
+        227 => 125,
+        228 => 97,
+        229 => 54,
+        230 => 100,
+        231 => 126,
+        _ => 0,
+    };
+    code as u32
+}
+
+/// The arguments of `InputContext::set_cursor_location` for a caret at
+/// `rect`, in the logical coordinates of `window`
+///
+/// The position of the window is added, and the result is scaled to
+/// physical pixels when the drawable is larger than the window, like on
+/// HiDPI screens with `SDL_WINDOW_ALLOW_HIGHDPI`.
+pub fn cursor_location(window: &Window, rect: Rect) -> (i32, i32, i32, i32) {
+    let (wx, wy) = window.position();
+    let (width, _) = window.size();
+    let (drawable_width, _) = window.drawable_size();
+    let scale = if width == 0 {
+        1.0
+    } else {
+        drawable_width as f64 / width as f64
+    };
+    let scaled = |v: i32| (v as f64 * scale).round() as i32;
+    (
+        scaled(wx + rect.x()),
+        scaled(wy + rect.y()),
+        scaled(rect.width() as i32),
+        scaled(rect.height() as i32),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keysyms() {
+        assert_eq!(keysym(Keycode::A, Mod::NOMOD), Some(keysyms::KEY_a));
+        assert_eq!(keysym(Keycode::A, Mod::LSHIFTMOD), Some(keysyms::KEY_A));
+        assert_eq!(
+            keysym(Keycode::A, Mod::LSHIFTMOD | Mod::CAPSMOD),
+            Some(keysyms::KEY_a)
+        );
+        assert_eq!(
+            keysym(Keycode::Return, Mod::NOMOD),
+            Some(keysyms::KEY_Return)
+        );
+        assert_eq!(keysym(Keycode::F5, Mod::NOMOD), Some(keysyms::KEY_F5));
+        assert_eq!(keysym(Keycode::Kp7, Mod::NOMOD), Some(keysyms::KEY_KP_7));
+    }
+
+    #[test]
+    fn evdev_codes() {
+        assert_eq!(evdev_code(Scancode::A), 30);
+        assert_eq!(evdev_code(Scancode::Num1), 2);
+        assert_eq!(evdev_code(Scancode::Num0), 11);
+        assert_eq!(evdev_code(Scancode::F10), 68);
+        assert_eq!(evdev_code(Scancode::F12), 88);
+        assert_eq!(evdev_code(Scancode::Space), 57);
+    }
+}
@@ -0,0 +1,150 @@
+use std::ops::Range;
+
+/// The cursor and the anchor of a surrounding text, see
+/// `InputContext::set_surrounding_text`
+///
+/// IBus counts the positions in characters (UTF-32 code units), while Rust
+/// strings and most toolkits count in UTF-8 bytes or UTF-16 code units. The
+/// conversions return `None` for a position that's out of range or inside
+/// a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Selection {
+    pub cursor: u32,
+    /// The other end of the selection, the same as `cursor` if nothing is
+    /// selected
+    pub anchor: u32,
+}
+impl Selection {
+    pub fn new(cursor: u32, anchor: u32) -> Self {
+        Selection { cursor, anchor }
+    }
+
+    /// A cursor without a selection
+    pub fn caret(cursor: u32) -> Self {
+        Selection::new(cursor, cursor)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cursor == self.anchor
+    }
+
+    /// The selected characters, from the smaller position to the larger
+    pub fn range(&self) -> Range<u32> {
+        self.cursor.min(self.anchor)..self.cursor.max(self.anchor)
+    }
+
+    /// Whether both positions are in `text`, the end of the text included
+    pub fn is_valid_in(&self, text: &str) -> bool {
+        let len = char_len(text);
+        self.cursor <= len && self.anchor <= len
+    }
+
+    /// Moves the positions that are after the end of `text` to the end
+    pub fn clamp_to(self, text: &str) -> Self {
+        let len = char_len(text);
+        Selection::new(self.cursor.min(len), self.anchor.min(len))
+    }
+
+    pub fn from_utf8(text: &str, cursor: usize, anchor: usize) -> Option<Self> {
+        Some(Selection::new(
+            utf8_to_char(text, cursor)?,
+            utf8_to_char(text, anchor)?,
+        ))
+    }
+
+    /// The positions as byte offsets into `text`
+    pub fn to_utf8(&self, text: &str) -> Option<(usize, usize)> {
+        Some((
+            char_to_utf8(text, self.cursor)?,
+            char_to_utf8(text, self.anchor)?,
+        ))
+    }
+
+    pub fn from_utf16(text: &str, cursor: usize, anchor: usize) -> Option<Self> {
+        Some(Selection::new(
+            utf16_to_char(text, cursor)?,
+            utf16_to_char(text, anchor)?,
+        ))
+    }
+
+    /// The positions as offsets in UTF-16 code units, e.g. for Qt or the web
+    pub fn to_utf16(&self, text: &str) -> Option<(usize, usize)> {
+        Some((
+            char_to_utf16(text, self.cursor)?,
+            char_to_utf16(text, self.anchor)?,
+        ))
+    }
+}
+
+fn char_len(text: &str) -> u32 {
+    text.chars().count() as u32
+}
+
+fn utf8_to_char(text: &str, offset: usize) -> Option<u32> {
+    if !text.is_char_boundary(offset) {
+        return None;
+    }
+    Some(char_len(&text[..offset]))
+}
+
+fn char_to_utf8(text: &str, index: u32) -> Option<usize> {
+    text.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .nth(index as usize)
+}
+
+fn utf16_to_char(text: &str, offset: usize) -> Option<u32> {
+    let mut units = 0;
+    for (index, c) in text.chars().enumerate() {
+        if units == offset {
+            return Some(index as u32);
+        }
+        units += c.len_utf16();
+        if units > offset {
+            return None;
+        }
+    }
+    (units == offset).then(|| char_len(text))
+}
+
+fn char_to_utf16(text: &str, index: u32) -> Option<usize> {
+    let mut chars = text.chars();
+    let mut units = 0;
+    for _ in 0..index {
+        units += chars.next()?.len_utf16();
+    }
+    Some(units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        // "é" is 2 bytes, "😀" is 4 bytes and 2 UTF-16 units
+        let text = "é😀a";
+        let selection = Selection::new(2, 1);
+        assert_eq!(selection.to_utf8(text), Some((6, 2)));
+        assert_eq!(Selection::from_utf8(text, 6, 2), Some(selection));
+        assert_eq!(selection.to_utf16(text), Some((3, 1)));
+        assert_eq!(Selection::from_utf16(text, 3, 1), Some(selection));
+        assert_eq!(Selection::from_utf8(text, 7, 0), Some(Selection::new(3, 0)));
+
+        // Inside a character or out of range
+        assert_eq!(Selection::from_utf8(text, 1, 0), None);
+        assert_eq!(Selection::from_utf16(text, 2, 0), None);
+        assert_eq!(Selection::from_utf16(text, 5, 0), None);
+        assert_eq!(Selection::caret(4).to_utf8(text), None);
+    }
+
+    #[test]
+    fn validity() {
+        let text = "héllo";
+        assert!(Selection::new(5, 0).is_valid_in(text));
+        assert!(!Selection::new(2, 6).is_valid_in(text));
+        assert_eq!(Selection::new(9, 2).clamp_to(text), Selection::new(5, 2));
+        assert_eq!(Selection::new(4, 1).range(), 1..4);
+    }
+}
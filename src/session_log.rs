@@ -0,0 +1,438 @@
+use std::{
+    io::{Read, Write},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use dbus::{
+    arg::{AppendAll, IterAppend},
+    channel::{MatchingReceiver, Token},
+    strings::Path,
+    Message,
+};
+
+use crate::{
+    dump, input_context::INTERFACE_NAME, Bus, Capabilites, Error, ImContext, ImeEvent, Modifiers,
+    PropState, Text,
+};
+
+/// The first bytes of a serialized `SessionLog`, with the format version
+const MAGIC: &[u8; 8] = b"IBUSLOG1";
+
+/// The largest message that D-Bus allows, a longer one means the log is
+/// corrupt
+const MAX_MESSAGE_LEN: u32 = 128 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// A signal from the daemon, or its reply to a call
+    Incoming,
+    /// A call of the application
+    Outgoing,
+}
+
+/// A message of a `SessionLog`
+#[derive(Debug)]
+pub struct LogEntry {
+    /// Since the start of the recording
+    pub at: Duration,
+    pub direction: Direction,
+    pub message: Message,
+}
+
+/// The signals that an application received from the input method, and the
+/// calls it made, as recorded by a `SessionRecorder`
+///
+/// A log can be saved with `write_to` and attached to a bug report, e.g.
+/// "the preedit renders wrong with engine X". `replay` then feeds the same
+/// events to the application code, without a daemon or the engine.
+///
+/// The messages are saved the way they were sent over D-Bus, so that the log
+/// keeps what the crate wouldn't decode.
+#[derive(Debug, Default)]
+pub struct SessionLog {
+    pub entries: Vec<LogEntry>,
+}
+impl SessionLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The events of the incoming signals, with the time they were received
+    pub fn events(&self) -> impl Iterator<Item = (Duration, ImeEvent)> + '_ {
+        self.entries
+            .iter()
+            .filter(|entry| entry.direction == Direction::Incoming)
+            .filter_map(|entry| Some((entry.at, ImeEvent::from_message(&entry.message)?)))
+    }
+
+    /// Passes the events to `handler` in the order they were received
+    ///
+    /// With `realtime`, the calling thread sleeps between the events as long
+    /// as there was between them during the recording, for bugs that depend
+    /// on the timing.
+    pub fn replay<F: FnMut(ImeEvent)>(&self, realtime: bool, mut handler: F) {
+        let start = Instant::now();
+        for (at, event) in self.events() {
+            if realtime {
+                std::thread::sleep(at.saturating_sub(start.elapsed()));
+            }
+            handler(event);
+        }
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(MAGIC)?;
+        for entry in &self.entries {
+            let mut bytes = Vec::new();
+            entry.message.marshal(|chunk| -> Result<(), Error> {
+                bytes.extend_from_slice(chunk);
+                Ok(())
+            })?;
+            writer.write_all(&(entry.at.as_micros() as u64).to_le_bytes())?;
+            writer.write_all(&[match entry.direction {
+                Direction::Incoming => 0,
+                Direction::Outgoing => 1,
+            }])?;
+            writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+            writer.write_all(&bytes)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads a log saved by `write_to`
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(log_error("Not a session log, or a newer version of it"));
+        }
+        let mut log = SessionLog::new();
+        loop {
+            let mut at = [0; 8];
+            match reader.read_exact(&mut at) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(log),
+                Err(e) => return Err(e.into()),
+            }
+            let mut header = [0; 5];
+            reader.read_exact(&mut header)?;
+            let direction = match header[0] {
+                0 => Direction::Incoming,
+                1 => Direction::Outgoing,
+                _ => return Err(log_error("Unknown message direction in the session log")),
+            };
+            let len = u32::from_le_bytes([header[1], header[2], header[3], header[4]]);
+            if len > MAX_MESSAGE_LEN {
+                return Err(log_error("Message too long in the session log"));
+            }
+            let mut bytes = vec![0; len as usize];
+            reader.read_exact(&mut bytes)?;
+            log.entries.push(LogEntry {
+                at: Duration::from_micros(u64::from_le_bytes(at)),
+                direction,
+                message: Message::demarshal(&bytes)?,
+            });
+        }
+    }
+}
+
+fn log_error(description: &str) -> Error {
+    Error::Unknown {
+        description: description.into(),
+    }
+}
+
+/// Records a `SessionLog`
+///
+/// The signals are recorded by `record_events`, the calls by the contexts
+/// wrapped with `record_calls`.
+///
+/// ```no_run
+/// use ibus::{Bus, ImContext, Modifiers, SessionRecorder};
+///
+/// let bus = Bus::new().unwrap();
+/// let recorder = SessionRecorder::new();
+/// recorder.record_events(&bus, |event| println!("{:?}", event.kind));
+/// let ctx = recorder.record_calls(bus.create_input_context("my-app").unwrap());
+/// ctx.process_key_event(ibus::keysyms::KEY_a, 30, Modifiers::empty())
+///     .unwrap();
+/// bus.process(std::time::Duration::from_millis(100)).unwrap();
+///
+/// let file = std::fs::File::create("session.ibuslog").unwrap();
+/// recorder.log().write_to(file).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SessionRecorder {
+    start: Instant,
+    entries: Arc<Mutex<Vec<LogEntry>>>,
+    serial: Arc<AtomicU32>,
+}
+impl SessionRecorder {
+    pub fn new() -> Self {
+        SessionRecorder {
+            start: Instant::now(),
+            entries: Arc::default(),
+            serial: Arc::new(AtomicU32::new(1)),
+        }
+    }
+
+    /// Records the signals of the input contexts, and passes their events on
+    /// to `handler`, from `Bus::process`
+    ///
+    /// The signals with a callback registered with the `on_*` methods of
+    /// `InputContext` before this go to that callback, and aren't recorded.
+    /// Pass the returned token to `Bus::remove_queue` to stop.
+    pub fn record_events<F>(&self, bus: &Bus, mut handler: F) -> Token
+    where
+        F: FnMut(ImeEvent) + Send + 'static,
+    {
+        let recorder = self.clone();
+        bus.conn.start_receive(
+            ImeEvent::match_rule(),
+            Box::new(move |msg, _| {
+                dump::received(&msg);
+                if let Some(event) = ImeEvent::from_message(&msg) {
+                    handler(event);
+                }
+                recorder.record(Direction::Incoming, msg);
+                true
+            }),
+        )
+    }
+
+    /// Wraps an input context, so that its calls and their replies are
+    /// recorded
+    pub fn record_calls<C: ImContext>(&self, ctx: C) -> RecordedContext<C> {
+        RecordedContext {
+            ctx,
+            recorder: self.clone(),
+        }
+    }
+
+    /// Takes what was recorded so far
+    pub fn log(&self) -> SessionLog {
+        SessionLog {
+            entries: std::mem::take(&mut *self.entries.lock().unwrap()),
+        }
+    }
+
+    pub(crate) fn record(&self, direction: Direction, mut message: Message) {
+        self.stamp(&mut message);
+        self.entries.lock().unwrap().push(LogEntry {
+            at: self.start.elapsed(),
+            direction,
+            message,
+        });
+    }
+
+    /// Gives a serial to a message that was made for the log rather than
+    /// sent, a message without one can't be read back
+    fn stamp(&self, message: &mut Message) {
+        if message.get_serial().is_none() {
+            message.set_serial(self.serial.fetch_add(1, Ordering::Relaxed));
+        }
+    }
+}
+impl Default for SessionRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An input context whose calls are recorded, see
+/// `SessionRecorder::record_calls`
+pub struct RecordedContext<C> {
+    ctx: C,
+    recorder: SessionRecorder,
+}
+impl<C: ImContext> RecordedContext<C> {
+    pub fn inner(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn into_inner(self) -> C {
+        self.ctx
+    }
+
+    fn call_message<A: AppendAll>(&self, method: &str, args: A) -> Message {
+        let mut call = Message::new_method_call(
+            "org.freedesktop.IBus",
+            self.ctx.path(),
+            INTERFACE_NAME,
+            method,
+        )
+        .expect("the call of an input context should be valid");
+        args.append(&mut IterAppend::new(&mut call));
+        call
+    }
+
+    /// Records the call, then makes it with `f`
+    fn record<A: AppendAll>(
+        &self,
+        method: &str,
+        args: A,
+        f: impl FnOnce(&C) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        self.recorder
+            .record(Direction::Outgoing, self.call_message(method, args));
+        f(&self.ctx)
+    }
+}
+impl<C: ImContext> ImContext for RecordedContext<C> {
+    fn path(&self) -> Path<'static> {
+        self.ctx.path()
+    }
+
+    fn set_capabilities(&self, caps: Capabilites) -> Result<(), Error> {
+        self.record("SetCapabilities", (caps.bits(),), |ctx| {
+            ctx.set_capabilities(caps)
+        })
+    }
+
+    fn process_key_event(&self, sym: u32, code: u32, modifiers: Modifiers) -> Result<bool, Error> {
+        // Recorded with its reply, which tells whether the application
+        // didn't need to handle the key itself
+        let mut call = self.call_message("ProcessKeyEvent", (sym, code, modifiers.bits()));
+        self.recorder.stamp(&mut call);
+        let reply = call.method_return();
+        self.recorder.record(Direction::Outgoing, call);
+        let handled = self.ctx.process_key_event(sym, code, modifiers)?;
+        self.recorder
+            .record(Direction::Incoming, reply.append1(handled));
+        Ok(handled)
+    }
+
+    fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Result<(), Error> {
+        self.record("SetCursorLocation", (x, y, w, h), |ctx| {
+            ctx.set_cursor_location(x, y, w, h)
+        })
+    }
+
+    fn focus_in(&self) -> Result<(), Error> {
+        self.record("FocusIn", (), |ctx| ctx.focus_in())
+    }
+
+    fn focus_out(&self) -> Result<(), Error> {
+        self.record("FocusOut", (), |ctx| ctx.focus_out())
+    }
+
+    fn reset(&self) -> Result<(), Error> {
+        self.record("Reset", (), |ctx| ctx.reset())
+    }
+
+    fn set_surrounding_text(
+        &self,
+        text: Text<'_>,
+        cursor_pos: u32,
+        anchor_pos: u32,
+    ) -> Result<(), Error> {
+        let args = (text.clone(), cursor_pos, anchor_pos);
+        self.record("SetSurroundingText", args, |ctx| {
+            ctx.set_surrounding_text(text, cursor_pos, anchor_pos)
+        })
+    }
+
+    fn page_up(&self) -> Result<(), Error> {
+        self.record("PageUp", (), |ctx| ctx.page_up())
+    }
+
+    fn page_down(&self) -> Result<(), Error> {
+        self.record("PageDown", (), |ctx| ctx.page_down())
+    }
+
+    fn cursor_up(&self) -> Result<(), Error> {
+        self.record("CursorUp", (), |ctx| ctx.cursor_up())
+    }
+
+    fn cursor_down(&self) -> Result<(), Error> {
+        self.record("CursorDown", (), |ctx| ctx.cursor_down())
+    }
+
+    fn candidate_clicked(&self, index: u32, button: u32, state: Modifiers) -> Result<(), Error> {
+        self.record("CandidateClicked", (index, button, state.bits()), |ctx| {
+            ctx.candidate_clicked(index, button, state)
+        })
+    }
+
+    fn property_activate(&self, name: &str, state: PropState) -> Result<(), Error> {
+        self.record("PropertyActivate", (name, state.to_value()), |ctx| {
+            ctx.property_activate(name, state)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dbus::MessageType;
+
+    use super::*;
+    use crate::{ImeEventKind, NullInputContext};
+
+    #[test]
+    fn record_write_read_replay() {
+        let recorder = SessionRecorder::new();
+        let ctx = recorder.record_calls(NullInputContext);
+        assert!(!ctx.process_key_event(0x61, 30, Modifiers::empty()).unwrap());
+        ctx.focus_in().unwrap();
+        let signal = Message::new_signal("/ic/1", INTERFACE_NAME, "CommitText")
+            .unwrap()
+            .append1(Text::from("a"));
+        recorder.record(Direction::Incoming, signal);
+
+        let mut bytes = Vec::new();
+        recorder.log().write_to(&mut bytes).unwrap();
+        let log = SessionLog::read_from(&bytes[..]).unwrap();
+        let members: Vec<_> = log
+            .entries
+            .iter()
+            .map(|e| {
+                (
+                    e.direction,
+                    e.message.msg_type(),
+                    e.message.member().map(|m| m.to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                (
+                    Direction::Outgoing,
+                    MessageType::MethodCall,
+                    Some("ProcessKeyEvent".into())
+                ),
+                (Direction::Incoming, MessageType::MethodReturn, None),
+                (
+                    Direction::Outgoing,
+                    MessageType::MethodCall,
+                    Some("FocusIn".into())
+                ),
+                (
+                    Direction::Incoming,
+                    MessageType::Signal,
+                    Some("CommitText".into())
+                ),
+            ]
+        );
+        assert!(!log.entries[1].message.read1::<bool>().unwrap());
+
+        let mut events = Vec::new();
+        log.replay(false, |event| events.push(event.kind));
+        assert_eq!(
+            events,
+            vec![ImeEventKind::CommitText(Text::from("a".to_owned()))]
+        );
+
+        assert!(SessionLog::read_from(&b"IBUSLOG9"[..]).is_err());
+        let mut too_long = MAGIC.to_vec();
+        too_long.extend_from_slice(&[0; 8]);
+        too_long.push(0);
+        too_long.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(SessionLog::read_from(&too_long[..]).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use std::ops::Range;
+
+use crate::Selection;
+
+/// How many characters before and after the selection
+/// `InputContext::set_surrounding_text_from` sends
+pub(crate) const CONTEXT_CHARS: u32 = 256;
+
+/// A text buffer that can give the text around the cursor to the engine, for
+/// `Capabilites::SURROUNDING_TEXT`
+///
+/// It's implemented for `String`, and for `ropey::Rope` with the `ropey`
+/// feature. The positions count characters, like the ones of IBus.
+pub trait SurroundingTextProvider {
+    /// The number of characters in the buffer
+    fn char_len(&self) -> u32;
+
+    /// The characters in `range`, which is in the buffer
+    fn chars_in(&self, range: Range<u32>) -> String;
+
+    /// The text around `selection`, with at most `context` characters before
+    /// and after it, and the selection in that text
+    ///
+    /// The positions of `selection` that are after the end of the buffer
+    /// are moved to the end.
+    fn surrounding_text(&self, selection: Selection, context: u32) -> (String, Selection) {
+        let len = self.char_len();
+        let selection = Selection::new(selection.cursor.min(len), selection.anchor.min(len));
+        let range = selection.range();
+        let start = range.start.saturating_sub(context);
+        let end = range.end.saturating_add(context).min(len);
+        let text = self.chars_in(start..end);
+        (
+            text,
+            Selection::new(selection.cursor - start, selection.anchor - start),
+        )
+    }
+}
+
+/// A text buffer that can delete the text that the engine asks for with
+/// `ImeEventKind::DeleteSurroundingText`
+pub trait SurroundingTextDeleter: SurroundingTextProvider {
+    /// Deletes the characters in `range`, which is in the buffer
+    fn delete_chars(&mut self, range: Range<u32>);
+
+    /// Deletes `nchars` characters from `offset` relative to `cursor`, the
+    /// arguments of `ImeEventKind::DeleteSurroundingText`, and returns where
+    /// the cursor is after it
+    ///
+    /// The part of the range that's outside of the buffer is left out.
+    fn delete_surrounding_text(&mut self, cursor: u32, offset: i32, nchars: u32) -> u32 {
+        let len = self.char_len();
+        let cursor = cursor.min(len);
+        let start = i64::from(cursor) + i64::from(offset);
+        let end = start + i64::from(nchars);
+        let clamp = |position: i64| position.clamp(0, i64::from(len)) as u32;
+        let range = clamp(start)..clamp(end);
+        self.delete_chars(range.clone());
+        if cursor >= range.end {
+            cursor - range.len() as u32
+        } else {
+            cursor.min(range.start)
+        }
+    }
+}
+
+/// The byte offset of the character at `index`, or the length of `text`
+fn byte_offset(text: &str, index: u32) -> usize {
+    text.char_indices()
+        .nth(index as usize)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
+impl SurroundingTextProvider for String {
+    fn char_len(&self) -> u32 {
+        self.chars().count() as u32
+    }
+
+    fn chars_in(&self, range: Range<u32>) -> String {
+        let start = byte_offset(self, range.start);
+        let end = start + byte_offset(&self[start..], range.end - range.start);
+        self[start..end].to_owned()
+    }
+}
+
+impl SurroundingTextDeleter for String {
+    fn delete_chars(&mut self, range: Range<u32>) {
+        let start = byte_offset(self, range.start);
+        let end = start + byte_offset(&self[start..], range.end - range.start);
+        self.replace_range(start..end, "");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_around_the_selection() {
+        let text = String::from("один два три");
+        let (window, selection) = text.surrounding_text(Selection::new(7, 5), 2);
+        assert_eq!(window, "н два ");
+        assert_eq!(selection, Selection::new(4, 2));
+
+        let (window, selection) = text.surrounding_text(Selection::caret(100), 3);
+        assert_eq!(window, "три");
+        assert_eq!(selection, Selection::caret(3));
+    }
+
+    #[test]
+    fn deletion() {
+        let mut text = String::from("один два");
+        assert_eq!(text.delete_surrounding_text(8, -3, 3), 5);
+        assert_eq!(text, "один ");
+        // Only the "о" of the range is in the text
+        let mut text = String::from("один");
+        assert_eq!(text.delete_surrounding_text(2, -5, 4), 1);
+        assert_eq!(text, "дин");
+        let mut text = String::from("один");
+        assert_eq!(text.delete_surrounding_text(1, 1, 10), 1);
+        assert_eq!(text, "од");
+    }
+}
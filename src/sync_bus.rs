@@ -0,0 +1,451 @@
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+use dbus::{
+    arg::{AppendAll, ReadAll},
+    blocking::{Proxy, SyncConnection},
+    channel::{MatchingReceiver, Token, Watch},
+    message::SignalArgs,
+    strings::Path,
+    Message,
+};
+
+use log::debug;
+
+use crate::{
+    bus_error, diagnostics, disconnect_filter, disconnected_rule,
+    dispatch::DispatchScope,
+    dump,
+    event_queue::queue_filter,
+    get_address,
+    input_context::{input_context_call, INTERFACE_NAME},
+    surrounding, validate, AfterCallback, Capabilites, EngineDesc, Error, EventQueue, ImeEvent,
+    Modifiers, PropState, RetryPolicy, Selection, SurroundingTextProvider, Text, Watchdog,
+    REQ_TIMEOUT,
+};
+
+const IBUS_NAME: &str = "org.freedesktop.IBus";
+const IBUS_PATH: &str = "/org/freedesktop/IBus";
+
+/// A version of `Bus` that is `Send + Sync`
+///
+/// One thread can run `process` in a loop, while the others make calls on
+/// the bus and on its input contexts. A blocking call only waits for its own
+/// reply, the messages that arrive in the meantime are left for `process`.
+///
+/// `process` doesn't dispatch while a call of an input context is waiting for
+/// its reply, or while a `DeferGuard` of `defer_dispatch` is alive. So the
+/// signals that the engine emits while it handles a call, e.g. the
+/// `CommitText` of a key event, are delivered after the call returned, in
+/// the order they were sent.
+///
+/// ```no_run
+/// use std::{sync::Arc, time::Duration};
+/// use ibus::{AfterCallback, Capabilites, CommitTextSignal, SyncBus};
+///
+/// let bus = Arc::new(SyncBus::new().unwrap());
+/// let ctx = bus.create_input_context("my-app").unwrap();
+/// ctx.on_signal(|signal: CommitTextSignal, _| {
+///     println!("Committed {}", signal.text.as_str());
+///     AfterCallback::Keep
+/// })
+/// .unwrap();
+///
+/// std::thread::spawn({
+///     let bus = bus.clone();
+///     move || loop {
+///         bus.process(Duration::from_secs(1)).unwrap();
+///     }
+/// });
+///
+/// ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS)
+///     .unwrap();
+/// ctx.focus_in().unwrap();
+/// ```
+pub struct SyncBus {
+    conn: Arc<SyncConnection>,
+    gate: Arc<Gate>,
+}
+impl SyncBus {
+    pub fn new() -> Result<Self, Error> {
+        let addr = get_address().map_err(|e| Error::Unknown { description: e })?;
+        Self::with_address(&addr)
+    }
+
+    /// See `Bus::with_address`
+    pub fn with_address(address: &str) -> Result<Self, Error> {
+        let mut channel = dbus::channel::Channel::open_private(address)?;
+        channel.register()?;
+        Ok(SyncBus {
+            conn: Arc::new(SyncConnection::from(channel)),
+            gate: Arc::default(),
+        })
+    }
+
+    /// The underlying connection, for calls that this crate doesn't wrap
+    pub fn connection(&self) -> &Arc<SyncConnection> {
+        &self.conn
+    }
+
+    pub fn create_input_context(&self, name: &str) -> Result<SyncInputContext, Error> {
+        let msg = Message::new_method_call(IBUS_NAME, IBUS_PATH, IBUS_NAME, "CreateInputContext")
+            .expect("the method call should be valid")
+            .append1(name);
+        let (obj_path,): (Path<'static>,) =
+            dump::call(self.conn.channel(), msg).map_err(|e| bus_error(e, "CreateInputContext"))?;
+        Ok(SyncInputContext {
+            conn: self.conn.clone(),
+            gate: self.gate.clone(),
+            obj_path,
+            retry: RetryPolicy::NONE,
+            watchdog: None,
+        })
+    }
+
+    /// See `Bus::global_engine`
+    pub fn global_engine(&self) -> Result<EngineDesc, Error> {
+        use dbus::blocking::stdintf::org_freedesktop_dbus::Properties;
+        let ibus = self.conn.with_proxy(IBUS_NAME, IBUS_PATH, REQ_TIMEOUT);
+        let desc = ibus
+            .get(IBUS_NAME, "GlobalEngine")
+            .map_err(|e| bus_error(e, "GlobalEngine"))?;
+        Ok(desc)
+    }
+
+    /// See `Bus::queue_events`
+    pub fn queue_events(&self, queue: EventQueue) -> Token {
+        self.conn
+            .start_receive(ImeEvent::match_rule(), queue_filter(queue))
+    }
+
+    /// Stops filling the queue of `queue_events`
+    pub fn remove_queue(&self, token: Token) {
+        self.conn.stop_receive(token);
+    }
+
+    /// See `Bus::on_disconnect`
+    pub fn on_disconnect<F>(&self, callback: F) -> Token
+    where
+        F: FnOnce() + Send + Sync + 'static,
+    {
+        self.conn
+            .start_receive(disconnected_rule(), disconnect_filter(callback))
+    }
+
+    /// Stops a callback of `on_disconnect`
+    pub fn remove_disconnect(&self, token: Token) {
+        self.conn.stop_receive(token);
+    }
+
+    /// Holds back the dispatch of `process` until the guard is dropped,
+    /// e.g. around a sequence of calls whose signals should be handled
+    /// together
+    pub fn defer_dispatch(&self) -> DeferGuard {
+        self.gate.defer()
+    }
+
+    /// See `Bus::process`
+    ///
+    /// The callbacks are called on the thread that runs this. Returns
+    /// `Ok(false)` if the dispatch was deferred for the whole `timeout`, or
+    /// if a callback calls this.
+    pub fn process(&self, timeout: Duration) -> Result<bool, Error> {
+        let _scope = match DispatchScope::enter(Arc::as_ptr(&self.conn)) {
+            Some(scope) => scope,
+            None => {
+                debug!("Not dispatching from within a callback of the same connection");
+                return Ok(false);
+            }
+        };
+        let _span = crate::instrument::dispatch_span();
+        self.process_until(Instant::now() + timeout)
+            .inspect_err(|_| self.dispatch_queued())
+    }
+
+    fn process_until(&self, deadline: Instant) -> Result<bool, Error> {
+        if !self.gate.wait_until(deadline) {
+            return Ok(false);
+        }
+        // The messages read while a call was waiting are already queued
+        if self.conn.process(Duration::ZERO)? {
+            return Ok(true);
+        }
+        // Reads without dispatching, because a call may start meanwhile
+        self.conn
+            .channel()
+            .read_write(Some(deadline.saturating_duration_since(Instant::now())))
+            .map_err(|()| Error::Unknown {
+                description: "The connection is closed".into(),
+            })?;
+        if !self.gate.wait_until(deadline) {
+            return Ok(false);
+        }
+        let processed = self.conn.process(Duration::ZERO)?;
+        Ok(processed)
+    }
+
+    /// See `Bus::dispatch_queued`
+    fn dispatch_queued(&self) {
+        while let Ok(true) = self.conn.process(Duration::ZERO) {}
+    }
+
+    /// See `Bus::watch`
+    pub fn watch(&self) -> Watch {
+        self.conn.channel().watch()
+    }
+}
+
+/// The version of `InputContext` for `SyncBus`, it's `Send + Sync`
+pub struct SyncInputContext {
+    conn: Arc<SyncConnection>,
+    gate: Arc<Gate>,
+    obj_path: Path<'static>,
+    retry: RetryPolicy,
+    watchdog: Option<Watchdog>,
+}
+impl SyncInputContext {
+    pub fn path(&self) -> &Path<'static> {
+        &self.obj_path
+    }
+
+    /// See `InputContext::set_retry_policy`
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+    }
+
+    /// See `InputContext::set_watchdog`
+    pub fn set_watchdog(&mut self, watchdog: Option<Watchdog>) {
+        self.watchdog = watchdog;
+    }
+
+    pub fn set_capabilities(&self, caps: Capabilites) -> Result<(), Error> {
+        diagnostics::set_capabilities(&self.obj_path, caps);
+        self.retry
+            .run(|| self.call("SetCapabilities", (caps.bits(),)))
+    }
+
+    /// Calls `callback` from `SyncBus::process` for every signal of type `S`
+    /// emitted by this input context, e.g. `CommitTextSignal`
+    pub fn on_signal<S, F>(&self, callback: F) -> Result<Token, Error>
+    where
+        S: SignalArgs + ReadAll,
+        F: FnMut(S, &Message) -> AfterCallback + Send + 'static,
+    {
+        // The filters of `SyncConnection` have to be `Sync`, the callback
+        // only has to be `Send`
+        let callback = Mutex::new(callback);
+        let token =
+            self.proxy()
+                .match_signal(move |signal: S, _: &SyncConnection, msg: &Message| {
+                    dump::received(msg);
+                    let mut callback = callback.lock().unwrap_or_else(|e| e.into_inner());
+                    (callback)(signal, msg).to_bool()
+                })?;
+        Ok(token)
+    }
+
+    /// Stops a callback registered with `on_signal`
+    pub fn remove_signal(&self, token: Token) -> Result<(), Error> {
+        self.proxy().match_stop(token, true)?;
+        Ok(())
+    }
+
+    /// See `InputContext::process_key_event`
+    pub fn process_key_event(
+        &self,
+        sym: u32,
+        code: u32,
+        modifiers: Modifiers,
+    ) -> Result<bool, Error> {
+        validate::key_event(&self.obj_path, sym, modifiers)?;
+        if let Some(watchdog) = &self.watchdog {
+            if !watchdog.should_send() {
+                return Ok(false);
+            }
+        }
+        let key_args = (sym, code, modifiers.bits());
+        crate::metrics::key_event_sent();
+        let (handled,): (bool,) = self.method_call("ProcessKeyEvent", key_args)?;
+        if !handled {
+            diagnostics::key_not_handled(&self.obj_path);
+        }
+        Ok(handled)
+    }
+
+    /// See `InputContext::set_cursor_location`
+    pub fn set_cursor_location(&self, x: i32, y: i32, w: i32, h: i32) -> Result<(), Error> {
+        validate::cursor_location(&self.obj_path, w, h)?;
+        self.retry
+            .run(|| self.call("SetCursorLocation", (x, y, w, h)))
+    }
+
+    pub fn focus_in(&self) -> Result<(), Error> {
+        self.retry.run(|| self.call("FocusIn", ()))
+    }
+
+    pub fn focus_out(&self) -> Result<(), Error> {
+        self.retry.run(|| self.call("FocusOut", ()))
+    }
+
+    pub fn reset(&self) -> Result<(), Error> {
+        self.call("Reset", ())
+    }
+
+    pub fn set_surrounding_text<'a>(
+        &self,
+        text: impl Into<Text<'a>>,
+        cursor_pos: u32,
+        anchor_pos: u32,
+    ) -> Result<(), Error> {
+        let text: Text<'a> = text.into();
+        validate::surrounding_text(&self.obj_path, &text, cursor_pos, anchor_pos)?;
+        self.call("SetSurroundingText", (text, cursor_pos, anchor_pos))
+    }
+
+    /// See `InputContext::set_surrounding_text_clamped`
+    pub fn set_surrounding_text_clamped<'a>(
+        &self,
+        text: impl Into<Text<'a>>,
+        selection: Selection,
+    ) -> Result<(), Error> {
+        let text: Text<'a> = text.into();
+        let Selection { cursor, anchor } = selection.clamp_to(text.as_str());
+        self.set_surrounding_text(text, cursor, anchor)
+    }
+
+    /// See `InputContext::set_surrounding_text_from`
+    pub fn set_surrounding_text_from(
+        &self,
+        buffer: &impl SurroundingTextProvider,
+        selection: Selection,
+    ) -> Result<(), Error> {
+        let (text, Selection { cursor, anchor }) =
+            buffer.surrounding_text(selection, surrounding::CONTEXT_CHARS);
+        self.set_surrounding_text(text, cursor, anchor)
+    }
+
+    pub fn page_up(&self) -> Result<(), Error> {
+        self.call("PageUp", ())
+    }
+
+    pub fn page_down(&self) -> Result<(), Error> {
+        self.call("PageDown", ())
+    }
+
+    pub fn cursor_up(&self) -> Result<(), Error> {
+        self.call("CursorUp", ())
+    }
+
+    pub fn cursor_down(&self) -> Result<(), Error> {
+        self.call("CursorDown", ())
+    }
+
+    /// See `InputContext::candidate_clicked`
+    pub fn candidate_clicked(
+        &self,
+        index: u32,
+        button: u32,
+        state: Modifiers,
+    ) -> Result<(), Error> {
+        self.call("CandidateClicked", (index, button, state.bits()))
+    }
+
+    pub fn property_activate(&self, name: &str, state: PropState) -> Result<(), Error> {
+        self.call("PropertyActivate", (name, state.to_value()))
+    }
+
+    /// See `InputContext::engine`
+    pub fn engine(&self) -> Result<EngineDesc, Error> {
+        let (desc,): (EngineDesc,) = self.method_call("GetEngine", ())?;
+        Ok(desc)
+    }
+
+    fn call<A: AppendAll>(&self, method: &str, args: A) -> Result<(), Error> {
+        self.method_call(method, args)
+    }
+
+    /// Defers the dispatch of `SyncBus::process` until the reply arrived
+    fn method_call<A: AppendAll, R: ReadAll>(&self, method: &str, args: A) -> Result<R, Error> {
+        let _guard = self.gate.defer();
+        let msg = input_context_call(&self.obj_path, method, args);
+        let result = dump::call(self.conn.channel(), msg)
+            .map_err(|e| Error::from(e).in_call(&self.obj_path, INTERFACE_NAME, method));
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.record(self.conn.channel(), &self.obj_path, &result);
+        }
+        result
+    }
+
+    fn proxy(&self) -> Proxy<'_, &SyncConnection> {
+        self.conn.with_proxy(IBUS_NAME, &self.obj_path, REQ_TIMEOUT)
+    }
+}
+
+/// Counts the calls that defer the dispatch of `SyncBus::process`
+#[derive(Default)]
+struct Gate {
+    deferring: Mutex<usize>,
+    released: Condvar,
+}
+impl Gate {
+    fn defer(self: &Arc<Self>) -> DeferGuard {
+        *self.deferring.lock().unwrap() += 1;
+        DeferGuard { gate: self.clone() }
+    }
+
+    /// Waits until nothing defers the dispatch. Returns `false` if
+    /// `deadline` passed before that.
+    fn wait_until(&self, deadline: Instant) -> bool {
+        let mut deferring = self.deferring.lock().unwrap();
+        while *deferring > 0 {
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            deferring = self
+                .released
+                .wait_timeout(deferring, deadline - now)
+                .unwrap()
+                .0;
+        }
+        true
+    }
+}
+
+/// Holds back the dispatch of `SyncBus::process`, see
+/// `SyncBus::defer_dispatch`
+pub struct DeferGuard {
+    gate: Arc<Gate>,
+}
+impl Drop for DeferGuard {
+    fn drop(&mut self) {
+        let mut deferring = self.gate.deferring.lock().unwrap();
+        *deferring -= 1;
+        if *deferring == 0 {
+            self.gate.released.notify_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gate_waits_for_the_guards() {
+        let gate = Arc::new(Gate::default());
+        let first = gate.defer();
+        let second = gate.defer();
+        drop(first);
+        assert!(!gate.wait_until(Instant::now() + Duration::from_millis(10)));
+
+        let waiter = std::thread::spawn({
+            let gate = gate.clone();
+            move || gate.wait_until(Instant::now() + Duration::from_secs(5))
+        });
+        drop(second);
+        assert!(waiter.join().unwrap());
+    }
+}
@@ -0,0 +1,636 @@
+//! Testing applications without an IBus daemon
+//!
+//! `MockServer` starts a private `dbus-daemon` and serves
+//! `org.freedesktop.IBus` on it, so the IME handling of an application can
+//! be integration-tested in CI where IBus isn't installed. Only `dbus-daemon`
+//! is needed, it comes with the `dbus` package of the distributions.
+//!
+//! The input contexts of the mock answer the keys as told by a
+//! `MockScript`, and every call they receive is recorded.
+//!
+//! Where IBus is installed, `IbusDaemon` runs the real daemon instead, for
+//! end-to-end tests.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use ibus::testing::{MockScript, MockServer};
+//! use ibus::{keysyms, AfterCallback, Modifiers};
+//!
+//! let script = MockScript::new().preedit(["n", "ni"]).commit_after(2, "你");
+//! let server = MockServer::start(script).unwrap();
+//! let bus = server.bus().unwrap();
+//! let ctx = bus.create_input_context("test").unwrap();
+//! ctx.on_commit_text(|signal, _, _| {
+//!     assert_eq!(signal.text.as_str(), "你");
+//!     AfterCallback::Keep
+//! })
+//! .unwrap();
+//!
+//! assert!(ctx.process_key_event(keysyms::KEY_n, 49, Modifiers::empty()).unwrap());
+//! assert!(ctx.process_key_event(keysyms::KEY_i, 23, Modifiers::empty()).unwrap());
+//! while bus.process(Duration::from_millis(100)).unwrap() {}
+//! ```
+//!
+//! This module needs the `testing` feature.
+//!
+
+mod daemon;
+
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader},
+    process::{Child, Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use dbus::{
+    arg::{Append, Arg, Get},
+    blocking::Connection,
+    channel::Channel,
+    message::MessageType,
+    strings::Path,
+    Message,
+};
+use log::debug;
+
+use crate::{
+    body_signature,
+    engine::{error_reply, invalid_args, EngineSignal, PreeditFocusMode},
+    input_context::INTERFACE_NAME,
+    Bus, Error, Modifiers, SyncBus, REQ_TIMEOUT,
+};
+
+pub use daemon::*;
+
+const IBUS_NAME: &str = "org.freedesktop.IBus";
+const IBUS_PATH: &str = "/org/freedesktop/IBus";
+
+/// How often the thread of the server checks for the requests of
+/// `MockServer`
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// What the input contexts of a `MockServer` do with the key presses
+///
+/// Without any rule, no key is handled and no signal is emitted. The key
+/// presses are counted for every input context, from its creation or from
+/// the last commit. Key releases are handled if the presses are, but don't
+/// count.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MockScript {
+    commit_after: Option<(usize, String)>,
+    preedit: Vec<String>,
+}
+impl MockScript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Commits `text` on every `n`th key press, and handles the ones in
+    /// between
+    pub fn commit_after(mut self, n: usize, text: impl Into<String>) -> Self {
+        self.commit_after = Some((n.max(1), text.into()));
+        self
+    }
+
+    /// Shows the texts as the preedit text one after the other, one for
+    /// every key press, until the commit. The last one stays if there are
+    /// more presses than texts.
+    pub fn preedit<I, S>(mut self, texts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.preedit = texts.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn handles_keys(&self) -> bool {
+        self.commit_after.is_some() || !self.preedit.is_empty()
+    }
+
+    /// Returns whether the `presses`th key press is handled, the signals to
+    /// emit for it, and whether it committed
+    fn respond(&self, presses: usize) -> (bool, Vec<EngineSignal>, bool) {
+        let mut signals = Vec::new();
+        match &self.commit_after {
+            Some((n, text)) if presses >= *n => {
+                signals.push(EngineSignal::CommitText(text.clone().into()));
+                if !self.preedit.is_empty() {
+                    signals.push(preedit_signal("", false));
+                }
+                return (true, signals, true);
+            }
+            _ => {}
+        }
+        let texts = &self.preedit;
+        if let Some(text) = texts.get(presses - 1).or_else(|| texts.last()) {
+            signals.push(preedit_signal(text, true));
+        }
+        (self.handles_keys(), signals, false)
+    }
+}
+
+fn preedit_signal(text: &str, visible: bool) -> EngineSignal {
+    EngineSignal::UpdatePreeditText {
+        text: text.to_owned().into(),
+        cursor_pos: text.chars().count() as u32,
+        visible,
+        mode: PreeditFocusMode::Clear,
+    }
+}
+
+/// A call received by an input context of a `MockServer`
+#[derive(Debug)]
+pub struct MockCall {
+    pub input_context: Path<'static>,
+    pub method: String,
+    /// For reading the arguments
+    pub message: Message,
+}
+
+enum Request {
+    Emit(Path<'static>, Box<EngineSignal>),
+    Stop,
+}
+
+/// A private bus with a mock of the IBus daemon on it, see the module
+/// documentation
+///
+/// The bus and its daemon are stopped when this is dropped.
+pub struct MockServer {
+    bus: PrivateBus,
+    requests: mpsc::Sender<Request>,
+    calls: Arc<Mutex<Vec<MockCall>>>,
+    thread: Option<JoinHandle<()>>,
+}
+impl MockServer {
+    /// Starts `dbus-daemon`, and a thread that serves the mock on it
+    pub fn start(script: MockScript) -> Result<Self, Error> {
+        let bus = PrivateBus::start()?;
+        let (requests, receiver) = mpsc::channel();
+        let (ready, wait_ready) = mpsc::channel();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let thread = std::thread::spawn({
+            let address = bus.address.clone();
+            let calls = calls.clone();
+            move || {
+                let conn = match connect(&address).and_then(|conn| {
+                    conn.request_name(IBUS_NAME, false, true, true)?;
+                    Ok(conn)
+                }) {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        let _ = ready.send(Err(e));
+                        return;
+                    }
+                };
+                let _ = ready.send(Ok(()));
+                let mut server = Server {
+                    script,
+                    contexts: HashMap::new(),
+                    next_id: 1,
+                    calls,
+                };
+                server.serve(&conn, receiver);
+            }
+        });
+        let server = MockServer {
+            bus,
+            requests,
+            calls,
+            thread: Some(thread),
+        };
+        match wait_ready.recv() {
+            Ok(Ok(())) => Ok(server),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(Error::Unknown {
+                description: "The thread of the mock server panicked".into(),
+            }),
+        }
+    }
+
+    /// The D-Bus address of the private bus
+    pub fn address(&self) -> &str {
+        &self.bus.address
+    }
+
+    /// Connects to the mock
+    pub fn bus(&self) -> Result<Bus, Error> {
+        Bus::with_address(self.address())
+    }
+
+    pub fn sync_bus(&self) -> Result<SyncBus, Error> {
+        SyncBus::with_address(self.address())
+    }
+
+    /// Emits a signal on an input context, as if its engine sent it
+    ///
+    /// The mode of `EngineSignal::UpdatePreeditText` is dropped, like the
+    /// daemon does for the applications.
+    pub fn emit(&self, input_context: &Path<'static>, signal: EngineSignal) {
+        let _ = self
+            .requests
+            .send(Request::Emit(input_context.clone(), Box::new(signal)));
+    }
+
+    /// Returns the calls received since the last time, in order
+    pub fn take_calls(&self) -> Vec<MockCall> {
+        std::mem::take(&mut *self.calls.lock().unwrap())
+    }
+}
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        let _ = self.requests.send(Request::Stop);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A `dbus-daemon` that only this process knows about
+struct PrivateBus {
+    address: String,
+    daemon: Child,
+}
+impl PrivateBus {
+    fn start() -> Result<Self, Error> {
+        let mut daemon = Command::new("dbus-daemon")
+            .args(["--session", "--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let mut address = String::new();
+        let stdout = daemon.stdout.take().expect("the stdout should be piped");
+        let read = BufReader::new(stdout).read_line(&mut address);
+        // Killed by `drop` if it fails
+        let bus = PrivateBus {
+            address: address.trim().to_owned(),
+            daemon,
+        };
+        read?;
+        if bus.address.is_empty() {
+            return Err(Error::Unknown {
+                description: "dbus-daemon didn't print its address".into(),
+            });
+        }
+        Ok(bus)
+    }
+
+    fn connect(&self) -> Result<Connection, Error> {
+        connect(&self.address)
+    }
+}
+impl Drop for PrivateBus {
+    fn drop(&mut self) {
+        let _ = self.daemon.kill();
+        let _ = self.daemon.wait();
+    }
+}
+
+fn connect(address: &str) -> Result<Connection, Error> {
+    let mut channel = Channel::open_private(address)?;
+    channel.register()?;
+    Ok(Connection::from(channel))
+}
+
+/// Sends values to itself over a private bus, to check that they're
+/// serialized the way the other D-Bus implementations expect
+///
+/// `dbus-daemon` validates the messages it relays, and drops the connection
+/// that sent an invalid one, so a value that only reads back from the
+/// `Message` it was appended to can still fail here.
+///
+/// ```no_run
+/// use ibus::{testing::Loopback, Text};
+///
+/// let loopback = Loopback::new().unwrap();
+/// let text = Text::from("abc".to_owned());
+/// assert_eq!(loopback.round_trip(&text).unwrap(), text);
+/// ```
+pub struct Loopback {
+    sender: Connection,
+    receiver: Connection,
+    _bus: PrivateBus,
+}
+impl Loopback {
+    /// Starts `dbus-daemon`, and connects to it twice
+    pub fn new() -> Result<Self, Error> {
+        let bus = PrivateBus::start()?;
+        Ok(Loopback {
+            sender: bus.connect()?,
+            receiver: bus.connect()?,
+            _bus: bus,
+        })
+    }
+
+    /// Sends `value` from a connection to the other, and reads it back
+    ///
+    /// Also fails if the signature of the received message isn't the one
+    /// that `T` claims.
+    pub fn round_trip<T>(&self, value: &T) -> Result<T, Error>
+    where
+        T: Arg + Append + for<'a> Get<'a>,
+    {
+        let mut msg = Message::new_method_call(
+            self.receiver.unique_name(),
+            "/",
+            LOOPBACK_INTERFACE,
+            LOOPBACK_METHOD,
+        )
+        .expect("the loopback call should be valid")
+        .append1(value);
+        msg.set_no_reply(true);
+        let sender = self.sender.channel();
+        if sender.send(msg).is_err() {
+            return Err(loopback_error("Couldn't send the value".into()));
+        }
+        sender.flush();
+
+        let receiver = self.receiver.channel();
+        let deadline = Instant::now() + REQ_TIMEOUT;
+        loop {
+            while let Some(msg) = receiver.pop_message() {
+                if msg.member().as_deref() != Some(LOOPBACK_METHOD) {
+                    continue;
+                }
+                let expected = T::signature();
+                let signature = body_signature(&msg);
+                if *signature != *expected {
+                    return Err(loopback_error(format!(
+                        "The value was sent as `{}`, not as `{}`",
+                        signature, &*expected
+                    )));
+                }
+                return msg
+                    .read1()
+                    .map_err(|e| loopback_error(format!("Couldn't read the value back: {}", e)));
+            }
+            let now = Instant::now();
+            if now >= deadline || receiver.read_write(Some(deadline - now)).is_err() {
+                return Err(loopback_error(
+                    "The value wasn't delivered, the bus probably rejected it".into(),
+                ));
+            }
+        }
+    }
+}
+
+const LOOPBACK_INTERFACE: &str = "org.freedesktop.IBus.Loopback";
+const LOOPBACK_METHOD: &str = "RoundTrip";
+
+fn loopback_error(description: String) -> Error {
+    Error::Unknown { description }
+}
+
+struct Server {
+    script: MockScript,
+    /// The key presses of every input context, see `MockScript`
+    contexts: HashMap<Path<'static>, usize>,
+    next_id: u32,
+    calls: Arc<Mutex<Vec<MockCall>>>,
+}
+impl Server {
+    fn serve(&mut self, conn: &Connection, requests: mpsc::Receiver<Request>) {
+        let channel = conn.channel();
+        loop {
+            loop {
+                match requests.try_recv() {
+                    Ok(Request::Emit(path, signal)) => {
+                        let _ = channel.send(signal.to_input_context_message(&path));
+                    }
+                    Ok(Request::Stop) | Err(mpsc::TryRecvError::Disconnected) => return,
+                    Err(mpsc::TryRecvError::Empty) => break,
+                }
+            }
+            if channel.read_write(Some(POLL_INTERVAL)).is_err() {
+                debug!("The private bus of the mock server is gone");
+                return;
+            }
+            while let Some(msg) = channel.pop_message() {
+                for reply in self.handle(msg) {
+                    let _ = channel.send(reply);
+                }
+            }
+            channel.flush();
+        }
+    }
+
+    /// Returns the signals and the reply for a method call
+    fn handle(&mut self, msg: Message) -> Vec<Message> {
+        if msg.msg_type() != MessageType::MethodCall {
+            return Vec::new();
+        }
+        let path = match msg.path() {
+            Some(path) => path.into_static(),
+            None => return Vec::new(),
+        };
+        let interface = msg.interface().map(|i| i.to_string());
+        let member = msg.member().map(|m| m.to_string()).unwrap_or_default();
+        match interface.as_deref() {
+            Some(IBUS_NAME) if &*path == IBUS_PATH && member == "CreateInputContext" => {
+                let path = Path::from(format!("{}/InputContext_{}", IBUS_PATH, self.next_id));
+                self.next_id += 1;
+                self.contexts.insert(path.clone(), 0);
+                vec![msg.method_return().append1(path)]
+            }
+            Some(INTERFACE_NAME) if self.contexts.contains_key(&path) => {
+                let messages = self.dispatch_context(&msg, &path, &member);
+                self.calls.lock().unwrap().push(MockCall {
+                    input_context: path,
+                    method: member,
+                    message: msg,
+                });
+                messages
+            }
+            _ => vec![error_reply(
+                &msg,
+                "org.freedesktop.DBus.Error.UnknownMethod",
+                format!(
+                    "The mock doesn't have {:?}.{} on {}",
+                    interface, member, path
+                ),
+            )],
+        }
+    }
+
+    fn dispatch_context(
+        &mut self,
+        msg: &Message,
+        path: &Path<'static>,
+        method: &str,
+    ) -> Vec<Message> {
+        match method {
+            "ProcessKeyEvent" => {
+                let (_sym, _code, state): (u32, u32, u32) = match msg.read3() {
+                    Ok(args) => args,
+                    Err(e) => return vec![invalid_args(msg, e)],
+                };
+                let (handled, signals) =
+                    if Modifiers::from_bits_truncate(state).contains(Modifiers::RELEASE) {
+                        (self.script.handles_keys(), Vec::new())
+                    } else {
+                        let presses = self.contexts.get_mut(path).unwrap();
+                        *presses += 1;
+                        let (handled, signals, committed) = self.script.respond(*presses);
+                        if committed {
+                            *presses = 0;
+                        }
+                        (handled, signals)
+                    };
+                let mut messages: Vec<Message> = signals
+                    .iter()
+                    .map(|signal| signal.to_input_context_message(path))
+                    .collect();
+                messages.push(msg.method_return().append1(handled));
+                messages
+            }
+            "Reset" | "FocusOut" => {
+                self.contexts.insert(path.clone(), 0);
+                vec![msg.method_return()]
+            }
+            "Destroy" => {
+                self.contexts.remove(path);
+                vec![msg.method_return()]
+            }
+            "GetEngine" => vec![error_reply(
+                msg,
+                "org.freedesktop.DBus.Error.Failed",
+                "The mock doesn't have engines".into(),
+            )],
+            _ => vec![msg.method_return()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::{
+        panel::extension::{ExtensionEvent, ExtensionKind},
+        Attribute, AttributeKind, ComponentBuilder, EngineDescBuilder, LookupTable, PropList,
+        PropState, PropType, Property, Text, UnderlineKind,
+    };
+
+    fn check<T>(loopback: &Loopback, value: T)
+    where
+        T: Arg + Append + for<'a> Get<'a> + PartialEq + Debug,
+    {
+        assert_eq!(loopback.round_trip(&value).unwrap(), value);
+    }
+
+    #[test]
+    fn loopback_round_trips() {
+        let loopback = Loopback::new().expect("needs dbus-daemon");
+
+        let underline = Attribute {
+            kind: AttributeKind::Underline(UnderlineKind::Single),
+            start_index: 0,
+            end_index: 2,
+        };
+        check(&loopback, underline.clone());
+        let colored = Attribute {
+            kind: AttributeKind::Foreground(0xff0000),
+            start_index: 1,
+            end_index: 3,
+        };
+        let text = Text::new("nǐ好".to_owned(), vec![underline, colored]);
+        check(&loopback, text.clone());
+        check(&loopback, Text::from(String::new()));
+
+        let mut table = LookupTable::new(5, 1, true, false);
+        table.append_candidate(text);
+        table.append_candidate("你".to_owned());
+        table.append_label("a".to_owned());
+        check(&loopback, table);
+
+        let mut menu = Property::input_mode("あ".to_owned(), "Hiragana".to_owned());
+        menu.prop_type = PropType::Menu;
+        let mut radio = Property::new("Katakana", PropType::Radio);
+        radio.state = PropState::Checked;
+        menu.sub_props.push(radio);
+        check(&loopback, menu.clone());
+        let mut props = PropList::new();
+        props.push(menu);
+        props.push(Property::new("Settings", PropType::Normal));
+        check(&loopback, props);
+
+        let desc = EngineDescBuilder::new("test")
+            .language("ja")
+            .rank(10)
+            .symbol("あ")
+            .build();
+        check(&loopback, desc.clone());
+        let component = ComponentBuilder::new("org.freedesktop.IBus.Test")
+            .exec("/usr/bin/test --ibus")
+            .engine(desc)
+            .build();
+        check(&loopback, component);
+
+        let mut event = ExtensionEvent::new(ExtensionKind::Emoji, true);
+        event.params = "smile".into();
+        check(&loopback, event);
+    }
+
+    /// Drops the server under a bus, and runs `process` until it fails
+    fn disconnect<P>(process: P) -> bool
+    where
+        P: Fn(Duration) -> Result<bool, Error>,
+    {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if process(Duration::from_millis(100)).is_err() {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn disconnect_callback() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let server = MockServer::start(MockScript::new()).expect("needs dbus-daemon");
+        let bus = server.bus().unwrap();
+        let sync_bus = server.sync_bus().unwrap();
+        let called = Arc::new(AtomicBool::new(false));
+        let sync_called = Arc::new(AtomicBool::new(false));
+        bus.on_disconnect({
+            let called = called.clone();
+            move || called.store(true, Ordering::SeqCst)
+        });
+        sync_bus.on_disconnect({
+            let called = sync_called.clone();
+            move || called.store(true, Ordering::SeqCst)
+        });
+        drop(server);
+
+        assert!(disconnect(|timeout| bus.process(timeout)));
+        assert!(called.load(Ordering::SeqCst));
+        assert!(disconnect(|timeout| sync_bus.process(timeout)));
+        assert!(sync_called.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn script_responses() {
+        let script = MockScript::new().preedit(["n", "ni"]).commit_after(3, "你");
+        let (handled, signals, committed) = script.respond(1);
+        assert!(handled && !committed);
+        assert_eq!(signals, vec![preedit_signal("n", true)]);
+        let (_, signals, _) = script.respond(2);
+        assert_eq!(signals, vec![preedit_signal("ni", true)]);
+        let (_, signals, _) = script.respond(3);
+        assert_eq!(
+            signals,
+            vec![
+                EngineSignal::CommitText("你".to_owned().into()),
+                preedit_signal("", false)
+            ]
+        );
+
+        let (handled, signals, _) = MockScript::new().respond(1);
+        assert!(!handled && signals.is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    sync::atomic::{AtomicU32, Ordering},
+    time::{Duration, Instant},
+};
+
+use super::PrivateBus;
+use crate::{Bus, Error, SyncBus};
+
+/// How long the daemon gets to open its socket
+const START_TIMEOUT: Duration = Duration::from_secs(10);
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+
+/// A real `ibus-daemon` that only this process knows about, for end-to-end
+/// tests in containers where IBus is installed but no desktop is running
+///
+/// The daemon runs without a panel and without the dconf config, with the
+/// XDG directories in a temporary directory and a private `dbus-daemon` as
+/// its session bus, so it neither sees nor changes the settings of the user.
+/// The engines are still the ones installed on the system.
+///
+/// ```no_run
+/// use ibus::testing::IbusDaemon;
+/// use ibus::{keysyms, Modifiers};
+///
+/// let daemon = IbusDaemon::start().unwrap();
+/// let bus = daemon.bus().unwrap();
+/// let ctx = bus.create_input_context("test").unwrap();
+/// // No engine is set, so the daemon doesn't handle the key
+/// assert!(!ctx.process_key_event(keysyms::KEY_a, 30, Modifiers::empty()).unwrap());
+/// ```
+///
+/// The daemon is stopped and its directory removed when this is dropped.
+pub struct IbusDaemon {
+    address: String,
+    dir: PathBuf,
+    daemon: Child,
+    _session: PrivateBus,
+}
+impl IbusDaemon {
+    /// Starts `dbus-daemon` and `ibus-daemon`, and waits until the latter
+    /// accepts connections
+    ///
+    /// Fails with an `Error::Io` of the kind `NotFound` where either of them
+    /// isn't installed, tests can be skipped on that.
+    pub fn start() -> Result<Self, Error> {
+        let session = PrivateBus::start()?;
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("ibus-rs-{}-{}", std::process::id(), id));
+        for sub in ["config", "cache", "data", "runtime"] {
+            fs::create_dir_all(dir.join(sub))?;
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            // Required of XDG_RUNTIME_DIR
+            fs::set_permissions(dir.join("runtime"), fs::Permissions::from_mode(0o700))?;
+        }
+        let address = format!("unix:path={}", dir.join("ibus").display());
+        let daemon = Command::new("ibus-daemon")
+            .args(["--panel", "disable", "--config", "disable", "--address"])
+            .arg(&address)
+            .env("XDG_CONFIG_HOME", dir.join("config"))
+            .env("XDG_CACHE_HOME", dir.join("cache"))
+            .env("XDG_DATA_HOME", dir.join("data"))
+            .env("XDG_RUNTIME_DIR", dir.join("runtime"))
+            .env("DBUS_SESSION_BUS_ADDRESS", &session.address)
+            .env("GSETTINGS_BACKEND", "memory")
+            .env_remove("IBUS_ADDRESS")
+            .env_remove("DISPLAY")
+            .env_remove("WAYLAND_DISPLAY")
+            .stdin(Stdio::null())
+            .spawn();
+        let daemon = match daemon {
+            Ok(daemon) => daemon,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&dir);
+                return Err(e.into());
+            }
+        };
+        // Stopped by `drop` if it doesn't start
+        let mut daemon = IbusDaemon {
+            address,
+            dir,
+            daemon,
+            _session: session,
+        };
+        daemon.wait_ready()?;
+        Ok(daemon)
+    }
+
+    fn wait_ready(&mut self) -> Result<(), Error> {
+        let deadline = Instant::now() + START_TIMEOUT;
+        loop {
+            match self.bus() {
+                Ok(_) => return Ok(()),
+                Err(e) if Instant::now() >= deadline => return Err(e),
+                Err(_) => {}
+            }
+            if let Some(status) = self.daemon.try_wait()? {
+                return Err(Error::Unknown {
+                    description: format!("ibus-daemon exited with {}", status),
+                });
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    /// The D-Bus address of the daemon, for `IBUS_ADDRESS` of the
+    /// processes started by the test
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Connects to the daemon
+    pub fn bus(&self) -> Result<Bus, Error> {
+        Bus::with_address(&self.address)
+    }
+
+    pub fn sync_bus(&self) -> Result<SyncBus, Error> {
+        SyncBus::with_address(&self.address)
+    }
+}
+impl Drop for IbusDaemon {
+    fn drop(&mut self) {
+        let _ = self.daemon.kill();
+        let _ = self.daemon.wait();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keysyms, Capabilites, Modifiers};
+
+    #[test]
+    #[ignore = "needs ibus-daemon, run with `cargo test --features testing -- --ignored`"]
+    fn end_to_end() {
+        let daemon = IbusDaemon::start().expect("Couldn't start ibus-daemon");
+        let bus = daemon.bus().unwrap();
+        let ctx = bus.create_input_context("ibus-rs test").unwrap();
+        ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+        ctx.focus_in().unwrap();
+        assert!(!ctx
+            .process_key_event(keysyms::KEY_a, 30, Modifiers::empty())
+            .unwrap());
+        ctx.focus_out().unwrap();
+    }
+}
@@ -4,17 +4,17 @@
 //! underlining, foreground and background color
 //!
 
-use std::{any::Any, borrow::Cow, os::raw::c_uint};
+use std::{any::Any, borrow::Cow, ops::Range, os::raw::c_uint};
 
-use log::{debug, warn};
+use log::debug;
 
 use dbus::arg::{Append, Arg, ArgType, Get, PropMap, RefArg, Variant};
 
-const ATTRIBUTE_NAME: &'static str = "IBusAttribute";
-const ATTRIBUTE_LIST_NAME: &'static str = "IBusAttrList";
-const TEXT_NAME: &'static str = "IBusText";
+const ATTRIBUTE_NAME: &str = "IBusAttribute";
+const ATTRIBUTE_LIST_NAME: &str = "IBusAttrList";
+const TEXT_NAME: &str = "IBusText";
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnderlineKind {
     None,
     Single,
@@ -46,7 +46,7 @@ impl UnderlineKind {
 }
 
 /// A string attribute kind
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AttributeKind {
     Underline(UnderlineKind),
 
@@ -67,10 +67,31 @@ pub enum AttributeKind {
     ///
     /// See: `Foreground`
     Background(u32),
+
+    /// An attribute type that this crate doesn't know about
+    ///
+    /// It's kept as-is so that forwarding a received `Text` doesn't lose
+    /// information. This is also used for underline attributes with an
+    /// unknown underline value.
+    Other {
+        kind: u32,
+        value: u32,
+    },
+}
+impl AttributeKind {
+    /// Returns the attribute type and value as they appear on the wire
+    fn to_raw(self) -> (c_uint, c_uint) {
+        match self {
+            AttributeKind::Underline(v) => (1, v.to_value()),
+            AttributeKind::Foreground(c) => (2, c as c_uint),
+            AttributeKind::Background(c) => (3, c as c_uint),
+            AttributeKind::Other { kind, value } => (kind as c_uint, value as c_uint),
+        }
+    }
 }
 
 /// A string attribute
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Attribute {
     pub kind: AttributeKind,
 
@@ -96,22 +117,7 @@ impl RefArg for Attribute {
     }
 
     fn append(&self, i: &mut dbus::arg::IterAppend) {
-        let type_: c_uint;
-        let value: c_uint;
-        match self.kind {
-            AttributeKind::Underline(v) => {
-                type_ = 1;
-                value = v.to_value();
-            }
-            AttributeKind::Foreground(c) => {
-                type_ = 2;
-                value = c as c_uint;
-            }
-            AttributeKind::Background(c) => {
-                type_ = 3;
-                value = c as c_uint;
-            }
-        }
+        let (type_, value) = self.kind.to_raw();
         i.append(Variant((
             ATTRIBUTE_NAME,
             PropMap::new(),
@@ -175,15 +181,21 @@ impl<'a> Get<'a> for Attribute {
         let end_index = attrib_struct.5;
 
         let kind = match type_ {
-            1 => AttributeKind::Underline(UnderlineKind::from_value(value)?),
+            1 => match UnderlineKind::from_value(value) {
+                Some(underline) => AttributeKind::Underline(underline),
+                None => {
+                    debug!("Unexpected underline value `{}`", value);
+                    AttributeKind::Other { kind: type_, value }
+                }
+            },
             2 => AttributeKind::Foreground(value),
             3 => AttributeKind::Background(value),
             _ => {
-                warn!(
+                debug!(
                     "Unexpected attribute type `{}` for {}",
                     type_, ATTRIBUTE_NAME
                 );
-                return None;
+                AttributeKind::Other { kind: type_, value }
             }
         };
         Some(Attribute {
@@ -199,11 +211,7 @@ type SerializedAttrList<'a> = (&'a str, PropMap, Vec<Attribute>);
 fn serialize_attribute_list(
     attributes: &[Attribute],
 ) -> Variant<(&'static str, PropMap, Vec<Attribute>)> {
-    Variant((
-        ATTRIBUTE_LIST_NAME,
-        PropMap::new(),
-        attributes.iter().map(|a| a.clone()).collect::<Vec<_>>(),
-    ))
+    Variant((ATTRIBUTE_LIST_NAME, PropMap::new(), attributes.to_vec()))
 }
 
 fn deserialize_attribute_list<'a>(
@@ -225,7 +233,10 @@ fn deserialize_attribute_list<'a>(
 }
 
 /// Contains a string and a list of attributes
-#[derive(Debug, Clone)]
+///
+/// Equality compares the attributes in order. See `eq_ignore_attribute_order`
+/// for a comparison that doesn't depend on the order of the attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Text<'a> {
     string: Cow<'a, str>,
     attributes: Vec<Attribute>,
@@ -259,6 +270,69 @@ impl<'a> Text<'a> {
     pub fn into_string(self) -> String {
         self.string.into_owned()
     }
+
+    /// Returns the attributes that overlap with `char_range`, clipped to it.
+    ///
+    /// The indices of the returned attributes are relative to the start of
+    /// `char_range`. Like the attribute indices, the range is counting in
+    /// UTF32 characters.
+    pub fn attributes_in_range(&self, char_range: Range<u32>) -> Vec<Attribute> {
+        self.attributes
+            .iter()
+            .filter_map(|a| {
+                let start = a.start_index.max(char_range.start);
+                let end = a.end_index.min(char_range.end);
+                if start >= end {
+                    return None;
+                }
+                Some(Attribute {
+                    kind: a.kind,
+                    start_index: start - char_range.start,
+                    end_index: end - char_range.start,
+                })
+            })
+            .collect()
+    }
+
+    /// Splits the text into two at the given character (UTF32) index.
+    ///
+    /// The attributes are split as well, and the attributes of the second
+    /// text are re-based so that they are relative to its start.
+    ///
+    /// If `char_index` is past the end of the string, the second text will be
+    /// empty.
+    pub fn split_at(&self, char_index: u32) -> (Text<'_>, Text<'_>) {
+        let byte_index = self
+            .string
+            .char_indices()
+            .nth(char_index as usize)
+            .map_or(self.string.len(), |(i, _)| i);
+        let (first, second) = self.string.split_at(byte_index);
+        (
+            Text::new(first, self.attributes_in_range(0..char_index)),
+            Text::new(second, self.attributes_in_range(char_index..u32::MAX)),
+        )
+    }
+
+    /// Returns true if both texts have the same string and the same set of
+    /// attributes, regardless of the order in which the attributes are listed.
+    pub fn eq_ignore_attribute_order(&self, other: &Text) -> bool {
+        if self.string != other.string || self.attributes.len() != other.attributes.len() {
+            return false;
+        }
+        // `AttributeKind` isn't ordered, so each attribute is matched with an
+        // equal one that wasn't matched yet
+        let mut unmatched: Vec<&Attribute> = other.attributes.iter().collect();
+        self.attributes.iter().all(|attribute| {
+            match unmatched.iter().position(|other| *other == attribute) {
+                Some(index) => {
+                    unmatched.swap_remove(index);
+                    true
+                }
+                None => false,
+            }
+        })
+    }
 }
 impl<'a> From<&'a str> for Text<'a> {
     #[inline]
@@ -361,3 +435,198 @@ impl<'a> Get<'a> for Text<'static> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn unknown_attribute_kind_round_trip() {
+        let text = Text::new(
+            "abc",
+            vec![
+                Attribute {
+                    kind: AttributeKind::Other { kind: 7, value: 42 },
+                    start_index: 0,
+                    end_index: 2,
+                },
+                Attribute {
+                    kind: AttributeKind::Other { kind: 1, value: 99 },
+                    start_index: 1,
+                    end_index: 3,
+                },
+            ],
+        );
+        let msg = dbus::Message::new_method_call("a.b", "/a/b", "a.b", "C")
+            .unwrap()
+            .append1(text);
+        let read: Text = msg.read1().unwrap();
+        assert_eq!(read.as_str(), "abc");
+        let kinds: Vec<_> = read
+            .attributes()
+            .iter()
+            .map(|a| match a.kind {
+                AttributeKind::Other { kind, value } => (kind, value),
+                _ => panic!("unexpected attribute kind {:?}", a.kind),
+            })
+            .collect();
+        assert_eq!(kinds, vec![(7, 42), (1, 99)]);
+    }
+
+    #[test]
+    fn split_rebases_attributes() {
+        let text = Text::new(
+            "aéb€c",
+            vec![Attribute {
+                kind: AttributeKind::Underline(UnderlineKind::Single),
+                start_index: 1,
+                end_index: 4,
+            }],
+        );
+        let (first, second) = text.split_at(2);
+        assert_eq!(first.as_str(), "aé");
+        assert_eq!(second.as_str(), "b€c");
+        let ranges = |t: &Text| {
+            t.attributes()
+                .iter()
+                .map(|a| (a.start_index, a.end_index))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranges(&first), vec![(1, 2)]);
+        assert_eq!(ranges(&second), vec![(0, 2)]);
+        assert!(text.attributes_in_range(4..5).is_empty());
+
+        let (all, rest) = text.split_at(10);
+        assert_eq!(all, text);
+        assert_eq!(all.as_str(), "aéb€c");
+        assert_eq!(rest.as_str(), "");
+    }
+
+    #[test]
+    fn attribute_order_insensitive_eq() {
+        let underline = Attribute {
+            kind: AttributeKind::Underline(UnderlineKind::Single),
+            start_index: 0,
+            end_index: 1,
+        };
+        let background = Attribute {
+            kind: AttributeKind::Background(0xff0000),
+            start_index: 0,
+            end_index: 2,
+        };
+        let a = Text::new("ab", vec![underline.clone(), background.clone()]);
+        let b = Text::new("ab", vec![background, underline]);
+        assert_ne!(a, b);
+        assert!(a.eq_ignore_attribute_order(&b));
+        assert!(!a.eq_ignore_attribute_order(&Text::from("ab")));
+
+        // The same raw type and value as `underline`, but not equal to it
+        let other = Attribute {
+            kind: AttributeKind::Other { kind: 1, value: 1 },
+            start_index: 0,
+            end_index: 1,
+        };
+        let c = Text::new("ab", vec![a.attributes()[1].clone(), other]);
+        assert_ne!(a.attributes()[0], c.attributes()[1]);
+        assert!(!a.eq_ignore_attribute_order(&c));
+    }
+
+    fn round_trip<T: Arg + Append + for<'a> Get<'a>>(value: T) -> T {
+        dbus::Message::new_method_call("a.b", "/a/b", "a.b", "C")
+            .unwrap()
+            .append1(value)
+            .read1()
+            .unwrap()
+    }
+
+    /// Strings heavy on combining characters and emoji, which are several
+    /// bytes in UTF-8 and may be several chars per grapheme
+    fn string() -> impl Strategy<Value = String> {
+        let c = prop_oneof![
+            any::<char>().prop_filter("D-Bus strings can't contain NUL", |c| *c != '\0'),
+            prop::char::range('\u{300}', '\u{36f}'),
+            prop::char::range('\u{1f300}', '\u{1f64f}'),
+            Just('\u{200d}'),
+            Just('\u{fe0f}'),
+        ];
+        prop::collection::vec(c, 0..24).prop_map(|chars| chars.into_iter().collect())
+    }
+
+    /// Only kinds that read back as themselves, `Other` can't be a known one
+    fn attribute_kind() -> impl Strategy<Value = AttributeKind> {
+        let underline = prop_oneof![
+            Just(UnderlineKind::None),
+            Just(UnderlineKind::Single),
+            Just(UnderlineKind::Double),
+            Just(UnderlineKind::Low),
+            Just(UnderlineKind::Error),
+        ];
+        prop_oneof![
+            underline.prop_map(AttributeKind::Underline),
+            any::<u32>().prop_map(AttributeKind::Foreground),
+            any::<u32>().prop_map(AttributeKind::Background),
+            (4..u32::MAX, any::<u32>())
+                .prop_map(|(kind, value)| AttributeKind::Other { kind, value }),
+            (5..u32::MAX).prop_map(|value| AttributeKind::Other { kind: 1, value }),
+        ]
+    }
+
+    /// A text whose attributes are within its string
+    fn text() -> impl Strategy<Value = Text<'static>> {
+        string().prop_flat_map(|string| {
+            let len = string.chars().count() as u32;
+            let attribute =
+                (attribute_kind(), 0..=len, 0..=len).prop_map(|(kind, a, b)| Attribute {
+                    kind,
+                    start_index: a.min(b),
+                    end_index: a.max(b),
+                });
+            prop::collection::vec(attribute, 0..6)
+                .prop_map(move |attributes| Text::new(string.clone(), attributes))
+        })
+    }
+
+    fn char_len(text: &Text) -> u32 {
+        text.as_str().chars().count() as u32
+    }
+
+    proptest! {
+        #[test]
+        fn text_serialization_round_trip(text in text()) {
+            prop_assert_eq!(round_trip(text.clone()), text);
+        }
+
+        #[test]
+        fn attribute_serialization_round_trip(
+            kind in attribute_kind(),
+            start_index in any::<u32>(),
+            end_index in any::<u32>(),
+        ) {
+            let attribute = Attribute { kind, start_index, end_index };
+            prop_assert_eq!(round_trip(attribute.clone()), attribute);
+        }
+
+        #[test]
+        fn split_keeps_the_text(text in text(), index in 0..30u32) {
+            let (first, second) = text.split_at(index);
+            prop_assert_eq!(format!("{}{}", first.as_str(), second.as_str()), text.as_str());
+            prop_assert_eq!(char_len(&first), index.min(char_len(&text)));
+            for part in [&first, &second] {
+                for a in part.attributes() {
+                    prop_assert!(a.start_index < a.end_index);
+                    prop_assert!(a.end_index <= char_len(part));
+                }
+            }
+        }
+
+        #[test]
+        fn attributes_in_range_are_clipped(text in text(), start in 0..30u32, len in 0..30u32) {
+            for a in text.attributes_in_range(start..start + len) {
+                prop_assert!(a.start_index < a.end_index);
+                prop_assert!(a.end_index <= len);
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! The checks of the arguments of the input context calls, done before the
+//! call is sent
+//!
+//! The daemon forwards the arguments to the engine as they are, and some
+//! engines misbehave or crash on the invalid ones instead of answering with
+//! an error.
+
+use crate::{input_context::INTERFACE_NAME, CallContext, Error, Modifiers, Text};
+
+fn invalid(path: &str, member: &str, description: String) -> Error {
+    Error::InvalidArgument {
+        call: CallContext::new(path, INTERFACE_NAME, member),
+        description,
+    }
+}
+
+/// A key press must have a keysym, only the releases may come without one
+pub(crate) fn key_event(path: &str, sym: u32, modifiers: Modifiers) -> Result<(), Error> {
+    if sym == 0 && !modifiers.contains(Modifiers::RELEASE) {
+        return Err(invalid(
+            path,
+            "ProcessKeyEvent",
+            "a key press must have a keysym".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn cursor_location(path: &str, w: i32, h: i32) -> Result<(), Error> {
+    if w < 0 || h < 0 {
+        return Err(invalid(
+            path,
+            "SetCursorLocation",
+            format!("the rectangle is inverted, its size is {}x{}", w, h),
+        ));
+    }
+    Ok(())
+}
+
+/// The positions are in characters, and may be at the end of the text
+pub(crate) fn surrounding_text(
+    path: &str,
+    text: &Text,
+    cursor_pos: u32,
+    anchor_pos: u32,
+) -> Result<(), Error> {
+    let len = text.as_str().chars().count();
+    for (name, pos) in [("cursor", cursor_pos), ("anchor", anchor_pos)] {
+        if pos as usize > len {
+            return Err(invalid(
+                path,
+                "SetSurroundingText",
+                format!(
+                    "the {} is at {}, after the end of the text of {} characters",
+                    name, pos, len
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "/org/freedesktop/IBus/InputContext_1";
+
+    #[test]
+    fn checks() {
+        assert!(key_event(PATH, 0x61, Modifiers::empty()).is_ok());
+        assert!(key_event(PATH, 0, Modifiers::RELEASE).is_ok());
+        assert!(key_event(PATH, 0, Modifiers::empty()).is_err());
+
+        assert!(cursor_location(PATH, 0, 0).is_ok());
+        assert!(cursor_location(PATH, -1, 10).is_err());
+
+        let text = Text::from("héllo");
+        assert!(surrounding_text(PATH, &text, 5, 0).is_ok());
+        match surrounding_text(PATH, &text, 2, 6) {
+            Err(Error::InvalidArgument { call, description }) => {
+                assert_eq!(call.member, "SetSurroundingText");
+                assert!(description.contains("anchor"));
+            }
+            r => panic!("unexpected {:?}", r),
+        }
+    }
+}
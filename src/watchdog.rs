@@ -0,0 +1,200 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use dbus::{channel::Channel, strings::Path};
+use log::warn;
+
+use crate::{dump, input_context::input_context_call, retry::is_transient, Error};
+
+/// The callback of `Watchdog::on_health_change`
+type OnChange = Arc<dyn Fn(&Path<'static>, bool) + Send + Sync>;
+
+/// Notices when the engine of an input context stops answering
+///
+/// After `threshold` calls in a row time out, the context is marked
+/// unhealthy and `on_health_change` is called. While it's unhealthy,
+/// `process_key_event` returns `Ok(false)` right away instead of blocking
+/// for the timeout on every key, so the application handles the keys itself.
+/// A key is still sent every `probe_interval`, and the context is healthy
+/// again once one of them is answered.
+///
+/// The watchdog is shared by its clones, so the application can keep one to
+/// check `is_healthy`.
+///
+/// ```no_run
+/// use ibus::{Bus, Watchdog};
+///
+/// let bus = Bus::new().unwrap();
+/// let mut ctx = bus.create_input_context("app").unwrap();
+/// let watchdog = Watchdog::new(3)
+///     .recover(true)
+///     .on_health_change(|path, healthy| eprintln!("{} healthy: {}", path, healthy));
+/// ctx.set_watchdog(Some(watchdog));
+/// ```
+#[derive(Clone)]
+pub struct Watchdog {
+    threshold: u32,
+    probe_interval: Duration,
+    recover: bool,
+    on_change: Option<OnChange>,
+    state: Arc<Mutex<State>>,
+}
+
+struct State {
+    timeouts: u32,
+    healthy: bool,
+    last_probe: Option<Instant>,
+}
+
+impl Watchdog {
+    pub fn new(threshold: u32) -> Self {
+        Watchdog {
+            threshold: threshold.max(1),
+            probe_interval: Duration::from_secs(5),
+            recover: false,
+            on_change: None,
+            state: Arc::new(Mutex::new(State {
+                timeouts: 0,
+                healthy: true,
+                last_probe: None,
+            })),
+        }
+    }
+
+    /// How often a key is sent while the context is unhealthy, 5 seconds by
+    /// default
+    pub fn probe_interval(mut self, interval: Duration) -> Self {
+        self.probe_interval = interval;
+        self
+    }
+
+    /// Whether to send `Reset` and `FocusOut` when the context becomes
+    /// unhealthy, without waiting for the replies, so that the engine drops
+    /// its preedit once it answers again
+    pub fn recover(mut self, recover: bool) -> Self {
+        self.recover = recover;
+        self
+    }
+
+    /// Calls `callback` with the path of the context and whether it's
+    /// healthy when that changes
+    pub fn on_health_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Path<'static>, bool) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.state().healthy
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether a key event should be sent, false while the context is
+    /// unhealthy and it's not time for a probe
+    pub(crate) fn should_send(&self) -> bool {
+        let mut state = self.state();
+        if state.healthy {
+            return true;
+        }
+        let now = Instant::now();
+        match state.last_probe {
+            Some(last) if now.duration_since(last) < self.probe_interval => false,
+            _ => {
+                state.last_probe = Some(now);
+                true
+            }
+        }
+    }
+
+    /// Counts the result of a call
+    pub(crate) fn record<R>(
+        &self,
+        channel: &Channel,
+        path: &Path<'static>,
+        result: &Result<R, Error>,
+    ) {
+        let timed_out = matches!(result, Err(e) if is_transient(e));
+        let healthy = match self.count(timed_out) {
+            Some(healthy) => healthy,
+            None => return,
+        };
+        if healthy {
+            warn!("The engine of {} answers again", path);
+        } else {
+            warn!(
+                "The engine of {} didn't answer {} calls in a row",
+                path, self.threshold
+            );
+            if self.recover {
+                for method in ["Reset", "FocusOut"] {
+                    let mut msg = input_context_call(path, method, ());
+                    msg.set_no_reply(true);
+                    dump::sent(&msg);
+                    let _ = channel.send(msg);
+                }
+            }
+        }
+        if let Some(on_change) = &self.on_change {
+            on_change(path, healthy);
+        }
+    }
+
+    /// Returns whether the context is healthy if that changed
+    fn count(&self, timed_out: bool) -> Option<bool> {
+        let mut state = self.state();
+        if timed_out {
+            state.timeouts += 1;
+            if state.healthy && state.timeouts >= self.threshold {
+                state.healthy = false;
+                state.last_probe = Some(Instant::now());
+                return Some(false);
+            }
+            None
+        } else {
+            state.timeouts = 0;
+            if state.healthy {
+                return None;
+            }
+            state.healthy = true;
+            Some(true)
+        }
+    }
+}
+impl std::fmt::Debug for Watchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watchdog")
+            .field("threshold", &self.threshold)
+            .field("probe_interval", &self.probe_interval)
+            .field("recover", &self.recover)
+            .field("healthy", &self.is_healthy())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unhealthy_after_the_threshold() {
+        let watchdog = Watchdog::new(2).probe_interval(Duration::from_secs(3600));
+        assert_eq!(watchdog.count(true), None);
+        assert_eq!(watchdog.count(false), None);
+        assert_eq!(watchdog.count(true), None);
+        assert_eq!(watchdog.count(true), Some(false));
+        assert!(!watchdog.clone().is_healthy());
+        // The first probe was when it became unhealthy
+        assert!(!watchdog.should_send());
+        assert_eq!(watchdog.count(true), None);
+
+        assert_eq!(watchdog.count(false), Some(true));
+        assert!(watchdog.should_send());
+    }
+}
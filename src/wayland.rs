@@ -0,0 +1,248 @@
+//! Sending the keys of a native Wayland client to IBus
+//!
+//! A client that can't use `zwp_text_input_v3`, because the compositor
+//! doesn't support it or doesn't connect it to IBus, can send the keys of
+//! its `wl_keyboard` to an input context itself. `Keyboard` keeps the keymap
+//! and the modifier state that the compositor sends, and turns the `key`
+//! events into the arguments of `InputContext::process_key_event`:
+//!
+//! ```no_run
+//! use ibus::wayland::Keyboard;
+//! use wayland_client::protocol::wl_keyboard::{self, WlKeyboard};
+//! use wayland_client::{Connection, Dispatch, QueueHandle};
+//!
+//! struct App {
+//!     keyboard: Keyboard,
+//!     ctx: ibus::InputContext,
+//! }
+//! impl Dispatch<WlKeyboard, ()> for App {
+//!     fn event(
+//!         app: &mut Self,
+//!         _: &WlKeyboard,
+//!         event: wl_keyboard::Event,
+//!         _: &(),
+//!         _: &Connection,
+//!         _: &QueueHandle<Self>,
+//!     ) {
+//!         if let Some((sym, code, state)) = app.keyboard.handle_event(event) {
+//!             if !app.ctx.process_key_event(sym, code, state).unwrap_or(false) {
+//!                 // Handle the key as usual
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The `enter` and `leave` events are a good place for `focus_in` and
+//! `focus_out`. The compositor doesn't repeat the keys, the client does with
+//! the delay and rate of `repeat_info`, and sends the repeated keys to IBus
+//! like the first one.
+//!
+//! This module needs the `wayland` feature.
+//!
+
+use std::os::fd::OwnedFd;
+
+use log::warn;
+use wayland_client::{
+    protocol::wl_keyboard::{Event, KeyState, KeymapFormat},
+    WEnum,
+};
+use xkbcommon::xkb;
+
+use crate::Modifiers;
+
+/// The real modifiers of XKB, in the order of the bits of `Modifiers`
+const MODIFIER_NAMES: [&str; 8] = [
+    xkb::MOD_NAME_SHIFT,
+    xkb::MOD_NAME_CAPS,
+    xkb::MOD_NAME_CTRL,
+    xkb::MOD_NAME_ALT,
+    xkb::MOD_NAME_NUM,
+    "Mod3",
+    xkb::MOD_NAME_LOGO,
+    "Mod5",
+];
+
+/// The keymap and the modifier state of a `wl_keyboard`
+pub struct Keyboard {
+    context: xkb::Context,
+    state: Option<xkb::State>,
+}
+impl Keyboard {
+    pub fn new() -> Self {
+        Keyboard {
+            context: xkb::Context::new(xkb::CONTEXT_NO_FLAGS),
+            state: None,
+        }
+    }
+
+    /// Handles the `keymap` and `modifiers` events, and returns the
+    /// arguments of `InputContext::process_key_event` for the `key` events
+    ///
+    /// Returns `None` for the other events, for the keys without a keysym,
+    /// and until the keymap is received.
+    pub fn handle_event(&mut self, event: Event) -> Option<(u32, u32, Modifiers)> {
+        match event {
+            Event::Keymap { format, fd, size } => {
+                self.keymap(format, fd, size);
+                None
+            }
+            Event::Modifiers {
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+                ..
+            } => {
+                self.modifiers(mods_depressed, mods_latched, mods_locked, group);
+                None
+            }
+            Event::Key { key, state, .. } => self.key(key, state),
+            _ => None,
+        }
+    }
+
+    /// Loads the keymap of the `keymap` event
+    ///
+    /// The modifier state starts empty, the compositor sends a `modifiers`
+    /// event after it.
+    pub fn keymap(&mut self, format: WEnum<KeymapFormat>, fd: OwnedFd, size: u32) {
+        self.state = None;
+        if format != WEnum::Value(KeymapFormat::XkbV1) {
+            warn!("The keymap format {:?} isn't supported", format);
+            return;
+        }
+        // Safety: the compositor sends a file that can be mapped with `size`
+        let keymap = unsafe {
+            xkb::Keymap::new_from_fd(
+                &self.context,
+                fd,
+                size as usize,
+                xkb::KEYMAP_FORMAT_TEXT_V1,
+                xkb::KEYMAP_COMPILE_NO_FLAGS,
+            )
+        };
+        match keymap {
+            Ok(Some(keymap)) => self.set_keymap(&keymap),
+            Ok(None) => warn!("Couldn't compile the keymap of the compositor"),
+            Err(e) => warn!("Couldn't read the keymap of the compositor: {}", e),
+        }
+    }
+
+    fn set_keymap(&mut self, keymap: &xkb::Keymap) {
+        self.state = Some(xkb::State::new(keymap));
+    }
+
+    /// Updates the modifier state with a `modifiers` event
+    pub fn modifiers(&mut self, depressed: u32, latched: u32, locked: u32, group: u32) {
+        if let Some(state) = &mut self.state {
+            state.update_mask(depressed, latched, locked, 0, 0, group);
+        }
+    }
+
+    /// The arguments of `InputContext::process_key_event` for a `key` event
+    pub fn key(&self, key: u32, key_state: WEnum<KeyState>) -> Option<(u32, u32, Modifiers)> {
+        let state = self.state.as_ref()?;
+        let sym = state.key_get_one_sym(xkb::Keycode::new(key + 8)).raw();
+        if sym == 0 {
+            return None;
+        }
+        let mut modifiers = self.current_modifiers()?;
+        if key_state == WEnum::Value(KeyState::Released) {
+            modifiers |= Modifiers::RELEASE;
+        }
+        Some((sym, key, modifiers))
+    }
+
+    /// The modifiers that are active, `None` until the keymap is received
+    pub fn current_modifiers(&self) -> Option<Modifiers> {
+        let state = self.state.as_ref()?;
+        let mut modifiers = Modifiers::empty();
+        for (bit, name) in MODIFIER_NAMES.iter().enumerate() {
+            if state.mod_name_is_active(name, xkb::STATE_MODS_EFFECTIVE) {
+                modifiers |= Modifiers::from_bits_truncate(1 << bit);
+            }
+        }
+        if modifiers.contains(Modifiers::MOD4) {
+            modifiers |= Modifiers::SUPER;
+        }
+        Some(modifiers)
+    }
+}
+impl Default for Keyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl std::fmt::Debug for Keyboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keyboard")
+            .field("has_keymap", &self.state.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keysyms::*;
+
+    const KEYMAP: &str = r#"xkb_keymap {
+    xkb_keycodes "test" {
+        minimum = 8;
+        maximum = 255;
+        <AC01> = 38;
+        <LFSH> = 50;
+    };
+    xkb_types "test" {
+        type "ONE_LEVEL" {
+            modifiers = none;
+            level_name[Level1] = "Any";
+        };
+        type "ALPHABETIC" {
+            modifiers = Shift + Lock;
+            map[Shift] = Level2;
+            map[Lock] = Level2;
+            level_name[Level1] = "Base";
+            level_name[Level2] = "Caps";
+        };
+    };
+    xkb_compatibility "test" {
+        interpret Shift_L {
+            action = SetMods(modifiers = Shift);
+        };
+    };
+    xkb_symbols "test" {
+        key <AC01> { type = "ALPHABETIC", [ a, A ] };
+        key <LFSH> { [ Shift_L ] };
+        modifier_map Shift { <LFSH> };
+    };
+};"#;
+
+    #[test]
+    fn keys() {
+        let mut keyboard = Keyboard::new();
+        assert_eq!(keyboard.key(30, WEnum::Value(KeyState::Pressed)), None);
+
+        let keymap = xkb::Keymap::new_from_string(
+            &keyboard.context,
+            KEYMAP.to_owned(),
+            xkb::KEYMAP_FORMAT_TEXT_V1,
+            xkb::KEYMAP_COMPILE_NO_FLAGS,
+        )
+        .unwrap();
+        keyboard.set_keymap(&keymap);
+        assert_eq!(
+            keyboard.key(30, WEnum::Value(KeyState::Pressed)),
+            Some((KEY_a, 30, Modifiers::empty()))
+        );
+        keyboard.modifiers(1, 0, 0, 0);
+        assert_eq!(
+            keyboard.key(30, WEnum::Value(KeyState::Released)),
+            Some((KEY_A, 30, Modifiers::SHIFT | Modifiers::RELEASE))
+        );
+        // Not in the keymap
+        assert_eq!(keyboard.key(100, WEnum::Value(KeyState::Pressed)), None);
+    }
+}
@@ -0,0 +1,327 @@
+//! Receiving the events of IBus in a winit event loop
+//!
+//! `WinitDispatcher` runs the connection on a thread, and sends the decoded
+//! `ImeEvent`s to the event loop as user events through an
+//! `EventLoopProxy`. The input context lives on that thread, calls are sent
+//! to it with `with_context`.
+//!
+//! ```no_run
+//! use ibus::{winit::WinitDispatcher, Capabilites, ImeEvent, ImeEventKind};
+//! use winit::event_loop::EventLoop;
+//!
+//! #[derive(Debug)]
+//! enum UserEvent {
+//!     Ime(ImeEvent),
+//! }
+//! impl From<ImeEvent> for UserEvent {
+//!     fn from(event: ImeEvent) -> Self {
+//!         UserEvent::Ime(event)
+//!     }
+//! }
+//!
+//! let event_loop = EventLoop::<UserEvent>::with_user_event().build().unwrap();
+//! let ime = WinitDispatcher::spawn(event_loop.create_proxy(), "my-app").unwrap();
+//! ime.with_context(|ctx| {
+//!     ctx.set_capabilities(Capabilites::PREEDIT_TEXT | Capabilites::FOCUS);
+//!     ctx.focus_in()
+//! })
+//! .unwrap()
+//! .unwrap();
+//! // In `ApplicationHandler::user_event`:
+//! // UserEvent::Ime(ImeEvent { kind: ImeEventKind::CommitText(text), .. }) => ...
+//! ```
+//!
+//! `key_event_args` converts the `KeyEvent`s of winit to the keysyms,
+//! keycodes and modifiers of IBus, and `window_origin` finds where the
+//! caret of a window is on the screen.
+//!
+//! This module needs the `winit` feature.
+//!
+
+use winit::{
+    event::{ElementState, KeyEvent},
+    event_loop::EventLoopProxy,
+    keyboard::{Key, KeyLocation, ModifiersState, NamedKey},
+    platform::scancode::PhysicalKeyExtScancode,
+    window::Window,
+};
+
+use crate::{
+    context_thread::ContextThread, keysyms, Error, ImeEvent, InputContext, Modifiers, WindowOrigin,
+};
+
+/// An input context on a thread that forwards its events to a winit event
+/// loop, see the module documentation
+///
+/// The thread stops when the dispatcher is dropped, or when the event loop
+/// exits.
+pub struct WinitDispatcher {
+    thread: ContextThread,
+}
+impl WinitDispatcher {
+    /// Connects to the daemon and creates an input context called `name` on
+    /// a new thread. Returns once the input context is created.
+    pub fn spawn<T>(proxy: EventLoopProxy<T>, name: &str) -> Result<Self, Error>
+    where
+        T: From<ImeEvent> + Send + 'static,
+    {
+        let thread = ContextThread::spawn("ibus-winit", name, move |event| {
+            proxy.send_event(event.into()).is_ok()
+        })?;
+        Ok(WinitDispatcher { thread })
+    }
+
+    /// Calls `f` with the input context on the thread of the connection, and
+    /// returns its result
+    ///
+    /// Events emitted while `f` runs are sent to the event loop after it
+    /// returns.
+    pub fn with_context<R, F>(&self, f: F) -> Result<R, Error>
+    where
+        R: Send + 'static,
+        F: FnOnce(&InputContext) -> R + Send + 'static,
+    {
+        self.thread.with_context(f)
+    }
+
+    /// Whether the thread is still running
+    pub fn is_running(&self) -> bool {
+        self.thread.is_running()
+    }
+}
+
+/// Where the content of `window` is on the screen, to convert its caret with
+/// `WindowOrigin::caret_rect`
+///
+/// ```no_run
+/// # fn update(ctx: &ibus::InputContext, window: &winit::window::Window) {
+/// // The caret at 100, 40 in the logical coordinates of the window
+/// let caret = ibus::winit::window_origin(window).caret_rect(100.0, 40.0, 1.0, 18.0);
+/// ctx.set_cursor_location(caret.x, caret.y, caret.width, caret.height)
+///     .unwrap();
+/// # }
+/// ```
+///
+/// Wayland doesn't tell the position of the windows, so it's 0, 0 there.
+pub fn window_origin(window: &Window) -> WindowOrigin {
+    let outer = window.outer_position().unwrap_or_default();
+    let inner = window.inner_position().unwrap_or(outer);
+    WindowOrigin::new((outer.x, outer.y), window.scale_factor())
+        .with_content_offset((inner.x - outer.x, inner.y - outer.y))
+}
+
+/// The keysym, the keycode and the modifiers of a winit key event, the
+/// arguments of `InputContext::process_key_event`
+///
+/// `modifiers` is the state of the last `WindowEvent::ModifiersChanged`. The
+/// keysym comes from the logical key, so it follows the layout, and the
+/// keycode is the evdev code of the physical key, or 0 if winit doesn't know
+/// it. Returns `None` for keys without a keysym, like `Key::Unidentified`.
+///
+/// ```no_run
+/// # fn handle(ctx: &ibus::InputContext, event: &winit::event::KeyEvent, modifiers: winit::keyboard::ModifiersState) {
+/// if let Some((sym, code, state)) = ibus::winit::key_event_args(event, modifiers) {
+///     let handled = ctx.process_key_event(sym, code, state).unwrap_or(false);
+///     // Handle the key as usual if `!handled`
+/// }
+/// # }
+/// ```
+pub fn key_event_args(
+    event: &KeyEvent,
+    modifiers: ModifiersState,
+) -> Option<(u32, u32, Modifiers)> {
+    let sym = keysym(&event.logical_key, event.location)?;
+    let code = event.physical_key.to_scancode().unwrap_or(0);
+    let mut state = self::modifiers(modifiers);
+    if event.state == ElementState::Released {
+        state |= Modifiers::RELEASE;
+    }
+    Some((sym, code, state))
+}
+
+/// Converts winit's modifiers to the ones of IBus, Super is also `MOD4` as
+/// in X11
+pub fn modifiers(modifiers: ModifiersState) -> Modifiers {
+    let mut state = Modifiers::empty();
+    if modifiers.shift_key() {
+        state |= Modifiers::SHIFT;
+    }
+    if modifiers.control_key() {
+        state |= Modifiers::CONTROL;
+    }
+    if modifiers.alt_key() {
+        state |= Modifiers::MOD1;
+    }
+    if modifiers.super_key() {
+        state |= Modifiers::SUPER | Modifiers::MOD4;
+    }
+    state
+}
+
+/// The keysym of a logical key, `location` tells the keypad and the right
+/// modifiers apart
+pub fn keysym(key: &Key, location: KeyLocation) -> Option<u32> {
+    match key {
+        Key::Character(s) => {
+            let mut chars = s.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            match location {
+                KeyLocation::Numpad => {
+                    keysyms::keypad_keysym(c).or_else(|| keysyms::keysym_from_char(c))
+                }
+                _ => keysyms::keysym_from_char(c),
+            }
+        }
+        Key::Named(NamedKey::Enter) if location == KeyLocation::Numpad => {
+            Some(keysyms::KEY_KP_Enter)
+        }
+        Key::Named(named) => named_keysym(*named, location == KeyLocation::Right),
+        Key::Dead(Some(c)) => dead_keysym(*c),
+        Key::Dead(None) | Key::Unidentified(_) => None,
+    }
+}
+
+fn named_keysym(key: NamedKey, right: bool) -> Option<u32> {
+    use keysyms::*;
+    let side = |left, right_sym| if right { right_sym } else { left };
+    Some(match key {
+        NamedKey::Enter => KEY_Return,
+        NamedKey::Tab => KEY_Tab,
+        NamedKey::Space => KEY_space,
+        NamedKey::Backspace => KEY_BackSpace,
+        NamedKey::Escape => KEY_Escape,
+        NamedKey::Delete => KEY_Delete,
+        NamedKey::Insert => KEY_Insert,
+        NamedKey::Home => KEY_Home,
+        NamedKey::End => KEY_End,
+        NamedKey::PageUp => KEY_Page_Up,
+        NamedKey::PageDown => KEY_Page_Down,
+        NamedKey::ArrowLeft => KEY_Left,
+        NamedKey::ArrowRight => KEY_Right,
+        NamedKey::ArrowUp => KEY_Up,
+        NamedKey::ArrowDown => KEY_Down,
+        NamedKey::Shift => side(KEY_Shift_L, KEY_Shift_R),
+        NamedKey::Control => side(KEY_Control_L, KEY_Control_R),
+        NamedKey::Alt => side(KEY_Alt_L, KEY_Alt_R),
+        NamedKey::Super => side(KEY_Super_L, KEY_Super_R),
+        NamedKey::Meta => side(KEY_Meta_L, KEY_Meta_R),
+        NamedKey::Hyper => side(KEY_Hyper_L, KEY_Hyper_R),
+        NamedKey::AltGraph => KEY_ISO_Level3_Shift,
+        NamedKey::CapsLock => KEY_Caps_Lock,
+        NamedKey::NumLock => KEY_Num_Lock,
+        NamedKey::ScrollLock => KEY_Scroll_Lock,
+        NamedKey::PrintScreen => KEY_Print,
+        NamedKey::Pause => KEY_Pause,
+        NamedKey::ContextMenu => KEY_Menu,
+        NamedKey::Compose => KEY_Multi_key,
+        NamedKey::Help => KEY_Help,
+        NamedKey::Clear => KEY_Clear,
+        NamedKey::Select => KEY_Select,
+        NamedKey::Execute => KEY_Execute,
+        NamedKey::Undo => KEY_Undo,
+        NamedKey::Redo => KEY_Redo,
+        NamedKey::Find => KEY_Find,
+        NamedKey::Cancel => KEY_Cancel,
+        // The keys of the Japanese and Korean keyboards, which IMEs use
+        NamedKey::Convert => KEY_Henkan,
+        NamedKey::NonConvert => KEY_Muhenkan,
+        NamedKey::KanaMode => KEY_Kana_Lock,
+        NamedKey::Hiragana => KEY_Hiragana,
+        NamedKey::Katakana => KEY_Katakana,
+        NamedKey::HiraganaKatakana => KEY_Hiragana_Katakana,
+        NamedKey::ZenkakuHankaku => KEY_Zenkaku_Hankaku,
+        NamedKey::KanjiMode => KEY_Kanji,
+        NamedKey::Eisu => KEY_Eisu_toggle,
+        NamedKey::Romaji => KEY_Romaji,
+        NamedKey::HangulMode => KEY_Hangul,
+        NamedKey::HanjaMode => KEY_Hangul_Hanja,
+        NamedKey::F1 => KEY_F1,
+        NamedKey::F2 => KEY_F2,
+        NamedKey::F3 => KEY_F3,
+        NamedKey::F4 => KEY_F4,
+        NamedKey::F5 => KEY_F5,
+        NamedKey::F6 => KEY_F6,
+        NamedKey::F7 => KEY_F7,
+        NamedKey::F8 => KEY_F8,
+        NamedKey::F9 => KEY_F9,
+        NamedKey::F10 => KEY_F10,
+        NamedKey::F11 => KEY_F11,
+        NamedKey::F12 => KEY_F12,
+        NamedKey::F13 => KEY_F13,
+        NamedKey::F14 => KEY_F14,
+        NamedKey::F15 => KEY_F15,
+        NamedKey::F16 => KEY_F16,
+        NamedKey::F17 => KEY_F17,
+        NamedKey::F18 => KEY_F18,
+        NamedKey::F19 => KEY_F19,
+        NamedKey::F20 => KEY_F20,
+        NamedKey::F21 => KEY_F21,
+        NamedKey::F22 => KEY_F22,
+        NamedKey::F23 => KEY_F23,
+        NamedKey::F24 => KEY_F24,
+        _ => return None,
+    })
+}
+
+/// winit reports a dead key with its spacing character
+fn dead_keysym(c: char) -> Option<u32> {
+    use keysyms::*;
+    Some(match c {
+        '`' => KEY_dead_grave,
+        '´' | '\'' => KEY_dead_acute,
+        '^' => KEY_dead_circumflex,
+        '~' => KEY_dead_tilde,
+        '¨' | '"' => KEY_dead_diaeresis,
+        '¸' => KEY_dead_cedilla,
+        '˚' => KEY_dead_abovering,
+        'ˇ' => KEY_dead_caron,
+        '¯' => KEY_dead_macron,
+        '˘' => KEY_dead_breve,
+        '˙' => KEY_dead_abovedot,
+        '˝' => KEY_dead_doubleacute,
+        '˛' => KEY_dead_ogonek,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keysyms() {
+        let key = |s: &str| Key::Character(s.into());
+        assert_eq!(
+            keysym(&key("a"), KeyLocation::Standard),
+            Some(keysyms::KEY_a)
+        );
+        assert_eq!(
+            keysym(&key("7"), KeyLocation::Numpad),
+            Some(keysyms::KEY_KP_7)
+        );
+        assert_eq!(
+            keysym(&Key::Named(NamedKey::Shift), KeyLocation::Right),
+            Some(keysyms::KEY_Shift_R)
+        );
+        assert_eq!(
+            keysym(&Key::Named(NamedKey::Enter), KeyLocation::Numpad),
+            Some(keysyms::KEY_KP_Enter)
+        );
+        assert_eq!(
+            keysym(&Key::Dead(Some('´')), KeyLocation::Standard),
+            Some(keysyms::KEY_dead_acute)
+        );
+        assert_eq!(keysym(&Key::Dead(None), KeyLocation::Standard), None);
+    }
+
+    #[test]
+    fn modifier_state() {
+        assert_eq!(
+            modifiers(ModifiersState::SHIFT | ModifiersState::SUPER),
+            Modifiers::SHIFT | Modifiers::SUPER | Modifiers::MOD4
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Sending the keys of a plain X11 application to IBus
+//!
+//! The `KeyPress` and `KeyRelease` events of X11 have a keycode and a
+//! modifier state. `key_event_args` turns them into the arguments of
+//! `InputContext::process_key_event`, with a `KeysymLookup` that finds the
+//! keysym. That's `KeyboardMapping` for xcb and x11rb, made from the reply of
+//! `GetKeyboardMapping`, or a closure that calls `XLookupKeysym` for Xlib:
+//!
+//! ```no_run
+//! use ibus::x11::{key_event_args, KeyboardMapping};
+//!
+//! # fn handle(ctx: &ibus::InputContext, min_keycode: u8, reply: (u8, Vec<u32>), event: (u8, u16)) {
+//! // From `get_keyboard_mapping(min_keycode, max_keycode - min_keycode + 1)`
+//! let (keysyms_per_keycode, keysyms) = reply;
+//! let mapping = KeyboardMapping::new(min_keycode, keysyms_per_keycode, keysyms);
+//!
+//! // For a KeyPress event
+//! let (detail, state) = event;
+//! if let Some((sym, code, modifiers)) = key_event_args(&mapping, detail, state, false) {
+//!     let handled = ctx.process_key_event(sym, code, modifiers).unwrap_or(false);
+//! }
+//! # }
+//! ```
+//!
+//! The modifier state of X11 is the same as `Modifiers`, and the keycodes are
+//! the evdev codes plus 8, so only the keysym needs a lookup.
+//!
+
+use crate::{keysyms, Modifiers};
+
+/// Finds the keysym of a key, given the keycode and the modifier state of
+/// the X11 event
+pub trait KeysymLookup {
+    fn lookup(&self, keycode: u8, state: u16) -> Option<u32>;
+}
+impl<F: Fn(u8, u16) -> Option<u32>> KeysymLookup for F {
+    fn lookup(&self, keycode: u8, state: u16) -> Option<u32> {
+        self(keycode, state)
+    }
+}
+
+/// The keysym, the keycode and the modifiers of a `KeyPress` (or a
+/// `KeyRelease` if `released`), the arguments of
+/// `InputContext::process_key_event`
+///
+/// Returns `None` if the key has no keysym.
+pub fn key_event_args(
+    lookup: &impl KeysymLookup,
+    keycode: u8,
+    state: u16,
+    released: bool,
+) -> Option<(u32, u32, Modifiers)> {
+    let sym = lookup.lookup(keycode, state)?;
+    let mut modifiers = Modifiers::from_bits_truncate(u32::from(state));
+    if released {
+        modifiers |= Modifiers::RELEASE;
+    }
+    Some((sym, u32::from(keycode).saturating_sub(8), modifiers))
+}
+
+/// The keysyms of every keycode, as `GetKeyboardMapping` returns them
+///
+/// The keysym is chosen by the rules of the core protocol, with the group
+/// in the bits 13 and 14 of the state like XKB sends it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardMapping {
+    min_keycode: u8,
+    keysyms_per_keycode: u8,
+    keysyms: Vec<u32>,
+    num_lock: Modifiers,
+}
+impl KeyboardMapping {
+    pub fn new(min_keycode: u8, keysyms_per_keycode: u8, keysyms: Vec<u32>) -> Self {
+        KeyboardMapping {
+            min_keycode,
+            keysyms_per_keycode,
+            keysyms,
+            num_lock: Modifiers::MOD2,
+        }
+    }
+
+    /// Sets the modifier that Num Lock is mapped to, `MOD2` by default
+    pub fn set_num_lock(&mut self, modifier: Modifiers) {
+        self.num_lock = modifier;
+    }
+
+    /// The keysyms of `keycode`, `NoSymbol` (0) included
+    fn keysyms(&self, keycode: u8) -> &[u32] {
+        let per_keycode = self.keysyms_per_keycode as usize;
+        let start = match keycode.checked_sub(self.min_keycode) {
+            Some(index) => index as usize * per_keycode,
+            None => return &[],
+        };
+        self.keysyms.get(start..start + per_keycode).unwrap_or(&[])
+    }
+}
+impl KeysymLookup for KeyboardMapping {
+    fn lookup(&self, keycode: u8, state: u16) -> Option<u32> {
+        let keysyms = self.keysyms(keycode);
+        let group = ((state >> 13) & 3) as usize;
+        let pair = |group: usize| {
+            let first = *keysyms.get(group * 2)?;
+            let second = keysyms.get(group * 2 + 1).copied().unwrap_or(0);
+            (first != 0 || second != 0).then_some((first, second))
+        };
+        let (first, second) = pair(group).or_else(|| pair(0))?;
+        let (first, second) = match (first, second) {
+            (first, 0) => (lower(first), upper(first)),
+            pair => pair,
+        };
+
+        let state = Modifiers::from_bits_truncate(u32::from(state));
+        let shift = state.contains(Modifiers::SHIFT);
+        let lock = state.contains(Modifiers::LOCK);
+        let sym = if state.intersects(self.num_lock) && is_keypad(second) {
+            if shift {
+                first
+            } else {
+                second
+            }
+        } else {
+            match (shift, lock) {
+                (false, false) => first,
+                (false, true) => upper(first),
+                (true, false) => second,
+                (true, true) => upper(second),
+            }
+        };
+        (sym != 0).then_some(sym)
+    }
+}
+
+fn is_keypad(keysym: u32) -> bool {
+    matches!(keysym, keysyms::KEY_KP_Space..=keysyms::KEY_KP_Equal)
+}
+
+fn upper(keysym: u32) -> u32 {
+    convert_case(keysym, char::to_uppercase)
+}
+
+fn lower(keysym: u32) -> u32 {
+    convert_case(keysym, char::to_lowercase)
+}
+
+/// The keysym of the other case, if it's a single character
+fn convert_case<I: Iterator<Item = char>>(keysym: u32, convert: impl Fn(char) -> I) -> u32 {
+    let converted = keysyms::keysym_to_char(keysym).and_then(|c| {
+        let mut chars = convert(c);
+        match (chars.next(), chars.next()) {
+            (Some(converted), None) => keysyms::keysym_from_char(converted),
+            _ => None,
+        }
+    });
+    converted.unwrap_or(keysym)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keysyms::*;
+
+    fn mapping() -> KeyboardMapping {
+        // Keycodes 38 (a), 10 (1) and 87 (KP_1), 4 keysyms each
+        let mut keysyms = vec![0; 4 * 80];
+        let mut set = |keycode: usize, syms: [u32; 4]| {
+            keysyms[(keycode - 8) * 4..(keycode - 8) * 4 + 4].copy_from_slice(&syms)
+        };
+        set(38, [KEY_a, 0, KEY_Cyrillic_ef, KEY_Cyrillic_EF]);
+        set(10, [KEY_1, KEY_exclam, 0, 0]);
+        set(87, [KEY_KP_End, KEY_KP_1, 0, 0]);
+        KeyboardMapping::new(8, 4, keysyms)
+    }
+
+    #[test]
+    fn core_protocol_rules() {
+        let mapping = mapping();
+        let shift = Modifiers::SHIFT.bits() as u16;
+        let lock = Modifiers::LOCK.bits() as u16;
+        let num_lock = Modifiers::MOD2.bits() as u16;
+        assert_eq!(mapping.lookup(38, 0), Some(KEY_a));
+        assert_eq!(mapping.lookup(38, shift), Some(KEY_A));
+        assert_eq!(mapping.lookup(38, lock), Some(KEY_A));
+        assert_eq!(mapping.lookup(38, 1 << 13), Some(KEY_Cyrillic_ef));
+        assert_eq!(mapping.lookup(10, shift), Some(KEY_exclam));
+        assert_eq!(mapping.lookup(10, lock), Some(KEY_1));
+        // The second group is empty, so the first is used
+        assert_eq!(mapping.lookup(10, 1 << 13), Some(KEY_1));
+        assert_eq!(mapping.lookup(87, 0), Some(KEY_KP_End));
+        assert_eq!(mapping.lookup(87, num_lock), Some(KEY_KP_1));
+        assert_eq!(mapping.lookup(87, num_lock | shift), Some(KEY_KP_End));
+        assert_eq!(mapping.lookup(9, 0), None);
+        assert_eq!(mapping.lookup(200, 0), None);
+    }
+
+    #[test]
+    fn event_args() {
+        let mapping = mapping();
+        assert_eq!(
+            key_event_args(&mapping, 38, Modifiers::CONTROL.bits() as u16, true),
+            Some((KEY_a, 30, Modifiers::CONTROL | Modifiers::RELEASE))
+        );
+        let lookup = |_: u8, _: u16| Some(KEY_Return);
+        assert_eq!(
+            key_event_args(&lookup, 36, 0, false),
+            Some((KEY_Return, 28, Modifiers::empty()))
+        );
+    }
+}
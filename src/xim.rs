@@ -0,0 +1,404 @@
+//! The IBus side of an XIM server, for legacy X clients
+//!
+//! This module doesn't speak the XIM protocol itself. Accepting the
+//! clients, the transport over X11, and encoding and decoding the messages
+//! are left to the XIM server that uses it, this crate doesn't contain one.
+//! What it does is mapping an XIM input context to an IBus one.
+//!
+//! The server keeps an `XimBridge` and an `InputContext` for each input
+//! context of its clients. The decoded requests of the client are passed to
+//! the bridge, and the signals of the input context go to `handle_event`,
+//! which returns the messages for the server to send to the client:
+//!
+//! ```no_run
+//! use ibus::xim::{XimAction, XimBridge, XIM_PREEDIT_CALLBACKS, XIM_STATUS_NOTHING};
+//! use ibus::Bus;
+//!
+//! let bus = Bus::new().unwrap();
+//! // XIM_CREATE_IC, with the `XNInputStyle` of the client
+//! let ctx = bus.create_input_context("xim").unwrap();
+//! let mut bridge = XimBridge::new(XIM_PREEDIT_CALLBACKS | XIM_STATUS_NOTHING);
+//! ctx.set_capabilities(bridge.capabilities());
+//!
+//! // XIM_SET_IC_FOCUS, and `XNSpotLocation` in the focus window at (100, 200)
+//! bridge.focus_in(&ctx).unwrap();
+//! bridge.set_window_origin(100, 200);
+//! bridge.set_spot_location(&ctx, 4, 16).unwrap();
+//!
+//! // For the events popped from an `EventQueue`, or in the handler of `EventRouter`
+//! # let event = ibus::ImeEventKind::ShowPreeditText;
+//! for action in bridge.handle_event(&event) {
+//!     match action {
+//!         XimAction::Commit(text) => { /* XIM_COMMIT */ }
+//!         _ => { /* The preedit callbacks and XIM_FORWARD_EVENT */ }
+//!     }
+//! }
+//! ```
+//!
+//! The keys come with `XIM_FORWARD_EVENT`, and go to `forward_event`. The
+//! ones it doesn't handle are sent back to the client with
+//! `XIM_FORWARD_EVENT`, and the synchronous ones are answered with
+//! `XIM_SYNC_REPLY` either way.
+//!
+//! With `XIM_PREEDIT_CALLBACKS` ("on the spot") the client draws the
+//! preedit. With the other styles the preedit is left to the candidate
+//! window of IBus, at the spot location.
+//!
+
+use crate::{
+    x11::{self, KeysymLookup},
+    AttributeKind, Capabilites, Error, ImeEventKind, InputContext, Modifiers, Text, UnderlineKind,
+};
+
+/// The preedit is drawn by the client, with the preedit callbacks
+pub const XIM_PREEDIT_CALLBACKS: u32 = 0x0002;
+/// The preedit is drawn by the server, at `XNSpotLocation`
+pub const XIM_PREEDIT_POSITION: u32 = 0x0004;
+/// The preedit is drawn by the server, e.g. in the root window
+pub const XIM_PREEDIT_NOTHING: u32 = 0x0008;
+/// There's no status
+pub const XIM_STATUS_NOTHING: u32 = 0x0400;
+
+/// The input styles to answer `XIM_GET_IM_VALUES` for `XNQueryInputStyle`
+/// with
+pub const SUPPORTED_INPUT_STYLES: [u32; 3] = [
+    XIM_PREEDIT_CALLBACKS | XIM_STATUS_NOTHING,
+    XIM_PREEDIT_POSITION | XIM_STATUS_NOTHING,
+    XIM_PREEDIT_NOTHING | XIM_STATUS_NOTHING,
+];
+
+/// `XIMReverse`, the feedback of the selected part of the preedit
+pub const XIM_REVERSE: u32 = 1;
+/// `XIMUnderline`
+pub const XIM_UNDERLINE: u32 = 1 << 1;
+
+/// A message to send to the client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XimAction {
+    /// `XIM_COMMIT` with the text, which is converted to the encoding that
+    /// was negotiated with the client (usually `COMPOUND_TEXT`)
+    Commit(String),
+    /// The `PreeditStart` callback
+    PreeditStart,
+    /// The `PreeditDraw` callback, which replaces the `chg_length`
+    /// characters from `chg_first` with `text`
+    ///
+    /// `feedbacks` has an `XIM_REVERSE` and `XIM_UNDERLINE` mask for each
+    /// character of `text`.
+    PreeditDraw {
+        caret: u32,
+        chg_first: u32,
+        chg_length: u32,
+        text: String,
+        feedbacks: Vec<u32>,
+    },
+    /// The `PreeditDone` callback
+    PreeditDone,
+    /// `XIM_FORWARD_EVENT` with a key event that the engine forwarded, the
+    /// keycode and the state of X11
+    ForwardKeyEvent {
+        keycode: u8,
+        state: u16,
+        released: bool,
+    },
+}
+
+/// Maps the requests of an XIM input context to an `InputContext`, and its
+/// signals to the messages for the client, see the module documentation
+#[derive(Debug, Clone)]
+pub struct XimBridge {
+    input_style: u32,
+    window_origin: (i32, i32),
+    line_height: i32,
+    /// The preedit of the engine, with the cursor in characters
+    preedit: Text<'static>,
+    preedit_cursor: u32,
+    preedit_visible: bool,
+    /// The number of characters that the client draws, if the preedit was
+    /// started
+    drawn: Option<u32>,
+}
+impl XimBridge {
+    /// A bridge for an input context with the `XNInputStyle` of the client,
+    /// one of `SUPPORTED_INPUT_STYLES`
+    pub fn new(input_style: u32) -> Self {
+        XimBridge {
+            input_style,
+            window_origin: (0, 0),
+            line_height: 16,
+            preedit: Text::from(""),
+            preedit_cursor: 0,
+            preedit_visible: false,
+            drawn: None,
+        }
+    }
+
+    pub fn input_style(&self) -> u32 {
+        self.input_style
+    }
+
+    /// Whether the client draws the preedit
+    pub fn on_the_spot(&self) -> bool {
+        self.input_style & XIM_PREEDIT_CALLBACKS != 0
+    }
+
+    /// The capabilities to set on the input context, the preedit is only
+    /// sent to the clients that draw it
+    pub fn capabilities(&self) -> Capabilites {
+        if self.on_the_spot() {
+            Capabilites::PREEDIT_TEXT | Capabilites::FOCUS
+        } else {
+            Capabilites::FOCUS
+        }
+    }
+
+    /// The preedit of the engine, for `XIM_RESET_IC_REPLY`
+    pub fn preedit(&self) -> &str {
+        self.preedit.as_str()
+    }
+
+    /// Sets where the focus window is on the screen, from
+    /// `TranslateCoordinates`, to place the spot location
+    pub fn set_window_origin(&mut self, x: i32, y: i32) {
+        self.window_origin = (x, y);
+    }
+
+    /// The `XNLineSpace` of the client, the height of the cursor location
+    pub fn set_line_space(&mut self, line_space: i32) {
+        self.line_height = line_space.max(0);
+    }
+
+    /// The `XNSpotLocation` of the client, the start of the baseline of the
+    /// caret in the focus window
+    pub fn set_spot_location(&self, ctx: &InputContext, x: i16, y: i16) -> Result<(), Error> {
+        let (origin_x, origin_y) = self.window_origin;
+        ctx.set_cursor_location(
+            origin_x + i32::from(x),
+            origin_y + i32::from(y) - self.line_height,
+            0,
+            self.line_height,
+        )
+    }
+
+    /// `XIM_SET_IC_FOCUS`
+    pub fn focus_in(&self, ctx: &InputContext) -> Result<(), Error> {
+        ctx.focus_in()
+    }
+
+    /// `XIM_UNSET_IC_FOCUS`, returns the callbacks that end the preedit
+    pub fn focus_out(&mut self, ctx: &InputContext) -> Result<Vec<XimAction>, Error> {
+        ctx.focus_out()?;
+        Ok(self.end_preedit())
+    }
+
+    /// `XIM_RESET_IC`, returns the callbacks that end the preedit
+    ///
+    /// Take `preedit` before it for the reply.
+    pub fn reset(&mut self, ctx: &InputContext) -> Result<Vec<XimAction>, Error> {
+        ctx.reset()?;
+        Ok(self.end_preedit())
+    }
+
+    /// A key event of `XIM_FORWARD_EVENT`, with the keycode and the state of
+    /// the X11 event. Returns whether the engine handled it.
+    pub fn forward_event(
+        &self,
+        ctx: &InputContext,
+        lookup: &impl KeysymLookup,
+        keycode: u8,
+        state: u16,
+        released: bool,
+    ) -> Result<bool, Error> {
+        match x11::key_event_args(lookup, keycode, state, released) {
+            Some((sym, code, modifiers)) => ctx.process_key_event(sym, code, modifiers),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the messages to send to the client for a signal of the input
+    /// context
+    pub fn handle_event(&mut self, event: &ImeEventKind) -> Vec<XimAction> {
+        match event {
+            ImeEventKind::CommitText(text) => {
+                let mut actions = self.end_preedit();
+                actions.push(XimAction::Commit(text.as_str().to_owned()));
+                actions
+            }
+            ImeEventKind::UpdatePreeditText {
+                text,
+                cursor_pos,
+                visible,
+            } => {
+                self.preedit = text.clone();
+                self.preedit_cursor = *cursor_pos;
+                self.preedit_visible = *visible;
+                self.draw_preedit()
+            }
+            ImeEventKind::ShowPreeditText => {
+                self.preedit_visible = true;
+                self.draw_preedit()
+            }
+            ImeEventKind::HidePreeditText => {
+                self.preedit_visible = false;
+                self.draw_preedit()
+            }
+            ImeEventKind::ForwardKeyEvent {
+                keycode, modifiers, ..
+            } => vec![XimAction::ForwardKeyEvent {
+                keycode: keycode.saturating_add(8).min(u32::from(u8::MAX)) as u8,
+                state: (*modifiers & !Modifiers::RELEASE).bits() as u16,
+                released: modifiers.contains(Modifiers::RELEASE),
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    /// The callbacks that show the current preedit, or end it if it's
+    /// hidden or empty
+    fn draw_preedit(&mut self) -> Vec<XimAction> {
+        if !self.on_the_spot() {
+            return Vec::new();
+        }
+        let text = self.preedit.as_str();
+        if !self.preedit_visible || text.is_empty() {
+            return self.end_preedit();
+        }
+        let mut actions = Vec::new();
+        let chg_length = match self.drawn {
+            Some(drawn) => drawn,
+            None => {
+                actions.push(XimAction::PreeditStart);
+                0
+            }
+        };
+        let len = text.chars().count() as u32;
+        actions.push(XimAction::PreeditDraw {
+            caret: self.preedit_cursor.min(len),
+            chg_first: 0,
+            chg_length,
+            text: text.to_owned(),
+            feedbacks: feedbacks(&self.preedit, len),
+        });
+        self.drawn = Some(len);
+        actions
+    }
+
+    /// Clears the preedit of the client, if it was started
+    fn end_preedit(&mut self) -> Vec<XimAction> {
+        self.preedit = Text::from("");
+        self.preedit_cursor = 0;
+        match self.drawn.take() {
+            Some(drawn) => vec![
+                XimAction::PreeditDraw {
+                    caret: 0,
+                    chg_first: 0,
+                    chg_length: drawn,
+                    text: String::new(),
+                    feedbacks: Vec::new(),
+                },
+                XimAction::PreeditDone,
+            ],
+            None => Vec::new(),
+        }
+    }
+}
+
+/// The feedback of each character of the preedit, the text that has a
+/// background color is the selected part
+fn feedbacks(text: &Text, len: u32) -> Vec<u32> {
+    let mut feedbacks = vec![0; len as usize];
+    for attribute in text.attributes() {
+        let feedback = match attribute.kind {
+            AttributeKind::Underline(UnderlineKind::None) => continue,
+            AttributeKind::Underline(_) => XIM_UNDERLINE,
+            AttributeKind::Background(_) => XIM_REVERSE,
+            _ => continue,
+        };
+        let end = attribute.end_index.min(len);
+        for index in attribute.start_index.min(end)..end {
+            feedbacks[index as usize] |= feedback;
+        }
+    }
+    feedbacks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Attribute;
+
+    fn preedit(text: &str, cursor_pos: u32) -> ImeEventKind {
+        ImeEventKind::UpdatePreeditText {
+            text: Text::new(
+                text.to_owned(),
+                vec![Attribute {
+                    kind: AttributeKind::Underline(UnderlineKind::Single),
+                    start_index: 0,
+                    end_index: 2,
+                }],
+            ),
+            cursor_pos,
+            visible: true,
+        }
+    }
+
+    #[test]
+    fn preedit_callbacks() {
+        let mut bridge = XimBridge::new(XIM_PREEDIT_CALLBACKS | XIM_STATUS_NOTHING);
+        assert_eq!(
+            bridge.handle_event(&preedit("nih", 3)),
+            [
+                XimAction::PreeditStart,
+                XimAction::PreeditDraw {
+                    caret: 3,
+                    chg_first: 0,
+                    chg_length: 0,
+                    text: "nih".to_owned(),
+                    feedbacks: vec![XIM_UNDERLINE, XIM_UNDERLINE, 0],
+                },
+            ]
+        );
+        assert!(matches!(
+            bridge.handle_event(&preedit("ni", 2))[..],
+            [XimAction::PreeditDraw { chg_length: 3, .. }]
+        ));
+
+        let commit = ImeEventKind::CommitText(Text::new("你", vec![]));
+        assert_eq!(
+            bridge.handle_event(&commit),
+            [
+                XimAction::PreeditDraw {
+                    caret: 0,
+                    chg_first: 0,
+                    chg_length: 2,
+                    text: String::new(),
+                    feedbacks: Vec::new(),
+                },
+                XimAction::PreeditDone,
+                XimAction::Commit("你".to_owned()),
+            ]
+        );
+        assert!(bridge.handle_event(&preedit("", 0)).is_empty());
+    }
+
+    #[test]
+    fn server_drawn_preedit() {
+        let mut bridge = XimBridge::new(XIM_PREEDIT_POSITION | XIM_STATUS_NOTHING);
+        assert_eq!(bridge.capabilities(), Capabilites::FOCUS);
+        assert!(bridge.handle_event(&preedit("ni", 2)).is_empty());
+
+        let forward = ImeEventKind::ForwardKeyEvent {
+            keysym: crate::keysyms::KEY_a,
+            keycode: 30,
+            modifiers: Modifiers::SHIFT | Modifiers::RELEASE,
+        };
+        assert_eq!(
+            bridge.handle_event(&forward),
+            [XimAction::ForwardKeyEvent {
+                keycode: 38,
+                state: 1,
+                released: true,
+            }]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+#!/usr/bin/env python3
+"""Captures the golden messages of `src/golden.rs` from libibus
+
+The values are serialized by libibus and marshalled by GDBus, the way an
+application or an engine written in C sends them. Needs PyGObject and the
+IBus typelib (`gir1.2-ibus-1.0` on Debian).
+
+Usage: tools/capture_golden.py [src/golden]
+"""
+
+import os
+import struct
+import sys
+
+import gi
+
+gi.require_version("IBus", "1.0")
+from gi.repository import GLib, Gio, IBus  # noqa: E402
+
+PATH = "/org/freedesktop/IBus/InputContext_1"
+INPUT_CONTEXT = "org.freedesktop.IBus.InputContext"
+RELEASE = 1 << 30
+
+
+def text(string, attrs=()):
+    t = IBus.Text.new_from_string(string)
+    attr_list = IBus.AttrList.new()
+    for attr in attrs:
+        attr_list.append(attr)
+    t.set_attributes(attr_list)
+    return t.serialize_object()
+
+
+def lookup_table():
+    table = IBus.LookupTable.new(5, 0, True, False)
+    table.set_orientation(IBus.Orientation.SYSTEM)
+    for candidate in ["你", "尼"]:
+        table.append_candidate(IBus.Text.new_from_string(candidate))
+    return table.serialize_object()
+
+
+def prop_list():
+    prop = IBus.Property.new(
+        "InputMode",
+        IBus.PropType.NORMAL,
+        IBus.Text.new_from_string("あ"),
+        "",
+        IBus.Text.new_from_string(""),
+        True,
+        True,
+        IBus.PropState.UNCHECKED,
+        None,
+    )
+    prop.set_symbol(IBus.Text.new_from_string("あ"))
+    props = IBus.PropList.new()
+    props.append(prop)
+    return props.serialize_object()
+
+
+def underline(start, end):
+    return IBus.attr_underline_new(IBus.AttrUnderline.SINGLE, start, end)
+
+
+# The same values as the tests of `src/golden.rs`
+CASES = [
+    ("process_key_event", "call", "ProcessKeyEvent", "(uuu)", (0x61, 30, 0)),
+    ("process_key_event_release", "call", "ProcessKeyEvent", "(uuu)", (0x61, 30, RELEASE)),
+    ("set_cursor_location", "call", "SetCursorLocation", "(iiii)", (10, 20, 1, 16)),
+    ("set_capabilities", "call", "SetCapabilities", "(u)", (1 | 8 | 32,)),
+    ("set_surrounding_text", "call", "SetSurroundingText", "(vuu)", (text("héllo"), 3, 1)),
+    ("focus_in", "call", "FocusIn", None, None),
+    ("commit_text", "signal", "CommitText", "(v)", (text("你好"),)),
+    (
+        "update_preedit_text",
+        "signal",
+        "UpdatePreeditText",
+        "(vubu)",
+        (text("ni", [underline(0, 2)]), 2, True, 1),
+    ),
+    ("update_auxiliary_text", "signal", "UpdateAuxiliaryText", "(vb)", (text("n"), True)),
+    ("update_lookup_table", "signal", "UpdateLookupTable", "(vb)", (lookup_table(), True)),
+    ("forward_key_event", "signal", "ForwardKeyEvent", "(uuu)", (0xFF0D, 28, 0)),
+    ("delete_surrounding_text", "signal", "DeleteSurroundingText", "(iu)", (-1, 1)),
+    ("register_properties", "signal", "RegisterProperties", "(v)", (prop_list(),)),
+]
+
+
+def body(kind, member, signature, args):
+    if kind == "call":
+        msg = Gio.DBusMessage.new_method_call("org.freedesktop.IBus", PATH, INPUT_CONTEXT, member)
+    else:
+        msg = Gio.DBusMessage.new_signal(PATH, INPUT_CONTEXT, member)
+    if signature is not None:
+        msg.set_body(GLib.Variant(signature, args))
+    msg.set_serial(1)
+    blob = msg.to_blob(Gio.DBusCapabilityFlags.NONE)
+    assert blob[0:1] == b"l", "the golden messages are little endian"
+    (length,) = struct.unpack_from("<I", blob, 4)
+    return blob[len(blob) - length :]
+
+
+def main():
+    out = sys.argv[1] if len(sys.argv) > 1 else "src/golden"
+    for name, kind, member, signature, args in CASES:
+        data = body(kind, member, signature, args)
+        with open(os.path.join(out, name + ".golden"), "w") as f:
+            f.write("signature: %s\n" % (signature or "()")[1:-1])
+            for i in range(0, len(data), 16):
+                f.write(" ".join("%02x" % b for b in data[i : i + 16]) + "\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+"""Generates `src/keysyms/table.rs` from X11's `keysymdef.h`
+
+Usage: tools/gen_keysyms.py [/usr/include/X11/keysymdef.h] > src/keysyms/table.rs
+"""
+
+import re
+import sys
+
+DEFINE = re.compile(r"^#define XK_([a-zA-Z_0-9]+)\s+0x([0-9a-fA-F]+)")
+UNICODE = re.compile(r"/\*\s*U\+([0-9a-fA-F]{4,6}) ")
+
+
+def main():
+    path = sys.argv[1] if len(sys.argv) > 1 else "/usr/include/X11/keysymdef.h"
+    keysyms = []
+    seen = set()
+    to_unicode = {}
+    with open(path) as f:
+        for line in f:
+            m = DEFINE.match(line)
+            if not m:
+                continue
+            name, value = m.group(1), int(m.group(2), 16)
+            if name in seen:
+                continue
+            seen.add(name)
+            keysyms.append((name, value))
+            u = UNICODE.search(line)
+            # Aliases are marked as deprecated, the first mapping wins
+            if u and value < 0x1000000 and value not in to_unicode:
+                to_unicode[value] = int(u.group(1), 16)
+
+    out = sys.stdout
+    out.write("// This file is generated by `tools/gen_keysyms.py`. Do not edit.\n\n")
+    for name, value in keysyms:
+        out.write("pub const KEY_%s: u32 = 0x%x;\n" % (name, value))
+
+    out.write("\n/// Sorted by name\n")
+    out.write("pub(crate) const BY_NAME: &[(&str, u32)] = &[\n")
+    for name, value in sorted(keysyms, key=lambda k: k[0].encode()):
+        out.write('    ("%s", KEY_%s),\n' % (name, name))
+    out.write("];\n")
+
+    # When several names share a value, the first one in keysymdef.h is the
+    # canonical one (the later ones are usually deprecated aliases)
+    by_value = {}
+    for name, value in keysyms:
+        by_value.setdefault(value, name)
+    out.write("\n/// Sorted by value, containing only the canonical name for each value\n")
+    out.write("pub(crate) const BY_VALUE: &[(u32, &str)] = &[\n")
+    for value in sorted(by_value):
+        out.write('    (KEY_%s, "%s"),\n' % (by_value[value], by_value[value]))
+    out.write("];\n")
+
+    out.write("\n/// Sorted by keysym, the characters that keysyms outside of the Unicode\n")
+    out.write("/// keysym range (`0x01000000` + code point) correspond to\n")
+    out.write("pub(crate) const TO_UNICODE: &[(u32, char)] = &[\n")
+    for value in sorted(to_unicode):
+        out.write("    (0x%x, '\\u{%x}'),\n" % (value, to_unicode[value]))
+    out.write("];\n")
+
+
+if __name__ == "__main__":
+    main()